@@ -10,7 +10,7 @@
 
 use crate::event::Event;
 use crate::style;
-use crate::Vec2;
+use crate::{Rect, Vec2};
 
 /// Trait defining the required methods to be a backend.
 ///
@@ -53,6 +53,14 @@ pub trait Backend {
     /// Should return `true` if this backend supports colors.
     fn has_colors(&self) -> bool;
 
+    /// Returns the number of colors this backend can display.
+    ///
+    /// Used to estimate how colors will actually look, for example when
+    /// checking contrast. The default implementation assumes true colors.
+    fn color_resolution(&self) -> style::ColorResolution {
+        style::ColorResolution::TrueColor
+    }
+
     /// Returns the screen size.
     fn screen_size(&self) -> Vec2;
 
@@ -92,6 +100,57 @@ pub trait Backend {
     /// Disables the given effect.
     fn unset_effect(&self, effect: style::Effect);
 
+    /// Starts using a new underline color.
+    ///
+    /// `None` means underlines use the text color.
+    ///
+    /// The default implementation does nothing: underlines always use the text color.
+    fn set_underline_color(&self, color: Option<style::Color>) {
+        let _ = color;
+    }
+
+    /// Reports the region of the screen that changed during this frame.
+    ///
+    /// This will be called after printing the changed cells, right before `refresh()`.
+    /// It is not called when nothing changed.
+    ///
+    /// Backends sending their output somewhere else (for example over a network)
+    /// can use this to only transmit the given region.
+    ///
+    /// The default implementation does nothing.
+    fn flush_region(&self, region: Rect) {
+        let _ = region;
+    }
+
+    /// Returns the graphics protocol this backend can use to display images, if any.
+    ///
+    /// Data for this protocol is sent with `print_raw()`.
+    ///
+    /// The default implementation returns `None`: views will only use text cells.
+    fn graphics_protocol(&self) -> Option<GraphicsProtocol> {
+        None
+    }
+
+    /// Returns the size of a single cell in pixels, if known.
+    ///
+    /// Used to scale images for protocols working with pixels, like sixel.
+    ///
+    /// The default implementation returns `None`.
+    fn cell_pixel_size(&self) -> Option<Vec2> {
+        None
+    }
+
+    /// Sends data to the terminal as-is, at the current cursor.
+    ///
+    /// This is used for escape sequences, like graphics protocols. The data
+    /// should not be translated, and does not take any cell: the cursor
+    /// position is unspecified afterwards.
+    ///
+    /// The default implementation ignores the data.
+    fn print_raw(&self, data: &str) {
+        let _ = data;
+    }
+
     /// Returns a name to identify the backend.
     ///
     /// Mostly used for debugging.
@@ -100,6 +159,70 @@ pub trait Backend {
     }
 }
 
+/// Terminal protocol used to display images.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GraphicsProtocol {
+    /// The DEC sixel format, supported by xterm, foot, mlterm and others.
+    Sixel,
+
+    /// The kitty graphics protocol, supported by kitty, WezTerm and Ghostty.
+    Kitty,
+
+    /// iTerm2 inline images, also supported by WezTerm.
+    Iterm2,
+}
+
+impl GraphicsProtocol {
+    /// Guesses the protocol supported by the current terminal from the environment.
+    ///
+    /// The `CURSIVE_GRAPHICS` environment variable can be set to `sixel`,
+    /// `kitty`, `iterm2` or `none` to override the detection.
+    pub fn detect() -> Option<Self> {
+        let var = |name: &str| std::env::var(name).unwrap_or_default();
+
+        if let Ok(value) = std::env::var("CURSIVE_GRAPHICS") {
+            return value.parse().ok();
+        }
+
+        let term = var("TERM");
+        let term_program = var("TERM_PROGRAM");
+
+        if term == "xterm-kitty" || term == "xterm-ghostty" || !var("KITTY_WINDOW_ID").is_empty() {
+            Some(GraphicsProtocol::Kitty)
+        } else if term_program == "iTerm.app" || term_program == "WezTerm" {
+            Some(GraphicsProtocol::Iterm2)
+        } else if term.contains("sixel") || term.starts_with("foot") || term == "mlterm" {
+            Some(GraphicsProtocol::Sixel)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a sequence removing all images drawn with this protocol.
+    ///
+    /// Returns `None` if images are simply erased by text printed over them.
+    pub fn clear_sequence(self) -> Option<&'static str> {
+        match self {
+            // Deletes every placement on screen, and frees the images.
+            GraphicsProtocol::Kitty => Some("\x1b_Ga=d,d=A,q=2\x1b\\"),
+            GraphicsProtocol::Sixel | GraphicsProtocol::Iterm2 => None,
+        }
+    }
+}
+
+impl std::str::FromStr for GraphicsProtocol {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Sixel" | "sixel" => GraphicsProtocol::Sixel,
+            "Kitty" | "kitty" => GraphicsProtocol::Kitty,
+            "Iterm2" | "iterm2" => GraphicsProtocol::Iterm2,
+            _ => return Err(()),
+        })
+    }
+}
+
 /// Dummy backend that does nothing and immediately exits.
 ///
 /// Mostly used for testing.
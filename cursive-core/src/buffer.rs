@@ -1,12 +1,19 @@
 //! Output buffer
 
-use crate::backend::Backend;
-use crate::style::ConcreteStyle;
+use crate::backend::{Backend, GraphicsProtocol};
+use crate::style::{ColorDowngrade, ConcreteStyle, Style};
+use crate::utils::markup::StyledString;
 use crate::{Rect, Vec2};
 
+use std::collections::HashMap;
+use std::sync::Arc;
+
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
+// Downgraded styles cached by a buffer, so colorful content doesn't grow it forever.
+const MAX_DOWNGRADED_STYLES: usize = 4096;
+
 /// The width of a cell.
 ///
 /// Most characters are single-width. Some asian characters and emojis are double-width.
@@ -96,6 +103,11 @@ impl Cell {
         self.width.as_usize()
     }
 
+    /// Returns the style of this cell.
+    pub fn style(&self) -> ConcreteStyle {
+        self.style
+    }
+
     /// Sets the style for this cell.
     pub fn set_style(&mut self, style: ConcreteStyle) {
         self.style = style;
@@ -122,6 +134,176 @@ impl Cell {
     }
 }
 
+/// A substitution table for graphemes the terminal cannot render.
+///
+/// Each entry maps a single grapheme to a replacement string. The table is
+/// applied by the [`PrintBuffer`] when printing, so views don't need to know
+/// about it.
+///
+/// To preserve the layout, a replacement always occupies exactly as many
+/// cells as the original grapheme: it will be truncated or padded with
+/// spaces as needed.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::buffer::GlyphFallbacks;
+///
+/// let mut fallbacks = GlyphFallbacks::ascii();
+/// // Replace a nerd-font folder icon with something more portable.
+/// fallbacks.insert("\u{f07b}", "D");
+///
+/// assert_eq!(fallbacks.get("┌"), Some("+"));
+/// assert_eq!(fallbacks.get("\u{f07b}"), Some("D"));
+/// assert_eq!(fallbacks.get("a"), None);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GlyphFallbacks {
+    map: HashMap<String, String>,
+}
+
+impl GlyphFallbacks {
+    /// Creates a new empty table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a table mapping the box-drawing and block characters used by
+    /// built-in views to ASCII approximations.
+    pub fn ascii() -> Self {
+        ASCII_FALLBACKS.iter().copied().collect()
+    }
+
+    /// Returns a table suited to the current terminal.
+    ///
+    /// If the locale (as given by `LC_ALL`, `LC_CTYPE` or `LANG`) does not
+    /// advertise UTF-8 support, this returns [`GlyphFallbacks::ascii()`].
+    /// Otherwise, it returns an empty table.
+    pub fn detect() -> Self {
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|value| !value.is_empty());
+
+        match locale {
+            Some(locale) if !is_utf8_locale(&locale) => Self::ascii(),
+            _ => Self::new(),
+        }
+    }
+
+    /// Adds a replacement for the given grapheme.
+    ///
+    /// Returns the previous replacement, if any.
+    pub fn insert<S, R>(&mut self, grapheme: S, replacement: R) -> Option<String>
+    where
+        S: Into<String>,
+        R: Into<String>,
+    {
+        self.map.insert(grapheme.into(), replacement.into())
+    }
+
+    /// Adds a replacement for the given grapheme.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn with<S, R>(mut self, grapheme: S, replacement: R) -> Self
+    where
+        S: Into<String>,
+        R: Into<String>,
+    {
+        self.insert(grapheme, replacement);
+        self
+    }
+
+    /// Removes the replacement for the given grapheme.
+    ///
+    /// Returns the removed replacement, if any.
+    pub fn remove(&mut self, grapheme: &str) -> Option<String> {
+        self.map.remove(grapheme)
+    }
+
+    /// Returns the replacement for the given grapheme, if any.
+    pub fn get(&self, grapheme: &str) -> Option<&str> {
+        self.map.get(grapheme).map(String::as_str)
+    }
+
+    /// Returns `true` if this table has no replacement.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+impl<S, R> Extend<(S, R)> for GlyphFallbacks
+where
+    S: Into<String>,
+    R: Into<String>,
+{
+    fn extend<T: IntoIterator<Item = (S, R)>>(&mut self, iter: T) {
+        self.map.extend(
+            iter.into_iter()
+                .map(|(grapheme, replacement)| (grapheme.into(), replacement.into())),
+        );
+    }
+}
+
+impl<S, R> FromIterator<(S, R)> for GlyphFallbacks
+where
+    S: Into<String>,
+    R: Into<String>,
+{
+    fn from_iter<T: IntoIterator<Item = (S, R)>>(iter: T) -> Self {
+        GlyphFallbacks {
+            map: iter
+                .into_iter()
+                .map(|(grapheme, replacement)| (grapheme.into(), replacement.into()))
+                .collect(),
+        }
+    }
+}
+
+// ASCII approximations for the glyphs used by built-in views.
+//
+// Every replacement has the same width as the original glyph.
+static ASCII_FALLBACKS: &[(&str, &str)] = &[
+    ("─", "-"),
+    ("│", "|"),
+    ("┌", "+"),
+    ("┐", "+"),
+    ("└", "+"),
+    ("┘", "+"),
+    ("├", "+"),
+    ("┤", "+"),
+    ("┬", "+"),
+    ("┴", "+"),
+    ("┼", "+"),
+    ("╳", "+"),
+    ("▒", "#"),
+    ("█", "#"),
+    ("▸", ">"),
+    ("▾", "v"),
+    // Partial blocks can't be approximated, so we round them down.
+    ("▏", " "),
+    ("▎", " "),
+    ("▍", " "),
+    ("▌", " "),
+    ("▋", " "),
+    ("▊", " "),
+    ("▉", " "),
+];
+
+/// Returns an ASCII approximation for the given grapheme, if one is known.
+pub(crate) fn ascii_fallback(grapheme: &str) -> Option<&'static str> {
+    ASCII_FALLBACKS
+        .iter()
+        .find(|&&(glyph, _)| glyph == grapheme)
+        .map(|&(_, replacement)| replacement)
+}
+
+fn is_utf8_locale(locale: &str) -> bool {
+    let locale = locale.to_ascii_lowercase();
+    locale.contains("utf-8") || locale.contains("utf8")
+}
+
 /// A buffer for printing stuff.
 ///
 /// Can be flushed out to the backend.
@@ -146,9 +328,39 @@ pub struct PrintBuffer {
     // This is an internal cache used to remember the last style flushed to the backend.
     current_style: ConcreteStyle,
 
+    // Replacements applied to printed graphemes.
+    glyph_fallbacks: Option<Arc<GlyphFallbacks>>,
+
+    // Name of a view to capture, and its output once drawn.
+    capture: Option<(String, Option<Box<PrintBuffer>>)>,
+
+    // Area written to since the last flush.
+    //
+    // Only this area needs to be compared when flushing.
+    damage: Option<Rect>,
+
+    // Graphics protocol and cell size in pixels reported by the backend.
+    graphics_protocol: Option<GraphicsProtocol>,
+    cell_pixel_size: Option<Vec2>,
+
+    // Images drawn over the cells, and the ones last flushed.
+    graphics: Vec<Graphics>,
+    frozen_graphics: Vec<Graphics>,
+
+    // How colors are downgraded when flushing, and the styles already downgraded.
+    color_downgrade: ColorDowngrade,
+    downgraded_styles: Option<HashMap<ConcreteStyle, ConcreteStyle>>,
+
     size: Vec2,
 }
 
+// Image drawn with a graphics protocol over an area of the buffer.
+#[derive(Clone, PartialEq, Eq)]
+struct Graphics {
+    area: Rect,
+    data: Arc<str>,
+}
+
 /// A view into a rectangular area of the buffer.
 pub struct Window<'a> {
     buffer: &'a mut PrintBuffer,
@@ -217,10 +429,190 @@ impl PrintBuffer {
             active_buffer: Vec::new(),
             frozen_buffer: Vec::new(),
             current_style: ConcreteStyle::terminal_default(),
+            glyph_fallbacks: None,
+            capture: None,
+            damage: None,
+            graphics_protocol: None,
+            cell_pixel_size: None,
+            graphics: Vec::new(),
+            frozen_graphics: Vec::new(),
+            color_downgrade: ColorDowngrade::TrueColor,
+            downgraded_styles: None,
             size: Vec2::ZERO,
         }
     }
 
+    /// Returns the area written to since the last flush, if any.
+    pub fn damage(&self) -> Option<Rect> {
+        self.damage
+    }
+
+    // Records that the given area was written to.
+    fn mark_damaged(&mut self, area: Rect) {
+        self.damage = Some(match self.damage {
+            Some(damage) => damage.union(area),
+            None => area,
+        });
+    }
+
+    // Records that the entire buffer was written to.
+    fn mark_all_damaged(&mut self) {
+        if self.size.x > 0 && self.size.y > 0 {
+            self.mark_damaged(Rect::from_size(Vec2::zero(), self.size));
+        }
+    }
+
+    /// Sets the substitution table applied to printed graphemes.
+    ///
+    /// This only affects future prints.
+    pub fn set_glyph_fallbacks(&mut self, glyph_fallbacks: Option<Arc<GlyphFallbacks>>) {
+        self.glyph_fallbacks = glyph_fallbacks;
+    }
+
+    /// Returns the substitution table applied to printed graphemes, if any.
+    pub fn glyph_fallbacks(&self) -> Option<&GlyphFallbacks> {
+        self.glyph_fallbacks.as_deref()
+    }
+
+    /// Sets the graphics protocol images can be drawn with, and the size of a cell in pixels.
+    ///
+    /// This is usually what the backend reports.
+    pub fn set_graphics_support(
+        &mut self,
+        protocol: Option<GraphicsProtocol>,
+        cell_pixel_size: Option<Vec2>,
+    ) {
+        if self.graphics_protocol != protocol {
+            self.graphics.clear();
+        }
+        self.graphics_protocol = protocol;
+        self.cell_pixel_size = cell_pixel_size;
+    }
+
+    /// Returns the graphics protocol images can be drawn with, if any.
+    pub fn graphics_protocol(&self) -> Option<GraphicsProtocol> {
+        self.graphics_protocol
+    }
+
+    /// Returns the size of a cell in pixels, if known.
+    pub fn cell_pixel_size(&self) -> Option<Vec2> {
+        self.cell_pixel_size
+    }
+
+    /// Sets how colors are downgraded when flushing to the backend.
+    ///
+    /// `ColorDowngrade::Auto` keeps all colors: resolve it first with
+    /// [`ColorDowngrade::resolve`].
+    ///
+    /// The entire buffer is sent again on the next flush if this changes.
+    pub fn set_color_downgrade(&mut self, color_downgrade: ColorDowngrade) {
+        if self.color_downgrade != color_downgrade {
+            self.color_downgrade = color_downgrade;
+            self.downgraded_styles = None;
+
+            // Forget what was flushed, so every cell is printed again.
+            self.frozen_buffer.fill(None);
+            self.mark_all_damaged();
+        }
+    }
+
+    /// Returns how colors are downgraded when flushing to the backend.
+    pub fn color_downgrade(&self) -> ColorDowngrade {
+        self.color_downgrade
+    }
+
+    /// Draws an image over the given area, using the graphics protocol.
+    ///
+    /// `data` is sent as-is to the backend when flushing, after moving the cursor
+    /// to the top-left corner of the area. It should not move the cursor outside
+    /// of the area.
+    ///
+    /// The cells below should still be printed, as a fallback: the image is dropped
+    /// if any of them is printed over later, for example by another layer.
+    ///
+    /// Does nothing if there is no graphics protocol, or if the area does not fit in
+    /// the buffer. Sixel images are also not drawn on the last row, since the
+    /// terminal would scroll.
+    pub fn print_graphics(&mut self, area: Rect, data: Arc<str>) {
+        if self.graphics_protocol.is_none() || !area.bottom_right().strictly_lt(self.size) {
+            return;
+        }
+
+        // Terminals scroll after drawing a sixel image on the last row.
+        if self.graphics_protocol == Some(GraphicsProtocol::Sixel)
+            && area.bottom() + 1 == self.size.y
+        {
+            return;
+        }
+
+        self.graphics
+            .retain(|graphics| !graphics.area.intersects(area));
+        self.graphics.push(Graphics { area, data });
+    }
+
+    // Drops the images covering a cell being changed.
+    fn drop_graphics_at(&mut self, pos: Vec2) {
+        if !self.graphics.is_empty() {
+            self.graphics
+                .retain(|graphics| !graphics.area.contains(pos));
+        }
+    }
+
+    /// Requests the output of the view with the given name to be captured.
+    ///
+    /// Once drawn, the captured output can be retrieved with `take_captured()`.
+    pub(crate) fn capture_view(&mut self, name: &str) {
+        self.capture = Some((name.to_string(), None));
+    }
+
+    /// Returns `true` if the output of the view with this name should be captured.
+    ///
+    /// Only the first view drawn with a matching name is captured.
+    pub(crate) fn is_capturing(&self, name: &str) -> bool {
+        matches!(&self.capture, Some((target, None)) if target == name)
+    }
+
+    /// Stores the captured output of a view.
+    pub(crate) fn set_captured(&mut self, buffer: PrintBuffer) {
+        if let Some((_, captured)) = &mut self.capture {
+            *captured = Some(Box::new(buffer));
+        }
+    }
+
+    /// Returns the captured output of a view, if any.
+    pub(crate) fn take_captured(&mut self) -> Option<PrintBuffer> {
+        self.capture.take()?.1.map(|buffer| *buffer)
+    }
+
+    /// Converts the content of this buffer to a styled string.
+    ///
+    /// Each row of the buffer becomes a line. Cells never written to are
+    /// converted to unstyled spaces.
+    pub fn to_styled_string(&self) -> StyledString {
+        let mut result = StyledString::new();
+
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                result.append_plain("\n");
+            }
+
+            // Number of cells covered by the previous (double-wide) cell.
+            let mut covered = 0;
+            for cell in row {
+                match cell {
+                    Some(cell) => {
+                        result.append_styled(cell.text(), Style::from(cell.style()));
+                        covered = cell.width() - 1;
+                    }
+                    None if covered > 0 => covered -= 1,
+                    None => result.append_plain(" "),
+                }
+            }
+        }
+
+        result.canonical()
+    }
+
     /// Iterate on the rows of this buffer.
     pub fn rows(&self) -> impl Iterator<Item = &[Option<Cell>]> {
         self.active_buffer.chunks(self.size.x)
@@ -239,6 +631,8 @@ impl PrintBuffer {
 
         reset(&mut self.active_buffer);
         reset(&mut self.frozen_buffer);
+        self.graphics.clear();
+        self.mark_all_damaged();
     }
 
     /// Fill the buffer with the given text and style.
@@ -256,6 +650,8 @@ impl PrintBuffer {
             cell.text.push_str(text);
             cell.width = width;
         }
+        self.graphics.clear();
+        self.mark_all_damaged();
     }
 
     /// Returns the current size of the buffer.
@@ -277,6 +673,10 @@ impl PrintBuffer {
 
         self.active_buffer.resize_with(len, Default::default);
         self.frozen_buffer.resize_with(len, Default::default);
+        self.graphics.clear();
+
+        self.damage = None;
+        self.mark_all_damaged();
     }
 
     /// Print some text at the given location.
@@ -298,6 +698,7 @@ impl PrintBuffer {
                     prev.text.clear();
                     prev.text.push_str(" ");
                     // Preserve style.
+                    self.mark_damaged(Rect::from_point(start - (1, 0)));
                 }
             }
         }
@@ -312,6 +713,9 @@ impl PrintBuffer {
                 .all(|c| matches!(c, (..='\u{001F}') | ('\u{007F}'..='\u{009F}')))
         }
 
+        // Cheap clone so we can keep writing to `self` while reading the table.
+        let glyph_fallbacks = self.glyph_fallbacks.clone();
+
         // Fill our active buffer
         // TODO: Use some WithWidth(&str, usize) to not re-compute width a thousand times
         for g in text.graphemes(true) {
@@ -322,7 +726,9 @@ impl PrintBuffer {
                 continue;
             }
 
-            if is_control_char(g) {
+            if let Some(replacement) = glyph_fallbacks.as_ref().and_then(|f| f.get(g)) {
+                self.set_replacement(pos, replacement, width, style);
+            } else if is_control_char(g) {
                 // With unicode-width >= 0.1.13, control chars have non-zero width (in
                 // practice width = 1).
                 debug_assert_eq!(
@@ -338,15 +744,55 @@ impl PrintBuffer {
         }
     }
 
+    /// Prints `replacement` in exactly `width` cells, starting at `pos`.
+    ///
+    /// The replacement is truncated or padded with spaces as needed.
+    fn set_replacement(
+        &mut self,
+        pos: Vec2,
+        replacement: &str,
+        width: usize,
+        style: ConcreteStyle,
+    ) {
+        let mut pos = pos;
+        let mut remaining = width;
+
+        for g in replacement.graphemes(true) {
+            let g_width = g.width();
+            if g_width == 0 {
+                continue;
+            }
+            if g_width > remaining {
+                break;
+            }
+
+            self.set_cell(pos, g, CellWidth::from_usize(g_width), style);
+            pos.x += g_width;
+            remaining -= g_width;
+        }
+
+        for _ in 0..remaining {
+            self.set_cell(pos, " ", CellWidth::Single, style);
+            pos.x += 1;
+        }
+    }
+
     fn cell_id(&self, pos: Vec2) -> usize {
         pos.x + pos.y * self.size.x
     }
 
     /// Get mutable access to the style at the given cell, if any.
     ///
-    /// Returns `None` if the previous cell was double-wide.
+    /// Returns `None` if the previous cell was double-wide, or if `pos` is
+    /// outside of the buffer.
     pub fn style_at_mut(&mut self, pos: Vec2) -> Option<&mut ConcreteStyle> {
+        if !pos.strictly_lt(self.size) {
+            return None;
+        }
+
         let id = self.cell_id(pos);
+        self.mark_damaged(Rect::from_point(pos));
+        self.drop_graphics_at(pos);
         self.active_buffer[id].as_mut().map(|cell| &mut cell.style)
     }
 
@@ -394,6 +840,8 @@ impl PrintBuffer {
 
         let cell = &mut self.active_buffer[id].get_or_insert_with(Default::default);
         cell.set(style, grapheme, width);
+        self.mark_damaged(Rect::from_size(pos, (width.as_usize(), 1)));
+        self.drop_graphics_at(pos);
 
         // If this is a double-wide grapheme, mark the next cell as blocked.
         for dx in 1..width.as_usize() {
@@ -410,6 +858,13 @@ impl PrintBuffer {
     /// * Assumes the backend was representing `self.frozen_buffer`.
     /// * Ensures the backend now represents `self.active_buffer`.
     /// * Try to minimize the commands sent to the backend to achieve that.
+    /// * Consecutive cells are printed together, and short runs of unchanged cells are re-printed
+    ///   rather than moving the cursor over them.
+    /// * Only looks at the area written to since the last flush (see [`PrintBuffer::damage`]),
+    ///   unless the backend is not persistent.
+    /// * Reports the bounding box of the cells it printed with `backend.flush_region()`.
+    /// * Sends the images drawn with [`PrintBuffer::print_graphics`] after the cells,
+    ///   re-printing the cells below images that were removed.
     ///
     /// Afterwards, replace `self.frozen_buffer` with `self.active_buffer`.
     /// `self.active_buffer` should not be affected by this call.
@@ -420,25 +875,76 @@ impl PrintBuffer {
     ///
     /// (Successive calls should do nothing.)
     pub fn flush(&mut self, backend: &dyn Backend) {
-        let terminal_width = self.size.x;
-
         let persistent = backend.is_persistent();
 
-        let mut current_pos = Vec2::zero();
-        backend.move_to(current_pos);
+        if !persistent {
+            // The backend forgets everything, so send it the entire screen.
+            self.mark_all_damaged();
+        }
 
-        for (i, (active, frozen)) in self
-            .active_buffer
+        // Cells below removed images must be printed again, even if they did not change.
+        let stale: Vec<Rect> = self
+            .frozen_graphics
             .iter()
-            .zip(self.frozen_buffer.iter())
-            .enumerate()
-        {
-            if persistent && active == frozen {
-                // TODO (optim): it may be pricier to omit printing a letter but to then "move to" the
-                // cell to the right. So there should be a price N for the jump, and wait until we see
-                // N bytes without changes to actually jump. If it changes before that, flush the
-                // unchanged bytes rather than the jump.
+            .filter(|graphics| !self.graphics.contains(graphics))
+            .map(|graphics| graphics.area)
+            .collect();
+        for &area in &stale {
+            self.mark_damaged(area);
+        }
+
+        let changed = self.flush_cells(backend, persistent, &stale);
+        let changed = match (changed, self.flush_graphics(backend, persistent)) {
+            (Some(changed), Some(graphics)) => Some(changed.union(graphics)),
+            (changed, graphics) => changed.or(graphics),
+        };
+
+        if let Some(changed) = changed {
+            backend.flush_region(changed);
+        }
+    }
+
+    // Prints the damaged cells, returning the area actually printed.
+    fn flush_cells(
+        &mut self,
+        backend: &dyn Backend,
+        persistent: bool,
+        stale: &[Rect],
+    ) -> Option<Rect> {
+        let full = Rect::from_size(Vec2::zero(), self.size);
+        let damage = self
+            .damage
+            .take()
+            .and_then(|damage| damage.intersection(full))?;
+
+        let terminal_width = self.size.x;
 
+        // Downgraded styles, taken out to be used while going through the cells.
+        let mut downgraded_styles = self.downgraded_styles.take();
+
+        // Cursor position in the backend, once we moved it.
+        let mut current_pos: Option<Vec2> = None;
+
+        // Text waiting to be printed at `current_pos`, all with `self.current_style`.
+        //
+        // Consecutive cells are sent together in a single `print()` call.
+        let mut pending = String::new();
+
+        // Bounding box of the cells actually sent to the backend.
+        let mut changed: Option<Rect> = None;
+
+        let cells = (damage.top()..=damage.bottom())
+            .flat_map(|y| (damage.left()..=damage.right()).map(move |x| x + y * terminal_width));
+
+        for i in cells {
+            let active = &self.active_buffer[i];
+            let frozen = &self.frozen_buffer[i];
+
+            let x = i % terminal_width;
+            let y = i / terminal_width;
+            let pos = Vec2::new(x, y);
+
+            if persistent && active == frozen && !stale.iter().any(|area| area.contains(pos)) {
                 // Let's not change this cell.
                 continue;
             }
@@ -450,30 +956,151 @@ impl PrintBuffer {
                 continue;
             };
 
-            let x = i % terminal_width;
-            let y = i / terminal_width;
-
             // Should we move?
-            if current_pos != (x, y) {
-                current_pos = Vec2::new(x, y);
-                backend.move_to(current_pos);
+            if current_pos != Some(pos) {
+                // Re-printing a few unchanged cells is cheaper than moving the cursor.
+                let bridge = current_pos
+                    .and_then(|current_pos| self.bridge(current_pos, pos, &mut downgraded_styles));
+                match bridge {
+                    Some(bridge) => pending.push_str(&bridge),
+                    None => {
+                        print_pending(&mut pending, backend);
+                        backend.move_to(pos);
+                    }
+                }
             }
 
+            // This is the style the backend will actually use.
+            let style = downgrade(self.color_downgrade, &mut downgraded_styles, *style);
+
             // Make sure we have the correct style
-            // eprintln!("Applying {style:?} over {:?} for {text} @ {x}:{y}", self.current_style);
-            apply_diff(&self.current_style, style, backend);
-            self.current_style = *style;
+            if self.current_style != style {
+                print_pending(&mut pending, backend);
+                // eprintln!("Applying {style:?} over {:?} for {text} @ {x}:{y}", self.current_style);
+                apply_diff(&self.current_style, &style, backend);
+                self.current_style = style;
+            }
+
+            pending.push_str(text);
 
-            backend.print(text);
+            let cell = Rect::from_size((x, y), (width.as_usize(), 1));
+            changed = Some(changed.map_or(cell, |changed| changed.union(cell)));
 
-            current_pos.x += width.as_usize();
+            current_pos = Some(pos + (width.as_usize(), 0));
 
             // Assume we never wrap over?
         }
 
+        print_pending(&mut pending, backend);
+        self.downgraded_styles = downgraded_styles;
+
         // Keep the active buffer the same, because why not?
         // We could also flush it to Nones?
-        self.frozen_buffer.clone_from_slice(&self.active_buffer);
+        for y in damage.top()..=damage.bottom() {
+            let row = y * terminal_width;
+            let cells = row + damage.left()..=row + damage.right();
+            self.frozen_buffer[cells.clone()].clone_from_slice(&self.active_buffer[cells]);
+        }
+
+        changed
+    }
+
+    // Sends the new images, returning the area they cover.
+    fn flush_graphics(&mut self, backend: &dyn Backend, persistent: bool) -> Option<Rect> {
+        if persistent && self.graphics == self.frozen_graphics {
+            return None;
+        }
+
+        // Some terminals keep images when text is printed over: start from scratch.
+        let clear = self
+            .graphics_protocol
+            .and_then(GraphicsProtocol::clear_sequence);
+        if let Some(clear) = clear {
+            backend.print_raw(clear);
+        }
+
+        let mut changed: Option<Rect> = None;
+        for graphics in &self.graphics {
+            if persistent && clear.is_none() && self.frozen_graphics.contains(graphics) {
+                continue;
+            }
+
+            backend.move_to(graphics.area.top_left());
+            backend.print_raw(&graphics.data);
+            changed = Some(changed.map_or(graphics.area, |changed| changed.union(graphics.area)));
+        }
+
+        self.frozen_graphics.clone_from(&self.graphics);
+
+        changed
+    }
+
+    /// Returns the text to print to go from `from` to `to`.
+    ///
+    /// Returns `None` if moving the cursor is better: `to` is too far or on another row,
+    /// or the cells in between are not all printable with the current style.
+    ///
+    /// `downgraded_styles` caches the styles after color downgrade.
+    fn bridge(
+        &self,
+        from: Vec2,
+        to: Vec2,
+        downgraded_styles: &mut Option<HashMap<ConcreteStyle, ConcreteStyle>>,
+    ) -> Option<String> {
+        if from.y != to.y || to.x < from.x || to.x - from.x > MAX_BRIDGE_WIDTH {
+            return None;
+        }
+
+        let row = from.y * self.size.x;
+        let mut text = String::new();
+        let mut pos = from.x;
+        while pos < to.x {
+            match &self.active_buffer[row + pos] {
+                Some(cell)
+                    if downgrade(self.color_downgrade, downgraded_styles, cell.style)
+                        == self.current_style =>
+                {
+                    text.push_str(&cell.text);
+                    pos += cell.width.as_usize();
+                }
+                _ => return None,
+            }
+        }
+
+        // A double-width cell could overshoot the target.
+        if pos == to.x {
+            Some(text)
+        } else {
+            None
+        }
+    }
+}
+
+// Maximum number of unchanged cells re-printed to avoid moving the cursor.
+const MAX_BRIDGE_WIDTH: usize = 4;
+
+// Returns the style the backend will actually use, caching downgraded styles.
+fn downgrade(
+    policy: ColorDowngrade,
+    cache: &mut Option<HashMap<ConcreteStyle, ConcreteStyle>>,
+    style: ConcreteStyle,
+) -> ConcreteStyle {
+    match policy {
+        ColorDowngrade::Auto | ColorDowngrade::TrueColor => style,
+        policy => {
+            let cache = cache.get_or_insert_with(HashMap::new);
+            if cache.len() >= MAX_DOWNGRADED_STYLES {
+                cache.clear();
+            }
+            *cache.entry(style).or_insert_with(|| policy.style(style))
+        }
+    }
+}
+
+fn print_pending(pending: &mut String, backend: &dyn Backend) {
+    if !pending.is_empty() {
+        backend.print(pending);
+        pending.clear();
     }
 }
 
@@ -483,7 +1110,20 @@ fn apply_diff(old: &ConcreteStyle, new: &ConcreteStyle, backend: &dyn Backend) {
         backend.set_color(new.color);
     }
 
-    // Check the diff between two effect sets:
+    if old.underline != new.underline {
+        backend.set_underline_color(new.underline);
+    }
+
+    // Check the diff between two effect sets.
+    // Effects are unset first: some share a code to disable them
+    // (like the underline styles), which would undo newly set effects.
+    // - Effects in old but not in new
+    for effect in old.effects.iter() {
+        if new.effects.contains(effect) {
+            continue;
+        }
+        backend.unset_effect(effect);
+    }
     // - Effects in new but not in old
     for effect in new.effects.iter() {
         if old.effects.contains(effect) {
@@ -491,11 +1131,330 @@ fn apply_diff(old: &ConcreteStyle, new: &ConcreteStyle, backend: &dyn Backend) {
         }
         backend.set_effect(effect);
     }
-    // - Effects in old but not in new
-    for effect in old.effects.iter() {
-        if new.effects.contains(effect) {
-            continue;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row_text(buffer: &PrintBuffer, y: usize) -> String {
+        (0..buffer.size().x)
+            .filter_map(|x| buffer.cell_text(Vec2::new(x, y)))
+            .collect()
+    }
+
+    #[test]
+    fn glyph_fallbacks_keep_width() {
+        let mut buffer = PrintBuffer::new();
+        buffer.resize(Vec2::new(8, 1));
+        buffer.fill(" ", ConcreteStyle::terminal_default());
+
+        let fallbacks = GlyphFallbacks::ascii().with("界", "W").with("a", "xyz");
+        buffer.set_glyph_fallbacks(Some(Arc::new(fallbacks)));
+
+        buffer.print_at(Vec2::zero(), "┌界a─┐", ConcreteStyle::terminal_default());
+
+        // "界" is double-width: its replacement is padded.
+        // "a" is single-width: its replacement is truncated.
+        assert_eq!(row_text(&buffer, 0), "+W x-+  ");
+    }
+
+    #[test]
+    fn styled_string() {
+        let mut buffer = PrintBuffer::new();
+        buffer.resize(Vec2::new(4, 2));
+
+        let style = ConcreteStyle {
+            effects: crate::style::Effect::Bold.into(),
+            ..ConcreteStyle::terminal_default()
+        };
+        buffer.print_at(Vec2::zero(), "a界", style);
+        buffer.print_at(Vec2::new(1, 1), "b", ConcreteStyle::terminal_default());
+
+        let styled = buffer.to_styled_string();
+        assert_eq!(styled.source(), "a界 \n b  ");
+
+        let spans: Vec<_> = styled.spans().map(|span| span.content).collect();
+        assert_eq!(spans, ["a界", " \n ", "b", "  "]);
+    }
+
+    // Persistent backend recording what gets printed.
+    #[derive(Default)]
+    struct Recorder {
+        printed: parking_lot::Mutex<String>,
+        calls: parking_lot::Mutex<Vec<String>>,
+        regions: parking_lot::Mutex<Vec<Rect>>,
+        colors: parking_lot::Mutex<Vec<crate::style::ColorPair>>,
+        effects: parking_lot::Mutex<Vec<String>>,
+    }
+
+    impl Backend for Recorder {
+        fn poll_event(&mut self) -> Option<crate::event::Event> {
+            None
         }
-        backend.unset_effect(effect);
+        fn set_title(&mut self, _: String) {}
+        fn refresh(&mut self) {}
+        fn has_colors(&self) -> bool {
+            false
+        }
+        fn screen_size(&self) -> Vec2 {
+            Vec2::new(4, 2)
+        }
+        fn move_to(&self, pos: Vec2) {
+            self.calls.lock().push(format!("move {}:{}", pos.x, pos.y));
+        }
+        fn print(&self, text: &str) {
+            self.printed.lock().push_str(text);
+            self.calls.lock().push(format!("print {text}"));
+        }
+        fn clear(&self, _: crate::style::Color) {}
+        fn set_color(&self, colors: crate::style::ColorPair) -> crate::style::ColorPair {
+            self.colors.lock().push(colors);
+            colors
+        }
+        fn set_effect(&self, effect: crate::style::Effect) {
+            self.effects.lock().push(format!("+{effect:?}"));
+        }
+        fn unset_effect(&self, effect: crate::style::Effect) {
+            self.effects.lock().push(format!("-{effect:?}"));
+        }
+        fn set_underline_color(&self, color: Option<crate::style::Color>) {
+            self.effects.lock().push(format!("underline {color:?}"));
+        }
+        fn is_persistent(&self) -> bool {
+            true
+        }
+        fn flush_region(&self, region: Rect) {
+            self.regions.lock().push(region);
+        }
+        fn print_raw(&self, data: &str) {
+            self.calls.lock().push(format!("raw {data}"));
+        }
+    }
+
+    #[test]
+    fn damage() {
+        let backend = Recorder::default();
+        let style = ConcreteStyle::terminal_default();
+
+        let mut buffer = PrintBuffer::new();
+        buffer.resize(Vec2::new(4, 2));
+        buffer.fill(".", style);
+        assert_eq!(buffer.damage(), Some(Rect::from_size((0, 0), (4, 2))));
+
+        buffer.flush(&backend);
+        assert_eq!(buffer.damage(), None);
+        assert_eq!(backend.printed.lock().len(), 8);
+
+        // Only the damaged area is compared, and only changes are printed.
+        backend.printed.lock().clear();
+        buffer.print_at(Vec2::new(1, 1), "ab", style);
+        buffer.print_at(Vec2::new(0, 1), ".", style);
+        assert_eq!(buffer.damage(), Some(Rect::from_corners((0, 1), (2, 1))));
+
+        buffer.flush(&backend);
+        assert_eq!(*backend.printed.lock(), "ab");
+
+        // Nothing changed since last time.
+        backend.printed.lock().clear();
+        buffer.flush(&backend);
+        assert!(backend.printed.lock().is_empty());
+    }
+
+    #[test]
+    fn flush_region() {
+        let backend = Recorder::default();
+        let style = ConcreteStyle::terminal_default();
+
+        let mut buffer = PrintBuffer::new();
+        buffer.resize(Vec2::new(4, 2));
+        buffer.fill(".", style);
+        buffer.flush(&backend);
+        assert_eq!(*backend.regions.lock(), [Rect::from_size((0, 0), (4, 2))]);
+
+        // Only the cells that actually changed are reported.
+        backend.regions.lock().clear();
+        buffer.print_at(Vec2::new(0, 0), ".", style);
+        buffer.print_at(Vec2::new(2, 1), "界", style);
+        buffer.flush(&backend);
+        assert_eq!(*backend.regions.lock(), [Rect::from_size((2, 1), (2, 1))]);
+
+        // Nothing is reported when nothing changed.
+        backend.regions.lock().clear();
+        buffer.print_at(Vec2::new(0, 0), ".", style);
+        buffer.flush(&backend);
+        assert!(backend.regions.lock().is_empty());
+    }
+
+    #[test]
+    fn batched_output() {
+        let backend = Recorder::default();
+        let style = ConcreteStyle::terminal_default();
+
+        let mut buffer = PrintBuffer::new();
+        buffer.resize(Vec2::new(12, 2));
+        buffer.fill(".", style);
+        buffer.flush(&backend);
+        assert_eq!(
+            *backend.calls.lock(),
+            [
+                "move 0:0",
+                "print ............",
+                "move 0:1",
+                "print ............"
+            ]
+        );
+
+        // Small gaps are re-printed, larger ones are jumped over.
+        backend.calls.lock().clear();
+        buffer.print_at(Vec2::new(0, 0), "ab", style);
+        buffer.print_at(Vec2::new(4, 0), "c", style);
+        buffer.print_at(Vec2::new(10, 0), "d", style);
+        buffer.flush(&backend);
+        assert_eq!(
+            *backend.calls.lock(),
+            ["move 0:0", "print ab..c", "move 10:0", "print d"]
+        );
+
+        // Style changes split the output.
+        backend.calls.lock().clear();
+        let bold = ConcreteStyle {
+            effects: crate::style::Effect::Bold.into(),
+            ..style
+        };
+        buffer.print_at(Vec2::new(0, 1), "e", bold);
+        buffer.print_at(Vec2::new(1, 1), "f", style);
+        buffer.flush(&backend);
+        assert_eq!(*backend.calls.lock(), ["move 0:1", "print e", "print f"]);
+    }
+
+    #[test]
+    fn flush_graphics() {
+        let backend = Recorder::default();
+        let style = ConcreteStyle::terminal_default();
+
+        let mut buffer = PrintBuffer::new();
+        buffer.resize(Vec2::new(4, 3));
+        buffer.fill(".", style);
+        buffer.flush(&backend);
+        backend.calls.lock().clear();
+
+        // Images are sent after the cells below them.
+        buffer.set_graphics_support(Some(GraphicsProtocol::Sixel), None);
+        buffer.print_at(Vec2::zero(), "ab", style);
+        buffer.print_graphics(Rect::from_size((0, 0), (2, 1)), "IMG".into());
+        buffer.flush(&backend);
+        assert_eq!(
+            *backend.calls.lock(),
+            ["move 0:0", "print ab", "move 0:0", "raw IMG"]
+        );
+
+        // Unchanged images are not sent again.
+        backend.calls.lock().clear();
+        buffer.flush(&backend);
+        assert!(backend.calls.lock().is_empty());
+
+        // Printing over an image removes it, so the cells below are printed again.
+        buffer.print_at(Vec2::new(1, 0), "x", style);
+        buffer.flush(&backend);
+        assert_eq!(*backend.calls.lock(), ["move 0:0", "print ax"]);
+
+        // Kitty images are cleared before drawing new ones.
+        backend.calls.lock().clear();
+        buffer.set_graphics_support(Some(GraphicsProtocol::Kitty), None);
+        buffer.print_graphics(Rect::from_size((2, 1), (2, 1)), "K".into());
+        buffer.flush(&backend);
+        assert_eq!(
+            *backend.calls.lock(),
+            ["raw \x1b_Ga=d,d=A,q=2\x1b\\", "move 2:1", "raw K"]
+        );
+    }
+
+    #[test]
+    fn underlines() {
+        use crate::style::{BaseColor, Effect};
+
+        let backend = Recorder::default();
+        let underline = ConcreteStyle {
+            effects: Effect::Underline.into(),
+            ..ConcreteStyle::terminal_default()
+        };
+        let curly = ConcreteStyle {
+            effects: Effect::CurlyUnderline.into(),
+            underline: Some(BaseColor::Red.dark()),
+            ..ConcreteStyle::terminal_default()
+        };
+
+        let mut buffer = PrintBuffer::new();
+        buffer.resize(Vec2::new(2, 1));
+        buffer.print_at(Vec2::zero(), "a", underline);
+        buffer.print_at(Vec2::new(1, 0), "b", curly);
+        buffer.flush(&backend);
+
+        // The previous underline style is removed before setting the new one.
+        assert_eq!(
+            *backend.effects.lock(),
+            [
+                "+Underline",
+                "underline Some(Dark(Red))",
+                "-Underline",
+                "+CurlyUnderline",
+            ]
+        );
+    }
+
+    #[test]
+    fn color_downgrade() {
+        use crate::style::{BaseColor, Color, ColorPair};
+
+        let backend = Recorder::default();
+        let orange = ConcreteStyle::from(ColorPair {
+            front: Color::Rgb(255, 100, 0),
+            back: Color::TerminalDefault,
+        });
+
+        let mut buffer = PrintBuffer::new();
+        buffer.resize(Vec2::new(4, 2));
+        buffer.print_at(Vec2::zero(), "ab", orange);
+        buffer.set_color_downgrade(ColorDowngrade::Colors16);
+        buffer.flush(&backend);
+        assert_eq!(
+            *backend.colors.lock(),
+            [ColorPair {
+                front: Color::Light(BaseColor::Red),
+                back: Color::TerminalDefault,
+            }]
+        );
+
+        // Changing the policy prints everything again.
+        backend.colors.lock().clear();
+        buffer.set_color_downgrade(ColorDowngrade::TrueColor);
+        buffer.flush(&backend);
+        assert_eq!(*backend.colors.lock(), [orange.color]);
+        assert_eq!(*backend.printed.lock(), "abab");
+    }
+
+    #[test]
+    fn downgraded_bridge() {
+        use crate::style::{Color, ColorPair};
+
+        let backend = Recorder::default();
+        let orange = ConcreteStyle::from(ColorPair {
+            front: Color::Rgb(255, 100, 0),
+            back: Color::TerminalDefault,
+        });
+
+        let mut buffer = PrintBuffer::new();
+        buffer.resize(Vec2::new(8, 1));
+        buffer.set_color_downgrade(ColorDowngrade::Colors16);
+        buffer.fill(".", orange);
+        buffer.flush(&backend);
+
+        // The gap is printed again with the downgraded style.
+        backend.calls.lock().clear();
+        buffer.print_at(Vec2::new(0, 0), "a", orange);
+        buffer.print_at(Vec2::new(3, 0), "b", orange);
+        buffer.flush(&backend);
+        assert_eq!(*backend.calls.lock(), ["move 0:0", "print a..b"]);
     }
 }
@@ -51,6 +51,34 @@
 //! (`#[blueprint]`). When a `Context` is created, they are automatically gathered from all
 //! dependencies - so third party crates can define blueprints too.
 //!
+//! Composite widgets can also be defined in config, and registered on a `Context` at runtime
+//! with `Context::register_widgets`. Callers fill their named slots with child view configs.
+//!
+//! ## Wrappers
+//!
+//! Any view config can list wrappers to apply around the view, under `with` (or its alias
+//! `wrappers`). They are applied in order, like chained calls to `.wrap_with()` in Rust:
+//!
+//! ```yaml
+//! EditView:
+//!     wrappers:
+//!         - on_event:
+//!             ctrl-s: $save
+//!             cancel: $Cursive.quit
+//!         - enableable:
+//!             enabled: false
+//!         - hideable
+//!         - padding: 1
+//!         - shadow
+//!         - fixed_width: 20
+//! ```
+//!
+//! ## Schema
+//!
+//! [`schema()`] returns a JSON Schema describing all registered blueprints and wrappers, with
+//! the config keys of the ones defined with `#[blueprint]`. Editors can use it to complete and
+//! validate layouts.
+//!
 //! ## Resolving things
 //!
 //! Blueprints will need to parse various types from the config to build their views - strings,
@@ -67,8 +95,10 @@
 #![cfg_attr(not(feature = "builder"), allow(unused))]
 
 mod resolvable;
+mod schema;
 
 pub use self::resolvable::{NoConfig, Resolvable};
+pub use self::schema::schema;
 
 use crate::views::BoxedView;
 
@@ -653,6 +683,102 @@ impl Context {
         }
     }
 
+    /// Register a composite widget defined in config _for this context only_.
+    ///
+    /// Once registered, `name` can be used like any other blueprint.
+    ///
+    /// `definition` is an object with the following fields:
+    ///
+    /// * `view`: the template for the widget. It can use the caller's parameters and slots as
+    ///   variables (`$title`, `$content`, ...).
+    /// * `slots` (optional): names of the child views callers must provide. Each slot is a view
+    ///   config, built where the template uses it.
+    /// * `defaults` (optional): default values for parameters and slots.
+    ///
+    /// For example, with this definition:
+    ///
+    /// ```yaml
+    /// slots: [content]
+    /// defaults:
+    ///     title: Untitled
+    /// view:
+    ///     Panel:
+    ///         title: $title
+    ///         view: $content
+    /// ```
+    ///
+    /// The widget could be used like this:
+    ///
+    /// ```yaml
+    /// Card:
+    ///     content:
+    ///         TextView: Hello
+    /// ```
+    pub fn register_widget(
+        &mut self,
+        name: impl Into<String>,
+        definition: &Config,
+    ) -> Result<(), Error> {
+        let template = definition
+            .get("view")
+            .cloned()
+            .ok_or_else(|| Error::invalid_config("Expected `view` field", definition))?;
+
+        let slots: Vec<String> = match definition.get("slots") {
+            None => Vec::new(),
+            Some(slots) => serde_json::from_value(slots.clone())
+                .map_err(|_| Error::invalid_config("Expected list of slot names", slots))?,
+        };
+
+        let defaults: Object = match definition.get("defaults") {
+            None => Object::new(),
+            Some(Config::Object(defaults)) => defaults.clone(),
+            Some(defaults) => return Err(Error::invalid_config("Expected object", defaults)),
+        };
+
+        self.register_blueprint(name, move |config, context| {
+            let mut params = defaults.clone();
+            match config {
+                Config::Object(config) => params.extend(
+                    config
+                        .iter()
+                        .map(|(key, value)| (key.clone(), value.clone())),
+                ),
+                Config::Null => (),
+                _ => return Err(Error::invalid_config("Expected object", config)),
+            }
+
+            if let Some(slot) = slots.iter().find(|slot| !params.contains_key(*slot)) {
+                return Err(Error::invalid_config(
+                    format!("Missing slot `{slot}`"),
+                    config,
+                ));
+            }
+
+            context.build_template(&Config::Object(params), &template)
+        });
+
+        Ok(())
+    }
+
+    /// Register a set of composite widgets _for this context only_.
+    ///
+    /// `definitions` should be an object mapping widget names to definitions.
+    ///
+    /// See [`Context::register_widget`] for the format of each definition.
+    pub fn register_widgets(&mut self, definitions: &Config) -> Result<(), Error> {
+        let definitions = definitions
+            .as_object()
+            .ok_or_else(|| Error::invalid_config("Expected object", definitions))?;
+
+        for (name, definition) in definitions {
+            self.register_widget(name, definition)
+                .map_err(|e| Error::BlueprintFailed(name.clone(), Box::new(e)))?;
+        }
+
+        Ok(())
+    }
+
     /*
     /// Loads a variable of the given type.
     ///
@@ -758,16 +884,20 @@ impl Context {
     }
 
     fn get_wrappers(&self, config: &Config) -> Result<Vec<Wrapper>, Error> {
-        fn get_with(config: &Config) -> Option<&Vec<Config>> {
-            config.as_object()?.get("with")?.as_array()
+        fn get_list<'a>(config: &'a Config, key: &str) -> &'a [Config] {
+            config
+                .as_object()
+                .and_then(|config| config.get(key))
+                .and_then(Config::as_array)
+                .map_or(&[], Vec::as_slice)
         }
 
-        let with = match get_with(config) {
-            Some(with) => with,
-            None => return Ok(Vec::new()),
-        };
-
-        with.iter().map(|with| self.build_wrapper(with)).collect()
+        // `wrappers` is an alias for `with`; both can be used together.
+        get_list(config, "with")
+            .iter()
+            .chain(get_list(config, "wrappers"))
+            .map(|with| self.build_wrapper(with))
+            .collect()
     }
 
     /// Build a new view from the given config.
@@ -961,9 +1091,51 @@ impl WrapperBlueprint {
     }
 }
 
+/// Describes the config accepted by a blueprint.
+///
+/// This is registered by `#[blueprint]` next to the blueprint itself, and used
+/// to generate a [`schema`]. Manual blueprints don't have one.
+pub struct BlueprintSchema {
+    /// Name of the blueprint this describes.
+    pub name: &'static str,
+
+    /// Keys accepted in the config object.
+    pub fields: &'static [BlueprintField],
+
+    /// JSON types accepted in place of an object, like `"string"` for `TextView`.
+    ///
+    /// An empty string means any value.
+    pub shorthands: &'static [&'static str],
+
+    /// Whether the blueprint can be used with just its name, without config.
+    pub allows_null: bool,
+}
+
+/// Describes a key in the config of a blueprint.
+pub struct BlueprintField {
+    /// Name of the key in the config.
+    pub name: &'static str,
+
+    /// Rust type the value is resolved as, or `"callback"`.
+    pub rust_type: &'static str,
+
+    /// JSON type expected for the value: `"string"`, `"integer"`, `"view"`, ...
+    ///
+    /// An empty string means any value.
+    pub json_type: &'static str,
+
+    /// Whether the config must include this key.
+    pub required: bool,
+
+    /// Documentation for this key, if any.
+    pub description: &'static str,
+}
+
 #[cfg(feature = "builder")]
 inventory::collect!(Blueprint);
 #[cfg(feature = "builder")]
+inventory::collect!(BlueprintSchema);
+#[cfg(feature = "builder")]
 inventory::collect!(CallbackBlueprint);
 #[cfg(feature = "builder")]
 inventory::collect!(WrapperBlueprint);
@@ -1092,6 +1264,12 @@ fn_blueprint!("cursup", |config, context| {
     Ok(crate::utils::markup::cursup::parse(text))
 });
 
+fn_blueprint!("html", |config, context| {
+    let text: String = context.resolve(config)?;
+
+    Ok(crate::utils::markup::html::parse(text))
+});
+
 #[cfg(feature = "builder")]
 #[cfg(test)]
 mod tests {
@@ -1150,4 +1328,134 @@ mod tests {
 
         assert_eq!(content.source(), foo);
     }
+
+    #[test]
+    fn test_widgets() {
+        use crate::view::Finder;
+
+        let definitions = r#"
+            Card:
+                slots: [content]
+                defaults:
+                    title: Untitled
+                view:
+                    LinearLayout:
+                        children:
+                            - TextView:
+                                content: $title
+                                with:
+                                    - name: title
+                            - $content
+        "#;
+
+        let definitions: crate::builder::Config = serde_yaml::from_str(definitions).unwrap();
+
+        let mut context = crate::builder::Context::new();
+        context.register_widgets(&definitions).unwrap();
+        context.store("greeting", "Hello".to_string());
+
+        let config = r#"
+            LinearLayout:
+                children:
+                    - Card:
+                        title: First
+                        content:
+                            TextView:
+                                content: $greeting
+                                with:
+                                    - name: text
+                    - Card:
+                        content: DummyView
+        "#;
+        let config: crate::builder::Config = serde_yaml::from_str(config).unwrap();
+
+        let mut res = context.build(&config).unwrap();
+
+        // Slots are built with the caller's variables.
+        let content = res
+            .call_on_name("text", |v: &mut crate::views::TextView| v.get_content())
+            .unwrap();
+        assert_eq!(content.source(), "Hello");
+
+        let mut titles = Vec::new();
+        res.call_on_all(
+            &crate::view::Selector::Name("title"),
+            |v: &mut crate::views::TextView| titles.push(v.get_content().source().to_string()),
+        );
+        assert_eq!(titles, ["First", "Untitled"]);
+
+        // Slots are required.
+        let config: crate::builder::Config = serde_yaml::from_str("Card: {}").unwrap();
+        assert!(context.build(&config).is_err());
+    }
+
+    #[test]
+    fn test_wrappers() {
+        use crate::event::Event;
+        use crate::view::{Finder, View};
+        use crate::views::{EnableableView, HideableView, OnEventView};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let config = r#"
+            TextView:
+                content: foo
+                with:
+                    - name: text
+                wrappers:
+                    - on_event:
+                        ctrl-s: $save
+                        submit: $save
+                    - hideable
+                    - enableable:
+                        enabled: false
+        "#;
+        let config: crate::builder::Config = serde_yaml::from_str(config).unwrap();
+
+        let count = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&count);
+        let save: Arc<dyn Fn(&mut crate::Cursive) + Send + Sync> = Arc::new(move |_| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+
+        let mut context = crate::builder::Context::new();
+        context.store("save", save);
+
+        let mut res = context.build(&config).unwrap();
+        assert!(res
+            .call_on_name("text", |_: &mut crate::views::TextView| ())
+            .is_some());
+
+        // Wrappers are applied in order, so the last one is outermost.
+        let enableable = res
+            .downcast_mut::<EnableableView<crate::views::BoxedView>>()
+            .unwrap();
+        assert!(!enableable.is_enabled());
+        enableable.enable();
+
+        let hideable = enableable
+            .get_inner_mut()
+            .downcast_mut::<HideableView<crate::views::BoxedView>>()
+            .unwrap();
+        let on_event = hideable
+            .get_inner_mut()
+            .downcast_mut::<OnEventView<crate::views::BoxedView>>()
+            .unwrap();
+
+        // Both the event and the keymap action trigger the callback.
+        let mut siv = crate::Cursive::new();
+        for event in [Event::CtrlChar('s'), Event::Key(crate::event::Key::Enter)] {
+            on_event.on_event(event).process(&mut siv);
+        }
+        assert_eq!(count.load(Ordering::Relaxed), 2);
+
+        let config = r#"
+            OnEventView:
+                view: DummyView
+                events:
+                    not-an-action: $save
+        "#;
+        let config: crate::builder::Config = serde_yaml::from_str(config).unwrap();
+        assert!(context.build(&config).is_err());
+    }
 }
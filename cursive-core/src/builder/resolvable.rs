@@ -266,6 +266,34 @@ impl Resolvable for crate::style::Palette {
     }
 }
 
+impl Resolvable for crate::style::PalettePatch {
+    fn from_config(config: &Config, context: &Context) -> Result<Self, Error> {
+        let mut patch = Self::new();
+
+        let config = config
+            .as_object()
+            .ok_or_else(|| Error::invalid_config("Expected object", config))?;
+
+        for (key, value) in config {
+            if key == "styles" {
+                let styles = value
+                    .as_object()
+                    .ok_or_else(|| Error::invalid_config("Expected object", value))?;
+                for (key, value) in styles {
+                    let key: crate::style::PaletteStyle = key.parse().map_err(|_| {
+                        Error::invalid_config("Expected valid palette style", value)
+                    })?;
+                    patch.set_style(key, context.resolve::<crate::style::Style>(value)?);
+                }
+            } else {
+                patch.set_color_by_name(key, context.resolve(value)?);
+            }
+        }
+
+        Ok(patch)
+    }
+}
+
 impl Resolvable for crate::style::BorderStyle {
     fn from_config(config: &Config, context: &Context) -> Result<Self, Error> {
         let borders: String = context.resolve(config)?;
@@ -318,8 +346,15 @@ impl Resolvable for crate::style::Style {
                     // Option A: explicit effects and color
                     let effects = context.resolve(&config["effects"])?;
                     let color = context.resolve(&config["color"])?;
+                    let underline = context
+                        .resolve::<Option<crate::style::ColorType>>(&config["underline"])?
+                        .unwrap_or_default();
 
-                    Ok(crate::style::Style { effects, color })
+                    Ok(crate::style::Style {
+                        effects,
+                        color,
+                        underline,
+                    })
                 },
                 |config, context| {
                     // Option B: just a color style
@@ -348,10 +383,18 @@ impl Resolvable for crate::theme::Theme {
             theme.borders = borders;
         }
 
+        if let Some(ascii) = context.resolve(&config["ascii"])? {
+            theme.ascii = ascii;
+        }
+
         if let Some(palette) = context.resolve(&config["palette"])? {
             theme.palette = palette;
         }
 
+        if let Some(scrollbar) = context.resolve(&config["scrollbar"])? {
+            theme.scrollbar = scrollbar;
+        }
+
         Ok(theme)
     }
 }
@@ -794,6 +837,7 @@ impl Resolvable for crate::style::ConcreteStyle {
                     Ok(Self {
                         effects: ConcreteEffects::empty(),
                         color,
+                        underline: None,
                     })
                 },
                 |config, context| {
@@ -801,7 +845,12 @@ impl Resolvable for crate::style::ConcreteStyle {
                         .resolve::<Option<ConcreteEffects>>(&config["effects"])?
                         .unwrap_or_else(ConcreteEffects::empty);
                     let color = context.resolve(&config["color"])?;
-                    Ok(Self { effects, color })
+                    let underline = context.resolve(&config["underline"])?;
+                    Ok(Self {
+                        effects,
+                        color,
+                        underline,
+                    })
                 },
             ],
         )
@@ -983,6 +1032,153 @@ impl Resolvable for crate::view::ScrollStrategy {
     }
 }
 
+impl Resolvable for crate::views::InputMask {
+    fn from_config(config: &Config, context: &Context) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        resolve_from_str(config, context, |_| {
+            "Expected one of digits, numeric, hex_digits, alphanumeric"
+        })
+    }
+}
+
+impl Resolvable for crate::view::scroll::Easing {
+    fn from_config(config: &Config, context: &Context) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        resolve_from_str(config, context, |_| {
+            "Expected one of linear, ease_out, ease_in_out"
+        })
+    }
+}
+
+impl Resolvable for crate::view::scroll::ScrollStyle {
+    fn from_config(config: &Config, context: &Context) -> Result<Self, Error> {
+        let mut style = Self::default();
+
+        let glyphs = [
+            ("thumb", &mut style.thumb),
+            ("thumb_grabbed", &mut style.thumb_grabbed),
+            ("vertical_track", &mut style.vertical_track),
+            ("horizontal_track", &mut style.horizontal_track),
+            ("corner", &mut style.corner),
+        ];
+        for (key, glyph) in glyphs {
+            if let Some(value) = context.resolve(&config[key])? {
+                *glyph = value;
+            }
+        }
+
+        if let Some(thickness) = context.resolve::<Option<usize>>(&config["thickness"])? {
+            style.thickness = thickness.max(1);
+        }
+
+        let styles = [
+            ("thumb_style", &mut style.thumb_style),
+            ("thumb_inactive_style", &mut style.thumb_inactive_style),
+            ("track_style", &mut style.track_style),
+        ];
+        for (key, value) in styles {
+            if let Some(resolved) = context.resolve(&config[key])? {
+                *value = resolved;
+            }
+        }
+
+        // Delay in seconds.
+        if let Some(delay) = context.resolve::<Option<f64>>(&config["auto_hide"])? {
+            style.auto_hide = Some(std::time::Duration::from_secs_f64(delay.max(0.0)));
+        }
+
+        Ok(style)
+    }
+}
+
+impl Resolvable for crate::views::ProgressGlyphs {
+    fn from_config(config: &Config, context: &Context) -> Result<Self, Error> {
+        if let Config::Array(_) = config {
+            return context.resolve(config).map(Self::Custom);
+        }
+
+        resolve_from_str(config, context, |_| {
+            "Expected blocks, braille or a list of glyphs"
+        })
+    }
+}
+
+impl Resolvable for crate::views::SpinnerStyle {
+    fn from_config(config: &Config, context: &Context) -> Result<Self, Error> {
+        if let Config::Array(_) = config {
+            return context.resolve(config).map(Self::Custom);
+        }
+
+        resolve_from_str(config, context, |_| {
+            "Expected braille, dots, line or a list of frames"
+        })
+    }
+}
+
+impl Resolvable for crate::views::ChartGlyphs {
+    fn from_config(config: &Config, context: &Context) -> Result<Self, Error> {
+        resolve_from_str(config, context, |_| "Expected blocks or braille")
+    }
+}
+
+impl Resolvable for crate::views::SelectColumns {
+    fn from_config(config: &Config, context: &Context) -> Result<Self, Error> {
+        if let Ok(columns) = context.resolve(config) {
+            return Ok(Self::Fixed(columns));
+        }
+
+        resolve_from_str(config, context, |_| "Expected auto or a number of columns")
+    }
+}
+
+impl Resolvable for crate::view::scroll::IndicatorFormat {
+    fn from_config(config: &Config, context: &Context) -> Result<Self, Error> {
+        resolve_from_str(config, context, |_| "Expected percent or lines")
+    }
+}
+
+impl Resolvable for crate::view::scroll::ScrollIndicator {
+    fn from_config(config: &Config, context: &Context) -> Result<Self, Error> {
+        let mut indicator = Self::default();
+
+        if let Some(format) = context.resolve(&config["format"])? {
+            indicator.format = format;
+        }
+        if let Some(align) = context.resolve(&config["align"])? {
+            indicator.align = align;
+        }
+        if let Some(style) = context.resolve(&config["style"])? {
+            indicator.style = style;
+        }
+
+        // Delay in seconds, or `false` to always show the indicator.
+        match &config["auto_hide"] {
+            Config::Bool(false) => indicator.auto_hide = None,
+            value => {
+                if let Some(delay) = context.resolve::<Option<f64>>(value)? {
+                    indicator.auto_hide = Some(std::time::Duration::from_secs_f64(delay.max(0.0)));
+                }
+            }
+        }
+
+        Ok(indicator)
+    }
+}
+
+impl Resolvable for crate::view::scroll::ScrollKeys {
+    fn from_config(config: &Config, context: &Context) -> Result<Self, Error> {
+        Ok(Self {
+            half_page: context.resolve_or(&config["half_page"], false)?,
+            page_size: context.resolve(&config["page_size"])?,
+            marks: context.resolve_or(&config["marks"], false)?,
+        })
+    }
+}
+
 impl Resolvable for crate::view::Offset {
     fn from_config(config: &Config, context: &Context) -> Result<Self, Error> {
         if let Some("center" | "Center") = config.as_str() {
@@ -1050,6 +1246,33 @@ impl Resolvable for crate::view::SizeConstraint {
     }
 }
 
+impl Resolvable for crate::utils::lines::tabs::TabStops {
+    fn from_config(config: &Config, context: &Context) -> Result<Self, Error> {
+        // A single number is a fixed tab width.
+        if let Ok(width) = context.resolve(config) {
+            return Ok(Self::Fixed(width));
+        }
+
+        let config = config
+            .as_object()
+            .ok_or_else(|| Error::invalid_config("Expected a number or an object.", config))?;
+
+        let (key, value) = config
+            .iter()
+            .next()
+            .ok_or_else(|| Error::invalid_config("Expected non-empty object.", config))?;
+
+        match key.as_str() {
+            "Fixed" | "fixed" => Ok(Self::Fixed(context.resolve(value)?)),
+            "Elastic" | "elastic" => Ok(Self::Elastic(context.resolve(value)?)),
+            _ => Err(Error::invalid_config(
+                "Expected `fixed` or `elastic` key.",
+                config,
+            )),
+        }
+    }
+}
+
 impl Resolvable for crate::views::LayerPosition {
     fn from_config(config: &Config, context: &Context) -> Result<Self, Error>
     where
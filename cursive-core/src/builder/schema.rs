@@ -0,0 +1,201 @@
+use super::{BlueprintField, BlueprintSchema, Config, Context};
+use serde_json::{json, Map};
+use std::collections::BTreeSet;
+
+/// Returns a JSON Schema describing view configs.
+///
+/// All blueprints and wrappers registered with the macros are included, so
+/// editors can offer completion and validation for YAML or JSON layouts.
+/// Keys are described for blueprints defined with `#[blueprint]`; manual
+/// blueprints accept any config.
+///
+/// This is the same as `Context::new().schema()`.
+///
+/// # Examples
+///
+/// ```rust
+/// let schema = cursive_core::builder::schema();
+/// assert_eq!(schema["$ref"], "#/definitions/view");
+/// ```
+pub fn schema() -> Config {
+    Context::new().schema()
+}
+
+impl Context {
+    /// Returns a JSON Schema describing view configs for this context.
+    ///
+    /// Unlike [`schema()`], this includes blueprints and widgets registered at
+    /// runtime on this context; they accept any config.
+    pub fn schema(&self) -> Config {
+        let blueprints: BTreeSet<&String> = self.blueprints.keys().collect();
+        let wrappers: BTreeSet<&String> = self.blueprints.wrapper_keys().collect();
+
+        let mut properties = Map::new();
+        let mut names = Vec::new();
+        for &name in &blueprints {
+            let schema = find_schema(name);
+            if schema.map_or(true, |schema| schema.allows_null) {
+                names.push(json!(name));
+            }
+            properties.insert(name.clone(), blueprint_schema(schema));
+        }
+
+        json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "title": "Cursive view",
+            "$ref": "#/definitions/view",
+            "definitions": {
+                "variable": {
+                    "description": "Variable provided to the context.",
+                    "type": "string",
+                    "pattern": "^\\$",
+                },
+                "view": {
+                    "anyOf": [
+                        { "$ref": "#/definitions/variable" },
+                        { "enum": names },
+                        {
+                            "type": "object",
+                            "properties": properties,
+                            "additionalProperties": false,
+                            "minProperties": 1,
+                            "maxProperties": 1,
+                        },
+                    ],
+                },
+                "wrapper": {
+                    "anyOf": [
+                        { "enum": wrappers },
+                        {
+                            "type": "object",
+                            "propertyNames": { "enum": wrappers },
+                            "minProperties": 1,
+                            "maxProperties": 1,
+                        },
+                    ],
+                },
+                "wrappers": {
+                    "type": "array",
+                    "items": { "$ref": "#/definitions/wrapper" },
+                },
+            },
+        })
+    }
+}
+
+// Returns the schema registered for this blueprint, if any.
+fn find_schema(name: &str) -> Option<&'static BlueprintSchema> {
+    #[cfg(feature = "builder")]
+    return inventory::iter::<BlueprintSchema>().find(|schema| schema.name == name);
+
+    #[cfg(not(feature = "builder"))]
+    return {
+        let _ = name;
+        None
+    };
+}
+
+// Returns the schema for the config given to a blueprint.
+fn blueprint_schema(schema: Option<&BlueprintSchema>) -> Config {
+    let Some(schema) = schema else {
+        // Nothing is known about manual blueprints.
+        return json!({});
+    };
+
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for field in schema.fields {
+        properties.insert(field.name.into(), field_schema(field));
+        if field.required {
+            required.push(field.name);
+        }
+    }
+    for key in ["with", "wrappers"] {
+        properties.insert(key.into(), json!({ "$ref": "#/definitions/wrappers" }));
+    }
+
+    let mut any_of = vec![json!({ "$ref": "#/definitions/variable" })];
+    if schema.allows_null {
+        any_of.push(json!({ "type": "null" }));
+    }
+    any_of.extend(
+        schema
+            .shorthands
+            .iter()
+            .map(|&json_type| value_schema(json_type)),
+    );
+    any_of.push(json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    }));
+
+    json!({ "anyOf": any_of })
+}
+
+// Returns the schema for a field, with its documentation.
+fn field_schema(field: &BlueprintField) -> Config {
+    let mut schema = value_schema(field.json_type);
+    let description = if field.description.is_empty() {
+        format!("`{}`", field.rust_type)
+    } else {
+        format!("{} (`{}`)", field.description, field.rust_type)
+    };
+    schema["description"] = description.into();
+    schema
+}
+
+// Returns the schema for a value of the given JSON type.
+fn value_schema(json_type: &str) -> Config {
+    match json_type {
+        "" => json!({}),
+        "view" => json!({ "$ref": "#/definitions/view" }),
+        // Variables are strings too.
+        "string" => json!({ "type": "string" }),
+        json_type => json!({
+            "anyOf": [
+                { "type": json_type },
+                { "$ref": "#/definitions/variable" },
+            ],
+        }),
+    }
+}
+
+#[cfg(all(test, feature = "builder"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blueprints() {
+        let schema = schema();
+        let views = &schema["definitions"]["view"]["anyOf"];
+
+        // `TextView` can be used alone, with a string, or with an object.
+        assert!(views[1]["enum"]
+            .as_array()
+            .unwrap()
+            .contains(&json!("TextView")));
+        let text_view = &views[2]["properties"]["TextView"]["anyOf"];
+        assert!(text_view
+            .as_array()
+            .unwrap()
+            .contains(&json!({ "type": "string" })));
+
+        // Required keys are listed.
+        let panel = views[2]["properties"]["Panel"]["anyOf"]
+            .as_array()
+            .unwrap()
+            .last()
+            .unwrap();
+        assert_eq!(panel["required"], json!(["view"]));
+        assert_eq!(
+            panel["properties"]["view"]["$ref"],
+            json!("#/definitions/view")
+        );
+
+        // Wrappers are listed too.
+        let wrappers = &schema["definitions"]["wrapper"]["anyOf"][0]["enum"];
+        assert!(wrappers.as_array().unwrap().contains(&json!("scroll")));
+    }
+}
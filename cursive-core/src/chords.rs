@@ -0,0 +1,251 @@
+use crate::event::{Callback, Event};
+use crate::style::PaletteStyle;
+use crate::timer::TimerHandle;
+use crate::{Cursive, Printer, Rect, Vec2};
+use std::time::Duration;
+use unicode_width::UnicodeWidthStr;
+
+// Default time allowed between two keys of a chord.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Multi-key sequences bound to global callbacks.
+pub(crate) struct Chords {
+    bindings: Vec<(Vec<Event>, Callback)>,
+
+    // Keys received so far for the current sequence.
+    pending: Vec<Event>,
+
+    // Clears the pending sequence once the timeout expires.
+    timer: Option<TimerHandle>,
+
+    timeout: Option<Duration>,
+
+    // If `true`, the pending sequence is shown in the bottom-right corner.
+    show_pending: bool,
+
+    // `true` if the indicator changed since the last draw.
+    indicator_changed: bool,
+}
+
+impl Default for Chords {
+    fn default() -> Self {
+        Chords {
+            bindings: Vec::new(),
+            pending: Vec::new(),
+            timer: None,
+            timeout: Some(DEFAULT_TIMEOUT),
+            show_pending: false,
+            indicator_changed: false,
+        }
+    }
+}
+
+impl Chords {
+    /// Binds a sequence, replacing any existing binding for it.
+    pub fn add(&mut self, sequence: Vec<Event>, cb: Callback) {
+        self.remove(&sequence);
+        self.bindings.push((sequence, cb));
+    }
+
+    /// Removes the binding for the given sequence.
+    pub fn remove(&mut self, sequence: &[Event]) {
+        self.bindings.retain(|(s, _)| s != sequence);
+    }
+
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.timeout = timeout;
+    }
+
+    pub fn set_show_pending(&mut self, show_pending: bool) {
+        self.show_pending = show_pending;
+        self.indicator_changed = true;
+    }
+
+    pub fn pending(&self) -> &[Event] {
+        &self.pending
+    }
+
+    fn set_pending(&mut self, pending: Vec<Event>) {
+        if let Some(timer) = self.timer.take() {
+            timer.cancel();
+        }
+        self.indicator_changed |= self.show_pending && pending != self.pending;
+        self.pending = pending;
+    }
+
+    /// Returns the area of the indicator if it needs to be redrawn.
+    pub fn dirty_area(&self, screen_size: Vec2) -> Option<Rect> {
+        if !self.indicator_changed || screen_size.y == 0 {
+            return None;
+        }
+
+        // The indicator may have shrunk, so redraw the entire row.
+        Some(Rect::from_size((0, screen_size.y - 1), (screen_size.x, 1)))
+    }
+
+    /// Draws the pending sequence, if the indicator is enabled.
+    pub fn draw(&mut self, printer: &Printer) {
+        self.indicator_changed = false;
+
+        if !self.show_pending || self.pending.is_empty() || printer.size.y == 0 {
+            return;
+        }
+
+        let text = format!(" {} ", describe(&self.pending));
+        let x = printer.size.x.saturating_sub(text.width());
+        printer.with_style(PaletteStyle::Highlight, |printer| {
+            printer.print((x, printer.size.y - 1), &text);
+        });
+    }
+}
+
+/// Describes a key sequence, like `Ctrl-x Ctrl-c`.
+pub(crate) fn describe(sequence: &[Event]) -> String {
+    let keys: Vec<String> = sequence
+        .iter()
+        .map(|event| match *event {
+            Event::Char(c) => c.to_string(),
+            Event::CtrlChar(c) => format!("Ctrl-{c}"),
+            Event::AltChar(c) => format!("Alt-{c}"),
+            Event::Key(key) => format!("{key:?}"),
+            Event::Shift(key) => format!("Shift-{key:?}"),
+            Event::Alt(key) => format!("Alt-{key:?}"),
+            Event::AltShift(key) => format!("Alt-Shift-{key:?}"),
+            Event::Ctrl(key) => format!("Ctrl-{key:?}"),
+            Event::CtrlShift(key) => format!("Ctrl-Shift-{key:?}"),
+            Event::CtrlAlt(key) => format!("Ctrl-Alt-{key:?}"),
+            ref event => format!("{event:?}"),
+        })
+        .collect();
+    keys.join(" ")
+}
+
+impl Cursive {
+    /// Feeds a key event to the chord bindings.
+    ///
+    /// Returns `true` if the event was used by a chord, and should not be
+    /// processed any further.
+    pub(crate) fn process_chord(&mut self, event: &Event) -> bool {
+        let is_key = matches!(
+            event,
+            Event::Char(_)
+                | Event::CtrlChar(_)
+                | Event::AltChar(_)
+                | Event::Key(_)
+                | Event::Shift(_)
+                | Event::Alt(_)
+                | Event::AltShift(_)
+                | Event::Ctrl(_)
+                | Event::CtrlShift(_)
+                | Event::CtrlAlt(_)
+        );
+        if !is_key || self.chords.bindings.is_empty() {
+            return false;
+        }
+
+        let mut sequence = self.chords.pending.clone();
+        let was_pending = !sequence.is_empty();
+        sequence.push(event.clone());
+
+        let matched = self
+            .chords
+            .bindings
+            .iter()
+            .find(|(s, _)| *s == sequence)
+            .map(|(_, cb)| cb.clone());
+        if let Some(cb) = matched {
+            self.chords.set_pending(Vec::new());
+            cb(self);
+            return true;
+        }
+
+        let is_prefix = self
+            .chords
+            .bindings
+            .iter()
+            .any(|(s, _)| s.starts_with(&sequence));
+        if is_prefix {
+            self.chords.set_pending(sequence);
+            if let Some(timeout) = self.chords.timeout {
+                let timer = self.set_timeout(timeout, |s| s.chords.set_pending(Vec::new()));
+                self.chords.timer = Some(timer);
+            }
+            return true;
+        }
+
+        // An unknown sequence is dropped entirely.
+        self.chords.set_pending(Vec::new());
+        was_pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::event::{Event, Key};
+    use crate::Cursive;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    fn counter(siv: &mut Cursive, sequence: &[Event]) -> Arc<AtomicUsize> {
+        let count = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&count);
+        siv.add_global_chord(sequence, move |_| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+        count
+    }
+
+    #[test]
+    fn sequences() {
+        let mut siv = Cursive::new();
+        let save = counter(&mut siv, &[Event::CtrlChar('x'), Event::CtrlChar('s')]);
+        let open = counter(&mut siv, &[Event::CtrlChar('x'), Event::Char('o')]);
+
+        siv.on_event(Event::CtrlChar('x'));
+        assert_eq!(siv.pending_chord(), [Event::CtrlChar('x')]);
+        siv.on_event(Event::CtrlChar('s'));
+        assert_eq!(save.load(Ordering::Relaxed), 1);
+        assert!(siv.pending_chord().is_empty());
+
+        // A broken sequence is dropped.
+        siv.on_event(Event::CtrlChar('x'));
+        siv.on_event(Event::Key(Key::Enter));
+        siv.on_event(Event::Char('o'));
+        assert_eq!(open.load(Ordering::Relaxed), 0);
+        assert!(siv.pending_chord().is_empty());
+
+        siv.clear_global_chord(&[Event::CtrlChar('x'), Event::CtrlChar('s')]);
+        siv.on_event(Event::CtrlChar('x'));
+        siv.on_event(Event::CtrlChar('s'));
+        assert_eq!(save.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn timeout() {
+        let mut siv = Cursive::new();
+        let count = counter(&mut siv, &[Event::Char('g'), Event::Char('g')]);
+        siv.set_chord_timeout(Some(Duration::ZERO));
+
+        siv.on_event(Event::Char('g'));
+        siv.process_timers();
+        assert!(siv.pending_chord().is_empty());
+
+        siv.on_event(Event::Char('g'));
+        assert_eq!(count.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn indicator() {
+        let mut siv = Cursive::new();
+        counter(&mut siv, &[Event::CtrlChar('x'), Event::CtrlChar('c')]);
+        siv.set_chord_indicator(true);
+
+        let mut harness = crate::test::Harness::with_cursive(siv, (20, 3));
+        harness.feed_event(Event::CtrlChar('x'));
+        assert!(harness.screen().row(2).ends_with(" Ctrl-x "));
+
+        harness.feed_event(Event::CtrlChar('c'));
+        assert!(!harness.screen().contains("Ctrl-x"));
+    }
+}
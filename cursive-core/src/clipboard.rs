@@ -0,0 +1,323 @@
+//! Copy text out of the application.
+//!
+//! Cursive keeps a clipboard [`Provider`], used by [`Cursive::copy_to_clipboard`] and
+//! [`Cursive::clipboard_text`]. Views supporting text selection (like
+//! [`TextArea`](crate::views::TextArea)) copy the selected text when the mouse is released.
+//!
+//! A few providers are available:
+//!
+//! * [`Internal`] only keeps the text inside the application. This is the default.
+//! * [`Osc52`] asks the terminal to set the clipboard, using an escape sequence. This works
+//!   through SSH, but not all terminals support it.
+//! * [`Command`] runs an external program, like `wl-copy` or `xclip`.
+//!
+//! [`detect()`] picks a provider suited to the current environment.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cursive_core::{clipboard, Cursive};
+//!
+//! let mut siv = Cursive::new();
+//! siv.set_clipboard_provider(clipboard::detect());
+//! ```
+//!
+//! [`Cursive::copy_to_clipboard`]: crate::Cursive::copy_to_clipboard
+//! [`Cursive::clipboard_text`]: crate::Cursive::clipboard_text
+use crate::event::Callback;
+use std::io::{self, Read, Write};
+use std::process::Stdio;
+
+/// A way to access the clipboard.
+pub trait Provider: Send {
+    /// Copies the given text to the clipboard.
+    fn copy(&mut self, text: &str) -> io::Result<()>;
+
+    /// Returns the current content of the clipboard.
+    ///
+    /// Returns `Ok(None)` if this provider cannot read the clipboard.
+    fn paste(&mut self) -> io::Result<Option<String>> {
+        Ok(None)
+    }
+
+    /// Returns an escape sequence copying the given text, if this provider uses one.
+    ///
+    /// [`Cursive::copy_to_clipboard`] then sends this sequence through the
+    /// backend instead of calling `copy`, so it reaches the terminal even when
+    /// the standard output is redirected.
+    ///
+    /// [`Cursive::copy_to_clipboard`]: crate::Cursive::copy_to_clipboard
+    fn terminal_sequence(&self, _text: &str) -> Option<String> {
+        None
+    }
+}
+
+impl Provider for Box<dyn Provider> {
+    fn copy(&mut self, text: &str) -> io::Result<()> {
+        (**self).copy(text)
+    }
+
+    fn paste(&mut self) -> io::Result<Option<String>> {
+        (**self).paste()
+    }
+
+    fn terminal_sequence(&self, text: &str) -> Option<String> {
+        (**self).terminal_sequence(text)
+    }
+}
+
+/// Keeps the copied text inside the application.
+#[derive(Clone, Debug, Default)]
+pub struct Internal {
+    content: Option<String>,
+}
+
+impl Internal {
+    /// Creates a new empty clipboard.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Provider for Internal {
+    fn copy(&mut self, text: &str) -> io::Result<()> {
+        self.content = Some(text.to_string());
+        Ok(())
+    }
+
+    fn paste(&mut self) -> io::Result<Option<String>> {
+        Ok(self.content.clone())
+    }
+}
+
+/// Sets the clipboard using the OSC 52 escape sequence.
+///
+/// The terminal is in charge of actually setting the clipboard, so this works
+/// even through SSH. Some terminals ignore this sequence, or require it to be
+/// enabled in their settings.
+///
+/// Reading the clipboard is not supported.
+///
+/// The sequence is written by the backend, so this provider only works through
+/// [`Cursive::copy_to_clipboard`]: calling [`Provider::copy`] directly fails.
+///
+/// [`Cursive::copy_to_clipboard`]: crate::Cursive::copy_to_clipboard
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Osc52;
+
+impl Provider for Osc52 {
+    fn copy(&mut self, _text: &str) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "OSC 52 needs to be written by the backend",
+        ))
+    }
+
+    fn terminal_sequence(&self, text: &str) -> Option<String> {
+        Some(format!("\x1b]52;c;{}\x07", base64(text.as_bytes())))
+    }
+}
+
+/// Runs external programs to access the clipboard.
+///
+/// The text to copy is written to the standard input of the copy command, and
+/// the output of the paste command is used as the clipboard content.
+#[derive(Clone, Debug)]
+pub struct Command {
+    copy: Vec<String>,
+    paste: Option<Vec<String>>,
+}
+
+impl Command {
+    /// Creates a provider using the given command to copy text.
+    ///
+    /// The first element is the program to run, the others are its arguments.
+    ///
+    /// # Panics
+    ///
+    /// If `copy` is empty.
+    pub fn new<I, S>(copy: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let copy: Vec<String> = copy.into_iter().map(Into::into).collect();
+        assert!(!copy.is_empty(), "the copy command cannot be empty");
+
+        Command { copy, paste: None }
+    }
+
+    /// Sets the command to use to read the clipboard.
+    ///
+    /// # Panics
+    ///
+    /// If `paste` is empty.
+    #[must_use]
+    pub fn paste_command<I, S>(mut self, paste: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let paste: Vec<String> = paste.into_iter().map(Into::into).collect();
+        assert!(!paste.is_empty(), "the paste command cannot be empty");
+
+        self.paste = Some(paste);
+        self
+    }
+
+    /// Uses `wl-copy` and `wl-paste`, for Wayland sessions.
+    pub fn wl_clipboard() -> Self {
+        Command::new(["wl-copy"]).paste_command(["wl-paste", "--no-newline"])
+    }
+
+    /// Uses `xclip`, for X11 sessions.
+    pub fn xclip() -> Self {
+        Command::new(["xclip", "-selection", "clipboard"]).paste_command([
+            "xclip",
+            "-selection",
+            "clipboard",
+            "-out",
+        ])
+    }
+}
+
+fn command(args: &[String]) -> std::process::Command {
+    let mut command = std::process::Command::new(&args[0]);
+    command.args(&args[1..]).stderr(Stdio::null());
+    command
+}
+
+impl Provider for Command {
+    fn copy(&mut self, text: &str) -> io::Result<()> {
+        let mut child = command(&self.copy)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .spawn()?;
+
+        // Dropping stdin closes it, so the command knows the text is complete.
+        child.stdin.take().unwrap().write_all(text.as_bytes())?;
+
+        let status = child.wait()?;
+        if !status.success() {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                format!("`{}` failed: {status}", self.copy[0]),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn paste(&mut self) -> io::Result<Option<String>> {
+        let Some(paste) = &self.paste else {
+            return Ok(None);
+        };
+
+        let mut child = command(paste)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .spawn()?;
+
+        let mut content = String::new();
+        child.stdout.take().unwrap().read_to_string(&mut content)?;
+
+        let status = child.wait()?;
+        Ok(status.success().then(|| content))
+    }
+}
+
+/// Returns a provider suited to the current environment.
+///
+/// * In a Wayland session, uses [`Command::wl_clipboard()`].
+/// * In an X11 session, uses [`Command::xclip()`].
+/// * Otherwise (for example through SSH), uses [`Osc52`].
+///
+/// This only looks at environment variables: it does not check that the
+/// programs are actually installed.
+pub fn detect() -> Box<dyn Provider> {
+    let has_var = |var: &str| std::env::var_os(var).map_or(false, |value| !value.is_empty());
+
+    if has_var("WAYLAND_DISPLAY") {
+        Box::new(Command::wl_clipboard())
+    } else if has_var("DISPLAY") {
+        Box::new(Command::xclip())
+    } else {
+        Box::new(Osc52)
+    }
+}
+
+/// Returns a callback copying `text` to the clipboard.
+///
+/// Errors are logged.
+pub(crate) fn copy_callback(text: String) -> Callback {
+    Callback::from_fn(move |s| {
+        if let Err(err) = s.copy_to_clipboard(&text) {
+            log::warn!("Could not copy to the clipboard: {err}");
+        }
+    })
+}
+
+// Standard base64 encoding, with padding.
+pub(crate) fn base64(input: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut result = String::with_capacity((input.len() + 2) / 3 * 4);
+
+    for chunk in input.chunks(3) {
+        let bytes = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let index = (n >> (18 - 6 * i)) & 0x3f;
+                result.push(ALPHABET[index as usize] as char);
+            } else {
+                result.push('=');
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_encoding() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+        assert_eq!(base64("漢字".as_bytes()), "5ryi5a2X");
+    }
+
+    #[test]
+    fn internal() {
+        let mut clipboard = Internal::new();
+        assert_eq!(clipboard.paste().unwrap(), None);
+
+        clipboard.copy("some text").unwrap();
+        assert_eq!(clipboard.paste().unwrap().as_deref(), Some("some text"));
+    }
+
+    #[test]
+    fn osc52() {
+        let mut siv = crate::Cursive::new();
+        siv.set_clipboard_provider(Osc52);
+        siv.copy_to_clipboard("foo").unwrap();
+
+        // The sequence waits for the backend.
+        assert_eq!(siv.backend_calls.len(), 1);
+        assert_eq!(
+            Osc52.terminal_sequence("foo").as_deref(),
+            Some("\x1b]52;c;Zm9v\x07")
+        );
+    }
+}
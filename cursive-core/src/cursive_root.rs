@@ -1,18 +1,33 @@
 use std::any::Any;
 use std::num::NonZeroU32;
-#[cfg(feature = "toml")]
 use std::path::Path;
+use std::sync::Arc;
 
 use crossbeam_channel::{self, Receiver, Sender};
 use parking_lot::RwLock;
 
 use crate::{
     backend,
+    buffer::{GlyphFallbacks, PrintBuffer},
+    chords::Chords,
+    clipboard,
     cursive_run::CursiveRunner,
+    density::{self, Density},
     direction,
-    event::{Event, EventResult},
+    event::{self, Event, EventResult},
+    event_source::{self, EventSources, SourceHandle},
+    jobs::{self, JobContext, JobHandle},
+    keymap::EventMap,
+    menu,
+    notifications::Notifications,
     printer::Printer,
-    theme,
+    profiling,
+    recording::{self, EventRecorder},
+    rect::Rect,
+    statusbar::Statusbar,
+    style, theme,
+    timer::{TimerHandle, Timers},
+    utils::markup::StyledString,
     view::{self, Finder, IntoBoxedView, Position, View, ViewNotFound},
     views::{self, LayerPosition},
     Dump, Vec2,
@@ -20,9 +35,15 @@ use crate::{
 
 static DEBUG_VIEW_NAME: &str = "_cursive_debug_view";
 
+// Prefix for the names given to transient layers.
+static TRANSIENT_LAYER_PREFIX: &str = "_cursive_transient_";
+
 type RootView = views::OnEventView<views::ScreensView<views::StackView>>;
 type BackendCallback = dyn FnOnce(&mut dyn backend::Backend);
 type Callback = dyn FnOnce(&mut Cursive) + Send;
+type ShutdownHook = dyn FnMut(&mut Cursive) + Send;
+
+type FrameHook = dyn FnMut(&mut Cursive, &profiling::FrameMetrics) + Send;
 
 /// Central part of the cursive library.
 ///
@@ -34,6 +55,9 @@ type Callback = dyn FnOnce(&mut Cursive) + Send;
 pub struct Cursive {
     theme: theme::Theme,
 
+    // Replacements for graphemes the terminal can't render.
+    glyph_fallbacks: Arc<GlyphFallbacks>,
+
     // The main view
     root: RootView,
 
@@ -47,14 +71,79 @@ pub struct Cursive {
     cb_source: Receiver<Box<Callback>>,
     cb_sink: Sender<Box<Callback>>,
 
+    // Handle injected events
+    event_source: Receiver<Event>,
+    event_sink: Sender<Event>,
+
     last_size: Vec2,
 
     // User-provided data.
     user_data: Box<dyn Any>,
 
+    // Where copied text goes.
+    clipboard: Box<dyn clipboard::Provider>,
+
     // Handle auto-refresh when no event is received.
     fps: Option<NonZeroU32>,
 
+    // Warn about duplicate names when adding layers.
+    warn_duplicate_names: bool,
+
+    // Warn about low-contrast colors when drawing.
+    contrast_check: Option<style::ContrastCheck>,
+
+    // Input latency measurements.
+    profiler: profiling::Profiler,
+
+    // When the input event being processed was polled from the backend.
+    event_timestamp: Option<std::time::Instant>,
+
+    // Callbacks to run after each frame handling input is presented.
+    frame_hooks: Vec<Box<FrameHook>>,
+
+    // Only redraw what changed on auto-refresh frames.
+    damage_tracking: bool,
+
+    // Spacing used by the views, read during the layout.
+    density: Density,
+
+    // Number of nested batch updates currently active.
+    //
+    // No layout or draw happens while this is non-zero.
+    batch_depth: usize,
+
+    // Selection callbacks produced during a batch update, run when it ends.
+    //
+    // An entry replaces any earlier entry with the same key.
+    deferred: Vec<(usize, Box<Callback>)>,
+
+    // Callbacks scheduled to run later.
+    pub(crate) timers: Timers,
+
+    // External sources of messages.
+    pub(crate) event_sources: EventSources,
+
+    // Log of input events being recorded, if any.
+    recorder: Option<EventRecorder>,
+
+    // Multi-key sequences bound to global callbacks.
+    pub(crate) chords: Chords,
+
+    // Toasts shown over the views.
+    pub(crate) notifications: Notifications,
+
+    // Bar reserving the bottom rows, and the command line.
+    pub(crate) statusbar: Statusbar,
+
+    // Translations applied to incoming events.
+    event_map: EventMap,
+
+    // Number of transient layers added so far, used to name them.
+    transient_layers: usize,
+
+    // Callbacks to run once the event loop stops, before the backend is closed.
+    shutdown_hooks: Vec<Box<ShutdownHook>>,
+
     // List of callbacks to run on the backend.
     // The current assumption is that we only add calls here during event processing.
     pub(crate) backend_calls: Vec<Box<BackendCallback>>,
@@ -74,6 +163,11 @@ pub type ScreenId = usize;
 /// [`send_wrapper`]: https://crates.io/crates/send_wrapper
 pub type CbSink = Sender<Box<dyn FnOnce(&mut Cursive) + Send>>;
 
+/// Convenient alias to the result of `Cursive::event_sink`.
+///
+/// Events sent here are processed by the event loop as if they came from the backend.
+pub type EventSink = Sender<Event>;
+
 new_default!(Cursive);
 
 impl Cursive {
@@ -88,9 +182,11 @@ impl Cursive {
         let theme = theme::load_default();
 
         let (cb_sink, cb_source) = crossbeam_channel::unbounded();
+        let (event_sink, event_source) = crossbeam_channel::unbounded();
 
         let mut cursive = Cursive {
             theme,
+            glyph_fallbacks: Arc::new(GlyphFallbacks::new()),
             root: views::OnEventView::new(views::ScreensView::single_screen(
                 views::StackView::new(),
             )),
@@ -100,8 +196,29 @@ impl Cursive {
             running: true,
             cb_source,
             cb_sink,
+            event_source,
+            event_sink,
             fps: None,
+            warn_duplicate_names: false,
+            contrast_check: None,
+            profiler: profiling::Profiler::default(),
+            event_timestamp: None,
+            frame_hooks: Vec::new(),
+            damage_tracking: false,
+            density: Density::Normal,
+            batch_depth: 0,
+            deferred: Vec::new(),
+            timers: Timers::default(),
+            event_sources: EventSources::default(),
+            recorder: None,
+            chords: Chords::default(),
+            notifications: Notifications::default(),
+            statusbar: Statusbar::default(),
+            event_map: EventMap::default(),
+            transient_layers: 0,
+            shutdown_hooks: Vec::new(),
             user_data: Box::new(()),
+            clipboard: Box::new(clipboard::Internal::new()),
             backend_calls: Vec::new(),
         };
         cursive.reset_default_callbacks();
@@ -116,29 +233,78 @@ impl Cursive {
         self.last_size
     }
 
+    // Returns the area of the screen that changed since the last draw.
+    //
+    // Must be called before `layout()`, which clears the views' dirty state.
+    pub(crate) fn dirty_area(&self) -> Option<Rect> {
+        if self.needs_clear {
+            return Some(Rect::from_size(Vec2::zero(), self.last_size));
+        }
+
+        let offset = usize::from(!self.menubar.autohide);
+        let size = self.root_size(self.last_size);
+        let area = self.root.dirty_area(size).map(|area| area + (0, offset));
+
+        [
+            self.statusbar.dirty_area(self.last_size),
+            self.chords.dirty_area(self.last_size),
+            self.notifications.dirty_area(self.last_size),
+        ]
+        .into_iter()
+        .flatten()
+        .fold(area, |area, other| match area {
+            Some(area) => Some(area.union(other)),
+            None => Some(other),
+        })
+    }
+
+    // Returns the size left for the views, between the menubar and the status bar.
+    fn root_size(&self, screen_size: Vec2) -> Vec2 {
+        let offset = usize::from(!self.menubar.autohide);
+        screen_size.saturating_sub((0, offset + self.statusbar.height()))
+    }
+
     pub(crate) fn layout(&mut self, size: Vec2) {
         self.last_size = size;
-        let offset = usize::from(!self.menubar.autohide);
-        let size = size.saturating_sub((0, offset));
-        self.root.layout(size);
+        density::with_density(self.density, || {
+            self.menubar.layout(Vec2::new(size.x, 1));
+            let offset = usize::from(!self.menubar.autohide);
+            self.statusbar.layout(size.saturating_sub((0, offset)));
+            let size = self.root_size(size);
+            self.root.layout(size);
+        });
     }
 
-    pub(crate) fn draw(&mut self, buffer: &RwLock<crate::buffer::PrintBuffer>) {
-        let size = buffer.read().size();
+    pub(crate) fn draw(&mut self, buffer: &RwLock<crate::buffer::PrintBuffer>, area: Option<Rect>) {
+        let size = {
+            let mut buffer = buffer.write();
+            let glyph_fallbacks = Some(Arc::clone(&self.glyph_fallbacks)).filter(|f| !f.is_empty());
+            buffer.set_glyph_fallbacks(glyph_fallbacks);
+            buffer.size()
+        };
 
         let printer = Printer::new(size, &self.theme, buffer);
 
-        if self.needs_clear {
+        let printer = if self.needs_clear {
             printer.clear();
             self.needs_clear = false;
-        }
+            printer
+        } else if let Some(area) = area {
+            // Only redraw the given area; the rest is kept from the last frame.
+            printer.clipped(area)
+        } else {
+            printer
+        };
 
         let selected = self.menubar.receive_events();
 
         let offset = usize::from(!self.menubar.autohide);
 
         // The printer for the stackview
-        let sv_printer = printer.offset((0, offset)).focused(!selected);
+        let sv_printer = printer
+            .offset((0, offset))
+            .cropped(self.root_size(printer.size))
+            .focused(!selected);
 
         // Print the stackview background (the blue background) before the menubar
         self.root.get_inner().draw_bg(&sv_printer);
@@ -154,6 +320,14 @@ impl Cursive {
 
         // Finally draw stackview layers
         self.root.get_inner().draw_fg(&sv_printer);
+
+        self.statusbar.draw(&printer.offset((0, offset)));
+
+        // Notifications go over the views, but not the menubar.
+        self.notifications.draw(&printer.offset((0, offset)));
+
+        // The pending chord goes on top of everything.
+        self.chords.draw(&printer);
     }
 
     /// Sets some data to be stored in Cursive.
@@ -302,6 +476,54 @@ impl Cursive {
         &self.cb_sink
     }
 
+    /// Injects an event into the event loop.
+    ///
+    /// The event will be processed during the next event cycle, exactly like
+    /// an event coming from the backend: it goes through the menubar, the
+    /// view tree, and then the global callbacks.
+    ///
+    /// To inject events from another thread, use [`Cursive::event_sink`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::event::Event;
+    /// # let mut siv = cursive_core::Cursive::new();
+    /// siv.add_global_callback('q', |s| s.quit());
+    ///
+    /// // Simulate the user pressing `q` during the next event cycle.
+    /// siv.inject_event(Event::Char('q'));
+    ///
+    /// siv.runner(cursive_core::backend::Dummy::init()).step();
+    /// assert!(!siv.is_running());
+    /// ```
+    pub fn inject_event(&mut self, event: Event) {
+        // We hold the receiver, so this cannot fail.
+        self.event_sink.send(event).unwrap();
+    }
+
+    /// Returns a sender to inject events from other threads.
+    ///
+    /// Events sent this way will be processed during the next event cycle,
+    /// in the order they were sent, after the events from the backend.
+    ///
+    /// This can be used to automate input, or to bridge other input sources.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::event::Event;
+    /// # let mut siv = cursive_core::Cursive::new();
+    /// let sink = siv.event_sink().clone();
+    ///
+    /// std::thread::spawn(move || {
+    ///     sink.send(Event::Char('q')).unwrap();
+    /// });
+    /// ```
+    pub fn event_sink(&self) -> &EventSink {
+        &self.event_sink
+    }
+
     /// Selects the menubar.
     pub fn select_menubar(&mut self) {
         if let Ok(res) = self.menubar.take_focus(direction::Direction::none()) {
@@ -401,6 +623,118 @@ impl Cursive {
         self.set_theme(theme);
     }
 
+    /// Returns the table of glyph fallbacks currently used.
+    pub fn glyph_fallbacks(&self) -> &GlyphFallbacks {
+        &self.glyph_fallbacks
+    }
+
+    /// Sets the table of glyph fallbacks.
+    ///
+    /// Any grapheme found in this table will be replaced when printed on screen.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// use cursive_core::buffer::GlyphFallbacks;
+    ///
+    /// let mut siv = Cursive::new();
+    ///
+    /// // Use ASCII borders if the locale doesn't support UTF-8.
+    /// siv.set_glyph_fallbacks(GlyphFallbacks::detect());
+    /// ```
+    pub fn set_glyph_fallbacks(&mut self, glyph_fallbacks: GlyphFallbacks) {
+        self.glyph_fallbacks = Arc::new(glyph_fallbacks);
+        self.clear();
+    }
+
+    /// Sets the clipboard provider.
+    ///
+    /// By default, copied text is only kept inside the application (see
+    /// [`clipboard::Internal`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::{clipboard, Cursive};
+    /// let mut siv = Cursive::new();
+    ///
+    /// siv.set_clipboard_provider(clipboard::Command::xclip());
+    /// ```
+    pub fn set_clipboard_provider<P>(&mut self, provider: P)
+    where
+        P: clipboard::Provider + 'static,
+    {
+        self.clipboard = Box::new(provider);
+    }
+
+    /// Copies the given text to the clipboard.
+    ///
+    /// Providers using an escape sequence (like [`clipboard::Osc52`]) have it
+    /// written by the backend, on the next step of the event loop.
+    pub fn copy_to_clipboard(&mut self, text: &str) -> std::io::Result<()> {
+        if let Some(sequence) = self.clipboard.terminal_sequence(text) {
+            self.backend_calls
+                .push(Box::new(move |backend| backend.print_raw(&sequence)));
+            return Ok(());
+        }
+        self.clipboard.copy(text)
+    }
+
+    /// Returns the current content of the clipboard.
+    ///
+    /// Returns `Ok(None)` if the clipboard provider cannot read the clipboard.
+    pub fn clipboard_text(&mut self) -> std::io::Result<Option<String>> {
+        self.clipboard.paste()
+    }
+
+    /// Updates the table of glyph fallbacks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// let mut siv = Cursive::new();
+    ///
+    /// siv.update_glyph_fallbacks(|fallbacks| {
+    ///     fallbacks.insert("\u{e0b0}", ">");
+    /// });
+    /// ```
+    pub fn update_glyph_fallbacks(&mut self, f: impl FnOnce(&mut GlyphFallbacks)) {
+        f(Arc::make_mut(&mut self.glyph_fallbacks));
+        self.clear();
+    }
+
+    /// Returns the layout density.
+    pub fn density(&self) -> Density {
+        self.density
+    }
+
+    /// Sets the layout density, and redraws the screen.
+    ///
+    /// Views will pick it up during the next layout. This can be used to
+    /// toggle a "compact mode" at runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// use cursive_core::density::Density;
+    ///
+    /// let mut siv = Cursive::new();
+    /// siv.add_global_callback('z', |s| {
+    ///     let density = match s.density() {
+    ///         Density::Compact => Density::Normal,
+    ///         _ => Density::Compact,
+    ///     };
+    ///     s.set_density(density);
+    /// });
+    /// ```
+    pub fn set_density(&mut self, density: Density) {
+        self.density = density;
+        self.clear();
+    }
+
     /// Clears the screen.
     ///
     /// Users rarely have to call this directly.
@@ -445,6 +779,27 @@ impl Cursive {
         self.set_fps(if autorefresh { 30 } else { 0 });
     }
 
+    /// Enables or disables damage tracking.
+    ///
+    /// When enabled, frames only redraw the parts of the screen reported by
+    /// [`View::dirty_area`], and are skipped entirely if nothing changed.
+    /// This applies to frames following a callback, a timer or an event
+    /// source message, as well as auto-refresh frames (see [`Cursive::set_fps`]).
+    ///
+    /// The whole screen is still redrawn after an input event (most views
+    /// don't report focus or selection changes), after it was cleared or
+    /// resized, or when layers are added or removed.
+    ///
+    /// Disabled by default.
+    pub fn set_damage_tracking(&mut self, enabled: bool) {
+        self.damage_tracking = enabled;
+    }
+
+    /// Returns `true` if damage tracking is enabled.
+    pub fn damage_tracking(&self) -> bool {
+        self.damage_tracking
+    }
+
     /// Returns the current refresh rate, if any.
     ///
     /// Returns `None` if no auto-refresh is set. Otherwise, returns the rate
@@ -453,141 +808,536 @@ impl Cursive {
         self.fps
     }
 
-    /// Returns a reference to the currently active screen.
-    pub fn screen(&self) -> &views::StackView {
-        self.root.get_inner().screen().unwrap()
+    /// Starts a batch update.
+    ///
+    /// Until the matching call to [`Cursive::end_update`], the event loop
+    /// will not layout or redraw the view tree, and selection callbacks from
+    /// a [`SelectView`](views::SelectView) are put on hold. They run in order
+    /// when the update ends, once per view: for the last selection, with the
+    /// selection from before the update as the previous one.
+    ///
+    /// Other events and callbacks are still processed as usual. This is
+    /// useful when a bulk operation is spread over multiple callbacks (for
+    /// example when loading data in chunks through the
+    /// [callback sink](Cursive::cb_sink)), to avoid drawing intermediate
+    /// states.
+    ///
+    /// Calls can be nested: updates will resume when every call to
+    /// `begin_update` has been matched by a call to `end_update`.
+    pub fn begin_update(&mut self) {
+        self.batch_depth += 1;
     }
 
-    /// Returns a mutable reference to the currently active screen.
-    pub fn screen_mut(&mut self) -> &mut views::StackView {
-        self.root.get_inner_mut().screen_mut().unwrap()
-    }
+    /// Ends a batch update started with [`Cursive::begin_update`].
+    ///
+    /// When the outermost update ends, this runs the queued selection callbacks.
+    ///
+    /// Does nothing if no batch update is active.
+    pub fn end_update(&mut self) {
+        if self.batch_depth == 0 {
+            return;
+        }
+        self.batch_depth -= 1;
+
+        let mut deferred = std::mem::take(&mut self.deferred).into_iter();
+        while !self.is_updating() {
+            let Some((_, cb)) = deferred.next() else {
+                break;
+            };
+            cb(self);
+        }
 
-    /// Returns the id of the currently active screen.
-    pub fn active_screen(&self) -> ScreenId {
-        self.root.get_inner().active_screen()
+        // A callback started a new update: the rest waits for it to end.
+        let mut remaining: Vec<_> = deferred.collect();
+        remaining.append(&mut self.deferred);
+        self.deferred = remaining;
     }
 
-    /// Adds a new screen, and returns its ID.
-    pub fn add_screen(&mut self) -> ScreenId {
-        self.root
-            .get_inner_mut()
-            .add_screen(views::StackView::new())
+    /// Returns `true` if a batch update is currently active.
+    pub fn is_updating(&self) -> bool {
+        self.batch_depth > 0
     }
 
-    /// Convenient method to create a new screen, and set it as active.
-    pub fn add_active_screen(&mut self) -> ScreenId {
-        let res = self.add_screen();
-        self.set_screen(res);
-        res
+    /// Runs the given closure as a batch update.
+    ///
+    /// This calls [`Cursive::begin_update`], then `f`, then
+    /// [`Cursive::end_update`], and returns the result from `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::{Cursive, views};
+    /// # use cursive_core::traits::*;
+    /// let mut siv = Cursive::new();
+    /// siv.add_layer(
+    ///     views::SelectView::<usize>::new()
+    ///         .on_select(|s, i| s.set_window_title(format!("Item {i}")))
+    ///         .with_name("select"),
+    /// );
+    ///
+    /// siv.batch(|s| {
+    ///     for i in 0..10_000 {
+    ///         let cb = s
+    ///             .call_on_name("select", |v: &mut views::SelectView<usize>| {
+    ///                 v.add_item(i.to_string(), i);
+    ///                 v.set_selection(i)
+    ///             })
+    ///             .unwrap();
+    ///         cb(s);
+    ///     }
+    /// });
+    /// // The title was only set once, for the last item.
+    /// ```
+    pub fn batch<F, R>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut Cursive) -> R,
+    {
+        self.begin_update();
+        let result = f(self);
+        self.end_update();
+        result
     }
 
-    /// Sets the active screen. Panics if no such screen exist.
-    pub fn set_screen(&mut self, screen_id: ScreenId) {
-        self.root.get_inner_mut().set_active_screen(screen_id);
+    /// Runs the callback now, or when the batch update ends if one is active.
+    ///
+    /// This replaces any callback queued with the same `key`.
+    pub(crate) fn defer<F>(&mut self, key: usize, cb: F)
+    where
+        F: 'static + FnOnce(&mut Cursive) + Send,
+    {
+        if !self.is_updating() {
+            cb(self);
+            return;
+        }
+
+        self.deferred.retain(|(k, _)| *k != key);
+        self.deferred.push((key, Box::new(cb)));
     }
 
-    /// Tries to find the view pointed to by the given selector.
+    /// Schedules a callback to run once, after the given delay.
     ///
-    /// Runs a closure on the view once it's found, and return the
-    /// result.
+    /// The callback is run by the event loop, on the main thread, so it has
+    /// full access to the cursive root. The actual delay may be slightly
+    /// longer, depending on the event loop activity.
     ///
-    /// If the view is not found, or if it is not of the asked type,
-    /// returns None.
+    /// Returns a handle that can be used to cancel the timer.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use cursive_core::{Cursive, views, view};
-    /// # use cursive_core::traits::*;
+    /// # use cursive_core::Cursive;
+    /// # use std::time::Duration;
     /// let mut siv = Cursive::new();
     ///
-    /// siv.add_layer(views::TextView::new("Text #1").with_name("text"));
+    /// // Quit after 10 seconds, unless cancelled.
+    /// let handle = siv.set_timeout(Duration::from_secs(10), |s| s.quit());
     ///
-    /// siv.add_global_callback('p', |s| {
-    ///     s.call_on(
-    ///         &view::Selector::Name("text"),
-    ///         |view: &mut views::TextView| {
-    ///             view.set_content("Text #2");
-    ///         },
-    ///     );
-    /// });
+    /// siv.add_global_callback('c', move |_| handle.cancel());
     /// ```
-    pub fn call_on<V, F, R>(&mut self, sel: &view::Selector, callback: F) -> Option<R>
+    pub fn set_timeout<F>(&mut self, delay: std::time::Duration, cb: F) -> TimerHandle
     where
-        V: View,
-        F: FnOnce(&mut V) -> R,
+        F: FnOnce(&mut Cursive) + Send + 'static,
     {
-        self.root.call_on(sel, callback)
+        let mut cb = Some(cb);
+        self.timers.add(
+            delay,
+            None,
+            Box::new(move |s| {
+                if let Some(cb) = cb.take() {
+                    cb(s);
+                }
+            }),
+        )
     }
 
-    /// Tries to find the view identified by the given name.
+    /// Schedules a callback to run repeatedly, every `interval`.
     ///
-    /// Convenient method to use `call_on` with a [`view::Selector::Name`].
+    /// The first run happens after one `interval`. If the event loop falls
+    /// behind, missed runs are skipped rather than queued.
+    ///
+    /// The timer keeps running until the returned handle is cancelled.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use cursive_core::{Cursive, views};
-    /// # use cursive_core::traits::*;
+    /// # use cursive_core::Cursive;
+    /// # use cursive_core::view::Nameable;
+    /// # use cursive_core::views::TextView;
+    /// # use std::time::Duration;
     /// let mut siv = Cursive::new();
+    /// siv.add_layer(TextView::new("0").with_name("ticks"));
     ///
-    /// siv.add_layer(views::TextView::new("Text #1").with_name("text"));
-    ///
-    /// siv.add_global_callback('p', |s| {
-    ///     s.call_on_name("text", |view: &mut views::TextView| {
-    ///         view.set_content("Text #2");
-    ///     });
+    /// let mut ticks = 0;
+    /// siv.set_interval(Duration::from_secs(1), move |s| {
+    ///     ticks += 1;
+    ///     s.call_on_name("ticks", |v: &mut TextView| v.set_content(ticks.to_string()));
     /// });
     /// ```
-    pub fn call_on_name<V, F, R>(&mut self, name: &str, callback: F) -> Option<R>
+    pub fn set_interval<F>(&mut self, interval: std::time::Duration, cb: F) -> TimerHandle
     where
-        V: View,
-        F: FnOnce(&mut V) -> R,
+        F: FnMut(&mut Cursive) + Send + 'static,
     {
-        self.call_on(&view::Selector::Name(name), callback)
+        self.timers.add(interval, Some(interval), Box::new(cb))
     }
 
-    /// Call the given closure on all views with the given name and the correct type.
-    pub fn call_on_all_named<V, F>(&mut self, name: &str, callback: F)
-    where
-        V: View,
-        F: FnMut(&mut V),
-    {
-        self.root.call_on_all(&view::Selector::Name(name), callback);
+    /// Returns the time when the next scheduled timer is due, if any.
+    pub fn next_timer_deadline(&self) -> Option<std::time::Instant> {
+        self.timers.next_deadline()
     }
 
-    /// Convenient method to find a view wrapped in [`NamedView`].
+    /// Registers a channel as an event source.
     ///
-    /// This looks for a `NamedView<V>` with the given name, and return
-    /// a [`ViewRef`] to the wrapped view. The `ViewRef` implements
-    /// `DerefMut<Target=T>`, so you can treat it just like a `&mut T`.
+    /// Every message received on `receiver` is given to `cb` from the event
+    /// loop. A message wakes the event loop right away, instead of waiting for
+    /// the next poll.
+    ///
+    /// The source is removed when the returned handle is removed, or when all
+    /// the senders for this channel are dropped.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use cursive_core::Cursive;
-    /// # use cursive_core::views::{TextView, ViewRef};
-    /// # let mut siv = Cursive::new();
-    /// use cursive_core::traits::Nameable;
+    /// # use cursive_core::view::Nameable;
+    /// # use cursive_core::views::TextView;
+    /// let mut siv = Cursive::new();
+    /// siv.add_layer(TextView::new("").with_name("status"));
     ///
-    /// siv.add_layer(TextView::new("foo").with_name("id"));
+    /// let (sender, receiver) = cursive_core::reexports::crossbeam_channel::unbounded();
     ///
-    /// // Could be called in a callback
-    /// let mut view: ViewRef<TextView> = siv.find_name("id").unwrap();
-    /// view.set_content("bar");
+    /// siv.add_event_source(receiver, |s, status: String| {
+    ///     s.call_on_name("status", |v: &mut TextView| v.set_content(status));
+    /// });
+    ///
+    /// std::thread::spawn(move || {
+    ///     sender.send("Connected".to_string()).unwrap();
+    /// });
     /// ```
+    pub fn add_event_source<T, F>(&mut self, receiver: Receiver<T>, cb: F) -> SourceHandle
+    where
+        T: Send + 'static,
+        F: FnMut(&mut Cursive, T) + Send + 'static,
+    {
+        self.event_sources.add(receiver, Box::new(cb))
+    }
+
+    /// Registers a reader, like a socket or a pipe, as an event source.
     ///
-    /// Note that you must specify the exact type for the view you're after; for example, using the
-    /// wrong item type in a `SelectView` will not find anything:
+    /// A background thread reads from `reader`, and each chunk of data (or
+    /// read error) is given to `cb` from the event loop. Incoming data wakes
+    /// the event loop right away.
     ///
-    /// ```rust
-    /// # use cursive_core::Cursive;
-    /// # use cursive_core::views::{SelectView};
-    /// # let mut siv = Cursive::new();
-    /// use cursive_core::traits::Nameable;
+    /// The source is removed after the end of the input, or after the first
+    /// read error.
     ///
-    /// let select = SelectView::new().item("zero", 0u32).item("one", 1u32);
+    /// Removing the source with the returned handle does not interrupt a
+    /// blocking read: the background thread only stops after the next read
+    /// returns.
+    pub fn add_reader_source<R, F>(&mut self, reader: R, cb: F) -> SourceHandle
+    where
+        R: std::io::Read + Send + 'static,
+        F: FnMut(&mut Cursive, std::io::Result<Vec<u8>>) + Send + 'static,
+    {
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        let handle = self.add_event_source(receiver, cb);
+
+        event_source::read_in_background(reader, sender, handle.clone());
+        handle
+    }
+
+    /// Starts recording input events to the given file.
+    ///
+    /// Every event received from the backend is written to the file with
+    /// its time since the start of the recording, until
+    /// [`Cursive::stop_recording`] is called. Events injected by the
+    /// application itself are not recorded.
+    ///
+    /// The log can be played back with [`Cursive::replay_events`], for
+    /// example to reproduce a bug report or to automate a demo.
+    ///
+    /// This replaces any recording already in progress.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use cursive_core::Cursive;
+    /// let mut siv = Cursive::new();
+    ///
+    /// siv.record_events("events.log").unwrap();
+    /// ```
+    pub fn record_events<P: AsRef<Path>>(&mut self, path: P) -> std::io::Result<()> {
+        let file = std::fs::File::create(path)?;
+        self.stop_recording();
+        self.recorder = Some(EventRecorder::new(Box::new(std::io::BufWriter::new(file))));
+        Ok(())
+    }
+
+    /// Stops the current recording, if any.
+    pub fn stop_recording(&mut self) {
+        if let Some(mut recorder) = self.recorder.take() {
+            if let Err(e) = recorder.flush() {
+                log::warn!("Could not save recorded events: {e}");
+            }
+        }
+    }
+
+    /// Returns `true` if input events are being recorded.
+    pub fn is_recording(&self) -> bool {
+        self.recorder.is_some()
+    }
+
+    /// Adds an input event to the current recording, if any.
+    ///
+    /// The recording stops if the event cannot be written.
+    pub(crate) fn record_event(&mut self, event: &Event) {
+        let Some(recorder) = self.recorder.as_mut() else {
+            return;
+        };
+
+        if let Err(e) = recorder.record(event) {
+            log::warn!("Could not record event, stopping the recording: {e}");
+            self.recorder = None;
+        }
+    }
+
+    /// Plays back input events recorded with [`Cursive::record_events`].
+    ///
+    /// Each event is scheduled as a timer, keeping the delays between the
+    /// original events divided by `speed`: use `1.0` to replay at the
+    /// original pace, or a larger value to go faster.
+    ///
+    /// Returns a handle that can be used to cancel the remaining events.
+    ///
+    /// # Panics
+    ///
+    /// If `speed` is not a positive, finite number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use cursive_core::Cursive;
+    /// let mut siv = Cursive::new();
+    ///
+    /// // Replay a bug report, twice as fast.
+    /// let replay = siv.replay_events("events.log", 2.0).unwrap();
+    ///
+    /// siv.add_global_callback('s', move |_| replay.cancel());
+    /// ```
+    pub fn replay_events<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        speed: f64,
+    ) -> std::io::Result<TimerHandle> {
+        assert!(
+            speed > 0.0 && speed.is_finite(),
+            "invalid replay speed: {speed}"
+        );
+
+        let file = std::io::BufReader::new(std::fs::File::open(path)?);
+        let events = recording::read_events(file)?;
+
+        let handle = TimerHandle::default();
+        for (time, event) in events {
+            let mut event = Some(event);
+            self.timers.add_with_handle(
+                time.div_f64(speed),
+                None,
+                Box::new(move |s| {
+                    if let Some(event) = event.take() {
+                        s.on_event(event);
+                    }
+                }),
+                handle.clone(),
+            );
+        }
+
+        Ok(handle)
+    }
+
+    /// Returns a reference to the currently active screen.
+    pub fn screen(&self) -> &views::StackView {
+        self.root.get_inner().screen().unwrap()
+    }
+
+    /// Returns a mutable reference to the currently active screen.
+    pub fn screen_mut(&mut self) -> &mut views::StackView {
+        self.root.get_inner_mut().screen_mut().unwrap()
+    }
+
+    /// Returns the id of the currently active screen.
+    pub fn active_screen(&self) -> ScreenId {
+        self.root.get_inner().active_screen()
+    }
+
+    /// Adds a new screen, and returns its ID.
+    pub fn add_screen(&mut self) -> ScreenId {
+        self.root
+            .get_inner_mut()
+            .add_screen(views::StackView::new())
+    }
+
+    /// Convenient method to create a new screen, and set it as active.
+    pub fn add_active_screen(&mut self) -> ScreenId {
+        let res = self.add_screen();
+        self.set_screen(res);
+        res
+    }
+
+    /// Sets the active screen. Panics if no such screen exist.
+    pub fn set_screen(&mut self, screen_id: ScreenId) {
+        self.root.get_inner_mut().set_active_screen(screen_id);
+    }
+
+    /// Switches to the given screen, playing the configured transition.
+    ///
+    /// See [`ScreensView::switch_to_screen`](views::ScreensView::switch_to_screen).
+    ///
+    /// Panics if no such screen exist.
+    pub fn switch_to_screen(&mut self, screen_id: ScreenId) {
+        self.root
+            .get_inner_mut()
+            .switch_to_screen(screen_id)
+            .process(self);
+    }
+
+    /// Switches to the next screen, wrapping around after the last one.
+    pub fn next_screen(&mut self) {
+        self.root.get_inner_mut().next_screen().process(self);
+    }
+
+    /// Switches to the previous screen, wrapping around before the first one.
+    pub fn previous_screen(&mut self) {
+        self.root.get_inner_mut().previous_screen().process(self);
+    }
+
+    /// Returns a reference to the view holding all screens.
+    pub fn screens_view(&self) -> &views::ScreensView<views::StackView> {
+        self.root.get_inner()
+    }
+
+    /// Returns a mutable reference to the view holding all screens.
+    ///
+    /// This can be used to configure screen transitions or switch keys.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::event::{Event, Key};
+    /// # use cursive_core::views::ScreenTransition;
+    /// # use std::time::Duration;
+    /// let mut siv = cursive_core::Cursive::new();
+    /// siv.add_screen();
+    ///
+    /// let screens = siv.screens_view_mut();
+    /// screens.set_switch_keys(Event::Ctrl(Key::PageDown), Event::Ctrl(Key::PageUp));
+    /// screens.set_transition(ScreenTransition::Slide(Duration::from_millis(200)));
+    /// screens.set_on_screen_change(|_, old, new| log::info!("Screen {old} -> {new}"));
+    /// ```
+    pub fn screens_view_mut(&mut self) -> &mut views::ScreensView<views::StackView> {
+        self.root.get_inner_mut()
+    }
+
+    /// Tries to find the view pointed to by the given selector.
+    ///
+    /// Runs a closure on the view once it's found, and return the
+    /// result.
+    ///
+    /// If the view is not found, or if it is not of the asked type,
+    /// returns None.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::{Cursive, views, view};
+    /// # use cursive_core::traits::*;
+    /// let mut siv = Cursive::new();
+    ///
+    /// siv.add_layer(views::TextView::new("Text #1").with_name("text"));
+    ///
+    /// siv.add_global_callback('p', |s| {
+    ///     s.call_on(
+    ///         &view::Selector::Name("text"),
+    ///         |view: &mut views::TextView| {
+    ///             view.set_content("Text #2");
+    ///         },
+    ///     );
+    /// });
+    /// ```
+    pub fn call_on<V, F, R>(&mut self, sel: &view::Selector, callback: F) -> Option<R>
+    where
+        V: View,
+        F: FnOnce(&mut V) -> R,
+    {
+        self.root.call_on(sel, callback)
+    }
+
+    /// Tries to find the view identified by the given name.
+    ///
+    /// Convenient method to use `call_on` with a [`view::Selector::Name`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::{Cursive, views};
+    /// # use cursive_core::traits::*;
+    /// let mut siv = Cursive::new();
+    ///
+    /// siv.add_layer(views::TextView::new("Text #1").with_name("text"));
+    ///
+    /// siv.add_global_callback('p', |s| {
+    ///     s.call_on_name("text", |view: &mut views::TextView| {
+    ///         view.set_content("Text #2");
+    ///     });
+    /// });
+    /// ```
+    pub fn call_on_name<V, F, R>(&mut self, name: &str, callback: F) -> Option<R>
+    where
+        V: View,
+        F: FnOnce(&mut V) -> R,
+    {
+        self.call_on(&view::Selector::Name(name), callback)
+    }
+
+    /// Call the given closure on all views with the given name and the correct type.
+    pub fn call_on_all_named<V, F>(&mut self, name: &str, callback: F)
+    where
+        V: View,
+        F: FnMut(&mut V),
+    {
+        self.root.call_on_all(&view::Selector::Name(name), callback);
+    }
+
+    /// Convenient method to find a view wrapped in [`NamedView`].
+    ///
+    /// This looks for a `NamedView<V>` with the given name, and return
+    /// a [`ViewRef`] to the wrapped view. The `ViewRef` implements
+    /// `DerefMut<Target=T>`, so you can treat it just like a `&mut T`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// # use cursive_core::views::{TextView, ViewRef};
+    /// # let mut siv = Cursive::new();
+    /// use cursive_core::traits::Nameable;
+    ///
+    /// siv.add_layer(TextView::new("foo").with_name("id"));
+    ///
+    /// // Could be called in a callback
+    /// let mut view: ViewRef<TextView> = siv.find_name("id").unwrap();
+    /// view.set_content("bar");
+    /// ```
+    ///
+    /// Note that you must specify the exact type for the view you're after; for example, using the
+    /// wrong item type in a `SelectView` will not find anything:
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// # use cursive_core::views::{SelectView};
+    /// # let mut siv = Cursive::new();
+    /// use cursive_core::traits::Nameable;
+    ///
+    /// let select = SelectView::new().item("zero", 0u32).item("one", 1u32);
     /// siv.add_layer(select.with_name("select"));
     ///
     /// // Specifying a wrong type will not return anything.
@@ -616,107 +1366,554 @@ impl Cursive {
         self.focus(&view::Selector::Name(name))
     }
 
+    /// Scrolls to make the view identified by `name` visible.
+    ///
+    /// Convenient method to call `reveal` with a [`view::Selector::Name`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// # use cursive_core::views::{EditView, LinearLayout, ScrollView};
+    /// use cursive_core::traits::Nameable;
+    ///
+    /// let mut siv = Cursive::new();
+    /// siv.add_layer(ScrollView::new(
+    ///     LinearLayout::vertical()
+    ///         .child(EditView::new().with_name("name"))
+    ///         .child(EditView::new().with_name("email")),
+    /// ));
+    ///
+    /// // Show the field with an invalid value, without taking the focus.
+    /// siv.reveal_name("email").unwrap();
+    /// ```
+    pub fn reveal_name(&mut self, name: &str) -> Result<(), ViewNotFound> {
+        self.reveal(&view::Selector::Name(name))
+    }
+
+    /// Renders the view identified by `name`, and returns its output.
+    ///
+    /// This draws the entire screen on a separate buffer, keeping only the
+    /// region covered by the first [`NamedView`] with this name. The actual
+    /// screen is not affected.
+    ///
+    /// This can be used to copy the content of a specific view, like a table
+    /// or a chart. Cells where the view didn't print anything are converted to
+    /// unstyled spaces.
+    ///
+    /// Views are drawn with the size given in the last layout phase: before
+    /// that, the result will be empty.
+    ///
+    /// Returns `Err(ViewNotFound)` if no view with this name is drawn.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// # use cursive_core::views::TextView;
+    /// use cursive_core::traits::Nameable;
+    ///
+    /// let mut siv = Cursive::new();
+    /// siv.add_layer(TextView::new("Some text").with_name("text"));
+    ///
+    /// // Keep a copy of the text view's output when pressing `c`.
+    /// siv.add_global_callback('c', |s| {
+    ///     if let Ok(output) = s.render_view_to_styled("text") {
+    ///         s.set_user_data(output);
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// [`NamedView`]: views::NamedView
+    pub fn render_view_to_styled(&mut self, name: &str) -> Result<StyledString, ViewNotFound> {
+        let buffer = RwLock::new(PrintBuffer::new());
+        {
+            let mut buffer = buffer.write();
+            buffer.resize(self.screen_size());
+            buffer.capture_view(name);
+        }
+
+        self.draw_offscreen(&buffer);
+
+        let captured = buffer.write().take_captured().ok_or(ViewNotFound)?;
+
+        Ok(captured.to_styled_string())
+    }
+
+    /// Renders the entire screen, and returns the result.
+    ///
+    /// This works with any backend: the result includes the text, color and effects of each
+    /// cell, and can be saved to a file or compared in tests.
+    ///
+    /// The screen is drawn with the size given in the last layout phase: before that, the
+    /// result will be empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// # use cursive_core::views::TextView;
+    /// let mut siv = Cursive::new();
+    /// siv.add_layer(TextView::new("Some text"));
+    ///
+    /// // Keep a copy of the screen when pressing `p`.
+    /// siv.add_global_callback('p', |s| {
+    ///     let screenshot = s.screenshot();
+    ///     s.set_user_data(screenshot.to_styled_string());
+    /// });
+    /// ```
+    pub fn screenshot(&mut self) -> PrintBuffer {
+        let buffer = RwLock::new(PrintBuffer::new());
+        buffer.write().resize(self.screen_size());
+
+        self.draw_offscreen(&buffer);
+
+        buffer.into_inner()
+    }
+
+    /// Draws the views to a buffer other than the actual screen.
+    fn draw_offscreen(&mut self, buffer: &RwLock<PrintBuffer>) {
+        // Drawing resets the dirty state of the views, but the screen still needs
+        // to be updated.
+        let needs_clear = self.needs_clear || self.dirty_area().is_some();
+
+        // Don't let this draw prevent a clear of the actual screen.
+        self.draw(buffer, None);
+        self.needs_clear = needs_clear;
+    }
+
     /// Moves the focus to the view identified by `sel`.
     pub fn focus(&mut self, sel: &view::Selector) -> Result<EventResult, ViewNotFound> {
         self.root.focus_view(sel)
     }
 
-    /// Adds a global callback.
+    /// Scrolls to make the view identified by `sel` visible.
+    ///
+    /// Every scrolling view between the root and the view found scrolls just
+    /// enough to show it. The focus is not changed.
+    ///
+    /// This uses the sizes from the last layout phase.
+    pub fn reveal(&mut self, sel: &view::Selector) -> Result<(), ViewNotFound> {
+        let size = self.root_size(self.last_size);
+        self.root.reveal_view(sel, size).map(|_| ())
+    }
+
+    /// Adds a global callback.
+    ///
+    /// Will be triggered on the given key press when no view catches it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::*;
+    /// let mut siv = Cursive::new();
+    ///
+    /// siv.add_global_callback('q', |s| s.quit());
+    /// ```
+    pub fn add_global_callback<F, E: Into<Event>>(&mut self, event: E, cb: F)
+    where
+        F: FnMut(&mut Cursive) + 'static + Send + Sync,
+    {
+        self.set_on_post_event(event.into(), cb);
+    }
+
+    /// Registers a callback for ignored events.
+    ///
+    /// This is the same as `add_global_callback`, but can register any `EventTrigger`.
+    pub fn set_on_post_event<F, E>(&mut self, trigger: E, cb: F)
+    where
+        F: FnMut(&mut Cursive) + 'static + Send + Sync,
+        E: Into<crate::event::EventTrigger>,
+    {
+        self.root.set_on_event(trigger, crate::immut1!(cb));
+    }
+
+    /// Registers a priority callback.
+    ///
+    /// If an event matches the given trigger, it will not be sent to the view
+    /// tree and will go to the given callback instead.
+    ///
+    /// Note that regular "post-event" callbacks will also be skipped for
+    /// these events.
+    pub fn set_on_pre_event<F, E>(&mut self, trigger: E, cb: F)
+    where
+        F: FnMut(&mut Cursive) + 'static + Send + Sync,
+        E: Into<crate::event::EventTrigger>,
+    {
+        self.root.set_on_pre_event(trigger, crate::immut1!(cb));
+    }
+
+    /// Registers an inner priority callback.
+    ///
+    /// See [`OnEventView`] for more information.
+    ///
+    /// [`OnEventView`]: crate::views::OnEventView::set_on_pre_event_inner()
+    pub fn set_on_pre_event_inner<E, F>(&mut self, trigger: E, cb: F)
+    where
+        E: Into<crate::event::EventTrigger>,
+        F: Fn(&Event) -> Option<EventResult> + 'static + Send + Sync,
+    {
+        self.root
+            .set_on_pre_event_inner(trigger, move |_, event| cb(event));
+    }
+
+    /// Registers an inner callback.
+    ///
+    /// See [`OnEventView`] for more information.
+    ///
+    /// [`OnEventView`]: crate::views::OnEventView::set_on_event_inner()
+    pub fn set_on_event_inner<E, F>(&mut self, trigger: E, cb: F)
+    where
+        E: Into<crate::event::EventTrigger>,
+        F: Fn(&Event) -> Option<EventResult> + 'static + Send + Sync,
+    {
+        self.root
+            .set_on_event_inner(trigger, move |_, event| cb(event));
+    }
+
+    /// Sets the only global callback for the given event.
+    ///
+    /// Any other callback for this event will be removed.
+    ///
+    /// See also [`Cursive::add_global_callback`].
+    pub fn set_global_callback<F, E: Into<Event>>(&mut self, event: E, cb: F)
+    where
+        F: FnMut(&mut Cursive) + 'static + Send + Sync,
+    {
+        let event = event.into();
+        self.clear_global_callbacks(event.clone());
+        self.add_global_callback(event, cb);
+    }
+
+    /// Adds a global callback for a sequence of keys.
+    ///
+    /// The callback runs once all the keys in `sequence` were pressed in a
+    /// row, like `Ctrl-X Ctrl-C` in Emacs.
+    ///
+    /// Unlike [`Cursive::add_global_callback`], sequences are matched before
+    /// the view tree gets the events: once a sequence is started, the
+    /// following keys are not sent to the views. A key that doesn't continue
+    /// any sequence drops the pending keys, and is dropped as well.
+    ///
+    /// Any existing callback for the same sequence is replaced.
+    ///
+    /// See also [`Cursive::set_chord_timeout`] and
+    /// [`Cursive::set_chord_indicator`].
+    ///
+    /// # Panics
+    ///
+    /// If `sequence` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// # use cursive_core::event::Event;
+    /// let mut siv = Cursive::new();
+    ///
+    /// siv.add_global_chord(&[Event::CtrlChar('x'), Event::CtrlChar('c')], |s| s.quit());
+    /// ```
+    pub fn add_global_chord<F>(&mut self, sequence: &[Event], cb: F)
+    where
+        F: FnMut(&mut Cursive) + 'static + Send + Sync,
+    {
+        assert!(!sequence.is_empty(), "chord sequences cannot be empty");
+        self.chords
+            .add(sequence.to_vec(), crate::event::Callback::from_fn_mut(cb));
+    }
+
+    /// Removes the global callback for the given sequence of keys.
+    pub fn clear_global_chord(&mut self, sequence: &[Event]) {
+        self.chords.remove(sequence);
+    }
+
+    /// Sets the time allowed between two keys of a sequence.
+    ///
+    /// When it expires, the keys pressed so far are dropped. Use `None` to
+    /// wait forever.
+    ///
+    /// Defaults to one second.
+    pub fn set_chord_timeout(&mut self, timeout: Option<std::time::Duration>) {
+        self.chords.set_timeout(timeout);
+    }
+
+    /// Shows the keys of a pending sequence in the bottom-right corner.
+    ///
+    /// Disabled by default.
+    pub fn set_chord_indicator(&mut self, show: bool) {
+        self.chords.set_show_pending(show);
+    }
+
+    /// Sets the translations applied to incoming events.
+    ///
+    /// Events are translated before anything else sees them, including
+    /// global callbacks and chords. This can fix keys sent by some
+    /// terminals, without changing the backend.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// use cursive_core::keymap::EventMap;
+    ///
+    /// let mut map = EventMap::new();
+    /// map.load_config(&serde_json::json!({"unknown-27,91,49,59,53,65": "ctrl-up"}))
+    ///     .unwrap();
+    ///
+    /// let mut siv = Cursive::new();
+    /// siv.set_event_map(map);
+    /// ```
+    pub fn set_event_map(&mut self, event_map: EventMap) {
+        self.event_map = event_map;
+    }
+
+    /// Returns the translations applied to incoming events.
+    pub fn event_map(&self) -> &EventMap {
+        &self.event_map
+    }
+
+    /// Returns a mutable reference to the translations applied to incoming events.
+    pub fn event_map_mut(&mut self) -> &mut EventMap {
+        &mut self.event_map
+    }
+
+    /// Returns the keys pressed so far for a sequence.
+    ///
+    /// Returns an empty slice if no sequence is in progress.
+    pub fn pending_chord(&self) -> &[Event] {
+        self.chords.pending()
+    }
+
+    /// Fetches the type name of a view in the tree.
+    pub fn debug_name(&mut self, name: &str) -> Option<&'static str> {
+        let mut result = None;
+
+        self.root.call_on_any(
+            &view::Selector::Name(name),
+            &mut |v: &mut dyn crate::View| result = Some(v.type_name()),
+        );
+        result
+    }
+
+    /// Lists all named views in the tree, with their types.
+    ///
+    /// Views are listed in the tree order. Names used more than once will
+    /// appear multiple times.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::{Cursive, views};
+    /// # use cursive_core::traits::*;
+    /// let mut siv = Cursive::new();
+    ///
+    /// siv.add_layer(views::TextView::new("Text #1").with_name("text"));
+    ///
+    /// let names = siv.dump_names();
+    /// assert_eq!(names.len(), 1);
+    /// assert_eq!(names[0].0, "text");
+    /// assert!(names[0].1.ends_with("TextView"));
+    /// ```
+    pub fn dump_names(&mut self) -> Vec<(String, &'static str)> {
+        collect_names(&mut self.root)
+    }
+
+    /// Lists the views along the focus path, from the root to the focused view.
+    ///
+    /// Each view comes with its type, and with its name if it is wrapped in a
+    /// [`NamedView`]. The `NamedView` itself is not listed.
+    ///
+    /// Use [`view::Selector::Focused`] to run a callback on the focused view.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::{Cursive, views};
+    /// # use cursive_core::traits::*;
+    /// let mut siv = Cursive::new();
+    ///
+    /// siv.add_layer(views::Dialog::around(views::EditView::new().with_name("edit")));
+    ///
+    /// let path = siv.focused_view_path();
+    /// let (name, type_name) = path.last().unwrap();
+    /// assert_eq!(name.as_deref(), Some("edit"));
+    /// assert!(type_name.ends_with("EditView"));
+    /// ```
+    pub fn focused_view_path(&mut self) -> Vec<(Option<String>, &'static str)> {
+        let mut views = Vec::new();
+        view::call_on_focus_path(&mut self.root, &mut |v| {
+            views.push((view_name(v), v.type_name()));
+        });
+
+        let mut path = Vec::new();
+        let mut pending_name = None;
+        for (name, type_name) in views.into_iter().rev() {
+            match name {
+                // Give the name to the wrapped view instead.
+                Some(name) => pending_name = Some(name),
+                None => path.push((pending_name.take(), type_name)),
+            }
+        }
+        path
+    }
+
+    /// Enables or disables warnings about duplicate view names.
+    ///
+    /// When enabled, every call to [`Cursive::add_layer`] or
+    /// [`Cursive::add_fullscreen_layer`] will check the names used by the new
+    /// layer, and log a warning for each name already used in the tree.
+    ///
+    /// This walks the entire view tree, so it is mostly useful while debugging.
+    ///
+    /// Disabled by default.
+    pub fn set_warn_duplicate_names(&mut self, enabled: bool) {
+        self.warn_duplicate_names = enabled;
+    }
+
+    /// Enables or disables warnings about low-contrast colors.
+    ///
+    /// When enabled, a warning is logged for each style of the theme palette,
+    /// and for each color pair drawn on screen, with a contrast ratio below
+    /// `threshold` (see [`style::contrast_ratio`]). Colors are compared as the
+    /// current backend displays them, so a theme may be fine with true colors
+    /// but not with 16 colors. [`style::MIN_CONTRAST_RATIO`] is a good
+    /// threshold for regular text.
+    ///
+    /// Each issue is only reported once. This scans the entire screen after
+    /// each draw, so it is mostly useful while debugging.
+    ///
+    /// Disabled by default.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// use cursive_core::style::MIN_CONTRAST_RATIO;
+    ///
+    /// let mut siv = Cursive::new();
+    /// siv.set_contrast_warnings(Some(MIN_CONTRAST_RATIO));
+    /// ```
+    pub fn set_contrast_warnings(&mut self, threshold: Option<f32>) {
+        self.contrast_check = threshold.map(style::ContrastCheck::new);
+    }
+
+    // Reports low-contrast colors from the last draw, if enabled.
+    pub(crate) fn check_contrast(
+        &mut self,
+        buffer: &PrintBuffer,
+        resolution: style::ColorResolution,
+    ) {
+        if let Some(check) = self.contrast_check.as_mut() {
+            check.check(&self.theme.palette, buffer, resolution);
+        }
+    }
+
+    /// Returns when the input event being processed was polled from the backend.
     ///
-    /// Will be triggered on the given key press when no view catches it.
+    /// Returns `None` outside of input event processing, for example in
+    /// callbacks, timers or for events injected by the application.
+    ///
+    /// See the [`profiling`](crate::profiling) module.
+    pub fn event_timestamp(&self) -> Option<std::time::Instant> {
+        self.event_timestamp
+    }
+
+    /// Returns the input latency metrics collected so far.
+    ///
+    /// See the [`profiling`](crate::profiling) module.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use cursive_core::*;
-    /// let mut siv = Cursive::new();
+    /// # use cursive_core::Cursive;
+    /// let siv = Cursive::new();
     ///
-    /// siv.add_global_callback('q', |s| s.quit());
+    /// // No frame was presented yet.
+    /// assert_eq!(siv.latency_stats().frames(), 0);
+    /// assert_eq!(siv.latency_stats().mean(), None);
     /// ```
-    pub fn add_global_callback<F, E: Into<Event>>(&mut self, event: E, cb: F)
-    where
-        F: FnMut(&mut Cursive) + 'static + Send + Sync,
-    {
-        self.set_on_post_event(event.into(), cb);
+    pub fn latency_stats(&self) -> &profiling::LatencyStats {
+        self.profiler.stats()
     }
 
-    /// Registers a callback for ignored events.
-    ///
-    /// This is the same as `add_global_callback`, but can register any `EventTrigger`.
-    pub fn set_on_post_event<F, E>(&mut self, trigger: E, cb: F)
-    where
-        F: FnMut(&mut Cursive) + 'static + Send + Sync,
-        E: Into<crate::event::EventTrigger>,
-    {
-        self.root.set_on_event(trigger, crate::immut1!(cb));
+    /// Clears the input latency metrics collected so far.
+    pub fn reset_latency_stats(&mut self) {
+        self.profiler.reset();
     }
 
-    /// Registers a priority callback.
+    /// Registers a callback to run after each frame handling input is presented.
     ///
-    /// If an event matches the given trigger, it will not be sent to the view
-    /// tree and will go to the given callback instead.
+    /// The callback receives the latency metrics for this frame.
     ///
-    /// Note that regular "post-event" callbacks will also be skipped for
-    /// these events.
-    pub fn set_on_pre_event<F, E>(&mut self, trigger: E, cb: F)
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// let mut siv = Cursive::new();
+    /// siv.on_frame(|_, frame| {
+    ///     if frame.max_latency.as_millis() > 100 {
+    ///         log::warn!("Slow frame: {:?}", frame.max_latency);
+    ///     }
+    /// });
+    /// ```
+    pub fn on_frame<F>(&mut self, cb: F)
     where
-        F: FnMut(&mut Cursive) + 'static + Send + Sync,
-        E: Into<crate::event::EventTrigger>,
+        F: FnMut(&mut Cursive, &profiling::FrameMetrics) + Send + 'static,
     {
-        self.root.set_on_pre_event(trigger, crate::immut1!(cb));
+        self.frame_hooks.push(Box::new(cb));
     }
 
-    /// Registers an inner priority callback.
-    ///
-    /// See [`OnEventView`] for more information.
-    ///
-    /// [`OnEventView`]: crate::views::OnEventView::set_on_pre_event_inner()
-    pub fn set_on_pre_event_inner<E, F>(&mut self, trigger: E, cb: F)
-    where
-        E: Into<crate::event::EventTrigger>,
-        F: Fn(&Event) -> Option<EventResult> + 'static + Send + Sync,
-    {
-        self.root
-            .set_on_pre_event_inner(trigger, move |_, event| cb(event));
+    /// Removes all the callbacks registered with [`Cursive::on_frame`].
+    pub fn clear_frame_hooks(&mut self) {
+        self.frame_hooks.clear();
     }
 
-    /// Registers an inner callback.
-    ///
-    /// See [`OnEventView`] for more information.
-    ///
-    /// [`OnEventView`]: crate::views::OnEventView::set_on_event_inner()
-    pub fn set_on_event_inner<E, F>(&mut self, trigger: E, cb: F)
-    where
-        E: Into<crate::event::EventTrigger>,
-        F: Fn(&Event) -> Option<EventResult> + 'static + Send + Sync,
-    {
-        self.root
-            .set_on_event_inner(trigger, move |_, event| cb(event));
+    // Sets the timestamp of the input event about to be processed.
+    pub(crate) fn set_event_timestamp(&mut self, timestamp: Option<std::time::Instant>) {
+        if let Some(timestamp) = timestamp {
+            self.profiler.event_polled(timestamp);
+        }
+        self.event_timestamp = timestamp;
     }
 
-    /// Sets the only global callback for the given event.
-    ///
-    /// Any other callback for this event will be removed.
-    ///
-    /// See also [`Cursive::add_global_callback`].
-    pub fn set_global_callback<F, E: Into<Event>>(&mut self, event: E, cb: F)
-    where
-        F: FnMut(&mut Cursive) + 'static + Send + Sync,
-    {
-        let event = event.into();
-        self.clear_global_callbacks(event.clone());
-        self.add_global_callback(event, cb);
+    // Records that a frame was just presented, and runs the frame hooks.
+    pub(crate) fn frame_presented(&mut self) {
+        let Some(frame) = self.profiler.frame_presented(std::time::Instant::now()) else {
+            return;
+        };
+
+        let mut hooks = std::mem::take(&mut self.frame_hooks);
+        for hook in &mut hooks {
+            hook(self, &frame);
+        }
+
+        // Keep hooks registered while the previous ones were running.
+        hooks.append(&mut self.frame_hooks);
+        self.frame_hooks = hooks;
     }
 
-    /// Fetches the type name of a view in the tree.
-    pub fn debug_name(&mut self, name: &str) -> Option<&'static str> {
-        let mut result = None;
+    // Starts the dismiss delay of the dialogs in a new layer of the current screen.
+    fn start_auto_dismiss(&mut self, view: &mut dyn View) {
+        let screen = self.active_screen();
+        for (name, delay) in views::Dialog::start_auto_dismiss(view) {
+            self.set_timeout(delay, move |s| s.remove_named_layer(screen, &name));
+        }
+    }
 
-        self.root.call_on_any(
-            &view::Selector::Name(name),
-            &mut |v: &mut dyn crate::View| result = Some(v.type_name()),
-        );
-        result
+    fn check_duplicate_names(&mut self, view: &mut dyn View) {
+        let mut known: Vec<String> = self
+            .dump_names()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+
+        for (name, type_name) in collect_names(view) {
+            if known.contains(&name) {
+                log::warn!("Duplicate view name `{name}` (used by a {type_name})");
+            } else {
+                known.push(name);
+            }
+        }
     }
 
     /// Removes any callback tied to the given event.
@@ -769,9 +1966,137 @@ impl Cursive {
     where
         T: IntoBoxedView,
     {
+        let mut view = view.into_boxed_view();
+        if self.warn_duplicate_names {
+            self.check_duplicate_names(&mut *view);
+        }
+        self.start_auto_dismiss(&mut *view);
         self.screen_mut().add_layer(view);
     }
 
+    /// Adds a layer to the current screen, and removes it after `delay`.
+    ///
+    /// This is useful for splash screens or short notices. The layer can
+    /// still be removed earlier, for example with [`Cursive::pop_layer`];
+    /// cancelling the returned handle keeps it on screen.
+    ///
+    /// The view is wrapped in a [`NamedView`] to find it later.
+    ///
+    /// [`NamedView`]: crate::views::NamedView
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::{views, Cursive};
+    /// use std::time::Duration;
+    ///
+    /// let mut siv = Cursive::new();
+    ///
+    /// siv.add_transient_layer(
+    ///     views::TextView::new("Welcome!"),
+    ///     Duration::from_secs(3),
+    /// );
+    /// ```
+    pub fn add_transient_layer<T>(&mut self, view: T, delay: std::time::Duration) -> TimerHandle
+    where
+        T: IntoBoxedView,
+    {
+        let name = format!("{TRANSIENT_LAYER_PREFIX}{}", self.transient_layers);
+        self.transient_layers += 1;
+
+        let mut view = view.into_boxed_view();
+        if self.warn_duplicate_names {
+            self.check_duplicate_names(&mut *view);
+        }
+        self.start_auto_dismiss(&mut *view);
+
+        let screen = self.active_screen();
+        self.screen_mut().add_layer(views::NamedView::new(
+            name.clone(),
+            views::BoxedView::new(view),
+        ));
+
+        self.set_timeout(delay, move |s| s.remove_named_layer(screen, &name))
+    }
+
+    /// Shows a busy overlay while `task` runs in the background.
+    ///
+    /// The overlay is a [`BusyView`] covering the current screen: it dims the
+    /// layers below and keeps them from receiving events, while showing a
+    /// spinner next to `message`. It is removed once the task returns, or is
+    /// cancelled through the returned handle.
+    ///
+    /// The task can use [`JobContext::cb_sink`] to send its result back to
+    /// the event loop.
+    ///
+    /// [`BusyView`]: crate::views::BusyView
+    /// [`JobContext::cb_sink`]: crate::jobs::JobContext::cb_sink
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use cursive_core::{views, Cursive};
+    /// let mut siv = Cursive::new();
+    ///
+    /// siv.with_busy_overlay("Saving...", |ctx| {
+    ///     std::thread::sleep(std::time::Duration::from_secs(2));
+    ///     ctx.cb_sink()
+    ///         .send(Box::new(|s| s.add_layer(views::Dialog::info("Saved!"))))
+    ///         .ok();
+    /// });
+    /// ```
+    pub fn with_busy_overlay<S, F>(&mut self, message: S, task: F) -> JobHandle
+    where
+        S: Into<StyledString>,
+        F: FnOnce(&JobContext) + Send + 'static,
+    {
+        let name = format!("{TRANSIENT_LAYER_PREFIX}{}", self.transient_layers);
+        self.transient_layers += 1;
+
+        let screen = self.active_screen();
+        self.screen_mut().add_layer(views::stack_view::Fullscreen(
+            views::stack_view::Transparent(views::NamedView::new(
+                name.clone(),
+                views::BusyView::new(message),
+            )),
+        ));
+
+        let job = jobs::spawn_with_progress(&self.cb_sink, task, {
+            let name = name.clone();
+            move |s, ()| s.remove_named_layer(screen, &name)
+        });
+
+        // Redraw regularly to animate the spinner, and notice a cancelled job.
+        let timer = TimerHandle::default();
+        self.timers.add_with_handle(
+            views::BusyView::SPINNER_INTERVAL,
+            Some(views::BusyView::SPINNER_INTERVAL),
+            Box::new({
+                let job = job.clone();
+                let timer = timer.clone();
+                move |s| {
+                    if job.is_finished() || job.is_cancelled() {
+                        timer.cancel();
+                        s.remove_named_layer(screen, &name);
+                    }
+                }
+            }),
+            timer,
+        );
+
+        job
+    }
+
+    // Removes the layer with the given name from a screen, if it is still there.
+    pub(crate) fn remove_named_layer(&mut self, screen: ScreenId, name: &str) {
+        let Some(stack) = self.root.get_inner_mut().get_screen_mut(screen) else {
+            return;
+        };
+        if let Some(position) = stack.find_layer_from_name(name) {
+            stack.remove_layer(position);
+        }
+    }
+
     /// Adds a new full-screen layer to the current screen.
     ///
     /// Fullscreen layers have no shadow.
@@ -779,9 +2104,67 @@ impl Cursive {
     where
         T: IntoBoxedView,
     {
+        let mut view = view.into_boxed_view();
+        if self.warn_duplicate_names {
+            self.check_duplicate_names(&mut *view);
+        }
+        self.start_auto_dismiss(&mut *view);
         self.screen_mut().add_fullscreen_layer(view);
     }
 
+    /// Adds a new modal layer to the current screen, shading the layers behind.
+    ///
+    /// See [`StackView::add_modal_layer`](views::StackView::add_modal_layer).
+    pub fn add_modal_layer<T>(&mut self, view: T)
+    where
+        T: IntoBoxedView,
+    {
+        let mut view = view.into_boxed_view();
+        if self.warn_duplicate_names {
+            self.check_duplicate_names(&mut *view);
+        }
+        self.start_auto_dismiss(&mut *view);
+        self.screen_mut().add_modal_layer(view);
+    }
+
+    /// Shows a context menu at the given position on the current screen.
+    ///
+    /// The menu opens below and to the right of `position`, but flips to the
+    /// left or upward when it would not fit on the screen.
+    ///
+    /// `position` is relative to the screen, like mouse positions received
+    /// by the views. Empty menus are not shown.
+    ///
+    /// See also [`OnEventView::on_context_menu`](views::OnEventView::on_context_menu).
+    pub fn show_context_menu(&mut self, position: Vec2, tree: menu::Tree) {
+        let tree = tree.generate(self).unwrap_or(tree);
+        if tree.is_empty() {
+            return;
+        }
+
+        let available = self.root_size(self.screen_size());
+        let mut popup = views::MenuPopup::new(Arc::new(tree));
+        let size = popup.required_size(available);
+
+        // Flip the menu around the position if it doesn't fit after it.
+        let flip = |position: usize, size: usize, available: usize| {
+            if position + size <= available {
+                position
+            } else if position + 1 >= size {
+                position + 1 - size
+            } else {
+                available.saturating_sub(size)
+            }
+        };
+        let offset = Vec2::new(
+            flip(position.x, size.x, available.x),
+            flip(position.y, size.y, available.y),
+        );
+
+        self.screen_mut()
+            .add_layer_at(Position::absolute(offset), popup);
+    }
+
     /// Convenient method to remove a layer from the current screen.
     pub fn pop_layer(&mut self) -> Option<Box<dyn View>> {
         self.screen_mut().pop_layer()
@@ -794,10 +2177,24 @@ impl Cursive {
 
     /// Processes an event.
     ///
+    /// * The event is first translated by the event map.
     /// * If the menubar is active, it will be handled the event.
     /// * The view tree will be handled the event.
+    /// * If ignored, it may open the command line (see `set_command_handler`).
+    /// * If ignored, menu accelerators will be checked for this event.
     /// * If ignored, global_callbacks will be checked for this event.
     pub fn on_event(&mut self, event: Event) {
+        let event = self.event_map.translate(event);
+
+        if self.process_chord(&event) {
+            return;
+        }
+
+        if self.is_command_line_open() && !matches!(event, Event::Mouse { .. }) {
+            self.on_command_line_event(event);
+            return;
+        }
+
         if let Event::Mouse {
             event, position, ..
         } = event
@@ -818,8 +2215,29 @@ impl Cursive {
 
             let result = View::on_event(&mut self.root, event.relativized((0, offset)));
 
-            if let EventResult::Consumed(Some(cb)) = result {
-                cb(self);
+            match result {
+                EventResult::Consumed(Some(cb)) => cb(self),
+                EventResult::Ignored => {
+                    if self.check_command_line(&event) {
+                        return;
+                    }
+
+                    // Menu accelerators only apply when no menu is open.
+                    if !self.menubar.has_submenu() {
+                        if let Some(cb) = self.menubar.find_accelerator(&event) {
+                            cb(self);
+                            return;
+                        }
+                    }
+
+                    // Views without paste support still get the text, one key at a time.
+                    if let Event::Paste(text) = event {
+                        for event in paste_keys(&text) {
+                            self.on_event(event);
+                        }
+                    }
+                }
+                EventResult::Consumed(None) => (),
             }
         }
     }
@@ -838,6 +2256,32 @@ impl Cursive {
         }
     }
 
+    /// Try to process a single injected event.
+    ///
+    /// Returns `true` if an event was processed, `false` if there was
+    /// nothing to process.
+    pub(crate) fn process_injected_event(&mut self) -> bool {
+        match self.event_source.try_recv() {
+            Ok(event) => {
+                self.on_event(event);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Blocks until a callback, an injected event or an event source message
+    /// is available, or until `timeout` expires.
+    pub(crate) fn wait_for_messages(&self, timeout: std::time::Duration) {
+        let mut select = crossbeam_channel::Select::new();
+        select.recv(&self.cb_source);
+        select.recv(&self.event_source);
+        self.event_sources.register(&mut select);
+
+        // We only want to wake up, the messages are received later.
+        let _ = select.ready_timeout(timeout);
+    }
+
     /// Returns `true` until [`quit(&mut self)`] is called.
     ///
     /// [`quit(&mut self)`]: #method.quit
@@ -905,6 +2349,56 @@ impl Cursive {
         self.running = false;
     }
 
+    /// Registers a callback to run when the event loop stops.
+    ///
+    /// Hooks run in registration order, after the last event was processed
+    /// but before the backend is closed, while the view tree is still intact.
+    /// This is a good place to persist application state, like the window
+    /// layout or scroll positions.
+    ///
+    /// Hooks stay registered and will run again at the end of the next run.
+    ///
+    /// They are run by [`CursiveRunner::run`]; when stepping the event loop
+    /// manually, call [`Cursive::run_shutdown_hooks`] before dropping the
+    /// runner.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// let mut siv = Cursive::new();
+    /// siv.on_pre_shutdown(|s| {
+    ///     let layers = s.screen().len();
+    ///     // Save the number of layers somewhere...
+    /// #   let _ = layers;
+    /// });
+    /// ```
+    pub fn on_pre_shutdown<F>(&mut self, cb: F)
+    where
+        F: FnMut(&mut Cursive) + Send + 'static,
+    {
+        self.shutdown_hooks.push(Box::new(cb));
+    }
+
+    /// Removes all the hooks registered with [`Cursive::on_pre_shutdown`].
+    pub fn clear_pre_shutdown(&mut self) {
+        self.shutdown_hooks.clear();
+    }
+
+    /// Runs the hooks registered with [`Cursive::on_pre_shutdown`].
+    ///
+    /// This is called automatically at the end of [`CursiveRunner::run`].
+    pub fn run_shutdown_hooks(&mut self) {
+        let mut hooks = std::mem::take(&mut self.shutdown_hooks);
+        for hook in &mut hooks {
+            hook(self);
+        }
+
+        // Keep hooks registered while the previous ones were running.
+        hooks.append(&mut self.shutdown_hooks);
+        self.shutdown_hooks = hooks;
+    }
+
     /// Does not do anything.
     pub fn noop(&mut self) {
         // foo
@@ -915,22 +2409,30 @@ impl Cursive {
     /// *It will clear out this `Cursive` instance* and save everything, including:
     /// * The view tree
     /// * Callbacks
+    /// * Timers
+    /// * Event sources
     /// * Menubar
     /// * User data
     /// * Callback sink
+    /// * Event sink
     ///
     /// After calling this, the cursive object will be as if newly created.
     pub fn dump(&mut self) -> crate::Dump {
         let (cb_sink, cb_source) = crossbeam_channel::unbounded();
+        let (event_sink, event_source) = crossbeam_channel::unbounded();
         let root =
             views::OnEventView::new(views::ScreensView::single_screen(views::StackView::new()));
         Dump {
             cb_sink: std::mem::replace(&mut self.cb_sink, cb_sink),
             cb_source: std::mem::replace(&mut self.cb_source, cb_source),
+            event_sink: std::mem::replace(&mut self.event_sink, event_sink),
+            event_source: std::mem::replace(&mut self.event_source, event_source),
             fps: self.fps.take(),
             menubar: std::mem::take(&mut self.menubar),
             root_view: std::mem::replace(&mut self.root, root),
             theme: std::mem::take(&mut self.theme),
+            timers: std::mem::take(&mut self.timers),
+            event_sources: std::mem::take(&mut self.event_sources),
             user_data: std::mem::replace(&mut self.user_data, Box::new(())),
         }
     }
@@ -941,24 +2443,376 @@ impl Cursive {
     /// In particular:
     /// * All current views will be dropped, replaced by the dump.
     /// * All callbacks will be replaced.
+    /// * All timers will be replaced.
+    /// * All event sources will be replaced.
     /// * Menubar will be replaced.
     /// * User Data will be replaced.
     /// * The callback channel will be replaced - any previous call to
     ///   `cb_sink` on this instance will be disconnected.
+    /// * The event channel will be replaced as well.
     pub fn restore(&mut self, dump: Dump) {
         self.cb_sink = dump.cb_sink;
         self.cb_source = dump.cb_source;
+        self.event_sink = dump.event_sink;
+        self.event_source = dump.event_source;
         self.fps = dump.fps;
         self.menubar = dump.menubar;
         self.root = dump.root_view;
         self.theme = dump.theme;
+        self.timers = dump.timers;
+        self.event_sources = dump.event_sources;
         self.user_data = dump.user_data;
         self.clear();
     }
 }
 
+// Returns the key events equivalent to typing the given text.
+fn paste_keys(text: &str) -> impl Iterator<Item = Event> + '_ {
+    text.chars().filter_map(|c| match c {
+        '\n' => Some(Event::Key(event::Key::Enter)),
+        '\t' => Some(Event::Key(event::Key::Tab)),
+        c if c.is_control() => None,
+        c => Some(Event::Char(c)),
+    })
+}
+
+// Returns the names used in the given view tree, with the type of the named views.
+fn collect_names(view: &mut dyn View) -> Vec<(String, &'static str)> {
+    let names = std::cell::RefCell::new(Vec::new());
+    let mut type_names = Vec::new();
+
+    view.call_on_any(
+        &view::Selector::NameMatches(&|name| {
+            names.borrow_mut().push(name.to_string());
+            true
+        }),
+        &mut |v| type_names.push(inner_type_name(v.type_name())),
+    );
+
+    names.into_inner().into_iter().zip(type_names).collect()
+}
+
+// Returns the name of the given view, if it is a `NamedView`.
+fn view_name(view: &mut dyn View) -> Option<String> {
+    let type_name = view.type_name();
+    if inner_type_name(type_name) == type_name {
+        return None;
+    }
+
+    // A `NamedView` checks its own name before looking inside.
+    let name = std::cell::RefCell::new(None);
+    view.call_on_any(
+        &view::Selector::NameMatches(&|n| {
+            name.borrow_mut().get_or_insert_with(|| n.to_string());
+            false
+        }),
+        &mut |_| (),
+    );
+    name.into_inner()
+}
+
+// Turns `NamedView<T>` into `T`.
+fn inner_type_name(type_name: &'static str) -> &'static str {
+    let wrapper = std::any::type_name::<views::NamedView<()>>();
+    let prefix = wrapper.trim_end_matches("()>");
+
+    type_name
+        .strip_prefix(prefix)
+        .and_then(|inner| inner.strip_suffix('>'))
+        .unwrap_or(type_name)
+}
+
 // Callback blueprint
 crate::fn_blueprint!("Cursive.quit", |_config, _context| {
     let cb: std::sync::Arc<dyn Fn(&mut Cursive) + Send + Sync> = std::sync::Arc::new(|s| s.quit());
     Ok(cb)
 });
+
+#[cfg(test)]
+mod tests {
+    use super::Cursive;
+    use crate::traits::Nameable;
+    use crate::view::{Finder, Selector};
+    use crate::views::{EditView, LinearLayout, TextView};
+    use crate::Vec2;
+
+    #[test]
+    fn render_view_to_styled() {
+        let mut siv = Cursive::new();
+        siv.add_fullscreen_layer(
+            LinearLayout::vertical()
+                .child(TextView::new("first").with_name("first"))
+                .child(TextView::new("漢字\nsecond").with_name("second")),
+        );
+        siv.needs_clear = true;
+        siv.layout(Vec2::new(10, 4));
+
+        let output = siv.render_view_to_styled("second").unwrap();
+        assert_eq!(output.source(), "漢字  \nsecond");
+
+        // The actual screen still needs to be cleared.
+        assert!(siv.needs_clear);
+
+        assert!(siv.render_view_to_styled("third").is_err());
+    }
+
+    #[test]
+    fn focused_view_path() {
+        let mut siv = Cursive::new();
+        siv.add_layer(
+            LinearLayout::vertical()
+                .child(EditView::new().with_name("first"))
+                .child(EditView::new().with_name("second"))
+                .with_name("layout"),
+        );
+        siv.focus_name("second").unwrap();
+
+        let path = siv.focused_view_path();
+        let names: Vec<_> = path
+            .iter()
+            .filter_map(|(name, _)| name.as_deref())
+            .collect();
+        assert_eq!(names, ["layout", "second"]);
+        assert!(path.last().unwrap().1.ends_with("EditView"));
+
+        siv.call_on(&Selector::Focused, |v: &mut EditView| {
+            v.set_content("focused")
+        });
+        let content = siv.call_on_name("second", |v: &mut EditView| v.get_content());
+        assert_eq!(content.unwrap().as_str(), "focused");
+
+        // Ancestors of the focused view can be found too.
+        let focus = siv.call_on(&Selector::Focused, |v: &mut LinearLayout| {
+            v.get_focus_index()
+        });
+        assert_eq!(focus, Some(1));
+
+        // There is only one focused view.
+        let mut count = 0;
+        siv.screen_mut()
+            .call_on_all(&Selector::Focused, |_: &mut EditView| count += 1);
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn dirty_area() {
+        use crate::{buffer::PrintBuffer, views::TextContent, Rect};
+        use parking_lot::RwLock;
+
+        let content = TextContent::new("first");
+        let mut siv = Cursive::new();
+        siv.add_fullscreen_layer(
+            LinearLayout::vertical()
+                .child(TextView::new_with_content(content.clone()))
+                .child(TextView::new("second")),
+        );
+
+        let size = Vec2::new(10, 4);
+        let buffer = RwLock::new(PrintBuffer::new());
+        buffer.write().resize(size);
+
+        siv.layout(size);
+        siv.draw(&buffer, None);
+        assert_eq!(siv.dirty_area(), None);
+
+        // Only the changed view needs to be redrawn.
+        content.set_content("changed");
+        let area = siv.dirty_area().unwrap();
+        assert_eq!(area, Rect::from_size((0, 0), (6, 1)));
+
+        siv.layout(size);
+        buffer.write().flush(&crate::backend::Dummy);
+        siv.draw(&buffer, Some(area));
+        assert_eq!(buffer.read().damage(), Some(area));
+    }
+
+    #[test]
+    fn screenshot() {
+        let mut siv = Cursive::new();
+        siv.add_fullscreen_layer(TextView::new("Hello"));
+        siv.layout(Vec2::new(6, 2));
+
+        let screenshot = siv.screenshot();
+        assert_eq!(screenshot.size(), Vec2::new(6, 2));
+        assert_eq!(screenshot.to_styled_string().source(), "Hello \n      ");
+
+        // The actual screen still needs to be drawn.
+        assert!(siv.dirty_area().is_some());
+    }
+
+    #[test]
+    fn ignored_paste() {
+        use crate::event::Event;
+        use crate::views::Dialog;
+
+        let mut siv = Cursive::new();
+        siv.add_layer(Dialog::around(EditView::new().with_name("edit")));
+        siv.on_event(Event::Paste("hi".into()));
+        siv.call_on_name("edit", |view: &mut EditView| {
+            assert_eq!(*view.get_content(), "hi");
+        });
+
+        // Views ignoring the paste get individual keys instead.
+        siv.pop_layer();
+        siv.add_layer(TextView::new(""));
+        siv.add_global_callback('!', |s| s.quit());
+        siv.on_event(Event::Paste("a!".into()));
+        assert!(!siv.is_running());
+    }
+
+    #[test]
+    fn transient_layers() {
+        use std::time::Duration;
+
+        let mut siv = Cursive::new();
+        siv.add_layer(TextView::new("Back"));
+        siv.add_transient_layer(TextView::new("Splash"), Duration::ZERO);
+        let kept = siv.add_transient_layer(TextView::new("Kept"), Duration::ZERO);
+        kept.cancel();
+        assert_eq!(siv.screen().len(), 3);
+
+        siv.process_timers();
+        assert_eq!(siv.screen().len(), 2);
+        assert!(siv
+            .screen_mut()
+            .find_layer_from_name("_cursive_transient_1")
+            .is_some());
+    }
+
+    #[test]
+    fn shutdown_hooks() {
+        use std::sync::{Arc, Mutex};
+
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut siv = Cursive::new();
+        siv.add_layer(TextView::new("Hi").with_name("text"));
+        for i in 0..2 {
+            let log = Arc::clone(&log);
+            siv.on_pre_shutdown(move |s| {
+                // The view tree is still intact.
+                let len = s.call_on_name("text", |v: &mut TextView| v.get_content().source().len());
+                log.lock().unwrap().push((i, len));
+            });
+        }
+
+        siv.cb_sink().send(Box::new(|s| s.quit())).unwrap();
+        siv.run_dummy();
+        assert_eq!(*log.lock().unwrap(), [(0, Some(2)), (1, Some(2))]);
+
+        siv.clear_pre_shutdown();
+        siv.run_shutdown_hooks();
+        assert_eq!(log.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn event_map() {
+        use crate::event::Event;
+
+        let mut siv = Cursive::new();
+        siv.add_layer(EditView::new().with_name("edit"));
+        siv.event_map_mut()
+            .add(Event::Unknown(vec![195, 165]), Event::Char('a'));
+
+        siv.on_event(Event::Unknown(vec![195, 165]));
+        siv.on_event(Event::Char('b'));
+        let content = siv.call_on_name("edit", |v: &mut EditView| v.get_content());
+        assert_eq!(content.as_deref().map(String::as_str), Some("ab"));
+    }
+
+    #[test]
+    fn busy_overlay() {
+        use crate::event::Event;
+        use std::time::Duration;
+
+        let mut siv = Cursive::new();
+        siv.add_layer(EditView::new().with_name("edit"));
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        siv.with_busy_overlay("Wait", move |_| {
+            rx.recv().ok();
+        });
+        assert_eq!(siv.screen().len(), 2);
+
+        // The layers below don't get any event.
+        siv.on_event(Event::Char('a'));
+        let content = siv.call_on_name("edit", |v: &mut EditView| v.get_content());
+        assert_eq!(content.as_deref().map(String::as_str), Some(""));
+
+        tx.send(()).unwrap();
+        let cb = siv.cb_source.recv_timeout(Duration::from_secs(5)).unwrap();
+        cb(&mut siv);
+        assert_eq!(siv.screen().len(), 1);
+    }
+    #[test]
+    fn batch() {
+        use crate::views::SelectView;
+        use std::sync::{Arc, Mutex};
+
+        let selected = Arc::new(Mutex::new(Vec::new()));
+
+        let mut siv = Cursive::new();
+        siv.add_layer(
+            SelectView::<usize>::new()
+                .on_select({
+                    let selected = Arc::clone(&selected);
+                    move |_, &i| selected.lock().unwrap().push(i)
+                })
+                .with_name("select"),
+        );
+
+        siv.batch(|s| {
+            for i in 0..100 {
+                let cb = s
+                    .call_on_name("select", |v: &mut SelectView<usize>| {
+                        v.add_item(i.to_string(), i);
+                        v.set_selection(i)
+                    })
+                    .unwrap();
+                cb(s);
+            }
+            assert!(selected.lock().unwrap().is_empty());
+
+            // Callbacks from the sink still run immediately.
+            let selected = Arc::clone(&selected);
+            s.cb_sink()
+                .send(Box::new(move |_| selected.lock().unwrap().push(1000)))
+                .unwrap();
+            assert!(s.process_callback());
+        });
+
+        assert_eq!(*selected.lock().unwrap(), [1000, 99]);
+    }
+
+    #[test]
+    fn batch_from_callbacks() {
+        use crate::event::{Event, Key};
+        use crate::views::SelectView;
+        use std::sync::{Arc, Mutex};
+
+        let selected = Arc::new(Mutex::new(Vec::new()));
+
+        let mut siv = Cursive::new();
+        siv.add_layer(
+            SelectView::<usize>::new()
+                .with_all((0..10).map(|i| (i.to_string(), i)))
+                .on_select_full({
+                    let selected = Arc::clone(&selected);
+                    move |_, _, previous, &i| selected.lock().unwrap().push((previous.copied(), i))
+                }),
+        );
+        siv.add_global_callback('b', Cursive::begin_update);
+        siv.add_global_callback('e', Cursive::end_update);
+
+        // The update can span several events, and end from a global callback.
+        siv.on_event(Event::Char('b'));
+        for _ in 0..3 {
+            siv.on_event(Event::Key(Key::Down));
+        }
+        assert!(selected.lock().unwrap().is_empty());
+        siv.on_event(Event::Char('e'));
+        assert!(!siv.is_updating());
+
+        // A single call, from the selection before the update.
+        assert_eq!(*selected.lock().unwrap(), [(Some(0), 3)]);
+    }
+}
@@ -1,7 +1,7 @@
-use crate::{backend, buffer, event, Cursive, Vec2};
+use crate::{backend, buffer, event, Cursive, Rect, Vec2};
 use parking_lot::RwLock;
 use std::borrow::{Borrow, BorrowMut};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 // How long we wait between two empty input polls
 const INPUT_POLL_DELAY_MS: u64 = 30;
@@ -19,6 +19,8 @@ pub struct CursiveRunner<C> {
     buffer: RwLock<buffer::PrintBuffer>,
 
     boring_frame_count: u32,
+    // Set when an input event was processed since the last frame.
+    handled_input: bool,
     // Last layer sizes of the stack view.
     // If it changed, clear the screen.
     last_sizes: Vec<Vec2>,
@@ -52,6 +54,7 @@ impl<C> CursiveRunner<C> {
             backend,
             buffer: RwLock::new(buffer::PrintBuffer::new()),
             boring_frame_count: 0,
+            handled_input: false,
             last_sizes: Vec::new(),
         }
     }
@@ -84,7 +87,8 @@ where
         }
     }
 
-    fn draw(&mut self) {
+    // Draws the view tree, only updating `area` if given.
+    fn draw(&mut self, area: Option<Rect>) {
         let sizes = self.screen().layer_sizes();
         if self.last_sizes != sizes {
             // TODO: Maybe we only need to clear if the _max_ size differs?
@@ -94,7 +98,18 @@ where
         }
 
         self.buffer.write().resize(self.screen_size());
-        self.siv.borrow_mut().draw(&self.buffer);
+        self.buffer.write().set_graphics_support(
+            self.backend.graphics_protocol(),
+            self.backend.cell_pixel_size(),
+        );
+        let color_downgrade = self.siv.borrow().current_theme().color_downgrade;
+        self.buffer
+            .write()
+            .set_color_downgrade(color_downgrade.resolve(self.backend.color_resolution()));
+        self.siv.borrow_mut().draw(&self.buffer, area);
+        self.siv
+            .borrow_mut()
+            .check_contrast(&self.buffer.read(), self.backend.color_resolution());
         self.buffer.write().flush(&*self.backend);
     }
 
@@ -103,11 +118,11 @@ where
     /// This is an advanced method for fine-tuned manual stepping;
     /// you probably want [`run`][1] or [`step`][2].
     ///
-    /// This processes any pending event or callback. After calling this,
+    /// This processes any pending event, callback or due timer. After calling this,
     /// you will want to call [`post_events`][3] with the result from this
     /// function.
     ///
-    /// Returns `true` if an event or callback was received,
+    /// Returns `true` if an event, callback or timer was processed,
     /// and `false` otherwise.
     ///
     /// [1]: CursiveRunner::run()
@@ -120,7 +135,32 @@ where
         // First, handle all available input
         while let Some(event) = self.backend.poll_event() {
             boring = false;
+            self.handled_input = true;
+            self.record_event(&event);
+            self.set_event_timestamp(Some(Instant::now()));
             self.on_event(event);
+            self.set_event_timestamp(None);
+            self.process_pending_backend_calls();
+
+            if !self.is_running() {
+                return true;
+            }
+        }
+
+        // Then, handle events injected by the application
+        while self.process_injected_event() {
+            boring = false;
+            self.handled_input = true;
+            self.process_pending_backend_calls();
+
+            if !self.is_running() {
+                return true;
+            }
+        }
+
+        // Then, handle messages from external event sources
+        if self.process_event_sources() {
+            boring = false;
             self.process_pending_backend_calls();
 
             if !self.is_running() {
@@ -137,6 +177,11 @@ where
             }
         }
 
+        // Finally, run any timer that is due
+        if self.process_timers() {
+            boring = false;
+        }
+
         !boring
     }
 
@@ -155,13 +200,15 @@ where
         // How many times should we try if it's still boring?
         // Total duration will be INPUT_POLL_DELAY_MS * repeats
         // So effectively fps = 1000 / INPUT_POLL_DELAY_MS / repeats
-        if !boring
+        let should_draw = !boring
             || self
                 .fps()
                 .map(|fps| 1000 / INPUT_POLL_DELAY_MS as u32 / fps.get())
                 .map(|repeats| self.boring_frame_count >= repeats)
-                .unwrap_or(false)
-        {
+                .unwrap_or(false);
+
+        // Nothing is drawn while a batch update is active.
+        if should_draw && !self.is_updating() {
             // We deserve to draw something!
 
             if boring {
@@ -170,18 +217,38 @@ where
                 self.process_pending_backend_calls();
             }
 
-            self.refresh();
+            // Views don't report focus changes, so input redraws everything.
+            if self.damage_tracking() && !self.handled_input {
+                self.refresh_damaged();
+            } else {
+                self.refresh();
+            }
         }
 
         if boring {
-            std::thread::sleep(Duration::from_millis(INPUT_POLL_DELAY_MS));
+            // Wake up early if a timer is due before the next poll.
+            let mut delay = Duration::from_millis(INPUT_POLL_DELAY_MS);
+            if let Some(deadline) = self.next_timer_deadline() {
+                delay = delay.min(deadline.saturating_duration_since(Instant::now()));
+            }
+            // Callbacks and event sources can also wake us up.
+            self.wait_for_messages(delay);
             self.boring_frame_count += 1;
         }
     }
 
     /// Refresh the screen with the current view tree state.
+    ///
+    /// Does nothing while a batch update is active.
+    ///
+    /// See [`Cursive::begin_update`].
     pub fn refresh(&mut self) {
+        if self.is_updating() {
+            return;
+        }
+
         self.boring_frame_count = 0;
+        self.handled_input = false;
 
         // Do we need to redraw every time?
         // Probably, actually.
@@ -190,8 +257,44 @@ where
 
         // TODO: Do we need to redraw every view every time?
         // (Is this getting repetitive? :p)
-        self.draw();
+        self.draw(None);
         self.backend.refresh();
+        self.frame_presented();
+    }
+
+    // Refresh the screen, only redrawing what changed since the last frame.
+    fn refresh_damaged(&mut self) {
+        self.boring_frame_count = 0;
+
+        // Views forget what changed once laid out.
+        let before = self.dirty_area();
+        let resized = self.screen_size() != self.siv.borrow().screen_size();
+        self.layout();
+
+        // Everything moves when the screen is resized, or when layers come and go.
+        if resized || self.needs_clear || self.screen().layer_sizes() != self.last_sizes {
+            self.draw(None);
+            self.backend.refresh();
+            self.frame_presented();
+            return;
+        }
+
+        // But the layout may also have moved things around.
+        let area = match (before, self.dirty_area()) {
+            (Some(before), Some(after)) => Some(before.union(after)),
+            (before, after) => before.or(after),
+        };
+
+        // Nothing to do if nothing changed.
+        let Some(area) = area else {
+            // The input had no visible effect: it is already presented.
+            self.frame_presented();
+            return;
+        };
+
+        self.draw(Some(area));
+        self.backend.refresh();
+        self.frame_presented();
     }
 
     /// Return the name of the backend used.
@@ -224,6 +327,9 @@ where
     /// Internally, it calls [`step(&mut self)`] until [`quit(&mut self)`] is
     /// called.
     ///
+    /// Once the loop stops, hooks registered with [`Cursive::on_pre_shutdown`]
+    /// are run before this function returns.
+    ///
     /// After this function returns, you can call it again and it will start a
     /// new loop.
     ///
@@ -236,5 +342,97 @@ where
         while self.is_running() {
             self.step();
         }
+
+        self.run_shutdown_hooks();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::Backend;
+    use crate::event::{Event, Key};
+    use crate::views::{Canvas, Dialog, LinearLayout, TextContent, TextView};
+    use crate::{Cursive, Rect, Vec2};
+    use parking_lot::Mutex;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    // Persistent backend of the given size, recording the flushed regions.
+    struct Recorder(Arc<Mutex<Vec<Rect>>>, Vec2);
+
+    impl Backend for Recorder {
+        fn poll_event(&mut self) -> Option<Event> {
+            None
+        }
+        fn set_title(&mut self, _: String) {}
+        fn refresh(&mut self) {}
+        fn has_colors(&self) -> bool {
+            false
+        }
+        fn screen_size(&self) -> Vec2 {
+            self.1
+        }
+        fn move_to(&self, _: Vec2) {}
+        fn print(&self, _: &str) {}
+        fn clear(&self, _: crate::style::Color) {}
+        fn set_color(&self, colors: crate::style::ColorPair) -> crate::style::ColorPair {
+            colors
+        }
+        fn set_effect(&self, _: crate::style::Effect) {}
+        fn unset_effect(&self, _: crate::style::Effect) {}
+        fn is_persistent(&self) -> bool {
+            true
+        }
+        fn flush_region(&self, region: Rect) {
+            self.0.lock().push(region);
+        }
+    }
+
+    #[test]
+    fn damage_tracking() {
+        let content = TextContent::new("first");
+
+        let mut siv = Cursive::new();
+        siv.set_damage_tracking(true);
+        siv.add_fullscreen_layer(
+            LinearLayout::vertical()
+                .child(TextView::new_with_content(content.clone()))
+                // Shows how many times it was drawn, so any redraw changes it.
+                .child(Canvas::new(AtomicUsize::new(0)).with_draw(|count, printer| {
+                    let count = count.fetch_add(1, Ordering::Relaxed);
+                    printer.print((0, 0), &count.to_string());
+                })
+                .with_needs_relayout(|_| false)),
+        );
+
+        let regions = Arc::new(Mutex::new(Vec::new()));
+        let mut runner = siv.runner(Box::new(Recorder(Arc::clone(&regions), Vec2::new(10, 2))));
+        runner.refresh();
+        assert_eq!(*regions.lock(), [Rect::from_size((0, 0), (10, 2))]);
+
+        regions.lock().clear();
+        runner.set_timeout(Duration::ZERO, move |_| content.set_content("other"));
+        assert!(runner.step());
+
+        // Only the text changed: the canvas below was not drawn again.
+        assert_eq!(*regions.lock(), [Rect::from_size((0, 0), (5, 1))]);
+    }
+
+    #[test]
+    fn damage_tracking_focus() {
+        let mut siv = Cursive::new();
+        siv.set_damage_tracking(true);
+        siv.add_fullscreen_layer(Dialog::info("Hi").button("Other", |_| ()));
+
+        let regions = Arc::new(Mutex::new(Vec::new()));
+        let mut runner = siv.runner(Box::new(Recorder(Arc::clone(&regions), Vec2::new(20, 5))));
+        runner.refresh();
+
+        // Moving the focus between buttons redraws the dialog.
+        regions.lock().clear();
+        runner.inject_event(Event::Key(Key::Right));
+        assert!(runner.step());
+        assert_eq!(*regions.lock(), [Rect::from_size((2, 3), (12, 1))]);
     }
 }
@@ -0,0 +1,80 @@
+//! Application-wide layout density.
+//!
+//! The [`Density`] lets an application switch all views between a compact
+//! and a comfortable layout, for example to offer a "compact mode".
+//!
+//! Each [`Cursive`] root has its own density, set with [`Cursive::set_density`].
+//! Views consult it during their layout with [`density()`], usually through
+//! [`Density::spacing`]. Among the built-in views:
+//!
+//! * [`Panel`] adds a column of padding inside its borders when comfortable.
+//! * [`ListView`] adds an empty row between its rows when comfortable, and
+//!   collapses its delimiters when compact.
+//!
+//! [`Cursive`]: crate::Cursive
+//! [`Panel`]: crate::views::Panel
+//! [`ListView`]: crate::views::ListView
+//! [`Cursive::set_density`]: crate::Cursive::set_density
+use std::cell::Cell;
+
+thread_local! {
+    // Density of the `Cursive` root currently laying out its views.
+    static DENSITY: Cell<Density> = const { Cell::new(Density::Normal) };
+}
+
+/// Spacing used by views between and around their elements.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Density {
+    /// Less spacing than usual, to fit more content on screen.
+    Compact,
+
+    /// The regular spacing.
+    #[default]
+    Normal,
+
+    /// More spacing than usual, for a more airy layout.
+    Comfortable,
+}
+
+impl Density {
+    /// Returns the spacing to use instead of `normal` for this density.
+    ///
+    /// Compact removes one cell, comfortable adds one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::density::Density;
+    ///
+    /// assert_eq!(Density::Compact.spacing(1), 0);
+    /// assert_eq!(Density::Compact.spacing(0), 0);
+    /// assert_eq!(Density::Normal.spacing(1), 1);
+    /// assert_eq!(Density::Comfortable.spacing(1), 2);
+    /// ```
+    pub fn spacing(self, normal: usize) -> usize {
+        match self {
+            Density::Compact => normal.saturating_sub(1),
+            Density::Normal => normal,
+            Density::Comfortable => normal + 1,
+        }
+    }
+}
+
+/// Returns the density of the views being laid out.
+///
+/// This is the density of the [`Cursive`](crate::Cursive) root running the
+/// layout, or `Density::Normal` outside of a layout.
+pub fn density() -> Density {
+    DENSITY.with(Cell::get)
+}
+
+/// Runs `f` with the given density as the current one.
+pub(crate) fn with_density<F, R>(density: Density, f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    let previous = DENSITY.with(|d| d.replace(density));
+    let result = f();
+    DENSITY.with(|d| d.set(previous));
+    result
+}
@@ -25,7 +25,14 @@
 //!   This is mostly relevant when referring to change of focus. Hitting the
 //!   `Tab` key would usually cycle focus in the "front" direction, while
 //!   using the arrow keys would use absolute directions instead.
+//!
+//! ### Spatial navigation
+//!
+//! [`rank_neighbors`] and [`find_neighbor`] help containers that place their
+//! children freely (like `FixedLayout`) pick the next child to focus when
+//! moving in an absolute direction.
 
+use crate::Rect;
 use crate::Vec2;
 use crate::XY;
 
@@ -376,3 +383,143 @@ impl Absolute {
         }
     }
 }
+
+/// Ranks candidate rectangles by how well they continue a move from `from` towards `direction`.
+///
+/// Returns the indices (in `candidates`) of every rectangle located beyond
+/// `from` in the given direction, best candidate first.
+///
+/// Candidates are preferred when they are:
+/// * Closer to `from` along the direction of movement.
+/// * Aligned with `from` (overlapping with it on the other axis).
+///
+/// This is meant for containers implementing arrow-key navigation between
+/// children at arbitrary positions. Since some children may refuse focus,
+/// they can try each candidate in turn.
+///
+/// Returns an empty list for `Absolute::None`.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::direction::{rank_neighbors, Absolute};
+/// use cursive_core::Rect;
+///
+/// // A 2x2 grid of buttons.
+/// let rects = [
+///     Rect::from_size((0, 0), (5, 1)),
+///     Rect::from_size((6, 0), (5, 1)),
+///     Rect::from_size((0, 2), (5, 1)),
+///     Rect::from_size((6, 2), (5, 1)),
+/// ];
+///
+/// // From the top-left button, going down prefers the one right below.
+/// assert_eq!(rank_neighbors(rects[0], Absolute::Down, rects), [2, 3]);
+/// assert!(rank_neighbors(rects[0], Absolute::Left, rects).is_empty());
+/// ```
+pub fn rank_neighbors<I>(from: Rect, direction: Absolute, candidates: I) -> Vec<usize>
+where
+    I: IntoIterator<Item = Rect>,
+{
+    if direction == Absolute::None {
+        return Vec::new();
+    }
+
+    let (orientation, _) = direction.split();
+    let from_side = from.side(orientation.swap());
+
+    let mut ranked: Vec<_> = candidates
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, rect)| {
+            let distance = distance_beyond(from, rect, direction)?;
+
+            let side = rect.side(orientation.swap());
+            let misalignment = interval_gap(from_side, side);
+
+            // Compare twice the centers to stay on integers.
+            let center_offset = (from_side.0 + from_side.1).abs_diff(side.0 + side.1);
+
+            // Moving sideways costs more than moving along the direction.
+            let score = distance + 2 * misalignment;
+
+            Some(((score, misalignment, center_offset), i))
+        })
+        .collect();
+
+    ranked.sort();
+    ranked.into_iter().map(|(_, i)| i).collect()
+}
+
+/// Returns the best candidate to continue a move from `from` towards `direction`.
+///
+/// This is the first element of [`rank_neighbors`], if any.
+pub fn find_neighbor<I>(from: Rect, direction: Absolute, candidates: I) -> Option<usize>
+where
+    I: IntoIterator<Item = Rect>,
+{
+    rank_neighbors(from, direction, candidates)
+        .into_iter()
+        .next()
+}
+
+// Returns the gap between `from` and `to` along `direction`.
+//
+// Returns `None` if `to` does not extend beyond `from` in that direction.
+fn distance_beyond(from: Rect, to: Rect, direction: Absolute) -> Option<usize> {
+    match direction {
+        Absolute::Left => {
+            (to.left() < from.left()).then(|| from.left().saturating_sub(to.right() + 1))
+        }
+        Absolute::Right => {
+            (to.right() > from.right()).then(|| to.left().saturating_sub(from.right() + 1))
+        }
+        Absolute::Up => (to.top() < from.top()).then(|| from.top().saturating_sub(to.bottom() + 1)),
+        Absolute::Down => {
+            (to.bottom() > from.bottom()).then(|| to.top().saturating_sub(from.bottom() + 1))
+        }
+        Absolute::None => None,
+    }
+}
+
+// Returns the gap between two inclusive intervals, or 0 if they overlap.
+fn interval_gap(a: (usize, usize), b: (usize, usize)) -> usize {
+    // At most one of these is non-zero.
+    b.0.saturating_sub(a.1).max(a.0.saturating_sub(b.1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_neighbor, rank_neighbors, Absolute};
+    use crate::Rect;
+
+    #[test]
+    fn prefers_aligned_neighbors() {
+        let from = Rect::from_size((10, 10), (4, 1));
+
+        let candidates = [
+            // Far away, but right below.
+            Rect::from_size((10, 15), (4, 1)),
+            // Closer, but way off to the side.
+            Rect::from_size((30, 12), (4, 1)),
+            // Close and slightly shifted.
+            Rect::from_size((12, 12), (4, 1)),
+        ];
+
+        assert_eq!(rank_neighbors(from, Absolute::Down, candidates), [2, 0, 1]);
+        assert_eq!(find_neighbor(from, Absolute::Up, candidates), None);
+    }
+
+    #[test]
+    fn skips_overlapping_rects() {
+        let from = Rect::from_size((0, 0), (10, 10));
+        let inner = Rect::from_size((2, 2), (2, 2));
+        let right = Rect::from_size((12, 0), (2, 2));
+
+        assert_eq!(
+            find_neighbor(from, Absolute::Right, [from, inner, right]),
+            Some(2)
+        );
+        assert_eq!(find_neighbor(from, Absolute::None, [right]), None);
+    }
+}
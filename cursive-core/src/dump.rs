@@ -1,4 +1,6 @@
-use crate::{theme::Theme, views, Cursive};
+use crate::{
+    event::Event, event_source::EventSources, theme::Theme, timer::Timers, views, Cursive,
+};
 use crossbeam_channel::{Receiver, Sender};
 use std::any::Any;
 use std::num::NonZeroU32;
@@ -12,6 +14,9 @@ pub struct Dump {
     pub(crate) cb_sink: Sender<Box<Callback>>,
     pub(crate) cb_source: Receiver<Box<Callback>>,
 
+    pub(crate) event_sink: Sender<Event>,
+    pub(crate) event_source: Receiver<Event>,
+
     pub(crate) fps: Option<NonZeroU32>,
 
     pub(crate) menubar: views::Menubar,
@@ -19,5 +24,9 @@ pub struct Dump {
 
     pub(crate) theme: Theme,
 
+    pub(crate) timers: Timers,
+
+    pub(crate) event_sources: EventSources,
+
     pub(crate) user_data: Box<dyn Any>,
 }
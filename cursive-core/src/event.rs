@@ -479,7 +479,7 @@ pub enum MouseButton {
     /// Fifth button if the mouse supports it.
     Button5,
 
-    // TODO: handle more buttons? Wheel left/right?
+    // TODO: handle more buttons?
     #[doc(hidden)]
     Other,
 }
@@ -497,12 +497,20 @@ pub enum MouseEvent {
     WheelUp,
     /// The wheel was moved down.
     WheelDown,
+    /// The wheel was moved left.
+    ///
+    /// Some backends also send this when the wheel is moved up while holding Shift.
+    WheelLeft,
+    /// The wheel was moved right.
+    ///
+    /// Some backends also send this when the wheel is moved down while holding Shift.
+    WheelRight,
 }
 
 impl MouseEvent {
     /// Returns the button used by this event, if any.
     ///
-    /// Returns `None` if `self` is `WheelUp` or `WheelDown`.
+    /// Returns `None` if `self` is a wheel event.
     pub fn button(self) -> Option<MouseButton> {
         match self {
             MouseEvent::Press(btn) | MouseEvent::Release(btn) | MouseEvent::Hold(btn) => Some(btn),
@@ -510,9 +518,20 @@ impl MouseEvent {
         }
     }
 
+    /// Returns `true` if `self` is a wheel event, in any direction.
+    pub fn is_wheel(self) -> bool {
+        matches!(
+            self,
+            MouseEvent::WheelUp
+                | MouseEvent::WheelDown
+                | MouseEvent::WheelLeft
+                | MouseEvent::WheelRight
+        )
+    }
+
     /// Returns `true` if `self` is an event that can grab focus.
     ///
-    /// This includes `Press`, `WheelUp` and `WheelDown`.
+    /// This includes `Press` and wheel events.
     ///
     /// It does _not_ include `Release` or `Hold`.
     ///
@@ -521,7 +540,11 @@ impl MouseEvent {
     pub fn grabs_focus(self) -> bool {
         matches!(
             self,
-            MouseEvent::Press(_) | MouseEvent::WheelUp | MouseEvent::WheelDown
+            MouseEvent::Press(_)
+                | MouseEvent::WheelUp
+                | MouseEvent::WheelDown
+                | MouseEvent::WheelLeft
+                | MouseEvent::WheelRight
         )
     }
 }
@@ -561,6 +584,15 @@ pub enum Event {
     /// A non-character key was pressed with the Ctrl and Alt keys pressed.
     CtrlAlt(Key),
 
+    /// Some text was pasted.
+    ///
+    /// Only sent by backends supporting bracketed paste. Line breaks are
+    /// normalized to `\n`.
+    ///
+    /// If the focused view ignores this event, the text is sent again as
+    /// individual key events.
+    Paste(String),
+
     /// A mouse event was sent.
     Mouse {
         /// Position of the top-left corner of the view receiving this event.
@@ -597,6 +629,11 @@ impl Event {
         }
     }
 
+    /// Returns `true` if `self` is a mouse wheel event.
+    pub fn is_wheel(&self) -> bool {
+        matches!(self, Event::Mouse { event, .. } if event.is_wheel())
+    }
+
     /// Returns the position of the mouse, if `self` is a mouse event.
     pub fn mouse_position(&self) -> Option<Vec2> {
         if let Event::Mouse { position, .. } = *self {
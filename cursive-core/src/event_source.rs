@@ -0,0 +1,261 @@
+use crate::Cursive;
+use crossbeam_channel::{Receiver, Select, Sender, TryRecvError};
+use std::io::Read;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+type SourceCallback<T> = dyn FnMut(&mut Cursive, T) + Send;
+
+// Maximum number of messages delivered from a single source in one step.
+//
+// This keeps a busy source from starving the other ones.
+const MAX_MESSAGES_PER_STEP: usize = 64;
+
+// Size of the buffer used by reader sources.
+const READ_BUFFER_SIZE: usize = 4096;
+
+/// Handle to an event source registered on the event loop.
+///
+/// Returned by [`Cursive::add_event_source`] and [`Cursive::add_reader_source`].
+///
+/// Dropping the handle does _not_ remove the source; call [`SourceHandle::remove`] for that.
+///
+/// The handle can be cloned and sent to other threads.
+#[derive(Clone, Debug, Default)]
+pub struct SourceHandle {
+    removed: Arc<AtomicBool>,
+}
+
+impl SourceHandle {
+    /// Removes the source from the event loop.
+    ///
+    /// Its callback will not run again, even if messages are pending.
+    pub fn remove(&self) {
+        self.removed.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if this source was removed.
+    ///
+    /// Sources are also removed once all their senders are dropped.
+    pub fn is_removed(&self) -> bool {
+        self.removed.load(Ordering::Relaxed)
+    }
+}
+
+trait Source: Send {
+    /// Adds the underlying channel to the selection.
+    fn register<'a>(&'a self, select: &mut Select<'a>);
+
+    /// Delivers pending messages.
+    ///
+    /// Returns `true` if at least one message was delivered.
+    fn process(&mut self, siv: &mut Cursive) -> bool;
+
+    fn handle(&self) -> &SourceHandle;
+}
+
+struct ChannelSource<T> {
+    receiver: Receiver<T>,
+    callback: Box<SourceCallback<T>>,
+    handle: SourceHandle,
+}
+
+impl<T: Send> Source for ChannelSource<T> {
+    fn register<'a>(&'a self, select: &mut Select<'a>) {
+        select.recv(&self.receiver);
+    }
+
+    fn process(&mut self, siv: &mut Cursive) -> bool {
+        let mut processed = false;
+
+        for _ in 0..MAX_MESSAGES_PER_STEP {
+            // A callback may have removed this source, or stopped the event loop.
+            if self.handle.is_removed() || !siv.is_running() {
+                break;
+            }
+
+            match self.receiver.try_recv() {
+                Ok(message) => {
+                    (self.callback)(siv, message);
+                    processed = true;
+                }
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    self.handle.remove();
+                    break;
+                }
+            }
+        }
+
+        processed
+    }
+
+    fn handle(&self) -> &SourceHandle {
+        &self.handle
+    }
+}
+
+/// Set of external sources the event loop listens to.
+#[derive(Default)]
+pub(crate) struct EventSources {
+    sources: Vec<Box<dyn Source>>,
+}
+
+impl EventSources {
+    /// Adds a new source and returns its handle.
+    pub fn add<T: Send + 'static>(
+        &mut self,
+        receiver: Receiver<T>,
+        callback: Box<SourceCallback<T>>,
+    ) -> SourceHandle {
+        let handle = SourceHandle::default();
+        self.sources.push(Box::new(ChannelSource {
+            receiver,
+            callback,
+            handle: handle.clone(),
+        }));
+        handle
+    }
+
+    /// Adds the channels of all active sources to the selection.
+    pub fn register<'a>(&'a self, select: &mut Select<'a>) {
+        for source in &self.sources {
+            if !source.handle().is_removed() {
+                source.register(select);
+            }
+        }
+    }
+}
+
+/// Reads from `reader` in a background thread, and sends each chunk of data.
+///
+/// Stops at the end of the input, after the first error, or once `handle` is removed.
+pub(crate) fn read_in_background<R>(
+    mut reader: R,
+    sender: Sender<std::io::Result<Vec<u8>>>,
+    handle: SourceHandle,
+) where
+    R: Read + Send + 'static,
+{
+    std::thread::spawn(move || {
+        let mut buffer = vec![0; READ_BUFFER_SIZE];
+        while !handle.is_removed() {
+            let message = match reader.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(n) => Ok(buffer[..n].to_vec()),
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => Err(e),
+            };
+
+            let failed = message.is_err();
+            if sender.send(message).is_err() || failed {
+                break;
+            }
+        }
+    });
+}
+
+impl Cursive {
+    /// Delivers pending messages from all event sources.
+    ///
+    /// Returns `true` if at least one message was delivered.
+    pub(crate) fn process_event_sources(&mut self) -> bool {
+        let mut sources = std::mem::take(&mut self.event_sources.sources);
+
+        let mut processed = false;
+        for source in &mut sources {
+            if !self.is_running() {
+                break;
+            }
+            processed |= source.process(self);
+        }
+
+        // Callbacks may have registered new sources.
+        sources.append(&mut self.event_sources.sources);
+        sources.retain(|source| !source.handle().is_removed());
+        self.event_sources.sources = sources;
+
+        processed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Cursive;
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn channel_source() {
+        let mut siv = Cursive::new();
+        let received = Arc::new(Mutex::new(Vec::new()));
+
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        let log = Arc::clone(&received);
+        let handle = siv.add_event_source(receiver, move |_, n: u32| {
+            log.lock().unwrap().push(n);
+        });
+
+        assert!(!siv.process_event_sources());
+
+        sender.send(1).unwrap();
+        sender.send(2).unwrap();
+        assert!(siv.process_event_sources());
+        assert_eq!(*received.lock().unwrap(), [1, 2]);
+
+        // The source goes away with its senders.
+        drop(sender);
+        assert!(!siv.process_event_sources());
+        assert!(handle.is_removed());
+        assert!(siv.event_sources.sources.is_empty());
+    }
+
+    #[test]
+    fn removed_source() {
+        let mut siv = Cursive::new();
+
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        let handle = siv.add_event_source(receiver, |s, ()| s.quit());
+
+        handle.remove();
+        sender.send(()).unwrap();
+        assert!(!siv.process_event_sources());
+        assert!(siv.is_running());
+    }
+
+    #[test]
+    fn message_wakes_up() {
+        let mut siv = Cursive::new();
+
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        siv.add_event_source(receiver, |s, ()| s.quit());
+        sender.send(()).unwrap();
+
+        let start = Instant::now();
+        siv.wait_for_messages(Duration::from_secs(60));
+        assert!(start.elapsed() < Duration::from_secs(60));
+    }
+
+    #[test]
+    fn reader_source() {
+        let mut siv = Cursive::new();
+        let received = Arc::new(Mutex::new(Vec::new()));
+
+        let log = Arc::clone(&received);
+        let handle = siv.add_reader_source(&b"hello"[..], move |_, data| {
+            log.lock().unwrap().extend(data.unwrap());
+        });
+
+        // Wait for the background thread to send its data and finish.
+        for _ in 0..100 {
+            siv.wait_for_messages(Duration::from_millis(10));
+            siv.process_event_sources();
+            if handle.is_removed() {
+                break;
+            }
+        }
+
+        assert!(handle.is_removed());
+        assert_eq!(*received.lock().unwrap(), b"hello");
+    }
+}
@@ -0,0 +1,227 @@
+//! Run tasks in the background and report back to the UI.
+//!
+//! A task started with [`spawn_with_progress`] runs in its own thread. It can
+//! report its progress through a [`JobContext`], and its result is sent back
+//! to the event loop through a [`CbSink`] when it completes.
+//!
+//! See also [`Dialog::async_loading`], which shows a loading dialog while the
+//! task runs.
+//!
+//! [`Dialog::async_loading`]: crate::views::Dialog::async_loading
+use crate::utils::Counter;
+use crate::{CbSink, Cursive};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+/// Handle to a task started with [`spawn_with_progress`].
+///
+/// The handle can be cloned and sent to other threads.
+///
+/// Dropping the handle does _not_ cancel the task; call [`JobHandle::cancel`] for that.
+#[derive(Clone, Debug)]
+pub struct JobHandle {
+    progress: Counter,
+    cancelled: Arc<AtomicBool>,
+    finished: Arc<AtomicBool>,
+}
+
+impl JobHandle {
+    fn new() -> Self {
+        JobHandle {
+            progress: Counter::new(0),
+            cancelled: Arc::new(AtomicBool::new(false)),
+            finished: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Requests the task to stop.
+    ///
+    /// The task should check [`JobContext::is_cancelled`] regularly and
+    /// return early. Either way, its result will be discarded.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if this task was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Returns `true` if the task returned, whether it was cancelled or not.
+    pub fn is_finished(&self) -> bool {
+        self.finished.load(Ordering::Relaxed)
+    }
+
+    /// Returns the counter following the progress of the task.
+    ///
+    /// This can be given to a [`ProgressBar`](crate::views::ProgressBar).
+    pub fn progress(&self) -> &Counter {
+        &self.progress
+    }
+}
+
+/// Given to a background task to report its progress.
+pub struct JobContext {
+    handle: JobHandle,
+    cb_sink: CbSink,
+
+    // Set while a redraw request is waiting in the callback channel.
+    redraw_pending: Arc<AtomicBool>,
+}
+
+impl JobContext {
+    /// Returns `true` if the task was cancelled.
+    ///
+    /// Long-running tasks should check this regularly.
+    pub fn is_cancelled(&self) -> bool {
+        self.handle.is_cancelled()
+    }
+
+    /// Sets the current progress value.
+    ///
+    /// This wakes up the event loop, so any view following the progress is redrawn.
+    pub fn set_progress(&self, value: usize) {
+        if self.handle.progress.get() != value {
+            self.handle.progress.set(value);
+            self.request_redraw();
+        }
+    }
+
+    /// Increases the current progress by `ticks`.
+    ///
+    /// This wakes up the event loop, so any view following the progress is redrawn.
+    pub fn tick(&self, ticks: usize) {
+        if ticks > 0 {
+            self.handle.progress.tick(ticks);
+            self.request_redraw();
+        }
+    }
+
+    /// Returns the current progress value.
+    pub fn progress(&self) -> usize {
+        self.handle.progress.get()
+    }
+
+    /// Returns the sink used to send callbacks to the event loop.
+    pub fn cb_sink(&self) -> &CbSink {
+        &self.cb_sink
+    }
+
+    fn request_redraw(&self) {
+        // The pending request will show the latest progress anyway.
+        if self.redraw_pending.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        // Running the callback is enough to trigger a refresh.
+        let pending = Arc::clone(&self.redraw_pending);
+        self.cb_sink
+            .send(Box::new(move |_| pending.store(false, Ordering::Release)))
+            .ok();
+    }
+}
+
+/// Runs `task` in a separate thread, then gives its result to `on_done`.
+///
+/// `on_done` runs on the event loop, through the given `cb_sink`. It is not
+/// called if the task was cancelled.
+///
+/// Returns a handle that can be used to follow the progress of the task, or
+/// to cancel it.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use cursive_core::{jobs, Cursive};
+/// # use cursive_core::views::Dialog;
+/// # let siv = Cursive::new();
+/// let handle = jobs::spawn_with_progress(
+///     siv.cb_sink(),
+///     |ctx| {
+///         for i in 0..100 {
+///             if ctx.is_cancelled() {
+///                 break;
+///             }
+///             std::thread::sleep(std::time::Duration::from_millis(10));
+///             ctx.set_progress(i + 1);
+///         }
+///         42
+///     },
+///     |s, answer| s.add_layer(Dialog::info(format!("Answer: {answer}"))),
+/// );
+/// ```
+pub fn spawn_with_progress<F, R, C>(cb_sink: &CbSink, task: F, on_done: C) -> JobHandle
+where
+    F: FnOnce(&JobContext) -> R + Send + 'static,
+    R: Send + 'static,
+    C: FnOnce(&mut Cursive, R) + Send + 'static,
+{
+    let handle = JobHandle::new();
+    let context = JobContext {
+        handle: handle.clone(),
+        cb_sink: cb_sink.clone(),
+        redraw_pending: Arc::new(AtomicBool::new(false)),
+    };
+
+    thread::spawn(move || {
+        let result = task(&context);
+        context.handle.finished.store(true, Ordering::Relaxed);
+
+        if context.is_cancelled() {
+            return;
+        }
+
+        let handle = context.handle;
+        context
+            .cb_sink
+            .send(Box::new(move |s| {
+                // The task may have been cancelled while this was waiting.
+                if !handle.is_cancelled() {
+                    on_done(s, result);
+                }
+            }))
+            .ok();
+    });
+
+    handle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::spawn_with_progress;
+    use crate::Cursive;
+    use std::sync::mpsc;
+
+    #[test]
+    fn cancelled_job() {
+        let mut siv = Cursive::new();
+        let (tx, rx) = mpsc::channel();
+
+        let handle = spawn_with_progress(
+            siv.cb_sink(),
+            move |ctx| {
+                // Redraw requests are coalesced.
+                for i in 1..=10 {
+                    ctx.set_progress(i);
+                }
+                // Wait until the test cancels us.
+                rx.recv().ok();
+                ctx.is_cancelled()
+            },
+            |_, _| panic!("a cancelled job should not report back"),
+        );
+
+        handle.cancel();
+        tx.send(()).unwrap();
+
+        while !handle.is_finished() {
+            std::thread::yield_now();
+        }
+        assert_eq!(handle.progress().get(), 10);
+
+        // Only the redraw request was sent.
+        assert!(siv.process_callback());
+        assert!(!siv.process_callback());
+    }
+}
@@ -0,0 +1,659 @@
+//! Remappable key bindings for the built-in views.
+//!
+//! Views don't hardcode most of their navigation keys. Instead, they ask the
+//! active [`Keymap`] whether an event triggers a named action, like
+//! `"focus_next"` or `"submit"`.
+//!
+//! The keymap is global to the application: set it with [`set_keymap`]
+//! before running the event loop.
+//!
+//! Here are the actions used by the built-in views, and their default keys:
+//!
+//! | Action             | Default keys      | Used by                                |
+//! |--------------------|-------------------|----------------------------------------|
+//! | `focus_next`       | `tab`             | `LinearLayout`, `ListView`, `FixedLayout` |
+//! | `focus_prev`       | `shift-tab`       | `LinearLayout`, `ListView`, `FixedLayout` |
+//! | `submit`           | `enter`           | `Button`, `EditView`, `SelectView`     |
+//! | `cancel`           | `esc`             | `MenuPopup`                            |
+//! | `scroll_up`        | `up`, `ctrl-up`   | Scrollable views                       |
+//! | `scroll_down`      | `down`, `ctrl-down` | Scrollable views                     |
+//! | `scroll_left`      | `left`, `ctrl-left` | Scrollable views                     |
+//! | `scroll_right`     | `right`, `ctrl-right` | Scrollable views                   |
+//! | `page_up`          | `pageup`          | Scrollable views                       |
+//! | `page_down`        | `pagedown`        | Scrollable views                       |
+//! | `scroll_to_top`    | `home`            | Scrollable views                       |
+//! | `scroll_to_bottom` | `end`             | Scrollable views                       |
+//! | `cursor_left`      | `left`, `ctrl-b`  | `EditView`                             |
+//! | `cursor_right`     | `right`, `ctrl-f` | `EditView`                             |
+//! | `cursor_word_left` | `alt-b`           | `EditView`                             |
+//! | `cursor_word_right` | `alt-f`          | `EditView`                             |
+//! | `cursor_home`      | `home`, `ctrl-a`  | `EditView`                             |
+//! | `cursor_end`       | `end`, `ctrl-e`   | `EditView`                             |
+//! | `kill_to_start`    | `ctrl-u`          | `EditView`                             |
+//! | `kill_to_end`      | `ctrl-k`          | `EditView`                             |
+//! | `kill_word_back`   | `ctrl-w`          | `EditView`                             |
+//! | `yank`             | `ctrl-y`          | `EditView`                             |
+//! | `next_window`      | `f6`              | `WindowManager`                        |
+//! | `command_line`     | `:`               | `Cursive::set_command_handler`         |
+//!
+//! Applications can also define their own actions, and check them with
+//! [`matches`].
+//!
+//! ## Configuration files
+//!
+//! Each entry maps an action to a key, or to a list of keys. Listed actions
+//! replace their default keys; other actions keep their defaults.
+//!
+//! ```toml
+//! focus_next = ["tab", "ctrl-n"]
+//! focus_prev = ["shift-tab", "ctrl-p"]
+//! scroll_down = ["down", "j"]
+//! scroll_up = ["up", "k"]
+//! scroll_to_bottom = "G"
+//! ```
+//!
+//! Keys are written like `"a"`, `"enter"`, `"ctrl-c"`, `"alt-left"` or
+//! `"shift-tab"`. See [`parse_event`] for the full syntax.
+//!
+//! Loading toml requires the `toml` feature. Other formats, like YAML or
+//! JSON, can be loaded into a `serde_json::Value` and given to
+//! [`Keymap::load_config`].
+//!
+//! ## Event translations
+//!
+//! Some terminals send unusual sequences for some keys, which backends
+//! report as `Event::Unknown`, or as the wrong event. An [`EventMap`] given to
+//! `Cursive::set_event_map` rewrites these events before anything else sees
+//! them, so they can be fixed from a config file:
+//!
+//! ```toml
+//! "unknown-27,91,49,59,53,65" = "ctrl-up"
+//! "å" = "alt-a"
+//! ```
+use crate::event::{Event, Key};
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+#[cfg(feature = "toml")]
+use std::{fs::File, io::Read, path::Path};
+
+lazy_static! {
+    // Keymap queried by the views.
+    static ref KEYMAP: RwLock<Arc<Keymap>> = RwLock::new(Arc::new(Keymap::default()));
+}
+
+/// Maps named actions to the events triggering them.
+///
+/// The default keymap holds the bindings listed in the [module
+/// documentation](self).
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::event::{Event, Key};
+/// use cursive_core::keymap::{self, Keymap};
+///
+/// let mut keymap = Keymap::default();
+/// keymap.bind("scroll_down", 'j');
+/// keymap.bind("scroll_up", 'k');
+/// assert!(keymap.matches("scroll_down", &Event::Char('j')));
+/// assert!(keymap.matches("scroll_down", &Event::Key(Key::Down)));
+///
+/// keymap::set_keymap(keymap);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Keymap {
+    bindings: HashMap<String, Vec<Event>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let defaults = [
+            ("focus_next", vec![Event::Key(Key::Tab)]),
+            ("focus_prev", vec![Event::Shift(Key::Tab)]),
+            ("submit", vec![Event::Key(Key::Enter)]),
+            ("cancel", vec![Event::Key(Key::Esc)]),
+            ("scroll_up", vec![Event::Key(Key::Up), Event::Ctrl(Key::Up)]),
+            (
+                "scroll_down",
+                vec![Event::Key(Key::Down), Event::Ctrl(Key::Down)],
+            ),
+            (
+                "scroll_left",
+                vec![Event::Key(Key::Left), Event::Ctrl(Key::Left)],
+            ),
+            (
+                "scroll_right",
+                vec![Event::Key(Key::Right), Event::Ctrl(Key::Right)],
+            ),
+            ("page_up", vec![Event::Key(Key::PageUp)]),
+            ("page_down", vec![Event::Key(Key::PageDown)]),
+            ("scroll_to_top", vec![Event::Key(Key::Home)]),
+            ("scroll_to_bottom", vec![Event::Key(Key::End)]),
+            (
+                "cursor_left",
+                vec![Event::Key(Key::Left), Event::CtrlChar('b')],
+            ),
+            (
+                "cursor_right",
+                vec![Event::Key(Key::Right), Event::CtrlChar('f')],
+            ),
+            ("cursor_word_left", vec![Event::AltChar('b')]),
+            ("cursor_word_right", vec![Event::AltChar('f')]),
+            (
+                "cursor_home",
+                vec![Event::Key(Key::Home), Event::CtrlChar('a')],
+            ),
+            (
+                "cursor_end",
+                vec![Event::Key(Key::End), Event::CtrlChar('e')],
+            ),
+            ("kill_to_start", vec![Event::CtrlChar('u')]),
+            ("kill_to_end", vec![Event::CtrlChar('k')]),
+            ("kill_word_back", vec![Event::CtrlChar('w')]),
+            ("yank", vec![Event::CtrlChar('y')]),
+            ("next_window", vec![Event::Key(Key::F6)]),
+            ("command_line", vec![Event::Char(':')]),
+        ];
+
+        Keymap {
+            bindings: defaults
+                .into_iter()
+                .map(|(action, events)| (action.to_string(), events))
+                .collect(),
+        }
+    }
+}
+
+impl Keymap {
+    /// Creates an empty keymap, where no action is bound.
+    pub fn new() -> Self {
+        Keymap {
+            bindings: HashMap::new(),
+        }
+    }
+
+    /// Adds an event triggering the given action.
+    ///
+    /// Existing events for this action are kept.
+    pub fn bind<S: Into<String>, E: Into<Event>>(&mut self, action: S, event: E) {
+        let events = self.bindings.entry(action.into()).or_default();
+        let event = event.into();
+        if !events.contains(&event) {
+            events.push(event);
+        }
+    }
+
+    /// Sets the events triggering the given action, replacing existing ones.
+    pub fn set<S: Into<String>>(&mut self, action: S, events: Vec<Event>) {
+        self.bindings.insert(action.into(), events);
+    }
+
+    /// Removes all events for the given action.
+    pub fn unbind(&mut self, action: &str) {
+        self.bindings.remove(action);
+    }
+
+    /// Returns the events triggering the given action.
+    pub fn get(&self, action: &str) -> &[Event] {
+        self.bindings.get(action).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Returns `true` if `event` triggers the given action.
+    pub fn matches(&self, action: &str, event: &Event) -> bool {
+        self.get(action).contains(event)
+    }
+
+    /// Loads bindings from a config value.
+    ///
+    /// The value must be an object mapping action names to a key, or to a
+    /// list of keys. Listed actions replace their existing keys.
+    ///
+    /// If an error is returned, no binding was changed.
+    pub fn load_config(&mut self, config: &serde_json::Value) -> Result<(), Error> {
+        let Some(config) = config.as_object() else {
+            return Err(Error::InvalidConfig);
+        };
+
+        let mut bindings = Vec::new();
+        for (action, keys) in config {
+            let keys = match keys {
+                serde_json::Value::String(key) => vec![key.as_str()],
+                serde_json::Value::Array(keys) => keys
+                    .iter()
+                    .map(|key| {
+                        key.as_str()
+                            .ok_or_else(|| Error::InvalidAction(action.clone()))
+                    })
+                    .collect::<Result<_, _>>()?,
+                _ => return Err(Error::InvalidAction(action.clone())),
+            };
+
+            let events = keys
+                .into_iter()
+                .map(|key| {
+                    parse_event(key).ok_or_else(|| Error::UnknownKey {
+                        action: action.clone(),
+                        key: key.to_string(),
+                    })
+                })
+                .collect::<Result<_, _>>()?;
+            bindings.push((action.clone(), events));
+        }
+
+        self.bindings.extend(bindings);
+        Ok(())
+    }
+
+    /// Loads bindings from a toml string.
+    ///
+    /// Must have the `toml` feature enabled.
+    #[cfg(feature = "toml")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "toml")))]
+    pub fn load_toml(&mut self, content: &str) -> Result<(), Error> {
+        let config: serde_json::Value = toml::de::from_str(content)?;
+        self.load_config(&config)
+    }
+}
+
+/// Rewrites incoming events into other events.
+///
+/// Used to fix keys sent by some terminals: see the [module
+/// documentation](self#event-translations).
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::event::{Event, Key};
+/// use cursive_core::keymap::EventMap;
+///
+/// let mut map = EventMap::new();
+/// map.add(Event::Unknown(vec![27, 91, 49, 59, 53, 65]), Event::Ctrl(Key::Up));
+///
+/// assert_eq!(
+///     map.translate(Event::Unknown(vec![27, 91, 49, 59, 53, 65])),
+///     Event::Ctrl(Key::Up)
+/// );
+/// assert_eq!(map.translate(Event::Char('a')), Event::Char('a'));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EventMap {
+    translations: HashMap<Event, Event>,
+}
+
+impl EventMap {
+    /// Creates an empty map, where no event is translated.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Translates `from` into `to`.
+    ///
+    /// Replaces any existing translation for `from`.
+    pub fn add<F: Into<Event>, T: Into<Event>>(&mut self, from: F, to: T) {
+        self.translations.insert(from.into(), to.into());
+    }
+
+    /// Removes the translation for the given event.
+    pub fn remove(&mut self, from: &Event) {
+        self.translations.remove(from);
+    }
+
+    /// Returns the event `from` is translated into, if any.
+    pub fn get(&self, from: &Event) -> Option<&Event> {
+        self.translations.get(from)
+    }
+
+    /// Returns `true` if no event is translated.
+    pub fn is_empty(&self) -> bool {
+        self.translations.is_empty()
+    }
+
+    /// Returns the translation of `event`, or `event` itself if it has none.
+    ///
+    /// Translations are not chained: the result is not translated again.
+    pub fn translate(&self, event: Event) -> Event {
+        match self.translations.get(&event) {
+            Some(translated) => translated.clone(),
+            None => event,
+        }
+    }
+
+    /// Loads translations from a config value.
+    ///
+    /// The value must be an object mapping event names to event names, using
+    /// the syntax of [`parse_event`]. Existing translations for the same
+    /// events are replaced.
+    ///
+    /// If an error is returned, no translation was changed.
+    pub fn load_config(&mut self, config: &serde_json::Value) -> Result<(), Error> {
+        let Some(config) = config.as_object() else {
+            return Err(Error::InvalidConfig);
+        };
+
+        let parse =
+            |name: &str| parse_event(name).ok_or_else(|| Error::UnknownEvent(name.to_string()));
+
+        let mut translations = Vec::new();
+        for (from, to) in config {
+            let Some(to) = to.as_str() else {
+                return Err(Error::InvalidAction(from.clone()));
+            };
+            translations.push((parse(from)?, parse(to)?));
+        }
+
+        self.translations.extend(translations);
+        Ok(())
+    }
+
+    /// Loads translations from a toml string.
+    ///
+    /// Must have the `toml` feature enabled.
+    #[cfg(feature = "toml")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "toml")))]
+    pub fn load_toml(&mut self, content: &str) -> Result<(), Error> {
+        let config: serde_json::Value = toml::de::from_str(content)?;
+        self.load_config(&config)
+    }
+}
+
+/// Error loading a keymap or an event map.
+#[derive(Debug)]
+pub enum Error {
+    /// An error occurred when reading the file.
+    #[cfg(feature = "toml")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "toml")))]
+    Io(std::io::Error),
+
+    /// An error occurred when parsing the toml content.
+    #[cfg(feature = "toml")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "toml")))]
+    Parse(toml::de::Error),
+
+    /// The config is not a map from actions to keys.
+    InvalidConfig,
+
+    /// The keys for this action are neither a string nor a list of strings.
+    ///
+    /// For an event map, the translation of this event is not a string.
+    InvalidAction(String),
+
+    /// A key name could not be parsed.
+    UnknownKey {
+        /// Action the key was bound to.
+        action: String,
+
+        /// Key name that could not be parsed.
+        key: String,
+    },
+
+    /// An event name from an event map could not be parsed.
+    UnknownEvent(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "toml")]
+            Error::Io(err) => write!(f, "could not read keymap: {err}"),
+            #[cfg(feature = "toml")]
+            Error::Parse(err) => write!(f, "could not parse keymap: {err}"),
+            Error::InvalidConfig => write!(f, "keymap must map actions to keys"),
+            Error::InvalidAction(action) => {
+                write!(
+                    f,
+                    "keys for `{action}` must be a string or a list of strings"
+                )
+            }
+            Error::UnknownKey { action, key } => {
+                write!(f, "unknown key `{key}` for `{action}`")
+            }
+            Error::UnknownEvent(name) => write!(f, "unknown event `{name}`"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(feature = "toml")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "toml")))]
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+#[cfg(feature = "toml")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "toml")))]
+impl From<toml::de::Error> for Error {
+    fn from(err: toml::de::Error) -> Self {
+        Error::Parse(err)
+    }
+}
+
+/// Sets the keymap used by all views.
+pub fn set_keymap(keymap: Keymap) {
+    *KEYMAP.write() = Arc::new(keymap);
+}
+
+/// Returns the keymap currently used by the views.
+pub fn keymap() -> Arc<Keymap> {
+    Arc::clone(&KEYMAP.read())
+}
+
+/// Returns `true` if `event` triggers the given action in the current keymap.
+pub fn matches(action: &str, event: &Event) -> bool {
+    KEYMAP.read().matches(action, event)
+}
+
+/// Loads a keymap from a toml file, on top of the default bindings.
+///
+/// The result can then be given to [`set_keymap`].
+///
+/// Must have the `toml` feature enabled.
+#[cfg(feature = "toml")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "toml")))]
+pub fn load_keymap_file<P: AsRef<Path>>(filename: P) -> Result<Keymap, Error> {
+    let mut content = String::new();
+    File::open(filename)?.read_to_string(&mut content)?;
+
+    let mut keymap = Keymap::default();
+    keymap.load_toml(&content)?;
+    Ok(keymap)
+}
+
+/// Parses a key name, like `"a"`, `"enter"`, `"ctrl-c"` or `"shift-tab"`.
+///
+/// * A single character is this character. It is case-sensitive.
+/// * Named keys are `enter`, `tab`, `backspace`, `esc`, `ins`, `del`, `left`,
+///   `right`, `up`, `down`, `home`, `end`, `pageup`, `pagedown`, `space` and
+///   `f1` to `f12`.
+/// * Modifiers are `ctrl-`, `alt-` and `shift-`. Characters only support
+///   `ctrl-` and `alt-`.
+/// * `unknown-` followed by comma-separated bytes, like `unknown-27,91,90`,
+///   is an `Event::Unknown` with these bytes.
+///
+/// Names and modifiers are case-insensitive. Returns `None` if the name is
+/// not recognized.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::event::{Event, Key};
+/// use cursive_core::keymap::parse_event;
+///
+/// assert_eq!(parse_event("G"), Some(Event::Char('G')));
+/// assert_eq!(parse_event("Ctrl-x"), Some(Event::CtrlChar('x')));
+/// assert_eq!(parse_event("shift-tab"), Some(Event::Shift(Key::Tab)));
+/// assert_eq!(parse_event("unknown-27,79,80"), Some(Event::Unknown(vec![27, 79, 80])));
+/// assert_eq!(parse_event("hyper-a"), None);
+/// ```
+pub fn parse_event(name: &str) -> Option<Event> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(Event::Char(c));
+    }
+
+    let (modifier, name) = match name.split_once('-') {
+        Some((modifier, name)) if !name.is_empty() => (Some(modifier.to_lowercase()), name),
+        _ => (None, name),
+    };
+
+    if modifier.as_deref() == Some("unknown") {
+        return name
+            .split(',')
+            .map(|byte| byte.trim().parse().ok())
+            .collect::<Option<_>>()
+            .map(Event::Unknown);
+    }
+
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return match modifier.as_deref() {
+            Some("ctrl") => Some(Event::CtrlChar(c)),
+            Some("alt") => Some(Event::AltChar(c)),
+            _ => None,
+        };
+    }
+
+    let key = match name.to_lowercase().as_str() {
+        "space" if modifier.is_none() => return Some(Event::Char(' ')),
+        "enter" => Key::Enter,
+        "tab" => Key::Tab,
+        "backspace" => Key::Backspace,
+        "esc" => Key::Esc,
+        "ins" => Key::Ins,
+        "del" => Key::Del,
+        "left" => Key::Left,
+        "right" => Key::Right,
+        "up" => Key::Up,
+        "down" => Key::Down,
+        "home" => Key::Home,
+        "end" => Key::End,
+        "pageup" => Key::PageUp,
+        "pagedown" => Key::PageDown,
+        name => match name.strip_prefix('f')?.parse().ok()? {
+            n @ 1..=12 => Key::from_f(n),
+            _ => return None,
+        },
+    };
+
+    match modifier.as_deref() {
+        None => Some(Event::Key(key)),
+        Some("ctrl") => Some(Event::Ctrl(key)),
+        Some("alt") => Some(Event::Alt(key)),
+        Some("shift") => Some(Event::Shift(key)),
+        Some(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn load_config() {
+        let mut keymap = Keymap::default();
+        keymap
+            .load_config(&json!({
+                "scroll_down": ["down", "j"],
+                "submit": "ctrl-m",
+                "quit": "Q",
+            }))
+            .unwrap();
+
+        assert_eq!(
+            keymap.get("scroll_down"),
+            [Event::Key(Key::Down), Event::Char('j')]
+        );
+        assert_eq!(keymap.get("submit"), [Event::CtrlChar('m')]);
+        assert!(keymap.matches("quit", &Event::Char('Q')));
+        assert!(!keymap.matches("quit", &Event::Char('q')));
+
+        // Unlisted actions keep their defaults.
+        assert_eq!(keymap.get("focus_next"), [Event::Key(Key::Tab)]);
+    }
+
+    #[test]
+    fn invalid_config() {
+        let mut keymap = Keymap::default();
+        let err = keymap
+            .load_config(&json!({"submit": "ctrl-m", "cancel": "hyper-x"}))
+            .unwrap_err();
+        assert!(matches!(err, Error::UnknownKey { ref key, .. } if key == "hyper-x"));
+        assert!(matches!(
+            keymap.load_config(&json!({"submit": 3})),
+            Err(Error::InvalidAction(_))
+        ));
+        assert!(matches!(
+            keymap.load_config(&json!(["enter"])),
+            Err(Error::InvalidConfig)
+        ));
+
+        // Nothing was applied.
+        assert_eq!(keymap, Keymap::default());
+    }
+
+    #[test]
+    fn remapped_view() {
+        use crate::view::View;
+        use crate::views::{Button, LinearLayout};
+
+        // Only add bindings, to keep the defaults for the other tests.
+        let mut keymap = Keymap::default();
+        keymap.bind("focus_next", Event::CtrlChar('n'));
+        set_keymap(keymap);
+
+        let mut layout = LinearLayout::vertical()
+            .child(Button::new("a", |_| ()))
+            .child(Button::new("b", |_| ()));
+        assert!(layout.on_event(Event::CtrlChar('n')).is_consumed());
+        assert_eq!(layout.get_focus_index(), 1);
+    }
+
+    #[test]
+    fn event_map() {
+        let mut map = EventMap::new();
+        map.load_config(&json!({
+            "unknown-27,91,49,59,53,65": "ctrl-up",
+            "å": "alt-a",
+            "alt-a": "b",
+        }))
+        .unwrap();
+
+        assert_eq!(
+            map.translate(Event::Unknown(vec![27, 91, 49, 59, 53, 65])),
+            Event::Ctrl(Key::Up)
+        );
+        // Translations are not chained.
+        assert_eq!(map.translate(Event::Char('å')), Event::AltChar('a'));
+        assert_eq!(map.translate(Event::Char('x')), Event::Char('x'));
+
+        assert!(matches!(
+            map.load_config(&json!({"f1": "enter", "unknown-27,x": "esc"})),
+            Err(Error::UnknownEvent(ref name)) if name == "unknown-27,x"
+        ));
+        assert!(matches!(
+            map.load_config(&json!({"f1": ["enter"]})),
+            Err(Error::InvalidAction(_))
+        ));
+        assert_eq!(map.get(&Event::Key(Key::F1)), None);
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn load_toml() {
+        let mut keymap = Keymap::new();
+        keymap
+            .load_toml("focus_next = [\"tab\", \"ctrl-n\"]")
+            .unwrap();
+        assert_eq!(
+            keymap.get("focus_next"),
+            [Event::Key(Key::Tab), Event::CtrlChar('n')]
+        );
+    }
+}
@@ -46,9 +46,6 @@ pub mod reexports {
     #[cfg(feature = "toml")]
     pub use toml;
 
-    #[cfg(feature = "ansi")]
-    pub use ansi_parser;
-
     pub use serde_json;
 }
 
@@ -56,6 +53,9 @@ pub mod reexports {
 
 pub use cursive_macros::{blueprint, callback_helpers};
 
+#[doc(hidden)]
+pub use cursive_macros::__style;
+
 #[macro_use]
 pub mod utils;
 #[macro_use]
@@ -65,11 +65,18 @@ pub mod views;
 
 pub mod align;
 pub mod backend;
+pub mod clipboard;
+pub mod density;
 pub mod direction;
 pub mod event;
+pub mod jobs;
+pub mod keymap;
 pub mod logger;
 pub mod menu;
+pub mod notifications;
+pub mod profiling;
 pub mod style;
+pub mod test;
 pub mod theme;
 pub mod traits;
 pub mod vec;
@@ -81,21 +88,28 @@ pub use inventory::submit;
 pub mod builder;
 
 pub mod buffer;
+mod chords;
 mod cursive_root;
 mod cursive_run;
 mod dump;
+mod event_source;
 mod printer;
+mod recording;
 mod rect;
+mod statusbar;
+mod timer;
 mod with;
 mod xy;
 
 mod div;
 
-pub use self::cursive_root::{CbSink, Cursive, ScreenId};
+pub use self::cursive_root::{CbSink, Cursive, EventSink, ScreenId};
 pub use self::cursive_run::CursiveRunner;
 pub use self::dump::Dump;
+pub use self::event_source::SourceHandle;
 pub use self::printer::Printer;
 pub use self::rect::Rect;
+pub use self::timer::TimerHandle;
 pub use self::vec::Vec2;
 pub use self::view::View;
 pub use self::with::With;
@@ -8,23 +8,91 @@
 //! * Sub-trees are made of a label, and another `Tree`.
 //! * Delimiters are just there to separate groups of related children.
 //!
+//! Leaves can also be checkboxes or radio items, and show a keyboard
+//! accelerator. Trees created with [`Tree::dynamic`] generate their children
+//! every time they are opened.
+//!
 //! The [menubar] is the main way to show menus.
 //!
 //! [`Tree`]: struct.Tree.html
 //! [menubar]: ../struct.Cursive.html#method.menubar
 
+use crate::event::{Callback, Event};
 use crate::utils::markup::PlainStr;
 use crate::utils::span::{SpannedStr, SpannedText as _};
-use crate::{event::Callback, style::Style, utils::markup::StyledString, Cursive, With};
+use crate::{style::Style, utils::markup::StyledString, Cursive, With};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
 
 static DELIMITER: PlainStr = PlainStr::new_with_width("│", 1);
 
+type Generator = Arc<dyn Fn(&mut Cursive) -> Tree + Send + Sync>;
+
 /// Root of a menu tree.
 #[derive(Default, Clone)]
 pub struct Tree {
     /// Menu items
     pub children: Vec<Item>,
+
+    // If set, children are generated when the tree is opened.
+    generator: Option<Generator>,
+}
+
+/// Group of radio items in a menu.
+///
+/// At most one item of the group is selected: the one whose value is the
+/// group's selection. Clones share the same selection.
+#[derive(Default, Clone, Debug)]
+pub struct RadioGroup {
+    selected: Arc<AtomicUsize>,
+}
+
+impl RadioGroup {
+    /// Creates a new group with the given selection.
+    pub fn new(selected: usize) -> Self {
+        RadioGroup {
+            selected: Arc::new(AtomicUsize::new(selected)),
+        }
+    }
+
+    /// Returns the value of the selected item.
+    pub fn selected(&self) -> usize {
+        self.selected.load(Ordering::Relaxed)
+    }
+
+    /// Selects the item with the given value.
+    pub fn set_selected(&self, value: usize) {
+        self.selected.store(value, Ordering::Relaxed);
+    }
+}
+
+/// Check mark state of a menu leaf.
+#[derive(Clone, Debug)]
+pub enum Check {
+    /// Checkbox item, checked when the flag is set.
+    Checkbox(Arc<AtomicBool>),
+    /// Radio item, checked when the group selects this value.
+    Radio(RadioGroup, usize),
+}
+
+impl Check {
+    /// Returns `true` if the item is currently checked.
+    pub fn is_checked(&self) -> bool {
+        match *self {
+            Check::Checkbox(ref checked) => checked.load(Ordering::Relaxed),
+            Check::Radio(ref group, value) => group.selected() == value,
+        }
+    }
+
+    /// Returns the marker shown before the item label.
+    pub fn marker(&self) -> &'static str {
+        match (self, self.is_checked()) {
+            (Check::Checkbox(_), true) => "[X]",
+            (Check::Checkbox(_), false) => "[ ]",
+            (Check::Radio(..), true) => "(*)",
+            (Check::Radio(..), false) => "( )",
+        }
+    }
 }
 
 /// Node in the menu tree.
@@ -40,6 +108,12 @@ pub enum Item {
         ///
         /// Disabled items cannot be selected and are displayed grayed out.
         enabled: bool,
+        /// Check mark, if this item is a checkbox or a radio item.
+        check: Option<Check>,
+        /// Key shown next to the label, which also runs the callback.
+        ///
+        /// See [`Tree::find_accelerator`].
+        accelerator: Option<Event>,
     },
 
     /// Sub-menu with a label.
@@ -67,8 +141,47 @@ impl Item {
     {
         let label = label.into();
         let cb = Callback::from_fn(cb);
-        let enabled = true;
-        Item::Leaf { label, cb, enabled }
+        Item::Leaf {
+            label,
+            cb,
+            enabled: true,
+            check: None,
+            accelerator: None,
+        }
+    }
+
+    /// Create a new checkbox menu item.
+    ///
+    /// Selecting the item toggles it, then calls `cb` with the new state.
+    pub fn checkbox<S, F>(label: S, checked: bool, cb: F) -> Self
+    where
+        S: Into<StyledString>,
+        F: 'static + Fn(&mut Cursive, bool) + Send + Sync,
+    {
+        let flag = Arc::new(AtomicBool::new(checked));
+        let check = Check::Checkbox(Arc::clone(&flag));
+        Item::leaf(label, move |s| {
+            let checked = !flag.fetch_xor(true, Ordering::Relaxed);
+            cb(s, checked);
+        })
+        .with(|item| item.set_check(Some(check)))
+    }
+
+    /// Create a new radio menu item.
+    ///
+    /// Selecting the item selects `value` in the group, then calls `cb`.
+    pub fn radio<S, F>(label: S, group: &RadioGroup, value: usize, cb: F) -> Self
+    where
+        S: Into<StyledString>,
+        F: 'static + Fn(&mut Cursive) + Send + Sync,
+    {
+        let check = Check::Radio(group.clone(), value);
+        let group = group.clone();
+        Item::leaf(label, move |s| {
+            group.set_selected(value);
+            cb(s);
+        })
+        .with(|item| item.set_check(Some(check)))
     }
 
     /// Create a new subtree menu item.
@@ -130,14 +243,99 @@ impl Item {
     ///
     /// Does not affect delimiters.
     pub fn disable(&mut self) {
+        self.set_enabled(false);
+    }
+
+    /// Enable this item.
+    ///
+    /// Does not affect delimiters.
+    pub fn enable(&mut self) {
+        self.set_enabled(true);
+    }
+
+    /// Enables or disables this item.
+    ///
+    /// Does not affect delimiters.
+    pub fn set_enabled(&mut self, enabled: bool) {
         if let Item::Leaf {
-            ref mut enabled, ..
+            enabled: ref mut e, ..
         }
         | Item::Subtree {
-            ref mut enabled, ..
+            enabled: ref mut e, ..
+        } = self
+        {
+            *e = enabled;
+        }
+    }
+
+    /// Sets the check mark of this item.
+    ///
+    /// This only changes how the item is drawn: use [`Item::checkbox`] or
+    /// [`Item::radio`] for items that update their state when selected.
+    ///
+    /// Only affects leaves.
+    pub fn set_check(&mut self, check: Option<Check>) {
+        if let Item::Leaf {
+            check: ref mut c, ..
+        } = self
+        {
+            *c = check;
+        }
+    }
+
+    /// Returns the check mark of this item, if any.
+    pub fn get_check(&self) -> Option<&Check> {
+        match *self {
+            Item::Leaf { ref check, .. } => check.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this item is a checked checkbox or radio item.
+    pub fn is_checked(&self) -> bool {
+        matches!(self.get_check(), Some(check) if check.is_checked())
+    }
+
+    /// Sets the keyboard accelerator for this item.
+    ///
+    /// The accelerator is shown right-aligned in menu popups. When no view
+    /// uses the event, it runs the callback of this item.
+    ///
+    /// Only affects leaves.
+    pub fn set_accelerator<E: Into<Event>>(&mut self, accelerator: Option<E>) {
+        if let Item::Leaf {
+            accelerator: ref mut a,
+            ..
         } = self
         {
-            *enabled = false;
+            *a = accelerator.map(Into::into);
+        }
+    }
+
+    /// Sets the keyboard accelerator for this item.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::event::Event;
+    /// use cursive_core::menu::{Item, Tree};
+    ///
+    /// let tree = Tree::new().item(Item::leaf("Save", |_| ()).accelerator(Event::CtrlChar('s')));
+    /// ```
+    #[must_use]
+    pub fn accelerator<E: Into<Event>>(self, accelerator: E) -> Self {
+        self.with(|item| item.set_accelerator(Some(accelerator)))
+    }
+
+    /// Returns the keyboard accelerator of this item, if any.
+    pub fn get_accelerator(&self) -> Option<&Event> {
+        match *self {
+            Item::Leaf {
+                ref accelerator, ..
+            } => accelerator.as_ref(),
+            _ => None,
         }
     }
 
@@ -172,9 +370,50 @@ impl Tree {
     pub const fn new() -> Self {
         Self {
             children: Vec::new(),
+            generator: None,
+        }
+    }
+
+    /// Creates a tree whose children are generated every time it is opened.
+    ///
+    /// This is useful for menus that change often, like recently opened files.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::menu::Tree;
+    ///
+    /// let tree = Tree::new().subtree(
+    ///     "Recent files",
+    ///     Tree::dynamic(|_| {
+    ///         Tree::new()
+    ///             .leaf("notes.txt", |_| ())
+    ///             .leaf("todo.txt", |_| ())
+    ///     }),
+    /// );
+    /// ```
+    pub fn dynamic<F>(generator: F) -> Self
+    where
+        F: 'static + Fn(&mut Cursive) -> Tree + Send + Sync,
+    {
+        Self {
+            children: Vec::new(),
+            generator: Some(Arc::new(generator)),
         }
     }
 
+    /// Returns `true` if this tree generates its children when opened.
+    pub fn is_dynamic(&self) -> bool {
+        self.generator.is_some()
+    }
+
+    /// Generates the children of a dynamic tree.
+    ///
+    /// Returns `None` if this tree is not dynamic.
+    pub fn generate(&self, siv: &mut Cursive) -> Option<Tree> {
+        self.generator.as_ref().map(|generator| generator(siv))
+    }
+
     /// Remove every children from this tree.
     pub fn clear(&mut self) {
         self.children.clear();
@@ -218,15 +457,7 @@ impl Tree {
         S: Into<StyledString>,
         F: 'static + Fn(&mut Cursive) + Send + Sync,
     {
-        let label = label.into();
-        self.insert(
-            i,
-            Item::Leaf {
-                label,
-                cb: Callback::from_fn(cb),
-                enabled: true,
-            },
-        );
+        self.insert(i, Item::leaf(label, cb));
     }
 
     /// Adds a actionnable leaf to the end of this tree - chainable variant.
@@ -239,6 +470,48 @@ impl Tree {
         self.with(|menu| menu.add_leaf(label, cb))
     }
 
+    /// Adds a checkbox to the end of this tree.
+    ///
+    /// See [`Item::checkbox`].
+    pub fn add_checkbox<S, F>(&mut self, label: S, checked: bool, cb: F)
+    where
+        S: Into<StyledString>,
+        F: 'static + Fn(&mut Cursive, bool) + Send + Sync,
+    {
+        self.add_item(Item::checkbox(label, checked, cb));
+    }
+
+    /// Adds a checkbox to the end of this tree - chainable variant.
+    #[must_use]
+    pub fn checkbox<S, F>(self, label: S, checked: bool, cb: F) -> Self
+    where
+        S: Into<StyledString>,
+        F: 'static + Fn(&mut Cursive, bool) + Send + Sync,
+    {
+        self.with(|menu| menu.add_checkbox(label, checked, cb))
+    }
+
+    /// Adds a radio item to the end of this tree.
+    ///
+    /// See [`Item::radio`].
+    pub fn add_radio<S, F>(&mut self, label: S, group: &RadioGroup, value: usize, cb: F)
+    where
+        S: Into<StyledString>,
+        F: 'static + Fn(&mut Cursive) + Send + Sync,
+    {
+        self.add_item(Item::radio(label, group, value, cb));
+    }
+
+    /// Adds a radio item to the end of this tree - chainable variant.
+    #[must_use]
+    pub fn radio<S, F>(self, label: S, group: &RadioGroup, value: usize, cb: F) -> Self
+    where
+        S: Into<StyledString>,
+        F: 'static + Fn(&mut Cursive) + Send + Sync,
+    {
+        self.with(|menu| menu.add_radio(label, group, value, cb))
+    }
+
     /// Inserts a subtree at the given position.
     pub fn insert_subtree<S>(&mut self, i: usize, label: S, tree: Tree)
     where
@@ -342,6 +615,28 @@ impl Tree {
     pub fn is_empty(&self) -> bool {
         self.children.is_empty()
     }
+
+    /// Looks for an enabled leaf with the given accelerator.
+    ///
+    /// Searches nested subtrees too, except disabled or dynamic ones.
+    ///
+    /// Returns the callback of the leaf, if one was found.
+    pub fn find_accelerator(&self, event: &Event) -> Option<Callback> {
+        self.children.iter().find_map(|child| match *child {
+            Item::Leaf {
+                ref cb,
+                enabled: true,
+                accelerator: Some(ref accelerator),
+                ..
+            } if accelerator == event => Some(cb.clone()),
+            Item::Subtree {
+                ref tree,
+                enabled: true,
+                ..
+            } => tree.find_accelerator(event),
+            _ => None,
+        })
+    }
 }
 #[cfg(test)]
 mod tests {
@@ -363,6 +658,7 @@ mod tests {
                     front: ColorType::InheritParent,
                     back: ColorType::InheritParent,
                 },
+                underline: ColorType::InheritParent,
             },
             width: 1,
         }];
@@ -377,6 +673,8 @@ mod tests {
             label: label.clone(),
             enabled: true,
             cb: Callback::from_fn(|_| {}),
+            check: None,
+            accelerator: None,
         };
         let styled_label = item.styled_label();
         assert_eq!(styled_label.source(), "Leaf");
@@ -393,4 +691,48 @@ mod tests {
         let styled_label = item.styled_label();
         assert_eq!(styled_label.source(), "Subtree");
     }
+
+    #[test]
+    fn checks() {
+        let group = RadioGroup::new(0);
+        let tree = Tree::new()
+            .checkbox("Wrap", false, |_, _| ())
+            .radio("Small", &group, 0, |_| ())
+            .radio("Large", &group, 1, |_| ());
+
+        let mut siv = Cursive::new();
+        let run = |siv: &mut Cursive, i: usize| match tree.children[i] {
+            Item::Leaf { ref cb, .. } => cb(siv),
+            _ => unreachable!(),
+        };
+        let checked = || {
+            tree.children
+                .iter()
+                .map(Item::is_checked)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(checked(), [false, true, false]);
+        run(&mut siv, 0);
+        run(&mut siv, 2);
+        assert_eq!(checked(), [true, false, true]);
+        assert_eq!(group.selected(), 1);
+        run(&mut siv, 0);
+        assert_eq!(checked(), [false, false, true]);
+    }
+
+    #[test]
+    fn find_accelerator() {
+        let mut tree = Tree::new().subtree(
+            "File",
+            Tree::new().item(Item::leaf("Save", |_| ()).accelerator(Event::CtrlChar('s'))),
+        );
+
+        assert!(tree.find_accelerator(&Event::CtrlChar('s')).is_some());
+        assert!(tree.find_accelerator(&Event::CtrlChar('o')).is_none());
+
+        // Disabled items don't react.
+        tree.find_subtree("File").unwrap().children[0].disable();
+        assert!(tree.find_accelerator(&Event::CtrlChar('s')).is_none());
+    }
 }
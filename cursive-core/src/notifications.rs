@@ -0,0 +1,346 @@
+//! Non-blocking notifications shown over the views.
+//!
+//! See [`Cursive::notify`].
+use crate::align::Align;
+use crate::style::{ColorStyle, PaletteColor, StyleType};
+use crate::utils::lines::spans::LinesIterator;
+use crate::utils::markup::StyledString;
+use crate::{Cursive, Printer, Rect, Vec2};
+use std::collections::VecDeque;
+use std::time::Duration;
+
+// Default number of notifications shown at once.
+const DEFAULT_MAX_VISIBLE: usize = 5;
+
+/// How important a notification is.
+///
+/// This decides the style of the notification. See [`Cursive::set_notification_style`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Severity {
+    /// Uses the `HighlightInactive` palette color by default.
+    Info,
+    /// Uses the `TitleSecondary` palette color by default.
+    Warning,
+    /// Uses the `Highlight` palette color by default.
+    Error,
+}
+
+impl Severity {
+    fn index(self) -> usize {
+        match self {
+            Severity::Info => 0,
+            Severity::Warning => 1,
+            Severity::Error => 2,
+        }
+    }
+}
+
+/// Identifies a notification sent with [`Cursive::notify`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NotificationId(usize);
+
+struct Notification {
+    id: NotificationId,
+    text: StyledString,
+    severity: Severity,
+    duration: Duration,
+}
+
+/// Toasts shown over the views.
+pub(crate) struct Notifications {
+    // Notifications currently shown, from the corner outward.
+    visible: Vec<Notification>,
+
+    // Notifications waiting for some room.
+    queue: VecDeque<Notification>,
+
+    next_id: usize,
+
+    max_visible: usize,
+
+    align: Align,
+
+    styles: [StyleType; 3],
+
+    // `true` if the notifications changed since the last draw.
+    changed: bool,
+}
+
+impl Default for Notifications {
+    fn default() -> Self {
+        let style = |back| StyleType::from(ColorStyle::new(PaletteColor::HighlightText, back));
+        Notifications {
+            visible: Vec::new(),
+            queue: VecDeque::new(),
+            next_id: 0,
+            max_visible: DEFAULT_MAX_VISIBLE,
+            align: Align::top_right(),
+            styles: [
+                style(PaletteColor::HighlightInactive),
+                style(PaletteColor::TitleSecondary),
+                style(PaletteColor::Highlight),
+            ],
+            changed: false,
+        }
+    }
+}
+
+impl Notifications {
+    fn push(
+        &mut self,
+        text: StyledString,
+        severity: Severity,
+        duration: Duration,
+    ) -> NotificationId {
+        let id = NotificationId(self.next_id);
+        self.next_id += 1;
+        self.queue.push_back(Notification {
+            id,
+            text,
+            severity,
+            duration,
+        });
+        id
+    }
+
+    fn remove(&mut self, id: NotificationId) {
+        let len = self.visible.len();
+        self.visible.retain(|notification| notification.id != id);
+        self.changed |= self.visible.len() != len;
+        self.queue.retain(|notification| notification.id != id);
+    }
+
+    pub fn set_max_visible(&mut self, max_visible: usize) {
+        self.max_visible = max_visible.max(1);
+    }
+
+    pub fn set_align(&mut self, align: Align) {
+        self.align = align;
+        self.changed = true;
+    }
+
+    pub fn set_style(&mut self, severity: Severity, style: StyleType) {
+        self.styles[severity.index()] = style;
+        self.changed = true;
+    }
+
+    pub fn clear(&mut self) {
+        self.changed |= !self.visible.is_empty();
+        self.visible.clear();
+        self.queue.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.visible.len() + self.queue.len()
+    }
+
+    /// Returns the area to redraw, if the notifications changed.
+    pub fn dirty_area(&self, screen_size: Vec2) -> Option<Rect> {
+        // Removed notifications reveal the views below, so redraw everything.
+        self.changed
+            .then(|| Rect::from_size(Vec2::zero(), screen_size))
+    }
+
+    /// Draws the visible notifications in their corner.
+    pub fn draw(&mut self, printer: &Printer) {
+        self.changed = false;
+
+        let max_width = (printer.size.x / 2).max(20).min(printer.size.x);
+        let mut y = 0;
+        for notification in &self.visible {
+            // Each toast has one column of padding on each side.
+            let width = max_width.saturating_sub(2);
+            let rows: Vec<_> = LinesIterator::new(&notification.text, width).collect();
+            let size = Vec2::new(
+                rows.iter().map(|row| row.width).max().unwrap_or(0) + 2,
+                rows.len(),
+            );
+            if y + size.y > printer.size.y {
+                break;
+            }
+
+            let x = self.align.h.get_offset(size.x, printer.size.x);
+            let top = match self.align.v {
+                crate::align::VAlign::Top => y,
+                // Stack from the middle row downward.
+                crate::align::VAlign::Center => printer.size.y / 2 + y,
+                crate::align::VAlign::Bottom => printer.size.y - y - size.y,
+            };
+            if top + size.y > printer.size.y {
+                break;
+            }
+
+            let printer = printer.offset((x, top)).cropped(size);
+            printer.with_style(self.styles[notification.severity.index()], |printer| {
+                for row_y in 0..size.y {
+                    printer.print_hline((0, row_y), size.x, " ");
+                }
+                for (row_y, row) in rows.iter().enumerate() {
+                    let mut x = 1;
+                    for span in row.resolve_stream(&notification.text) {
+                        printer.with_style(*span.attr, |printer| {
+                            printer.print((x, row_y), span.content);
+                        });
+                        x += span.width;
+                    }
+                }
+            });
+
+            // Leave a blank row between notifications.
+            y += size.y + 1;
+        }
+    }
+}
+
+impl Cursive {
+    /// Shows queued notifications, as long as there is room.
+    fn show_queued_notifications(&mut self) {
+        while self.notifications.visible.len() < self.notifications.max_visible {
+            let Some(notification) = self.notifications.queue.pop_front() else {
+                break;
+            };
+
+            let id = notification.id;
+            self.set_timeout(notification.duration, move |s| {
+                s.dismiss_notification(id);
+            });
+            self.notifications.visible.push(notification);
+            self.notifications.changed = true;
+        }
+    }
+
+    /// Shows a notification for the given duration.
+    ///
+    /// Notifications are shown over all views, stacked in a corner of the
+    /// screen (the top-right corner by default), and don't take the focus.
+    /// When too many notifications are shown, new ones wait for some room.
+    ///
+    /// The duration starts when the notification is shown.
+    ///
+    /// This is the same as `notify_with_severity(text, Severity::Info, duration)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// # use std::time::Duration;
+    /// let mut siv = Cursive::new();
+    ///
+    /// siv.notify("File saved", Duration::from_secs(3));
+    /// ```
+    pub fn notify<S>(&mut self, text: S, duration: Duration) -> NotificationId
+    where
+        S: Into<StyledString>,
+    {
+        self.notify_with_severity(text, Severity::Info, duration)
+    }
+
+    /// Shows a notification with the given severity.
+    ///
+    /// See [`Cursive::notify`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// # use cursive_core::notifications::Severity;
+    /// # use std::time::Duration;
+    /// let mut siv = Cursive::new();
+    ///
+    /// siv.notify_with_severity("Connection lost", Severity::Error, Duration::from_secs(5));
+    /// ```
+    pub fn notify_with_severity<S>(
+        &mut self,
+        text: S,
+        severity: Severity,
+        duration: Duration,
+    ) -> NotificationId
+    where
+        S: Into<StyledString>,
+    {
+        let id = self.notifications.push(text.into(), severity, duration);
+        self.show_queued_notifications();
+        id
+    }
+
+    /// Removes a notification before it expires.
+    pub fn dismiss_notification(&mut self, id: NotificationId) {
+        self.notifications.remove(id);
+        self.show_queued_notifications();
+    }
+
+    /// Removes all notifications, including queued ones.
+    pub fn clear_notifications(&mut self) {
+        self.notifications.clear();
+    }
+
+    /// Returns the number of notifications, including queued ones.
+    pub fn notification_count(&self) -> usize {
+        self.notifications.len()
+    }
+
+    /// Sets where notifications are shown.
+    ///
+    /// Notifications are stacked vertically from this corner. Defaults to the
+    /// top-right corner.
+    pub fn set_notification_align(&mut self, align: Align) {
+        self.notifications.set_align(align);
+    }
+
+    /// Sets how many notifications can be shown at once.
+    ///
+    /// Other notifications are queued until some are dismissed. Defaults to 5.
+    pub fn set_max_notifications(&mut self, max: usize) {
+        self.notifications.set_max_visible(max);
+        self.show_queued_notifications();
+    }
+
+    /// Sets the style used for notifications of the given severity.
+    pub fn set_notification_style<S>(&mut self, severity: Severity, style: S)
+    where
+        S: Into<StyleType>,
+    {
+        self.notifications.set_style(severity, style.into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Severity;
+    use crate::test::Harness;
+    use crate::Cursive;
+    use std::time::Duration;
+
+    #[test]
+    fn stacked_and_queued() {
+        let mut siv = Cursive::new();
+        siv.set_max_notifications(2);
+        let first = siv.notify("First", Duration::from_secs(60));
+        siv.notify_with_severity("Second", Severity::Warning, Duration::from_secs(60));
+        siv.notify_with_severity("Third", Severity::Error, Duration::ZERO);
+        assert_eq!(siv.notification_count(), 3);
+
+        let mut harness = Harness::with_cursive(siv, (30, 6));
+        let screen = harness.screen();
+        assert!(screen.row(0).ends_with(" First "));
+        assert!(screen.row(2).ends_with(" Second "));
+        assert!(!screen.contains("Third"));
+
+        // The queued notification is shown once there is room, and expires on its own.
+        harness.cursive().dismiss_notification(first);
+        assert!(harness.screen().row(2).ends_with(" Third "));
+        harness.cursive().process_timers();
+        assert!(!harness.screen().contains("Third"));
+        assert_eq!(harness.cursive().notification_count(), 1);
+    }
+
+    #[test]
+    fn corner() {
+        let mut siv = Cursive::new();
+        siv.set_notification_align(crate::align::Align::bot_left());
+        siv.notify("Saved", Duration::from_secs(60));
+
+        let mut harness = Harness::with_cursive(siv, (30, 6));
+        assert!(harness.screen().row(5).starts_with(" Saved "));
+    }
+}
@@ -1,6 +1,7 @@
 //! Provide higher-level abstraction to draw things on buffers.
 
-use crate::buffer::{PrintBuffer, Window};
+use crate::backend::GraphicsProtocol;
+use crate::buffer::{ascii_fallback, PrintBuffer, Window};
 use crate::direction::Orientation;
 use crate::rect::Rect;
 use crate::style::{
@@ -15,8 +16,10 @@ use crate::Vec2;
 
 use enumset::EnumSet;
 use parking_lot::RwLock;
+use std::borrow::Cow;
 use std::cell::Cell;
 use std::cmp::min;
+use std::sync::Arc;
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
@@ -92,6 +95,7 @@ impl<'a, 'b> Printer<'a, 'b> {
             current_style: Cell::new(ConcreteStyle {
                 color: ColorPair::terminal_default(),
                 effects: EnumSet::empty(),
+                underline: None,
             }),
         }
     }
@@ -108,6 +112,93 @@ impl<'a, 'b> Printer<'a, 'b> {
         self.buffer.read().size()
     }
 
+    /// Returns the graphics protocol available to draw images, if any.
+    ///
+    /// See [`Printer::print_graphics`].
+    pub fn graphics_protocol(&self) -> Option<GraphicsProtocol> {
+        self.buffer.read().graphics_protocol()
+    }
+
+    /// Returns the size of a cell in pixels, if known.
+    pub fn cell_pixel_size(&self) -> Option<Vec2> {
+        self.buffer.read().cell_pixel_size()
+    }
+
+    /// Draws an image over the given area, using the graphics protocol.
+    ///
+    /// `data` is the escape sequence drawing the image at the cursor, for the
+    /// protocol returned by [`Printer::graphics_protocol`]. The cells in this
+    /// area should be printed first, as a fallback.
+    ///
+    /// Images cannot be cropped: this returns `false` and does nothing if the area
+    /// is not entirely visible, or if there is no graphics protocol.
+    pub fn print_graphics<S, T>(&self, start: S, size: T, data: Arc<str>) -> bool
+    where
+        S: Into<Vec2>,
+        T: Into<Vec2>,
+    {
+        let start = start.into();
+        let size = size.into();
+
+        let visible = Rect::from_size(self.content_offset, self.output_size);
+        let area = Rect::from_size(start, size);
+        if self.graphics_protocol().is_none()
+            || !Vec2::zero().strictly_lt(size)
+            || !Vec2::zero().strictly_lt(self.output_size)
+            || !visible.contains_rect(area)
+        {
+            return false;
+        }
+
+        let area = Rect::from_size(self.offset + start - self.content_offset, size);
+        self.buffer.write().print_graphics(area, data);
+        true
+    }
+
+    /// Returns a printer with the same settings, drawing on a separate buffer.
+    ///
+    /// The output window will start at the top-left corner of `buffer`.
+    pub(crate) fn detached<'c>(&self, buffer: &'c RwLock<PrintBuffer>) -> Printer<'a, 'c> {
+        Printer {
+            offset: Vec2::zero(),
+            output_size: self.output_size,
+            size: self.size,
+            content_offset: self.content_offset,
+            focused: self.focused,
+            enabled: self.enabled,
+            theme: self.theme,
+            current_style: self.current_style.clone(),
+            buffer,
+        }
+    }
+
+    /// Copies the content of `source` to the output window.
+    ///
+    /// Cells never written to in `source` are left untouched.
+    pub(crate) fn print_buffer(&self, source: &PrintBuffer) {
+        let mut buffer = self.buffer.write();
+        let size = Vec2::min(source.size(), self.output_size);
+
+        for y in 0..size.y {
+            for x in 0..size.x {
+                let pos = Vec2::new(x, y);
+                if let Some(cell) = source.cell_at(pos) {
+                    buffer.print_at(self.offset + pos, cell.text(), cell.style());
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if the output of the view with the given name should be captured.
+    pub(crate) fn is_capturing(&self, name: &str) -> bool {
+        self.buffer.read().is_capturing(name)
+    }
+
+    /// Stores the captured output of a view.
+    pub(crate) fn set_captured(&self, buffer: PrintBuffer) {
+        self.buffer.write().set_captured(buffer);
+    }
+
     /// Clear the screen.
     ///
     /// It will discard anything drawn before.
@@ -259,7 +350,23 @@ impl<'a, 'b> Printer<'a, 'b> {
         let start = start + self.offset;
         self.buffer
             .write()
-            .print_at(start, text, self.current_style());
+            .print_at(start, &self.glyphs(text), self.current_style());
+    }
+
+    /// Returns `text` as it should be printed with the current theme.
+    ///
+    /// If the theme is in ASCII mode, known glyphs are replaced with ASCII
+    /// approximations of the same width.
+    fn glyphs<'s>(&self, text: &'s str) -> Cow<'s, str> {
+        if !self.theme.ascii || text.is_ascii() {
+            return Cow::Borrowed(text);
+        }
+
+        Cow::Owned(
+            text.graphemes(true)
+                .map(|g| ascii_fallback(g).unwrap_or(g))
+                .collect(),
+        )
     }
 
     /// Prints a vertical line using the given character.
@@ -292,13 +399,31 @@ impl<'a, 'b> Printer<'a, 'b> {
         let height = min(height, self.output_size.y - start.y);
 
         let start = start + self.offset;
+        let c = self.glyphs(c);
         for y in 0..height {
             self.buffer
                 .write()
-                .print_at(start + (0, y), c, self.current_style());
+                .print_at(start + (0, y), &c, self.current_style());
         }
     }
 
+    /// Returns `true` if `text` was already printed at the given position.
+    ///
+    /// The comparison uses the glyphs of the current theme.
+    pub(crate) fn is_printed_at<T: Into<Vec2>>(&self, pos: T, text: &str) -> bool {
+        let pos = pos.into();
+        if !pos.fits(self.content_offset) {
+            return false;
+        }
+
+        let pos = pos - self.content_offset;
+        if !pos.strictly_lt(self.output_size) {
+            return false;
+        }
+
+        self.buffer.read().cell_text(pos + self.offset) == Some(&*self.glyphs(text))
+    }
+
     /// Calls a closure on the output window for this printer.
     pub fn on_window<F, R>(&self, f: F) -> R
     where
@@ -332,6 +457,26 @@ impl<'a, 'b> Printer<'a, 'b> {
         }
     }
 
+    /// Fills a rectangle using the given character and style.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::{Printer, Rect};
+    /// use cursive_core::style::PaletteStyle;
+    ///
+    /// # fn with_printer(printer: &Printer) {
+    /// // Clear an area with the highlight color.
+    /// printer.fill_rect(Rect::from_size((1, 1), (4, 2)), " ", PaletteStyle::Highlight);
+    /// # }
+    /// ```
+    pub fn fill_rect<S>(&self, rect: Rect, c: &str, style: S)
+    where
+        S: Into<StyleType>,
+    {
+        self.with_style(style, |printer| printer.print_rect(rect, c));
+    }
+
     /// Prints a horizontal line using the given character.
     pub fn print_hline<T: Into<Vec2>>(&self, start: T, width: usize, c: &str) {
         let start = start.into();
@@ -362,10 +507,11 @@ impl<'a, 'b> Printer<'a, 'b> {
         let repetitions = min(width, self.output_size.x - start.x) / c_width;
 
         let mut start = start + self.offset;
+        let c = self.glyphs(c);
         let mut buffer = self.buffer.write();
         let style = self.current_style();
         for _ in 0..repetitions {
-            buffer.print_at(start, c, style);
+            buffer.print_at(start, &c, style);
             start.x += c_width;
         }
     }
@@ -520,27 +666,52 @@ impl<'a, 'b> Printer<'a, 'b> {
     /// # }
     /// ```
     pub fn print_box<T: Into<Vec2>, S: Into<Vec2>>(&self, start: T, size: S, invert: bool) {
-        let start = start.into();
-        let size = size.into();
+        self.print_borders(start.into(), size.into(), self.theme.borders, invert);
+    }
+
+    /// Prints a frame around `rect`, using the given border style.
+    ///
+    /// Unlike [`Printer::print_box`], this ignores the borders from the theme.
+    /// Outset frames use [`PaletteStyle::Tertiary`] for the top-left edges,
+    /// and [`PaletteStyle::Primary`] for the rest.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::{Printer, Rect};
+    /// use cursive_core::style::BorderStyle;
+    ///
+    /// # fn with_printer(printer: &Printer) {
+    /// printer.print_frame(Rect::from_size((0, 0), (6, 4)), BorderStyle::Outset);
+    /// # }
+    /// ```
+    pub fn print_frame(&self, rect: Rect, borders: BorderStyle) {
+        self.print_borders(rect.top_left(), rect.size(), borders, false);
+    }
 
+    fn print_borders(&self, start: Vec2, size: Vec2, borders: BorderStyle, invert: bool) {
         if size.x < 2 || size.y < 2 {
             return;
         }
         let size = size - (1, 1);
 
-        self.with_high_border(invert, |s| {
-            s.print(start, "┌");
-            s.print(start + size.keep_y(), "└");
-            s.print_hline(start + (1, 0), size.x - 1, "─");
-            s.print_vline(start + (0, 1), size.y - 1, "│");
-        });
+        if let Some(style) = border_style(borders, invert, true) {
+            self.with_style(style, |s| {
+                s.print(start, "┌");
+                s.print(start + size.keep_y(), "└");
+                s.print_hline(start + (1, 0), size.x - 1, "─");
+                s.print_vline(start + (0, 1), size.y - 1, "│");
+            });
+        }
 
-        self.with_low_border(invert, |s| {
-            s.print(start + size.keep_x(), "┐");
-            s.print(start + size, "┘");
-            s.print_hline(start + (1, 0) + size.keep_y(), size.x - 1, "─");
-            s.print_vline(start + (0, 1) + size.keep_x(), size.y - 1, "│");
-        });
+        if let Some(style) = border_style(borders, invert, false) {
+            self.with_style(style, |s| {
+                s.print(start + size.keep_x(), "┐");
+                s.print(start + size, "┘");
+                s.print_hline(start + (1, 0) + size.keep_y(), size.x - 1, "─");
+                s.print_vline(start + (0, 1) + size.keep_x(), size.y - 1, "│");
+            });
+        }
     }
 
     /// Runs the given function using a color depending on the theme.
@@ -553,13 +724,9 @@ impl<'a, 'b> Printer<'a, 'b> {
     where
         F: FnOnce(&Printer),
     {
-        let style = match self.theme.borders {
-            BorderStyle::None => return,
-            BorderStyle::Outset if !invert => PaletteStyle::Tertiary,
-            _ => PaletteStyle::Primary,
-        };
-
-        self.with_style(style, f);
+        if let Some(style) = border_style(self.theme.borders, invert, true) {
+            self.with_style(style, f);
+        }
     }
 
     /// Runs the given function using a color depending on the theme.
@@ -572,13 +739,51 @@ impl<'a, 'b> Printer<'a, 'b> {
     where
         F: FnOnce(&Printer),
     {
-        let color = match self.theme.borders {
-            BorderStyle::None => return,
-            BorderStyle::Outset if invert => PaletteStyle::Tertiary,
-            _ => PaletteStyle::Primary,
-        };
+        if let Some(style) = border_style(self.theme.borders, invert, false) {
+            self.with_style(style, f);
+        }
+    }
 
-        self.with_style(color, f);
+    /// Highlights already printed text, as selected.
+    ///
+    /// Covers the cells from `start` to `end` (excluded) in reading order: the
+    /// end of the first row, every row in between, and the start of the last row.
+    ///
+    /// Like [`Printer::with_selection`], this uses [`PaletteStyle::Highlight`] if the printer
+    /// has the focus, and [`PaletteStyle::HighlightInactive`] otherwise.
+    pub fn highlight_selection<S, E>(&self, start: S, end: E)
+    where
+        S: Into<Vec2>,
+        E: Into<Vec2>,
+    {
+        let start = start.into();
+        let end = end.into();
+        if (start.y, start.x) >= (end.y, end.x) {
+            return;
+        }
+
+        let style = if self.focused {
+            PaletteStyle::Highlight
+        } else {
+            PaletteStyle::HighlightInactive
+        };
+        let style = self.theme.palette[style];
+
+        self.on_window(|window| {
+            for y in start.y..=end.y {
+                let first = if y == start.y { start.x } else { 0 };
+                let last = if y == end.y { end.x } else { self.size.x };
+
+                for x in first..last {
+                    let Some(pos) = Vec2::new(x, y).checked_sub(self.content_offset) else {
+                        continue;
+                    };
+                    if let Some(cell_style) = window.style_at_mut(pos) {
+                        *cell_style = style.resolve(&self.theme.palette, *cell_style);
+                    }
+                }
+            }
+        });
     }
 
     /// Apply a selection style and call the given function.
@@ -667,6 +872,31 @@ impl<'a, 'b> Printer<'a, 'b> {
         self.offset(viewport.top_left()).cropped(viewport.size())
     }
 
+    /// Returns a new sub-printer that only prints in the given area.
+    ///
+    /// Unlike `windowed`, this does not move the content: prints outside of
+    /// `area` are simply discarded.
+    #[must_use]
+    pub fn clipped(&self, area: Rect) -> Self {
+        self.clone().with(|s| {
+            let visible = Rect::from_size(s.content_offset, s.output_size);
+            let clip = if s.output_size.x > 0 && s.output_size.y > 0 {
+                visible.intersection(area)
+            } else {
+                None
+            };
+
+            match clip {
+                Some(clip) => {
+                    s.offset = s.offset + (clip.top_left() - s.content_offset);
+                    s.content_offset = clip.top_left();
+                    s.output_size = clip.size();
+                }
+                None => s.output_size = Vec2::zero(),
+            }
+        })
+    }
+
     /// Returns a new sub-printer with a cropped area.
     ///
     /// The new printer size will be the minimum of `size` and its current size.\
@@ -755,3 +985,39 @@ impl<'a, 'b> Printer<'a, 'b> {
         })
     }
 }
+
+// Style for the top-left (`high`) or bottom-right edges of a border.
+fn border_style(borders: BorderStyle, invert: bool, high: bool) -> Option<PaletteStyle> {
+    match borders {
+        BorderStyle::None => None,
+        BorderStyle::Outset if invert != high => Some(PaletteStyle::Tertiary),
+        _ => Some(PaletteStyle::Primary),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::render_view;
+    use crate::traits::Resizable;
+    use crate::views::Canvas;
+
+    #[test]
+    fn frames() {
+        let canvas = Canvas::new(()).with_draw(|_, printer| {
+            printer.fill_rect(
+                Rect::from_size((1, 1), (3, 1)),
+                "#",
+                PaletteStyle::Highlight,
+            );
+            printer.print_frame(Rect::from_size((0, 0), (5, 3)), BorderStyle::Simple);
+            printer.print_frame(Rect::from_size((5, 0), (2, 2)), BorderStyle::None);
+        });
+
+        let screen = render_view(canvas.fixed_size((7, 3)), (7, 3));
+        assert_eq!(screen.rows(), ["┌───┐  ", "│###│  ", "└───┘  "]);
+
+        // Only the filled area is highlighted.
+        assert_ne!(screen.style_at((2, 1)), screen.style_at((0, 1)));
+    }
+}
@@ -0,0 +1,161 @@
+//! Input latency metrics.
+//!
+//! Each input event is timestamped when it is polled from the backend. Once
+//! the frame showing the result of these events has been presented, the
+//! time elapsed since the oldest of them is recorded as the frame latency.
+//!
+//! This measures how long the application takes to react to user input,
+//! which makes it possible to compare backends or to quantify reports of
+//! sluggishness. Events injected by the application, callbacks and timers
+//! are not timestamped, and do not count towards latency.
+//!
+//! Use [`Cursive::latency_stats`] to get the metrics collected so far, and
+//! [`Cursive::on_frame`] to be notified after each frame.
+//!
+//! [`Cursive::latency_stats`]: crate::Cursive::latency_stats
+//! [`Cursive::on_frame`]: crate::Cursive::on_frame
+use std::time::{Duration, Instant};
+
+/// Latency metrics for a single presented frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FrameMetrics {
+    /// Number of input events handled in this frame.
+    pub events: usize,
+
+    /// Time between polling the oldest event and presenting the frame.
+    pub max_latency: Duration,
+
+    /// Time between polling the newest event and presenting the frame.
+    pub min_latency: Duration,
+
+    /// When the frame was presented.
+    pub presented: Instant,
+}
+
+/// Latency metrics aggregated over all frames since the last reset.
+///
+/// Only frames handling at least one input event are counted.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LatencyStats {
+    frames: usize,
+    events: usize,
+    total: Duration,
+    max: Duration,
+    last: Option<FrameMetrics>,
+}
+
+impl LatencyStats {
+    /// Returns the number of frames measured.
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    /// Returns the number of input events measured.
+    pub fn events(&self) -> usize {
+        self.events
+    }
+
+    /// Returns the average frame latency, if any frame was measured.
+    pub fn mean(&self) -> Option<Duration> {
+        let frames = u32::try_from(self.frames).ok().filter(|&n| n > 0)?;
+        Some(self.total / frames)
+    }
+
+    /// Returns the largest frame latency measured.
+    pub fn max(&self) -> Duration {
+        self.max
+    }
+
+    /// Returns the metrics for the last measured frame.
+    pub fn last_frame(&self) -> Option<&FrameMetrics> {
+        self.last.as_ref()
+    }
+
+    fn add(&mut self, frame: FrameMetrics) {
+        self.frames += 1;
+        self.events += frame.events;
+        self.total += frame.max_latency;
+        self.max = self.max.max(frame.max_latency);
+        self.last = Some(frame);
+    }
+}
+
+/// Collects the timestamps of input events until they are presented.
+#[derive(Default)]
+pub(crate) struct Profiler {
+    // Oldest and newest pending events, and how many are pending.
+    pending: Option<(Instant, Instant, usize)>,
+
+    stats: LatencyStats,
+}
+
+impl Profiler {
+    /// Records an event polled from the backend at `timestamp`.
+    pub fn event_polled(&mut self, timestamp: Instant) {
+        self.pending = Some(match self.pending {
+            Some((oldest, _, count)) => (oldest, timestamp, count + 1),
+            None => (timestamp, timestamp, 1),
+        });
+    }
+
+    /// Records a frame presented at `presented`.
+    ///
+    /// Returns the metrics for this frame, if it handled any input event.
+    pub fn frame_presented(&mut self, presented: Instant) -> Option<FrameMetrics> {
+        let (oldest, newest, events) = self.pending.take()?;
+        let frame = FrameMetrics {
+            events,
+            max_latency: presented.saturating_duration_since(oldest),
+            min_latency: presented.saturating_duration_since(newest),
+            presented,
+        };
+        self.stats.add(frame);
+        Some(frame)
+    }
+
+    pub fn stats(&self) -> &LatencyStats {
+        &self.stats
+    }
+
+    pub fn reset(&mut self) {
+        self.stats = LatencyStats::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn latency() {
+        let start = Instant::now();
+        let ms = |n| start + Duration::from_millis(n);
+
+        let mut profiler = Profiler::default();
+
+        // Nothing to measure without input.
+        assert_eq!(profiler.frame_presented(ms(5)), None);
+        assert_eq!(profiler.stats().mean(), None);
+
+        profiler.event_polled(ms(10));
+        profiler.event_polled(ms(12));
+        let frame = profiler.frame_presented(ms(20)).unwrap();
+        assert_eq!(frame.events, 2);
+        assert_eq!(frame.max_latency, Duration::from_millis(10));
+        assert_eq!(frame.min_latency, Duration::from_millis(8));
+
+        profiler.event_polled(ms(30));
+        profiler.frame_presented(ms(50));
+        assert_eq!(profiler.frame_presented(ms(60)), None);
+
+        let stats = profiler.stats();
+        assert_eq!(stats.frames(), 2);
+        assert_eq!(stats.events(), 3);
+        assert_eq!(stats.mean(), Some(Duration::from_millis(15)));
+        assert_eq!(stats.max(), Duration::from_millis(20));
+        assert_eq!(stats.last_frame().unwrap().presented, ms(50));
+
+        profiler.reset();
+        assert_eq!(profiler.stats().frames(), 0);
+    }
+}
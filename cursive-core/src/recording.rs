@@ -0,0 +1,369 @@
+use crate::event::{Event, Key, MouseButton, MouseEvent};
+use crate::Vec2;
+use serde_json::{json, Value};
+use std::io::{self, BufRead, Write};
+use std::time::{Duration, Instant};
+
+// Names used for the keys in the event log.
+const KEYS: [(Key, &str); 29] = [
+    (Key::Enter, "Enter"),
+    (Key::Tab, "Tab"),
+    (Key::Backspace, "Backspace"),
+    (Key::Esc, "Esc"),
+    (Key::Left, "Left"),
+    (Key::Right, "Right"),
+    (Key::Up, "Up"),
+    (Key::Down, "Down"),
+    (Key::Ins, "Ins"),
+    (Key::Del, "Del"),
+    (Key::Home, "Home"),
+    (Key::End, "End"),
+    (Key::PageUp, "PageUp"),
+    (Key::PageDown, "PageDown"),
+    (Key::PauseBreak, "PauseBreak"),
+    (Key::NumpadCenter, "NumpadCenter"),
+    (Key::F0, "F0"),
+    (Key::F1, "F1"),
+    (Key::F2, "F2"),
+    (Key::F3, "F3"),
+    (Key::F4, "F4"),
+    (Key::F5, "F5"),
+    (Key::F6, "F6"),
+    (Key::F7, "F7"),
+    (Key::F8, "F8"),
+    (Key::F9, "F9"),
+    (Key::F10, "F10"),
+    (Key::F11, "F11"),
+    (Key::F12, "F12"),
+];
+
+// Names used for the mouse buttons in the event log.
+const BUTTONS: [(MouseButton, &str); 6] = [
+    (MouseButton::Left, "Left"),
+    (MouseButton::Middle, "Middle"),
+    (MouseButton::Right, "Right"),
+    (MouseButton::Button4, "Button4"),
+    (MouseButton::Button5, "Button5"),
+    (MouseButton::Other, "Other"),
+];
+
+/// Writes input events to a log, one JSON object per line.
+///
+/// Each line holds the time since the recording started, and the event itself:
+///
+/// ```text
+/// {"time_ms":1520,"event":{"Char":"q"}}
+/// ```
+pub(crate) struct EventRecorder {
+    writer: Box<dyn Write + Send>,
+    start: Instant,
+}
+
+impl EventRecorder {
+    /// Starts a new recording to the given writer.
+    pub fn new(writer: Box<dyn Write + Send>) -> Self {
+        EventRecorder {
+            writer,
+            start: Instant::now(),
+        }
+    }
+
+    /// Appends an event to the log.
+    pub fn record(&mut self, event: &Event) -> io::Result<()> {
+        let time_ms = self.start.elapsed().as_millis() as u64;
+        let line = json!({ "time_ms": time_ms, "event": encode_event(event) });
+        writeln!(self.writer, "{line}")
+    }
+
+    /// Flushes the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// Reads an event log written by an [`EventRecorder`].
+///
+/// Returns the time of each event since the start of the recording.
+pub(crate) fn read_events<R: BufRead>(reader: R) -> io::Result<Vec<(Duration, Event)>> {
+    let mut events = Vec::new();
+
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let invalid = || {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid event on line {}: {line}", i + 1),
+            )
+        };
+
+        let value: Value = serde_json::from_str(&line).map_err(|_| invalid())?;
+        let time_ms = value["time_ms"].as_u64().ok_or_else(invalid)?;
+        let event = decode_event(&value["event"]).ok_or_else(invalid)?;
+
+        events.push((Duration::from_millis(time_ms), event));
+    }
+
+    Ok(events)
+}
+
+fn key_name(key: Key) -> &'static str {
+    KEYS.iter()
+        .find(|&&(k, _)| k == key)
+        .map(|&(_, name)| name)
+        .unwrap_or("Unknown")
+}
+
+fn parse_key(value: &Value) -> Option<Key> {
+    let name = value.as_str()?;
+    KEYS.iter().find(|&&(_, n)| n == name).map(|&(k, _)| k)
+}
+
+fn button_name(button: MouseButton) -> &'static str {
+    BUTTONS
+        .iter()
+        .find(|&&(b, _)| b == button)
+        .map(|&(_, name)| name)
+        .unwrap_or("Other")
+}
+
+fn parse_button(value: &Value) -> Option<MouseButton> {
+    let name = value.as_str()?;
+    BUTTONS.iter().find(|&&(_, n)| n == name).map(|&(b, _)| b)
+}
+
+fn parse_char(value: &Value) -> Option<char> {
+    let mut chars = value.as_str()?.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+fn parse_vec2(value: &Value) -> Option<Vec2> {
+    match value.as_array()?.as_slice() {
+        [x, y] => Some(Vec2::new(x.as_u64()? as usize, y.as_u64()? as usize)),
+        _ => None,
+    }
+}
+
+fn encode_mouse_event(event: MouseEvent) -> Value {
+    match event {
+        MouseEvent::Press(button) => json!({ "Press": button_name(button) }),
+        MouseEvent::Release(button) => json!({ "Release": button_name(button) }),
+        MouseEvent::Hold(button) => json!({ "Hold": button_name(button) }),
+        MouseEvent::WheelUp => json!("WheelUp"),
+        MouseEvent::WheelDown => json!("WheelDown"),
+        MouseEvent::WheelLeft => json!("WheelLeft"),
+        MouseEvent::WheelRight => json!("WheelRight"),
+    }
+}
+
+fn decode_mouse_event(value: &Value) -> Option<MouseEvent> {
+    if let Some(name) = value.as_str() {
+        return match name {
+            "WheelUp" => Some(MouseEvent::WheelUp),
+            "WheelDown" => Some(MouseEvent::WheelDown),
+            "WheelLeft" => Some(MouseEvent::WheelLeft),
+            "WheelRight" => Some(MouseEvent::WheelRight),
+            _ => None,
+        };
+    }
+
+    let (name, button) = single_entry(value)?;
+    let button = parse_button(button)?;
+    match name {
+        "Press" => Some(MouseEvent::Press(button)),
+        "Release" => Some(MouseEvent::Release(button)),
+        "Hold" => Some(MouseEvent::Hold(button)),
+        _ => None,
+    }
+}
+
+fn encode_event(event: &Event) -> Value {
+    match *event {
+        Event::WindowResize => json!("WindowResize"),
+        Event::FocusLost => json!("FocusLost"),
+        Event::Refresh => json!("Refresh"),
+        Event::Exit => json!("Exit"),
+        Event::Char(c) => json!({ "Char": c.to_string() }),
+        Event::CtrlChar(c) => json!({ "CtrlChar": c.to_string() }),
+        Event::AltChar(c) => json!({ "AltChar": c.to_string() }),
+        Event::Key(key) => json!({ "Key": key_name(key) }),
+        Event::Shift(key) => json!({ "Shift": key_name(key) }),
+        Event::Alt(key) => json!({ "Alt": key_name(key) }),
+        Event::AltShift(key) => json!({ "AltShift": key_name(key) }),
+        Event::Ctrl(key) => json!({ "Ctrl": key_name(key) }),
+        Event::CtrlShift(key) => json!({ "CtrlShift": key_name(key) }),
+        Event::CtrlAlt(key) => json!({ "CtrlAlt": key_name(key) }),
+        Event::Mouse {
+            offset,
+            position,
+            event,
+        } => json!({
+            "Mouse": {
+                "offset": [offset.x, offset.y],
+                "position": [position.x, position.y],
+                "event": encode_mouse_event(event),
+            }
+        }),
+        Event::Paste(ref text) => json!({ "Paste": text }),
+        Event::Unknown(ref bytes) => json!({ "Unknown": bytes }),
+    }
+}
+
+fn decode_event(value: &Value) -> Option<Event> {
+    if let Some(name) = value.as_str() {
+        return match name {
+            "WindowResize" => Some(Event::WindowResize),
+            "FocusLost" => Some(Event::FocusLost),
+            "Refresh" => Some(Event::Refresh),
+            "Exit" => Some(Event::Exit),
+            _ => None,
+        };
+    }
+
+    let (name, value) = single_entry(value)?;
+    let event = match name {
+        "Char" => Event::Char(parse_char(value)?),
+        "CtrlChar" => Event::CtrlChar(parse_char(value)?),
+        "AltChar" => Event::AltChar(parse_char(value)?),
+        "Key" => Event::Key(parse_key(value)?),
+        "Shift" => Event::Shift(parse_key(value)?),
+        "Alt" => Event::Alt(parse_key(value)?),
+        "AltShift" => Event::AltShift(parse_key(value)?),
+        "Ctrl" => Event::Ctrl(parse_key(value)?),
+        "CtrlShift" => Event::CtrlShift(parse_key(value)?),
+        "CtrlAlt" => Event::CtrlAlt(parse_key(value)?),
+        "Paste" => Event::Paste(value.as_str()?.to_string()),
+        "Mouse" => Event::Mouse {
+            offset: parse_vec2(&value["offset"])?,
+            position: parse_vec2(&value["position"])?,
+            event: decode_mouse_event(&value["event"])?,
+        },
+        "Unknown" => Event::Unknown(
+            value
+                .as_array()?
+                .iter()
+                .map(|byte| byte.as_u64().and_then(|byte| u8::try_from(byte).ok()))
+                .collect::<Option<_>>()?,
+        ),
+        _ => return None,
+    };
+
+    Some(event)
+}
+
+// Returns the only entry of a JSON object.
+fn single_entry(value: &Value) -> Option<(&str, &Value)> {
+    let object = value.as_object()?;
+    if object.len() != 1 {
+        return None;
+    }
+    object
+        .iter()
+        .next()
+        .map(|(name, value)| (name.as_str(), value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cursive;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    // Writer sharing its output with the test.
+    #[derive(Clone, Default)]
+    struct Output(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Output {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let events = vec![
+            Event::WindowResize,
+            Event::Refresh,
+            Event::Char('é'),
+            Event::CtrlChar('c'),
+            Event::AltChar('x'),
+            Event::Key(Key::Enter),
+            Event::Shift(Key::Tab),
+            Event::CtrlAlt(Key::F12),
+            Event::Mouse {
+                offset: Vec2::new(1, 2),
+                position: Vec2::new(3, 4),
+                event: MouseEvent::Press(MouseButton::Left),
+            },
+            Event::Mouse {
+                offset: Vec2::zero(),
+                position: Vec2::new(5, 0),
+                event: MouseEvent::WheelDown,
+            },
+            Event::Paste("multi\nline".into()),
+            Event::Unknown(vec![27, 91, 0]),
+        ];
+
+        let output = Output::default();
+        let mut recorder = EventRecorder::new(Box::new(output.clone()));
+        for event in &events {
+            recorder.record(event).unwrap();
+        }
+
+        let log = output.0.lock().unwrap().clone();
+        let read: Vec<Event> = read_events(&log[..])
+            .unwrap()
+            .into_iter()
+            .map(|(_, event)| event)
+            .collect();
+        assert_eq!(read, events);
+    }
+
+    #[test]
+    fn invalid_log() {
+        let log = "{\"time_ms\":0,\"event\":{\"Key\":\"Enter\"}}\n{\"time_ms\":3}\n";
+        let error = read_events(log.as_bytes()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().contains("line 2"));
+    }
+
+    #[test]
+    fn replay() {
+        let path = std::env::temp_dir().join(format!("cursive-replay-{}.log", std::process::id()));
+        std::fs::write(
+            &path,
+            "{\"time_ms\":0,\"event\":{\"Char\":\"a\"}}\n\
+             {\"time_ms\":10,\"event\":{\"Char\":\"a\"}}\n",
+        )
+        .unwrap();
+
+        let mut siv = Cursive::new();
+        let count = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&count);
+        siv.add_global_callback('a', move |_| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+
+        // Fast enough that both events are due right away.
+        siv.replay_events(&path, 1000.0).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        std::thread::sleep(Duration::from_millis(1));
+        siv.process_timers();
+        for _ in 0..2 {
+            siv.process_callback();
+        }
+        assert_eq!(count.load(Ordering::Relaxed), 2);
+    }
+}
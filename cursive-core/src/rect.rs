@@ -1,5 +1,6 @@
 //! Rectangles on the 2D character grid.
 use crate::direction::{Absolute, Orientation};
+use crate::view::Margins;
 use crate::Vec2;
 
 use std::ops::Add;
@@ -211,4 +212,189 @@ impl Rect {
     pub fn contains(self, point: Vec2) -> bool {
         point.fits(self.top_left) && point.fits_in(self.bottom_right)
     }
+
+    /// Checks if `other` is entirely included in `self`.
+    pub fn contains_rect<R>(self, other: R) -> bool
+    where
+        R: Into<Rect>,
+    {
+        let other = other.into();
+        self.contains(other.top_left) && self.contains(other.bottom_right)
+    }
+
+    /// Checks if `self` and `other` share at least one cell.
+    pub fn intersects<R>(self, other: R) -> bool
+    where
+        R: Into<Rect>,
+    {
+        self.intersection(other).is_some()
+    }
+
+    /// Returns the area covered by both `self` and `other`.
+    ///
+    /// Returns `None` if they do not overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Rect;
+    /// let a = Rect::from_size((0, 0), (4, 4));
+    /// let b = Rect::from_size((2, 1), (4, 4));
+    ///
+    /// assert_eq!(a.intersection(b), Some(Rect::from_corners((2, 1), (3, 3))));
+    /// assert_eq!(a.intersection(Rect::from_point((5, 5))), None);
+    /// ```
+    pub fn intersection<R>(self, other: R) -> Option<Rect>
+    where
+        R: Into<Rect>,
+    {
+        let other = other.into();
+
+        let top_left = self.top_left.or_max(other.top_left);
+        let bottom_right = self.bottom_right.or_min(other.bottom_right);
+
+        top_left.fits_in(bottom_right).then(|| Rect {
+            top_left,
+            bottom_right,
+        })
+    }
+
+    /// Returns the smallest rectangle that includes both `self` and `other`.
+    ///
+    /// This is equivalent to `self.expanded_to(other)`.
+    #[must_use]
+    pub fn union<R>(self, other: R) -> Self
+    where
+        R: Into<Rect>,
+    {
+        self.expanded_to(other)
+    }
+
+    /// Returns a rectangle grown by the given margins on each side.
+    ///
+    /// The top-left corner stops at `(0, 0)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Rect;
+    /// # use cursive_core::view::Margins;
+    /// let rect = Rect::from_size((1, 1), (2, 2));
+    ///
+    /// assert_eq!(
+    ///     rect.inflated(Margins::lrtb(2, 1, 1, 1)),
+    ///     Rect::from_corners((0, 0), (3, 3))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn inflated(self, margins: Margins) -> Self {
+        Rect {
+            top_left: self.top_left.saturating_sub(margins.top_left()),
+            bottom_right: self.bottom_right + margins.bot_right(),
+        }
+    }
+
+    /// Returns a rectangle shrunk by the given margins on each side.
+    ///
+    /// Returns `None` if nothing is left after removing the margins.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Rect;
+    /// # use cursive_core::view::Margins;
+    /// let rect = Rect::from_size((0, 0), (10, 4));
+    ///
+    /// assert_eq!(
+    ///     rect.deflated(Margins::lr(1, 1)),
+    ///     Some(Rect::from_size((1, 0), (8, 4)))
+    /// );
+    /// assert_eq!(rect.deflated(Margins::tb(2, 2)), None);
+    /// ```
+    pub fn deflated(self, margins: Margins) -> Option<Self> {
+        let top_left = self.top_left + margins.top_left();
+        let bottom_right = self.bottom_right.checked_sub(margins.bot_right())?;
+
+        top_left.fits_in(bottom_right).then(|| Rect {
+            top_left,
+            bottom_right,
+        })
+    }
+
+    /// Returns a rectangle moved to fit inside `bounds`.
+    ///
+    /// The size is preserved when possible, but it is reduced if `self` is
+    /// larger than `bounds`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Rect;
+    /// let bounds = Rect::from_size((0, 0), (10, 10));
+    ///
+    /// // Moved back inside, keeping its size.
+    /// let rect = Rect::from_size((8, 2), (4, 3));
+    /// assert_eq!(rect.clamped_to(bounds), Rect::from_size((6, 2), (4, 3)));
+    ///
+    /// // Too large: it gets cropped.
+    /// let rect = Rect::from_size((5, 5), (20, 2));
+    /// assert_eq!(rect.clamped_to(bounds), Rect::from_size((0, 5), (10, 2)));
+    /// ```
+    #[must_use]
+    pub fn clamped_to<R>(self, bounds: R) -> Self
+    where
+        R: Into<Rect>,
+    {
+        let bounds = bounds.into();
+
+        let size = self.size().or_min(bounds.size());
+        let max_top_left = bounds.bottom_right + (1, 1) - size;
+        let top_left = self.top_left.clamp(bounds.top_left, max_top_left);
+
+        Self::from_size(top_left, size)
+    }
+
+    /// Returns the point of `self` closest to `point`.
+    pub fn clamp_point<V>(self, point: V) -> Vec2
+    where
+        V: Into<Vec2>,
+    {
+        point.into().clamp(self.top_left, self.bottom_right)
+    }
+
+    /// Returns the center of the rectangle.
+    ///
+    /// For even sizes, this rounds towards the top-left corner.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Rect;
+    /// assert_eq!(Rect::from_size((2, 2), (5, 4)).center(), (4, 3));
+    /// ```
+    pub fn center(self) -> Vec2 {
+        self.top_left + (self.size() - (1, 1)) / 2
+    }
+
+    /// Returns an iterator on all the points in this rectangle.
+    ///
+    /// Points are given row by row, from the top-left corner.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::{Rect, Vec2};
+    /// let points: Vec<Vec2> = Rect::from_size((1, 1), (2, 2)).points().collect();
+    ///
+    /// assert_eq!(points, [(1, 1), (2, 1), (1, 2), (2, 2)]);
+    /// ```
+    pub fn points(self) -> impl Iterator<Item = Vec2> {
+        let Rect {
+            top_left,
+            bottom_right,
+        } = self;
+
+        (top_left.y..=bottom_right.y)
+            .flat_map(move |y| (top_left.x..=bottom_right.x).map(move |x| Vec2::new(x, y)))
+    }
 }
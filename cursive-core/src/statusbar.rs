@@ -0,0 +1,298 @@
+use crate::event::{Event, EventResult};
+use crate::style::PaletteStyle;
+use crate::view::{IntoBoxedView, View};
+use crate::views::{BoxedView, EditView};
+use crate::{keymap, Cursive, Printer, Rect, Vec2};
+use std::sync::Arc;
+
+type CommandHandler = Arc<dyn Fn(&mut Cursive, &str) + Send + Sync>;
+
+/// Status bar at the bottom of the screen, and the command line replacing it.
+#[derive(Default)]
+pub(crate) struct Statusbar {
+    view: Option<BoxedView>,
+
+    // Rows reserved at the bottom of the screen, as of the last layout.
+    height: usize,
+
+    // Called with the text entered in the command line.
+    handler: Option<CommandHandler>,
+
+    // Input of the command line, while it is open.
+    command: Option<EditView>,
+
+    // `true` if the command line was opened or closed since the last draw.
+    changed: bool,
+}
+
+impl Statusbar {
+    /// Returns the number of rows reserved at the bottom of the screen.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_command_open(&self) -> bool {
+        self.command.is_some()
+    }
+
+    /// Returns the area to redraw, if anything changed.
+    pub fn dirty_area(&self, screen_size: Vec2) -> Option<Rect> {
+        let top = screen_size.y.saturating_sub(self.height);
+        let size = Vec2::new(screen_size.x, self.height);
+        if self.changed || self.command.is_some() {
+            return Some(Rect::from_size((0, top), size));
+        }
+        self.view
+            .as_ref()
+            .and_then(|view| view.dirty_area(size))
+            .map(|area| area + (0, top))
+    }
+
+    /// Computes the rows to reserve for the given screen size.
+    pub fn layout(&mut self, screen_size: Vec2) {
+        let view_height = self.view.as_mut().map_or(0, |view| {
+            view.required_size(screen_size)
+                .y
+                .clamp(1, screen_size.y.max(1))
+        });
+        // The command line needs a row, even without a status bar.
+        let command_height = usize::from(self.command.is_some());
+        self.height = view_height.max(command_height).min(screen_size.y);
+
+        let size = Vec2::new(screen_size.x, self.height);
+        if let Some(ref mut view) = self.view {
+            view.layout(size);
+        }
+        if let Some(ref mut command) = self.command {
+            // Leave a column for the `:` prompt.
+            command.layout(Vec2::new(screen_size.x.saturating_sub(1), 1));
+        }
+    }
+
+    /// Draws the status bar, or the command line, at the bottom of the printer.
+    pub fn draw(&mut self, printer: &Printer) {
+        self.changed = false;
+        if self.height == 0 {
+            return;
+        }
+
+        let top = printer.size.y.saturating_sub(self.height);
+        let printer = printer
+            .offset((0, top))
+            .cropped((printer.size.x, self.height));
+
+        let Some(ref command) = self.command else {
+            if let Some(ref view) = self.view {
+                view.draw(&printer);
+            }
+            return;
+        };
+
+        // The command line replaces the whole status bar.
+        printer.with_style(PaletteStyle::View, |printer| {
+            for y in 0..self.height {
+                printer.print_hline((0, y), printer.size.x, " ");
+            }
+        });
+        let printer = printer.offset((0, self.height - 1));
+        printer.with_style(PaletteStyle::Primary, |printer| {
+            printer.print((0, 0), ":");
+        });
+        command.draw(&printer.offset((1, 0)).focused(true));
+    }
+}
+
+impl Cursive {
+    /// Sets a view shown at the bottom of the screen, like a status bar.
+    ///
+    /// This mirrors the menubar: the bottom rows are reserved for this view,
+    /// and the layers no longer cover them. The view takes as many rows as it
+    /// requires, and at least one.
+    ///
+    /// The status bar is only drawn: it does not receive events.
+    ///
+    /// See [`StatusBar`](crate::views::StatusBar) for a bar with left and
+    /// right segments.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::views::StatusBar;
+    /// let mut siv = cursive_core::Cursive::new();
+    ///
+    /// siv.set_statusbar(StatusBar::new().left("Ready").right("Ln 1"));
+    /// ```
+    pub fn set_statusbar<V: IntoBoxedView>(&mut self, view: V) {
+        self.statusbar.view = Some(BoxedView::new(view.into_boxed_view()));
+        self.clear();
+    }
+
+    /// Removes the status bar, if any, and returns it.
+    pub fn clear_statusbar(&mut self) -> Option<Box<dyn View>> {
+        let view = self.statusbar.view.take();
+        self.clear();
+        view.map(BoxedView::unwrap)
+    }
+
+    /// Runs a closure on the status bar, if it has the given type.
+    ///
+    /// Returns `None` if there is no status bar, or if it has a different type.
+    pub fn call_on_statusbar<V, F, R>(&mut self, callback: F) -> Option<R>
+    where
+        V: View,
+        F: FnOnce(&mut V) -> R,
+    {
+        self.statusbar
+            .view
+            .as_mut()
+            .and_then(BoxedView::get_mut)
+            .map(callback)
+    }
+
+    /// Enables the command line, and sets the callback for entered commands.
+    ///
+    /// When no view uses it, the `command_line` keymap action (`:` by default)
+    /// opens a command line at the bottom of the screen, temporarily replacing
+    /// the status bar. `<Enter>` closes it and calls `handler` with the
+    /// entered text; `<Esc>` just closes it.
+    ///
+    /// While the command line is open, it receives all key events.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut siv = cursive_core::Cursive::new();
+    ///
+    /// siv.set_command_handler(|s, command| match command {
+    ///     "q" | "quit" => s.quit(),
+    ///     _ => (),
+    /// });
+    /// ```
+    pub fn set_command_handler<F>(&mut self, handler: F)
+    where
+        F: 'static + Fn(&mut Cursive, &str) + Send + Sync,
+    {
+        self.statusbar.handler = Some(Arc::new(handler));
+    }
+
+    /// Disables the command line, and closes it if it is open.
+    pub fn clear_command_handler(&mut self) {
+        self.statusbar.handler = None;
+        self.close_command_line();
+    }
+
+    /// Opens the command line, even without a command handler.
+    ///
+    /// See [`Cursive::set_command_handler`].
+    pub fn open_command_line(&mut self) {
+        if self.statusbar.command.is_none() {
+            self.statusbar.command = Some(EditView::new());
+            self.command_line_toggled();
+        }
+    }
+
+    /// Closes the command line without running a command.
+    pub fn close_command_line(&mut self) {
+        if self.statusbar.command.take().is_some() {
+            self.command_line_toggled();
+        }
+    }
+
+    fn command_line_toggled(&mut self) {
+        if self.statusbar.view.is_none() {
+            // The command line takes a row from the views.
+            self.clear();
+        } else {
+            self.statusbar.changed = true;
+        }
+    }
+
+    /// Returns `true` if the command line is open.
+    pub fn is_command_line_open(&self) -> bool {
+        self.statusbar.is_command_open()
+    }
+
+    /// Opens the command line if the event triggers it.
+    ///
+    /// Returns `true` if the command line was opened.
+    pub(crate) fn check_command_line(&mut self, event: &Event) -> bool {
+        if self.statusbar.handler.is_none() || !keymap::matches("command_line", event) {
+            return false;
+        }
+        self.open_command_line();
+        true
+    }
+
+    /// Gives an event to the open command line.
+    pub(crate) fn on_command_line_event(&mut self, event: Event) {
+        let Some(ref mut command) = self.statusbar.command else {
+            return;
+        };
+
+        if keymap::matches("submit", &event) {
+            let text = command.get_content();
+            self.close_command_line();
+            if let Some(handler) = self.statusbar.handler.clone() {
+                handler(self, &text);
+            }
+        } else if keymap::matches("cancel", &event) {
+            self.close_command_line();
+        } else if let EventResult::Consumed(Some(cb)) = command.on_event(event) {
+            cb(self);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::Harness;
+    use crate::views::{StatusBar, TextView};
+    use crate::Cursive;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn statusbar() {
+        let mut siv = Cursive::new();
+        siv.add_fullscreen_layer(TextView::new("a\nb\nc\nd\ne\nf"));
+        siv.set_statusbar(StatusBar::new().left("Ready"));
+
+        let mut harness = Harness::with_cursive(siv, (20, 4));
+        let screen = harness.screen();
+        assert_eq!(screen.row(2).trim_end(), "c");
+        assert_eq!(screen.row(3).trim_end(), " Ready");
+
+        harness
+            .cursive()
+            .call_on_statusbar(|bar: &mut StatusBar| bar.set_left(0, "Busy"));
+        assert_eq!(harness.screen().row(3).trim_end(), " Busy");
+
+        harness.cursive().clear_statusbar();
+        assert_eq!(harness.screen().row(3).trim_end(), "d");
+    }
+
+    #[test]
+    fn command_line() {
+        let commands = Arc::new(Mutex::new(Vec::new()));
+
+        let mut siv = Cursive::new();
+        siv.set_statusbar(StatusBar::new().left("Ready"));
+        siv.set_command_handler({
+            let commands = Arc::clone(&commands);
+            move |_, command| commands.lock().unwrap().push(command.to_string())
+        });
+
+        let mut harness = Harness::with_cursive(siv, (20, 4));
+        harness.feed_keys(":wq");
+        assert!(harness.cursive().is_command_line_open());
+        assert!(harness.screen().row(3).starts_with(":wq_"));
+
+        harness.feed_keys("<enter>");
+        assert_eq!(*commands.lock().unwrap(), ["wq"]);
+        assert_eq!(harness.screen().row(3).trim_end(), " Ready");
+
+        // Escape closes the command line without running anything.
+        harness.feed_keys(":q<esc>");
+        assert!(!harness.cursive().is_command_line_open());
+        assert_eq!(commands.lock().unwrap().len(), 1);
+    }
+}
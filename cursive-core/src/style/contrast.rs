@@ -0,0 +1,343 @@
+use super::{BaseColor, Color, ColorDowngrade, ColorPair, Effect, Palette, PaletteStyle, Rgb};
+use crate::buffer::PrintBuffer;
+use std::collections::HashSet;
+
+/// Minimum contrast ratio recommended for regular text.
+///
+/// This is the WCAG "AA" level.
+pub const MIN_CONTRAST_RATIO: f32 = 4.5;
+
+/// Number of colors a backend can actually display.
+///
+/// Colors the backend cannot display are downgraded to the closest available
+/// one, which can change the contrast between them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ColorResolution {
+    /// Only the 8 dark base colors.
+    Colors8,
+
+    /// The 8 base colors, in their dark and light variants.
+    Colors16,
+
+    /// The 256 colors palette.
+    Colors256,
+
+    /// Any 24-bit color.
+    TrueColor,
+}
+
+impl ColorResolution {
+    /// Returns the resolution for a terminal supporting `n` colors.
+    pub fn from_colors(n: usize) -> Self {
+        match n {
+            0..=15 => ColorResolution::Colors8,
+            16..=255 => ColorResolution::Colors16,
+            256..=0xFF_FFFF => ColorResolution::Colors256,
+            _ => ColorResolution::TrueColor,
+        }
+    }
+
+    /// Returns the resolution from the terminal environment.
+    ///
+    /// `COLORTERM=truecolor` (or `24bit`) and `TERM` ending in `-direct` mean
+    /// true colors, a `TERM` containing `256color` means 256 colors, and other
+    /// `TERM` values mean 16 colors. Without `TERM` (like on Windows), true
+    /// colors are assumed.
+    pub fn detect() -> Self {
+        let colorterm = std::env::var("COLORTERM").unwrap_or_default();
+        if colorterm == "truecolor" || colorterm == "24bit" {
+            return ColorResolution::TrueColor;
+        }
+
+        match std::env::var("TERM") {
+            Ok(term) if term.ends_with("-direct") => ColorResolution::TrueColor,
+            Ok(term) if term.contains("256color") => ColorResolution::Colors256,
+            Ok(term) if !term.is_empty() => ColorResolution::Colors16,
+            _ => ColorResolution::TrueColor,
+        }
+    }
+
+    /// Returns the color actually displayed for `color` at this resolution.
+    ///
+    /// This is the color chosen by [`ColorDowngrade`] for this resolution.
+    ///
+    /// [`ColorDowngrade`]: super::ColorDowngrade
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::style::{BaseColor, Color, ColorResolution};
+    ///
+    /// let orange = Color::Rgb(255, 100, 0);
+    /// assert_eq!(ColorResolution::TrueColor.approximate(orange), orange);
+    /// assert_eq!(
+    ///     ColorResolution::Colors256.approximate(orange),
+    ///     Color::RgbLowRes(5, 1, 0)
+    /// );
+    /// assert_eq!(
+    ///     ColorResolution::Colors8.approximate(orange),
+    ///     Color::Dark(BaseColor::Red)
+    /// );
+    /// ```
+    pub fn approximate(self, color: Color) -> Color {
+        ColorDowngrade::from(self).color(color)
+    }
+}
+
+/// Returns the contrast ratio between two colors.
+///
+/// This is the WCAG contrast ratio, from 1 (no contrast) to 21 (black on
+/// white). Base colors are assumed to use the usual xterm values, since the
+/// actual colors depend on the terminal configuration.
+///
+/// Returns `None` if either color is `TerminalDefault`, which is unknown.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::style::{contrast_ratio, Color};
+///
+/// let ratio = contrast_ratio(Color::Rgb(0, 0, 0), Color::Rgb(255, 255, 255));
+/// assert_eq!(ratio.map(f32::round), Some(21.0));
+///
+/// let ratio = contrast_ratio(Color::Rgb(0, 0, 0), Color::TerminalDefault);
+/// assert_eq!(ratio, None);
+/// ```
+pub fn contrast_ratio(front: Color, back: Color) -> Option<f32> {
+    let front = luminance(to_rgb(front)?);
+    let back = luminance(to_rgb(back)?);
+
+    let (light, dark) = if front > back {
+        (front, back)
+    } else {
+        (back, front)
+    };
+    Some((light + 0.05) / (dark + 0.05))
+}
+
+/// Returns the palette styles with a contrast ratio below `threshold`.
+///
+/// Colors are first approximated to the given resolution. Styles that are
+/// not used to print text, like the background or shadows, are skipped.
+/// Colors inherited by a style are taken from the `View` style.
+pub fn check_palette(
+    palette: &Palette,
+    resolution: ColorResolution,
+    threshold: f32,
+) -> Vec<(PaletteStyle, f32)> {
+    // Text is usually printed inside a view, so inherited colors come from there.
+    let view = palette[PaletteStyle::View].resolve(palette, Default::default());
+
+    PaletteStyle::all()
+        .filter(|style| !matches!(style, PaletteStyle::Background | PaletteStyle::Shadow))
+        .filter_map(|style| {
+            let style_value = palette[style].resolve(palette, view);
+            let mut colors = style_value.color;
+            if style_value.effects.contains(Effect::Reverse) {
+                colors = colors.invert();
+            }
+            let ratio = pair_ratio(colors, resolution)?;
+            // Written this way to also catch NaN.
+            if ratio >= threshold {
+                None
+            } else {
+                Some((style, ratio))
+            }
+        })
+        .collect()
+}
+
+fn pair_ratio(colors: ColorPair, resolution: ColorResolution) -> Option<f32> {
+    contrast_ratio(
+        resolution.approximate(colors.front),
+        resolution.approximate(colors.back),
+    )
+}
+
+// Relative luminance, as defined by WCAG.
+fn luminance(rgb: Rgb<u8>) -> f32 {
+    let rgb = rgb.as_f32().map(|c| {
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    });
+    0.2126 * rgb.r + 0.7152 * rgb.g + 0.0722 * rgb.b
+}
+
+// Approximate RGB value of a color.
+pub(crate) fn to_rgb(color: Color) -> Option<Rgb<u8>> {
+    const LOW_RES: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+    let base = |base: BaseColor, light: bool| -> u32 {
+        match (base, light) {
+            (BaseColor::Black, false) => 0x000000,
+            (BaseColor::Red, false) => 0xcd0000,
+            (BaseColor::Green, false) => 0x00cd00,
+            (BaseColor::Yellow, false) => 0xcdcd00,
+            (BaseColor::Blue, false) => 0x0000ee,
+            (BaseColor::Magenta, false) => 0xcd00cd,
+            (BaseColor::Cyan, false) => 0x00cdcd,
+            (BaseColor::White, false) => 0xe5e5e5,
+            (BaseColor::Black, true) => 0x7f7f7f,
+            (BaseColor::Red, true) => 0xff0000,
+            (BaseColor::Green, true) => 0x00ff00,
+            (BaseColor::Yellow, true) => 0xffff00,
+            (BaseColor::Blue, true) => 0x5c5cff,
+            (BaseColor::Magenta, true) => 0xff00ff,
+            (BaseColor::Cyan, true) => 0x00ffff,
+            (BaseColor::White, true) => 0xffffff,
+        }
+    };
+
+    Some(match color {
+        Color::TerminalDefault => return None,
+        Color::Dark(color) => Rgb::from_u32(base(color, false)),
+        Color::Light(color) => Rgb::from_u32(base(color, true)),
+        Color::Rgb(r, g, b) => Rgb::new(r, g, b),
+        Color::RgbLowRes(r, g, b) => Rgb::new(r, g, b).map(|c| LOW_RES[usize::from(c)]),
+    })
+}
+
+/// Warns about low-contrast colors as they are drawn.
+pub(crate) struct ContrastCheck {
+    threshold: f32,
+
+    // Palette checked last, so a theme is only reported once.
+    palette: Option<Palette>,
+
+    // Color pairs already reported.
+    reported: HashSet<ColorPair>,
+}
+
+impl ContrastCheck {
+    pub fn new(threshold: f32) -> Self {
+        ContrastCheck {
+            threshold,
+            palette: None,
+            reported: HashSet::new(),
+        }
+    }
+
+    /// Logs a warning for each low-contrast palette style or drawn color pair.
+    pub fn check(&mut self, palette: &Palette, buffer: &PrintBuffer, resolution: ColorResolution) {
+        if self.palette.as_ref() != Some(palette) {
+            for (style, ratio) in check_palette(palette, resolution, self.threshold) {
+                log::warn!("Low contrast for {style:?} in the theme palette: {ratio:.2}");
+            }
+            self.palette = Some(palette.clone());
+        }
+
+        for (colors, ratio) in self.check_buffer(buffer, resolution) {
+            log::warn!(
+                "Low contrast for {:?} on {:?}: {ratio:.2}",
+                colors.front,
+                colors.back
+            );
+        }
+    }
+
+    // Returns the color pairs used for text, with a low contrast, not reported yet.
+    fn check_buffer(
+        &mut self,
+        buffer: &PrintBuffer,
+        resolution: ColorResolution,
+    ) -> Vec<(ColorPair, f32)> {
+        let mut found = Vec::new();
+        for cell in buffer.rows().flatten().flatten() {
+            if cell.text().trim().is_empty() {
+                continue;
+            }
+
+            let style = cell.style();
+            let mut colors = style.color;
+            if style.effects.contains(Effect::Reverse) {
+                colors = colors.invert();
+            }
+            if self.reported.contains(&colors) {
+                continue;
+            }
+            if let Some(ratio) = pair_ratio(colors, resolution) {
+                if ratio < self.threshold {
+                    self.reported.insert(colors);
+                    found.push((colors, ratio));
+                }
+            }
+        }
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::ConcreteStyle;
+
+    #[test]
+    fn ratios() {
+        let white = Color::Rgb(255, 255, 255);
+        let ratio = |a, b| (contrast_ratio(a, b).unwrap() * 100.0).round() / 100.0;
+
+        assert_eq!(ratio(white, white), 1.0);
+        assert_eq!(ratio(Color::Rgb(0, 0, 0), white), 21.0);
+        assert_eq!(ratio(white, Color::Rgb(0, 0, 0)), 21.0);
+        assert_eq!(ratio(Color::Rgb(0x77, 0x77, 0x77), white), 4.48);
+    }
+
+    #[test]
+    fn resolution() {
+        // Slightly different grays look the same with 16 colors.
+        let light = Color::Rgb(200, 200, 200);
+        let dark = Color::Rgb(190, 190, 190);
+        assert_eq!(
+            check_pair(light, dark, ColorResolution::Colors16),
+            Some(1.0)
+        );
+        assert!(check_pair(light, dark, ColorResolution::TrueColor).unwrap() > 1.0);
+    }
+
+    fn check_pair(front: Color, back: Color, resolution: ColorResolution) -> Option<f32> {
+        pair_ratio(ColorPair { front, back }, resolution)
+    }
+
+    #[test]
+    fn palette() {
+        let mut palette = Palette::retro();
+        let low = |palette: &Palette| -> Vec<PaletteStyle> {
+            check_palette(palette, ColorResolution::TrueColor, 3.0)
+                .into_iter()
+                .map(|(style, _)| style)
+                .collect()
+        };
+
+        // Tertiary text is light white on dark white.
+        assert_eq!(low(&palette), [PaletteStyle::Tertiary]);
+
+        palette[crate::style::PaletteColor::Primary] = Color::Light(BaseColor::White);
+        assert!(low(&palette).contains(&PaletteStyle::Primary));
+    }
+
+    #[test]
+    fn buffer() {
+        let gray = ColorPair {
+            front: Color::Rgb(200, 200, 200),
+            back: Color::Rgb(255, 255, 255),
+        };
+        let style = ConcreteStyle::from(gray);
+
+        let mut buffer = PrintBuffer::new();
+        buffer.resize((10, 1).into());
+        buffer.print_at((0, 0).into(), "faint", style);
+
+        let mut check = ContrastCheck::new(MIN_CONTRAST_RATIO);
+        let found = check.check_buffer(&buffer, ColorResolution::TrueColor);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].0, gray);
+
+        // Each pair is only reported once.
+        assert!(check
+            .check_buffer(&buffer, ColorResolution::TrueColor)
+            .is_empty());
+    }
+}
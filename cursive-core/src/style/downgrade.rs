@@ -0,0 +1,399 @@
+use super::contrast::to_rgb;
+use super::{BaseColor, Color, ColorPair, ColorResolution, ConcreteStyle, Effect, Rgb};
+use std::str::FromStr;
+
+/// Defines how colors are downgraded before reaching the backend.
+///
+/// Colors the terminal cannot display are replaced with the closest available
+/// one, based on perceived difference (CIEDE2000) rather than on each channel
+/// separately. This is done once for all backends, right before printing.
+///
+/// The policy is part of the theme (see [`Theme::color_downgrade`]); by
+/// default it follows what the backend reports (see
+/// [`Backend::color_resolution`]).
+///
+/// [`Theme::color_downgrade`]: crate::theme::Theme::color_downgrade
+/// [`Backend::color_resolution`]: crate::backend::Backend::color_resolution
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::style::{BaseColor, Color, ColorDowngrade};
+///
+/// let orange = Color::Rgb(255, 100, 0);
+/// assert_eq!(ColorDowngrade::TrueColor.color(orange), orange);
+/// assert_eq!(
+///     ColorDowngrade::Colors256.color(orange),
+///     Color::RgbLowRes(5, 1, 0)
+/// );
+/// assert_eq!(
+///     ColorDowngrade::Colors16.color(orange),
+///     Color::Light(BaseColor::Red)
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum ColorDowngrade {
+    /// Use the colors the backend reports it can display.
+    #[default]
+    Auto,
+
+    /// Keep all colors, including 24-bit ones.
+    TrueColor,
+
+    /// Only use the 256 colors palette.
+    ///
+    /// Base colors are kept, but other colors only use the 6x6x6 cube and the
+    /// grayscale ramp, since base colors often depend on the terminal theme.
+    Colors256,
+
+    /// Only use the 16 base colors, dark and light.
+    Colors16,
+
+    /// Only use the 8 dark base colors.
+    Colors8,
+
+    /// Do not use colors at all.
+    ///
+    /// Everything is printed with the terminal default colors. To keep
+    /// highlighted text visible, cells with a background lighter than their
+    /// text are printed in reverse video, assuming the terminal shows light
+    /// text on a dark background.
+    Mono,
+}
+
+impl ColorDowngrade {
+    /// Returns the actual policy for a backend with the given resolution.
+    ///
+    /// `Auto` follows the backend, and other policies never use more colors
+    /// than the backend can display.
+    pub fn resolve(self, resolution: ColorResolution) -> Self {
+        let backend = ColorDowngrade::from(resolution);
+        match self {
+            ColorDowngrade::Auto => backend,
+            policy if policy.rank() < backend.rank() => policy,
+            _ => backend,
+        }
+    }
+
+    /// Returns the color used in place of `color`.
+    ///
+    /// `Auto` is treated as `TrueColor`: call [`Self::resolve`] first.
+    pub fn color(self, color: Color) -> Color {
+        match (self, color) {
+            (_, Color::TerminalDefault) => color,
+            (ColorDowngrade::Auto | ColorDowngrade::TrueColor, _) => color,
+            (ColorDowngrade::Mono, _) => Color::TerminalDefault,
+            (
+                ColorDowngrade::Colors256,
+                Color::Dark(_) | Color::Light(_) | Color::RgbLowRes(..),
+            ) => color,
+            (ColorDowngrade::Colors16, Color::Dark(_) | Color::Light(_)) => color,
+            (ColorDowngrade::Colors8, Color::Dark(_)) => color,
+            (ColorDowngrade::Colors8, Color::Light(base)) => Color::Dark(base),
+            (policy, color) => to_rgb(color).map_or(color, |rgb| policy.nearest(rgb)),
+        }
+    }
+
+    /// Returns the style used in place of `style`.
+    ///
+    /// Same as [`Self::color`] for both colors, except that `Mono` may also
+    /// toggle `Effect::Reverse`.
+    pub fn style(self, style: ConcreteStyle) -> ConcreteStyle {
+        let ConcreteStyle {
+            mut effects,
+            color,
+            underline,
+        } = style;
+
+        if self == ColorDowngrade::Mono
+            && lightness(color.back, 0.0) > lightness(color.front, 100.0)
+        {
+            // Reverse video is the only way left to tell highlighted text apart.
+            if effects.contains(Effect::Reverse) {
+                effects.remove(Effect::Reverse);
+            } else {
+                effects.insert(Effect::Reverse);
+            }
+        }
+
+        ConcreteStyle {
+            effects,
+            color: ColorPair {
+                front: self.color(color.front),
+                back: self.color(color.back),
+            },
+            underline: underline.map(|underline| self.color(underline)),
+        }
+    }
+
+    // Lower ranks use fewer colors.
+    fn rank(self) -> u8 {
+        match self {
+            ColorDowngrade::Mono => 0,
+            ColorDowngrade::Colors8 => 1,
+            ColorDowngrade::Colors16 => 2,
+            ColorDowngrade::Colors256 => 3,
+            ColorDowngrade::TrueColor | ColorDowngrade::Auto => 4,
+        }
+    }
+
+    // Returns the closest color available with this policy.
+    fn nearest(self, rgb: Rgb<u8>) -> Color {
+        let candidates: Box<dyn Iterator<Item = Color>> = match self {
+            ColorDowngrade::Colors256 => Box::new((16..=255).map(Color::from_256colors)),
+            ColorDowngrade::Colors16 => Box::new(
+                BaseColor::all()
+                    .map(Color::Dark)
+                    .chain(BaseColor::all().map(Color::Light)),
+            ),
+            ColorDowngrade::Colors8 => Box::new(BaseColor::all().map(Color::Dark)),
+            _ => return Color::Rgb(rgb.r, rgb.g, rgb.b),
+        };
+
+        let target = Lab::from(rgb);
+        candidates
+            .filter_map(|color| {
+                let lab = Lab::from(to_rgb(color)?);
+                Some((color, ciede2000(target, lab)))
+            })
+            .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+            .map_or(Color::TerminalDefault, |(color, _)| color)
+    }
+}
+
+impl From<ColorResolution> for ColorDowngrade {
+    fn from(resolution: ColorResolution) -> Self {
+        match resolution {
+            ColorResolution::Colors8 => ColorDowngrade::Colors8,
+            ColorResolution::Colors16 => ColorDowngrade::Colors16,
+            ColorResolution::Colors256 => ColorDowngrade::Colors256,
+            ColorResolution::TrueColor => ColorDowngrade::TrueColor,
+        }
+    }
+}
+
+/// Error parsing a [`ColorDowngrade`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoSuchColorDowngrade;
+
+impl std::fmt::Display for NoSuchColorDowngrade {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid color downgrade")
+    }
+}
+
+impl std::error::Error for NoSuchColorDowngrade {}
+
+/// Parses `"auto"`, `"truecolor"`, `"256"`, `"16"`, `"8"` or `"mono"`.
+impl FromStr for ColorDowngrade {
+    type Err = NoSuchColorDowngrade;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "auto" => ColorDowngrade::Auto,
+            "truecolor" | "24bit" => ColorDowngrade::TrueColor,
+            "256" => ColorDowngrade::Colors256,
+            "16" => ColorDowngrade::Colors16,
+            "8" => ColorDowngrade::Colors8,
+            "mono" => ColorDowngrade::Mono,
+            _ => return Err(NoSuchColorDowngrade),
+        })
+    }
+}
+
+// Lightness used to pick reverse video in mono mode, from 0 to 100.
+//
+// `default` is used for the terminal default color, which is unknown.
+fn lightness(color: Color, default: f32) -> f32 {
+    to_rgb(color).map_or(default, |rgb| Lab::from(rgb).l)
+}
+
+// Color in the CIELAB space, with a D65 white point.
+#[derive(Clone, Copy, Debug)]
+struct Lab {
+    l: f32,
+    a: f32,
+    b: f32,
+}
+
+impl From<Rgb<u8>> for Lab {
+    fn from(rgb: Rgb<u8>) -> Self {
+        let rgb = rgb.as_f32().map(|c| {
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        });
+
+        let x = (0.4124 * rgb.r + 0.3576 * rgb.g + 0.1805 * rgb.b) / 0.95047;
+        let y = 0.2126 * rgb.r + 0.7152 * rgb.g + 0.0722 * rgb.b;
+        let z = (0.0193 * rgb.r + 0.1192 * rgb.g + 0.9505 * rgb.b) / 1.08883;
+
+        let f = |t: f32| {
+            if t > 216.0 / 24389.0 {
+                t.cbrt()
+            } else {
+                (24389.0 / 27.0 * t + 16.0) / 116.0
+            }
+        };
+        let (fx, fy, fz) = (f(x), f(y), f(z));
+
+        Lab {
+            l: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+}
+
+// CIEDE2000 color difference.
+fn ciede2000(lab1: Lab, lab2: Lab) -> f32 {
+    const POW25_7: f32 = 6_103_515_625.0;
+
+    let c_bar = (lab1.a.hypot(lab1.b) + lab2.a.hypot(lab2.b)) / 2.0;
+    let g = 0.5 * (1.0 - (c_bar.powi(7) / (c_bar.powi(7) + POW25_7)).sqrt());
+
+    let a1 = (1.0 + g) * lab1.a;
+    let a2 = (1.0 + g) * lab2.a;
+    let c1 = a1.hypot(lab1.b);
+    let c2 = a2.hypot(lab2.b);
+
+    let hue = |b: f32, a: f32| {
+        if a == 0.0 && b == 0.0 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        }
+    };
+    let h1 = hue(lab1.b, a1);
+    let h2 = hue(lab2.b, a2);
+
+    let delta_l = lab2.l - lab1.l;
+    let delta_c = c2 - c1;
+    let delta_h = if c1 * c2 == 0.0 {
+        0.0
+    } else {
+        match h2 - h1 {
+            d if d > 180.0 => d - 360.0,
+            d if d < -180.0 => d + 360.0,
+            d => d,
+        }
+    };
+    let delta_h = 2.0 * (c1 * c2).sqrt() * (delta_h / 2.0).to_radians().sin();
+
+    let l_bar = (lab1.l + lab2.l) / 2.0;
+    let c_bar = (c1 + c2) / 2.0;
+    let h_bar = if c1 * c2 == 0.0 {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        (h1 + h2) / 2.0
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) / 2.0
+    } else {
+        (h1 + h2 - 360.0) / 2.0
+    };
+
+    let cos = |degrees: f32| degrees.to_radians().cos();
+    let t =
+        1.0 - 0.17 * cos(h_bar - 30.0) + 0.24 * cos(2.0 * h_bar) + 0.32 * cos(3.0 * h_bar + 6.0)
+            - 0.20 * cos(4.0 * h_bar - 63.0);
+    let delta_theta = 30.0 * (-((h_bar - 275.0) / 25.0).powi(2)).exp();
+    let r_c = 2.0 * (c_bar.powi(7) / (c_bar.powi(7) + POW25_7)).sqrt();
+    let s_l = 1.0 + 0.015 * (l_bar - 50.0).powi(2) / (20.0 + (l_bar - 50.0).powi(2)).sqrt();
+    let s_c = 1.0 + 0.045 * c_bar;
+    let s_h = 1.0 + 0.015 * c_bar * t;
+    let r_t = -(2.0 * delta_theta).to_radians().sin() * r_c;
+
+    let (l, c, h) = (delta_l / s_l, delta_c / s_c, delta_h / s_h);
+    (l * l + c * c + h * h + r_t * c * h).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ciede2000_reference() {
+        // Pairs from Sharma et al., "The CIEDE2000 Color-Difference Formula".
+        let pairs = [
+            ((50.0, 2.6772, -79.7751), (50.0, 0.0, -82.7485), 2.0425),
+            ((50.0, 2.5, 0.0), (73.0, 25.0, -18.0), 27.1492),
+            (
+                (60.2574, -34.0099, 36.2677),
+                (60.4626, -34.1751, 39.4387),
+                1.2644,
+            ),
+        ];
+        for ((l1, a1, b1), (l2, a2, b2), expected) in pairs {
+            let lab1 = Lab {
+                l: l1,
+                a: a1,
+                b: b1,
+            };
+            let lab2 = Lab {
+                l: l2,
+                a: a2,
+                b: b2,
+            };
+            assert!((ciede2000(lab1, lab2) - expected).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn nearest() {
+        // Grays use the finer grayscale ramp.
+        assert_eq!(
+            ColorDowngrade::Colors256.color(Color::Rgb(100, 100, 100)),
+            Color::Rgb(98, 98, 98)
+        );
+        assert_eq!(
+            ColorDowngrade::Colors16.color(Color::Rgb(20, 20, 140)),
+            Color::Dark(BaseColor::Blue)
+        );
+        assert_eq!(
+            ColorDowngrade::Colors8.color(Color::Light(BaseColor::Green)),
+            Color::Dark(BaseColor::Green)
+        );
+        assert_eq!(
+            ColorDowngrade::Colors8.color(Color::RgbLowRes(5, 5, 5)),
+            Color::Dark(BaseColor::White)
+        );
+    }
+
+    #[test]
+    fn resolve() {
+        assert_eq!(
+            ColorDowngrade::Auto.resolve(ColorResolution::Colors256),
+            ColorDowngrade::Colors256
+        );
+        assert_eq!(
+            ColorDowngrade::Colors16.resolve(ColorResolution::TrueColor),
+            ColorDowngrade::Colors16
+        );
+        assert_eq!(
+            ColorDowngrade::TrueColor.resolve(ColorResolution::Colors16),
+            ColorDowngrade::Colors16
+        );
+        assert_eq!("mono".parse(), Ok(ColorDowngrade::Mono));
+    }
+
+    #[test]
+    fn mono() {
+        let style = |front, back| ConcreteStyle::from(ColorPair { front, back });
+
+        // Dark on light is reversed, light on dark is kept.
+        let dark_on_light = ColorDowngrade::Mono.style(style(
+            Color::Dark(BaseColor::Black),
+            Color::Dark(BaseColor::White),
+        ));
+        assert!(dark_on_light.effects.contains(Effect::Reverse));
+        assert_eq!(dark_on_light.color, ColorPair::terminal_default());
+
+        let light_on_dark = ColorDowngrade::Mono.style(style(
+            Color::Light(BaseColor::White),
+            Color::Dark(BaseColor::Red),
+        ));
+        assert!(!light_on_dark.effects.contains(Effect::Reverse));
+    }
+}
@@ -34,6 +34,15 @@ pub enum Effect {
 
     /// Foreground text blinks (background color is static).
     Blink,
+
+    /// Prints foreground with a curly underline (plain underline for ncurses/pancurses, no effect for blt)
+    CurlyUnderline,
+
+    /// Prints foreground with a double underline (plain underline for ncurses/pancurses, no effect for blt)
+    DoubleUnderline,
+
+    /// Prints foreground with a line above (has no effect for ncurses/pancurses/blt backends)
+    Overline,
 }
 
 impl Effect {
@@ -53,6 +62,9 @@ impl Effect {
             Effect::Strikethrough => 5,
             Effect::Underline => 6,
             Effect::Blink => 7,
+            Effect::CurlyUnderline => 8,
+            Effect::DoubleUnderline => 9,
+            Effect::Overline => 10,
         }
     }
 }
@@ -129,6 +141,9 @@ impl Effects {
             Self::status_for(5, effect),
             Self::status_for(6, effect),
             Self::status_for(7, effect),
+            Self::status_for(8, effect),
+            Self::status_for(9, effect),
+            Self::status_for(10, effect),
         ];
 
         Self {
@@ -136,6 +151,19 @@ impl Effects {
         }
     }
 
+    /// Returns a copy of `self` with `effect` set to `OppositeParent`.
+    ///
+    /// Unlike [`Effects::insert`], this can be used in const contexts.
+    #[must_use]
+    pub const fn and(self, effect: Effect) -> Self {
+        let mut statuses = *self.statuses.as_array();
+        statuses[effect.ordinal()] = EffectStatus::OppositeParent;
+
+        Self {
+            statuses: EnumMap::from_array(statuses),
+        }
+    }
+
     /// Resolve an effects directive into concrete effects.
     pub fn resolve(&self, old: ConcreteEffects) -> ConcreteEffects {
         let mut result = ConcreteEffects::default();
@@ -242,6 +270,9 @@ impl FromStr for Effect {
             "Strikethrough" | "strikethrough" => Effect::Strikethrough,
             "Underline" | "underline" => Effect::Underline,
             "Blink" | "blink" => Effect::Blink,
+            "CurlyUnderline" | "curly_underline" => Effect::CurlyUnderline,
+            "DoubleUnderline" | "double_underline" => Effect::DoubleUnderline,
+            "Overline" | "overline" => Effect::Overline,
             _ => return Err(super::NoSuchColor),
         })
     }
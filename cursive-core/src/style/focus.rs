@@ -0,0 +1,142 @@
+use super::{PaletteStyle, StyleType};
+
+/// Defines how built-in views show that they have the focus.
+///
+/// The theme includes a `FocusStyle` (see [`Theme::focus`]). Each entry
+/// applies to a family of views, so focus can be made more visible without
+/// changing the rest of the palette.
+///
+/// Styles can include effects: for example `Effect::Underline` instead of the
+/// default `Effect::Reverse` from the highlight style.
+///
+/// [`Theme::focus`]: crate::theme::Theme::focus
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::style::{BaseColor, ColorStyle, Effect, FocusStyle, Style};
+/// use cursive_core::theme::Theme;
+///
+/// let mut theme = Theme::default();
+/// theme.focus = FocusStyle {
+///     button: Style::from(ColorStyle::front(BaseColor::Yellow))
+///         .combine(Effect::Bold)
+///         .into(),
+///     button_markers: Some((">".into(), "<".into())),
+///     input: Effect::Underline.into(),
+///     ..FocusStyle::default()
+/// };
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FocusStyle {
+    /// Style of focused buttons, including dialog buttons.
+    ///
+    /// Defaults to `PaletteStyle::Highlight`.
+    pub button: StyleType,
+
+    /// Markers replacing the angle brackets of focused buttons.
+    ///
+    /// Only applies to labels starting with `<` and ending with `>`, like the
+    /// ones from [`Button::new`]. Each marker should be one cell wide, so the
+    /// button keeps the same size.
+    ///
+    /// Defaults to `None`: the brackets are kept.
+    ///
+    /// [`Button::new`]: crate::views::Button::new
+    pub button_markers: Option<(String, String)>,
+
+    /// Style of focused checkboxes and radio buttons.
+    ///
+    /// Defaults to `PaletteStyle::Highlight`.
+    pub toggle: StyleType,
+
+    /// Style of the selected item in focused lists, like `SelectView`.
+    ///
+    /// Also applies to focused popup selects.
+    ///
+    /// Defaults to `PaletteStyle::Highlight`.
+    pub list: StyleType,
+
+    /// Style added to the content of focused text inputs, like `EditView`.
+    ///
+    /// Defaults to `StyleType::inherit_parent()`: only the cursor shows the focus.
+    pub input: StyleType,
+}
+
+impl Default for FocusStyle {
+    fn default() -> Self {
+        FocusStyle {
+            button: PaletteStyle::Highlight.into(),
+            button_markers: None,
+            toggle: PaletteStyle::Highlight.into(),
+            list: PaletteStyle::Highlight.into(),
+            input: StyleType::inherit_parent(),
+        }
+    }
+}
+
+impl FocusStyle {
+    /// Load values from a toml table, overwriting previous values.
+    ///
+    /// Styles can be given as a palette style name (like `"highlight"`) or as
+    /// a style description (like `"red+bold"`, or a table).
+    ///
+    /// `button_markers` is an array with two strings, or `false` to keep the brackets.
+    #[cfg(feature = "toml")]
+    pub(crate) fn load_toml(&mut self, table: &toml::value::Table) {
+        let styles = [
+            ("button", &mut self.button),
+            ("toggle", &mut self.toggle),
+            ("list", &mut self.list),
+            ("input", &mut self.input),
+        ];
+        for (key, style) in styles {
+            let Some(value) = table.get(key) else {
+                continue;
+            };
+            match StyleType::parse(value) {
+                Some(parsed) => *style = parsed,
+                None => log::warn!("Could not parse focus style: `{value}`."),
+            }
+        }
+
+        match table.get("button_markers") {
+            Some(&toml::Value::Boolean(false)) => self.button_markers = None,
+            Some(toml::Value::Array(markers)) => match markers.as_slice() {
+                [toml::Value::String(left), toml::Value::String(right)] => {
+                    self.button_markers = Some((left.clone(), right.clone()));
+                }
+                _ => log::warn!("Could not parse button markers: `{markers:?}`."),
+            },
+            _ => (),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "toml"))]
+mod tests {
+    use super::*;
+    use crate::style::{Effect, Style};
+
+    #[test]
+    fn load_toml() {
+        let table = toml::from_str(
+            r#"
+            button = "yellow+bold"
+            button_markers = [">", "<"]
+            list = "title_primary"
+            input = "underline"
+            "#,
+        )
+        .unwrap();
+
+        let mut focus = FocusStyle::default();
+        focus.load_toml(&table);
+
+        assert_eq!(focus.button, "yellow+bold".parse::<Style>().unwrap().into());
+        assert_eq!(focus.button_markers, Some((">".into(), "<".into())));
+        assert_eq!(focus.toggle, PaletteStyle::Highlight.into());
+        assert_eq!(focus.list, PaletteStyle::TitlePrimary.into());
+        assert_eq!(focus.input, Style::from(Effect::Underline).into());
+    }
+}
@@ -110,6 +110,25 @@
 //! On top of a color style, some effects can be applied on cells: `Reverse`,
 //! for instance, swaps the foreground and background colors of a cell.
 //!
+//! Underlines can be plain, curly or double, and can use their own color
+//! (see [`Style::underline`]), for example to mark spelling mistakes. Backends
+//! without support for these fall back to a plain underline.
+//!
+//!
+//! # Contrast
+//!
+//! [`contrast_ratio`] measures how readable a pair of colors is, and
+//! [`check_palette`] finds palette styles that may be hard to read. Use
+//! [`Cursive::set_contrast_warnings`] to log such issues while the application
+//! runs.
+//!
+//! [`Cursive::set_contrast_warnings`]: crate::Cursive::set_contrast_warnings
+//!
+//! # Color downgrade
+//!
+//! Colors the terminal cannot display are replaced with the closest available
+//! one before printing, as defined by the [`ColorDowngrade`] policy of the
+//! theme.
 //!
 //! # Style
 //!
@@ -128,7 +147,10 @@ mod border_style;
 mod color;
 mod color_pair;
 mod color_style;
+mod contrast;
+mod downgrade;
 mod effect;
+mod focus;
 pub mod gradient;
 mod palette;
 mod style_types;
@@ -137,10 +159,44 @@ pub use self::border_style::BorderStyle;
 pub use self::color::{BaseColor, Color, Rgb};
 pub use self::color_pair::ColorPair;
 pub use self::color_style::{ColorStyle, ColorType};
+pub use self::contrast::{check_palette, contrast_ratio, ColorResolution, MIN_CONTRAST_RATIO};
+pub(crate) use self::contrast::{to_rgb, ContrastCheck};
+pub use self::downgrade::{ColorDowngrade, NoSuchColorDowngrade};
 pub use self::effect::{ConcreteEffects, Effect, EffectStatus, Effects};
-pub use self::palette::{Palette, PaletteColor, PaletteNode, PaletteStyle};
+pub use self::focus::FocusStyle;
+pub use self::palette::{Palette, PaletteColor, PaletteNode, PalettePatch, PaletteStyle};
 pub use self::style_types::{ConcreteStyle, Style, StyleType};
 
+/// Builds a [`Style`] from a description, parsed at compile time.
+///
+/// The description is a list of words, separated by spaces:
+///
+/// * Effects, like `bold`, `italic` or `underline`.
+/// * A foreground color: a base color (`red`, `light red`, `dark red`), a
+///   palette color (`primary`, `highlight`, ...), `default` for the
+///   terminal default, or a hex color (`#ff8000`).
+/// * `on`, followed by a background color.
+///
+/// Invalid descriptions are reported as compilation errors. Since no parsing
+/// happens at runtime, the result can be used in const contexts.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::style::Style;
+///
+/// const WARNING: Style = cursive_core::style!("bold red on black");
+/// const TITLE: Style = cursive_core::style!("underline title_primary");
+///
+/// assert_eq!(WARNING, "bold+red+back.black".parse().unwrap());
+/// ```
+#[macro_export]
+macro_rules! style {
+    ($description:literal) => {
+        $crate::__style!($crate, $description)
+    };
+}
+
 /// Error parsing a color.
 #[derive(Debug)]
 pub struct NoSuchColor;
@@ -152,3 +208,50 @@ impl std::fmt::Display for NoSuchColor {
 }
 
 impl std::error::Error for NoSuchColor {}
+
+#[cfg(test)]
+mod tests {
+    use super::Style;
+
+    #[test]
+    fn style_macro() {
+        fn parse(s: &str) -> Style {
+            s.parse().unwrap()
+        }
+
+        assert_eq!(crate::style!(""), Style::none());
+        assert_eq!(
+            crate::style!("bold red on black"),
+            parse("bold+red+back.black")
+        );
+        assert_eq!(
+            crate::style!("dim light blue on #fff underline"),
+            parse("dim+light blue+back.#fff+underline")
+        );
+        assert_eq!(crate::style!("on highlight"), parse("back.highlight"));
+        assert_eq!(crate::style!(r"dark green on 050"), parse("green+back.050"));
+        assert_eq!(
+            crate::style!("curly_underline overline"),
+            parse("curly_underline+overline")
+        );
+    }
+
+    #[test]
+    fn underline_color() {
+        use super::{BaseColor, ConcreteStyle, Effect, Palette};
+
+        let style: Style = "red+curly_underline+underline.blue".parse().unwrap();
+        assert_eq!(style.underline, BaseColor::Blue.dark().into());
+
+        let palette = Palette::default();
+        let concrete = style.resolve(&palette, ConcreteStyle::terminal_default());
+        assert!(concrete.effects.contains(Effect::CurlyUnderline));
+        assert_eq!(concrete.underline, Some(BaseColor::Blue.dark()));
+
+        // The underline color is inherited, unless replaced.
+        let nested = Style::from(Effect::Bold).resolve(&palette, concrete);
+        assert_eq!(nested.underline, Some(BaseColor::Blue.dark()));
+        let reset = Style::underline(super::Color::TerminalDefault).resolve(&palette, concrete);
+        assert_eq!(reset.underline, None);
+    }
+}
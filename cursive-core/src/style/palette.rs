@@ -1,4 +1,4 @@
-use super::{Color, Effects, NoSuchColor, Style};
+use super::{Color, ColorType, Effects, NoSuchColor, Style};
 use enum_map::{enum_map, Enum, EnumMap};
 #[cfg(feature = "toml")]
 use log::warn;
@@ -102,14 +102,17 @@ fn default_styles() -> EnumMap<PaletteStyle, Style> {
         Highlight => Style {
             color: ColorStyle::highlight().invert(),
             effects: Effects::only(Effect::Reverse),
+            underline: ColorType::InheritParent,
         },
         HighlightInactive => Style {
             color: ColorStyle::highlight_inactive().invert(),
             effects: Effects::only(Effect::Reverse),
+            underline: ColorType::InheritParent,
         },
         EditableText => Style {
             color: ColorStyle::secondary(),
             effects: Effects::only(Effect::Reverse),
+            underline: ColorType::InheritParent,
         },
         EditableTextCursor => ColorStyle::secondary().into(),
         EditableTextInactive => ColorStyle::secondary().into(),
@@ -298,6 +301,123 @@ impl Default for Palette {
     }
 }
 
+/// A partial set of palette overrides.
+///
+/// Unlike a full [`Palette`], a patch only records the entries it changes.
+/// It can be applied on top of any palette, leaving all other entries intact.
+///
+/// # Example
+///
+/// ```rust
+/// use cursive_core::style::{BaseColor::*, Palette, PaletteColor, PalettePatch};
+///
+/// let patch = PalettePatch::new().color(PaletteColor::Primary, Red.light());
+///
+/// let mut palette = Palette::default();
+/// let view = palette[PaletteColor::View];
+/// patch.apply(&mut palette);
+///
+/// assert_eq!(palette[PaletteColor::Primary], Red.light());
+/// assert_eq!(palette[PaletteColor::View], view);
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+pub struct PalettePatch {
+    basic: EnumMap<PaletteColor, Option<Color>>,
+    custom: HashMap<String, Color>,
+    styles: EnumMap<PaletteStyle, Option<Style>>,
+}
+
+impl PalettePatch {
+    /// Creates a new empty patch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if this patch doesn't override anything.
+    pub fn is_empty(&self) -> bool {
+        self.basic.values().all(Option::is_none)
+            && self.custom.is_empty()
+            && self.styles.values().all(Option::is_none)
+    }
+
+    /// Overrides a basic color.
+    pub fn set_color(&mut self, key: PaletteColor, color: Color) {
+        self.basic[key] = Some(color);
+    }
+
+    /// Overrides a basic color.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn color(mut self, key: PaletteColor, color: Color) -> Self {
+        self.set_color(key, color);
+        self
+    }
+
+    /// Returns the override for the given color, if any.
+    pub fn get_color(&self, key: PaletteColor) -> Option<Color> {
+        self.basic[key]
+    }
+
+    /// Overrides a color from its name.
+    ///
+    /// This will update either a basic color or a custom one.
+    pub fn set_color_by_name(&mut self, key: &str, color: Color) {
+        match PaletteColor::from_str(key) {
+            Ok(key) => self.set_color(key, color),
+            Err(_) => {
+                self.custom.insert(key.to_string(), color);
+            }
+        }
+    }
+
+    /// Overrides a palette style.
+    pub fn set_style<S: Into<Style>>(&mut self, key: PaletteStyle, style: S) {
+        self.styles[key] = Some(style.into());
+    }
+
+    /// Overrides a palette style.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn style<S: Into<Style>>(mut self, key: PaletteStyle, style: S) -> Self {
+        self.set_style(key, style);
+        self
+    }
+
+    /// Returns the override for the given style, if any.
+    pub fn get_style(&self, key: PaletteStyle) -> Option<Style> {
+        self.styles[key]
+    }
+
+    /// Removes the override for the given color.
+    pub fn reset_color(&mut self, key: PaletteColor) {
+        self.basic[key] = None;
+    }
+
+    /// Removes the override for the given style.
+    pub fn reset_style(&mut self, key: PaletteStyle) {
+        self.styles[key] = None;
+    }
+
+    /// Applies this patch to the given palette.
+    pub fn apply(&self, palette: &mut Palette) {
+        for (key, color) in &self.basic {
+            if let Some(color) = *color {
+                palette[key] = color;
+            }
+        }
+        for (key, &color) in &self.custom {
+            palette.set_color(key, color);
+        }
+        for (key, style) in &self.styles {
+            if let Some(style) = *style {
+                palette[key] = style;
+            }
+        }
+    }
+}
+
 // Iterate over a toml
 #[cfg(feature = "toml")]
 fn iterate_toml_colors(table: &toml::value::Table) -> impl Iterator<Item = (&str, PaletteNode)> {
@@ -20,6 +20,11 @@ pub struct Style {
 
     /// Color style to apply.
     pub color: ColorStyle,
+
+    /// Color of underlines.
+    ///
+    /// `Color::TerminalDefault` uses the text color.
+    pub underline: ColorType,
 }
 
 /// Combine a concrete color and effects.
@@ -33,6 +38,11 @@ pub struct ConcreteStyle {
 
     /// Color style to apply.
     pub color: ColorPair,
+
+    /// Color of underlines, if different from the text color.
+    ///
+    /// Only some backends support this.
+    pub underline: Option<Color>,
 }
 
 impl ConcreteStyle {
@@ -41,6 +51,7 @@ impl ConcreteStyle {
         ConcreteStyle {
             effects: EnumSet::empty(),
             color: ColorPair::terminal_default(),
+            underline: None,
         }
     }
 }
@@ -75,6 +86,17 @@ impl Style {
         Style {
             effects: Effects::empty(),
             color,
+            underline: ColorType::InheritParent,
+        }
+    }
+
+    /// Create a new `Style` that only sets the underline color.
+    ///
+    /// This does not enable any underline effect.
+    pub fn underline<C: Into<ColorType>>(color: C) -> Self {
+        Style {
+            underline: color.into(),
+            ..Self::inherit_parent()
         }
     }
 
@@ -133,6 +155,7 @@ impl Style {
         Style {
             color: ColorStyle::highlight().invert(),
             effects: Effects::only(Effect::Reverse),
+            underline: ColorType::InheritParent,
         }
     }
 
@@ -141,6 +164,7 @@ impl Style {
         Style {
             color: ColorStyle::highlight_inactive().invert(),
             effects: Effects::only(Effect::Reverse),
+            underline: ColorType::InheritParent,
         }
     }
 
@@ -157,7 +181,16 @@ impl Style {
 
         let color = ColorStyle::parse(table)?;
 
-        Some(Style { effects, color })
+        let underline = match table.get("underline") {
+            Some(underline) => underline.as_str()?.parse().ok()?,
+            None => ColorType::InheritParent,
+        };
+
+        Some(Style {
+            effects,
+            color,
+            underline,
+        })
     }
 
     /// Resolve a style to a concrete style.
@@ -165,6 +198,13 @@ impl Style {
         ConcreteStyle {
             effects: self.effects.resolve(previous.effects),
             color: self.color.resolve(palette, previous.color),
+            underline: match self.underline.resolve(
+                palette,
+                previous.underline.unwrap_or(Color::TerminalDefault),
+            ) {
+                Color::TerminalDefault => None,
+                color => Some(color),
+            },
         }
     }
 }
@@ -176,6 +216,12 @@ fn parse_single_style(s: &str) -> Result<Style, super::NoSuchColor> {
         }
     }
 
+    if let Some(s) = s.strip_prefix("underline.") {
+        if let Ok(underline) = s.parse::<ColorType>() {
+            return Ok(Style::underline(underline));
+        }
+    }
+
     if let Ok(front) = s.parse::<ColorType>() {
         return Ok(front.into());
     }
@@ -195,11 +241,21 @@ impl FromStr for Style {
     }
 }
 
+impl From<ConcreteStyle> for Style {
+    fn from(style: ConcreteStyle) -> Self {
+        Style {
+            effects: style.effects.into(),
+            color: style.color.into(),
+            underline: style.underline.unwrap_or(Color::TerminalDefault).into(),
+        }
+    }
+}
+
 impl From<Effects> for Style {
     fn from(effects: Effects) -> Self {
         Style {
             effects,
-            color: ColorStyle::inherit_parent(),
+            ..Self::inherit_parent()
         }
     }
 }
@@ -208,17 +264,14 @@ impl From<Effect> for Style {
     fn from(effect: Effect) -> Self {
         Style {
             effects: Effects::only(effect),
-            color: ColorStyle::inherit_parent(),
+            ..Self::inherit_parent()
         }
     }
 }
 
 impl From<ColorStyle> for Style {
     fn from(color: ColorStyle) -> Self {
-        Style {
-            effects: Effects::default(),
-            color,
-        }
+        Self::from_color_style(color)
     }
 }
 
@@ -266,6 +319,22 @@ pub enum StyleType {
 }
 
 impl StyleType {
+    /// Parse a toml entry.
+    ///
+    /// Strings can be a palette style name (like `"highlight"`) or a style
+    /// description (like `"red+bold"`). Tables are parsed as a `Style`.
+    #[cfg(feature = "toml")]
+    pub(crate) fn parse(value: &toml::Value) -> Option<Self> {
+        match value {
+            toml::Value::String(name) => name
+                .parse()
+                .map(StyleType::Palette)
+                .or_else(|_| name.parse::<Style>().map(StyleType::Style))
+                .ok(),
+            value => Style::parse(value).map(StyleType::Style),
+        }
+    }
+
     /// Given a palette, resolve `self` to a concrete style.
     pub fn resolve(self, palette: &Palette) -> Style {
         match self {
@@ -340,6 +409,7 @@ impl From<ColorPair> for ConcreteStyle {
         ConcreteStyle {
             effects: Default::default(),
             color,
+            underline: None,
         }
     }
 }
@@ -410,14 +480,20 @@ impl AsRef<Style> for Style {
 fn combine_styles<S: AsRef<Style>>(styles: impl IntoIterator<Item = S>) -> Style {
     let mut color = ColorStyle::inherit_parent();
     let mut effects = Effects::empty();
+    let mut underline = ColorType::InheritParent;
 
     for style in styles {
         let style = style.as_ref();
         color = ColorStyle::merge(color, style.color);
         effects = Effects::merge(effects, style.effects);
+        underline = ColorType::merge(underline, style.underline);
     }
 
-    Style { effects, color }
+    Style {
+        effects,
+        color,
+        underline,
+    }
 }
 
 /// Creates a new `Style` by merging all given styles.
@@ -0,0 +1,390 @@
+//! Helpers to test views without a terminal.
+//!
+//! A [`Harness`] runs a [`Cursive`] root with a fixed screen size and no backend. Input can be
+//! scripted with [`Harness::feed_keys`], and the output inspected as a [`Screen`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cursive_core::test::{render_view, Harness};
+//! use cursive_core::traits::Resizable;
+//! use cursive_core::views::{Dialog, EditView, TextView};
+//!
+//! // Render a single view.
+//! let screen = render_view(TextView::new("Hello"), (10, 3));
+//! screen.assert_contains("Hello");
+//!
+//! // Or script some interactions.
+//! let mut harness = Harness::new((20, 8));
+//! harness
+//!     .cursive()
+//!     .add_layer(Dialog::around(EditView::new().fixed_width(10)));
+//! harness.feed_keys("abc<left><backspace>");
+//! harness.screen().assert_contains("ac");
+//! ```
+use crate::buffer::PrintBuffer;
+use crate::event::Event;
+use crate::style::ConcreteStyle;
+use crate::view::IntoBoxedView;
+use crate::{Cursive, Vec2};
+
+use std::fmt;
+
+use unicode_width::UnicodeWidthStr;
+
+/// Renders the given view on a screen of the given size.
+///
+/// The view is added as a fullscreen layer to a new [`Cursive`] root.
+pub fn render_view<V, S>(view: V, size: S) -> Screen
+where
+    V: IntoBoxedView,
+    S: Into<Vec2>,
+{
+    let mut harness = Harness::new(size);
+    harness.cursive().add_fullscreen_layer(view);
+    harness.screen()
+}
+
+/// A [`Cursive`] root with a fixed screen size and no backend.
+pub struct Harness {
+    siv: Cursive,
+    size: Vec2,
+}
+
+impl Harness {
+    /// Creates a new harness with an empty [`Cursive`] root.
+    pub fn new<S: Into<Vec2>>(size: S) -> Self {
+        Self::with_cursive(Cursive::new(), size)
+    }
+
+    /// Creates a new harness around an existing [`Cursive`] root.
+    pub fn with_cursive<S: Into<Vec2>>(siv: Cursive, size: S) -> Self {
+        Harness {
+            siv,
+            size: size.into(),
+        }
+    }
+
+    /// Gives access to the [`Cursive`] root, for example to add layers.
+    pub fn cursive(&mut self) -> &mut Cursive {
+        &mut self.siv
+    }
+
+    /// Returns the screen size.
+    pub fn size(&self) -> Vec2 {
+        self.size
+    }
+
+    /// Changes the screen size.
+    pub fn resize<S: Into<Vec2>>(&mut self, size: S) {
+        self.size = size.into();
+    }
+
+    /// Sends an event, like the event loop would.
+    ///
+    /// Views are laid out first, and callbacks sent to the cb sink are processed afterwards.
+    pub fn feed_event(&mut self, event: Event) {
+        self.siv.layout(self.size);
+        self.siv.on_event(event);
+        self.process_callbacks();
+    }
+
+    /// Sends a sequence of events.
+    pub fn feed_events<I>(&mut self, events: I)
+    where
+        I: IntoIterator<Item = Event>,
+    {
+        for event in events {
+            self.feed_event(event);
+        }
+    }
+
+    /// Types the given keys.
+    ///
+    /// See [`parse_keys`] for the syntax.
+    ///
+    /// # Panics
+    ///
+    /// If `keys` contains an unknown key name.
+    #[track_caller]
+    pub fn feed_keys(&mut self, keys: &str) {
+        self.feed_events(parse_keys(keys));
+    }
+
+    /// Renders the current screen.
+    pub fn screen(&mut self) -> Screen {
+        self.siv.layout(self.size);
+        Screen {
+            buffer: self.siv.screenshot(),
+        }
+    }
+
+    fn process_callbacks(&mut self) {
+        while self.siv.process_callback() || self.siv.process_injected_event() {}
+    }
+}
+
+/// Parses a sequence of keys.
+///
+/// Characters are typed as-is, and special keys are written between angle brackets:
+///
+/// * `<enter>`, `<tab>`, `<backspace>`, `<esc>`, `<ins>`, `<del>`
+/// * `<left>`, `<right>`, `<up>`, `<down>`, `<home>`, `<end>`, `<pageup>`, `<pagedown>`
+/// * `<f1>` to `<f12>`
+/// * `<lt>` for a literal `<`
+///
+/// Keys can be prefixed with `ctrl-`, `alt-` or `shift-`, like `<ctrl-c>` or `<shift-tab>`.
+///
+/// Names are case-insensitive.
+///
+/// # Panics
+///
+/// If `keys` contains an unknown key name.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::event::{Event, Key};
+/// use cursive_core::test::parse_keys;
+///
+/// assert_eq!(
+///     parse_keys("a<enter><ctrl-c>"),
+///     [Event::Char('a'), Event::Key(Key::Enter), Event::CtrlChar('c')]
+/// );
+/// ```
+#[track_caller]
+pub fn parse_keys(keys: &str) -> Vec<Event> {
+    let mut events = Vec::new();
+
+    let mut rest = keys;
+    while let Some(c) = rest.chars().next() {
+        if c != '<' {
+            events.push(Event::Char(c));
+            rest = &rest[c.len_utf8()..];
+            continue;
+        }
+
+        let Some(end) = rest.find('>') else {
+            panic!("unclosed `<` in {keys:?}");
+        };
+        let name = &rest[1..end];
+        match parse_key(name) {
+            Some(event) => events.push(event),
+            None => panic!("unknown key `<{name}>` in {keys:?}"),
+        }
+        rest = &rest[end + 1..];
+    }
+
+    events
+}
+
+fn parse_key(name: &str) -> Option<Event> {
+    let name = name.to_lowercase();
+
+    if name == "lt" {
+        return Some(Event::Char('<'));
+    }
+
+    crate::keymap::parse_event(&name)
+}
+
+/// A rendered screen.
+///
+/// Displays as a snapshot: one line per row, without trailing whitespace.
+pub struct Screen {
+    buffer: PrintBuffer,
+}
+
+impl Screen {
+    /// Returns the size of the screen.
+    pub fn size(&self) -> Vec2 {
+        self.buffer.size()
+    }
+
+    /// Returns the underlying buffer.
+    pub fn buffer(&self) -> &PrintBuffer {
+        &self.buffer
+    }
+
+    /// Returns the text of the given row.
+    ///
+    /// Cells never written to are converted to spaces.
+    pub fn row(&self, y: usize) -> String {
+        (0..self.size().x)
+            .map(|x| {
+                let pos = Vec2::new(x, y);
+                match self.buffer.cell_text(pos) {
+                    Some(text) => text,
+                    // Cells covered by the previous, double-width one are skipped.
+                    None if x > 0 && self.is_covered(pos) => "",
+                    None => " ",
+                }
+            })
+            .collect()
+    }
+
+    fn is_covered(&self, pos: Vec2) -> bool {
+        let previous = self.buffer.cell_at(pos - (1, 0));
+        matches!(previous, Some(cell) if cell.width() > 1)
+    }
+
+    /// Returns the text of each row.
+    pub fn rows(&self) -> Vec<String> {
+        (0..self.size().y).map(|y| self.row(y)).collect()
+    }
+
+    /// Returns the position of the first occurrence of `text`, if any.
+    ///
+    /// `text` should not span multiple rows.
+    pub fn find(&self, text: &str) -> Option<Vec2> {
+        self.rows().iter().enumerate().find_map(|(y, row)| {
+            let start = row.find(text)?;
+            Some(Vec2::new(row[..start].width(), y))
+        })
+    }
+
+    /// Returns `true` if `text` is shown somewhere on the screen.
+    pub fn contains(&self, text: &str) -> bool {
+        self.find(text).is_some()
+    }
+
+    /// Returns the style of the cell at the given position.
+    pub fn style_at<P: Into<Vec2>>(&self, pos: P) -> Option<ConcreteStyle> {
+        self.buffer.cell_style(pos.into())
+    }
+
+    /// Checks that `text` is shown somewhere on the screen.
+    ///
+    /// # Panics
+    ///
+    /// If `text` is not found. The message includes the entire screen.
+    #[track_caller]
+    pub fn assert_contains(&self, text: &str) {
+        assert!(
+            self.contains(text),
+            "{text:?} not found on screen:\n{}",
+            self.snapshot()
+        );
+    }
+
+    /// Returns a readable text representation of the screen.
+    ///
+    /// Each row becomes a line, without trailing whitespace.
+    pub fn snapshot(&self) -> String {
+        let rows: Vec<_> = self
+            .rows()
+            .iter()
+            .map(|row| row.trim_end().to_string())
+            .collect();
+        rows.join("\n")
+    }
+
+    /// Compares the screen to an expected snapshot.
+    ///
+    /// Leading and trailing empty lines, as well as trailing whitespace on each line, are
+    /// ignored. This lets the expected value be written as a multi-line string literal.
+    ///
+    /// # Panics
+    ///
+    /// If the screen does not match the snapshot.
+    #[track_caller]
+    pub fn assert_snapshot(&self, expected: &str) {
+        let expected: Vec<_> = expected.lines().map(str::trim_end).collect();
+        let start = expected.iter().position(|line| !line.is_empty());
+        let end = expected.iter().rposition(|line| !line.is_empty());
+        let expected = match (start, end) {
+            (Some(start), Some(end)) => expected[start..=end].join("\n"),
+            _ => String::new(),
+        };
+
+        let snapshot = self.snapshot();
+        let actual = snapshot.trim_matches('\n');
+
+        assert!(
+            actual == expected,
+            "screen does not match snapshot.\nExpected:\n{expected}\nActual:\n{actual}"
+        );
+    }
+}
+
+impl fmt::Debug for Screen {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Screen")
+            .field("size", &self.size())
+            .field("rows", &self.rows())
+            .finish()
+    }
+}
+
+impl fmt::Display for Screen {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.snapshot())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::Key;
+    use crate::views::{Dialog, EditView, TextView};
+
+    #[test]
+    fn keys() {
+        assert_eq!(
+            parse_keys("a<Enter><lt><shift-tab><alt-x><ctrl-left><f5>"),
+            [
+                Event::Char('a'),
+                Event::Key(Key::Enter),
+                Event::Char('<'),
+                Event::Shift(Key::Tab),
+                Event::AltChar('x'),
+                Event::Ctrl(Key::Left),
+                Event::Key(Key::F5),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "unknown key `<nope>`")]
+    fn unknown_key() {
+        parse_keys("<nope>");
+    }
+
+    #[test]
+    fn screen() {
+        let screen = render_view(TextView::new("Hello\n界 world"), (10, 3));
+        screen.assert_snapshot(
+            "
+Hello
+界 world
+",
+        );
+        assert_eq!(screen.find("world"), Some(Vec2::new(3, 1)));
+        assert!(!screen.contains("nope"));
+        assert_eq!(screen.to_string(), "Hello\n界 world\n");
+    }
+
+    #[test]
+    fn harness() {
+        let mut harness = Harness::new((20, 8));
+        harness
+            .cursive()
+            .add_layer(Dialog::around(EditView::new()).button("Ok", |s| {
+                s.pop_layer();
+            }));
+
+        harness.feed_keys("abc<left><backspace>");
+        let screen = harness.screen();
+        screen.assert_contains("ac");
+
+        // Focus the button, and press it to close the dialog.
+        harness.feed_keys("<tab>");
+        let screen = harness.screen();
+        let button = screen.find("<Ok>").unwrap();
+        let edit = screen.find("ac").unwrap();
+        assert_ne!(screen.style_at(button), screen.style_at(edit));
+
+        harness.feed_keys("<enter>");
+        assert!(!harness.screen().contains("ac"));
+    }
+}
@@ -37,6 +37,10 @@
 //! # First come some various options
 //! shadow = false  # Don't draw shadows around stacked views
 //! borders = "simple"  # Alternatives are "none" and "outset"
+//! ascii = false  # Set to true to only draw ASCII borders and scrollbars
+//! # Colors the terminal cannot display use the closest available one.
+//! # Alternatives are "truecolor", "256", "16", "8" and "mono".
+//! color_downgrade = "auto"
 //!
 //! # Here we define the color palette.
 //! [colors]
@@ -60,6 +64,32 @@
 //!     # Lower precision values can use only 3 digits.
 //!     highlight          = "#F00"
 //!     highlight_inactive = "#5555FF"
+//!
+//! # Scrollbars can be customized too.
+//! [scrollbar]
+//!     thumb = "█"
+//!     vertical_track = "│"
+//!     horizontal_track = "─"
+//!     corner = " "
+//!     thickness = 1
+//!
+//!     # Styles are palette style names, or style descriptions.
+//!     thumb_style = "highlight"
+//!     thumb_inactive_style = "highlight_inactive"
+//!     track_style = "secondary"
+//!
+//!     # Only show scrollbars for 1.5 seconds after scrolling.
+//!     auto_hide = 1.5
+//!
+//! # Focused views can be made more visible.
+//! [focus]
+//!     button = "yellow+bold"
+//!     # Replace the angle brackets of focused buttons.
+//!     button_markers = ["»", "«"]
+//!     toggle = "highlight"
+//!     list = "back.yellow+black"
+//!     # Added to the content of focused text inputs.
+//!     input = "underline"
 //! ```
 //!
 //! [`Theme`]: ./struct.Theme.html
@@ -82,6 +112,8 @@ pub use crate::style::{
     PaletteStyle, Style, StyleType,
 };
 
+use crate::style::{ColorDowngrade, FocusStyle};
+use crate::view::scroll::ScrollStyle;
 #[cfg(feature = "toml")]
 use std::fs::File;
 use std::io;
@@ -99,8 +131,28 @@ pub struct Theme {
     /// How view borders should be drawn.
     pub borders: BorderStyle,
 
+    /// Whether built-in views should only use ASCII characters.
+    ///
+    /// This is useful for dumb terminals and serial consoles, which may not
+    /// render box-drawing characters properly.
+    pub ascii: bool,
+
     /// What colors should be used through the application?
     pub palette: Palette,
+
+    /// How scrollbars should be drawn.
+    ///
+    /// Individual views can override it with
+    /// [`Core::set_scroll_style`](crate::view::scroll::Core::set_scroll_style).
+    pub scrollbar: ScrollStyle,
+
+    /// How built-in views show that they have the focus.
+    pub focus: FocusStyle,
+
+    /// How colors are downgraded when the terminal cannot display them.
+    ///
+    /// Defaults to `ColorDowngrade::Auto`, which follows the backend.
+    pub color_downgrade: ColorDowngrade,
 }
 
 /// Currently returns the retro theme.
@@ -116,7 +168,11 @@ impl Theme {
         Theme {
             shadow: false,
             borders: BorderStyle::Simple,
+            ascii: false,
             palette: Palette::terminal_default(),
+            scrollbar: ScrollStyle::default(),
+            focus: FocusStyle::default(),
+            color_downgrade: ColorDowngrade::Auto,
         }
     }
 
@@ -125,7 +181,11 @@ impl Theme {
         Theme {
             shadow: true,
             borders: BorderStyle::Simple,
+            ascii: false,
             palette: Palette::retro(),
+            scrollbar: ScrollStyle::default(),
+            focus: FocusStyle::default(),
+            color_downgrade: ColorDowngrade::Auto,
         }
     }
 
@@ -143,6 +203,17 @@ impl Theme {
             self.borders = BorderStyle::from(borders);
         }
 
+        if let Some(&toml::Value::Boolean(ascii)) = table.get("ascii") {
+            self.ascii = ascii;
+        }
+
+        if let Some(toml::Value::String(color_downgrade)) = table.get("color_downgrade") {
+            match color_downgrade.parse() {
+                Ok(color_downgrade) => self.color_downgrade = color_downgrade,
+                Err(_) => log::warn!("Unknown color downgrade: `{color_downgrade}`."),
+            }
+        }
+
         if let Some(toml::Value::Table(table)) = table.get("colors") {
             self.palette.load_toml(table);
         }
@@ -150,6 +221,14 @@ impl Theme {
         if let Some(toml::Value::Table(table)) = table.get("styles") {
             self.palette.load_toml_styles(table);
         }
+
+        if let Some(toml::Value::Table(table)) = table.get("scrollbar") {
+            self.scrollbar.load_toml(table);
+        }
+
+        if let Some(toml::Value::Table(table)) = table.get("focus") {
+            self.focus.load_toml(table);
+        }
     }
 }
 
@@ -0,0 +1,186 @@
+use crate::Cursive;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+type TimerCallback = dyn FnMut(&mut Cursive) + Send;
+
+/// Handle to a timer scheduled on the event loop.
+///
+/// Returned by [`Cursive::set_timeout`] and [`Cursive::set_interval`].
+///
+/// Dropping the handle does _not_ cancel the timer; call [`TimerHandle::cancel`] for that.
+///
+/// The handle can be cloned and sent to other threads.
+#[derive(Clone, Debug, Default)]
+pub struct TimerHandle {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl TimerHandle {
+    /// Cancels the timer.
+    ///
+    /// The callback will not run again, even if it was already due.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if this timer was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// A callback scheduled to run at a given time.
+pub(crate) struct Timer {
+    deadline: Instant,
+
+    // If set, the timer is re-scheduled after each run.
+    interval: Option<Duration>,
+
+    callback: Box<TimerCallback>,
+    handle: TimerHandle,
+}
+
+/// Set of timers waiting to run.
+#[derive(Default)]
+pub(crate) struct Timers {
+    timers: Vec<Timer>,
+}
+
+impl Timers {
+    /// Schedules a new timer and returns its handle.
+    pub fn add(
+        &mut self,
+        delay: Duration,
+        interval: Option<Duration>,
+        callback: Box<TimerCallback>,
+    ) -> TimerHandle {
+        let handle = TimerHandle::default();
+        self.add_with_handle(delay, interval, callback, handle.clone());
+        handle
+    }
+
+    /// Schedules a new timer, cancelled along with the given handle.
+    ///
+    /// Several timers can share the same handle.
+    pub fn add_with_handle(
+        &mut self,
+        delay: Duration,
+        interval: Option<Duration>,
+        callback: Box<TimerCallback>,
+        handle: TimerHandle,
+    ) {
+        self.timers.push(Timer {
+            deadline: Instant::now() + delay,
+            interval,
+            callback,
+            handle,
+        });
+    }
+
+    /// Returns the earliest deadline among the active timers.
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.timers
+            .iter()
+            .filter(|timer| !timer.handle.is_cancelled())
+            .map(|timer| timer.deadline)
+            .min()
+    }
+
+    /// Removes and returns the timers due at `now`, in deadline order.
+    ///
+    /// Cancelled timers are dropped.
+    fn take_due(&mut self, now: Instant) -> Vec<Timer> {
+        self.timers.retain(|timer| !timer.handle.is_cancelled());
+
+        let (mut due, pending) = std::mem::take(&mut self.timers)
+            .into_iter()
+            .partition::<Vec<_>, _>(|timer| timer.deadline <= now);
+        self.timers = pending;
+
+        due.sort_by_key(|timer| timer.deadline);
+        due
+    }
+}
+
+impl Cursive {
+    /// Runs any timer that is due.
+    ///
+    /// Returns `true` if at least one callback was run.
+    pub(crate) fn process_timers(&mut self) -> bool {
+        let now = Instant::now();
+        let due = self.timers.take_due(now);
+        let ran = !due.is_empty();
+
+        for mut timer in due {
+            // A previous callback may have cancelled this one.
+            if timer.handle.is_cancelled() {
+                continue;
+            }
+
+            (timer.callback)(self);
+
+            if let Some(interval) = timer.interval {
+                if !timer.handle.is_cancelled() {
+                    // Don't try to catch up on missed runs if we're late.
+                    timer.deadline = std::cmp::max(timer.deadline + interval, now);
+                    self.timers.timers.push(timer);
+                }
+            }
+        }
+
+        ran
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Cursive;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[test]
+    fn timeout_runs_once() {
+        let mut siv = Cursive::new();
+        let count = Arc::new(AtomicUsize::new(0));
+
+        let counter = Arc::clone(&count);
+        siv.set_timeout(Duration::ZERO, move |_| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+
+        assert!(siv.process_timers());
+        assert!(!siv.process_timers());
+        assert_eq!(count.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn interval_until_cancelled() {
+        let mut siv = Cursive::new();
+        let count = Arc::new(AtomicUsize::new(0));
+
+        let counter = Arc::clone(&count);
+        let handle = siv.set_interval(Duration::ZERO, move |_| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+
+        assert!(siv.process_timers());
+        assert!(siv.process_timers());
+        handle.cancel();
+        assert!(!siv.process_timers());
+        assert_eq!(count.load(Ordering::Relaxed), 2);
+        assert!(siv.next_timer_deadline().is_none());
+    }
+
+    #[test]
+    fn pending_timeout() {
+        let mut siv = Cursive::new();
+        siv.set_timeout(Duration::from_secs(3600), |s| s.quit());
+
+        assert!(!siv.process_timers());
+        assert!(siv.next_timer_deadline().is_some());
+        assert!(siv.is_running());
+    }
+}
@@ -9,7 +9,7 @@
 //! ```
 
 #[doc(no_inline)]
-pub use crate::view::{Finder, Nameable, Resizable, Scrollable, View};
+pub use crate::view::{Backgroundable, Finder, Nameable, Resizable, Scrollable, View};
 
 #[doc(no_inline)]
 pub use crate::With;
@@ -0,0 +1,365 @@
+//! Pixel drawing with a resolution finer than cells.
+//!
+//! A [`PixelBuffer`] is a grid of colored pixels, several of them per cell.
+//! Shapes are drawn on the grid, and the buffer is then printed with a
+//! [`Printer`], for example from a [`Canvas`].
+//!
+//! [`Canvas`]: crate::views::Canvas
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cursive_core::style::{BaseColor, Color};
+//! use cursive_core::utils::draw::{PixelBuffer, PixelMode};
+//! use cursive_core::views::Canvas;
+//! use cursive_core::Vec2;
+//!
+//! // 20x5 cells hold 40x20 pixels.
+//! let mut buffer = PixelBuffer::new(PixelMode::Braille, Vec2::new(20, 5));
+//! buffer.circle(Vec2::new(20, 10), 8, Color::Dark(BaseColor::Blue));
+//! buffer.line(Vec2::new(0, 19), Vec2::new(39, 0), Color::Dark(BaseColor::Red));
+//!
+//! let canvas = Canvas::new(buffer)
+//!     .with_draw(|buffer, printer| buffer.draw(printer))
+//!     .with_required_size(|buffer, _| buffer.size());
+//! ```
+use crate::style::{Color, ColorStyle};
+use crate::{Printer, Rect, Vec2};
+
+/// How pixels are mapped to cells.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PixelMode {
+    /// 2x4 pixels per cell, drawn with braille patterns.
+    ///
+    /// A cell only has one color: the most common among its pixels.
+    #[default]
+    Braille,
+
+    /// 1x2 pixels per cell, drawn with half blocks (`▀`, `▄`).
+    ///
+    /// Each pixel keeps its own color.
+    HalfBlock,
+}
+
+impl PixelMode {
+    /// Returns the number of pixels in each cell, horizontally and vertically.
+    pub fn cell_size(self) -> Vec2 {
+        match self {
+            PixelMode::Braille => Vec2::new(2, 4),
+            PixelMode::HalfBlock => Vec2::new(1, 2),
+        }
+    }
+}
+
+impl std::str::FromStr for PixelMode {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Braille" | "braille" => Self::Braille,
+            "HalfBlock" | "half_block" => Self::HalfBlock,
+            _ => return Err(()),
+        })
+    }
+}
+
+// Braille dot for each pixel of a cell, indexed by `[y][x]`.
+const BRAILLE_DOTS: [[u32; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// Grid of colored pixels, printed with several pixels per cell.
+///
+/// Pixels are addressed from the top-left corner. Drawing outside of the
+/// buffer is ignored.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PixelBuffer {
+    mode: PixelMode,
+
+    // Size in cells.
+    size: Vec2,
+
+    // Color of each lit pixel, row by row.
+    pixels: Vec<Option<Color>>,
+}
+
+impl PixelBuffer {
+    /// Creates a new empty buffer covering `size` cells.
+    pub fn new(mode: PixelMode, size: Vec2) -> Self {
+        let pixel_size = size * mode.cell_size();
+        PixelBuffer {
+            mode,
+            size,
+            pixels: vec![None; pixel_size.x * pixel_size.y],
+        }
+    }
+
+    /// Returns how pixels are mapped to cells.
+    pub fn mode(&self) -> PixelMode {
+        self.mode
+    }
+
+    /// Returns the size of the buffer, in cells.
+    pub fn size(&self) -> Vec2 {
+        self.size
+    }
+
+    /// Returns the size of the buffer, in pixels.
+    pub fn pixel_size(&self) -> Vec2 {
+        self.size * self.mode.cell_size()
+    }
+
+    /// Changes the size of the buffer, in cells.
+    ///
+    /// This clears all pixels.
+    pub fn resize(&mut self, size: Vec2) {
+        *self = Self::new(self.mode, size);
+    }
+
+    /// Turns off all pixels.
+    pub fn clear(&mut self) {
+        self.pixels.iter_mut().for_each(|pixel| *pixel = None);
+    }
+
+    fn index(&self, pos: Vec2) -> Option<usize> {
+        let size = self.pixel_size();
+        pos.strictly_lt(size).then(|| pos.y * size.x + pos.x)
+    }
+
+    /// Returns the color of the given pixel, or `None` if it is off.
+    pub fn pixel(&self, pos: Vec2) -> Option<Color> {
+        self.index(pos).and_then(|i| self.pixels[i])
+    }
+
+    /// Turns on the given pixel with a color.
+    pub fn set_pixel(&mut self, pos: Vec2, color: Color) {
+        if let Some(i) = self.index(pos) {
+            self.pixels[i] = Some(color);
+        }
+    }
+
+    /// Turns off the given pixel.
+    pub fn clear_pixel(&mut self, pos: Vec2) {
+        if let Some(i) = self.index(pos) {
+            self.pixels[i] = None;
+        }
+    }
+
+    /// Draws a line between two pixels, both included.
+    pub fn line(&mut self, from: Vec2, to: Vec2, color: Color) {
+        // Bresenham's algorithm.
+        let (mut x, mut y) = (from.x as isize, from.y as isize);
+        let (x1, y1) = (to.x as isize, to.y as isize);
+        let (dx, dy) = ((x1 - x).abs(), -(y1 - y).abs());
+        let (sx, sy) = ((x1 - x).signum(), (y1 - y).signum());
+        let mut error = dx + dy;
+
+        loop {
+            self.set_pixel(Vec2::new(x as usize, y as usize), color);
+            if (x, y) == (x1, y1) {
+                break;
+            }
+            let double = 2 * error;
+            if double >= dy {
+                error += dy;
+                x += sx;
+            }
+            if double <= dx {
+                error += dx;
+                y += sy;
+            }
+        }
+    }
+
+    /// Draws the outline of a rectangle of pixels.
+    pub fn rect(&mut self, rect: Rect, color: Color) {
+        self.line(rect.top_left(), rect.top_right(), color);
+        self.line(rect.bottom_left(), rect.bottom_right(), color);
+        self.line(rect.top_left(), rect.bottom_left(), color);
+        self.line(rect.top_right(), rect.bottom_right(), color);
+    }
+
+    /// Turns on all the pixels in a rectangle.
+    pub fn fill_rect(&mut self, rect: Rect, color: Color) {
+        for y in rect.top()..=rect.bottom() {
+            for x in rect.left()..=rect.right() {
+                self.set_pixel(Vec2::new(x, y), color);
+            }
+        }
+    }
+
+    /// Draws the outline of a circle.
+    ///
+    /// Parts of the circle outside of the buffer are not drawn.
+    pub fn circle(&mut self, center: Vec2, radius: usize, color: Color) {
+        self.for_circle(center, radius, |buffer, (cx, cy), (x, y)| {
+            for (px, py) in [(x, y), (y, x)] {
+                for (sx, sy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+                    buffer.set_signed(cx + sx * px, cy + sy * py, color);
+                }
+            }
+        });
+    }
+
+    /// Turns on all the pixels in a circle.
+    pub fn fill_circle(&mut self, center: Vec2, radius: usize, color: Color) {
+        self.for_circle(center, radius, |buffer, (cx, cy), (x, y)| {
+            for (px, py) in [(x, y), (y, x)] {
+                for dx in -px..=px {
+                    buffer.set_signed(cx + dx, cy + py, color);
+                    buffer.set_signed(cx + dx, cy - py, color);
+                }
+            }
+        });
+    }
+
+    // Runs `f` for each point of the first octant of a circle.
+    fn for_circle<F>(&mut self, center: Vec2, radius: usize, mut f: F)
+    where
+        F: FnMut(&mut Self, (isize, isize), (isize, isize)),
+    {
+        // Midpoint circle algorithm.
+        let center = (center.x as isize, center.y as isize);
+        let (mut x, mut y) = (radius as isize, 0);
+        let mut error = 1 - x;
+
+        while x >= y {
+            f(self, center, (x, y));
+            y += 1;
+            if error < 0 {
+                error += 2 * y + 1;
+            } else {
+                x -= 1;
+                error += 2 * (y - x) + 1;
+            }
+        }
+    }
+
+    // Sets a pixel from signed coordinates, ignoring negative ones.
+    fn set_signed(&mut self, x: isize, y: isize, color: Color) {
+        if x >= 0 && y >= 0 {
+            self.set_pixel(Vec2::new(x as usize, y as usize), color);
+        }
+    }
+
+    /// Prints the buffer, starting at the top-left corner of the printer.
+    ///
+    /// Cells without any lit pixel are left untouched.
+    pub fn draw(&self, printer: &Printer) {
+        let cell_size = self.mode.cell_size();
+
+        for cy in 0..self.size.y {
+            for cx in 0..self.size.x {
+                let origin = Vec2::new(cx, cy) * cell_size;
+                let pixel = |x, y| self.pixel(origin + (x, y));
+
+                match self.mode {
+                    PixelMode::Braille => self.draw_braille(printer, Vec2::new(cx, cy), pixel),
+                    PixelMode::HalfBlock => {
+                        let (text, style) = match (pixel(0, 0), pixel(0, 1)) {
+                            (None, None) => continue,
+                            (Some(top), None) => ("▀", ColorStyle::front(top)),
+                            (None, Some(bottom)) => ("▄", ColorStyle::front(bottom)),
+                            (Some(top), Some(bottom)) if top == bottom => {
+                                ("█", ColorStyle::front(top))
+                            }
+                            (Some(top), Some(bottom)) => ("▀", ColorStyle::new(top, bottom)),
+                        };
+                        printer.with_color(style, |printer| printer.print((cx, cy), text));
+                    }
+                }
+            }
+        }
+    }
+
+    fn draw_braille<F>(&self, printer: &Printer, cell: Vec2, pixel: F)
+    where
+        F: Fn(usize, usize) -> Option<Color>,
+    {
+        let mut dots = 0;
+        let mut colors: Vec<(Color, usize)> = Vec::new();
+
+        for (y, row) in BRAILLE_DOTS.iter().enumerate() {
+            for (x, dot) in row.iter().enumerate() {
+                let Some(color) = pixel(x, y) else {
+                    continue;
+                };
+                dots |= dot;
+                match colors.iter_mut().find(|(c, _)| *c == color) {
+                    Some((_, count)) => *count += 1,
+                    None => colors.push((color, 1)),
+                }
+            }
+        }
+
+        // On a tie, the first color found wins.
+        let Some(&(color, _)) = colors.iter().rev().max_by_key(|&&(_, count)| count) else {
+            return;
+        };
+
+        let text = char::from_u32(0x2800 + dots).unwrap_or(' ').to_string();
+        printer.with_color(ColorStyle::front(color), |printer| {
+            printer.print(cell, &text)
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::PrintBuffer;
+    use crate::style::BaseColor;
+    use crate::theme::Theme;
+
+    const RED: Color = Color::Dark(BaseColor::Red);
+    const BLUE: Color = Color::Dark(BaseColor::Blue);
+
+    fn render(buffer: &PixelBuffer) -> Vec<String> {
+        let size = buffer.size();
+        let mut output = PrintBuffer::new();
+        output.resize(size);
+        let output = parking_lot::RwLock::new(output);
+        buffer.draw(&Printer::new(size, &Theme::default(), &output));
+
+        let rendered = output.read().to_styled_string();
+        rendered.source().lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn braille() {
+        let mut buffer = PixelBuffer::new(PixelMode::Braille, Vec2::new(2, 1));
+        assert_eq!(buffer.pixel_size(), Vec2::new(4, 4));
+
+        buffer.line(Vec2::new(0, 0), Vec2::new(3, 3), RED);
+        assert_eq!(buffer.pixel(Vec2::new(1, 1)), Some(RED));
+        assert_eq!(buffer.pixel(Vec2::new(1, 0)), None);
+        assert_eq!(render(&buffer), ["⠑⢄"]);
+
+        // Out of bounds pixels are ignored.
+        buffer.set_pixel(Vec2::new(4, 0), RED);
+        buffer.clear();
+        buffer.fill_rect(Rect::from_corners((0, 0), (1, 3)), BLUE);
+        assert_eq!(render(&buffer), ["⣿ "]);
+    }
+
+    #[test]
+    fn half_blocks() {
+        let mut buffer = PixelBuffer::new(PixelMode::HalfBlock, Vec2::new(3, 1));
+        buffer.set_pixel(Vec2::new(0, 0), RED);
+        buffer.set_pixel(Vec2::new(1, 1), RED);
+        buffer.set_pixel(Vec2::new(2, 0), RED);
+        buffer.set_pixel(Vec2::new(2, 1), BLUE);
+        assert_eq!(render(&buffer), ["▀▄▀"]);
+    }
+
+    #[test]
+    fn circles() {
+        let mut buffer = PixelBuffer::new(PixelMode::HalfBlock, Vec2::new(5, 3));
+        buffer.circle(Vec2::new(2, 2), 2, RED);
+        for pos in [(0, 2), (4, 2), (2, 0), (2, 4)] {
+            assert_eq!(buffer.pixel(Vec2::from(pos)), Some(RED));
+        }
+        assert_eq!(buffer.pixel(Vec2::new(2, 2)), None);
+
+        buffer.fill_circle(Vec2::new(2, 2), 2, BLUE);
+        assert_eq!(buffer.pixel(Vec2::new(2, 2)), Some(BLUE));
+        assert_eq!(buffer.pixel(Vec2::new(0, 0)), None);
+    }
+}
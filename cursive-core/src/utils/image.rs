@@ -0,0 +1,407 @@
+//! Images made of RGB pixels, and their encoding for terminal graphics protocols.
+//!
+//! An [`Image`] can be shown with [`ImageView`], which picks the protocol
+//! supported by the backend, and falls back to half-block characters.
+//!
+//! [`ImageView`]: crate::views::ImageView
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cursive_core::style::Rgb;
+//! use cursive_core::utils::image::Image;
+//! use cursive_core::Vec2;
+//!
+//! // A horizontal gradient, from black to red.
+//! let image = Image::from_fn((16, 8), |pos| Rgb::new((pos.x * 16) as u8, 0, 0));
+//! assert_eq!(image.size(), Vec2::new(16, 8));
+//! ```
+use crate::backend::GraphicsProtocol;
+use crate::clipboard::base64;
+use crate::style::Rgb;
+use crate::Vec2;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+/// Size of a cell in pixels, when the backend does not know it.
+pub const DEFAULT_CELL_PIXEL_SIZE: Vec2 = Vec2::new(10, 20);
+
+// Maximum size of a single kitty graphics command payload.
+const KITTY_CHUNK_SIZE: usize = 4096;
+
+// Maximum size of a deflate stored block.
+const STORED_BLOCK_SIZE: usize = 65535;
+
+/// A rectangle of RGB pixels.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    size: Vec2,
+    pixels: Vec<Rgb<u8>>,
+}
+
+impl Image {
+    /// Creates an image from raw RGB data, with 3 bytes per pixel, row after row.
+    ///
+    /// Returns `None` if the data does not match the size.
+    pub fn from_rgb<S: Into<Vec2>>(size: S, data: &[u8]) -> Option<Self> {
+        let size = size.into();
+        if data.len() != size.x * size.y * 3 {
+            return None;
+        }
+
+        let pixels = data
+            .chunks(3)
+            .map(|rgb| Rgb::new(rgb[0], rgb[1], rgb[2]))
+            .collect();
+
+        Some(Image { size, pixels })
+    }
+
+    /// Creates an image by computing the color of each pixel.
+    pub fn from_fn<S, F>(size: S, mut f: F) -> Self
+    where
+        S: Into<Vec2>,
+        F: FnMut(Vec2) -> Rgb<u8>,
+    {
+        let size = size.into();
+        let pixels = (0..size.y)
+            .flat_map(|y| (0..size.x).map(move |x| Vec2::new(x, y)))
+            .map(&mut f)
+            .collect();
+
+        Image { size, pixels }
+    }
+
+    /// Returns the size of this image, in pixels.
+    pub fn size(&self) -> Vec2 {
+        self.size
+    }
+
+    /// Returns `true` if this image has no pixel.
+    pub fn is_empty(&self) -> bool {
+        self.pixels.is_empty()
+    }
+
+    /// Returns the color of the pixel at the given position.
+    pub fn pixel<P: Into<Vec2>>(&self, pos: P) -> Option<Rgb<u8>> {
+        let pos = pos.into();
+        pos.strictly_lt(self.size)
+            .then(|| self.pixels[pos.x + pos.y * self.size.x])
+    }
+
+    /// Returns a copy of this image scaled to the given size.
+    ///
+    /// Uses the nearest pixel, without any smoothing.
+    #[must_use]
+    pub fn resized<S: Into<Vec2>>(&self, size: S) -> Self {
+        let size = size.into();
+        if self.is_empty() {
+            return Image::from_fn(size, |_| Rgb::new(0, 0, 0));
+        }
+
+        Image::from_fn(size, |pos| {
+            let x = pos.x * self.size.x / size.x;
+            let y = pos.y * self.size.y / size.y;
+            self.pixels[x + y * self.size.x]
+        })
+    }
+
+    /// Returns the sequence drawing this image at the cursor with the given protocol.
+    ///
+    /// The image is stretched to cover `cells`. `cell_pixel_size` is used to
+    /// scale the image for sixel, and defaults to [`DEFAULT_CELL_PIXEL_SIZE`].
+    ///
+    /// Returns an empty string for an empty image.
+    pub fn encode(
+        &self,
+        protocol: GraphicsProtocol,
+        cells: Vec2,
+        cell_pixel_size: Option<Vec2>,
+    ) -> String {
+        if self.is_empty() || !Vec2::zero().strictly_lt(cells) {
+            return String::new();
+        }
+
+        match protocol {
+            GraphicsProtocol::Sixel => {
+                let pixels = cells * cell_pixel_size.unwrap_or(DEFAULT_CELL_PIXEL_SIZE);
+                self.resized(pixels).to_sixel()
+            }
+            GraphicsProtocol::Kitty => self.to_kitty(cells),
+            GraphicsProtocol::Iterm2 => self.to_iterm2(cells),
+        }
+    }
+
+    /// Encodes this image in the sixel format, at its current size.
+    ///
+    /// Colors are reduced to 216 levels.
+    pub fn to_sixel(&self) -> String {
+        // Index of the closest color in a 6x6x6 cube.
+        let level = |value: u8| (usize::from(value) * 5 + 127) / 255;
+        let colors: Vec<usize> = self
+            .pixels
+            .iter()
+            .map(|rgb| level(rgb.r) * 36 + level(rgb.g) * 6 + level(rgb.b))
+            .collect();
+
+        // Pixels left at 0 are transparent.
+        let mut result = format!("\x1bP0;1q\"1;1;{};{}", self.size.x, self.size.y);
+
+        let used: BTreeSet<usize> = colors.iter().copied().collect();
+        for &color in &used {
+            let percent = |level: usize| level * 20;
+            write!(
+                result,
+                "#{color};2;{};{};{}",
+                percent(color / 36),
+                percent(color / 6 % 6),
+                percent(color % 6)
+            )
+            .unwrap();
+        }
+
+        for top in (0..self.size.y).step_by(6) {
+            let rows = top..(top + 6).min(self.size.y);
+            let band: BTreeSet<usize> = rows
+                .clone()
+                .flat_map(|y| &colors[y * self.size.x..(y + 1) * self.size.x])
+                .copied()
+                .collect();
+
+            for (i, &color) in band.iter().enumerate() {
+                if i > 0 {
+                    // Back to the start of the band for the next color.
+                    result.push('$');
+                }
+                write!(result, "#{color}").unwrap();
+
+                let sixels = (0..self.size.x).map(|x| {
+                    let bits = rows
+                        .clone()
+                        .filter(|&y| colors[x + y * self.size.x] == color)
+                        .fold(0, |bits, y| bits | 1 << (y - top));
+                    char::from(63 + bits)
+                });
+                push_run_length(&mut result, sixels);
+            }
+
+            result.push('-');
+        }
+
+        result.push_str("\x1b\\");
+        result
+    }
+
+    /// Encodes this image for the kitty graphics protocol, covering `cells`.
+    ///
+    /// The cursor is not moved.
+    pub fn to_kitty(&self, cells: Vec2) -> String {
+        let data: Vec<u8> = self
+            .pixels
+            .iter()
+            .flat_map(|rgb| [rgb.r, rgb.g, rgb.b])
+            .collect();
+        let payload = base64(&data);
+
+        let mut result = String::new();
+        let chunks: Vec<&[u8]> = payload.as_bytes().chunks(KITTY_CHUNK_SIZE).collect();
+        for (i, chunk) in chunks.iter().enumerate() {
+            let more = usize::from(i + 1 < chunks.len());
+            // Base64 only uses ASCII characters.
+            let chunk = std::str::from_utf8(chunk).unwrap();
+            if i == 0 {
+                write!(
+                    result,
+                    "\x1b_Ga=T,f=24,s={},v={},c={},r={},C=1,q=2,m={more};{chunk}\x1b\\",
+                    self.size.x, self.size.y, cells.x, cells.y
+                )
+                .unwrap();
+            } else {
+                write!(result, "\x1b_Gm={more};{chunk}\x1b\\").unwrap();
+            }
+        }
+
+        result
+    }
+
+    /// Encodes this image as an iTerm2 inline image, covering `cells`.
+    pub fn to_iterm2(&self, cells: Vec2) -> String {
+        let png = self.to_png();
+        format!(
+            "\x1b]1337;File=inline=1;size={};width={};height={};preserveAspectRatio=0:{}\x07",
+            png.len(),
+            cells.x,
+            cells.y,
+            base64(&png)
+        )
+    }
+
+    /// Encodes this image as an uncompressed PNG file.
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+
+        let mut header = Vec::with_capacity(13);
+        header.extend_from_slice(&(self.size.x as u32).to_be_bytes());
+        header.extend_from_slice(&(self.size.y as u32).to_be_bytes());
+        // 8 bits per channel, RGB, default compression, filter and no interlacing.
+        header.extend_from_slice(&[8, 2, 0, 0, 0]);
+        push_png_chunk(&mut png, b"IHDR", &header);
+
+        // Each row starts with its filter type: none.
+        let mut raw = Vec::with_capacity(self.size.y * (1 + 3 * self.size.x));
+        for row in self.pixels.chunks(self.size.x.max(1)) {
+            raw.push(0);
+            raw.extend(row.iter().flat_map(|rgb| [rgb.r, rgb.g, rgb.b]));
+        }
+        push_png_chunk(&mut png, b"IDAT", &zlib_stored(&raw));
+        push_png_chunk(&mut png, b"IEND", &[]);
+
+        png
+    }
+}
+
+// Appends sixel characters, compressing runs of the same character.
+fn push_run_length(result: &mut String, sixels: impl Iterator<Item = char>) {
+    let flush = |result: &mut String, c: char, n: usize| {
+        if n > 3 {
+            write!(result, "!{n}{c}").unwrap();
+        } else {
+            result.extend(std::iter::repeat(c).take(n));
+        }
+    };
+
+    let mut run: Option<(char, usize)> = None;
+    for c in sixels {
+        run = match run {
+            Some((previous, n)) if previous == c => Some((c, n + 1)),
+            Some((previous, n)) => {
+                flush(result, previous, n);
+                Some((c, 1))
+            }
+            None => Some((c, 1)),
+        };
+    }
+
+    if let Some((c, n)) = run {
+        flush(result, c, n);
+    }
+}
+
+fn push_png_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+// Wraps the data in a zlib stream, without compression.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut result = vec![0x78, 0x01];
+
+    let blocks: Vec<&[u8]> = data.chunks(STORED_BLOCK_SIZE).collect();
+    if blocks.is_empty() {
+        result.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    for (i, block) in blocks.iter().enumerate() {
+        let last = u8::from(i + 1 == blocks.len());
+        let len = block.len() as u16;
+        result.push(last);
+        result.extend_from_slice(&len.to_le_bytes());
+        result.extend_from_slice(&(!len).to_le_bytes());
+        result.extend_from_slice(block);
+    }
+
+    result.extend_from_slice(&adler32(data).to_be_bytes());
+    result
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + u32::from(byte)) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_rgb() {
+        assert_eq!(Image::from_rgb((2, 1), &[0, 0, 0]), None);
+
+        let image = Image::from_rgb((2, 1), &[1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(image.pixel((1, 0)), Some(Rgb::new(4, 5, 6)));
+        assert_eq!(image.pixel((0, 1)), None);
+
+        let resized = image.resized((4, 2));
+        assert_eq!(resized.pixel((1, 1)), Some(Rgb::new(1, 2, 3)));
+        assert_eq!(resized.pixel((2, 0)), Some(Rgb::new(4, 5, 6)));
+    }
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn png() {
+        let image = Image::from_fn((1, 1), |_| Rgb::new(255, 0, 0));
+        let png = image.to_png();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\x01\0\0\0\x01"));
+        assert!(png.ends_with(b"\0\0\0\0IEND\xae\x42\x60\x82"));
+
+        let iterm2 = image.to_iterm2(Vec2::new(2, 1));
+        assert!(iterm2.starts_with(&format!(
+            "\x1b]1337;File=inline=1;size={};width=2;height=1;",
+            png.len()
+        )));
+        assert!(iterm2.ends_with('\x07'));
+    }
+
+    #[test]
+    fn sixel() {
+        // A red pixel above a blue one.
+        let image = Image::from_rgb((1, 2), &[255, 0, 0, 0, 0, 255]).unwrap();
+        assert_eq!(
+            image.to_sixel(),
+            "\x1bP0;1q\"1;1;1;2#5;2;0;0;100#180;2;100;0;0#5A$#180@-\x1b\\"
+        );
+
+        let mut runs = String::new();
+        push_run_length(&mut runs, "aaaaabbc".chars());
+        assert_eq!(runs, "!5abbc");
+    }
+
+    #[test]
+    fn kitty() {
+        let image = Image::from_fn((64, 64), |_| Rgb::new(0, 0, 0));
+        let kitty = image.to_kitty(Vec2::new(8, 4));
+
+        // 64*64*3 bytes take 4 chunks of base64.
+        assert!(kitty.starts_with("\x1b_Ga=T,f=24,s=64,v=64,c=8,r=4,C=1,q=2,m=1;AAAA"));
+        assert_eq!(kitty.matches("\x1b_G").count(), 4);
+        assert!(kitty.contains("\x1b_Gm=0;"));
+        assert_eq!(
+            image.encode(GraphicsProtocol::Kitty, Vec2::zero(), None),
+            ""
+        );
+    }
+}
@@ -0,0 +1,191 @@
+//! Expand tab characters into spaces.
+//!
+//! Terminals don't have a reliable way to print a tab character, so text
+//! containing tabs needs to be converted before being printed.
+//!
+//! Two strategies are available:
+//!
+//! * Fixed tab stops, where each tab advances to the next multiple of a
+//!   given width.
+//! * Elastic tab stops, where tab-separated cells are aligned across
+//!   consecutive lines, forming columns.
+use crate::utils::markup::StyledString;
+
+use unicode_width::UnicodeWidthStr;
+
+/// Describes how tab characters are rendered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TabStops {
+    /// Tabs advance to the next multiple of the given width.
+    ///
+    /// A width of `0` removes tabs entirely.
+    Fixed(usize),
+
+    /// Tab-separated cells are aligned across consecutive lines.
+    ///
+    /// Columns are as wide as their largest cell, plus the given padding.
+    ///
+    /// A cell only aligns with the cells right above and below it, so a line
+    /// with fewer columns ends the alignment for the columns it does not have.
+    Elastic(usize),
+}
+
+impl Default for TabStops {
+    fn default() -> Self {
+        TabStops::Fixed(8)
+    }
+}
+
+/// Returns a copy of `text` where each tab is replaced by spaces.
+///
+/// Styles are preserved; the spaces from a tab use the style of the tab itself.
+///
+/// # Examples
+///
+/// ```rust
+/// # use cursive_core::utils::lines::tabs::{expand_tabs, TabStops};
+/// # use cursive_core::utils::markup::StyledString;
+/// let text = StyledString::plain("a\tb\nccccc\td");
+///
+/// let fixed = expand_tabs(&text, TabStops::Fixed(4));
+/// assert_eq!(fixed.source(), "a   b\nccccc   d");
+///
+/// let elastic = expand_tabs(&text, TabStops::Elastic(1));
+/// assert_eq!(elastic.source(), "a     b\nccccc d");
+/// ```
+pub fn expand_tabs(text: &StyledString, tab_stops: TabStops) -> StyledString {
+    let expander = match tab_stops {
+        TabStops::Fixed(width) => Expander::Fixed(width),
+        TabStops::Elastic(padding) => Expander::Elastic(elastic_widths(text, padding)),
+    };
+
+    let mut result = StyledString::new();
+
+    let mut line = 0;
+    let mut cell = 0;
+    let mut column = 0;
+
+    for span in text.spans() {
+        let mut content = String::with_capacity(span.content.len());
+
+        for (i, piece) in span.content.split('\t').enumerate() {
+            if i > 0 {
+                let target = expander.tab_end(line, cell, column);
+                content.extend(std::iter::repeat(' ').take(target - column));
+                column = target;
+                cell += 1;
+            }
+
+            for (j, piece) in piece.split('\n').enumerate() {
+                if j > 0 {
+                    content.push('\n');
+                    line += 1;
+                    cell = 0;
+                    column = 0;
+                }
+                content.push_str(piece);
+                column += piece.width();
+            }
+        }
+
+        result.append_styled(content, *span.attr);
+    }
+
+    result
+}
+
+enum Expander {
+    Fixed(usize),
+
+    // For each line, the column where each cell ends.
+    Elastic(Vec<Vec<usize>>),
+}
+
+impl Expander {
+    // Returns the column reached by a tab.
+    fn tab_end(&self, line: usize, cell: usize, column: usize) -> usize {
+        match self {
+            Expander::Fixed(0) => column,
+            Expander::Fixed(width) => (column / *width + 1) * *width,
+            Expander::Elastic(ends) => ends[line][cell].max(column),
+        }
+    }
+}
+
+// Computes where each cell should end, for each line.
+fn elastic_widths(text: &StyledString, padding: usize) -> Vec<Vec<usize>> {
+    let content: String = text.spans().map(|span| span.content).collect();
+
+    // Width of each tab-terminated cell, for each line.
+    let cells: Vec<Vec<usize>> = content
+        .split('\n')
+        .map(|line| {
+            let mut cells: Vec<usize> = line.split('\t').map(|cell| cell.width()).collect();
+            // The last cell is not followed by a tab.
+            cells.pop();
+            cells
+        })
+        .collect();
+
+    let mut ends: Vec<Vec<usize>> = cells.iter().map(|cells| vec![0; cells.len()]).collect();
+
+    let n_columns = cells.iter().map(Vec::len).max().unwrap_or(0);
+    for column in 0..n_columns {
+        let mut start = 0;
+        while start < cells.len() {
+            if cells[start].len() <= column {
+                start += 1;
+                continue;
+            }
+
+            // Find the block of consecutive lines having this column.
+            let end = (start..cells.len())
+                .find(|&i| cells[i].len() <= column)
+                .unwrap_or(cells.len());
+
+            let width = (start..end).map(|i| cells[i][column]).max().unwrap_or(0) + padding;
+
+            for line_ends in &mut ends[start..end] {
+                let offset = column
+                    .checked_sub(1)
+                    .map_or(0, |previous| line_ends[previous]);
+                line_ends[column] = offset + width;
+            }
+
+            start = end;
+        }
+    }
+
+    ends
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{expand_tabs, TabStops};
+    use crate::style::Effect;
+    use crate::utils::markup::StyledString;
+
+    #[test]
+    fn fixed_tabs_keep_styles() {
+        let mut text = StyledString::plain("ab");
+        text.append_styled("\tc", Effect::Bold);
+
+        let expanded = expand_tabs(&text, TabStops::Fixed(4));
+        assert_eq!(expanded.source(), "ab  c");
+        assert_eq!(expanded.spans().nth(1).unwrap().content, "  c");
+    }
+
+    #[test]
+    fn elastic_blocks() {
+        let text = StyledString::plain("a\tbb\tc\naaa\tb\tc\nx\naaaaa\tb");
+
+        let expanded = expand_tabs(&text, TabStops::Elastic(2));
+        assert_eq!(expanded.source(), "a    bb  c\naaa  b   c\nx\naaaaa  b");
+    }
+
+    #[test]
+    fn no_tabs() {
+        let text = StyledString::plain("abc\ndef");
+        assert_eq!(expand_tabs(&text, TabStops::Elastic(1)), text);
+    }
+}
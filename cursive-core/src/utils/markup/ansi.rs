@@ -1,6 +1,19 @@
 //! Parse text with ANSI color codes.
 //!
 //! Needs the `ansi` feature to be enabled.
+//!
+//! This turns the output of programs using SGR escape codes (colors and
+//! effects) into a [`StyledString`] that can be displayed in a `TextView`.
+//!
+//! Supported codes include:
+//! * The 16 base colors, for both foreground and background.
+//! * 256-colors (`38;5;n`) and true colors (`38;2;r;g;b`), including the
+//!   colon-separated forms (`38:5:n`, `38:2::r:g:b`).
+//! * Bold, dim, italic, underline, blink, reverse and strikethrough, and their resets.
+//! * Full resets (`0` or an empty sequence).
+//! * Cursor-forward (`CSI n C`), rendered as `n` spaces (at most 1024).
+//!
+//! Any other escape sequence (cursor movement, erase, window titles, ...) is skipped.
 #![cfg(feature = "ansi")]
 #![cfg_attr(feature = "doc-cfg", doc(cfg(feature = "ansi")))]
 
@@ -8,10 +21,24 @@ use crate::style::{BaseColor, Color, Effect, Style};
 use crate::utils::markup::{StyledIndexedSpan, StyledString};
 use crate::utils::span::IndexedCow;
 
-use ansi_parser::AnsiParser;
 use unicode_width::UnicodeWidthStr;
 
 /// Parses the given text with ANSI codes.
+///
+/// # Examples
+///
+/// ```rust
+/// # use cursive_core::utils::markup::ansi;
+/// # use cursive_core::style::{Effect, EffectStatus};
+/// let styled = ansi::parse("\x1b[1;31mError:\x1b[0m file not found");
+///
+/// assert_eq!(styled.source(), "\x1b[1;31mError:\x1b[0m file not found");
+///
+/// let spans: Vec<_> = styled.spans().collect();
+/// assert_eq!(spans[0].content, "Error:");
+/// assert_eq!(spans[0].attr.effects.statuses[Effect::Bold], EffectStatus::OppositeParent);
+/// assert_eq!(spans[1].content, " file not found");
+/// ```
 pub fn parse<S>(input: S) -> StyledString
 where
     S: Into<String>,
@@ -28,6 +55,20 @@ where
 /// Useful if you need to parse something in the middle of a large text.
 ///
 /// Returns the parsed string, and the ending style.
+///
+/// This can be used to parse a stream of text piece by piece (for example
+/// line by line), giving each call the style returned by the previous one.
+///
+/// # Examples
+///
+/// ```rust
+/// # use cursive_core::utils::markup::ansi;
+/// # use cursive_core::style::Style;
+/// let (first, style) = ansi::parse_with_starting_style(Style::default(), "\x1b[32mgreen");
+/// let (second, _) = ansi::parse_with_starting_style(style, " still green\x1b[m");
+///
+/// assert_eq!(first.spans().next().unwrap().attr, second.spans().next().unwrap().attr);
+/// ```
 pub fn parse_with_starting_style<S>(current_style: Style, input: S) -> (StyledString, Style)
 where
     S: Into<String>,
@@ -45,25 +86,147 @@ where
 pub struct Parser<'a> {
     input: &'a str,
     current_style: Style,
-    parser: ansi_parser::AnsiParseIterator<'a>,
+
+    // Byte offset of the next thing to parse in `input`.
+    position: usize,
+
+    // If `true`, stop before an incomplete escape sequence instead of dropping it.
+    stop_at_incomplete: bool,
 }
 
-fn parse_color(mut bytes: impl Iterator<Item = u8>) -> Option<Color> {
-    Some(match bytes.next()? {
-        5 => {
-            let color = bytes.next()?;
-            Color::from_256colors(color)
+/// A single escape sequence found in the input.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum Escape<'a> {
+    /// Select Graphic Rendition: changes the current style.
+    Sgr(&'a str),
+
+    /// Moves the cursor forward by this many cells.
+    CursorForward(usize),
+
+    /// Any other sequence, ignored.
+    Other,
+}
+
+/// Result of scanning an escape sequence.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum Scan<'a> {
+    /// A complete sequence, of the given length in bytes.
+    Complete(usize, Escape<'a>),
+
+    /// The input ends before the end of the sequence.
+    Incomplete,
+}
+
+/// Scans the escape sequence at the start of `input`.
+///
+/// `input` must start with the ESC character.
+pub(crate) fn scan_escape(input: &str) -> Scan<'_> {
+    let bytes = input.as_bytes();
+    debug_assert_eq!(bytes.first(), Some(&ESC));
+
+    let Some(&kind) = bytes.get(1) else {
+        return Scan::Incomplete;
+    };
+
+    match kind {
+        // Control Sequence Introducer
+        b'[' => {
+            // Parameter bytes, then intermediate bytes, then a final byte.
+            let params_end = 2 + count_bytes(&bytes[2..], 0x30..=0x3F);
+            let intermediates_end = params_end + count_bytes(&bytes[params_end..], 0x20..=0x2F);
+
+            let Some(&final_byte) = bytes.get(intermediates_end) else {
+                return Scan::Incomplete;
+            };
+
+            if !(0x40..=0x7E).contains(&final_byte) {
+                // Malformed sequence: drop what we have so far.
+                return Scan::Complete(intermediates_end, Escape::Other);
+            }
+
+            let params = &input[2..params_end];
+            let escape = match (final_byte, intermediates_end == params_end) {
+                (b'm', true) => Escape::Sgr(params),
+                (b'C', true) => {
+                    Escape::CursorForward(params.parse().unwrap_or(1).clamp(1, MAX_CURSOR_FORWARD))
+                }
+                _ => Escape::Other,
+            };
+
+            Scan::Complete(intermediates_end + 1, escape)
         }
-        2 => {
-            let r = bytes.next()?;
-            let g = bytes.next()?;
-            let b = bytes.next()?;
-            Color::Rgb(r, g, b)
+        // Strings (OSC, DCS, ...), terminated by BEL or ST (`ESC \`).
+        b']' | b'P' | b'X' | b'^' | b'_' => {
+            let mut i = 2;
+            while i < bytes.len() {
+                match bytes[i] {
+                    BEL => return Scan::Complete(i + 1, Escape::Other),
+                    ESC => {
+                        return match bytes.get(i + 1) {
+                            None => Scan::Incomplete,
+                            Some(b'\\') => Scan::Complete(i + 2, Escape::Other),
+                            // Unterminated string, another sequence starts here.
+                            Some(_) => Scan::Complete(i, Escape::Other),
+                        };
+                    }
+                    _ => i += 1,
+                }
+            }
+            Scan::Incomplete
         }
+        // Other escape sequences: optional intermediate bytes, then a final byte.
         _ => {
-            // ???
-            return None;
+            let end = 1 + count_bytes(&bytes[1..], 0x20..=0x2F);
+            if end < bytes.len() {
+                Scan::Complete(end + 1, Escape::Other)
+            } else {
+                Scan::Incomplete
+            }
+        }
+    }
+}
+
+const ESC: u8 = 0x1B;
+const BEL: u8 = 0x07;
+
+// Incomplete escape sequences longer than this are truncated to their introducer.
+const MAX_PENDING: usize = 4096;
+
+// Cursor-forward sequences move by at most this many cells.
+//
+// The input may not be trusted: this keeps `CSI 999999999 C` from allocating gigabytes.
+const MAX_CURSOR_FORWARD: usize = 1024;
+
+// Counts the leading bytes within the given range.
+fn count_bytes(bytes: &[u8], range: std::ops::RangeInclusive<u8>) -> usize {
+    bytes.iter().take_while(|b| range.contains(b)).count()
+}
+
+// Parses a single (sub-)parameter. Empty parameters default to 0.
+fn parse_param(param: &str) -> Option<u16> {
+    if param.is_empty() {
+        Some(0)
+    } else {
+        param.parse().ok()
+    }
+}
+
+// Parses the color following a 38, 48 or 58 code.
+//
+// `params` yields the parameters following the code, for example
+// `["5", "196"]` or `["2", "255", "0", "0"]`.
+fn parse_color<'a>(mut params: impl Iterator<Item = &'a str>) -> Option<Color> {
+    let mut next = || params.next().and_then(parse_param);
+
+    Some(match next()? {
+        5 => Color::from_256colors(u8::try_from(next()?).ok()?),
+        2 => {
+            let r = u8::try_from(next()?).ok()?;
+            let g = u8::try_from(next()?).ok()?;
+            let b = u8::try_from(next()?).ok()?;
+            Color::Rgb(r, g, b)
         }
+        _ => return None,
     })
 }
 
@@ -86,93 +249,128 @@ impl<'a> Parser<'a> {
         Parser {
             input,
             current_style,
-            parser: input.ansi_parse(),
+            position: 0,
+            stop_at_incomplete: false,
         }
     }
+}
 
-    fn parse_sequence(&mut self, seq: &[u8]) -> Option<()> {
-        let mut bytes = seq.iter().copied();
-        loop {
-            let byte = bytes.next()?;
+/// Applies the given SGR parameters to `style`.
+pub(crate) fn apply_sgr(style: &mut Style, params: &str) {
+    // An empty sequence (`ESC[m`) is a reset.
+    if params.is_empty() {
+        *style = Style::default();
+        return;
+    }
 
-            match byte {
-                0 => self.current_style = Style::default(),
-                1 => {
-                    self.current_style.effects.insert(Effect::Bold);
-                    self.current_style.effects.remove(Effect::Dim);
-                }
-                2 => {
-                    self.current_style.effects.insert(Effect::Dim);
-                    self.current_style.effects.remove(Effect::Bold);
-                }
-                22 => {
-                    self.current_style.effects.remove(Effect::Dim);
-                    self.current_style.effects.remove(Effect::Bold);
-                }
-                3 => {
-                    self.current_style.effects.insert(Effect::Italic);
-                }
-                23 => {
-                    self.current_style.effects.remove(Effect::Italic);
-                }
-                4 => {
-                    self.current_style.effects.insert(Effect::Underline);
-                }
-                24 => {
-                    self.current_style.effects.remove(Effect::Underline);
-                }
-                5 | 6 => {
-                    // Technically 6 is rapid blink...
-                    self.current_style.effects.insert(Effect::Blink);
-                }
-                25 => {
-                    // Technically 6 is rapid blink...
-                    self.current_style.effects.remove(Effect::Blink);
-                }
-                7 => {
-                    self.current_style.effects.insert(Effect::Reverse);
-                }
-                27 => {
-                    self.current_style.effects.remove(Effect::Reverse);
-                }
-                9 => {
-                    self.current_style.effects.insert(Effect::Strikethrough);
-                }
-                29 => {
-                    self.current_style.effects.remove(Effect::Strikethrough);
-                }
-                30..=37 => {
-                    self.current_style.color.front = BaseColor::from(byte - 30).dark().into();
-                }
-                38 => {
-                    self.current_style.color.front = parse_color(&mut bytes)?.into();
-                }
-                39 => {
-                    self.current_style.color.front = Color::TerminalDefault.into();
-                }
-                40..=47 => {
-                    self.current_style.color.back = BaseColor::from(byte - 40).dark().into();
-                }
-                48 => {
-                    self.current_style.color.back = parse_color(&mut bytes)?.into();
-                }
-                49 => {
-                    self.current_style.color.back = Color::TerminalDefault.into();
-                }
-                58 => {
-                    // Set underline color.
-                    // Not implemented, but consumes the rest
-                    parse_color(&mut bytes)?;
-                }
-                90..=97 => {
-                    self.current_style.color.front = BaseColor::from(byte - 90).light().into();
-                }
-                100..=107 => {
-                    self.current_style.color.back = BaseColor::from(byte - 100).light().into();
+    let mut params = params.split(';');
+    while let Some(param) = params.next() {
+        if param.contains(':') {
+            // Sub-parameters only apply to this code.
+            let mut sub_params = param.split(':');
+            let code = sub_params.next().and_then(parse_param);
+
+            match code {
+                // Colors: `38:2::r:g:b` has an (ignored) empty color space ID.
+                Some(code @ (38 | 48 | 58)) => {
+                    let sub_params: Vec<&str> = sub_params.collect();
+                    let sub_params = match sub_params.as_slice() {
+                        ["2", "", rgb @ ..] => {
+                            std::iter::once("2").chain(rgb.iter().copied()).collect()
+                        }
+                        _ => sub_params,
+                    };
+                    if let Some(color) = parse_color(sub_params.into_iter()) {
+                        set_color(style, code, color);
+                    }
                 }
-                _ => (),
+                // Underline styles: `4:0` removes it, dotted and dashed become plain.
+                Some(4) => match sub_params.next().and_then(parse_param) {
+                    Some(0) => set_underline(style, None),
+                    Some(2) => set_underline(style, Some(Effect::DoubleUnderline)),
+                    Some(3) => set_underline(style, Some(Effect::CurlyUnderline)),
+                    _ => set_underline(style, Some(Effect::Underline)),
+                },
+                Some(code) => apply_code(style, code, &mut std::iter::empty()),
+                None => (),
+            }
+        } else if let Some(code) = parse_param(param) {
+            apply_code(style, code, &mut params);
+        }
+    }
+}
+
+fn set_color(style: &mut Style, code: u16, color: Color) {
+    match code {
+        38 => style.color.front = color.into(),
+        48 => style.color.back = color.into(),
+        _ => style.underline = color.into(),
+    }
+}
+
+// Enables a single underline style, or none.
+fn set_underline(style: &mut Style, underline: Option<Effect>) {
+    for effect in [
+        Effect::Underline,
+        Effect::CurlyUnderline,
+        Effect::DoubleUnderline,
+    ] {
+        if Some(effect) == underline {
+            style.effects.insert(effect);
+        } else {
+            style.effects.remove(effect);
+        }
+    }
+}
+
+// Applies a single SGR code.
+//
+// `params` gives the following parameters, for codes that consume some.
+fn apply_code<'a>(style: &mut Style, code: u16, params: &mut impl Iterator<Item = &'a str>) {
+    let effects = &mut style.effects;
+
+    match code {
+        0 => *style = Style::default(),
+        1 => {
+            effects.insert(Effect::Bold);
+            effects.remove(Effect::Dim);
+        }
+        2 => {
+            effects.insert(Effect::Dim);
+            effects.remove(Effect::Bold);
+        }
+        22 => {
+            effects.remove(Effect::Dim);
+            effects.remove(Effect::Bold);
+        }
+        3 => effects.insert(Effect::Italic),
+        23 => effects.remove(Effect::Italic),
+        4 => set_underline(style, Some(Effect::Underline)),
+        21 => set_underline(style, Some(Effect::DoubleUnderline)),
+        24 => set_underline(style, None),
+        // Technically 6 is rapid blink...
+        5 | 6 => effects.insert(Effect::Blink),
+        25 => effects.remove(Effect::Blink),
+        7 => effects.insert(Effect::Reverse),
+        27 => effects.remove(Effect::Reverse),
+        9 => effects.insert(Effect::Strikethrough),
+        29 => effects.remove(Effect::Strikethrough),
+        53 => effects.insert(Effect::Overline),
+        55 => effects.remove(Effect::Overline),
+        59 => style.underline = Color::TerminalDefault.into(),
+        30..=37 => style.color.front = BaseColor::from((code - 30) as u8).dark().into(),
+        39 => style.color.front = Color::TerminalDefault.into(),
+        40..=47 => style.color.back = BaseColor::from((code - 40) as u8).dark().into(),
+        49 => style.color.back = Color::TerminalDefault.into(),
+        90..=97 => style.color.front = BaseColor::from((code - 90) as u8).light().into(),
+        100..=107 => style.color.back = BaseColor::from((code - 100) as u8).light().into(),
+        38 | 48 | 58 => {
+            // Even if the color is invalid, its parameters belong to it.
+            if let Some(color) = parse_color(params) {
+                set_color(style, code, color);
             }
         }
+        _ => (),
     }
 }
 
@@ -181,23 +379,381 @@ impl<'a> Iterator for Parser<'a> {
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
-            let next = self.parser.next()?;
-            match next {
-                ansi_parser::Output::TextBlock(text) => {
-                    let width = text.width();
+            let rest = &self.input[self.position..];
+            if rest.is_empty() {
+                return None;
+            }
+
+            if !rest.starts_with('\x1b') {
+                let len = rest.find('\x1b').unwrap_or(rest.len());
+                let text = &rest[..len];
+                self.position += len;
+
+                return Some(StyledIndexedSpan {
+                    content: IndexedCow::from_str(text, self.input),
+                    attr: self.current_style,
+                    width: text.width(),
+                });
+            }
+
+            let (len, escape) = match scan_escape(rest) {
+                Scan::Complete(len, escape) => (len, escape),
+                // Nothing more will come, drop the partial sequence.
+                Scan::Incomplete if self.stop_at_incomplete => return None,
+                Scan::Incomplete => (rest.len(), Escape::Other),
+            };
+            self.position += len;
+
+            match escape {
+                Escape::Sgr(params) => apply_sgr(&mut self.current_style, params),
+                Escape::CursorForward(n) => {
                     return Some(StyledIndexedSpan {
-                        content: IndexedCow::from_str(text, self.input),
+                        content: IndexedCow::Owned(" ".repeat(n)),
                         attr: self.current_style,
-                        width,
+                        width: n,
                     });
                 }
-                ansi_parser::Output::Escape(sequence) => {
-                    if let ansi_parser::AnsiSequence::SetGraphicsMode(bytes) = sequence {
-                        self.parse_sequence(&bytes);
+                Escape::Other => (),
+            }
+        }
+    }
+}
+
+/// Decodes text with ANSI codes, received in chunks.
+///
+/// Chunks can be cut anywhere, even in the middle of an escape sequence or
+/// of a UTF-8 character: anything incomplete is kept until the next chunk.
+/// Only the start of escape sequences longer than 4096 bytes is kept.
+///
+/// This is useful to follow the output of a running program: each decoded
+/// piece can be given to [`TextContent::append`].
+///
+/// [`TextContent::append`]: crate::views::TextContent::append
+///
+/// # Examples
+///
+/// ```rust
+/// # use cursive_core::utils::markup::ansi::Decoder;
+/// let mut decoder = Decoder::new();
+///
+/// // Escape sequences can be split across chunks.
+/// let mut text = decoder.feed("\x1b[3");
+/// text.append(decoder.feed("1mred\x1b[0m\n"));
+///
+/// let spans: Vec<_> = text.spans().collect();
+/// assert_eq!(spans[0].content, "red");
+/// assert_eq!(spans[1].content, "\n");
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Decoder {
+    current_style: Style,
+
+    // Beginning of an escape sequence or of a UTF-8 character.
+    pending: Vec<u8>,
+}
+
+impl Decoder {
+    /// Creates a new decoder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new decoder, using the given initial style.
+    pub fn with_starting_style(current_style: Style) -> Self {
+        Decoder {
+            current_style,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Returns the current style.
+    ///
+    /// This is the style that will be applied to the next text.
+    pub fn current_style(&self) -> Style {
+        self.current_style
+    }
+
+    /// Decodes the next chunk of text.
+    ///
+    /// Returns the text decoded so far.
+    pub fn feed(&mut self, chunk: &str) -> StyledString {
+        self.feed_bytes(chunk.as_bytes())
+    }
+
+    /// Decodes the next chunk of raw bytes.
+    ///
+    /// Invalid UTF-8 sequences are replaced with `U+FFFD`.
+    ///
+    /// Returns the text decoded so far.
+    pub fn feed_bytes(&mut self, chunk: &[u8]) -> StyledString {
+        self.pending.extend_from_slice(chunk);
+
+        let input = take_utf8(&mut self.pending, false);
+        self.decode(input, true)
+    }
+
+    /// Decodes anything left from the previous chunks.
+    ///
+    /// Incomplete escape sequences are dropped, and incomplete UTF-8
+    /// characters are replaced with `U+FFFD`.
+    pub fn finish(&mut self) -> StyledString {
+        let input = take_utf8(&mut self.pending, true);
+        self.decode(input, false)
+    }
+
+    fn decode(&mut self, mut input: String, stop_at_incomplete: bool) -> StyledString {
+        let mut parser = Parser::with_starting_style(self.current_style, &input);
+        parser.stop_at_incomplete = stop_at_incomplete;
+
+        let spans = (&mut parser).collect();
+        let position = parser.position;
+        self.current_style = parser.current_style;
+
+        // Keep the incomplete sequence for later, before any leftover bytes.
+        let mut pending = input.split_off(position).into_bytes();
+        if pending.len() > MAX_PENDING {
+            // Don't let it grow forever: the rest of the sequence is ignored anyway,
+            // and keeping its start lets us skip it until it ends.
+            pending.truncate(2);
+        }
+        pending.append(&mut self.pending);
+        self.pending = pending;
+
+        StyledString::with_spans(input, spans)
+    }
+}
+
+// Takes the valid UTF-8 text from the beginning of `bytes`.
+//
+// Invalid bytes are replaced with `U+FFFD`. An incomplete character at the
+// end is left in `bytes`, unless `flush` is `true`.
+fn take_utf8(bytes: &mut Vec<u8>, flush: bool) -> String {
+    let mut result = String::new();
+    let mut start = 0;
+
+    loop {
+        match std::str::from_utf8(&bytes[start..]) {
+            Ok(text) => {
+                result.push_str(text);
+                start = bytes.len();
+                break;
+            }
+            Err(error) => {
+                let valid = start + error.valid_up_to();
+                result.push_str(std::str::from_utf8(&bytes[start..valid]).unwrap());
+
+                match error.error_len() {
+                    Some(len) => {
+                        result.push(char::REPLACEMENT_CHARACTER);
+                        start = valid + len;
+                    }
+                    None if flush => {
+                        result.push(char::REPLACEMENT_CHARACTER);
+                        start = bytes.len();
+                        break;
+                    }
+                    None => {
+                        // The end of the buffer is the beginning of a character.
+                        start = valid;
+                        break;
                     }
-                    // Nothing else to handle? Maybe SetMode/ResetMode?
                 }
             }
         }
     }
+
+    bytes.drain(..start);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+    use crate::style::{BaseColor, Color, ColorType, Effect, EffectStatus, Style};
+    use crate::utils::markup::StyledString;
+
+    fn styles(input: &str) -> Vec<(String, Style)> {
+        parse(input)
+            .spans()
+            .map(|span| (span.content.to_string(), *span.attr))
+            .collect()
+    }
+
+    #[test]
+    fn colors() {
+        let spans = styles("\x1b[31ma\x1b[38;5;196mb\x1b[48;2;1;2;3mc");
+
+        assert_eq!(
+            spans[0].1.color.front,
+            ColorType::Color(BaseColor::Red.dark())
+        );
+        assert_eq!(
+            spans[1].1.color.front,
+            ColorType::Color(Color::from_256colors(196))
+        );
+        assert_eq!(spans[2].1.color.back, ColorType::Color(Color::Rgb(1, 2, 3)));
+    }
+
+    #[test]
+    fn effects_and_resets() {
+        let spans = styles("\x1b[1;4mab\x1b[22mc\x1b[7md\x1b[me");
+
+        assert_eq!(
+            spans[0].1.effects.statuses[Effect::Bold],
+            EffectStatus::OppositeParent
+        );
+        assert_eq!(
+            spans[0].1.effects.statuses[Effect::Underline],
+            EffectStatus::OppositeParent
+        );
+
+        assert_ne!(
+            spans[1].1.effects.statuses[Effect::Bold],
+            EffectStatus::OppositeParent
+        );
+        assert_eq!(
+            spans[1].1.effects.statuses[Effect::Underline],
+            EffectStatus::OppositeParent
+        );
+
+        assert_eq!(
+            spans[2].1.effects.statuses[Effect::Reverse],
+            EffectStatus::OppositeParent
+        );
+
+        assert_eq!(spans[3], ("e".to_string(), Style::default()));
+    }
+
+    #[test]
+    fn cursor_forward() {
+        let spans = styles("a\x1b[3Cb\x1b[Cc\x1b[999999999999999999Cd");
+
+        assert_eq!(spans[1].0, "   ");
+        assert_eq!(spans[3].0, " ");
+        assert_eq!(spans[5].0.len(), super::MAX_CURSOR_FORWARD);
+    }
+
+    #[test]
+    fn underlines() {
+        let spans = styles("\x1b[4:3;58:2::255:0:0ma\x1b[21;53mb\x1b[24;55;59mc");
+
+        let on =
+            |style: &Style, effect| style.effects.statuses[effect] == EffectStatus::OppositeParent;
+
+        assert!(on(&spans[0].1, Effect::CurlyUnderline));
+        assert!(!on(&spans[0].1, Effect::Underline));
+        assert_eq!(
+            spans[0].1.underline,
+            ColorType::Color(Color::Rgb(255, 0, 0))
+        );
+
+        // A new underline style replaces the previous one.
+        assert!(on(&spans[1].1, Effect::DoubleUnderline));
+        assert!(!on(&spans[1].1, Effect::CurlyUnderline));
+        assert!(on(&spans[1].1, Effect::Overline));
+
+        assert!(!on(&spans[2].1, Effect::DoubleUnderline));
+        assert!(!on(&spans[2].1, Effect::Overline));
+        assert_eq!(
+            spans[2].1.underline,
+            ColorType::Color(Color::TerminalDefault)
+        );
+    }
+
+    #[test]
+    fn long_sequences() {
+        let spans = styles("\x1b[1;38;5;196ma\x1b[0;38;2;1;2;3;4mb\x1b[38:2::4:5:6;48:5:16mc");
+
+        assert_eq!(
+            spans[0].1.color.front,
+            ColorType::Color(Color::from_256colors(196))
+        );
+        assert_eq!(
+            spans[0].1.effects.statuses[Effect::Bold],
+            EffectStatus::OppositeParent
+        );
+
+        // The trailing 4 is an underline.
+        assert_eq!(
+            spans[1].1.color.front,
+            ColorType::Color(Color::Rgb(1, 2, 3))
+        );
+        assert_eq!(
+            spans[1].1.effects.statuses[Effect::Underline],
+            EffectStatus::OppositeParent
+        );
+
+        assert_eq!(
+            spans[2].1.color.front,
+            ColorType::Color(Color::Rgb(4, 5, 6))
+        );
+        assert_eq!(
+            spans[2].1.color.back,
+            ColorType::Color(Color::from_256colors(16))
+        );
+    }
+
+    #[test]
+    fn skip_other_sequences() {
+        let text = parse("\x1b]0;title\x07\x1b[2K\x1b[1Ga\x1b[3Cb\x1b(Bc\x1b]8;;url\x1b\\d\x1b[");
+
+        let content: String = text.spans().map(|span| span.content).collect();
+        assert_eq!(content, "a   bcd");
+        assert_eq!(text.width(), 7);
+    }
+
+    // Returns the style of each character.
+    fn char_styles(text: &StyledString) -> Vec<(char, Style)> {
+        text.spans()
+            .flat_map(|span| span.content.chars().map(move |c| (c, *span.attr)))
+            .collect()
+    }
+
+    #[test]
+    fn decoder_chunks() {
+        let input = "\x1b[1;38;2;1;2;3mé\x1b]0;t\x1b\\ab\x1b[2C\x1b[0;4mc\nd\x1b[m";
+        let expected = char_styles(&super::parse(input));
+
+        // Cut the input anywhere, even in the middle of a character.
+        for cut in 0..=input.len() {
+            let (first, second) = input.as_bytes().split_at(cut);
+
+            let mut decoder = super::Decoder::new();
+            let mut text = decoder.feed_bytes(first);
+            text.append(decoder.feed_bytes(second));
+            text.append(decoder.finish());
+
+            assert_eq!(char_styles(&text), expected, "cut at {cut}");
+        }
+    }
+
+    #[test]
+    fn decoder_finish() {
+        let mut decoder = super::Decoder::new();
+
+        // Incomplete escape sequences are dropped.
+        assert_eq!(decoder.feed("a\x1b[3").source(), "a");
+        assert!(decoder.finish().is_empty());
+
+        // Incomplete characters are replaced.
+        assert_eq!(decoder.feed_bytes(b"b\xC3").source(), "b");
+        assert_eq!(decoder.finish().source(), "\u{FFFD}");
+    }
+
+    #[test]
+    fn decoder_unterminated() {
+        let mut decoder = super::Decoder::new();
+
+        // A long string is still skipped, without keeping it all.
+        assert_eq!(decoder.feed("a\x1b]0;").source(), "a");
+        for _ in 0..100 {
+            assert!(decoder.feed(&"x".repeat(100)).is_empty());
+            assert!(decoder.pending.len() <= super::MAX_PENDING);
+        }
+        let text = decoder.feed("\x07b");
+        assert_eq!(
+            text.spans().map(|span| span.content).collect::<String>(),
+            "b"
+        );
+    }
 }
@@ -15,9 +15,46 @@
 //! // Highlight a letter from the word to show a shortcut available.
 //! Button::new(cursup::parse("/red{Q}uit"), |s| s.quit());
 //! ```
+//!
+//! # Syntax
+//!
+//! ```text
+//! markup    := (text | escape | span)*
+//! escape    := '\' ('/' | '{' | '}' | '\')
+//! span      := '/' directive ('+' directive)* '{' markup '}'
+//! directive := style | '@' name | 'gradient.' colors | 'back.gradient.' colors
+//! colors    := color ('.' color)+
+//! ```
+//!
+//! * A `style` is anything [`Style`] can parse: an effect like `bold`, a
+//!   color like `red`, `light_blue`, `#ff8000` or a palette color like
+//!   `primary`, or a background color like `back.black`.
+//! * `@name` refers to a style from the palette (like `@highlight`), or to a
+//!   custom color from the palette. Without `@`, names like `highlight` are
+//!   palette *colors*; palette styles without a matching color (like
+//!   `editable_text`) can be used directly.
+//! * `gradient.red.blue` colors the text of the span with a gradient between
+//!   the given colors. Use `back.gradient` to color the background instead.
+//!
+//! Spans can be nested. Inner spans are combined with the style of the outer ones.
+//!
+//! Anything that doesn't follow this syntax (like an unclosed span), is kept as plain text.
+//! To use `/`, `{`, `}` or `\` literally, prefix them with a `\`.
+//!
+//! ```
+//! # use cursive_core::utils::markup::cursup;
+//! let text = cursup::parse("/@highlight{1\\/2} /gradient.red.blue{Rainbow!}");
+//! assert_eq!(text.source(), "/@highlight{1\\/2} /gradient.red.blue{Rainbow!}");
+//!
+//! // Escapes are removed from the displayed text.
+//! let content: String = text.spans().map(|span| span.content).collect();
+//! assert_eq!(content, "1/2 Rainbow!");
+//! ```
 #![cfg_attr(feature = "doc-cfg", doc(cfg(feature = "cursup")))]
 
-use crate::style::Style;
+use crate::style::gradient::Linear;
+use crate::style::{ColorStyle, Palette, PaletteStyle, Rgb, Style};
+use crate::utils::markup::gradient::decorate_spans_with;
 use crate::utils::markup::{StyledIndexedSpan, StyledString};
 use crate::utils::span::IndexedCow;
 
@@ -33,19 +70,96 @@ struct Candidate {
     brace: usize,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+/// Parsed content between the slash and the brace.
+struct Directive {
+    style: Style,
+    gradient: Option<Gradient>,
+}
+
+enum Gradient {
+    Front(Linear),
+    Back(Linear),
+}
+
 enum Event {
-    Start { style: Style, brace: usize },
+    Start { directive: Directive, brace: usize },
     End,
+    // Skip a single byte, used for escape characters.
+    Skip,
+}
+
+fn parse_gradient(colors: &str) -> Option<Linear> {
+    let colors = colors
+        .split('.')
+        .map(|color| color.parse::<Rgb>().ok())
+        .collect::<Option<Vec<_>>>()?;
+
+    (colors.len() >= 2).then(|| Linear::evenly_spaced(&colors))
+}
+
+fn parse_directive(action: &str, palette: &Palette) -> Option<Directive> {
+    let mut styles = Vec::new();
+    let mut gradient = None;
+
+    for part in action.split('+') {
+        if let Some(name) = part.strip_prefix('@') {
+            if let Ok(palette_style) = name.parse::<PaletteStyle>() {
+                styles.push(palette[palette_style]);
+            } else {
+                styles.push(ColorStyle::front(*palette.custom(name)?).into());
+            }
+        } else if let Some(colors) = part.strip_prefix("gradient.") {
+            gradient = Some(Gradient::Front(parse_gradient(colors)?));
+        } else if let Some(colors) = part.strip_prefix("back.gradient.") {
+            gradient = Some(Gradient::Back(parse_gradient(colors)?));
+        } else if let Ok(style) = part.parse::<Style>() {
+            styles.push(style);
+        } else {
+            // Palette styles that are not also palette colors need no `@`.
+            styles.push(palette[part.parse::<PaletteStyle>().ok()?]);
+        }
+    }
+
+    Some(Directive {
+        style: Style::merge(&styles),
+        gradient,
+    })
+}
+
+fn plain_span(input: &str, start: usize, end: usize, attr: Style) -> StyledIndexedSpan {
+    StyledIndexedSpan {
+        content: IndexedCow::Borrowed { start, end },
+        attr,
+        width: input[start..end].width(),
+    }
 }
 
 /// Parse spans for the given text.
+///
+/// Palette references are resolved using the default palette.
 pub fn parse_spans(input: &str) -> Vec<StyledIndexedSpan> {
+    parse_spans_with_palette(input, &Palette::default())
+}
+
+/// Parse spans for the given text, resolving palette references with the given palette.
+pub fn parse_spans_with_palette(input: &str, palette: &Palette) -> Vec<StyledIndexedSpan> {
     let mut candidates = Vec::<Candidate>::new();
     let mut state = State::Plain;
     let mut events = Vec::new();
 
-    for (i, b) in input.bytes().enumerate() {
+    let bytes = input.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+
+        if b == b'\\' && matches!(bytes.get(i + 1), Some(b'/' | b'{' | b'}' | b'\\')) {
+            // Drop the backslash, and keep the next character as plain text.
+            events.push((i, Event::Skip));
+            state = State::Plain;
+            i += 2;
+            continue;
+        }
+
         match (&mut state, b) {
             (State::Plain, b'/') => {
                 state = State::Slash(i);
@@ -55,12 +169,15 @@ pub fn parse_spans(input: &str) -> Vec<StyledIndexedSpan> {
                 let candidate = candidates.pop().unwrap();
 
                 let action = &input[candidate.slash + 1..candidate.brace];
-                let style = action.parse::<Style>().unwrap_or_default();
+                let directive = parse_directive(action, palette).unwrap_or(Directive {
+                    style: Style::default(),
+                    gradient: None,
+                });
 
                 events.push((
                     candidate.slash,
                     Event::Start {
-                        style,
+                        directive,
                         brace: candidate.brace,
                     },
                 ));
@@ -72,7 +189,7 @@ pub fn parse_spans(input: &str) -> Vec<StyledIndexedSpan> {
 
             (
                 State::Slash(_),
-                b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'#' | b'+' | b'.' | b'_',
+                b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'#' | b'+' | b'.' | b'_' | b'@',
             ) => (),
             (State::Slash(slash), b'{') => {
                 // Add a candidate.
@@ -91,83 +208,101 @@ pub fn parse_spans(input: &str) -> Vec<StyledIndexedSpan> {
                 state = State::Plain;
             }
         }
+
+        i += 1;
     }
 
     events.sort_by_key(|(i, _)| *i);
 
     let mut spans = Vec::new();
-    let mut style_stack = vec![Style::default()];
+
+    // For each level: the style to use, and the gradient to apply to the
+    // spans starting from the given index.
+    let mut style_stack = vec![(Style::default(), None)];
 
     let mut cursor = 0;
     for (i, event) in events {
-        match event {
-            Event::Start { style, brace } => {
-                // Flush everything between cursor and i.
-                if cursor != i {
-                    spans.push(StyledIndexedSpan {
-                        content: IndexedCow::Borrowed {
-                            start: cursor,
-                            end: i,
-                        },
-                        attr: *style_stack.last().unwrap(),
-                        width: input[cursor..i].width(),
-                    });
-                }
+        // Flush everything between cursor and i.
+        if cursor < i {
+            spans.push(plain_span(input, cursor, i, style_stack.last().unwrap().0));
+        }
 
-                let new_style = style_stack.last().unwrap().combine(style);
-                style_stack.push(new_style);
+        match event {
+            Event::Start { directive, brace } => {
+                let new_style = style_stack.last().unwrap().0.combine(directive.style);
+                let gradient = directive.gradient.map(|gradient| (gradient, spans.len()));
+                style_stack.push((new_style, gradient));
 
                 cursor = brace + 1;
             }
             Event::End => {
-                // Just like StartSkip, but we pop a style from the stack.
-                if cursor != i {
-                    spans.push(StyledIndexedSpan {
-                        content: IndexedCow::Borrowed {
-                            start: cursor,
-                            end: i,
-                        },
-                        attr: *style_stack.last().unwrap(),
-                        width: input[cursor..i].width(),
+                if let (_, Some((gradient, first_span))) = style_stack.pop().unwrap() {
+                    let decorated = decorate_spans_with(input, &spans[first_span..], |style, x| {
+                        let color = match &gradient {
+                            Gradient::Front(linear) => {
+                                ColorStyle::front(linear.interpolate(x).as_u8().as_color())
+                            }
+                            Gradient::Back(linear) => {
+                                ColorStyle::back(linear.interpolate(x).as_u8().as_color())
+                            }
+                        };
+                        style.combine(color)
                     });
+                    spans.truncate(first_span);
+                    spans.extend(decorated);
                 }
-                style_stack.pop();
+                cursor = i + 1;
+            }
+            Event::Skip => {
                 cursor = i + 1;
             }
         }
     }
     if cursor != input.len() {
-        spans.push(StyledIndexedSpan {
-            content: IndexedCow::Borrowed {
-                start: cursor,
-                end: input.len(),
-            },
-            attr: *style_stack.last().unwrap(),
-            width: input[cursor..].width(),
-        });
+        spans.push(plain_span(
+            input,
+            cursor,
+            input.len(),
+            style_stack.last().unwrap().0,
+        ));
     }
 
     spans
 }
 
 /// Parse the given text into a styled string.
+///
+/// Palette references are resolved using the default palette.
 pub fn parse<S>(input: S) -> crate::utils::markup::StyledString
+where
+    S: Into<String>,
+{
+    parse_with_palette(input, &Palette::default())
+}
+
+/// Parse the given text into a styled string, resolving palette references with the given palette.
+pub fn parse_with_palette<S>(input: S, palette: &Palette) -> StyledString
 where
     S: Into<String>,
 {
     let input = input.into();
 
-    let spans = parse_spans(&input);
+    let spans = parse_spans_with_palette(&input, palette);
 
     StyledString::with_spans(input, spans)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::style::{BaseColor, Color, ColorType, Effect, Style};
-    use crate::utils::markup::cursup::parse;
+    use crate::style::{
+        BaseColor, Color, ColorType, Effect, EffectStatus, Palette, PaletteColor, PaletteStyle,
+        Style,
+    };
+    use crate::utils::markup::cursup::{parse, parse_spans, parse_with_palette};
     use crate::utils::markup::StyledString;
+    use crate::utils::span::IndexedCow;
     use crate::utils::span::Span;
+    use unicode_width::UnicodeWidthStr;
 
     #[test]
     fn empty_string() {
@@ -295,4 +430,139 @@ mod tests {
         let parsed = parse("/red{red");
         assert_eq!(parsed, StyledString::plain("/red{red"));
     }
+
+    #[test]
+    fn backslash_escape() {
+        let parsed = parse(r"\/red{foo\} \\ \a").canonical();
+        assert_eq!(parsed, StyledString::plain(r"/red{foo} \ \a"));
+
+        let parsed = parse(r"/red{\{\}}").canonical();
+        assert_eq!(
+            parsed,
+            StyledString::single_span("{}", Color::Dark(BaseColor::Red).into())
+        );
+    }
+
+    #[test]
+    fn palette_reference() {
+        let palette = Palette::default();
+        let parsed = parse("/@highlight+bold{foo}").canonical();
+        assert_eq!(
+            parsed,
+            StyledString::single_span(
+                "foo",
+                palette[PaletteStyle::Highlight].combine(Effect::Bold)
+            )
+        );
+
+        let mut palette = Palette::default();
+        palette.set_color("accent", Color::Dark(BaseColor::Magenta));
+        let parsed = parse_with_palette("/@accent{foo}", &palette).canonical();
+        assert_eq!(
+            parsed,
+            StyledString::single_span("foo", Color::Dark(BaseColor::Magenta).into())
+        );
+
+        // Unknown references are ignored.
+        let parsed = parse("/@accent{foo}").canonical();
+        assert_eq!(parsed, StyledString::plain("foo"));
+
+        // Without `@`, palette colors win over palette styles.
+        let parsed = parse("/highlight{a}/editable_text+underline{b}").canonical();
+        assert_eq!(
+            parsed,
+            StyledString::concatenate([
+                StyledString::single_span("a", PaletteColor::Highlight.into()),
+                StyledString::single_span(
+                    "b",
+                    palette[PaletteStyle::EditableText].combine(Effect::Underline)
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn gradient() {
+        let parsed = parse("/gradient.#000000.#ff0000+bold{abc}/red{d}");
+        let spans: Vec<_> = parsed.spans().collect();
+        let fronts: Vec<_> = spans.iter().map(|span| span.attr.color.front).collect();
+        assert_eq!(
+            fronts,
+            [
+                ColorType::Color(Color::Rgb(0, 0, 0)),
+                ColorType::Color(Color::Rgb(128, 0, 0)),
+                ColorType::Color(Color::Rgb(255, 0, 0)),
+                ColorType::Color(Color::Dark(BaseColor::Red)),
+            ]
+        );
+        assert!(spans[..3]
+            .iter()
+            .all(|span| span.attr.effects.statuses[Effect::Bold] == EffectStatus::OppositeParent));
+
+        let parsed = parse("/back.gradient.#000000.#ff0000.#00ff00{abcde}");
+        let backs: Vec<_> = parsed.spans().map(|span| span.attr.color.back).collect();
+        assert_eq!(backs[0], ColorType::Color(Color::Rgb(0, 0, 0)));
+        assert_eq!(backs[2], ColorType::Color(Color::Rgb(255, 0, 0)));
+        assert_eq!(backs[4], ColorType::Color(Color::Rgb(0, 255, 0)));
+
+        // A single color is not a gradient.
+        let parsed = parse("/gradient.red{foo}").canonical();
+        assert_eq!(parsed, StyledString::plain("foo"));
+    }
+
+    #[test]
+    fn fuzz() {
+        const ALPHABET: &[&str] = &[
+            "/",
+            "{",
+            "}",
+            "\\",
+            "a",
+            "r",
+            "e",
+            "d",
+            "+",
+            "@",
+            ".",
+            "#",
+            "é",
+            "漢",
+            " ",
+            "gradient.",
+            "red",
+            "#f00",
+        ];
+
+        // Simple deterministic LCG, to keep this test reproducible.
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next = move || {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            (state >> 33) as usize
+        };
+
+        for _ in 0..2000 {
+            let len = next() % 24;
+            let input: String = (0..len)
+                .map(|_| ALPHABET[next() % ALPHABET.len()])
+                .collect();
+
+            let parsed = parse(input.clone());
+            assert_eq!(parsed.source(), input);
+
+            let mut previous_end = 0;
+            for span in parse_spans(&input) {
+                let (start, end) = match span.content {
+                    IndexedCow::Borrowed { start, end } => (start, end),
+                    IndexedCow::Owned(_) => panic!("unexpected owned span in {input:?}"),
+                };
+                assert!(previous_end <= start && start < end, "{input:?}");
+                assert!(input.is_char_boundary(start), "{input:?}");
+                assert!(input.is_char_boundary(end), "{input:?}");
+                assert_eq!(span.width, input[start..end].width(), "{input:?}");
+                previous_end = end;
+            }
+        }
+    }
 }
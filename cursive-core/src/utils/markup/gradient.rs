@@ -28,30 +28,48 @@ where
 }
 
 /// Generate spans to decorate the given styled string.
-pub fn decorate_with<F>(text: &StyledString, mut style_maker: F) -> Vec<StyledIndexedSpan>
+pub fn decorate_with<F>(text: &StyledString, style_maker: F) -> Vec<StyledIndexedSpan>
+where
+    F: FnMut(Style, f32) -> Style,
+{
+    decorate_spans_with(text.source(), text.spans_raw(), style_maker)
+}
+
+/// Generate spans to decorate the given spans, indexing `source`.
+pub(crate) fn decorate_spans_with<F>(
+    source: &str,
+    spans: &[StyledIndexedSpan],
+    mut style_maker: F,
+) -> Vec<StyledIndexedSpan>
 where
     F: FnMut(Style, f32) -> Style,
 {
     let mut result = Vec::new();
     let mut x = 0f32;
 
-    let mut graphemes = text
-        .spans()
-        .flat_map(|span| span.content.graphemes(true))
+    let mut graphemes = spans
+        .iter()
+        .flat_map(|span| span.resolve(source).content.graphemes(true))
         .map(|g| g.width());
     let first_half = graphemes.next().unwrap_or(0) as f32 / 2f32;
     let last_half = graphemes.next_back().unwrap_or(0) as f32 / 2f32;
     let total_width =
-        text.spans().map(|span| span.width).sum::<usize>() as f32 - first_half - last_half;
+        spans.iter().map(|span| span.width).sum::<usize>() as f32 - first_half - last_half;
 
-    for span in text.spans_raw() {
+    for span in spans {
         let mut cursor = 0;
-        let text = span.resolve(text.source());
+        let text = span.resolve(source);
         for g in text.content.graphemes(true) {
             let l = g.len();
             let gw = g.width();
             let gwf = gw as f32;
-            let new_color = style_maker(span.attr, (x + (gwf / 2f32) - first_half) / total_width);
+            // If nothing has any width, there is nothing to interpolate.
+            let position = if total_width > 0f32 {
+                (x + (gwf / 2f32) - first_half) / total_width
+            } else {
+                0f32
+            };
+            let new_color = style_maker(span.attr, position);
             result.push(StyledIndexedSpan {
                 content: span.content.subcow(cursor..cursor + l),
                 attr: new_color,
@@ -0,0 +1,431 @@
+//! Parse a small subset of HTML.
+//!
+//! This is useful to display text from tools that emit HTML-like markup.
+//!
+//! # Examples
+//!
+//! ```
+//! # use cursive_core as cursive;
+//! use cursive::utils::markup::html;
+//!
+//! let text = html::parse("<b>Warning:</b> <span style=\"color: red\">disk &amp; memory</span>");
+//!
+//! let content: String = text.spans().map(|span| span.content).collect();
+//! assert_eq!(content, "Warning: disk & memory");
+//! ```
+//!
+//! # Supported markup
+//!
+//! * `<b>` and `<strong>` are bold, `<i>` and `<em>` are italic.
+//! * `<u>`, `<ins>` and `<a>` are underlined.
+//! * `<s>`, `<strike>` and `<del>` are struck through.
+//! * `<br>` is a line break.
+//! * `<font color="...">` sets the text color.
+//! * The `style` attribute, on any element, can set `color`,
+//!   `background-color`, `font-weight: bold`, `font-style: italic` and
+//!   `text-decoration` (`underline` or `line-through`). Colors are anything
+//!   [`ColorType`] can parse (like `red`, `#ff8000` or `primary`), or
+//!   `rgb(r, g, b)`.
+//! * Entities like `&lt;`, `&amp;`, `&nbsp;` or `&#x263A;` are decoded.
+//!
+//! Other elements are ignored, but their content is kept. Unlike browsers,
+//! whitespace is kept as-is: line breaks in the source are line breaks in
+//! the text.
+//!
+//! Anything that doesn't look like a tag or an entity is kept as plain text.
+
+use crate::style::{Color, ColorStyle, ColorType, Effect, Style};
+use crate::utils::markup::{StyledIndexedSpan, StyledString};
+use crate::utils::span::IndexedCow;
+
+use unicode_width::UnicodeWidthStr;
+
+// A start or end tag.
+struct Tag<'a> {
+    // Lowercase name of the element.
+    name: String,
+
+    closing: bool,
+
+    attributes: Vec<(String, &'a str)>,
+}
+
+impl<'a> Tag<'a> {
+    fn attribute(&self, name: &str) -> Option<&'a str> {
+        self.attributes
+            .iter()
+            .find(|(attribute, _)| attribute == name)
+            .map(|&(_, value)| value)
+    }
+}
+
+// Returns the length of the prefix matching `predicate`.
+fn prefix_len(input: &str, predicate: impl Fn(char) -> bool) -> usize {
+    input.find(|c| !predicate(c)).unwrap_or(input.len())
+}
+
+// Parses a tag at the start of `input`.
+//
+// Returns the tag and its length in bytes.
+fn parse_tag(input: &str) -> Option<(Tag<'_>, usize)> {
+    let mut i = 1;
+
+    let closing = input[i..].starts_with('/');
+    if closing {
+        i += 1;
+    }
+
+    let name_len = prefix_len(&input[i..], |c| c.is_ascii_alphanumeric());
+    if !input[i..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let name = input[i..i + name_len].to_ascii_lowercase();
+    i += name_len;
+
+    let mut attributes = Vec::new();
+    loop {
+        i += prefix_len(&input[i..], char::is_whitespace);
+        let rest = &input[i..];
+
+        if rest.starts_with('>') {
+            return Some((
+                Tag {
+                    name,
+                    closing,
+                    attributes,
+                },
+                i + 1,
+            ));
+        }
+        if rest.starts_with("/>") {
+            return Some((
+                Tag {
+                    name,
+                    closing,
+                    attributes,
+                },
+                i + 2,
+            ));
+        }
+
+        let attribute_len = prefix_len(rest, |c| {
+            !c.is_whitespace() && !matches!(c, '=' | '>' | '/' | '"' | '\'' | '<')
+        });
+        if attribute_len == 0 {
+            return None;
+        }
+        let attribute = rest[..attribute_len].to_ascii_lowercase();
+        i += attribute_len;
+        i += prefix_len(&input[i..], char::is_whitespace);
+
+        let mut value = "";
+        if input[i..].starts_with('=') {
+            i += 1;
+            i += prefix_len(&input[i..], char::is_whitespace);
+            let rest = &input[i..];
+            match rest.chars().next()? {
+                quote @ ('"' | '\'') => {
+                    let len = rest[1..].find(quote)?;
+                    value = &rest[1..1 + len];
+                    i += len + 2;
+                }
+                _ => {
+                    let len = prefix_len(rest, |c| !c.is_whitespace() && c != '>');
+                    value = &rest[..len];
+                    i += len;
+                }
+            }
+        }
+        attributes.push((attribute, value));
+    }
+}
+
+// Parses an entity at the start of `input`.
+//
+// Returns the character and the length of the entity in bytes.
+fn parse_entity(input: &str) -> Option<(char, usize)> {
+    // The longest entity we support is `&#x10FFFF;`.
+    let end = input.get(..11).unwrap_or(input).find(';')?;
+    let name = &input[1..end];
+
+    let c = match name {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => '\u{a0}',
+        _ => {
+            let code = name.strip_prefix('#')?;
+            let code = match code.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => code.parse().ok()?,
+            };
+            char::from_u32(code)?
+        }
+    };
+
+    Some((c, end + 1))
+}
+
+// Parses a CSS color, like `red` or `rgb(255, 128, 0)`.
+fn parse_color(value: &str) -> Option<ColorType> {
+    if let Some(rgb) = value
+        .strip_prefix("rgb(")
+        .and_then(|rgb| rgb.strip_suffix(')'))
+    {
+        let rgb = rgb
+            .split(',')
+            .map(|part| part.trim().parse().ok())
+            .collect::<Option<Vec<u8>>>()?;
+        return match rgb[..] {
+            [r, g, b] => Some(Color::Rgb(r, g, b).into()),
+            _ => None,
+        };
+    }
+
+    value.parse().ok()
+}
+
+// Parses the content of a `style` attribute.
+//
+// Unknown properties are ignored.
+fn parse_css(css: &str) -> Style {
+    let mut styles = Vec::new();
+
+    for declaration in css.split(';') {
+        let Some((property, value)) = declaration.split_once(':') else {
+            continue;
+        };
+        let property = property.trim().to_ascii_lowercase();
+        let value = value.trim().to_ascii_lowercase();
+
+        match property.as_str() {
+            "color" => {
+                if let Some(color) = parse_color(&value) {
+                    styles.push(ColorStyle::front(color).into());
+                }
+            }
+            "background" | "background-color" => {
+                if let Some(color) = parse_color(&value) {
+                    styles.push(ColorStyle::back(color).into());
+                }
+            }
+            "font-weight" => {
+                let bold = match value.as_str() {
+                    "bold" | "bolder" => true,
+                    weight => matches!(weight.parse::<u16>(), Ok(weight) if weight >= 600),
+                };
+                if bold {
+                    styles.push(Effect::Bold.into());
+                }
+            }
+            "font-style" if value == "italic" || value == "oblique" => {
+                styles.push(Effect::Italic.into());
+            }
+            "text-decoration" | "text-decoration-line" => {
+                for decoration in value.split_whitespace() {
+                    match decoration {
+                        "underline" => styles.push(Effect::Underline.into()),
+                        "line-through" => styles.push(Effect::Strikethrough.into()),
+                        _ => (),
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    Style::merge(&styles)
+}
+
+// Returns the style added by an element.
+fn element_style(tag: &Tag) -> Style {
+    let style = match tag.name.as_str() {
+        "b" | "strong" => Effect::Bold.into(),
+        "i" | "em" => Effect::Italic.into(),
+        "u" | "ins" | "a" => Effect::Underline.into(),
+        "s" | "strike" | "del" => Effect::Strikethrough.into(),
+        "font" => tag
+            .attribute("color")
+            .and_then(parse_color)
+            .map_or_else(Style::none, |color| ColorStyle::front(color).into()),
+        _ => Style::none(),
+    };
+
+    match tag.attribute("style") {
+        Some(css) => style.combine(parse_css(css)),
+        None => style,
+    }
+}
+
+/// Parse the given text into a list of spans.
+pub fn parse_spans(input: &str) -> Vec<StyledIndexedSpan> {
+    let mut spans = Vec::new();
+
+    // Open elements, with the style of their content.
+    let mut stack: Vec<(String, Style)> = Vec::new();
+    let current =
+        |stack: &[(String, Style)]| stack.last().map_or_else(Style::none, |(_, style)| *style);
+
+    // Start of the text not added to the spans yet.
+    let mut start = 0;
+    let mut i = 0;
+    while let Some(offset) = input[i..].find(['<', '&']) {
+        let position = i + offset;
+        let rest = &input[position..];
+        i = position + 1;
+
+        let flush = |spans: &mut Vec<StyledIndexedSpan>, style| {
+            if start < position {
+                spans.push(StyledIndexedSpan {
+                    content: IndexedCow::Borrowed {
+                        start,
+                        end: position,
+                    },
+                    attr: style,
+                    width: input[start..position].width(),
+                });
+            }
+        };
+
+        if let Some(len) = rest
+            .starts_with("<!--")
+            .then(|| rest.find("-->").map_or(rest.len(), |end| end + 3))
+        {
+            // Comments are skipped.
+            flush(&mut spans, current(&stack));
+            i = position + len;
+        } else if let Some((tag, len)) = rest.starts_with('<').then(|| parse_tag(rest)).flatten() {
+            flush(&mut spans, current(&stack));
+            i = position + len;
+
+            if tag.closing {
+                // Close the matching element, and any element left open inside.
+                if let Some(index) = stack.iter().rposition(|(name, _)| *name == tag.name) {
+                    stack.truncate(index);
+                }
+            } else if tag.name == "br" {
+                spans.push(StyledIndexedSpan::simple_owned(
+                    "\n".into(),
+                    current(&stack),
+                ));
+            } else {
+                let style = current(&stack).combine(element_style(&tag));
+                stack.push((tag.name, style));
+            }
+        } else if let Some((c, len)) = rest.starts_with('&').then(|| parse_entity(rest)).flatten() {
+            flush(&mut spans, current(&stack));
+            i = position + len;
+
+            spans.push(StyledIndexedSpan::simple_owned(c.into(), current(&stack)));
+        } else {
+            // Not markup: keep it as text.
+            continue;
+        }
+
+        start = i;
+    }
+
+    if start < input.len() {
+        spans.push(StyledIndexedSpan {
+            content: IndexedCow::Borrowed {
+                start,
+                end: input.len(),
+            },
+            attr: current(&stack),
+            width: input[start..].width(),
+        });
+    }
+
+    spans
+}
+
+/// Parse the given text into a styled string.
+pub fn parse<S>(input: S) -> StyledString
+where
+    S: Into<String>,
+{
+    let input = input.into();
+
+    let spans = parse_spans(&input);
+
+    StyledString::with_spans(input, spans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::BaseColor;
+
+    // Returns the content and style of each span.
+    fn spans(input: &str) -> Vec<(String, Style)> {
+        parse(input)
+            .spans()
+            .map(|span| (span.content.to_string(), *span.attr))
+            .collect()
+    }
+
+    #[test]
+    fn plain() {
+        assert_eq!(parse("abc"), StyledString::plain("abc"));
+        assert_eq!(parse("a < b && c"), StyledString::plain("a < b && c"));
+    }
+
+    #[test]
+    fn elements() {
+        assert_eq!(
+            spans("<b>bold <I>both</I></b><br/><u>under</u><a href='x'>link</a>"),
+            [
+                ("bold ".to_string(), Style::from(Effect::Bold)),
+                (
+                    "both".to_string(),
+                    Style::from(Effect::Bold).combine(Effect::Italic)
+                ),
+                ("\n".to_string(), Style::none()),
+                ("under".to_string(), Effect::Underline.into()),
+                ("link".to_string(), Effect::Underline.into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn colors() {
+        let spans = spans(
+            "<span style=\"color: red; background-color: rgb(1, 2, 3); font-weight: 700\">a</span>\
+             <font color=\"#00ff00\">b</font>",
+        );
+
+        assert_eq!(
+            spans[0].1,
+            Style::merge(&[
+                ColorStyle::new(BaseColor::Red.dark(), Color::Rgb(1, 2, 3)).into(),
+                Effect::Bold.into(),
+            ])
+        );
+        assert_eq!(spans[1].1, Style::from(Color::Rgb(0, 255, 0)));
+    }
+
+    #[test]
+    fn entities_and_unknown_tags() {
+        let text = parse("<p>1 &lt; 2 &amp;&#x263A;&#33;</p><!-- hidden --> &unknown;");
+        let content: String = text.spans().map(|span| span.content).collect();
+
+        assert_eq!(content, "1 < 2 &☺! &unknown;");
+    }
+
+    #[test]
+    fn unbalanced() {
+        // Closing an element also closes the ones inside.
+        assert_eq!(
+            spans("<b><i>a</b>b</i>"),
+            [
+                (
+                    "a".to_string(),
+                    Style::from(Effect::Bold).combine(Effect::Italic)
+                ),
+                ("b".to_string(), Style::none()),
+            ]
+        );
+    }
+}
@@ -1,18 +1,36 @@
 //! Parse markdown text.
 //!
 //! Needs the `markdown` feature to be enabled.
+//!
+//! Block elements are rendered as indented text: lists get bullets or
+//! numbers, block quotes get a `> ` margin, code blocks are indented and
+//! tables are aligned in columns. Headings use the title colors from the
+//! palette.
+//!
+//! Code blocks can be highlighted with [`parse_with_highlighter`], for example
+//! using `cursive-syntect`.
 #![cfg(feature = "markdown")]
 #![cfg_attr(feature = "doc-cfg", doc(cfg(feature = "markdown")))]
 
 use std::borrow::Cow;
+use std::collections::VecDeque;
 
-use crate::style::{Effect, Style};
+use crate::style::{ColorStyle, Effect, Style};
 use crate::utils::markup::{StyledIndexedSpan, StyledString};
 use crate::utils::span::IndexedCow;
 
-use pulldown_cmark::{self, CowStr, Event, Tag, TagEnd};
+use pulldown_cmark::{
+    self, Alignment, CodeBlockKind, CowStr, Event, HeadingLevel, Options, Tag, TagEnd,
+};
 use unicode_width::UnicodeWidthStr;
 
+/// Highlights the content of code blocks.
+///
+/// Called with the language of the block, if any, and its content.
+///
+/// Returning `None` uses the default code style.
+pub type Highlighter<'a> = dyn FnMut(Option<&str>, &str) -> Option<StyledString> + 'a;
+
 /// Parses the given string as markdown text.
 pub fn parse<S>(input: S) -> StyledString
 where
@@ -25,6 +43,36 @@ where
     StyledString::with_spans(input, spans)
 }
 
+/// Parses the given string as markdown text, highlighting code blocks.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::style::BaseColor;
+/// use cursive_core::utils::markup::{markdown, StyledString};
+///
+/// let text = markdown::parse_with_highlighter("```rust\nlet a = 1;\n```", |language, code| {
+///     let color = match language? {
+///         "rust" => BaseColor::Red.dark(),
+///         _ => return None,
+///     };
+///     Some(StyledString::styled(code, color))
+/// });
+/// let content: String = text.spans().map(|span| span.content).collect();
+/// assert_eq!(content, "    let a = 1;");
+/// ```
+pub fn parse_with_highlighter<S, F>(input: S, highlighter: F) -> StyledString
+where
+    S: Into<String>,
+    F: FnMut(Option<&str>, &str) -> Option<StyledString>,
+{
+    let input = input.into();
+
+    let spans = Parser::new(&input).with_highlighter(highlighter).collect();
+
+    StyledString::with_spans(input, spans)
+}
+
 // Convert a CowStr from pulldown into a regular Cow<str>
 // We lose the inline optimization, but oh well.
 fn cowvert(cow: CowStr) -> Cow<str> {
@@ -35,25 +83,102 @@ fn cowvert(cow: CowStr) -> Cow<str> {
     }
 }
 
+// Creates a span for structural text, like list markers or line breaks.
+fn plain<S>(text: S) -> StyledIndexedSpan
+where
+    S: Into<String>,
+{
+    StyledIndexedSpan::simple_owned(text.into(), Style::none())
+}
+
+// Prefix added to each line by an enclosing block.
+enum Prefix {
+    Quote,
+
+    // Blank space aligning content, like in code blocks.
+    Indent(usize),
+
+    // List item marker, only shown on the first line of the item.
+    Marker(String),
+}
+
+// Table being parsed.
+struct Table {
+    alignments: Vec<Alignment>,
+
+    // Spans of each cell, row by row. The first row is the header.
+    rows: Vec<Vec<Vec<StyledIndexedSpan>>>,
+}
+
 /// Iterator that parse a markdown text and outputs styled spans.
 pub struct Parser<'a> {
-    first: bool,
     stack: Vec<Style>,
     input: &'a str,
     parser: pulldown_cmark::Parser<'a>,
+
+    // Spans ready to be returned.
+    pending: VecDeque<StyledIndexedSpan>,
+
+    prefixes: Vec<Prefix>,
+
+    // Next number of each enclosing list, or `None` for bullet lists.
+    lists: Vec<Option<u64>>,
+
+    // Line breaks to add before the next content.
+    breaks: usize,
+
+    // Prefix of blank lines between blocks, from before the last block started.
+    blank: Option<String>,
+
+    // `true` until something is printed on the current line.
+    line_start: bool,
+
+    // `true` once some content was printed.
+    started: bool,
+
+    // Language and content of the current code block.
+    code: Option<(Option<String>, String)>,
+
+    table: Option<Table>,
+
+    highlighter: Option<Box<Highlighter<'a>>>,
 }
 
 impl<'a> Parser<'a> {
     /// Creates a new parser with the given input text.
     pub fn new(input: &'a str) -> Self {
+        let options =
+            Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+
         Parser {
             input,
-            first: true,
-            parser: pulldown_cmark::Parser::new(input),
+            parser: pulldown_cmark::Parser::new_ext(input, options),
             stack: Vec::new(),
+            pending: VecDeque::new(),
+            prefixes: Vec::new(),
+            lists: Vec::new(),
+            breaks: 0,
+            blank: None,
+            line_start: true,
+            started: false,
+            code: None,
+            table: None,
+            highlighter: None,
         }
     }
 
+    /// Uses the given function to highlight code blocks.
+    ///
+    /// See [`Highlighter`].
+    #[must_use]
+    pub fn with_highlighter<F>(mut self, highlighter: F) -> Self
+    where
+        F: FnMut(Option<&str>, &str) -> Option<StyledString> + 'a,
+    {
+        self.highlighter = Some(Box::new(highlighter));
+        self
+    }
+
     /// Creates a new span with the given value
     fn literal<S>(&self, text: S) -> StyledIndexedSpan
     where
@@ -61,71 +186,356 @@ impl<'a> Parser<'a> {
     {
         StyledIndexedSpan::simple_owned(text.into(), Style::merge(&self.stack))
     }
+
+    // Starts a new block, at least `breaks` line breaks after the previous content.
+    fn start_block(&mut self, breaks: usize) {
+        // The first block of a list item goes right after the marker.
+        if !self.started || matches!(self.prefixes.last(), Some(Prefix::Marker(_))) {
+            return;
+        }
+        self.breaks = self.breaks.max(breaks);
+        if self.blank.is_none() {
+            self.blank = Some(self.blank_prefix());
+        }
+    }
+
+    // Returns the prefix for the next line, and only shows list markers once.
+    fn line_prefix(&mut self) -> String {
+        let mut result = String::new();
+        for prefix in &mut self.prefixes {
+            match prefix {
+                Prefix::Quote => result.push_str("> "),
+                Prefix::Indent(width) => result.push_str(&" ".repeat(*width)),
+                Prefix::Marker(marker) => {
+                    let width = marker.width();
+                    result.push_str(marker);
+                    *prefix = Prefix::Indent(width);
+                }
+            }
+        }
+        result
+    }
+
+    // Returns the prefix for blank lines: only block quotes are shown.
+    fn blank_prefix(&self) -> String {
+        let mut result = String::new();
+        for prefix in &self.prefixes {
+            match prefix {
+                Prefix::Quote => result.push_str("> "),
+                Prefix::Indent(width) => result.push_str(&" ".repeat(*width)),
+                Prefix::Marker(marker) => result.push_str(&" ".repeat(marker.width())),
+            }
+        }
+        result.trim_end().to_string()
+    }
+
+    // Adds a span to the output, after pending line breaks and prefixes.
+    fn push(&mut self, span: StyledIndexedSpan) {
+        if let Some(table) = &mut self.table {
+            if let Some(cell) = table.rows.last_mut().and_then(|row| row.last_mut()) {
+                cell.push(span);
+            }
+            return;
+        }
+
+        if self.breaks > 0 {
+            let blank = self.blank.take().unwrap_or_else(|| self.blank_prefix());
+            let mut text = String::new();
+            for i in 0..self.breaks {
+                if i > 0 {
+                    text.push_str(&blank);
+                }
+                text.push('\n');
+            }
+            if self.started {
+                self.pending.push_back(plain(text));
+            }
+            self.breaks = 0;
+            self.line_start = true;
+        }
+
+        if self.line_start {
+            self.line_start = false;
+            let prefix = self.line_prefix();
+            if !prefix.is_empty() {
+                self.pending.push_back(plain(prefix));
+            }
+        }
+
+        self.started = true;
+        self.pending.push_back(span);
+    }
+
+    // Adds some text, which may span multiple lines.
+    fn push_lines(&mut self, text: &str, style: Style) {
+        for (i, line) in text.split('\n').enumerate() {
+            if i > 0 {
+                self.breaks += 1;
+            }
+            if !line.is_empty() {
+                self.push(StyledIndexedSpan::simple_owned(line.to_string(), style));
+            }
+        }
+    }
+
+    fn push_text(&mut self, text: CowStr<'a>) {
+        if let Some((_, code)) = &mut self.code {
+            code.push_str(&text);
+            return;
+        }
+
+        let text = cowvert(text);
+        let width = text.width();
+        // Return something!
+        let span = StyledIndexedSpan {
+            content: IndexedCow::from_cow(text, self.input),
+            attr: Style::merge(&self.stack),
+            width,
+        };
+        self.push(span);
+    }
+
+    fn push_code_block(&mut self, language: Option<String>, code: String) {
+        let code = code.strip_suffix('\n').unwrap_or(&code);
+        let highlighted = self
+            .highlighter
+            .as_mut()
+            .and_then(|highlighter| highlighter(language.as_deref(), code));
+
+        self.prefixes.push(Prefix::Indent(4));
+        match highlighted {
+            Some(highlighted) => {
+                for span in highlighted.spans() {
+                    self.push_lines(span.content, *span.attr);
+                }
+            }
+            None => self.push_lines(code, code_style()),
+        }
+        self.prefixes.pop();
+    }
+
+    fn push_table(&mut self, table: Table) {
+        fn cell_width(cell: &[StyledIndexedSpan]) -> usize {
+            cell.iter().map(|span| span.width).sum()
+        }
+
+        let columns = table.rows.iter().map(Vec::len).max().unwrap_or(0);
+        let widths: Vec<usize> = (0..columns)
+            .map(|i| {
+                table
+                    .rows
+                    .iter()
+                    .filter_map(|row| row.get(i))
+                    .map(|cell| cell_width(cell))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        for (i, row) in table.rows.into_iter().enumerate() {
+            if i > 0 {
+                self.breaks = 1;
+            }
+
+            let mut cells = row.into_iter();
+            for (column, &width) in widths.iter().enumerate() {
+                let cell = cells.next().unwrap_or_default();
+                let last = column + 1 == columns;
+                if column > 0 {
+                    self.push(plain(if last && cell.is_empty() {
+                        " │"
+                    } else {
+                        " │ "
+                    }));
+                }
+
+                let padding = width - cell_width(&cell);
+                let (left, right) = match table.alignments.get(column) {
+                    Some(Alignment::Right) => (padding, 0),
+                    Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+                    _ => (0, padding),
+                };
+                // Don't leave trailing spaces.
+                let (left, right) = match (last, cell.is_empty()) {
+                    (true, true) => (0, 0),
+                    (true, false) => (left, 0),
+                    _ => (left, right),
+                };
+
+                if left > 0 {
+                    self.push(plain(" ".repeat(left)));
+                }
+                for span in cell {
+                    self.push(span);
+                }
+                if right > 0 {
+                    self.push(plain(" ".repeat(right)));
+                }
+            }
+
+            if i == 0 {
+                // Separate the header from the rest.
+                let rule: Vec<String> = widths.iter().map(|&width| "─".repeat(width)).collect();
+                self.breaks = 1;
+                self.push(plain(rule.join("─┼─")));
+            }
+        }
+    }
+
+    fn start(&mut self, tag: Tag<'a>) {
+        match tag {
+            // Add to the stack!
+            Tag::Emphasis => self.stack.push(Style::from(Effect::Italic)),
+            Tag::Strong => self.stack.push(Style::from(Effect::Bold)),
+            Tag::Strikethrough => self.stack.push(Style::from(Effect::Strikethrough)),
+            Tag::Heading { level, .. } => {
+                self.start_block(2);
+                self.stack.push(heading_style(level));
+                self.push(self.literal(format!("{} ", heading(level as usize))));
+            }
+            Tag::BlockQuote(_) => {
+                self.start_block(2);
+                self.prefixes.push(Prefix::Quote);
+            }
+            Tag::List(first) => {
+                // Nested lists are not separated by a blank line.
+                self.start_block(if self.lists.is_empty() { 2 } else { 1 });
+                self.lists.push(first);
+            }
+            Tag::Item => {
+                self.start_block(1);
+                let depth = self.lists.len().saturating_sub(1);
+                let marker = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}. ", *number - 1)
+                    }
+                    _ => format!("{} ", ["•", "◦", "▪"][depth % 3]),
+                };
+                self.prefixes.push(Prefix::Marker(marker));
+            }
+            Tag::Link {
+                dest_url, title, ..
+            } => self.push(self.literal(format!("[{title}]({dest_url})"))),
+            Tag::CodeBlock(kind) => {
+                self.start_block(2);
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => {
+                        info.split_whitespace().next().map(ToString::to_string)
+                    }
+                    CodeBlockKind::Indented => None,
+                };
+                self.code = Some((language, String::new()));
+            }
+            Tag::Table(alignments) => {
+                self.start_block(2);
+                self.table = Some(Table {
+                    alignments,
+                    rows: Vec::new(),
+                });
+            }
+            Tag::TableHead => {
+                if let Some(table) = &mut self.table {
+                    table.rows.push(Vec::new());
+                }
+                self.stack.push(Style::from(Effect::Bold));
+            }
+            Tag::TableRow => {
+                if let Some(table) = &mut self.table {
+                    table.rows.push(Vec::new());
+                }
+            }
+            Tag::TableCell => {
+                if let Some(row) = self.table.as_mut().and_then(|table| table.rows.last_mut()) {
+                    row.push(Vec::new());
+                }
+            }
+            Tag::Paragraph | Tag::HtmlBlock => self.start_block(2),
+            _ => (),
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            // Remove from stack!
+            TagEnd::Emphasis
+            | TagEnd::Strong
+            | TagEnd::Strikethrough
+            | TagEnd::Heading(..)
+            | TagEnd::TableHead => {
+                self.stack.pop().unwrap();
+            }
+            TagEnd::BlockQuote(_) | TagEnd::Item => {
+                self.prefixes.pop();
+            }
+            TagEnd::List(_) => {
+                self.lists.pop();
+            }
+            TagEnd::CodeBlock => {
+                if let Some((language, code)) = self.code.take() {
+                    self.push_code_block(language, code);
+                }
+            }
+            TagEnd::Table => {
+                if let Some(table) = self.table.take() {
+                    self.push_table(table);
+                }
+            }
+            _ => (),
+        }
+    }
 }
 
 fn heading(level: usize) -> &'static str {
     &"##########"[..level]
 }
 
+// Returns the style of headings with the given level.
+fn heading_style(level: HeadingLevel) -> Style {
+    match level {
+        HeadingLevel::H1 => Style::title_primary().combine(Effect::Bold),
+        HeadingLevel::H2 => Style::title_secondary().combine(Effect::Bold),
+        _ => Style::from(Effect::Bold),
+    }
+}
+
+// Returns the style of inline code and code blocks.
+fn code_style() -> Style {
+    ColorStyle::secondary().into()
+}
+
 impl<'a> Iterator for Parser<'a> {
     type Item = StyledIndexedSpan;
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
-            let next = match self.parser.next() {
-                None => return None,
-                Some(event) => event,
-            };
-
-            match next {
-                Event::Start(tag) => match tag {
-                    // Add to the stack!
-                    Tag::Emphasis => self.stack.push(Style::from(Effect::Italic)),
-                    Tag::Heading { level, .. } => {
-                        return Some(self.literal(format!("{} ", heading(level as usize))))
-                    }
-                    Tag::BlockQuote(_) => return Some(self.literal("> ")),
-                    Tag::Link {
-                        dest_url, title, ..
-                    } => return Some(self.literal(format!("[{title}]({dest_url})"))),
-                    Tag::CodeBlock(_) => return Some(self.literal("```")),
-                    Tag::Strong => self.stack.push(Style::from(Effect::Bold)),
-                    Tag::Paragraph if !self.first => return Some(self.literal("\n\n")),
-                    _ => (),
-                },
-                Event::End(tag) => match tag {
-                    // Remove from stack!
-                    TagEnd::Paragraph if self.first => self.first = false,
-                    TagEnd::Heading(..) => return Some(self.literal("\n\n")),
-                    TagEnd::CodeBlock => return Some(self.literal("```")),
-                    TagEnd::Emphasis | TagEnd::Strong => {
-                        self.stack.pop().unwrap();
-                    }
-                    _ => (),
-                },
-                Event::Rule => return Some(self.literal("---")),
-                Event::SoftBreak => return Some(self.literal("\n")),
-                Event::HardBreak => return Some(self.literal("\n")),
+            if let Some(span) = self.pending.pop_front() {
+                return Some(span);
+            }
+
+            match self.parser.next()? {
+                Event::Start(tag) => self.start(tag),
+                Event::End(tag) => self.end(tag),
+                Event::Rule => {
+                    self.start_block(2);
+                    self.push(plain("---"));
+                }
+                Event::SoftBreak | Event::HardBreak => self.breaks += 1,
+                Event::Code(text) => {
+                    self.stack.push(code_style());
+                    self.push_text(text);
+                    self.stack.pop();
+                }
+                Event::Html(text) => self.push_lines(&text, Style::merge(&self.stack)),
                 // Treat all text the same
                 Event::FootnoteReference(text)
                 | Event::InlineHtml(text)
-                | Event::Html(text)
                 | Event::Text(text)
-                | Event::Code(text)
                 | Event::InlineMath(text)
-                | Event::DisplayMath(text) => {
-                    let text = cowvert(text);
-                    let width = text.width();
-                    // Return something!
-                    return Some(StyledIndexedSpan {
-                        content: IndexedCow::from_cow(text, self.input),
-                        attr: Style::merge(&self.stack),
-                        width,
-                    });
-                }
+                | Event::DisplayMath(text) => self.push_text(text),
                 Event::TaskListMarker(checked) => {
-                    let mark = if checked { "[x]" } else { "[ ]" };
-                    return Some(self.literal(mark));
+                    let mark = if checked { "[x] " } else { "[ ] " };
+                    self.push(self.literal(mark));
                 }
             }
         }
@@ -142,8 +552,14 @@ pub fn parse_spans(input: &str) -> Vec<StyledIndexedSpan> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::style::BaseColor;
     use crate::utils::span::Span;
 
+    // Returns the rendered text.
+    fn render(text: &StyledString) -> String {
+        text.spans().map(|span| span.content).collect()
+    }
+
     #[test]
     fn test_parse() {
         let input = r"
@@ -153,6 +569,8 @@ I *really* love __Cursive__!";
         let spans = parse_spans(input);
         let spans: Vec<_> = spans.iter().map(|span| span.resolve(input)).collect();
 
+        let title = heading_style(HeadingLevel::H1);
+
         // println!("{:?}", spans);
         assert_eq!(
             &spans[..],
@@ -160,12 +578,12 @@ I *really* love __Cursive__!";
                 Span {
                     content: "# ",
                     width: 2,
-                    attr: &Style::none(),
+                    attr: &title,
                 },
                 Span {
                     content: "Attention",
                     width: 9,
-                    attr: &Style::none(),
+                    attr: &title,
                 },
                 Span {
                     content: "\n\n",
@@ -200,4 +618,119 @@ I *really* love __Cursive__!";
             ]
         );
     }
+
+    #[test]
+    fn lists() {
+        let input = "\
+Intro
+- one
+- two
+  more
+  1. first
+  2. second
+- [x] done
+
+3. three
+4. four";
+
+        assert_eq!(
+            render(&parse(input)),
+            "\
+Intro
+
+• one
+• two
+  more
+  1. first
+  2. second
+• [x] done
+
+3. three
+4. four"
+        );
+    }
+
+    #[test]
+    fn quotes() {
+        let input = "\
+> Some
+> quote
+>
+> - item
+>
+> > nested";
+
+        assert_eq!(
+            render(&parse(input)),
+            "\
+> Some
+> quote
+>
+> • item
+>
+> > nested"
+        );
+    }
+
+    #[test]
+    fn code_blocks() {
+        let input = "\
+- item
+
+  ```rust
+  fn main() {
+
+  }
+  ```
+Done with `code`.";
+
+        let text = parse(input);
+        assert_eq!(
+            render(&text),
+            "\
+• item
+
+      fn main() {
+
+      }
+
+Done with code."
+        );
+
+        let code = text
+            .spans()
+            .find(|span| span.content == "fn main() {")
+            .unwrap();
+        assert_eq!(*code.attr, code_style());
+
+        // Highlighters can replace the style of code blocks.
+        let text = parse_with_highlighter(input, |language, code| {
+            assert_eq!(language, Some("rust"));
+            Some(StyledString::styled(code, BaseColor::Red.dark()))
+        });
+        let code = text.spans().find(|span| span.content == "}").unwrap();
+        assert_eq!(*code.attr, Style::from(BaseColor::Red.dark()));
+    }
+
+    #[test]
+    fn tables() {
+        let input = "\
+| Name | Count | Center |
+|------|------:|:------:|
+| a    | 1     | x      |
+| long | 1000  |        |";
+
+        let text = parse(input);
+        assert_eq!(
+            render(&text),
+            "\
+Name │ Count │ Center
+─────┼───────┼───────
+a    │     1 │   x
+long │  1000 │"
+        );
+
+        let header = text.spans().find(|span| span.content == "Count").unwrap();
+        assert_eq!(*header.attr, Style::from(Effect::Bold));
+    }
 }
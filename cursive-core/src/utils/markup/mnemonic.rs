@@ -0,0 +1,93 @@
+//! Labels with a mnemonic.
+//!
+//! A mnemonic is a letter from a label which, pressed with `Alt`, activates
+//! the associated control. In the label, the mnemonic is prefixed with `&`,
+//! and it is underlined when displayed. Use `&&` for a literal `&`.
+//!
+//! Mnemonics are handled by [`Dialog::mnemonic`].
+//!
+//! # Examples
+//!
+//! ```
+//! use cursive_core::utils::markup::mnemonic;
+//!
+//! let (label, key) = mnemonic::parse("&Subscribe && save");
+//! assert_eq!(key, Some('s'));
+//!
+//! let content: String = label.spans().map(|span| span.content).collect();
+//! assert_eq!(content, "Subscribe & save");
+//! ```
+//!
+//! [`Dialog::mnemonic`]: crate::views::Dialog::mnemonic
+use crate::style::Effect;
+use crate::utils::markup::StyledString;
+
+/// Parses a label, returning the text to display and its mnemonic, if any.
+///
+/// The mnemonic is returned in lowercase. Only the first `&` followed by an
+/// alphanumeric character declares a mnemonic, and any `&` not followed by
+/// an alphanumeric character is kept as-is.
+pub fn parse(label: &str) -> (StyledString, Option<char>) {
+    let mut result = StyledString::new();
+    let mut key = None;
+
+    let mut rest = label;
+    while let Some(i) = rest.find('&') {
+        result.append_plain(&rest[..i]);
+        rest = &rest[i + 1..];
+
+        let Some(c) = rest.chars().next() else {
+            // A trailing `&` is kept.
+            result.append_plain("&");
+            break;
+        };
+
+        if c == '&' {
+            result.append_plain("&");
+        } else if c.is_alphanumeric() && key.is_none() {
+            key = c.to_lowercase().next();
+            result.append_styled(c.to_string(), Effect::Underline);
+        } else if c.is_alphanumeric() {
+            // Only the first mnemonic is used.
+            result.append_plain(c.to_string());
+        } else {
+            result.append_plain("&");
+            continue;
+        }
+        rest = &rest[c.len_utf8()..];
+    }
+    result.append_plain(rest);
+
+    (result, key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn content(text: &StyledString) -> String {
+        text.spans().map(|span| span.content).collect()
+    }
+
+    #[test]
+    fn parse_labels() {
+        let (label, key) = parse("Se&ttings");
+        assert_eq!(key, Some('t'));
+        assert_eq!(content(&label), "Settings");
+        let underlined: Vec<_> = label
+            .spans()
+            .filter(|span| *span.attr == Effect::Underline.into())
+            .map(|span| span.content)
+            .collect();
+        assert_eq!(underlined, ["t"]);
+
+        // Only the first mnemonic counts.
+        let (label, key) = parse("&A &B");
+        assert_eq!(key, Some('a'));
+        assert_eq!(content(&label), "A B");
+
+        let (label, key) = parse("Tom & Jerry &");
+        assert_eq!(key, None);
+        assert_eq!(content(&label), "Tom & Jerry &");
+    }
+}
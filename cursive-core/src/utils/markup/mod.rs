@@ -5,7 +5,9 @@
 pub mod ansi;
 pub mod cursup;
 pub mod gradient;
+pub mod html;
 pub mod markdown;
+pub mod mnemonic;
 
 use crate::style::Style;
 use crate::utils::span::{IndexedCow, IndexedSpan, Span, SpannedStr, SpannedString, SpannedText};
@@ -127,4 +129,91 @@ impl SpannedString<Style> {
     {
         self.append(Self::styled(text, style));
     }
+
+    /// Adds `style` to the content in the given range.
+    ///
+    /// The range is given in bytes of the displayed content (see
+    /// [`SpannedString::content`]), and the style is combined with the
+    /// existing one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::style::Effect;
+    /// use cursive_core::utils::markup::StyledString;
+    ///
+    /// let mut text = StyledString::plain("Hello world");
+    /// text.style_range(6.., Effect::Bold);
+    ///
+    /// let spans: Vec<_> = text.spans().map(|span| span.content).collect();
+    /// assert_eq!(spans, ["Hello ", "world"]);
+    /// ```
+    pub fn style_range<R, T>(&mut self, range: R, style: T)
+    where
+        R: std::ops::RangeBounds<usize>,
+        T: Into<Style>,
+    {
+        let style = style.into();
+        self.map_range(range, |attr| attr.combine(style));
+    }
+
+    /// Adds `style` to each occurrence of `pattern` in the displayed content.
+    ///
+    /// Occurrences can span several spans. Returns the number of occurrences.
+    ///
+    /// This can be used to highlight search matches in already styled text.
+    pub fn find_and_style<T>(&mut self, pattern: &str, style: T) -> usize
+    where
+        T: Into<Style>,
+    {
+        if pattern.is_empty() {
+            return 0;
+        }
+
+        let style = style.into();
+        let content = self.content();
+        let matches: Vec<usize> = content.match_indices(pattern).map(|(i, _)| i).collect();
+        for &start in &matches {
+            self.style_range(start..start + pattern.len(), style);
+        }
+        matches.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::{ConcreteEffects, Effect, Effects};
+
+    #[test]
+    fn find_and_style() {
+        let mut text = StyledString::styled("one two", Effect::Italic);
+        text.append_plain(" one");
+
+        // Matches can span several spans.
+        assert_eq!(text.find_and_style("o o", Effect::Bold), 1);
+        assert_eq!(text.find_and_style("one", Effect::Underline), 2);
+        assert_eq!(text.find_and_style("", Effect::Underline), 0);
+
+        let spans: Vec<_> = text
+            .spans()
+            .map(|span| (span.content, span.attr.effects))
+            .collect();
+        let italic: ConcreteEffects = Effect::Italic.into();
+        let bold: ConcreteEffects = Effect::Bold.into();
+        let underline: ConcreteEffects = Effect::Underline.into();
+        let expected = [
+            ("one", italic | underline),
+            (" tw", italic),
+            ("o", italic | bold),
+            (" ", bold),
+            ("o", bold | underline),
+            ("ne", underline),
+        ];
+        let expected: Vec<_> = expected
+            .into_iter()
+            .map(|(content, effects)| (content, Effects::from(effects)))
+            .collect();
+        assert_eq!(spans, expected);
+    }
 }
@@ -0,0 +1,74 @@
+//! Matchers to filter items by a typed query.
+//!
+//! A matcher takes a query and a text, and returns the byte offset of each
+//! matched character in the text, or `None` if the text does not match. An
+//! empty query matches everything.
+//!
+//! Both matchers here ignore case. They are used by [`SelectView`] to filter
+//! its items, which accepts any function with the same signature.
+//!
+//! [`SelectView`]: crate::views::SelectView
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cursive_core::utils::matching;
+//!
+//! assert_eq!(matching::substring("ban", "Banana"), Some(vec![0, 1, 2]));
+//! assert_eq!(matching::fuzzy("bna", "Banana"), Some(vec![0, 2, 3]));
+//! assert_eq!(matching::substring("bna", "Banana"), None);
+//! ```
+
+// Case-insensitive comparison of two characters.
+fn same(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+/// Matches texts containing the query.
+///
+/// Returns the offsets of the first occurrence.
+pub fn substring(query: &str, text: &str) -> Option<Vec<usize>> {
+    let len = query.chars().count();
+
+    text.char_indices()
+        .find_map(|(start, _)| {
+            let candidate = text[start..].char_indices().take(len);
+            let offsets: Vec<usize> = candidate
+                .zip(query.chars())
+                .take_while(|&((_, c), q)| same(c, q))
+                .map(|((i, _), _)| start + i)
+                .collect();
+            (offsets.len() == len).then(|| offsets)
+        })
+        .or_else(|| query.is_empty().then(Vec::new))
+}
+
+/// Matches texts containing all the characters of the query, in order.
+///
+/// Each query character is matched with the first possible one in the text.
+pub fn fuzzy(query: &str, text: &str) -> Option<Vec<usize>> {
+    let mut chars = text.char_indices();
+
+    query
+        .chars()
+        .map(|q| chars.find(|&(_, c)| same(c, q)).map(|(i, _)| i))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matchers() {
+        assert_eq!(substring("", "abc"), Some(vec![]));
+        assert_eq!(substring("", ""), Some(vec![]));
+        assert_eq!(substring("NAN", "banana"), Some(vec![2, 3, 4]));
+        assert_eq!(substring("é", "cafÉ"), Some(vec![3]));
+        assert_eq!(substring("x", "banana"), None);
+
+        assert_eq!(fuzzy("", "abc"), Some(vec![]));
+        assert_eq!(fuzzy("sv", "SelectView"), Some(vec![0, 6]));
+        assert_eq!(fuzzy("vs", "SelectView"), None);
+    }
+}
@@ -1,12 +1,21 @@
 //! Toolbox to make text layout easier.
 
 mod counter;
+pub mod draw;
+pub mod image;
 #[macro_use]
 mod immutify;
 pub mod lines;
 pub mod markup;
+pub mod matching;
+pub(crate) mod placeholder;
 mod reader;
 pub mod span;
+pub mod table;
+mod type_ahead;
+mod vi;
 
 pub use self::counter::Counter;
 pub use self::reader::ProgressReader;
+pub use self::type_ahead::TypeAhead;
+pub use self::vi::{ViAction, ViBindings, ViMode};
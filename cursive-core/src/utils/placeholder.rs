@@ -0,0 +1,37 @@
+//! Placeholder text shown by views with no content.
+use crate::align::{HAlign, VAlign};
+use crate::style::Effect;
+use crate::utils::lines::spans::{LinesIterator, Row};
+use crate::utils::markup::StyledString;
+use crate::{Printer, Vec2};
+
+use unicode_width::UnicodeWidthStr;
+
+fn rows(content: &StyledString, width: usize) -> Vec<Row> {
+    LinesIterator::new(content, width).collect()
+}
+
+/// Returns the size needed to show the placeholder within `constraint`.
+pub(crate) fn required_size(content: &StyledString, constraint: Vec2) -> Vec2 {
+    let rows = rows(content, constraint.x);
+    let width = rows.iter().map(|row| row.width).max().unwrap_or(0);
+    Vec2::new(width, rows.len())
+}
+
+/// Draws the placeholder dimmed, at the center of the printer.
+pub(crate) fn draw(printer: &Printer, content: &StyledString) {
+    let rows = rows(content, printer.size.x);
+    let offset = VAlign::Center.get_offset(rows.len(), printer.size.y);
+
+    printer.with_effect(Effect::Dim, |printer| {
+        for (y, row) in rows.iter().enumerate() {
+            let mut x = HAlign::Center.get_offset(row.width, printer.size.x);
+            for span in row.resolve_stream(content) {
+                printer.with_style(*span.attr, |printer| {
+                    printer.print((x, offset + y), span.content);
+                    x += span.content.width();
+                });
+            }
+        }
+    });
+}
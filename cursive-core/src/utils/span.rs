@@ -275,6 +275,10 @@ impl<T> SpannedString<T> {
     }
 
     /// Attemps to reduce the number of spans by merging consecutive similar ones.
+    ///
+    /// Borrowed spans are merged when they are next to each other in the source,
+    /// and owned spans are always merged. Use [`Self::compact`] first to merge
+    /// all similar spans.
     pub fn simplify(&mut self)
     where
         T: PartialEq,
@@ -282,26 +286,109 @@ impl<T> SpannedString<T> {
         // Now, merge consecutive similar spans.
         let mut i = 0;
         while i + 1 < self.spans.len() {
-            let left = &self.spans[i];
-            let right = &self.spans[i + 1];
+            let (left, right) = self.spans.split_at_mut(i + 1);
+            let (left, right) = (&mut left[i], &right[0]);
             if left.attr != right.attr {
                 i += 1;
                 continue;
             }
 
-            let (_, left_end) = left.content.as_borrowed().unwrap();
-            let (right_start, right_end) = right.content.as_borrowed().unwrap();
-            let right_width = right.width;
+            match (&mut left.content, &right.content) {
+                (
+                    IndexedCow::Borrowed { end: left_end, .. },
+                    &IndexedCow::Borrowed {
+                        start: right_start,
+                        end: right_end,
+                    },
+                ) if *left_end == right_start => *left_end = right_end,
+                (IndexedCow::Owned(left), IndexedCow::Owned(content)) => left.push_str(content),
+                _ => {
+                    i += 1;
+                    continue;
+                }
+            }
 
-            if left_end != right_start {
-                i += 1;
+            left.width += right.width;
+            self.spans.remove(i + 1);
+        }
+    }
+
+    /// Returns the text shown by this string: the content of all spans.
+    ///
+    /// Unlike [`Self::source`], this does not include markup that was parsed.
+    pub fn content(&self) -> String {
+        self.spans().map(|span| span.content).collect()
+    }
+
+    /// Replaces the attribute of the content in the given range.
+    ///
+    /// The range is given in bytes of [`Self::content`], not of the source.
+    /// Spans are split at the ends of the range, and `f` gives the new
+    /// attribute of each span inside.
+    ///
+    /// # Panics
+    ///
+    /// If the ends of the range are not on a char boundary.
+    pub fn map_range<R, F>(&mut self, range: R, mut f: F)
+    where
+        R: std::ops::RangeBounds<usize>,
+        F: FnMut(&T) -> T,
+        T: Clone,
+    {
+        use std::ops::Bound;
+
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end + 1,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => usize::MAX,
+        };
+        if start >= end {
+            return;
+        }
+
+        let source = &self.source;
+        let mut spans = Vec::with_capacity(self.spans.len() + 2);
+        let mut offset = 0;
+        for span in std::mem::take(&mut self.spans) {
+            let len = span.content.resolve(source).len();
+            let span_start = offset;
+            offset += len;
+
+            // Part of the span inside the range.
+            let inside = start.clamp(span_start, offset) - span_start
+                ..end.clamp(span_start, offset) - span_start;
+            if inside.is_empty() {
+                spans.push(span);
                 continue;
             }
 
-            *self.spans[i].content.as_borrowed_mut().unwrap().1 = right_end;
-            self.spans[i].width += right_width;
-            self.spans.remove(i + 1);
+            let parts = [
+                (0..inside.start, false),
+                (inside.clone(), true),
+                (inside.end..len, false),
+            ];
+            for (part, mapped) in parts {
+                if part.is_empty() {
+                    continue;
+                }
+                let content = span.content.subcow(part);
+                spans.push(IndexedSpan {
+                    width: content.resolve(source).width(),
+                    content,
+                    attr: if mapped {
+                        f(&span.attr)
+                    } else {
+                        span.attr.clone()
+                    },
+                });
+            }
         }
+        self.spans = spans;
     }
 
     /// Shrink the source to discard any unused suffix.
@@ -387,6 +474,53 @@ impl<T> SpannedString<T> {
         self.spans.drain(range);
     }
 
+    /// Removes the first `n` lines of text.
+    ///
+    /// Each line ends with a `\n`. If there are fewer than `n` of them, the
+    /// entire string is cleared.
+    ///
+    /// Returns the number of spans that were removed, and the number of bytes
+    /// removed from the beginning of the new first span.
+    pub(crate) fn remove_first_lines(&mut self, n: usize) -> (usize, usize) {
+        if n == 0 {
+            return (0, 0);
+        }
+
+        let source = &self.source;
+        let cut = self
+            .spans
+            .iter()
+            .enumerate()
+            .flat_map(|(i, span)| {
+                let content = span.content.resolve(source);
+                content
+                    .match_indices('\n')
+                    .map(move |(pos, _)| (i, pos + 1, pos + 1 == content.len()))
+            })
+            .nth(n - 1);
+
+        let Some((i, offset, span_end)) = cut else {
+            let removed = self.spans.len();
+            self.spans.clear();
+            self.source.clear();
+            return (removed, 0);
+        };
+
+        let (removed, trimmed) = if span_end { (i + 1, 0) } else { (i, offset) };
+
+        self.spans.drain(..removed);
+        if trimmed > 0 {
+            let source = &self.source;
+            let span = &mut self.spans[0];
+            let len = span.content.resolve(source).len();
+            span.content = span.content.subcow(trimmed..len);
+            span.width = span.content.resolve(source).width();
+        }
+        self.trim_start();
+
+        (removed, trimmed)
+    }
+
     /// Iterates on the resolved spans.
     pub fn spans(
         &self,
@@ -733,6 +867,41 @@ mod tests {
     use super::*;
     use crate::style::Style;
 
+    #[test]
+    fn map_range() {
+        let mut text = SpannedString::single_span("Hello", 0);
+        text.append(SpannedString::single_span(String::from("World"), 1));
+
+        // Ranges can cover parts of several spans.
+        text.map_range(3..7, |attr| attr + 10);
+        assert_eq!(text.content(), "HelloWorld");
+
+        let spans: Vec<_> = text
+            .spans()
+            .map(|span| (span.content, *span.attr))
+            .collect();
+        assert_eq!(spans, [("Hel", 0), ("lo", 10), ("Wo", 11), ("rld", 1)]);
+    }
+
+    #[test]
+    fn simplify_owned() {
+        let mut text = SpannedString::with_spans(
+            "",
+            vec![
+                IndexedSpan::simple_owned("a".into(), 0),
+                IndexedSpan::simple_owned("b".into(), 0),
+                IndexedSpan::simple_owned("c".into(), 1),
+            ],
+        );
+        text.simplify();
+
+        let spans: Vec<_> = text
+            .spans()
+            .map(|span| (span.content, *span.attr))
+            .collect();
+        assert_eq!(spans, [("ab", 0), ("c", 1)]);
+    }
+
     #[test]
     fn test_spanned_str_width() {
         let spans = vec![
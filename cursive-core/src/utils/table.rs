@@ -0,0 +1,214 @@
+//! Format styled text into aligned columns.
+//!
+//! This is a lightweight alternative to a full table view, to display
+//! `ls`-style output inside a `TextView`.
+use crate::utils::markup::StyledString;
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Describes how wide a column should be.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum ColumnWidth {
+    /// As wide as the widest cell in the column.
+    #[default]
+    Auto,
+
+    /// Exactly the given width.
+    ///
+    /// Larger cells are truncated.
+    Fixed(usize),
+
+    /// As wide as the widest cell, but no larger than the given width.
+    ///
+    /// Larger cells are truncated.
+    AtMost(usize),
+}
+
+/// Formats rows of cells into aligned columns, separated by two spaces.
+///
+/// Returns one `StyledString` per row. See [`format_columns_with_separator`].
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::utils::table::{format_columns, ColumnWidth};
+///
+/// let rows = vec![
+///     vec!["NAME", "SIZE", "MODIFIED"],
+///     vec!["Cargo.toml", "1.2K", "yesterday"],
+///     vec!["a_very_long_file_name.rs", "12K", "today"],
+/// ];
+///
+/// let lines = format_columns(rows, &[ColumnWidth::AtMost(12)]);
+///
+/// assert_eq!(lines[0].source(), "NAME          SIZE  MODIFIED");
+/// assert_eq!(lines[1].source(), "Cargo.toml    1.2K  yesterday");
+/// assert_eq!(lines[2].source(), "a_very_long…  12K   today");
+/// ```
+pub fn format_columns<I, R, S>(rows: I, widths: &[ColumnWidth]) -> Vec<StyledString>
+where
+    I: IntoIterator<Item = R>,
+    R: IntoIterator<Item = S>,
+    S: Into<StyledString>,
+{
+    format_columns_with_separator(rows, widths, "  ")
+}
+
+/// Formats rows of cells into aligned columns.
+///
+/// * Cells are padded with spaces so columns align, except for the last cell
+///   of each row.
+/// * Cells too large for their column are truncated, ending with `…`.
+/// * `widths[i]` gives the policy for column `i`; columns without a policy
+///   use `ColumnWidth::Auto`.
+/// * Rows can have different numbers of cells.
+///
+/// Styles from each cell are preserved.
+pub fn format_columns_with_separator<I, R, S>(
+    rows: I,
+    widths: &[ColumnWidth],
+    separator: &str,
+) -> Vec<StyledString>
+where
+    I: IntoIterator<Item = R>,
+    R: IntoIterator<Item = S>,
+    S: Into<StyledString>,
+{
+    let rows: Vec<Vec<StyledString>> = rows
+        .into_iter()
+        .map(|row| row.into_iter().map(Into::into).collect())
+        .collect();
+
+    let n_columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+
+    let column_widths: Vec<usize> = (0..n_columns)
+        .map(|column| {
+            let widest = || {
+                rows.iter()
+                    .filter_map(|row| row.get(column))
+                    .map(StyledString::width)
+                    .max()
+                    .unwrap_or(0)
+            };
+
+            match widths.get(column).copied().unwrap_or_default() {
+                ColumnWidth::Auto => widest(),
+                ColumnWidth::Fixed(width) => width,
+                ColumnWidth::AtMost(width) => widest().min(width),
+            }
+        })
+        .collect();
+
+    rows.into_iter()
+        .map(|row| {
+            let n_cells = row.len();
+            let mut line = StyledString::new();
+
+            for (i, (cell, &width)) in row.into_iter().zip(&column_widths).enumerate() {
+                if i > 0 {
+                    line.append_plain(separator);
+                }
+
+                let cell = truncate(cell, width);
+                let padding = width - cell.width();
+
+                line.append(cell);
+
+                if i + 1 < n_cells {
+                    line.append_plain(" ".repeat(padding));
+                }
+            }
+
+            line
+        })
+        .collect()
+}
+
+/// Truncates a styled string to fit in the given width.
+///
+/// If anything is removed, the last visible character is replaced with `…`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use cursive_core::utils::table::truncate;
+/// # use cursive_core::utils::markup::StyledString;
+/// let text = StyledString::plain("Hello world");
+///
+/// assert_eq!(truncate(text.clone(), 20).source(), "Hello world");
+/// assert_eq!(truncate(text, 6).source(), "Hello…");
+/// ```
+pub fn truncate(text: StyledString, width: usize) -> StyledString {
+    if text.width() <= width {
+        return text;
+    }
+
+    let mut result = StyledString::new();
+    if width == 0 {
+        return result;
+    }
+
+    // Keep room for the ellipsis.
+    let mut available = width - 1;
+    let mut last_style = None;
+
+    'spans: for span in text.spans() {
+        last_style = Some(*span.attr);
+
+        let mut end = 0;
+        for (i, grapheme) in span.content.grapheme_indices(true) {
+            let grapheme_width = grapheme.width();
+            if grapheme_width > available {
+                result.append_styled(&span.content[..i], *span.attr);
+                break 'spans;
+            }
+            available -= grapheme_width;
+            end = i + grapheme.len();
+        }
+
+        result.append_styled(&span.content[..end], *span.attr);
+    }
+
+    result.append_styled("…", last_style.unwrap_or_default());
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_columns, format_columns_with_separator, truncate, ColumnWidth};
+    use crate::style::Effect;
+    use crate::utils::markup::StyledString;
+
+    #[test]
+    fn fixed_widths_and_ragged_rows() {
+        let rows = vec![vec!["a", "b", "c"], vec!["dddd"], vec!["e", "ffffff"]];
+
+        let lines =
+            format_columns_with_separator(rows, &[ColumnWidth::Fixed(3), ColumnWidth::Auto], "|");
+
+        let sources: Vec<_> = lines.iter().map(StyledString::source).collect();
+        assert_eq!(sources, ["a  |b     |c", "dd…", "e  |ffffff"]);
+    }
+
+    #[test]
+    fn keeps_styles() {
+        let rows = vec![vec![StyledString::styled("bold", Effect::Bold)]];
+
+        let lines = format_columns(rows, &[ColumnWidth::Fixed(3)]);
+        let spans: Vec<_> = lines[0].spans().collect();
+
+        assert_eq!(spans[0].content, "bo");
+        assert_eq!(*spans[0].attr, Effect::Bold.into());
+        assert_eq!(*spans[1].attr, Effect::Bold.into());
+    }
+
+    #[test]
+    fn truncate_wide_chars() {
+        let text = StyledString::plain("日本語");
+        assert_eq!(truncate(text.clone(), 4).source(), "日…");
+        assert_eq!(truncate(text.clone(), 3).source(), "日…");
+        assert_eq!(truncate(text, 0).source(), "");
+    }
+}
@@ -0,0 +1,194 @@
+use crate::style::PaletteStyle;
+use crate::Printer;
+use std::time::{Duration, Instant};
+use unicode_width::UnicodeWidthStr;
+
+/// Keyboard type-ahead: jumps to items as their label is typed.
+///
+/// Characters typed in quick succession are accumulated into a prefix, used
+/// to find the next matching label. The prefix is reset when no character was
+/// typed for a while (see [`TypeAhead::set_timeout`]).
+///
+/// Repeating the same character cycles through the items starting with it.
+///
+/// This is used by [`SelectView`] and [`ListView`] to implement autojump.
+///
+/// [`SelectView`]: crate::views::SelectView
+/// [`ListView`]: crate::views::ListView
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::utils::TypeAhead;
+///
+/// let labels = ["apple", "apricot", "banana", "blueberry"];
+/// let mut type_ahead = TypeAhead::new();
+///
+/// assert_eq!(type_ahead.on_char('b', 0, labels), Some(2));
+/// assert_eq!(type_ahead.on_char('l', 2, labels), Some(3));
+/// assert_eq!(type_ahead.buffer(), "bl");
+/// ```
+#[derive(Debug, Clone)]
+pub struct TypeAhead {
+    // Lowercase prefix typed so far.
+    buffer: String,
+
+    // When the last character was typed.
+    last_input: Option<Instant>,
+
+    timeout: Duration,
+}
+
+impl Default for TypeAhead {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TypeAhead {
+    /// Creates a new, empty type-ahead buffer.
+    pub fn new() -> Self {
+        TypeAhead {
+            buffer: String::new(),
+            last_input: None,
+            timeout: Duration::from_secs(1),
+        }
+    }
+
+    /// Sets the delay after which the typed prefix is forgotten.
+    ///
+    /// Defaults to 1 second.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Returns the delay after which the typed prefix is forgotten.
+    pub fn get_timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Returns the (lowercase) prefix typed so far.
+    ///
+    /// Returns an empty string if the prefix timed out.
+    pub fn buffer(&self) -> &str {
+        if self.is_expired() {
+            ""
+        } else {
+            &self.buffer
+        }
+    }
+
+    /// Forgets the typed prefix.
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+        self.last_input = None;
+    }
+
+    fn is_expired(&self) -> bool {
+        self.last_input
+            .map_or(true, |last_input| last_input.elapsed() >= self.timeout)
+    }
+
+    /// Adds a character to the prefix and looks for a matching label.
+    ///
+    /// `focus` is the index of the currently selected item, and `labels` lists
+    /// the labels of every item, in order.
+    ///
+    /// Returns the index of the item to select, or `None` if no label matches.
+    /// In that case, `c` is not kept in the prefix.
+    pub fn on_char<'a, I>(&mut self, c: char, focus: usize, labels: I) -> Option<usize>
+    where
+        I: IntoIterator<Item = &'a str>,
+        I::IntoIter: Clone,
+    {
+        if self.is_expired() {
+            self.buffer.clear();
+        }
+
+        let previous_len = self.buffer.len();
+        self.buffer.extend(c.to_lowercase());
+        self.last_input = Some(Instant::now());
+
+        // When the same character is repeated, cycle through the items
+        // starting with it, beginning after the current one.
+        // Otherwise, the current item is still a candidate for the longer prefix.
+        let first = self.buffer.chars().next().unwrap();
+        let (prefix, start) = if self.buffer.chars().all(|c| c == first) {
+            (&self.buffer[..first.len_utf8()], focus + 1)
+        } else {
+            (&self.buffer[..], focus)
+        };
+
+        let labels = labels.into_iter();
+        let found = labels
+            .clone()
+            .enumerate()
+            .skip(start)
+            .chain(labels.enumerate().take(start))
+            .find(|(_, label)| label.to_lowercase().starts_with(prefix))
+            .map(|(i, _)| i);
+
+        if found.is_none() {
+            self.buffer.truncate(previous_len);
+        }
+
+        found
+    }
+
+    /// Prints the typed prefix in the bottom-right corner of the printer.
+    ///
+    /// Does nothing if the prefix is empty or timed out.
+    pub fn draw(&self, printer: &Printer) {
+        let buffer = self.buffer();
+        if buffer.is_empty() || printer.size.y == 0 {
+            return;
+        }
+
+        let x = printer.size.x.saturating_sub(buffer.width());
+        printer.with_style(PaletteStyle::HighlightInactive, |printer| {
+            printer.print((x, printer.size.y - 1), buffer);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LABELS: [&str; 5] = ["Apple", "apricot", "Banana", "blueberry", "Cherry"];
+
+    #[test]
+    fn prefix() {
+        let mut type_ahead = TypeAhead::new();
+
+        assert_eq!(type_ahead.on_char('a', 4, LABELS), Some(0));
+        assert_eq!(type_ahead.on_char('p', 0, LABELS), Some(0));
+        assert_eq!(type_ahead.on_char('r', 0, LABELS), Some(1));
+        assert_eq!(type_ahead.buffer(), "apr");
+
+        // No match: the character is dropped.
+        assert_eq!(type_ahead.on_char('z', 1, LABELS), None);
+        assert_eq!(type_ahead.buffer(), "apr");
+    }
+
+    #[test]
+    fn repeated_char_cycles() {
+        let mut type_ahead = TypeAhead::new();
+
+        assert_eq!(type_ahead.on_char('b', 0, LABELS), Some(2));
+        assert_eq!(type_ahead.on_char('b', 2, LABELS), Some(3));
+        assert_eq!(type_ahead.on_char('b', 3, LABELS), Some(2));
+    }
+
+    #[test]
+    fn timeout() {
+        let mut type_ahead = TypeAhead::new();
+        type_ahead.set_timeout(Duration::ZERO);
+
+        assert_eq!(type_ahead.on_char('b', 0, LABELS), Some(2));
+        assert_eq!(type_ahead.buffer(), "");
+
+        // The previous `b` was forgotten.
+        assert_eq!(type_ahead.on_char('c', 2, LABELS), Some(4));
+    }
+}
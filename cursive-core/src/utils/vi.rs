@@ -0,0 +1,624 @@
+use crate::event::{Event, Key};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Editing mode of a view with vi bindings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ViMode {
+    /// Keys move the cursor and run commands.
+    Normal,
+
+    /// Keys insert text, as without vi bindings.
+    Insert,
+
+    /// Keys move the cursor to extend a selection.
+    Visual,
+}
+
+/// Result of a key given to [`ViBindings`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ViAction {
+    /// The view should handle the event as it would without vi bindings.
+    Pass,
+
+    /// The event is not used; it should be left to the parent view.
+    Ignored,
+
+    /// The event was used, but the content and cursor did not change.
+    Consumed,
+
+    /// The cursor moves to the given byte offset.
+    Move(usize),
+
+    /// The content is replaced, and the cursor moves to the given byte offset.
+    Edit {
+        /// New content.
+        content: String,
+
+        /// New cursor position.
+        cursor: usize,
+    },
+}
+
+// Operator waiting for its motion.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Operator {
+    Delete,
+    Yank,
+}
+
+/// Modal, vi-style editing for text views.
+///
+/// This only tracks the mode and the pending command: for each event, it
+/// tells the view what to do with its content and cursor. This is used by
+/// [`EditView`] and [`TextArea`] with their `with_vi_bindings()` method.
+///
+/// Supported commands in normal mode:
+///
+/// * Movement: `h`, `j`, `k`, `l` (or arrows), `w`, `b`, `0`, `$`.
+/// * Insertion: `i`, `a`, `I`, `A`, `o`, `O`.
+/// * Edition: `x`, `dd`, `yy`, `p`, `P`, and `d`/`y` followed by a movement.
+/// * `v` starts the visual mode, where `d`, `x` and `y` act on the selection.
+///
+/// `Esc` goes back to normal mode.
+///
+/// [`EditView`]: crate::views::EditView
+/// [`TextArea`]: crate::views::TextArea
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::event::Event;
+/// use cursive_core::utils::{ViAction, ViBindings, ViMode};
+///
+/// let mut vi = ViBindings::new();
+/// assert_eq!(vi.mode(), ViMode::Normal);
+///
+/// // `w` moves to the next word.
+/// let action = vi.on_event("hello world", 0, &Event::Char('w'), false);
+/// assert_eq!(action, ViAction::Move(6));
+///
+/// // `dw` deletes it.
+/// vi.on_event("hello world", 0, &Event::Char('d'), false);
+/// let action = vi.on_event("hello world", 0, &Event::Char('w'), false);
+/// assert_eq!(
+///     action,
+///     ViAction::Edit {
+///         content: "world".into(),
+///         cursor: 0
+///     }
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct ViBindings {
+    mode: ViMode,
+
+    // Operator typed in normal mode, waiting for a motion.
+    pending: Option<Operator>,
+
+    // Start of the selection in visual mode.
+    anchor: usize,
+
+    // Last deleted or yanked text.
+    register: String,
+
+    // `true` if the register holds entire lines.
+    linewise: bool,
+}
+
+impl Default for ViBindings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ViBindings {
+    /// Creates new bindings, starting in normal mode.
+    pub fn new() -> Self {
+        ViBindings {
+            mode: ViMode::Normal,
+            pending: None,
+            anchor: 0,
+            register: String::new(),
+            linewise: false,
+        }
+    }
+
+    /// Returns the current mode.
+    pub fn mode(&self) -> ViMode {
+        self.mode
+    }
+
+    /// Returns the text last deleted or yanked.
+    pub fn register(&self) -> &str {
+        &self.register
+    }
+
+    /// Returns the selected byte range in visual mode.
+    ///
+    /// `cursor` is the current cursor position; the selection includes the
+    /// character under the cursor.
+    pub fn selection(&self, content: &str, cursor: usize) -> Option<(usize, usize)> {
+        if self.mode != ViMode::Visual {
+            return None;
+        }
+
+        let start = self.anchor.min(cursor);
+        let end = next_grapheme(content, self.anchor.max(cursor));
+        Some((start, end))
+    }
+
+    /// Processes an event.
+    ///
+    /// `multiline` should be `true` for views holding several lines, where
+    /// `j`, `k`, `o` and line-wise commands apply to the current line rather
+    /// than to the entire content.
+    pub fn on_event(
+        &mut self,
+        content: &str,
+        cursor: usize,
+        event: &Event,
+        multiline: bool,
+    ) -> ViAction {
+        match self.mode {
+            ViMode::Insert => self.on_insert_event(content, cursor, event),
+            ViMode::Normal | ViMode::Visual => {
+                let Some(c) = as_command(event, multiline) else {
+                    return match event {
+                        Event::Key(Key::Esc) if self.pending.is_some() => {
+                            self.pending = None;
+                            ViAction::Consumed
+                        }
+                        Event::Key(Key::Esc) if self.mode == ViMode::Visual => {
+                            self.mode = ViMode::Normal;
+                            ViAction::Consumed
+                        }
+                        // Let the view handle submission, mouse and paste.
+                        Event::Key(Key::Enter) | Event::Mouse { .. } | Event::Paste(_) => {
+                            ViAction::Pass
+                        }
+                        _ => ViAction::Ignored,
+                    };
+                };
+
+                if let Some(operator) = self.pending.take() {
+                    self.on_operator(operator, content, cursor, c, multiline)
+                } else if self.mode == ViMode::Visual {
+                    self.on_visual_command(content, cursor, c, multiline)
+                } else {
+                    self.on_command(content, cursor, c, multiline)
+                }
+            }
+        }
+    }
+
+    fn on_insert_event(&mut self, content: &str, cursor: usize, event: &Event) -> ViAction {
+        if *event != Event::Key(Key::Esc) {
+            return ViAction::Pass;
+        }
+
+        self.mode = ViMode::Normal;
+        // Like vi, step back on the last inserted character.
+        let cursor = if cursor > line_start(content, cursor) {
+            prev_grapheme(content, cursor)
+        } else {
+            cursor
+        };
+        ViAction::Move(cursor)
+    }
+
+    fn on_command(&mut self, content: &str, cursor: usize, c: char, multiline: bool) -> ViAction {
+        let start = line_start(content, cursor);
+        let end = line_end(content, cursor);
+
+        match c {
+            'i' => self.insert_at(cursor),
+            'a' => self.insert_at(if cursor < end {
+                next_grapheme(content, cursor)
+            } else {
+                cursor
+            }),
+            'I' => self.insert_at(start),
+            'A' => self.insert_at(end),
+            'o' | 'O' if multiline => {
+                let at = if c == 'o' { end } else { start };
+                let content = format!("{}\n{}", &content[..at], &content[at..]);
+                self.mode = ViMode::Insert;
+                ViAction::Edit {
+                    content,
+                    cursor: if c == 'o' { at + 1 } else { at },
+                }
+            }
+            'v' => {
+                self.mode = ViMode::Visual;
+                self.anchor = cursor;
+                ViAction::Consumed
+            }
+            'd' => {
+                self.pending = Some(Operator::Delete);
+                ViAction::Consumed
+            }
+            'y' => {
+                self.pending = Some(Operator::Yank);
+                ViAction::Consumed
+            }
+            'x' if cursor < end => {
+                self.delete(content, cursor, next_grapheme(content, cursor), false)
+            }
+            'p' | 'P' if !self.register.is_empty() => self.paste(content, cursor, c == 'p'),
+            c => match motion(content, cursor, c, multiline) {
+                Some(target) => ViAction::Move(clamp(content, target)),
+                None => ViAction::Ignored,
+            },
+        }
+    }
+
+    fn on_visual_command(
+        &mut self,
+        content: &str,
+        cursor: usize,
+        c: char,
+        multiline: bool,
+    ) -> ViAction {
+        let (start, end) = self.selection(content, cursor).unwrap();
+        match c {
+            'v' => {
+                self.mode = ViMode::Normal;
+                ViAction::Consumed
+            }
+            'd' | 'x' => {
+                self.mode = ViMode::Normal;
+                self.delete(content, start, end, false)
+            }
+            'y' => {
+                self.mode = ViMode::Normal;
+                self.yank(&content[start..end], false);
+                ViAction::Move(start)
+            }
+            c => match motion(content, cursor, c, multiline) {
+                Some(target) => ViAction::Move(clamp(content, target)),
+                None => ViAction::Consumed,
+            },
+        }
+    }
+
+    fn on_operator(
+        &mut self,
+        operator: Operator,
+        content: &str,
+        cursor: usize,
+        c: char,
+        multiline: bool,
+    ) -> ViAction {
+        let repeated = matches!(
+            (operator, c),
+            (Operator::Delete, 'd') | (Operator::Yank, 'y')
+        );
+
+        let (start, end, linewise) = if repeated {
+            let (start, end) = if multiline {
+                (line_start(content, cursor), line_end(content, cursor))
+            } else {
+                (0, content.len())
+            };
+            (start, end, multiline)
+        } else {
+            let Some(target) = motion(content, cursor, c, multiline) else {
+                // Unknown motion: cancel the command.
+                return ViAction::Consumed;
+            };
+
+            // Like vi, `dw` on the last word of a line stops at the end of the line.
+            let target = if c == 'w' {
+                target.min(line_end(content, cursor))
+            } else {
+                target
+            };
+
+            // `$` includes the last character.
+            let target = if c == '$' {
+                line_end(content, cursor)
+            } else {
+                target
+            };
+            (cursor.min(target), cursor.max(target), false)
+        };
+
+        match operator {
+            Operator::Delete => self.delete(content, start, end, linewise),
+            Operator::Yank => {
+                self.yank(&content[start..end], linewise);
+                if linewise {
+                    ViAction::Consumed
+                } else {
+                    ViAction::Move(start)
+                }
+            }
+        }
+    }
+
+    fn insert_at(&mut self, cursor: usize) -> ViAction {
+        self.mode = ViMode::Insert;
+        ViAction::Move(cursor)
+    }
+
+    fn yank(&mut self, text: &str, linewise: bool) {
+        self.register = text.to_string();
+        self.linewise = linewise;
+    }
+
+    // Removes `start..end`, storing it in the register.
+    //
+    // For a line-wise deletion, `start..end` is the line without its newline.
+    fn delete(&mut self, content: &str, start: usize, end: usize, linewise: bool) -> ViAction {
+        self.yank(&content[start..end], linewise);
+
+        let (start, end) = if !linewise {
+            (start, end)
+        } else if end < content.len() {
+            // Remove the newline after the line...
+            (start, end + 1)
+        } else {
+            // ... or before it, for the last line.
+            (start.saturating_sub(1), end)
+        };
+
+        let content = format!("{}{}", &content[..start], &content[end..]);
+        let cursor = if linewise {
+            line_start(&content, start.min(content.len()))
+        } else {
+            start
+        };
+        let cursor = clamp(&content, cursor);
+        ViAction::Edit { content, cursor }
+    }
+
+    fn paste(&mut self, content: &str, cursor: usize, after: bool) -> ViAction {
+        let (content, cursor) = if self.linewise {
+            if after {
+                let at = line_end(content, cursor);
+                let new = format!("{}\n{}{}", &content[..at], self.register, &content[at..]);
+                (new, at + 1)
+            } else {
+                let at = line_start(content, cursor);
+                let new = format!("{}{}\n{}", &content[..at], self.register, &content[at..]);
+                (new, at)
+            }
+        } else {
+            let at = if after && cursor < line_end(content, cursor) {
+                next_grapheme(content, cursor)
+            } else {
+                cursor
+            };
+            let new = format!("{}{}{}", &content[..at], self.register, &content[at..]);
+            // The cursor ends on the last pasted character.
+            let cursor = prev_grapheme(&new, at + self.register.len());
+            (new, cursor)
+        };
+        ViAction::Edit { content, cursor }
+    }
+}
+
+// Returns the character for a normal mode command.
+fn as_command(event: &Event, multiline: bool) -> Option<char> {
+    match *event {
+        Event::Char(c) => Some(c),
+        Event::Key(Key::Left) => Some('h'),
+        Event::Key(Key::Right) => Some('l'),
+        Event::Key(Key::Down) => Some('j'),
+        Event::Key(Key::Up) => Some('k'),
+        Event::Key(Key::Home) => Some('0'),
+        Event::Key(Key::End) => Some('$'),
+        Event::Key(Key::Enter) if multiline => Some('j'),
+        Event::Key(Key::Del) => Some('x'),
+        _ => None,
+    }
+}
+
+// Returns the target of a movement command.
+fn motion(content: &str, cursor: usize, c: char, multiline: bool) -> Option<usize> {
+    let start = line_start(content, cursor);
+    let end = line_end(content, cursor);
+
+    match c {
+        'h' if cursor > start => Some(prev_grapheme(content, cursor)),
+        'l' if next_grapheme(content, cursor) < end => Some(next_grapheme(content, cursor)),
+        'h' | 'l' => Some(cursor),
+        '0' => Some(start),
+        '$' => Some(end),
+        'w' => Some(next_word(content, cursor)),
+        'b' => Some(prev_word(content, cursor)),
+        'j' if multiline && end < content.len() => {
+            Some(column(content, end + 1, graphemes(&content[start..cursor])))
+        }
+        'k' if multiline && start > 0 => {
+            let prev = line_start(content, start - 1);
+            Some(column(content, prev, graphemes(&content[start..cursor])))
+        }
+        _ => None,
+    }
+}
+
+// Returns the offset of the `col`-th grapheme of the line starting at `start`.
+fn column(content: &str, start: usize, col: usize) -> usize {
+    let end = line_end(content, start);
+    content[start..end]
+        .grapheme_indices(true)
+        .nth(col)
+        .map_or(end, |(i, _)| start + i)
+}
+
+fn graphemes(text: &str) -> usize {
+    text.graphemes(true).count()
+}
+
+// Classes of characters, words stop when the class changes.
+#[derive(PartialEq, Eq)]
+enum Class {
+    Space,
+    Word,
+    Punctuation,
+}
+
+fn class(grapheme: &str) -> Class {
+    match grapheme.chars().next() {
+        Some(c) if c.is_whitespace() => Class::Space,
+        Some(c) if c.is_alphanumeric() || c == '_' => Class::Word,
+        _ => Class::Punctuation,
+    }
+}
+
+// Start of the next word.
+fn next_word(content: &str, cursor: usize) -> usize {
+    let mut graphemes = content[cursor..]
+        .grapheme_indices(true)
+        .map(|(i, g)| (cursor + i, class(g)));
+
+    let Some((_, first)) = graphemes.next() else {
+        return cursor;
+    };
+
+    graphemes
+        .skip_while(|(_, class)| *class == first && first != Class::Space)
+        .find(|(_, class)| *class != Class::Space)
+        .map_or(content.len(), |(i, _)| i)
+}
+
+// Start of the current or previous word.
+fn prev_word(content: &str, cursor: usize) -> usize {
+    let mut graphemes = content[..cursor]
+        .grapheme_indices(true)
+        .rev()
+        .map(|(i, g)| (i, class(g)))
+        .skip_while(|(_, class)| *class == Class::Space);
+
+    let Some((mut start, word)) = graphemes.next() else {
+        return 0;
+    };
+
+    for (i, class) in graphemes {
+        if class != word {
+            break;
+        }
+        start = i;
+    }
+    start
+}
+
+fn line_start(content: &str, cursor: usize) -> usize {
+    content[..cursor].rfind('\n').map_or(0, |i| i + 1)
+}
+
+fn line_end(content: &str, cursor: usize) -> usize {
+    content[cursor..]
+        .find('\n')
+        .map_or(content.len(), |i| cursor + i)
+}
+
+fn next_grapheme(content: &str, cursor: usize) -> usize {
+    cursor + content[cursor..].graphemes(true).next().map_or(0, str::len)
+}
+
+fn prev_grapheme(content: &str, cursor: usize) -> usize {
+    cursor
+        - content[..cursor]
+            .graphemes(true)
+            .next_back()
+            .map_or(0, str::len)
+}
+
+// In normal mode, the cursor stays on a character: not past the end of a line.
+fn clamp(content: &str, cursor: usize) -> usize {
+    let start = line_start(content, cursor);
+    if cursor > start && cursor == line_end(content, cursor) {
+        prev_grapheme(content, cursor)
+    } else {
+        cursor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Feeds the keys, applying edits, and returns the content and cursor.
+    fn run(content: &str, cursor: usize, keys: &str, multiline: bool) -> (String, usize) {
+        let mut vi = ViBindings::new();
+        let mut content = content.to_string();
+        let mut cursor = cursor;
+        for c in keys.chars() {
+            let event = match c {
+                '\x1b' => Event::Key(Key::Esc),
+                c => Event::Char(c),
+            };
+            match vi.on_event(&content, cursor, &event, multiline) {
+                ViAction::Pass => {
+                    content.insert(cursor, c);
+                    cursor += c.len_utf8();
+                }
+                ViAction::Move(c) => cursor = c,
+                ViAction::Edit {
+                    content: new,
+                    cursor: c,
+                } => {
+                    content = new;
+                    cursor = c;
+                }
+                ViAction::Ignored | ViAction::Consumed => (),
+            }
+        }
+        (content, cursor)
+    }
+
+    #[test]
+    fn motions() {
+        let text = "foo.bar  baz";
+        assert_eq!(run(text, 0, "w", false).1, 3);
+        assert_eq!(run(text, 0, "ww", false).1, 4);
+        assert_eq!(run(text, 0, "www", false).1, 9);
+        assert_eq!(run(text, 9, "b", false).1, 4);
+        assert_eq!(run(text, 9, "bb", false).1, 3);
+        assert_eq!(run(text, 0, "$", false).1, 11);
+        assert_eq!(run(text, 5, "0", false).1, 0);
+        assert_eq!(run(text, 0, "hl", false).1, 1);
+
+        let text = "first\nab\nlast";
+        assert_eq!(run(text, 4, "j", true).1, 7);
+        assert_eq!(run(text, 4, "jj", true).1, 10);
+        assert_eq!(run(text, 4, "jjk", true).1, 7);
+    }
+
+    #[test]
+    fn edits() {
+        assert_eq!(run("hello big world", 6, "dw", false).0, "hello world");
+        assert_eq!(run("hello world", 6, "dw", false).0, "hello ");
+        assert_eq!(run("hello", 0, "xx", false), ("llo".into(), 0));
+        assert_eq!(run("hello", 4, "a!\x1b", false), ("hello!".into(), 5));
+        assert_eq!(run("one", 0, "yyp", false), ("oonene".into(), 3));
+        assert_eq!(run("hello", 0, "dd", false), (String::new(), 0));
+
+        let text = "one\ntwo\nthree";
+        assert_eq!(run(text, 5, "dd", true), ("one\nthree".into(), 4));
+        assert_eq!(run(text, 9, "dd", true), ("one\ntwo".into(), 4));
+        assert_eq!(
+            run(text, 0, "yyjp", true),
+            ("one\ntwo\none\nthree".into(), 8)
+        );
+        assert_eq!(run(text, 0, "ddp", true), ("two\none\nthree".into(), 4));
+        assert_eq!(
+            run(text, 0, "onew\x1b", true),
+            ("one\nnew\ntwo\nthree".into(), 6)
+        );
+    }
+
+    #[test]
+    fn visual() {
+        let mut vi = ViBindings::new();
+        vi.on_event("hello world", 0, &Event::Char('v'), false);
+        assert_eq!(vi.mode(), ViMode::Visual);
+        assert_eq!(vi.selection("hello world", 4), Some((0, 5)));
+
+        assert_eq!(run("hello world", 3, "vwy", false).1, 3);
+        assert_eq!(run("hello world", 0, "vwd", false).0, "orld");
+        assert_eq!(run("hello world", 0, "vll\x1bx", false).0, "helo world");
+    }
+}
@@ -289,6 +289,26 @@ impl<T: Ord> XY<T> {
         a.zip_map(b, min)
     }
 
+    /// Restricts each coordinate between `min` and `max`.
+    ///
+    /// # Panics
+    ///
+    /// If `min` is greater than `max` in any axis.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Vec2;
+    /// let v = Vec2::new(1, 12);
+    /// assert_eq!(v.clamp((2, 2), (10, 10)), Vec2::new(2, 10));
+    /// ```
+    #[must_use]
+    pub fn clamp<A: Into<XY<T>>, B: Into<XY<T>>>(self, min: A, max: B) -> Self {
+        let min = min.into();
+        let max = max.into();
+        self.zip3(min, max).map(|(v, min, max)| v.clamp(min, max))
+    }
+
     /// Returns the minimum of `self` and `other`.
     ///
     /// This is equivalent to `Vec2::min(self, other)`.
@@ -11,6 +11,9 @@ pub trait AnyView {
     /// Downcast self to a mutable `Any`.
     fn as_any_mut(&mut self) -> &mut dyn Any;
 
+    /// Upcast self to a mutable `View`.
+    fn as_view_mut(&mut self) -> &mut dyn View;
+
     /// Returns a boxed any from a boxed self.
     ///
     /// Can be used before `Box::downcast()`.
@@ -37,6 +40,11 @@ impl<T: View> AnyView for T {
         self
     }
 
+    /// Upcast self to a mutable `View`.
+    fn as_view_mut(&mut self) -> &mut dyn View {
+        self
+    }
+
     fn as_boxed_any(self: Box<Self>) -> Box<dyn Any> {
         self
     }
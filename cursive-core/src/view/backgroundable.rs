@@ -0,0 +1,15 @@
+use crate::style::ColorType;
+use crate::view::View;
+use crate::views::BackgroundView;
+
+/// Makes a view wrappable in a [`BackgroundView`].
+///
+/// [`BackgroundView`]: crate::views::BackgroundView
+pub trait Backgroundable: View + Sized {
+    /// Wraps `self` in a `BackgroundView` with the given color.
+    fn with_background<C: Into<ColorType>>(self, color: C) -> BackgroundView<Self> {
+        BackgroundView::new(self, color)
+    }
+}
+
+impl<T: View> Backgroundable for T {}
@@ -1,3 +1,4 @@
+use crate::event::AnyCb;
 use crate::view::{View, ViewWrapper};
 use crate::views::{BoxedView, NamedView, ViewRef};
 
@@ -60,6 +61,17 @@ impl<T: View> Finder for T {
         V: View,
         F: FnMut(&mut V),
     {
+        if let Selector::Focused = sel {
+            // Named views are also visited through their content, so only
+            // look for the view itself.
+            call_on_focus_path(self, &mut |v| {
+                if let Some(v) = v.downcast_mut::<V>() {
+                    callback(v);
+                }
+            });
+            return;
+        }
+
         self.call_on_any(sel, &mut |v: &mut dyn View| {
             if let Some(v) = v.downcast_mut::<V>() {
                 // Allow to select the view directly.
@@ -83,4 +95,27 @@ impl<T: View> Finder for T {
 pub enum Selector<'a> {
     /// Selects a view from its name.
     Name(&'a str),
+
+    /// Selects views whose name matches the given predicate.
+    ///
+    /// Unlike `Name`, this keeps looking inside matching views, so nested
+    /// named views can be found as well.
+    NameMatches(&'a dyn Fn(&str) -> bool),
+
+    /// Selects the views along the focus path.
+    ///
+    /// Views are visited from the focused one up to the root, so `call_on`
+    /// finds the focused view, or its closest ancestor of the asked type.
+    ///
+    /// This is handled by [`Finder`]: [`View::call_on_any`] does not look
+    /// for it.
+    Focused,
+}
+
+/// Runs `callback` on each view along the focus path of `view`.
+///
+/// Starts from the focused view and ends with `view` itself.
+pub(crate) fn call_on_focus_path(view: &mut dyn View, callback: AnyCb) {
+    view.call_on_focused_child(&mut |child| call_on_focus_path(child, callback));
+    callback(view);
 }
@@ -95,6 +95,7 @@ mod view_trait;
 pub mod scroll;
 
 // Helper bases
+mod backgroundable;
 mod into_boxed_view;
 mod nameable;
 mod resizable;
@@ -104,6 +105,8 @@ mod scrollable;
 mod scroll_base;
 
 pub use self::any::AnyView;
+pub use self::backgroundable::Backgroundable;
+pub(crate) use self::finder::call_on_focus_path;
 pub use self::finder::{Finder, Selector};
 pub use self::into_boxed_view::IntoBoxedView;
 pub use self::margins::Margins;
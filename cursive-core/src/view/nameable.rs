@@ -39,6 +39,15 @@ pub trait Nameable: View + Sized {
     fn with_name<S: Into<String>>(self, name: S) -> NamedView<Self> {
         NamedView::new(name, self)
     }
+
+    /// Wraps this view into an `NamedView` with a generated unique name.
+    ///
+    /// This is just a shortcut for `NamedView::new_unique(self)`.
+    ///
+    /// The generated name can be retrieved with [`NamedView::name`].
+    fn with_unique_name(self) -> NamedView<Self> {
+        NamedView::new_unique(self)
+    }
 }
 
 /// Any `View` implements this trait.
@@ -1,12 +1,18 @@
 use std::cmp::min;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+use std::time::{Duration, Instant};
+use unicode_width::UnicodeWidthStr;
 
 use crate::{
     direction::Orientation,
     event::{AnyCb, Event},
     printer::Printer,
     rect::Rect,
-    style::Style,
-    view::{ScrollStrategy, Selector, SizeCache, ViewNotFound},
+    view::{
+        scroll::{keys::MarkCommand, Easing, ScrollIndicator, ScrollKeys, ScrollStyle},
+        ScrollStrategy, Selector, SizeCache, ViewNotFound,
+    },
     with::With,
     Vec2, XY,
 };
@@ -94,6 +100,9 @@ pub struct Core {
     /// scrollbar_padding.x is the horizontal padding before the vertical scrollbar.
     scrollbar_padding: Vec2,
 
+    /// How much should the mouse wheel scroll in each direction?
+    scroll_step: Vec2,
+
     /// Initial position of the cursor when dragging.
     thumb_grab: Option<(Orientation, usize)>,
 
@@ -102,6 +111,49 @@ pub struct Core {
 
     /// Defines how to update the offset when the view size changes.
     scroll_strategy: ScrollStrategy,
+
+    /// Should page scrolling and scrolling to the focus be animated?
+    smooth_scroll: bool,
+
+    /// Easing curve for the animation.
+    easing: Easing,
+
+    /// How long should the animation last?
+    animation_duration: Duration,
+
+    /// Offset and time at the start of the current animation.
+    ///
+    /// The animation ends at `offset`.
+    animation: Option<(Vec2, Instant)>,
+
+    /// Style overriding the theme for the scrollbars.
+    scroll_style: Option<ScrollStyle>,
+
+    /// Scrollbar thickness from the theme, as seen during the last draw.
+    ///
+    /// The theme is not available during layout, so we remember it here.
+    theme_thickness: AtomicUsize,
+
+    /// Scrollbar thickness used during the last layout.
+    thickness: usize,
+
+    /// When the user last scrolled, used to auto-hide scrollbars.
+    last_scroll: Option<Instant>,
+
+    /// Optional overlay showing the scroll position.
+    indicator: Option<ScrollIndicator>,
+
+    /// Optional keyboard shortcuts.
+    scroll_keys: ScrollKeys,
+
+    /// Should scroll events go to outer scrollers once we reach an edge?
+    scroll_chaining: bool,
+
+    /// Positions saved with vi-style marks.
+    marks: HashMap<char, Vec2>,
+
+    /// Mark command waiting for its character.
+    pending_mark: Option<MarkCommand>,
 }
 
 impl Default for Core {
@@ -120,9 +172,23 @@ impl Core {
             enabled: XY::new(false, true),
             show_scrollbars: true,
             scrollbar_padding: Vec2::new(1, 0),
+            scroll_step: Vec2::new(3, 3),
             thumb_grab: None,
             size_cache: None,
             scroll_strategy: ScrollStrategy::KeepRow,
+            smooth_scroll: false,
+            easing: Easing::default(),
+            animation_duration: Duration::from_millis(200),
+            animation: None,
+            scroll_style: None,
+            theme_thickness: AtomicUsize::new(1),
+            thickness: 1,
+            last_scroll: None,
+            indicator: None,
+            scroll_keys: ScrollKeys::default(),
+            scroll_chaining: true,
+            marks: HashMap::new(),
+            pending_mark: None,
         }
     }
 
@@ -132,57 +198,77 @@ impl Core {
 
         let size = self.last_available_size();
 
+        let style = match self.scroll_style {
+            Some(ref style) => style,
+            None => {
+                let style = &printer.theme.scrollbar;
+                self.theme_thickness
+                    .store(style.thickness.max(1), AtomicOrdering::Relaxed);
+                style
+            }
+        };
+
         // Draw the scrollbars
-        if self.get_show_scrollbars() {
+        if self.get_show_scrollbars() && self.scrollbars_visible(style) {
             let scrolling = self.is_scrolling();
+            let thickness = self.thickness;
 
             let lengths = self.scrollbar_thumb_lengths();
             let offsets = self.scrollbar_thumb_offsets(lengths);
 
-            let line_c = XY::new("-", "|");
+            let track = XY::new(&style.horizontal_track, &style.vertical_track);
 
-            let style = if printer.focused {
-                Style::highlight()
+            let thumb_style = if printer.focused {
+                style.thumb_style
             } else {
-                Style::highlight_inactive()
+                style.thumb_inactive_style
             };
 
-            XY::zip5(lengths, offsets, size, line_c, Orientation::pair()).run_if(
+            XY::zip5(lengths, offsets, size, track, Orientation::pair()).run_if(
                 scrolling,
-                |(length, offset, size, c, orientation)| {
-                    let start = printer
-                        .size
-                        .saturating_sub((1, 1))
-                        .with_axis(orientation, 0);
-                    let offset = orientation.make_vec(offset, 0);
-
-                    printer.print_line(orientation, start, size, c);
-
-                    let thumb_c = if self
+                |(length, offset, size, track, orientation)| {
+                    let thumb = if self
                         .thumb_grab
                         .map(|(o, _)| o == orientation)
                         .unwrap_or(false)
                     {
-                        " "
+                        &style.thumb_grabbed
                     } else {
-                        "▒"
+                        &style.thumb
                     };
-                    printer.with_style(style, |printer| {
-                        printer.print_line(orientation, start + offset, length, thumb_c);
-                    });
+                    let offset = orientation.make_vec(offset, 0);
+
+                    for i in (1..=thickness).rev() {
+                        let start = printer
+                            .size
+                            .saturating_sub((i, i))
+                            .with_axis(orientation, 0);
+
+                        printer.with_style(style.track_style, |printer| {
+                            printer.print_line(orientation, start, size, track);
+                        });
+                        printer.with_style(thumb_style, |printer| {
+                            printer.print_line(orientation, start + offset, length, thumb);
+                        });
+                    }
                 },
             );
 
-            // Draw the X between the two scrollbars.
+            // Draw the corner between the two scrollbars.
             if scrolling.both() {
-                printer.print(printer.size.saturating_sub((1, 1)), "╳");
+                let corner = printer.size.saturating_sub((thickness, thickness));
+                printer.with_style(style.track_style, |printer| {
+                    for y in 0..thickness {
+                        printer.print_hline(corner + (0, y), thickness, &style.corner);
+                    }
+                });
             }
         }
 
         // Draw content
         printer
             .cropped(size)
-            .content_offset(self.offset)
+            .content_offset(self.visible_offset())
             .inner_size(self.inner_size)
     }
 
@@ -201,7 +287,7 @@ impl Core {
                 .checked_sub(offset)
                 .map(|p| p.fits_in(self.last_available_size()))
                 .unwrap_or(false);
-            *position = *position + self.offset;
+            *position = *position + self.visible_offset();
             inside
         } else {
             // For key events, assume it's inside by default.
@@ -211,11 +297,82 @@ impl Core {
 
     /// Specifies the size given in a layout phase.
     pub(crate) fn set_last_size(&mut self, last_size: Vec2, scrolling: XY<bool>) {
+        self.thickness = self.target_thickness();
         self.last_available = last_size.saturating_sub(
             scrolling
                 .swap()
-                .select_or(self.scrollbar_padding + (1, 1), Vec2::zero()),
+                .select_or(self.scrollbar_footprint(), Vec2::zero()),
+        );
+    }
+
+    /// Returns the space taken by a scrollbar, including padding.
+    ///
+    /// `x` is the width of the vertical scrollbar, `y` the height of the horizontal one.
+    pub(crate) fn scrollbar_footprint(&self) -> Vec2 {
+        let thickness = self.target_thickness();
+        self.scrollbar_padding + (thickness, thickness)
+    }
+
+    // Thickness the scrollbars should have on the next layout.
+    fn target_thickness(&self) -> usize {
+        match self.scroll_style {
+            Some(ref style) => style.thickness.max(1),
+            None => self.theme_thickness.load(AtomicOrdering::Relaxed),
+        }
+    }
+
+    /// Records that the user just scrolled.
+    ///
+    /// Auto-hiding scrollbars are shown for a while after this.
+    pub(crate) fn mark_scrolled(&mut self) {
+        self.last_scroll = Some(Instant::now());
+    }
+
+    fn scrollbars_visible(&self, style: &ScrollStyle) -> bool {
+        self.recently_scrolled(style.auto_hide)
+    }
+
+    // Returns `true` if the user scrolled less than `delay` ago.
+    //
+    // Always `true` without a delay.
+    fn recently_scrolled(&self, delay: Option<Duration>) -> bool {
+        let Some(delay) = delay else {
+            return true;
+        };
+
+        self.thumb_grab.is_some()
+            || self.is_animating()
+            || self
+                .last_scroll
+                .map_or(false, |last_scroll| last_scroll.elapsed() < delay)
+    }
+
+    /// Draws the scroll position indicator, if any, on top of the content.
+    ///
+    /// `printer` should be the one given to `sub_printer`.
+    pub(crate) fn draw_indicator(&self, printer: &Printer) {
+        let Some(ref indicator) = self.indicator else {
+            return;
+        };
+        if !self.recently_scrolled(indicator.auto_hide) {
+            return;
+        }
+
+        let size = self.last_available_size();
+        let Some(text) = indicator.text(self.visible_offset().y, size.y, self.inner_size.y) else {
+            return;
+        };
+
+        let width = text.width().min(size.x);
+        let position = Vec2::new(
+            indicator.align.h.get_offset(width, size.x),
+            indicator.align.v.get_offset(1, size.y),
         );
+        printer
+            .cropped(size)
+            .with_style(indicator.style, |printer| {
+                printer.print(position, &text);
+            });
     }
 
     /// Specifies the size allocated to the content.
@@ -243,7 +400,7 @@ impl Core {
     ///
     /// Even if this returns `false`, the content itself might still needs to relayout.
     pub fn needs_relayout(&self) -> bool {
-        self.size_cache.is_none()
+        self.size_cache.is_none() || self.target_thickness() != self.thickness
     }
 
     /// Performs `View::call_on_any()`
@@ -271,6 +428,100 @@ impl Core {
         Rect::from_size(self.offset, self.last_available_size())
     }
 
+    /// Sets the optional keyboard shortcuts.
+    ///
+    /// By default, none of them is enabled.
+    pub fn set_scroll_keys(&mut self, keys: ScrollKeys) {
+        if !keys.marks {
+            self.pending_mark = None;
+        }
+        self.scroll_keys = keys;
+    }
+
+    /// Sets the optional keyboard shortcuts.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn scroll_keys(self, keys: ScrollKeys) -> Self {
+        self.with(|s| s.set_scroll_keys(keys))
+    }
+
+    /// Returns the optional keyboard shortcuts.
+    pub fn get_scroll_keys(&self) -> &ScrollKeys {
+        &self.scroll_keys
+    }
+
+    /// Sets whether scroll events can reach outer scrollers.
+    ///
+    /// Mouse wheel and page events are first handled by the innermost scroller.
+    /// With chaining enabled (the default), they are passed on to the outer
+    /// scroller once the inner one reaches an edge in that direction.
+    ///
+    /// With chaining disabled, they are consumed even at the edge, so nested
+    /// scroll areas do not move their parent. Arrow keys are still passed on,
+    /// so the focus can leave the view.
+    pub fn set_scroll_chaining(&mut self, scroll_chaining: bool) {
+        self.scroll_chaining = scroll_chaining;
+    }
+
+    /// Sets whether scroll events can reach outer scrollers.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn scroll_chaining(self, scroll_chaining: bool) -> Self {
+        self.with(|s| s.set_scroll_chaining(scroll_chaining))
+    }
+
+    /// Returns `true` if scroll events can reach outer scrollers.
+    pub fn get_scroll_chaining(&self) -> bool {
+        self.scroll_chaining
+    }
+
+    /// Returns the number of rows scrolled by `PageUp` and `PageDown`.
+    ///
+    /// This is the page size set in [`ScrollKeys`], or the height of the viewport.
+    pub fn page_size(&self) -> usize {
+        self.scroll_keys.page_size.unwrap_or(self.last_available.y)
+    }
+
+    /// Saves the current position under the given mark.
+    pub fn set_mark(&mut self, mark: char) {
+        self.marks.insert(mark, self.offset);
+    }
+
+    /// Returns the position saved under the given mark, if any.
+    pub fn get_mark(&self, mark: char) -> Option<Vec2> {
+        self.marks.get(&mark).copied()
+    }
+
+    /// Scrolls to the position saved under the given mark.
+    ///
+    /// The current position is saved under the `'` mark.
+    ///
+    /// Returns `false` if there is no such mark.
+    pub fn jump_to_mark(&mut self, mark: char) -> bool {
+        let Some(target) = self.get_mark(mark) else {
+            return false;
+        };
+
+        self.marks.insert('\'', self.offset);
+        self.set_offset(target);
+        true
+    }
+
+    /// Forgets all saved marks.
+    pub fn clear_marks(&mut self) {
+        self.marks.clear();
+    }
+
+    pub(crate) fn set_pending_mark(&mut self, command: MarkCommand) {
+        self.pending_mark = Some(command);
+    }
+
+    pub(crate) fn take_pending_mark(&mut self) -> Option<MarkCommand> {
+        self.pending_mark.take()
+    }
+
     /// Defines the way scrolling is adjusted on content or size change.
     ///
     /// The scroll strategy defines how the scrolling position is adjusted
@@ -283,6 +534,62 @@ impl Core {
         self.adjust_scroll();
     }
 
+    /// Sets the style used to draw the scrollbars, overriding the theme.
+    pub fn set_scroll_style(&mut self, style: ScrollStyle) {
+        self.scroll_style = Some(style);
+        self.invalidate_cache();
+    }
+
+    /// Sets the style used to draw the scrollbars, overriding the theme.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn scroll_style(self, style: ScrollStyle) -> Self {
+        self.with(|s| s.set_scroll_style(style))
+    }
+
+    /// Uses the theme's style to draw the scrollbars.
+    ///
+    /// This is the default.
+    pub fn reset_scroll_style(&mut self) {
+        self.scroll_style = None;
+        self.invalidate_cache();
+    }
+
+    /// Returns the style used to draw the scrollbars, if it overrides the theme.
+    pub fn get_scroll_style(&self) -> Option<&ScrollStyle> {
+        self.scroll_style.as_ref()
+    }
+
+    /// Shows an overlay with the scroll position.
+    ///
+    /// Defaults to `None`.
+    ///
+    /// See [`ScrollIndicator`] for the available options.
+    pub fn set_indicator(&mut self, indicator: ScrollIndicator) {
+        self.indicator = Some(indicator);
+    }
+
+    /// Shows an overlay with the scroll position.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn indicator(self, indicator: ScrollIndicator) -> Self {
+        self.with(|s| s.set_indicator(indicator))
+    }
+
+    /// Removes the scroll position indicator.
+    ///
+    /// This is the default.
+    pub fn remove_indicator(&mut self) {
+        self.indicator = None;
+    }
+
+    /// Returns the scroll position indicator, if any.
+    pub fn get_indicator(&self) -> Option<&ScrollIndicator> {
+        self.indicator.as_ref()
+    }
+
     /// Defines the way scrolling is adjusted on content or size change.
     ///
     /// Chainable variant.
@@ -309,6 +616,143 @@ impl Core {
         self.scrollbar_padding
     }
 
+    /// Sets the number of cells scrolled by each mouse wheel event.
+    ///
+    /// `scroll_step.x` is used for horizontal scrolling, `scroll_step.y` for vertical scrolling.
+    ///
+    /// Defaults to `(3, 3)`.
+    pub fn set_scroll_step<V: Into<Vec2>>(&mut self, scroll_step: V) {
+        self.scroll_step = scroll_step.into();
+    }
+
+    /// Sets the number of cells scrolled by each mouse wheel event.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn scroll_step<V: Into<Vec2>>(self, scroll_step: V) -> Self {
+        self.with(|s| s.set_scroll_step(scroll_step))
+    }
+
+    /// Returns the number of cells scrolled by each mouse wheel event.
+    pub fn get_scroll_step(&self) -> Vec2 {
+        self.scroll_step
+    }
+
+    /// Controls whether scrolling by pages or to the focused element is animated.
+    ///
+    /// When enabled, these transitions are interpolated over a short duration
+    /// (see [`Core::set_animation_duration`]). Frames are only drawn when
+    /// something happens, so this requires an auto-refresh to be set with
+    /// `Cursive::set_fps` to look smooth.
+    ///
+    /// Defaults to `false`.
+    pub fn set_smooth_scroll(&mut self, smooth_scroll: bool) {
+        self.smooth_scroll = smooth_scroll;
+        if !smooth_scroll {
+            self.stop_animation();
+        }
+    }
+
+    /// Controls whether scrolling by pages or to the focused element is animated.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn smooth_scroll(self, smooth_scroll: bool) -> Self {
+        self.with(|s| s.set_smooth_scroll(smooth_scroll))
+    }
+
+    /// Returns `true` if smooth scrolling is enabled.
+    pub fn is_smooth_scroll(&self) -> bool {
+        self.smooth_scroll
+    }
+
+    /// Sets the easing curve used by smooth scrolling.
+    ///
+    /// Defaults to [`Easing::EaseOut`].
+    pub fn set_easing(&mut self, easing: Easing) {
+        self.easing = easing;
+    }
+
+    /// Sets the easing curve used by smooth scrolling.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn easing(self, easing: Easing) -> Self {
+        self.with(|s| s.set_easing(easing))
+    }
+
+    /// Returns the easing curve used by smooth scrolling.
+    pub fn get_easing(&self) -> Easing {
+        self.easing
+    }
+
+    /// Sets how long a smooth scrolling animation lasts.
+    ///
+    /// Defaults to 200ms.
+    pub fn set_animation_duration(&mut self, duration: Duration) {
+        self.animation_duration = duration;
+    }
+
+    /// Sets how long a smooth scrolling animation lasts.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn animation_duration(self, duration: Duration) -> Self {
+        self.with(|s| s.set_animation_duration(duration))
+    }
+
+    /// Returns how long a smooth scrolling animation lasts.
+    pub fn get_animation_duration(&self) -> Duration {
+        self.animation_duration
+    }
+
+    /// Returns `true` if a smooth scrolling animation is in progress.
+    pub fn is_animating(&self) -> bool {
+        self.animation_progress().is_some()
+    }
+
+    /// Returns the offset currently displayed.
+    ///
+    /// This is the same as `content_viewport().top_left()`, except during a
+    /// smooth scrolling animation, where it moves towards it.
+    pub fn visible_offset(&self) -> Vec2 {
+        let Some((from, t)) = self.animation_progress() else {
+            return self.offset;
+        };
+
+        let t = self.easing.apply(t);
+        from.zip_map(self.offset, |from, to| {
+            let from = from as f32;
+            (from + (to as f32 - from) * t).round() as usize
+        })
+    }
+
+    /// Animates the transition from `from` to the current offset.
+    ///
+    /// Does nothing unless smooth scrolling is enabled.
+    pub fn animate_from(&mut self, from: Vec2) {
+        if self.smooth_scroll && from != self.offset {
+            self.animation = Some((from, Instant::now()));
+        }
+    }
+
+    /// Stops any animation, jumping directly to the current offset.
+    pub fn stop_animation(&mut self) {
+        self.animation = None;
+    }
+
+    // Returns the start of the current animation and its linear progress.
+    fn animation_progress(&self) -> Option<(Vec2, f32)> {
+        let (from, start) = self.animation?;
+        let elapsed = start.elapsed();
+        (elapsed < self.animation_duration).then(|| {
+            (
+                from,
+                elapsed.as_secs_f32() / self.animation_duration.as_secs_f32(),
+            )
+        })
+    }
+
     /// For each axis, returns `true` if this view can scroll.
     ///
     /// For example, a vertically-scrolling view will return
@@ -523,9 +967,10 @@ impl Core {
     ///
     /// The scrollbar_size().x will be the horizontal space taken by the vertical scrollbar.
     pub fn scrollbar_size(&self) -> Vec2 {
-        self.is_scrolling()
-            .swap()
-            .select_or(self.scrollbar_padding + (1, 1), Vec2::zero())
+        self.is_scrolling().swap().select_or(
+            self.scrollbar_padding + (self.thickness, self.thickness),
+            Vec2::zero(),
+        )
     }
 
     /// Returns the last size available for the child view.
@@ -573,18 +1018,20 @@ impl Core {
     ///
     /// Returns `true` if the event was consumed.
     pub fn start_drag(&mut self, position: Vec2) -> bool {
-        // For each scrollbar, how far it is.
-        let scrollbar_pos = self.last_outer_size().saturating_sub((1, 1));
+        // For each scrollbar, where it starts.
+        let outer_size = self.last_outer_size();
+        let scrollbar_pos = outer_size.saturating_sub((self.thickness, self.thickness));
         let lengths = self.scrollbar_thumb_lengths();
         let offsets = self.scrollbar_thumb_offsets(lengths);
         let available = self.last_available_size();
 
         // This is true for Y if we grabbed the vertical scrollbar
         // More specifically, we need both (for instance for the vertical bar):
-        // * To be in the right column: X == scrollbar_pos
+        // * To be in the right columns: scrollbar_pos <= X < outer_size
         // * To be in the right range: Y < available
         let grabbed = position
-            .zip_map(scrollbar_pos, |p, s| p == s)
+            .zip_map(scrollbar_pos, |p, s| p >= s)
+            .and(position.zip_map(outer_size, |p, o| p < o))
             .swap()
             .and(position.zip_map(available, |p, a| p < a));
 
@@ -646,6 +1093,11 @@ impl Core {
     ///
     /// Returns the cached value if it works, or `None`.
     pub(crate) fn try_cache(&self, constraint: Vec2) -> Option<(Vec2, Vec2, XY<bool>)> {
+        // The theme may have changed the scrollbar thickness since the last layout.
+        if self.target_thickness() != self.thickness {
+            return None;
+        }
+
         self.size_cache.and_then(|cache| {
             if cache.zip_map(constraint, SizeCache::accept).both() {
                 Some((
@@ -672,7 +1124,7 @@ impl Core {
         let steps = (available + (1, 1)).saturating_sub(lengths);
         let max_offset = self.inner_size.saturating_sub(available) + (1, 1);
 
-        steps * self.offset / max_offset
+        steps * self.visible_offset() / max_offset
     }
 
     /// Apply the scrolling strategy to the current scroll position.
@@ -0,0 +1,119 @@
+use crate::align::Align;
+use crate::style::{Style, StyleType};
+use std::time::Duration;
+
+/// Overlay showing the vertical scroll position, like `37%` or `120/3200`.
+///
+/// The indicator is drawn on top of the content, in a corner of the
+/// viewport, and only when the content is taller than the viewport. Enable
+/// it with [`Core::set_indicator`].
+///
+/// [`Core::set_indicator`]: crate::view::scroll::Core::set_indicator
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::align::Align;
+/// use cursive_core::view::scroll::{IndicatorFormat, ScrollIndicator};
+/// use cursive_core::views::{ScrollView, TextView};
+///
+/// let indicator = ScrollIndicator {
+///     format: IndicatorFormat::Lines,
+///     align: Align::top_right(),
+///     ..ScrollIndicator::default()
+/// };
+///
+/// let view = ScrollView::new(TextView::new("Lots of text")).indicator(indicator);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ScrollIndicator {
+    /// What the indicator shows.
+    ///
+    /// Defaults to `IndicatorFormat::Percent`.
+    pub format: IndicatorFormat,
+
+    /// Where the indicator is drawn in the viewport.
+    ///
+    /// Defaults to the bottom-right corner.
+    pub align: Align,
+
+    /// Style of the indicator.
+    pub style: StyleType,
+
+    /// If set, the indicator is only drawn for this long after the last scroll.
+    ///
+    /// Hiding the indicator on time requires an auto-refresh to be set with
+    /// `Cursive::set_fps`.
+    ///
+    /// Defaults to 1 second. With `None`, the indicator is always drawn.
+    pub auto_hide: Option<Duration>,
+}
+
+/// Text shown by a [`ScrollIndicator`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum IndicatorFormat {
+    /// How far down the content is scrolled, like `37%`.
+    #[default]
+    Percent,
+
+    /// The first visible line and the number of lines, like `120/3200`.
+    Lines,
+}
+
+impl Default for ScrollIndicator {
+    fn default() -> Self {
+        ScrollIndicator {
+            format: IndicatorFormat::Percent,
+            align: Align::bot_right(),
+            style: Style::highlight().into(),
+            auto_hide: Some(Duration::from_secs(1)),
+        }
+    }
+}
+
+impl ScrollIndicator {
+    /// Returns the text to show for the given scroll state.
+    ///
+    /// `offset` is the first visible line, `visible` the height of the
+    /// viewport and `total` the height of the content.
+    ///
+    /// Returns `None` if the content fits in the viewport.
+    pub fn text(&self, offset: usize, visible: usize, total: usize) -> Option<String> {
+        let max_offset = total.checked_sub(visible).filter(|&max| max > 0)?;
+
+        Some(match self.format {
+            IndicatorFormat::Percent => {
+                format!(" {}% ", offset.min(max_offset) * 100 / max_offset)
+            }
+            IndicatorFormat::Lines => format!(" {}/{} ", offset + 1, total),
+        })
+    }
+}
+
+impl std::str::FromStr for IndicatorFormat {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Percent" | "percent" => Self::Percent,
+            "Lines" | "lines" => Self::Lines,
+            _ => return Err(()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text() {
+        let mut indicator = ScrollIndicator::default();
+        assert_eq!(indicator.text(0, 10, 10), None);
+        assert_eq!(indicator.text(0, 10, 110).as_deref(), Some(" 0% "));
+        assert_eq!(indicator.text(37, 10, 110).as_deref(), Some(" 37% "));
+        assert_eq!(indicator.text(100, 10, 110).as_deref(), Some(" 100% "));
+
+        indicator.format = IndicatorFormat::Lines;
+        assert_eq!(indicator.text(119, 20, 3200).as_deref(), Some(" 120/3200 "));
+    }
+}
@@ -0,0 +1,48 @@
+/// Optional keyboard shortcuts for scrolling.
+///
+/// All of these are disabled by default, and only apply to keys ignored by
+/// the content. Enable them with [`Core::set_scroll_keys`].
+///
+/// [`Core::set_scroll_keys`]: crate::view::scroll::Core::set_scroll_keys
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::view::scroll::ScrollKeys;
+/// use cursive_core::views::{ScrollView, TextView};
+///
+/// let keys = ScrollKeys {
+///     half_page: true,
+///     marks: true,
+///     ..ScrollKeys::default()
+/// };
+///
+/// let view = ScrollView::new(TextView::new("Lots of text")).scroll_keys(keys);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ScrollKeys {
+    /// Scroll down and up by half a page with `Ctrl-D` and `Ctrl-U`.
+    pub half_page: bool,
+
+    /// Number of rows scrolled by `PageUp` and `PageDown`.
+    ///
+    /// Also used for half-page scrolling.
+    ///
+    /// Defaults to `None`: scroll by the height of the viewport.
+    pub page_size: Option<usize>,
+
+    /// Enable vi-style marks.
+    ///
+    /// `m` followed by a character saves the current position under this
+    /// character, and `'` followed by the same character jumps back to it.
+    ///
+    /// `''` jumps back to the position before the last jump.
+    pub marks: bool,
+}
+
+/// A mark command waiting for its character.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum MarkCommand {
+    Set,
+    Jump,
+}
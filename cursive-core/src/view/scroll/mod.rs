@@ -20,9 +20,15 @@
 
 #[macro_use]
 mod core;
+mod indicator;
+mod keys;
 mod raw;
+mod style;
 
 pub use self::core::{Core, Scroller};
+pub use self::indicator::{IndicatorFormat, ScrollIndicator};
+pub use self::keys::ScrollKeys;
+pub use self::style::ScrollStyle;
 
 use crate::event::{Event, EventResult};
 use crate::{Printer, Rect, Vec2};
@@ -56,6 +62,47 @@ impl std::str::FromStr for ScrollStrategy {
     }
 }
 
+/// Easing curve used by smooth scrolling.
+///
+/// See [`Core::set_smooth_scroll`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Easing {
+    /// Moves at a constant speed.
+    Linear,
+    /// Starts fast and slows down at the end.
+    #[default]
+    EaseOut,
+    /// Starts slow, speeds up, and slows down at the end.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Returns the progress of the animation at time `t`.
+    ///
+    /// Both `t` and the result go from `0` (start) to `1` (end).
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut if t < 0.5 => 4.0 * t.powi(3),
+            Easing::EaseInOut => 1.0 - (2.0 - 2.0 * t).powi(3) / 2.0,
+        }
+    }
+}
+
+impl std::str::FromStr for Easing {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Linear" | "linear" => Self::Linear,
+            "EaseOut" | "ease_out" => Self::EaseOut,
+            "EaseInOut" | "ease_in_out" => Self::EaseInOut,
+            _ => return Err(()),
+        })
+    }
+}
+
 /// Performs `View::on_event` on a `scroll::Scroller`.
 ///
 /// Example:
@@ -3,9 +3,10 @@
 //! Most functions take a generic `Model` class, and various closures to get
 //! the required things from this model.
 use crate::{
-    event::{Event, EventResult, Key, MouseButton, MouseEvent},
+    event::{Event, EventResult, MouseButton, MouseEvent},
+    keymap,
     rect::Rect,
-    view::scroll,
+    view::scroll::{self, keys::MarkCommand},
     xy::XY,
     Printer, Vec2,
 };
@@ -21,8 +22,9 @@ pub fn draw<Model, GetScroller, Draw>(
     GetScroller: FnMut(&Model) -> &scroll::Core,
     Draw: FnOnce(&Model, &Printer),
 {
-    let printer = get_scroller(model).sub_printer(printer);
-    inner_draw(model, &printer);
+    let content_printer = get_scroller(model).sub_printer(printer);
+    inner_draw(model, &content_printer);
+    get_scroller(model).draw_indicator(printer);
 }
 
 /// Intermediate method to get the size requirements of a view.
@@ -46,10 +48,9 @@ where
     RequiredSize: FnMut(&mut Model, Vec2) -> Vec2,
 {
     // This is the size taken by the scrollbars.
-    let scrollbar_size = scrolling.swap().select_or(
-        get_scroller(model).get_scrollbar_padding() + (1, 1),
-        Vec2::zero(),
-    );
+    let scrollbar_size = scrolling
+        .swap()
+        .select_or(get_scroller(model).scrollbar_footprint(), Vec2::zero());
 
     let available = constraint.saturating_sub(scrollbar_size);
 
@@ -222,6 +223,27 @@ pub fn on_event<Model: ?Sized>(
     mut on_event: impl FnMut(&mut Model, Event) -> EventResult,
     mut important_area: impl FnMut(&Model, Vec2) -> Rect,
 ) -> EventResult {
+    // Where we start from, if this event leads to an animated scroll.
+    let visible_offset = get_scroller(model).visible_offset();
+    let offset = get_scroller(model).content_viewport().top_left();
+
+    // A mark command takes the next character, before the content sees it.
+    if let Some(command) = get_scroller(model).take_pending_mark() {
+        if let Event::Char(mark) = event {
+            let scroller = get_scroller(model);
+            match command {
+                MarkCommand::Set => scroller.set_mark(mark),
+                MarkCommand::Jump if scroller.jump_to_mark(mark) => {
+                    scroller.set_scroll_strategy(scroll::ScrollStrategy::KeepRow);
+                    scroller.mark_scrolled();
+                    scroller.animate_from(visible_offset);
+                }
+                MarkCommand::Jump => (),
+            }
+            return EventResult::Consumed(None);
+        }
+    }
+
     let mut relative_event = event.clone();
     let inside = get_scroller(model).is_event_inside(&mut relative_event);
     let result = if inside {
@@ -234,6 +256,18 @@ pub fn on_event<Model: ?Sized>(
         EventResult::Ignored => {
             // The view ignored the event, so we're free to use it.
 
+            if let Event::Char(c @ ('m' | '\'')) = event {
+                if get_scroller(model).get_scroll_keys().marks {
+                    let command = if c == 'm' {
+                        MarkCommand::Set
+                    } else {
+                        MarkCommand::Jump
+                    };
+                    get_scroller(model).set_pending_mark(command);
+                    return EventResult::Consumed(None);
+                }
+            }
+
             // If it's an arrow, try to scroll in the given direction.
             // If it's a mouse scroll, try to scroll as well.
             // Also allow Ctrl+arrow to move the view,
@@ -243,13 +277,29 @@ pub fn on_event<Model: ?Sized>(
                     event: MouseEvent::WheelUp,
                     ..
                 } if get_scroller(model).can_scroll_up() => {
-                    get_scroller(model).scroll_up(3);
+                    let step = get_scroller(model).get_scroll_step().y;
+                    get_scroller(model).scroll_up(step);
                 }
                 Event::Mouse {
                     event: MouseEvent::WheelDown,
                     ..
                 } if get_scroller(model).can_scroll_down() => {
-                    get_scroller(model).scroll_down(3);
+                    let step = get_scroller(model).get_scroll_step().y;
+                    get_scroller(model).scroll_down(step);
+                }
+                Event::Mouse {
+                    event: MouseEvent::WheelLeft,
+                    ..
+                } if get_scroller(model).can_scroll_left() => {
+                    let step = get_scroller(model).get_scroll_step().x;
+                    get_scroller(model).scroll_left(step);
+                }
+                Event::Mouse {
+                    event: MouseEvent::WheelRight,
+                    ..
+                } if get_scroller(model).can_scroll_right() => {
+                    let step = get_scroller(model).get_scroll_step().x;
+                    get_scroller(model).scroll_right(step);
                 }
                 Event::Mouse {
                     event: MouseEvent::Press(MouseButton::Left),
@@ -277,13 +327,17 @@ pub fn on_event<Model: ?Sized>(
                 } => {
                     get_scroller(model).release_grab();
                 }
-                Event::Key(Key::Home) if get_scroller(model).is_enabled().any() => {
+                _ if get_scroller(model).is_enabled().any()
+                    && keymap::matches("scroll_to_top", &event) =>
+                {
                     let actions: XY<fn(&mut scroll::Core)> =
                         XY::new(scroll::Core::scroll_to_left, scroll::Core::scroll_to_top);
                     let scroller = get_scroller(model);
                     actions.run_if(scroller.is_enabled(), |a| a(scroller));
                 }
-                Event::Key(Key::End) if get_scroller(model).is_enabled().any() => {
+                _ if get_scroller(model).is_enabled().any()
+                    && keymap::matches("scroll_to_bottom", &event) =>
+                {
                     let actions: XY<fn(&mut scroll::Core)> = XY::new(
                         scroll::Core::scroll_to_right,
                         scroll::Core::scroll_to_bottom,
@@ -291,53 +345,114 @@ pub fn on_event<Model: ?Sized>(
                     let scroller = get_scroller(model);
                     actions.run_if(scroller.is_enabled(), |a| a(scroller));
                 }
-                Event::Ctrl(Key::Up) | Event::Key(Key::Up)
-                    if get_scroller(model).can_scroll_up() =>
+                _ if get_scroller(model).can_scroll_up()
+                    && keymap::matches("scroll_up", &event) =>
                 {
                     get_scroller(model).scroll_up(1);
                 }
-                Event::Key(Key::PageUp) if get_scroller(model).can_scroll_up() => {
+                _ if get_scroller(model).can_scroll_up() && keymap::matches("page_up", &event) => {
                     let scroller = get_scroller(model);
-                    scroller.scroll_up(scroller.last_available_size().y);
+                    scroller.scroll_up(scroller.page_size());
                 }
-                Event::Key(Key::PageDown) if get_scroller(model).can_scroll_down() => {
+                _ if get_scroller(model).can_scroll_down()
+                    && keymap::matches("page_down", &event) =>
+                {
                     // No `min` check here - we allow going over the edge.
                     let scroller = get_scroller(model);
-                    scroller.scroll_down(scroller.last_available_size().y);
+                    scroller.scroll_down(scroller.page_size());
+                }
+                Event::CtrlChar('u')
+                    if get_scroller(model).get_scroll_keys().half_page
+                        && get_scroller(model).can_scroll_up() =>
+                {
+                    let scroller = get_scroller(model);
+                    scroller.scroll_up((scroller.page_size() / 2).max(1));
+                }
+                Event::CtrlChar('d')
+                    if get_scroller(model).get_scroll_keys().half_page
+                        && get_scroller(model).can_scroll_down() =>
+                {
+                    let scroller = get_scroller(model);
+                    scroller.scroll_down((scroller.page_size() / 2).max(1));
                 }
-                Event::Ctrl(Key::Down) | Event::Key(Key::Down)
-                    if get_scroller(model).can_scroll_down() =>
+                _ if get_scroller(model).can_scroll_down()
+                    && keymap::matches("scroll_down", &event) =>
                 {
                     get_scroller(model).scroll_down(1);
                 }
-                Event::Ctrl(Key::Left) | Event::Key(Key::Left)
-                    if get_scroller(model).can_scroll_left() =>
+                _ if get_scroller(model).can_scroll_left()
+                    && keymap::matches("scroll_left", &event) =>
                 {
                     let scroller = get_scroller(model);
                     scroller.scroll_left(scroller.last_available_size().x);
                 }
-                Event::Ctrl(Key::Right) | Event::Key(Key::Right)
-                    if get_scroller(model).can_scroll_right() =>
+                _ if get_scroller(model).can_scroll_right()
+                    && keymap::matches("scroll_right", &event) =>
                 {
                     let scroller = get_scroller(model);
                     scroller.scroll_right(scroller.last_available_size().x);
                 }
+                _ if !get_scroller(model).get_scroll_chaining()
+                    && is_scroll_event(&event, get_scroller(model).is_enabled()) =>
+                {
+                    // We are at the edge, but outer scrollers should not move either.
+                    return EventResult::Consumed(None);
+                }
                 _ => return EventResult::Ignored,
             };
 
             // We just scrolled manually, so reset the scroll strategy.
             get_scroller(model).set_scroll_strategy(scroll::ScrollStrategy::KeepRow);
+            get_scroller(model).mark_scrolled();
+
+            // Only jumps are animated: small steps and dragging follow the input directly.
+            let keymap = keymap::keymap();
+            let is_jump = ["page_up", "page_down", "scroll_to_top", "scroll_to_bottom"]
+                .iter()
+                .any(|action| keymap.matches(action, &event));
+            if is_jump || matches!(event, Event::CtrlChar('u' | 'd')) {
+                get_scroller(model).animate_from(visible_offset);
+            } else {
+                get_scroller(model).stop_animation();
+            }
 
             // TODO: return callback on_scroll?
             EventResult::Consumed(None)
         }
         other => {
             // The view consumed the event. Maybe something changed?
-            let inner_size = get_scroller(model).inner_size();
-            let important = important_area(model, inner_size);
-            get_scroller(model).scroll_to_rect(important);
+            // Wheel events only scroll an inner view: following its important
+            // area would make both scroll at once.
+            if !event.is_wheel() {
+                let inner_size = get_scroller(model).inner_size();
+                let important = important_area(model, inner_size);
+                get_scroller(model).scroll_to_rect(important);
+                get_scroller(model).animate_from(visible_offset);
+            }
+            if get_scroller(model).content_viewport().top_left() != offset {
+                get_scroller(model).mark_scrolled();
+            }
 
             other
         }
     }
 }
+
+// Returns `true` if the event would scroll along one of the enabled axes.
+//
+// Arrow keys are not included, since they also move the focus between views.
+fn is_scroll_event(event: &Event, enabled: XY<bool>) -> bool {
+    match event {
+        Event::Mouse {
+            event: MouseEvent::WheelUp | MouseEvent::WheelDown,
+            ..
+        } => enabled.y,
+        Event::Mouse {
+            event: MouseEvent::WheelLeft | MouseEvent::WheelRight,
+            ..
+        } => enabled.x,
+        _ => {
+            enabled.y && (keymap::matches("page_up", event) || keymap::matches("page_down", event))
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use crate::style::{Style, StyleType};
+use std::time::Duration;
+
+/// Defines how scrollbars are drawn.
+///
+/// The theme includes a default `ScrollStyle` (see [`Theme::scrollbar`]),
+/// which can be overridden for a single view with [`Core::set_scroll_style`].
+///
+/// [`Theme::scrollbar`]: crate::theme::Theme::scrollbar
+/// [`Core::set_scroll_style`]: crate::view::scroll::Core::set_scroll_style
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::style::PaletteStyle;
+/// use cursive_core::view::scroll::ScrollStyle;
+/// use cursive_core::views::{ScrollView, TextView};
+///
+/// let style = ScrollStyle {
+///     thumb: "█".into(),
+///     vertical_track: "│".into(),
+///     thumb_style: PaletteStyle::TitlePrimary.into(),
+///     ..ScrollStyle::default()
+/// };
+///
+/// let view = ScrollView::new(TextView::new("Lots of text")).scroll_style(style);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ScrollStyle {
+    /// Glyph used to draw the thumb.
+    ///
+    /// Defaults to `"▒"`.
+    pub thumb: String,
+
+    /// Glyph used to draw the thumb while it is being dragged.
+    ///
+    /// Defaults to `" "`.
+    pub thumb_grabbed: String,
+
+    /// Glyph used to draw the track of the vertical scrollbar.
+    ///
+    /// Defaults to `"|"`.
+    pub vertical_track: String,
+
+    /// Glyph used to draw the track of the horizontal scrollbar.
+    ///
+    /// Defaults to `"-"`.
+    pub horizontal_track: String,
+
+    /// Glyph used in the corner between both scrollbars.
+    ///
+    /// Defaults to `"╳"`.
+    pub corner: String,
+
+    /// Number of cells taken by each scrollbar.
+    ///
+    /// Defaults to `1`. A thickness of `0` is treated as `1`.
+    pub thickness: usize,
+
+    /// Style of the thumb when the view is focused.
+    pub thumb_style: StyleType,
+
+    /// Style of the thumb when the view is not focused.
+    pub thumb_inactive_style: StyleType,
+
+    /// Style of the track.
+    pub track_style: StyleType,
+
+    /// If set, scrollbars are only drawn for this long after the last scroll.
+    ///
+    /// The space for the scrollbars is still reserved when they are hidden.
+    ///
+    /// Defaults to `None`: scrollbars are always drawn when needed.
+    pub auto_hide: Option<Duration>,
+}
+
+impl Default for ScrollStyle {
+    fn default() -> Self {
+        ScrollStyle {
+            thumb: "▒".into(),
+            thumb_grabbed: " ".into(),
+            vertical_track: "|".into(),
+            horizontal_track: "-".into(),
+            corner: "╳".into(),
+            thickness: 1,
+            thumb_style: Style::highlight().into(),
+            thumb_inactive_style: Style::highlight_inactive().into(),
+            track_style: StyleType::inherit_parent(),
+            auto_hide: None,
+        }
+    }
+}
+
+impl ScrollStyle {
+    /// Load values from a toml table, overwriting previous values.
+    ///
+    /// Styles can be given as a palette style name (like `"highlight"`) or as
+    /// a style description (like `"red+bold"`, or a table).
+    ///
+    /// `auto_hide` can be a delay in seconds, or a boolean (`true` meaning 1 second).
+    #[cfg(feature = "toml")]
+    pub(crate) fn load_toml(&mut self, table: &toml::value::Table) {
+        let glyphs = [
+            ("thumb", &mut self.thumb),
+            ("thumb_grabbed", &mut self.thumb_grabbed),
+            ("vertical_track", &mut self.vertical_track),
+            ("horizontal_track", &mut self.horizontal_track),
+            ("corner", &mut self.corner),
+        ];
+        for (key, glyph) in glyphs {
+            if let Some(toml::Value::String(value)) = table.get(key) {
+                *glyph = value.clone();
+            }
+        }
+
+        if let Some(&toml::Value::Integer(thickness)) = table.get("thickness") {
+            self.thickness = usize::try_from(thickness).unwrap_or(1).max(1);
+        }
+
+        let styles = [
+            ("thumb_style", &mut self.thumb_style),
+            ("thumb_inactive_style", &mut self.thumb_inactive_style),
+            ("track_style", &mut self.track_style),
+        ];
+        for (key, style) in styles {
+            let Some(value) = table.get(key) else {
+                continue;
+            };
+            match StyleType::parse(value) {
+                Some(parsed) => *style = parsed,
+                None => log::warn!("Could not parse scrollbar style: `{value}`."),
+            }
+        }
+
+        match table.get("auto_hide") {
+            Some(&toml::Value::Boolean(auto_hide)) => {
+                self.auto_hide = auto_hide.then(|| Duration::from_secs(1));
+            }
+            Some(&toml::Value::Float(delay)) if delay >= 0.0 => {
+                self.auto_hide = Some(Duration::from_secs_f64(delay));
+            }
+            Some(&toml::Value::Integer(delay)) => {
+                self.auto_hide = u64::try_from(delay).ok().map(Duration::from_secs);
+            }
+            _ => (),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "toml"))]
+mod tests {
+    use super::*;
+    use crate::style::PaletteStyle;
+
+    #[test]
+    fn load_toml() {
+        let table = toml::from_str(
+            r#"
+            thumb = "█"
+            thickness = 2
+            thumb_style = "title_primary"
+            track_style = "red+bold"
+            auto_hide = 0.5
+            "#,
+        )
+        .unwrap();
+
+        let mut style = ScrollStyle::default();
+        style.load_toml(&table);
+
+        assert_eq!(style.thumb, "█");
+        assert_eq!(style.vertical_track, "|");
+        assert_eq!(style.thickness, 2);
+        assert_eq!(style.thumb_style, PaletteStyle::TitlePrimary.into());
+        assert_eq!(
+            style.track_style,
+            "red+bold".parse::<Style>().unwrap().into()
+        );
+        assert_eq!(style.auto_hide, Some(Duration::from_millis(500)));
+    }
+}
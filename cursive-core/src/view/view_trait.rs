@@ -63,6 +63,29 @@ pub trait View: Any + AnyView + Send + Sync {
         true
     }
 
+    /// Returns the area of this view that changed since it was last drawn.
+    ///
+    /// `size` is the size given in the last call to `layout()`. Returns `None`
+    /// if nothing changed.
+    ///
+    /// This is called before `layout()`, and is used by damage tracking to
+    /// only redraw what changed (see [`Cursive::set_damage_tracking`]).
+    ///
+    /// View groups should implement this to combine the dirty areas of their
+    /// children.
+    ///
+    /// Default implementation returns the entire view if `needs_relayout()`,
+    /// and `None` otherwise.
+    ///
+    /// [`Cursive::set_damage_tracking`]: crate::Cursive::set_damage_tracking
+    fn dirty_area(&self, size: Vec2) -> Option<Rect> {
+        if self.needs_relayout() {
+            Some(Rect::from_size(Vec2::zero(), size))
+        } else {
+            None
+        }
+    }
+
     /// Returns the minimum size the view requires with the given restrictions.
     ///
     /// This is the main way a view communicate its size to its parent.
@@ -117,6 +140,33 @@ pub trait View: Any + AnyView + Send + Sync {
         Err(ViewNotFound)
     }
 
+    /// Scrolls to make the view identified by the given selector visible.
+    ///
+    /// `size` is the size given in the last call to `layout()`.
+    ///
+    /// Returns the area covered by the view found, relative to this view.
+    /// Scrolling views reveal this area in their content, and return the
+    /// part of it now visible. The focus is not changed.
+    ///
+    /// View groups should implement this to forward the call to each
+    /// children, offsetting the area found with the child position.
+    ///
+    /// Default implementation simply returns `Err(ViewNotFound)`.
+    fn reveal_view(&mut self, _: &Selector, _size: Vec2) -> Result<Rect, ViewNotFound> {
+        Err(ViewNotFound)
+    }
+
+    /// Runs a closure on the child currently holding the focus, if any.
+    ///
+    /// This is used to follow the focus path down the view tree, for example
+    /// with [`Selector::Focused`].
+    ///
+    /// View groups should implement this to forward the call to their
+    /// focused child.
+    ///
+    /// Default implementation is a no-op.
+    fn call_on_focused_child(&mut self, _: AnyCb) {}
+
     /// Attempt to give this view the focus.
     ///
     /// `source` indicates where the focus comes from.
@@ -143,6 +193,17 @@ pub trait View: Any + AnyView + Send + Sync {
         Rect::from_size((0, 0), view_size)
     }
 
+    /// Returns how many rows were removed from the top of this view since
+    /// the last call.
+    ///
+    /// A parent `ScrollView` uses this to keep showing the same content when
+    /// rows disappear above it, for example when a `TextView` drops old lines.
+    ///
+    /// Default implementation always returns 0.
+    fn take_removed_rows(&mut self) -> usize {
+        0
+    }
+
     /// Returns the type of this view.
     ///
     /// Useful when you have a `&dyn View`.
@@ -2,7 +2,7 @@ use crate::{
     direction::Direction,
     event::{AnyCb, Event, EventResult},
     rect::Rect,
-    view::{CannotFocus, Selector, View, ViewNotFound},
+    view::{AnyView, CannotFocus, Selector, View, ViewNotFound},
     Printer, Vec2,
 };
 
@@ -88,16 +88,43 @@ pub trait ViewWrapper: Send + Sync + 'static {
             .unwrap_or(Err(ViewNotFound))
     }
 
+    /// Wraps the `reveal_view` method.
+    ///
+    /// Wrappers moving the wrapped view should override this.
+    fn wrap_reveal_view(&mut self, selector: &Selector, size: Vec2) -> Result<Rect, ViewNotFound> {
+        self.with_view_mut(|v| v.reveal_view(selector, size))
+            .unwrap_or(Err(ViewNotFound))
+    }
+
+    /// Wraps the `call_on_focused_child` method.
+    fn wrap_call_on_focused_child(&mut self, callback: AnyCb) {
+        self.with_view_mut(|v| callback(v.as_view_mut()));
+    }
+
     /// Wraps the `needs_relayout` method.
     fn wrap_needs_relayout(&self) -> bool {
         self.with_view(View::needs_relayout).unwrap_or(true)
     }
 
+    /// Wraps the `dirty_area` method.
+    ///
+    /// Wrappers moving the wrapped view, or drawing something themselves,
+    /// should override this.
+    fn wrap_dirty_area(&self, size: Vec2) -> Option<Rect> {
+        self.with_view(|v| v.dirty_area(size))
+            .unwrap_or_else(|| Some(Rect::from_size(Vec2::zero(), size)))
+    }
+
     /// Wraps the `important_area` method.
     fn wrap_important_area(&self, size: Vec2) -> Rect {
         self.with_view(|v| v.important_area(size))
             .unwrap_or_else(|| Rect::from_size(Vec2::zero(), size))
     }
+
+    /// Wraps the `take_removed_rows` method.
+    fn wrap_take_removed_rows(&mut self) -> usize {
+        self.with_view_mut(View::take_removed_rows).unwrap_or(0)
+    }
 }
 
 // The main point of implementing ViewWrapper is to have View for free.
@@ -126,6 +153,10 @@ impl<T: ViewWrapper> View for T {
         self.wrap_call_on_any(selector, callback)
     }
 
+    fn call_on_focused_child(&mut self, callback: AnyCb) {
+        self.wrap_call_on_focused_child(callback)
+    }
+
     fn needs_relayout(&self) -> bool {
         self.wrap_needs_relayout()
     }
@@ -134,9 +165,21 @@ impl<T: ViewWrapper> View for T {
         self.wrap_focus_view(selector)
     }
 
+    fn reveal_view(&mut self, selector: &Selector, size: Vec2) -> Result<Rect, ViewNotFound> {
+        self.wrap_reveal_view(selector, size)
+    }
+
+    fn dirty_area(&self, size: Vec2) -> Option<Rect> {
+        self.wrap_dirty_area(size)
+    }
+
     fn important_area(&self, size: Vec2) -> Rect {
         self.wrap_important_area(size)
     }
+
+    fn take_removed_rows(&mut self) -> usize {
+        self.wrap_take_removed_rows()
+    }
 }
 
 /// Convenient macro to implement the [`ViewWrapper`] trait.
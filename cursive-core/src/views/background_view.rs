@@ -0,0 +1,95 @@
+use crate::style::{ColorStyle, ColorType};
+use crate::view::{View, ViewWrapper};
+use crate::Printer;
+
+/// Wrapper view that fills its area with a background color.
+///
+/// Unlike [`Layer`], only the background is set: the wrapped view keeps the
+/// front color it inherits from its parent. Palette colors follow the
+/// current theme.
+///
+/// [`Layer`]: crate::views::Layer
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::style::PaletteColor;
+/// use cursive_core::traits::*;
+/// use cursive_core::views::TextView;
+///
+/// let view = TextView::new("Highlighted").with_background(PaletteColor::Highlight);
+/// ```
+#[derive(Debug)]
+pub struct BackgroundView<T> {
+    view: T,
+    color: ColorType,
+}
+
+impl<T> BackgroundView<T> {
+    /// Wraps the given view with a background color.
+    pub fn new<C: Into<ColorType>>(view: T, color: C) -> Self {
+        BackgroundView {
+            view,
+            color: color.into(),
+        }
+    }
+
+    /// Gets the current background color.
+    pub fn color(&self) -> ColorType {
+        self.color
+    }
+
+    /// Sets the background color.
+    pub fn set_color<C: Into<ColorType>>(&mut self, color: C) {
+        self.color = color.into();
+    }
+
+    inner_getters!(self.view: T);
+}
+
+impl<T: View> ViewWrapper for BackgroundView<T> {
+    wrap_impl!(self.view: T);
+
+    fn wrap_draw(&self, printer: &Printer) {
+        printer.with_color(ColorStyle::back(self.color), |printer| {
+            for y in 0..printer.size.y {
+                printer.print_hline((0, y), printer.size.x, " ");
+            }
+            self.view.draw(printer);
+        });
+    }
+}
+
+#[crate::blueprint(BackgroundView::new(view, color))]
+struct Blueprint {
+    view: crate::views::BoxedView,
+    color: ColorType,
+}
+
+crate::manual_blueprint!(with background, |config, context| {
+    let color: ColorType = context.resolve(config)?;
+    Ok(move |view| BackgroundView::new(view, color))
+});
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{BaseColor, PaletteColor};
+    use crate::theme::Theme;
+    use crate::traits::{Backgroundable, Resizable};
+    use crate::views::TextView;
+
+    #[test]
+    fn fills_background() {
+        let view = TextView::new("ab")
+            .fixed_size((4, 2))
+            .with_background(BaseColor::Red.dark());
+        let screen = crate::test::render_view(view, (4, 2));
+
+        let palette = Theme::default().palette;
+        for pos in [(0, 0), (3, 1)] {
+            let style = screen.style_at(pos).unwrap();
+            assert_eq!(style.color.back, BaseColor::Red.dark());
+            assert_eq!(style.color.front, palette[PaletteColor::Primary]);
+        }
+    }
+}
@@ -0,0 +1,391 @@
+use super::sparkline::{
+    cell_steps, column_steps, format_value, ratio, value_color, value_range, Formatter,
+};
+use crate::align::HAlign;
+use crate::style::{gradient::Linear, ColorType, PaletteColor};
+use crate::utils::lines::simple::simple_prefix;
+use crate::view::View;
+use crate::views::{ChartGlyphs, SeriesContent};
+use crate::{Printer, Vec2, With};
+use unicode_width::UnicodeWidthStr;
+
+// Space between two bars.
+const BAR_GAP: usize = 1;
+
+/// Chart showing a series of values as vertical bars.
+///
+/// Each bar can have a label, printed below it. Values can also be printed
+/// above their bar.
+///
+/// Values are scaled between 0 and the maximum value, unless a range is set.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::BarChart;
+///
+/// let mut chart = BarChart::new()
+///     .bar("Mon", 3.0)
+///     .bar("Tue", 5.0)
+///     .bar("Wed", 2.5)
+///     .show_values(true);
+///
+/// // The values can be updated later, possibly from another thread.
+/// let content = chart.get_shared_content();
+/// content.set_values([4.0, 5.0, 1.0]);
+/// ```
+pub struct BarChart {
+    content: SeriesContent,
+    labels: Vec<String>,
+    height: usize,
+    bar_width: usize,
+    glyphs: ChartGlyphs,
+    color: ColorType,
+    gradient: Option<Linear>,
+    min: Option<f64>,
+    max: Option<f64>,
+    show_values: bool,
+    formatter: Box<Formatter>,
+}
+
+new_default!(BarChart);
+
+impl BarChart {
+    /// Creates a new empty chart.
+    pub fn new() -> Self {
+        Self::new_with_content(SeriesContent::default())
+    }
+
+    /// Creates a new chart showing the given shared series.
+    pub fn new_with_content(content: SeriesContent) -> Self {
+        BarChart {
+            content,
+            labels: Vec::new(),
+            height: 8,
+            bar_width: 3,
+            glyphs: ChartGlyphs::Blocks,
+            color: PaletteColor::Highlight.into(),
+            gradient: None,
+            min: None,
+            max: None,
+            show_values: false,
+            formatter: Box::new(format_value),
+        }
+    }
+
+    /// Returns a shared reference to the series, allowing live updates.
+    pub fn get_shared_content(&mut self) -> SeriesContent {
+        self.content.clone()
+    }
+
+    /// Adds a bar with the given label and value.
+    pub fn add_bar<S: Into<String>>(&mut self, label: S, value: f64) {
+        let index = self.content.len();
+        self.labels.resize(index, String::new());
+        self.labels.push(label.into());
+        self.content.push(value);
+    }
+
+    /// Adds a bar with the given label and value.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn bar<S: Into<String>>(self, label: S, value: f64) -> Self {
+        self.with(|s| s.add_bar(label, value))
+    }
+
+    /// Replaces the values shown.
+    pub fn set_values<I: IntoIterator<Item = f64>>(&mut self, values: I) {
+        self.content.set_values(values);
+    }
+
+    /// Replaces the values shown.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn values<I: IntoIterator<Item = f64>>(self, values: I) -> Self {
+        self.with(|s| s.set_values(values))
+    }
+
+    /// Sets the labels printed below the bars, in order.
+    pub fn set_labels<S, I>(&mut self, labels: I)
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = S>,
+    {
+        self.labels = labels.into_iter().map(Into::into).collect();
+    }
+
+    /// Sets the labels printed below the bars, in order.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn labels<S, I>(self, labels: I) -> Self
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = S>,
+    {
+        self.with(|s| s.set_labels(labels))
+    }
+
+    /// Sets the number of rows used by the bars.
+    ///
+    /// Labels and values are printed outside of these rows.
+    ///
+    /// Defaults to 8.
+    pub fn set_height(&mut self, height: usize) {
+        self.height = height.max(1);
+    }
+
+    /// Sets the number of rows used by the bars.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn height(self, height: usize) -> Self {
+        self.with(|s| s.set_height(height))
+    }
+
+    /// Sets the width of each bar.
+    ///
+    /// Longer labels are truncated. Defaults to 3.
+    pub fn set_bar_width(&mut self, bar_width: usize) {
+        self.bar_width = bar_width.max(1);
+    }
+
+    /// Sets the width of each bar.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn bar_width(self, bar_width: usize) -> Self {
+        self.with(|s| s.set_bar_width(bar_width))
+    }
+
+    /// Sets the characters used to draw the bars.
+    ///
+    /// Defaults to `ChartGlyphs::Blocks`.
+    pub fn set_glyphs(&mut self, glyphs: ChartGlyphs) {
+        self.glyphs = glyphs;
+    }
+
+    /// Sets the characters used to draw the bars.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn glyphs(self, glyphs: ChartGlyphs) -> Self {
+        self.with(|s| s.set_glyphs(glyphs))
+    }
+
+    /// Sets the color of the bars.
+    ///
+    /// Defaults to the theme's highlight color.
+    pub fn set_color<C: Into<ColorType>>(&mut self, color: C) {
+        self.color = color.into();
+    }
+
+    /// Sets the color of the bars.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn color<C: Into<ColorType>>(self, color: C) -> Self {
+        self.with(|s| s.set_color(color))
+    }
+
+    /// Sets a gradient to color each bar, from the minimum to the maximum.
+    ///
+    /// It replaces the color set with `set_color`.
+    pub fn set_gradient(&mut self, gradient: Linear) {
+        self.gradient = Some(gradient);
+    }
+
+    /// Sets a gradient to color each bar, from the minimum to the maximum.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn gradient(self, gradient: Linear) -> Self {
+        self.with(|s| s.set_gradient(gradient))
+    }
+
+    /// Removes the gradient, using the color again.
+    pub fn clear_gradient(&mut self) {
+        self.gradient = None;
+    }
+
+    /// Sets the value shown as an empty bar.
+    ///
+    /// Smaller values are shown empty too.
+    pub fn set_min(&mut self, min: f64) {
+        self.min = Some(min);
+    }
+
+    /// Sets the value shown as an empty bar.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn min(self, min: f64) -> Self {
+        self.with(|s| s.set_min(min))
+    }
+
+    /// Sets the value shown as a full bar.
+    ///
+    /// Larger values are shown full too.
+    pub fn set_max(&mut self, max: f64) {
+        self.max = Some(max);
+    }
+
+    /// Sets the value shown as a full bar.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn max(self, max: f64) -> Self {
+        self.with(|s| s.set_max(max))
+    }
+
+    /// Sets the values shown as empty and full bars.
+    pub fn set_range(&mut self, min: f64, max: f64) {
+        self.set_min(min);
+        self.set_max(max);
+    }
+
+    /// Sets the values shown as empty and full bars.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn range(self, min: f64, max: f64) -> Self {
+        self.with(|s| s.set_range(min, max))
+    }
+
+    /// Scales the values from 0 to the maximum value again.
+    pub fn clear_range(&mut self) {
+        self.min = None;
+        self.max = None;
+    }
+
+    /// Prints each value above its bar.
+    pub fn set_show_values(&mut self, show_values: bool) {
+        self.show_values = show_values;
+    }
+
+    /// Prints each value above its bar.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn show_values(self, show_values: bool) -> Self {
+        self.with(|s| s.set_show_values(show_values))
+    }
+
+    /// Sets the function used to print the values.
+    pub fn set_formatter<F>(&mut self, formatter: F)
+    where
+        F: Fn(f64) -> String + 'static + Send + Sync,
+    {
+        self.formatter = Box::new(formatter);
+    }
+
+    /// Sets the function used to print the values.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn formatter<F>(self, formatter: F) -> Self
+    where
+        F: Fn(f64) -> String + 'static + Send + Sync,
+    {
+        self.with(|s| s.set_formatter(formatter))
+    }
+
+    // Returns `true` if a row is needed below the bars.
+    fn has_labels(&self) -> bool {
+        self.labels.iter().any(|label| !label.is_empty())
+    }
+
+    // Prints `text` centered above or below the bar starting at `x`.
+    fn print_centered(&self, printer: &Printer, x: usize, y: usize, text: &str) {
+        let text = &text[..simple_prefix(text, self.bar_width).length];
+        let offset = HAlign::Center.get_offset(text.width(), self.bar_width);
+        printer.print((x + offset, y), text);
+    }
+}
+
+impl View for BarChart {
+    fn draw(&self, printer: &Printer) {
+        let resolution = self.glyphs.resolution();
+        let top = usize::from(self.show_values);
+
+        self.content.with_values(|values| {
+            let range = value_range(values, self.min, self.max);
+
+            for (i, &value) in values.iter().enumerate() {
+                let x = i * (self.bar_width + BAR_GAP);
+                let ratio = ratio(value, range);
+                let steps = column_steps(ratio, self.height, resolution);
+                let color = value_color(self.gradient.as_ref(), self.color, ratio);
+
+                printer.with_color(color, |printer| {
+                    for row in 0..self.height {
+                        let fill = cell_steps(steps, row, resolution);
+                        let glyph = self.glyphs.glyph(fill, fill).to_string();
+                        let y = top + self.height - 1 - row;
+                        for dx in 0..self.bar_width {
+                            printer.print((x + dx, y), &glyph);
+                        }
+                    }
+                });
+
+                if self.show_values {
+                    self.print_centered(printer, x, 0, &(self.formatter)(value));
+                }
+                if let Some(label) = self.labels.get(i) {
+                    self.print_centered(printer, x, top + self.height, label);
+                }
+            }
+        });
+    }
+
+    fn required_size(&mut self, _constraint: Vec2) -> Vec2 {
+        let bars = self.content.len();
+        let width = (bars * (self.bar_width + BAR_GAP)).saturating_sub(BAR_GAP);
+        let height = self.height + usize::from(self.show_values) + usize::from(self.has_labels());
+        Vec2::new(width, height)
+    }
+}
+
+#[crate::blueprint(BarChart::new())]
+struct Blueprint {
+    values: Option<Vec<f64>>,
+    labels: Option<Vec<String>>,
+    height: Option<usize>,
+    bar_width: Option<usize>,
+    glyphs: Option<ChartGlyphs>,
+    color: Option<ColorType>,
+    gradient: Option<Linear>,
+    min: Option<f64>,
+    max: Option<f64>,
+    show_values: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::PrintBuffer;
+    use crate::theme::Theme;
+
+    #[test]
+    fn bar_chart() {
+        let mut chart = BarChart::new()
+            .height(2)
+            .bar_width(2)
+            .bar("Mon", 2.0)
+            .bar("Tue", 4.0)
+            .show_values(true);
+        assert_eq!(chart.required_size(Vec2::new(80, 24)), Vec2::new(5, 4));
+
+        let size = Vec2::new(5, 4);
+        let mut buffer = PrintBuffer::new();
+        buffer.resize(size);
+        let buffer = parking_lot::RwLock::new(buffer);
+        chart.draw(&Printer::new(size, &Theme::default(), &buffer));
+
+        let rendered = buffer.read().to_styled_string();
+        let lines: Vec<&str> = rendered.source().lines().collect();
+        assert_eq!(lines, ["2  4 ", "   ██", "██ ██", "Mo Tu"]);
+    }
+}
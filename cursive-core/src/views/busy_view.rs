@@ -0,0 +1,137 @@
+use crate::rect::Rect;
+use crate::style::{Effect, PaletteStyle};
+use crate::utils::markup::StyledString;
+use crate::view::View;
+use crate::views::SpinnerStyle;
+use crate::{Printer, Vec2, With};
+use std::time::{Duration, Instant};
+
+/// Overlay shown while the application is busy.
+///
+/// It dims everything drawn below it, and shows a spinner next to a message in
+/// the center. It ignores all events, so as a modal layer it keeps them from
+/// reaching the layers below, while global callbacks still work.
+///
+/// The spinner only moves when the screen is redrawn: see
+/// [`Cursive::with_busy_overlay`], which takes care of that.
+///
+/// [`Cursive::with_busy_overlay`]: crate::Cursive::with_busy_overlay
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::BusyView;
+///
+/// let overlay = BusyView::new("Saving...");
+/// ```
+pub struct BusyView {
+    message: StyledString,
+    started: Instant,
+}
+
+impl BusyView {
+    /// Time between two frames of the spinner.
+    pub(crate) const SPINNER_INTERVAL: Duration = Duration::from_millis(100);
+
+    /// Creates a new overlay with the given message.
+    pub fn new<S: Into<StyledString>>(message: S) -> Self {
+        BusyView {
+            message: message.into(),
+            started: Instant::now(),
+        }
+    }
+
+    /// Sets the message shown next to the spinner.
+    pub fn set_message<S: Into<StyledString>>(&mut self, message: S) {
+        self.message = message.into();
+    }
+
+    /// Sets the message shown next to the spinner.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn message<S: Into<StyledString>>(self, message: S) -> Self {
+        self.with(|s| s.set_message(message))
+    }
+
+    /// Returns the message shown next to the spinner.
+    pub fn get_message(&self) -> &StyledString {
+        &self.message
+    }
+
+    // Current glyph of the spinner.
+    fn spinner(&self) -> &'static str {
+        let frame = self.started.elapsed().as_millis() / Self::SPINNER_INTERVAL.as_millis();
+        SpinnerStyle::Braille.frame(frame as usize)
+    }
+}
+
+impl View for BusyView {
+    fn draw(&self, printer: &Printer) {
+        // Dim whatever was drawn below.
+        printer.on_window(|window| {
+            let size = window.size();
+            for y in 0..size.y {
+                for x in 0..size.x {
+                    if let Some(style) = window.style_at_mut((x, y)) {
+                        style.effects.insert(Effect::Dim);
+                    }
+                }
+            }
+        });
+
+        // Spinner, space and message, in a box with a space on each side.
+        let size = Vec2::new(self.message.width() + 6, 3);
+        let offset = printer.size.saturating_sub(size) / 2;
+        let printer = printer.offset(offset).cropped(size);
+
+        printer.fill_rect(Rect::from_size((0, 0), size), " ", PaletteStyle::View);
+        printer.print_box((0, 0), size, false);
+        printer.print((2, 1), self.spinner());
+        printer.print_styled((4, 1), &self.message);
+    }
+
+    fn required_size(&mut self, constraint: Vec2) -> Vec2 {
+        constraint
+    }
+}
+
+#[crate::blueprint(BusyView::new(message))]
+struct Blueprint {
+    message: StyledString,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::PrintBuffer;
+    use crate::theme::Theme;
+
+    #[test]
+    fn dims_below() {
+        let size = Vec2::new(12, 5);
+        let mut buffer = PrintBuffer::new();
+        buffer.resize(size);
+        let buffer = parking_lot::RwLock::new(buffer);
+        let theme = Theme::default();
+        let printer = Printer::new(size, &theme, &buffer);
+
+        printer.print((0, 0), "Lower layer");
+        BusyView::new("Wait").draw(&printer);
+
+        let buffer = buffer.read();
+        let rendered = buffer.to_styled_string();
+        let lines: Vec<&str> = rendered.source().lines().collect();
+        assert_eq!(lines[0], "Lower layer ");
+        assert!(lines[2].ends_with(" Wait │ "));
+
+        let style = buffer.cell_at(Vec2::new(0, 0)).unwrap().style();
+        assert!(style.effects.contains(Effect::Dim));
+    }
+
+    #[test]
+    fn empty_screen() {
+        // Nothing to draw, for example when the terminal is minimized.
+        crate::test::render_view(BusyView::new("Wait"), (0, 0));
+    }
+}
@@ -2,6 +2,7 @@ use crate::{
     align::HAlign,
     direction::Direction,
     event::*,
+    keymap,
     rect::Rect,
     style::PaletteStyle,
     utils::markup::StyledString,
@@ -138,15 +139,16 @@ impl View for Button {
             return;
         }
 
-        let style = if !(self.enabled && printer.enabled) {
+        let enabled = self.enabled && printer.enabled;
+        let style = if !enabled {
             // Disabled button goes blue
-            PaletteStyle::Secondary
+            PaletteStyle::Secondary.into()
         } else if printer.focused {
             // Selected button is highlighted
-            PaletteStyle::Highlight
+            printer.theme.focus.button
         } else {
             // Looks like regular text if not selected
-            PaletteStyle::Primary
+            PaletteStyle::Primary.into()
         };
 
         let offset = HAlign::Center.get_offset(self.label.width(), printer.size.x);
@@ -156,6 +158,18 @@ impl View for Button {
             // TODO: do we want to "fill" the button highlight color to the full given size?
             // printer.print_hline((0, 0), offset, " ");
             printer.print_styled((offset, 0), &self.label);
+
+            // Replace the brackets with the theme's markers, if any.
+            let label = self.label.source();
+            if let (true, Some((left, right))) = (
+                enabled && printer.focused,
+                &printer.theme.focus.button_markers,
+            ) {
+                if label.len() >= 2 && label.starts_with('<') && label.ends_with('>') {
+                    printer.print((offset, 0), left);
+                    printer.print((offset + self.label.width() - 1, 0), right);
+                }
+            }
             // let end = offset + self.label.width();
             // printer.print_hline(
             //     (end, 0),
@@ -185,7 +199,9 @@ impl View for Button {
         let width = self.label.width();
         let self_offset = HAlign::Center.get_offset(width, self.last_size.x);
         match event {
-            Event::Key(Key::Enter) => EventResult::Consumed(Some(self.callback.clone())),
+            _ if keymap::matches("submit", &event) => {
+                EventResult::Consumed(Some(self.callback.clone()))
+            }
             Event::Mouse {
                 event: MouseEvent::Release(MouseButton::Left),
                 position,
@@ -244,3 +260,35 @@ crate::manual_blueprint!(Button, |config, context| {
     })
 });
 */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::PrintBuffer;
+    use crate::theme::Theme;
+
+    fn render(button: &Button, theme: &Theme, focused: bool) -> String {
+        let size = Vec2::new(6, 1);
+        let mut buffer = PrintBuffer::new();
+        buffer.resize(size);
+        let buffer = parking_lot::RwLock::new(buffer);
+        button.draw(&Printer::new(size, theme, &buffer).focused(focused));
+
+        let rendered = buffer.read().to_styled_string();
+        rendered.source().to_string()
+    }
+
+    #[test]
+    fn focus_markers() {
+        let mut theme = Theme::default();
+        theme.focus.button_markers = Some((">".into(), "<".into()));
+
+        let button = Button::new("Ok", |_| ());
+        assert_eq!(render(&button, &theme, false), " <Ok> ");
+        assert_eq!(render(&button, &theme, true), " >Ok< ");
+
+        // Labels without brackets are left alone.
+        let raw = Button::new_raw("[Ok]", |_| ());
+        assert_eq!(render(&raw, &theme, true), " [Ok] ");
+    }
+}
@@ -0,0 +1,249 @@
+use crate::buffer::PrintBuffer;
+use crate::direction::Direction;
+use crate::event::{AnyCb, Event, EventResult};
+use crate::style::ConcreteStyle;
+use crate::view::{CannotFocus, Selector, View, ViewNotFound, ViewWrapper};
+use crate::{Printer, Vec2};
+use parking_lot::{Mutex, RwLock};
+
+/// Wrapper around a view that caches what it draws.
+///
+/// As long as the wrapped view doesn't need a relayout and the area to draw
+/// stays the same, the cells drawn last time are copied directly instead of
+/// calling the wrapped view's `draw` method again.
+///
+/// This only helps with views that implement [`View::needs_relayout`] to
+/// report when their content changes: the default implementation always
+/// returns `true`, which disables the cache.
+///
+/// The cache is also dropped when the wrapped view processes an event, when
+/// it takes the focus, and when it is accessed mutably. Other changes (for
+/// example a new theme) require a call to [`CachedView::invalidate`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use cursive_core::views::{CachedView, TextView};
+/// let view = CachedView::new(TextView::new("Expensive to draw"));
+/// ```
+pub struct CachedView<T> {
+    view: T,
+    cache: Mutex<Option<Cache>>,
+}
+
+// Everything that affects what the view draws, besides its own state.
+#[derive(PartialEq, Eq)]
+struct CacheKey {
+    size: Vec2,
+    output_size: Vec2,
+    content_offset: Vec2,
+    focused: bool,
+    enabled: bool,
+    style: ConcreteStyle,
+}
+
+impl CacheKey {
+    fn new(printer: &Printer) -> Self {
+        CacheKey {
+            size: printer.size,
+            output_size: printer.output_size,
+            content_offset: printer.content_offset,
+            focused: printer.focused,
+            enabled: printer.enabled,
+            style: printer.current_style(),
+        }
+    }
+}
+
+struct Cache {
+    key: CacheKey,
+    buffer: RwLock<PrintBuffer>,
+}
+
+new_default!(CachedView<T: Default>);
+
+impl<T> CachedView<T> {
+    /// Wraps the given view.
+    pub fn new(view: T) -> Self {
+        CachedView {
+            view,
+            cache: Mutex::new(None),
+        }
+    }
+
+    /// Drops the cache, so the wrapped view will be drawn again.
+    pub fn invalidate(&mut self) {
+        *self.cache.get_mut() = None;
+    }
+
+    /// Returns `true` if the last draw was cached.
+    ///
+    /// The next draw will re-use it, unless the wrapped view needs a relayout
+    /// or the area to draw changed.
+    pub fn is_cached(&self) -> bool {
+        self.cache.lock().is_some()
+    }
+
+    /// Gets access to the inner view.
+    pub fn get_inner(&self) -> &T {
+        &self.view
+    }
+
+    /// Gets mutable access to the inner view.
+    ///
+    /// This drops the cache.
+    pub fn get_inner_mut(&mut self) -> &mut T {
+        self.invalidate();
+        &mut self.view
+    }
+}
+
+impl<T: View> ViewWrapper for CachedView<T> {
+    type V = T;
+
+    fn with_view<F, R>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&Self::V) -> R,
+    {
+        Some(f(&self.view))
+    }
+
+    fn with_view_mut<F, R>(&mut self, f: F) -> Option<R>
+    where
+        F: FnOnce(&mut Self::V) -> R,
+    {
+        self.invalidate();
+        Some(f(&mut self.view))
+    }
+
+    fn into_inner(self) -> Result<Self::V, Self> {
+        Ok(self.view)
+    }
+
+    fn wrap_draw(&self, printer: &Printer) {
+        let key = CacheKey::new(printer);
+
+        let mut cache = self.cache.lock();
+        let fresh = match cache.take() {
+            Some(cache) if cache.key == key && !self.view.needs_relayout() => cache,
+            _ => {
+                let mut buffer = PrintBuffer::new();
+                buffer.resize(printer.output_size);
+                let buffer = RwLock::new(buffer);
+
+                self.view.draw(&printer.detached(&buffer));
+
+                Cache { key, buffer }
+            }
+        };
+        let cache = cache.insert(fresh);
+
+        printer.print_buffer(&cache.buffer.read());
+    }
+
+    fn wrap_layout(&mut self, size: Vec2) {
+        if self.view.needs_relayout() {
+            self.invalidate();
+        }
+        self.view.layout(size);
+    }
+
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        let result = self.view.on_event(event);
+        if result.is_consumed() {
+            self.invalidate();
+        }
+        result
+    }
+
+    fn wrap_take_focus(&mut self, source: Direction) -> Result<EventResult, CannotFocus> {
+        self.invalidate();
+        self.view.take_focus(source)
+    }
+
+    fn wrap_call_on_any(&mut self, selector: &Selector, callback: AnyCb) {
+        self.invalidate();
+        self.view.call_on_any(selector, callback);
+    }
+
+    fn wrap_call_on_focused_child(&mut self, callback: AnyCb) {
+        self.invalidate();
+        callback(&mut self.view);
+    }
+
+    fn wrap_focus_view(&mut self, selector: &Selector) -> Result<EventResult, ViewNotFound> {
+        self.invalidate();
+        self.view.focus_view(selector)
+    }
+}
+
+#[crate::blueprint(CachedView::new(view))]
+struct Blueprint {
+    view: crate::views::BoxedView,
+}
+
+crate::manual_blueprint!(with cached, |_, _| Ok(CachedView::new));
+
+#[cfg(test)]
+mod tests {
+    use super::CachedView;
+    use crate::buffer::PrintBuffer;
+    use crate::event::{Event, EventResult};
+    use crate::theme::Theme;
+    use crate::view::View;
+    use crate::{Printer, Vec2};
+
+    use parking_lot::RwLock;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    struct Counter {
+        draws: Arc<AtomicUsize>,
+    }
+
+    impl View for Counter {
+        fn draw(&self, printer: &Printer) {
+            let draws = self.draws.fetch_add(1, Ordering::Relaxed);
+            printer.print((0, 0), &draws.to_string());
+        }
+
+        fn needs_relayout(&self) -> bool {
+            false
+        }
+
+        fn on_event(&mut self, _: Event) -> EventResult {
+            EventResult::consumed()
+        }
+    }
+
+    #[test]
+    fn reuse_cells() {
+        let draws = Arc::new(AtomicUsize::new(0));
+        let mut view = CachedView::new(Counter {
+            draws: Arc::clone(&draws),
+        });
+
+        let size = Vec2::new(5, 1);
+        let mut buffer = PrintBuffer::new();
+        buffer.resize(size);
+        let buffer = RwLock::new(buffer);
+        let theme = Theme::default();
+        let printer = Printer::new(size, &theme, &buffer);
+
+        view.layout(size);
+        view.draw(&printer);
+        view.draw(&printer);
+        assert_eq!(draws.load(Ordering::Relaxed), 1);
+        assert_eq!(buffer.read().cell_at(Vec2::zero()).unwrap().text(), "0");
+
+        // A different area to draw is not cached.
+        view.draw(&printer.focused(false));
+        assert_eq!(draws.load(Ordering::Relaxed), 2);
+
+        // Events drop the cache.
+        view.on_event(Event::Refresh);
+        view.draw(&printer);
+        assert_eq!(draws.load(Ordering::Relaxed), 3);
+        assert_eq!(buffer.read().cell_at(Vec2::zero()).unwrap().text(), "2");
+    }
+}
@@ -153,8 +153,12 @@ impl View for Checkbox {
     }
 
     fn draw(&self, printer: &Printer) {
-        if self.enabled && printer.enabled {
-            printer.with_selection(printer.focused, |printer| self.draw_internal(printer));
+        if self.enabled && printer.enabled && printer.focused {
+            printer.with_style(printer.theme.focus.toggle, |printer| {
+                self.draw_internal(printer)
+            });
+        } else if self.enabled && printer.enabled {
+            self.draw_internal(printer);
         } else {
             printer.with_style(PaletteStyle::Secondary, |printer| {
                 self.draw_internal(printer)
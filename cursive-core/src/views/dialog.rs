@@ -2,15 +2,23 @@ use crate::{
     align::*,
     direction::{Absolute, Direction, Relative},
     event::{AnyCb, Event, EventResult, Key},
+    jobs::{self, JobContext},
     rect::Rect,
-    style::PaletteStyle,
+    style::{Effect, PaletteStyle},
+    traits::Nameable,
     utils::markup::StyledString,
     view::{CannotFocus, IntoBoxedView, Margins, Selector, View, ViewNotFound},
-    views::{BoxedView, Button, DummyView, LastSizeView, TextView},
-    Cursive, Printer, Vec2, With,
+    views::{BoxedView, Button, DummyView, LastSizeView, NamedView, ProgressBar, TextView},
+    CbSink, Cursive, Printer, Vec2, With,
 };
 use parking_lot::Mutex;
 use std::cmp::{max, min};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+// Name selecting the dialogs with a dismiss delay not started yet.
+const AUTO_DISMISS: &str = "cursive::dialog::auto_dismiss";
 
 /// Identifies currently focused element in [`Dialog`].
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -62,9 +70,42 @@ impl crate::builder::Resolvable for DialogFocus {
     }
 }
 
+/// What a [`Dialog`] mnemonic does with its control.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MnemonicAction {
+    /// Only focus the control.
+    Focus,
+
+    /// Focus the control, then activate it as if `<Enter>` was pressed.
+    ///
+    /// This toggles checkboxes, selects radio buttons and presses buttons.
+    Activate,
+}
+
+// Alt+key mnemonic targeting a named view in the content.
+struct Mnemonic {
+    key: char,
+    target: String,
+    action: MnemonicAction,
+}
+
+/// Option chosen in a [`Dialog::yes_no_cancel`] dialog.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DialogChoice {
+    /// The `Yes` button was pressed.
+    Yes,
+    /// The `No` button was pressed.
+    No,
+    /// The `Cancel` button was pressed.
+    Cancel,
+}
+
 struct ChildButton {
     button: LastSizeView<Button>,
     offset: Mutex<Vec2>,
+
+    // Keys pressing this button from anywhere in the dialog.
+    keys: Vec<Event>,
 }
 
 impl ChildButton {
@@ -75,6 +116,7 @@ impl ChildButton {
         ChildButton {
             button: LastSizeView::new(Button::new(label, cb)),
             offset: Mutex::new(Vec2::zero()),
+            keys: Vec::new(),
         }
     }
 }
@@ -115,6 +157,18 @@ pub struct Dialog {
 
     // `true` when we needs to relayout
     invalidated: bool,
+
+    // If set, the dialog closes itself after this delay.
+    auto_dismiss: Option<Duration>,
+
+    // Hidden name used to find the dialog once the dismiss timer is started.
+    dismiss_name: Option<String>,
+
+    // Alt+key shortcuts to named views in the content.
+    mnemonics: Vec<Mnemonic>,
+
+    // Button pressed by `<Enter>` when the focused view doesn't use it.
+    default_button: Option<usize>,
 }
 
 new_default!(Dialog);
@@ -139,6 +193,10 @@ impl Dialog {
             borders: Margins::lrtb(1, 1, 1, 1),
             align: Align::top_right(),
             invalidated: true,
+            auto_dismiss: None,
+            dismiss_name: None,
+            mnemonics: Vec::new(),
+            default_button: None,
         }
     }
 
@@ -238,6 +296,185 @@ impl Dialog {
         Dialog::text(text).dismiss_button("Ok")
     }
 
+    /// Creates a dialog asking a yes-or-no question.
+    ///
+    /// It has a `Yes` button (pressed with `y`) and a `No` button (pressed
+    /// with `n` or `<Esc>`). `Yes` is the default button.
+    ///
+    /// Pressing a button dismisses the dialog, then calls `cb` with `true` for
+    /// `Yes` and `false` for `No`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::Dialog;
+    ///
+    /// let dialog = Dialog::confirm("Quit now?", |s, yes| {
+    ///     if yes {
+    ///         s.quit();
+    ///     }
+    /// });
+    /// ```
+    pub fn confirm<S, F>(text: S, cb: F) -> Self
+    where
+        S: Into<StyledString>,
+        F: 'static + Fn(&mut Cursive, bool) + Send + Sync,
+    {
+        let cb = Arc::new(cb);
+        let answer = |yes| {
+            let cb = Arc::clone(&cb);
+            move |s: &mut Cursive| {
+                s.pop_layer();
+                cb(s, yes);
+            }
+        };
+
+        Dialog::text(text)
+            .button_with_key("Yes", 'y', answer(true))
+            .button_with_keys(
+                "No",
+                [Event::Char('n'), Event::Key(Key::Esc)],
+                answer(false),
+            )
+            .default_button(0)
+    }
+
+    /// Creates a dialog asking a yes-or-no question, answered through a channel.
+    ///
+    /// This is like [`Dialog::confirm`], but the answer is sent to the
+    /// returned receiver instead of a callback.
+    pub fn confirm_channel<S>(text: S) -> (Self, crossbeam_channel::Receiver<bool>)
+    where
+        S: Into<StyledString>,
+    {
+        let (sender, receiver) = crossbeam_channel::bounded(1);
+        let dialog = Dialog::confirm(text, move |_, yes| {
+            // The receiver may not be listening anymore.
+            sender.try_send(yes).ok();
+        });
+        (dialog, receiver)
+    }
+
+    /// Creates a dialog with `Yes`, `No` and `Cancel` buttons.
+    ///
+    /// The buttons are pressed with `y`, `n` and `<Esc>`. `Yes` is the default
+    /// button.
+    ///
+    /// Pressing a button dismisses the dialog, then calls `cb` with the
+    /// corresponding [`DialogChoice`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::{Dialog, DialogChoice};
+    ///
+    /// let dialog = Dialog::yes_no_cancel("Save changes?", |s, choice| match choice {
+    ///     DialogChoice::Yes => s.quit(),
+    ///     DialogChoice::No => s.quit(),
+    ///     DialogChoice::Cancel => (),
+    /// });
+    /// ```
+    pub fn yes_no_cancel<S, F>(text: S, cb: F) -> Self
+    where
+        S: Into<StyledString>,
+        F: 'static + Fn(&mut Cursive, DialogChoice) + Send + Sync,
+    {
+        let cb = Arc::new(cb);
+        let answer = |choice| {
+            let cb = Arc::clone(&cb);
+            move |s: &mut Cursive| {
+                s.pop_layer();
+                cb(s, choice);
+            }
+        };
+
+        Dialog::text(text)
+            .button_with_key("Yes", 'y', answer(DialogChoice::Yes))
+            .button_with_key("No", 'n', answer(DialogChoice::No))
+            .button_with_key("Cancel", Key::Esc, answer(DialogChoice::Cancel))
+            .default_button(0)
+    }
+
+    /// Creates a dialog with `Yes`, `No` and `Cancel` buttons, answered through a channel.
+    ///
+    /// This is like [`Dialog::yes_no_cancel`], but the choice is sent to the
+    /// returned receiver instead of a callback.
+    pub fn yes_no_cancel_channel<S>(text: S) -> (Self, crossbeam_channel::Receiver<DialogChoice>)
+    where
+        S: Into<StyledString>,
+    {
+        let (sender, receiver) = crossbeam_channel::bounded(1);
+        let dialog = Dialog::yes_no_cancel(text, move |_, choice| {
+            // The receiver may not be listening anymore.
+            sender.try_send(choice).ok();
+        });
+        (dialog, receiver)
+    }
+
+    /// Creates a dialog showing the progress of a background task.
+    ///
+    /// `task` runs in a separate thread, and reports its progress (from 0 to
+    /// 100) through the given [`JobContext`]. The dialog shows a progress bar
+    /// and a `Cancel` button, which cancels the task and dismisses the dialog.
+    ///
+    /// When the task completes, the view it returns replaces the progress bar,
+    /// and the `Cancel` button is replaced with a `Close` button.
+    ///
+    /// The dialog is wrapped in a `NamedView`, so it can be found when the task
+    /// completes. Use [`NamedView::get_mut`] to customize it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use cursive_core::views::{Dialog, TextView};
+    /// # let mut siv = cursive_core::Cursive::new();
+    /// let mut dialog = Dialog::async_loading(siv.cb_sink(), |ctx| {
+    ///     for i in 0..=100 {
+    ///         std::thread::sleep(std::time::Duration::from_millis(20));
+    ///         ctx.set_progress(i);
+    ///     }
+    ///     TextView::new("Done!")
+    /// });
+    /// dialog.get_mut().set_title("Loading");
+    ///
+    /// siv.add_layer(dialog);
+    /// ```
+    ///
+    /// [`JobContext`]: crate::jobs::JobContext
+    pub fn async_loading<F, V>(cb_sink: &CbSink, task: F) -> NamedView<Dialog>
+    where
+        F: FnOnce(&JobContext) -> V + Send + 'static,
+        V: IntoBoxedView + Send + 'static,
+    {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+        let name = format!(
+            "cursive::dialog::async_loading::{}",
+            NEXT_ID.fetch_add(1, Ordering::Relaxed)
+        );
+
+        let handle = jobs::spawn_with_progress(cb_sink, task, {
+            let name = name.clone();
+            move |s, view| {
+                s.call_on_name(&name, |dialog: &mut Dialog| {
+                    dialog.set_content(view);
+                    dialog.clear_buttons();
+                    dialog.add_button("Close", |s| {
+                        s.pop_layer();
+                    });
+                });
+            }
+        });
+
+        let progress = ProgressBar::new().with_value(handle.progress().clone());
+
+        Dialog::around(progress)
+            .button("Cancel", move |s| {
+                handle.cancel();
+                s.pop_layer();
+            })
+            .with_name(name)
+    }
+
     /// Adds a button to the dialog with the given label and callback.
     ///
     /// Consumes and returns self for easy chaining.
@@ -259,14 +496,152 @@ impl Dialog {
         self.invalidate();
     }
 
+    /// Adds a button to the dialog, which can also be pressed with `key`.
+    ///
+    /// The key is shown next to the label, and presses the button from
+    /// anywhere in the dialog, unless the focused view uses it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::event::Key;
+    /// use cursive_core::views::{Dialog, TextView};
+    ///
+    /// let dialog = Dialog::around(TextView::new("Unsaved changes."))
+    ///     .button_with_key("Save", Key::F2, |s| s.quit())
+    ///     .button_with_key("Discard", Key::F3, |s| s.quit());
+    /// ```
+    #[must_use]
+    pub fn button_with_key<F, S, E>(self, label: S, key: E, cb: F) -> Self
+    where
+        F: 'static + Fn(&mut Cursive) + Send + Sync,
+        S: Into<StyledString>,
+        E: Into<Event>,
+    {
+        self.with(|s| s.add_button_with_key(label, key, cb))
+    }
+
+    /// Adds a button to the dialog, which can also be pressed with `key`.
+    ///
+    /// If another button already uses this key, it loses it.
+    pub fn add_button_with_key<F, S, E>(&mut self, label: S, key: E, cb: F)
+    where
+        F: 'static + Fn(&mut Cursive) + Send + Sync,
+        S: Into<StyledString>,
+        E: Into<Event>,
+    {
+        self.add_button_with_keys(label, [key], cb);
+    }
+
+    /// Adds a button to the dialog, which can also be pressed with any of `keys`.
+    ///
+    /// Consumes and returns self for easy chaining.
+    #[must_use]
+    pub fn button_with_keys<F, S, I>(self, label: S, keys: I, cb: F) -> Self
+    where
+        F: 'static + Fn(&mut Cursive) + Send + Sync,
+        S: Into<StyledString>,
+        I: IntoIterator,
+        I::Item: Into<Event>,
+    {
+        self.with(|s| s.add_button_with_keys(label, keys, cb))
+    }
+
+    /// Adds a button to the dialog, which can also be pressed with any of `keys`.
+    ///
+    /// The keys are shown next to the label. If another button already uses
+    /// one of these keys, it loses it.
+    pub fn add_button_with_keys<F, S, I>(&mut self, label: S, keys: I, cb: F)
+    where
+        F: 'static + Fn(&mut Cursive) + Send + Sync,
+        S: Into<StyledString>,
+        I: IntoIterator,
+        I::Item: Into<Event>,
+    {
+        let keys: Vec<Event> = keys.into_iter().map(Into::into).collect();
+        let hint = keys
+            .iter()
+            .map(|key| crate::chords::describe(std::slice::from_ref(key)))
+            .collect::<Vec<_>>()
+            .join("/");
+        let label =
+            StyledString::concatenate([label.into(), StyledString::plain(format!(" ({hint})"))]);
+
+        for button in &mut self.buttons {
+            button.keys.retain(|key| !keys.contains(key));
+        }
+
+        let mut button = ChildButton::new(label, cb);
+        button.keys = keys;
+        self.buttons.push(button);
+        self.invalidate();
+    }
+
+    /// Presses the button using `key`, as if it was focused and activated.
+    ///
+    /// Returns `EventResult::Ignored` if no enabled button uses this key.
+    pub fn press_button_with_key(&mut self, key: &Event) -> EventResult {
+        let Some(button) = self
+            .buttons
+            .iter_mut()
+            .find(|button| button.keys.contains(key))
+        else {
+            return EventResult::Ignored;
+        };
+
+        button.button.on_event(Event::Key(Key::Enter))
+    }
+
     /// Returns the number of buttons on this dialog.
     pub fn buttons_len(&self) -> usize {
         self.buttons.len()
     }
 
+    /// Sets the default button, pressed by `<Enter>`.
+    ///
+    /// `<Enter>` presses the default button when the focused view doesn't use
+    /// it. The default button is drawn in bold.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::{Dialog, EditView};
+    ///
+    /// let dialog = Dialog::around(EditView::new())
+    ///     .button("Cancel", |s| s.quit())
+    ///     .button("Ok", |s| s.quit())
+    ///     .default_button(1);
+    /// ```
+    #[must_use]
+    pub fn default_button(self, i: usize) -> Self {
+        self.with(|s| s.set_default_button(Some(i)))
+    }
+
+    /// Sets the default button, pressed by `<Enter>`.
+    ///
+    /// Use `None` to remove the default button.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= self.buttons_len()`.
+    pub fn set_default_button(&mut self, i: Option<usize>) {
+        if let Some(i) = i {
+            assert!(i < self.buttons.len(), "no button at index {i}");
+        }
+        self.default_button = i;
+    }
+
+    /// Returns the index of the default button, if any.
+    pub fn get_default_button(&self) -> Option<usize> {
+        self.default_button
+    }
+
     /// Removes any button from `self`.
     pub fn clear_buttons(&mut self) -> EventResult {
         self.buttons.clear();
+        self.default_button = None;
         self.invalidate();
         if self.focus != DialogFocus::Content {
             self.focus = DialogFocus::Content;
@@ -286,6 +661,11 @@ impl Dialog {
     pub fn remove_button(&mut self, i: usize) -> EventResult {
         self.buttons.remove(i);
         self.invalidate();
+        self.default_button = match self.default_button {
+            Some(default) if default == i => None,
+            Some(default) if default > i => Some(default - 1),
+            default => default,
+        };
         // Fix focus?
         match (self.buttons.len(), self.focus) {
             (0, ref mut focus) => {
@@ -393,6 +773,159 @@ impl Dialog {
         self.title_position
     }
 
+    /// Closes the dialog automatically after the given delay.
+    ///
+    /// The delay starts when the layer containing the dialog is added with
+    /// [`Cursive::add_layer`] (or another `Cursive` method adding a layer),
+    /// and then this layer is removed. The dialog can still be wrapped in
+    /// other views.
+    ///
+    /// Layers added directly to a [`StackView`] are not removed.
+    ///
+    /// [`Cursive::add_layer`]: crate::Cursive::add_layer
+    /// [`StackView`]: crate::views::StackView
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::Dialog;
+    /// use std::time::Duration;
+    ///
+    /// let mut siv = cursive_core::Cursive::new();
+    /// siv.add_layer(Dialog::info("Saved!").auto_dismiss(Duration::from_secs(2)));
+    /// ```
+    #[must_use]
+    pub fn auto_dismiss(self, delay: Duration) -> Self {
+        self.with(|s| s.set_auto_dismiss(Some(delay)))
+    }
+
+    /// Sets the delay after which the dialog closes itself.
+    ///
+    /// Use `None` to keep the dialog open until it is closed explicitly.
+    pub fn set_auto_dismiss(&mut self, delay: Option<Duration>) {
+        self.auto_dismiss = delay;
+    }
+
+    /// Returns the delay after which the dialog closes itself, if any.
+    pub fn get_auto_dismiss(&self) -> Option<Duration> {
+        self.auto_dismiss
+    }
+
+    // Starts the dismiss delay of the dialogs in `view`, a new layer.
+    //
+    // Returns the name given to each dialog to find its layer once the delay is over, and the
+    // delay itself.
+    pub(crate) fn start_auto_dismiss(view: &mut dyn View) -> Vec<(String, Duration)> {
+        let mut dialogs = Vec::new();
+        view.call_on_any(&Selector::Name(AUTO_DISMISS), &mut |view| {
+            if let Some(dialog) = view.downcast_mut::<Dialog>() {
+                dialogs.extend(dialog.name_for_dismiss());
+            }
+        });
+        dialogs
+    }
+
+    // Names the dialog to find its layer once the delay is over, and returns both.
+    //
+    // Returns `None` if the dialog doesn't close itself, or if it was already named.
+    fn name_for_dismiss(&mut self) -> Option<(String, Duration)> {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+        let delay = self.auto_dismiss?;
+        if self.dismiss_name.is_some() {
+            return None;
+        }
+
+        let name = format!(
+            "cursive::dialog::auto_dismiss::{}",
+            NEXT_ID.fetch_add(1, Ordering::Relaxed)
+        );
+        self.dismiss_name = Some(name.clone());
+        Some((name, delay))
+    }
+
+    /// Adds a mnemonic to this dialog.
+    ///
+    /// Pressing `Alt` and `key` focuses the view named `target` in the
+    /// content, and activates it if `action` is [`MnemonicAction::Activate`].
+    /// The key is case-insensitive.
+    ///
+    /// Use [`mnemonic::parse`] to show the key in the control's label.
+    ///
+    /// [`mnemonic::parse`]: crate::utils::markup::mnemonic::parse
+    pub fn add_mnemonic<S: Into<String>>(&mut self, key: char, target: S, action: MnemonicAction) {
+        let key = key.to_lowercase().next().unwrap_or(key);
+        let target = target.into();
+
+        self.mnemonics.retain(|mnemonic| mnemonic.key != key);
+        self.mnemonics.push(Mnemonic {
+            key,
+            target,
+            action,
+        });
+    }
+
+    /// Adds a mnemonic to this dialog.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::traits::Nameable;
+    /// use cursive_core::utils::markup::mnemonic;
+    /// use cursive_core::views::{Checkbox, Dialog, LinearLayout, MnemonicAction, TextView};
+    ///
+    /// let (label, key) = mnemonic::parse("&Subscribe");
+    ///
+    /// let dialog = Dialog::around(
+    ///     LinearLayout::horizontal()
+    ///         .child(Checkbox::new().with_name("subscribe"))
+    ///         .child(TextView::new(label)),
+    /// )
+    /// .mnemonic(key.unwrap(), "subscribe", MnemonicAction::Activate);
+    /// ```
+    #[must_use]
+    pub fn mnemonic<S: Into<String>>(self, key: char, target: S, action: MnemonicAction) -> Self {
+        self.with(|s| s.add_mnemonic(key, target, action))
+    }
+
+    /// Removes all the mnemonics from this dialog.
+    pub fn clear_mnemonics(&mut self) {
+        self.mnemonics.clear();
+    }
+
+    /// Runs the mnemonic for `key`, as if `Alt` and `key` were pressed.
+    ///
+    /// Returns `EventResult::Ignored` if no mnemonic uses this key, or if
+    /// its target could not be focused.
+    pub fn trigger_mnemonic(&mut self, key: char) -> EventResult {
+        let key = key.to_lowercase().next().unwrap_or(key);
+        let Some(mnemonic) = self.mnemonics.iter().find(|mnemonic| mnemonic.key == key) else {
+            return EventResult::Ignored;
+        };
+
+        let selector = Selector::Name(&mnemonic.target);
+        let Ok(mut result) = self.content.focus_view(&selector) else {
+            return EventResult::Ignored;
+        };
+
+        // The focus may move from a button back to the content.
+        self.focus = DialogFocus::Content;
+
+        if mnemonic.action == MnemonicAction::Activate {
+            let mut activated = EventResult::Ignored;
+            self.content.call_on_any(&selector, &mut |view| {
+                activated = view.on_event(Event::Key(Key::Enter));
+            });
+            result = result.and(activated);
+        }
+
+        result.and(EventResult::consumed())
+    }
+
     /// Sets the padding in the dialog (around content and buttons).
     ///
     /// # Examples
@@ -651,6 +1184,15 @@ impl Dialog {
                     .cropped(size)
                     .focused(self.focus == DialogFocus::Button(i)),
             );
+            if self.default_button == Some(i) {
+                printer.offset(position).cropped(size).on_window(|window| {
+                    for x in 0..window.size().x {
+                        if let Some(style) = window.style_at_mut((x, 0)) {
+                            style.effects.insert(Effect::Bold);
+                        }
+                    }
+                });
+            }
             // Keep 1 blank between two buttons
             offset += size.x + 1;
             // Also keep 1 blank above the buttons
@@ -829,16 +1371,38 @@ impl View for Dialog {
 
     fn on_event(&mut self, event: Event) -> EventResult {
         // First: some mouse events can instantly change the focus.
+        // Wheel events are still passed on if they only moved the focus.
         let res = self
             .check_focus_grab(&event)
+            .filter(|res| !event.is_wheel() || res.has_callback())
             .unwrap_or(EventResult::Ignored);
 
-        res.and(match self.focus {
+        // Mnemonics take precedence over the focused view.
+        if let Event::AltChar(c) = event {
+            let result = self.trigger_mnemonic(c);
+            if result.is_consumed() {
+                return res.and(result);
+            }
+        }
+
+        let result = match self.focus {
             // If we are on the content, we can only go down.
             // TODO: Careful if/when we add buttons elsewhere on the dialog!
-            DialogFocus::Content => self.on_event_content(event),
+            DialogFocus::Content => self.on_event_content(event.clone()),
             // If we are on a button, we have more choice
-            DialogFocus::Button(i) => self.on_event_button(event, i),
+            DialogFocus::Button(i) => self.on_event_button(event.clone(), i),
+        };
+
+        // Button keys only apply to events the focused view doesn't use.
+        res.and(match result {
+            EventResult::Ignored => match (self.press_button_with_key(&event), self.default_button)
+            {
+                (EventResult::Ignored, Some(i)) if event == Event::Key(Key::Enter) => {
+                    self.buttons[i].button.on_event(event)
+                }
+                (result, _) => result,
+            },
+            result => result,
         })
     }
 
@@ -918,13 +1482,38 @@ impl View for Dialog {
     }
 
     fn call_on_any(&mut self, selector: &Selector, callback: AnyCb) {
+        if let Selector::Name(name) = *selector {
+            let waiting = name == AUTO_DISMISS && self.auto_dismiss.is_some();
+            if waiting || Some(name) == self.dismiss_name.as_deref() {
+                return callback(self);
+            }
+        }
         self.content.call_on_any(selector, callback);
     }
 
+    fn call_on_focused_child(&mut self, callback: AnyCb) {
+        match self.focus {
+            DialogFocus::Content => callback(&mut self.content),
+            DialogFocus::Button(i) => {
+                if let Some(button) = self.buttons.get_mut(i) {
+                    callback(&mut button.button);
+                }
+            }
+        }
+    }
+
     fn focus_view(&mut self, selector: &Selector) -> Result<EventResult, ViewNotFound> {
         self.content.focus_view(selector)
     }
 
+    fn reveal_view(&mut self, selector: &Selector, _: Vec2) -> Result<Rect, ViewNotFound> {
+        let offset = self.borders.top_left() + self.padding.top_left();
+        let size = self.content.size;
+        self.content
+            .reveal_view(selector, size)
+            .map(|area| area + offset)
+    }
+
     fn important_area(&self, _: Vec2) -> Rect {
         // Only the content is important.
         // TODO: if a button is focused, return the button position instead.
@@ -974,6 +1563,11 @@ crate::manual_blueprint!(Dialog, |config, context| {
         dialog.set_padding(padding);
     }
 
+    // Delay in seconds.
+    if let Some(delay) = context.resolve::<Option<f64>>(&config["auto_dismiss"])? {
+        dialog.set_auto_dismiss(Some(Duration::from_secs_f64(delay.max(0.0))));
+    }
+
     struct Btn {
         key: String,
         value: std::sync::Arc<dyn Fn(&mut Cursive) + Send + Sync>,
@@ -1002,6 +1596,16 @@ crate::manual_blueprint!(Dialog, |config, context| {
         dialog.add_button_with_cb(btn.key, btn.value);
     }
 
+    if let Some(i) = context.resolve::<Option<usize>>(&config["default_button"])? {
+        if i >= dialog.buttons_len() {
+            return Err(Error::invalid_config(
+                "Default button index out of range",
+                config,
+            ));
+        }
+        dialog.set_default_button(Some(i));
+    }
+
     if let Some(focus) = context.resolve(&config["focus"])? {
         dialog.set_focus(focus);
     }
@@ -1023,3 +1627,185 @@ crate::fn_blueprint!("Dialog.info", |config, context| {
         s.add_layer(Dialog::info(message.clone()));
     }))
 });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view::{Finder, Resizable};
+    use crate::views::{Checkbox, EditView, LinearLayout, TextView};
+
+    #[test]
+    fn mnemonics() {
+        let mut dialog = Dialog::around(
+            LinearLayout::vertical()
+                .child(EditView::new().with_name("name"))
+                .child(Checkbox::new().with_name("subscribe")),
+        )
+        .button("Ok", |_| ())
+        .mnemonic('n', "name", MnemonicAction::Focus)
+        .mnemonic('S', "subscribe", MnemonicAction::Activate);
+
+        let checked = |dialog: &mut Dialog| {
+            dialog
+                .call_on_name("subscribe", |checkbox: &mut Checkbox| checkbox.is_checked())
+                .unwrap()
+        };
+
+        dialog.set_focus(DialogFocus::Button(0));
+        assert!(dialog.on_event(Event::AltChar('s')).is_consumed());
+        assert!(checked(&mut dialog));
+        assert_eq!(dialog.focus(), DialogFocus::Content);
+
+        // The checkbox is now focused.
+        dialog.on_event(Event::Char(' '));
+        assert!(!checked(&mut dialog));
+
+        // Focus only moves the focus.
+        dialog.on_event(Event::AltChar('N'));
+        dialog.on_event(Event::Char('x'));
+        let content = dialog.call_on_name("name", |edit: &mut EditView| edit.get_content());
+        assert_eq!(content.unwrap().as_str(), "x");
+        assert!(!checked(&mut dialog));
+
+        // Unknown mnemonics go to the focused view.
+        assert!(!dialog.on_event(Event::AltChar('z')).is_consumed());
+    }
+
+    #[test]
+    fn async_loading() {
+        let mut siv = Cursive::new();
+        let dialog = Dialog::async_loading(siv.cb_sink(), |_| TextView::new("Done"));
+        let name = dialog.name().to_string();
+        siv.add_layer(dialog);
+
+        let labels = |siv: &mut Cursive| {
+            siv.call_on_name(&name, |dialog: &mut Dialog| {
+                dialog
+                    .buttons()
+                    .map(|b| b.label().to_string())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap()
+        };
+        assert_eq!(labels(&mut siv), ["<Cancel>"]);
+
+        // Wait for the task to report back.
+        while !siv.process_callback() {
+            std::thread::yield_now();
+        }
+        assert_eq!(labels(&mut siv), ["<Close>"]);
+    }
+
+    #[test]
+    fn button_keys() {
+        let mut dialog = Dialog::around(EditView::new().with_name("name"))
+            .button_with_key("Save", Key::F2, |_| ())
+            .button_with_key("Quit", Event::CtrlChar('q'), |_| ());
+
+        let labels: Vec<&str> = dialog.buttons().map(Button::label).collect();
+        assert_eq!(labels, ["<Save (F2)>", "<Quit (Ctrl-q)>"]);
+
+        // The key presses the button without moving the focus.
+        assert!(dialog.on_event(Event::Key(Key::F2)).has_callback());
+        assert_eq!(dialog.focus(), DialogFocus::Content);
+        assert!(dialog.on_event(Event::CtrlChar('q')).has_callback());
+
+        // Keys used by the focused view are not stolen.
+        dialog.on_event(Event::Char('x'));
+        dialog.add_button_with_key("Extra", 'x', |_| ());
+        dialog.on_event(Event::Char('x'));
+        let content = dialog.call_on_name("name", |edit: &mut EditView| edit.get_content());
+        assert_eq!(content.unwrap().as_str(), "xx");
+    }
+
+    #[test]
+    fn default_button() {
+        let mut dialog = Dialog::around(EditView::new())
+            .button("Cancel", |_| ())
+            .button("Ok", |_| ())
+            .default_button(1);
+
+        // Enter presses the default button when the content doesn't use it.
+        assert!(dialog.on_event(Event::Key(Key::Enter)).has_callback());
+        assert_eq!(dialog.focus(), DialogFocus::Content);
+
+        // Removing buttons keeps the default button in sync.
+        dialog.remove_button(0);
+        assert_eq!(dialog.get_default_button(), Some(0));
+        dialog.remove_button(0);
+        assert_eq!(dialog.get_default_button(), None);
+        assert!(!dialog.on_event(Event::Key(Key::Enter)).is_consumed());
+    }
+
+    #[test]
+    fn presets() {
+        let (dialog, receiver) = Dialog::yes_no_cancel_channel("Save changes?");
+        let labels: Vec<&str> = dialog.buttons().map(Button::label).collect();
+        assert_eq!(labels, ["<Yes (y)>", "<No (n)>", "<Cancel (Esc)>"]);
+
+        let mut siv = Cursive::new();
+        siv.add_layer(dialog);
+        siv.on_event(Event::Key(Key::Esc));
+        assert_eq!(receiver.try_recv(), Ok(DialogChoice::Cancel));
+        assert_eq!(siv.screen().len(), 0);
+
+        let (dialog, receiver) = Dialog::confirm_channel("Quit?");
+        let labels: Vec<&str> = dialog.buttons().map(Button::label).collect();
+        assert_eq!(labels, ["<Yes (y)>", "<No (n/Esc)>"]);
+
+        siv.add_layer(dialog);
+        siv.on_event(Event::Char('n'));
+        assert_eq!(receiver.try_recv(), Ok(false));
+
+        let (dialog, receiver) = Dialog::confirm_channel("Quit?");
+        siv.add_layer(dialog);
+        siv.on_event(Event::Key(Key::Enter));
+        assert_eq!(receiver.try_recv(), Ok(true));
+        assert_eq!(siv.screen().len(), 0);
+    }
+
+    #[test]
+    fn default_button_is_bold() {
+        let mut siv = Cursive::new();
+        siv.add_layer(
+            Dialog::around(EditView::new().fixed_width(20))
+                .button("Cancel", |_| ())
+                .button("Ok", |_| ())
+                .default_button(1),
+        );
+
+        let screen = crate::test::Harness::with_cursive(siv, (40, 10)).screen();
+        let (ok, cancel) = (
+            screen.find("<Ok>").unwrap(),
+            screen.find("<Cancel>").unwrap(),
+        );
+        let bold = |pos| screen.style_at(pos).unwrap().effects.contains(Effect::Bold);
+        assert!(bold(ok));
+        assert!(!bold(cancel));
+    }
+
+    #[test]
+    fn auto_dismiss() {
+        let mut siv = Cursive::new();
+        siv.add_layer(TextView::new("Back"));
+        siv.add_layer(
+            Dialog::text("Saved")
+                .auto_dismiss(Duration::ZERO)
+                .max_width(40),
+        );
+        siv.add_layer(Dialog::text("Kept").auto_dismiss(Duration::from_secs(3600)));
+        siv.screen_mut()
+            .add_layer(Dialog::text("Direct").auto_dismiss(Duration::ZERO));
+        assert_eq!(siv.screen().len(), 4);
+
+        // The timers start when the layers are added, not when they are laid out.
+        siv.process_timers();
+        assert_eq!(siv.screen().len(), 3);
+
+        // The remaining dialogs are still plain dialogs.
+        let direct = siv.pop_layer().unwrap();
+        assert!(direct.as_any().downcast_ref::<Dialog>().is_some());
+        let kept = siv.pop_layer().unwrap();
+        assert!(kept.as_any().downcast_ref::<Dialog>().is_some());
+    }
+}
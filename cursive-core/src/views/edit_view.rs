@@ -1,13 +1,19 @@
 use crate::{
+    clipboard,
     direction::Direction,
-    event::{Callback, Event, EventResult, Key, MouseEvent},
+    event::{Callback, Event, EventResult, Key, MouseButton, MouseEvent},
+    keymap,
     rect::Rect,
-    style::{PaletteStyle, StyleType},
+    style::{ColorStyle, ColorType, Effect, Effects, PaletteStyle, Style, StyleType},
     utils::lines::simple::{simple_prefix, simple_suffix},
+    utils::{ViAction, ViBindings, ViMode},
     view::{CannotFocus, View},
     Cursive, Printer, Vec2, With,
 };
+use std::cell::RefCell;
+use std::collections::VecDeque;
 use std::sync::{Arc, Mutex};
+use std::thread::LocalKey;
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
@@ -22,6 +28,56 @@ pub type OnEdit = dyn Fn(&mut Cursive, &str, usize) + Send + Sync;
 /// Arguments are the `Cursive` and the content of the input.
 pub type OnSubmit = dyn Fn(&mut Cursive, &str) + Send + Sync;
 
+// Accepts or rejects characters as they are typed.
+type Filter = dyn Fn(char) -> bool + Send + Sync;
+
+// Checks the content, returning an error message if it is invalid.
+type Validator = dyn Fn(&str) -> Result<(), String> + Send + Sync;
+
+/// Set of characters accepted by an [`EditView`].
+///
+/// See [`EditView::set_mask`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InputMask {
+    /// Only ASCII digits (`0-9`).
+    Digits,
+
+    /// Numbers: ASCII digits, signs (`+`, `-`) and the decimal point (`.`).
+    Numeric,
+
+    /// Hexadecimal digits (`0-9`, `a-f` and `A-F`).
+    HexDigits,
+
+    /// Letters and digits, from any script.
+    Alphanumeric,
+}
+
+impl InputMask {
+    /// Returns `true` if this mask accepts `ch`.
+    pub fn accepts(self, ch: char) -> bool {
+        match self {
+            InputMask::Digits => ch.is_ascii_digit(),
+            InputMask::Numeric => ch.is_ascii_digit() || matches!(ch, '+' | '-' | '.'),
+            InputMask::HexDigits => ch.is_ascii_hexdigit(),
+            InputMask::Alphanumeric => ch.is_alphanumeric(),
+        }
+    }
+}
+
+impl std::str::FromStr for InputMask {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Digits" | "digits" => InputMask::Digits,
+            "Numeric" | "numeric" => InputMask::Numeric,
+            "HexDigits" | "hex_digits" => InputMask::HexDigits,
+            "Alphanumeric" | "alphanumeric" => InputMask::Alphanumeric,
+            _ => return Err(()),
+        })
+    }
+}
+
 /// Input box where the user can enter and edit text.
 ///
 /// # Examples
@@ -75,6 +131,9 @@ pub struct EditView {
     /// Cursor position in the content, in bytes.
     cursor: usize,
 
+    /// Text selected with the mouse, as `(anchor, head)` byte offsets.
+    selection: Option<(usize, usize)>,
+
     /// Number of bytes to skip at the beginning of the content.
     ///
     /// (When the content is too long for the display, we hide part of it)
@@ -96,6 +155,15 @@ pub struct EditView {
     /// Callback when `<Enter>` is pressed.
     on_submit: Option<Arc<OnSubmit>>,
 
+    /// Only characters accepted by this filter can be typed.
+    filter: Option<Arc<Filter>>,
+
+    /// Checks the content, and gives an error message if it is invalid.
+    validator: Option<Arc<Validator>>,
+
+    /// When `true`, the validation error is shown after the content.
+    show_error: bool,
+
     /// When `true`, only print `*` instead of the true content.
     secret: bool,
 
@@ -104,9 +172,13 @@ pub struct EditView {
 
     enabled: bool,
 
+    /// Modal editing state, if vi bindings are enabled.
+    vi: Option<ViBindings>,
+
     regular_style: StyleType,
     inactive_style: StyleType,
     cursor_style: StyleType,
+    error_style: StyleType,
 }
 
 new_default!(EditView);
@@ -119,20 +191,85 @@ impl EditView {
         EditView {
             content: Arc::new(String::new()),
             cursor: 0,
+            selection: None,
             offset: 0,
             last_length: 0, // scrollable: false,
             on_edit: None,
             on_submit: None,
+            filter: None,
+            validator: None,
+            show_error: false,
             max_content_width: None,
             secret: false,
             filler: "_".to_string(),
             enabled: true,
+            vi: None,
             regular_style: PaletteStyle::EditableText.into(),
             inactive_style: PaletteStyle::EditableTextInactive.into(),
             cursor_style: PaletteStyle::EditableTextCursor.into(),
+            error_style: Style {
+                color: ColorStyle::title_primary(),
+                effects: Effects::only(Effect::Reverse),
+                underline: ColorType::InheritParent,
+            }
+            .into(),
         }
     }
 
+    /// Enables or disables vi-style modal editing.
+    ///
+    /// When enabled, the view starts in normal mode. See [`ViBindings`] for
+    /// the supported commands.
+    pub fn set_vi_bindings(&mut self, enabled: bool) {
+        self.vi = if enabled {
+            Some(ViBindings::new())
+        } else {
+            None
+        };
+        self.selection = None;
+    }
+
+    /// Enables vi-style modal editing.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn with_vi_bindings(self) -> Self {
+        self.with(|s| s.set_vi_bindings(true))
+    }
+
+    /// Returns the current editing mode, if vi bindings are enabled.
+    pub fn vi_mode(&self) -> Option<ViMode> {
+        self.vi.as_ref().map(ViBindings::mode)
+    }
+
+    // Gives the event to the vi bindings, if any.
+    //
+    // Returns `None` if the event should be handled as usual.
+    fn on_vi_event(&mut self, event: &Event) -> Option<EventResult> {
+        let vi = self.vi.as_mut()?;
+        let result = match vi.on_event(&self.content, self.cursor, event, false) {
+            ViAction::Pass => return None,
+            ViAction::Ignored => EventResult::Ignored,
+            ViAction::Consumed => EventResult::consumed(),
+            ViAction::Move(cursor) => {
+                self.set_cursor(cursor);
+                EventResult::consumed()
+            }
+            ViAction::Edit { content, cursor } => {
+                self.content = Arc::new(content);
+                self.offset = 0;
+                self.set_cursor(cursor);
+                EventResult::Consumed(self.make_edit_cb())
+            }
+        };
+
+        self.selection = match &self.vi {
+            Some(vi) if !self.secret => vi.selection(&self.content, self.cursor),
+            _ => None,
+        };
+        Some(result)
+    }
+
     /// Sets a maximum width for the content.
     ///
     /// Input will be rejected if it would make the content exceed this width.
@@ -353,6 +490,126 @@ impl EditView {
         self.with(|v| v.set_on_submit(callback))
     }
 
+    /// Sets a filter for typed characters.
+    ///
+    /// Characters rejected by `filter` are dropped when typed or pasted.
+    /// Content given with [`set_content`](Self::set_content) is not filtered.
+    pub fn set_filter<F>(&mut self, filter: F)
+    where
+        F: Fn(char) -> bool + 'static + Send + Sync,
+    {
+        self.filter = Some(Arc::new(filter));
+    }
+
+    /// Sets a filter for typed characters.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::views::EditView;
+    /// let username = EditView::new().filter(|c| c.is_ascii_lowercase() || c == '_');
+    /// ```
+    #[must_use]
+    pub fn filter<F>(self, filter: F) -> Self
+    where
+        F: Fn(char) -> bool + 'static + Send + Sync,
+    {
+        self.with(|v| v.set_filter(filter))
+    }
+
+    /// Only accepts the characters from the given mask.
+    ///
+    /// This replaces any filter set with [`set_filter`](Self::set_filter).
+    pub fn set_mask(&mut self, mask: InputMask) {
+        self.set_filter(move |ch| mask.accepts(ch));
+    }
+
+    /// Only accepts the characters from the given mask.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn mask(self, mask: InputMask) -> Self {
+        self.with(|v| v.set_mask(mask))
+    }
+
+    /// Sets a validator for the content.
+    ///
+    /// `validator` returns an error message when the content is invalid.
+    /// Invalid content is drawn with the error style, and `<Enter>` won't
+    /// trigger the submit callback.
+    pub fn set_validator<F>(&mut self, validator: F)
+    where
+        F: Fn(&str) -> Result<(), String> + 'static + Send + Sync,
+    {
+        self.validator = Some(Arc::new(validator));
+    }
+
+    /// Sets a validator for the content.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::views::{EditView, InputMask};
+    /// let port = EditView::new()
+    ///     .mask(InputMask::Digits)
+    ///     .validator(|text| match text.parse::<u16>() {
+    ///         Ok(_) => Ok(()),
+    ///         Err(_) => Err("Invalid port".into()),
+    ///     })
+    ///     .show_error();
+    /// ```
+    #[must_use]
+    pub fn validator<F>(self, validator: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + 'static + Send + Sync,
+    {
+        self.with(|v| v.set_validator(validator))
+    }
+
+    /// Returns the error message for the current content, if it is invalid.
+    pub fn validation_error(&self) -> Option<String> {
+        self.validator.as_ref()?(&self.content).err()
+    }
+
+    /// Returns `true` unless the validator rejects the current content.
+    pub fn is_valid(&self) -> bool {
+        self.validation_error().is_none()
+    }
+
+    /// If `show_error` is `true`, the validation error is shown inline.
+    ///
+    /// The message is printed at the end of the view, if it fits after the
+    /// content.
+    pub fn set_show_error(&mut self, show_error: bool) {
+        self.show_error = show_error;
+    }
+
+    /// Shows the validation error inline.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn show_error(self) -> Self {
+        self.with(|v| v.set_show_error(true))
+    }
+
+    /// Sets the style used when the content is invalid.
+    ///
+    /// Defaults to the reversed `TitlePrimary` color.
+    pub fn set_error_style<S: Into<StyleType>>(&mut self, style: S) {
+        self.error_style = style.into();
+    }
+
+    fn accepts(&self, ch: char) -> bool {
+        match &self.filter {
+            Some(filter) => filter(ch),
+            None => true,
+        }
+    }
+
     /// Replace the entire content of the view with the given one.
     ///
     /// Returns a callback in response to content change.
@@ -363,6 +620,7 @@ impl EditView {
         let len = content.len();
 
         self.content = Arc::new(content);
+        self.selection = None;
         self.offset = 0;
         self.set_cursor(len);
 
@@ -386,6 +644,25 @@ impl EditView {
         self
     }
 
+    /// Returns the text currently selected with the mouse, if any.
+    ///
+    /// Nothing can be selected in a secret view.
+    pub fn selected_text(&self) -> Option<&str> {
+        let (start, end) = self.selection_range()?;
+        Some(&self.content[start..end])
+    }
+
+    /// Returns the selected range of bytes, if it isn't empty.
+    fn selection_range(&self) -> Option<(usize, usize)> {
+        let (anchor, head) = self.selection?;
+        (anchor != head).then(|| (anchor.min(head), anchor.max(head)))
+    }
+
+    /// Returns the byte offset for the given column in the view.
+    fn offset_at(&self, x: usize) -> usize {
+        self.offset + simple_prefix(&self.content[self.offset..], x).length
+    }
+
     /// Returns the currest cursor position.
     pub fn get_cursor(&self) -> usize {
         self.cursor
@@ -405,6 +682,10 @@ impl EditView {
     /// You should run this callback with a `&mut Cursive`.
     pub fn insert(&mut self, ch: char) -> Callback {
         // First, make sure we can actually insert anything.
+        if !self.accepts(ch) {
+            return Callback::dummy();
+        }
+
         if let Some(width) = self.max_content_width {
             // XXX: we assume here that the widths are linearly additive.
             // Is that true? What about weird combined unicode thingies?
@@ -421,6 +702,7 @@ impl EditView {
         // and it will clone it into `self.content` otherwise.
 
         Arc::make_mut(&mut self.content).insert(self.cursor, ch);
+        self.selection = None;
         self.cursor += ch.len_utf8();
 
         self.keep_cursor_in_view();
@@ -428,6 +710,55 @@ impl EditView {
         self.make_edit_cb().unwrap_or_else(Callback::dummy)
     }
 
+    /// Insert `text` at the current cursor position.
+    ///
+    /// Line breaks and tabs are replaced with spaces, and other control
+    /// characters are dropped, as well as characters rejected by the filter.
+    /// If a maximum content width is set, the text is cut to fit.
+    ///
+    /// Returns a callback in response to content change.
+    ///
+    /// You should run this callback with a `&mut Cursive`.
+    pub fn insert_str(&mut self, text: &str) -> Callback {
+        let mut available = self
+            .max_content_width
+            .map(|width| width.saturating_sub(self.content.width()));
+
+        let mut inserted = String::new();
+        for grapheme in text.graphemes(true) {
+            let grapheme = match grapheme {
+                "\n" | "\r\n" | "\r" | "\t" => " ",
+                g if g.chars().any(char::is_control) => continue,
+                g => g,
+            };
+            if !grapheme.chars().all(|ch| self.accepts(ch)) {
+                continue;
+            }
+
+            if let Some(available) = &mut available {
+                let width = grapheme.width();
+                if width > *available {
+                    break;
+                }
+                *available -= width;
+            }
+
+            inserted.push_str(grapheme);
+        }
+
+        if inserted.is_empty() {
+            return Callback::dummy();
+        }
+
+        Arc::make_mut(&mut self.content).insert_str(self.cursor, &inserted);
+        self.selection = None;
+        self.cursor += inserted.len();
+
+        self.keep_cursor_in_view();
+
+        self.make_edit_cb().unwrap_or_else(Callback::dummy)
+    }
+
     /// Remove the character at the current cursor position.
     ///
     /// Returns a callback in response to content change.
@@ -437,12 +768,26 @@ impl EditView {
         let start = self.cursor;
         let end = self.cursor + len.min(self.content.len() - self.cursor);
         for _ in Arc::make_mut(&mut self.content).drain(start..end) {}
+        self.selection = None;
 
         self.keep_cursor_in_view();
 
         self.make_edit_cb().unwrap_or_else(Callback::dummy)
     }
 
+    // Removes `start..end`, saving it in the kill ring.
+    fn kill(&mut self, start: usize, end: usize) -> Callback {
+        // Don't keep passwords around.
+        if !self.secret && start < end {
+            push_kill(self.content[start..end].to_string());
+        }
+
+        let content = format!("{}{}", &self.content[..start], &self.content[end..]);
+        let callback = self.set_content(content);
+        self.set_cursor(start);
+        callback
+    }
+
     fn make_edit_cb(&self) -> Option<Callback> {
         self.on_edit.clone().map(|cb| {
             // Get a new Arc on the content
@@ -502,6 +847,45 @@ impl EditView {
     }
 }
 
+// Maximum number of entries in the kill ring.
+const KILL_RING_SIZE: usize = 32;
+
+thread_local! {
+    // Text killed by any `EditView`, most recent last.
+    static KILL_RING: RefCell<VecDeque<String>> = RefCell::new(VecDeque::new());
+}
+
+// `LocalKey::with` is spelled out, since `With::with` would take precedence.
+fn push_kill(text: String) {
+    LocalKey::with(&KILL_RING, |ring| {
+        let mut ring = ring.borrow_mut();
+        if ring.len() == KILL_RING_SIZE {
+            ring.pop_front();
+        }
+        ring.push_back(text);
+    });
+}
+
+fn last_kill() -> Option<String> {
+    LocalKey::with(&KILL_RING, |ring| ring.borrow().back().cloned())
+}
+
+// Start of the word before `cursor`, where `is_separator` delimits words.
+fn prev_word_start(text: &str, cursor: usize, is_separator: impl Fn(char) -> bool) -> usize {
+    let before = text[..cursor].trim_end_matches(&is_separator);
+    before
+        .rfind(&is_separator)
+        .map_or(0, |i| i + before[i..].chars().next().unwrap().len_utf8())
+}
+
+// End of the word after `cursor`.
+fn next_word_end(text: &str, cursor: usize) -> usize {
+    let is_separator = |c: char| !c.is_alphanumeric();
+    let after = text[cursor..].trim_start_matches(is_separator);
+    let start = text.len() - after.len();
+    after.find(is_separator).map_or(text.len(), |i| start + i)
+}
+
 /// Returns a `&str` with `length` characters `*`.
 ///
 /// Only works for small `length` (1 or 2).
@@ -524,10 +908,20 @@ impl View for EditView {
             self.last_length, printer.size.x
         );
 
-        let (style, cursor_style) = if self.enabled && printer.enabled {
+        let error = self.validation_error();
+        let (style, cursor_style) = if !(self.enabled && printer.enabled) {
+            (self.inactive_style, self.inactive_style)
+        } else if error.is_some() {
+            (self.error_style, self.cursor_style)
+        } else {
             (self.regular_style, self.cursor_style)
+        };
+        let style = if self.enabled && printer.enabled && printer.focused {
+            let palette = &printer.theme.palette;
+            let focus = printer.theme.focus.input.resolve(palette);
+            style.resolve(palette).combine(focus).into()
         } else {
-            (self.inactive_style, self.inactive_style)
+            style
         };
 
         let width = self.content.width();
@@ -542,6 +936,13 @@ impl View for EditView {
                 }
                 let filler_len = (printer.size.x - width) / self.filler.width();
                 printer.print_hline((width, 0), filler_len, self.filler.as_str());
+
+                // Keep a cell free for the cursor after the content.
+                if let (true, Some(error)) = (self.show_error, &error) {
+                    if width + 1 + error.width() <= self.last_length {
+                        printer.print((self.last_length - error.width(), 0), error);
+                    }
+                }
             } else {
                 let content = &self.content[self.offset..];
                 let display_bytes = content
@@ -573,6 +974,12 @@ impl View for EditView {
             }
         });
 
+        if let Some((start, end)) = self.selection_range() {
+            let first = self.content[self.offset..start.max(self.offset)].width();
+            let last = self.content[self.offset..end.max(self.offset)].width();
+            printer.highlight_selection((first, 0), (last, 0));
+        }
+
         // Now print cursor
         if printer.focused {
             let c: &str = if self.cursor == self.content.len() {
@@ -613,29 +1020,49 @@ impl View for EditView {
         if !self.enabled {
             return EventResult::Ignored;
         }
+
+        if let Some(result) = self.on_vi_event(&event) {
+            return result;
+        }
+
+        if !matches!(event, Event::Mouse { .. }) {
+            // Any other input cancels the selection.
+            self.selection = None;
+        }
+
         match event {
             Event::Char(ch) => {
                 return EventResult::Consumed(Some(self.insert(ch)));
             }
-            Event::CtrlChar('u') => {
-                // kill-to-front
-                let content = self.content[self.cursor..].to_owned();
-                let callback = self.set_content(content);
-                self.set_cursor(0);
-                return EventResult::Consumed(Some(callback));
+            Event::Paste(text) => {
+                return EventResult::Consumed(Some(self.insert_str(&text)));
+            }
+            _ if keymap::matches("kill_to_start", &event) => {
+                let cursor = self.cursor;
+                return EventResult::Consumed(Some(self.kill(0, cursor)));
             }
-            Event::CtrlChar('k') => {
-                // kill-to-end
-                let content = self.content[..self.cursor].to_owned();
-                return EventResult::Consumed(Some(self.set_content(content)));
+            _ if keymap::matches("kill_to_end", &event) => {
+                let (cursor, len) = (self.cursor, self.content.len());
+                return EventResult::Consumed(Some(self.kill(cursor, len)));
             }
-            Event::Key(Key::Home) | Event::CtrlChar('a') => self.set_cursor(0),
-            Event::Key(Key::End) | Event::CtrlChar('e') => {
+            _ if self.cursor > 0 && keymap::matches("kill_word_back", &event) => {
+                let start = prev_word_start(&self.content, self.cursor, char::is_whitespace);
+                let cursor = self.cursor;
+                return EventResult::Consumed(Some(self.kill(start, cursor)));
+            }
+            _ if keymap::matches("yank", &event) => {
+                let Some(text) = last_kill() else {
+                    return EventResult::Ignored;
+                };
+                return EventResult::Consumed(Some(self.insert_str(&text)));
+            }
+            _ if keymap::matches("cursor_home", &event) => self.set_cursor(0),
+            _ if keymap::matches("cursor_end", &event) => {
                 // When possible, NLL to the rescue!
                 let len = self.content.len();
                 self.set_cursor(len);
             }
-            Event::Key(Key::Left) | Event::CtrlChar('b') if self.cursor > 0 => {
+            _ if self.cursor > 0 && keymap::matches("cursor_left", &event) => {
                 let len = self.content[..self.cursor]
                     .graphemes(true)
                     .last()
@@ -644,7 +1071,7 @@ impl View for EditView {
                 let cursor = self.cursor - len;
                 self.set_cursor(cursor);
             }
-            Event::Key(Key::Right) | Event::CtrlChar('f') if self.cursor < self.content.len() => {
+            _ if self.cursor < self.content.len() && keymap::matches("cursor_right", &event) => {
                 let len = self.content[self.cursor..]
                     .graphemes(true)
                     .next()
@@ -653,6 +1080,16 @@ impl View for EditView {
                 let cursor = self.cursor + len;
                 self.set_cursor(cursor);
             }
+            _ if self.cursor > 0 && keymap::matches("cursor_word_left", &event) => {
+                let cursor = prev_word_start(&self.content, self.cursor, |c| !c.is_alphanumeric());
+                self.set_cursor(cursor);
+            }
+            _ if self.cursor < self.content.len()
+                && keymap::matches("cursor_word_right", &event) =>
+            {
+                let cursor = next_word_end(&self.content, self.cursor);
+                self.set_cursor(cursor);
+            }
             Event::Key(Key::Backspace) if self.cursor > 0 => {
                 let len = self.content[..self.cursor]
                     .graphemes(true)
@@ -670,7 +1107,10 @@ impl View for EditView {
                     .len();
                 return EventResult::Consumed(Some(self.remove(len)));
             }
-            Event::Key(Key::Enter) if self.on_submit.is_some() => {
+            _ if self.on_submit.is_some() && keymap::matches("submit", &event) => {
+                if !self.is_valid() {
+                    return EventResult::consumed();
+                }
                 let cb = self.on_submit.clone().unwrap();
                 let content = Arc::clone(&self.content);
                 return EventResult::with_cb(move |s| {
@@ -678,15 +1118,36 @@ impl View for EditView {
                 });
             }
             Event::Mouse {
-                event: MouseEvent::Press(_),
+                event: MouseEvent::Press(button),
                 position,
                 offset,
             } if position.fits_in_rect(offset, (self.last_length, 1)) => {
                 if let Some(position) = position.checked_sub(offset) {
-                    self.cursor = self.offset
-                        + simple_prefix(&self.content[self.offset..], position.x).length;
+                    self.cursor = self.offset_at(position.x);
+                    self.selection = (button == MouseButton::Left && !self.secret)
+                        .then(|| (self.cursor, self.cursor));
                 }
             }
+            Event::Mouse {
+                event: MouseEvent::Hold(MouseButton::Left),
+                position,
+                offset,
+            } if self.selection.is_some() => {
+                self.cursor = self.offset_at(position.saturating_sub(offset).x);
+                self.selection = self.selection.map(|(anchor, _)| (anchor, self.cursor));
+                return EventResult::consumed();
+            }
+            Event::Mouse {
+                event: MouseEvent::Release(MouseButton::Left),
+                ..
+            } => {
+                return match self.selected_text() {
+                    Some(text) => {
+                        EventResult::Consumed(Some(clipboard::copy_callback(text.to_string())))
+                    }
+                    None => EventResult::Ignored,
+                };
+            }
             _ => return EventResult::Ignored,
         }
 
@@ -721,6 +1182,10 @@ struct Blueprint {
     on_edit: Option<_>,
 
     on_submit: Option<_>,
+
+    vi_bindings: Option<bool>,
+
+    mask: Option<InputMask>,
 }
 
 // The above blueprint would expand to:
@@ -810,4 +1275,125 @@ mod tests {
         assert_eq!(view.get_cursor(), 3);
         assert_eq!(*view.get_content(), "bar");
     }
+
+    #[test]
+    fn mouse_selection() {
+        use crate::event::{MouseButton, MouseEvent};
+
+        let mouse = |event, x: usize| Event::Mouse {
+            event,
+            position: Vec2::new(x, 0),
+            offset: Vec2::zero(),
+        };
+
+        let mut view = EditView::new().content("some content");
+        view.layout(Vec2::new(20, 1));
+
+        view.on_event(mouse(MouseEvent::Press(MouseButton::Left), 12));
+        view.on_event(mouse(MouseEvent::Hold(MouseButton::Left), 5));
+        assert_eq!(view.selected_text(), Some("content"));
+        assert_eq!(view.get_cursor(), 5);
+
+        let result = view.on_event(mouse(MouseEvent::Release(MouseButton::Left), 5));
+        assert!(matches!(result, EventResult::Consumed(Some(_))));
+
+        // Secret views can't be selected.
+        let mut view = EditView::new().content("password").secret();
+        view.layout(Vec2::new(20, 1));
+        view.on_event(mouse(MouseEvent::Press(MouseButton::Left), 0));
+        view.on_event(mouse(MouseEvent::Hold(MouseButton::Left), 4));
+        assert_eq!(view.selected_text(), None);
+    }
+
+    #[test]
+    fn paste() {
+        let mut view = EditView::new().content("ab").max_content_width(8);
+        view.set_cursor(1);
+
+        view.on_event(Event::Paste("x\ty\nzzzzz".into()));
+        assert_eq!(*view.get_content(), "ax y zzb");
+        assert_eq!(view.get_cursor(), 7);
+    }
+
+    #[test]
+    fn readline() {
+        let mut view = EditView::new().content("git commit  -m");
+        view.on_event(Event::AltChar('b'));
+        assert_eq!(view.get_cursor(), 13);
+        view.on_event(Event::AltChar('b'));
+        assert_eq!(view.get_cursor(), 4);
+        view.on_event(Event::AltChar('f'));
+        assert_eq!(view.get_cursor(), 10);
+
+        view.on_event(Event::CtrlChar('e'));
+        view.on_event(Event::CtrlChar('w'));
+        assert_eq!(*view.get_content(), "git commit  ");
+        view.on_event(Event::CtrlChar('w'));
+        assert_eq!(*view.get_content(), "git ");
+
+        // The last killed text is yanked back, in any view.
+        let mut other = EditView::new();
+        other.on_event(Event::CtrlChar('y'));
+        assert_eq!(*other.get_content(), "commit  ");
+    }
+
+    #[test]
+    fn vi_bindings() {
+        use crate::utils::ViMode;
+
+        let mut view = EditView::new().content("hello world").with_vi_bindings();
+        view.set_cursor(0);
+
+        for c in "dwiX".chars() {
+            view.on_event(Event::Char(c));
+        }
+        assert_eq!(view.vi_mode(), Some(ViMode::Insert));
+        assert!(view.on_event(Event::Key(Key::Esc)).is_consumed());
+        assert_eq!(*view.get_content(), "Xworld");
+        assert_eq!(view.get_cursor(), 0);
+
+        // Unused keys go to the parent in normal mode.
+        assert!(!view.on_event(Event::Key(Key::Esc)).is_consumed());
+        assert!(!view.on_event(Event::Char('Z')).is_consumed());
+        assert_eq!(*view.get_content(), "Xworld");
+    }
+
+    #[test]
+    fn validation() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let submitted = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&submitted);
+        let mut view = EditView::new()
+            .mask(InputMask::Digits)
+            .validator(|text| match text.parse::<u8>() {
+                Ok(_) => Ok(()),
+                Err(_) => Err("0-255".into()),
+            })
+            .show_error()
+            .on_submit(move |_, _| {
+                counter.fetch_add(1, Ordering::Relaxed);
+            });
+
+        for c in "2a5".chars() {
+            view.on_event(Event::Char(c));
+        }
+        view.on_event(Event::Paste("6x".into()));
+        assert_eq!(*view.get_content(), "256");
+        assert_eq!(view.validation_error().as_deref(), Some("0-255"));
+
+        let mut siv = Cursive::new();
+        view.on_event(Event::Key(Key::Enter)).process(&mut siv);
+        assert_eq!(submitted.load(Ordering::Relaxed), 0);
+
+        let view = crate::traits::Resizable::fixed_width(view, 12);
+        let screen = crate::test::render_view(view, (12, 1));
+        assert_eq!(screen.row(0), "256____0-255");
+
+        let mut view = EditView::new().content("25").validator(|_| Ok(()));
+        assert!(view.is_valid());
+        view.set_filter(|c| c != '5');
+        view.on_event(Event::Char('5'));
+        assert_eq!(*view.get_content(), "25");
+    }
 }
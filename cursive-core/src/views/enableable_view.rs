@@ -65,6 +65,12 @@ impl<V: View> ViewWrapper for EnableableView<V> {
     }
 }
 
+#[crate::blueprint(EnableableView::new(view))]
+struct Blueprint {
+    view: crate::views::BoxedView,
+    enabled: Option<bool>,
+}
+
 crate::manual_blueprint!(with enableable, |config, context| {
     let enabled = context.resolve_or(&config["enabled"], true)?;
 
@@ -1,6 +1,7 @@
 use crate::{
-    direction::{Absolute, Direction, Relative},
+    direction::{self, Absolute, Direction, Relative},
     event::{AnyCb, Event, EventResult, Key},
+    keymap,
     rect::Rect,
     view::{CannotFocus, IntoBoxedView, Selector, ViewNotFound},
     {Printer, Vec2, View, With},
@@ -205,23 +206,19 @@ impl FixedLayout {
 
     fn move_focus_abs(&mut self, target: Absolute) -> EventResult {
         let source = Direction::Abs(target.opposite());
-        let (orientation, rel) = target.split();
-
-        fn intersects(a: (usize, usize), b: (usize, usize)) -> bool {
-            a.1 >= b.0 && a.0 <= b.1
-        }
 
         let current_position = self.children[self.focus].position;
-        let current_side = current_position.side(orientation.swap());
-        let current_edge = current_position.edge(target);
-
-        let focus_res = Self::iter_mut(source, &mut self.children)
-            .filter(|(_, c)| {
-                // Only select children actually aligned with us
-                Some(rel) == Relative::a_to_b(current_edge, c.position.edge(target))
-                    && intersects(c.position.side(orientation.swap()), current_side)
-            })
-            .find_map(Child::focuser(source));
+        let candidates = direction::rank_neighbors(
+            current_position,
+            target,
+            self.children.iter().map(|c| c.position),
+        );
+
+        // Pick the closest child that accepts focus.
+        let focus_res = candidates.into_iter().find_map(|i| {
+            let res = self.children[i].view.take_focus(source).ok()?;
+            Some((i, res))
+        });
 
         if let Some((i, res)) = focus_res {
             return self.set_focus_unchecked(i).and(res);
@@ -281,8 +278,10 @@ impl View for FixedLayout {
             return EventResult::Ignored;
         }
 
+        // Wheel events only moving the focus can still scroll an outer view.
         let res = self
             .check_focus_grab(&event)
+            .filter(|res| !event.is_wheel() || res.has_callback())
             .unwrap_or(EventResult::Ignored);
 
         let child = &mut self.children[self.focus];
@@ -293,8 +292,8 @@ impl View for FixedLayout {
 
         res.and(match result {
             EventResult::Ignored => match event {
-                Event::Shift(Key::Tab) => self.move_focus_rel(Relative::Front),
-                Event::Key(Key::Tab) => self.move_focus_rel(Relative::Back),
+                _ if keymap::matches("focus_prev", &event) => self.move_focus_rel(Relative::Front),
+                _ if keymap::matches("focus_next", &event) => self.move_focus_rel(Relative::Back),
                 Event::Key(Key::Left) => self.move_focus_abs(Absolute::Left),
                 Event::Key(Key::Right) => self.move_focus_abs(Absolute::Right),
                 Event::Key(Key::Up) => self.move_focus_abs(Absolute::Up),
@@ -355,6 +354,12 @@ impl View for FixedLayout {
         }
     }
 
+    fn call_on_focused_child(&mut self, callback: AnyCb) {
+        if let Some(child) = self.children.get_mut(self.focus) {
+            callback(&mut *child.view);
+        }
+    }
+
     fn focus_view(&mut self, selector: &Selector) -> Result<EventResult, ViewNotFound> {
         let focus_res = self
             .children
@@ -367,6 +372,18 @@ impl View for FixedLayout {
 
         Err(ViewNotFound)
     }
+
+    fn reveal_view(&mut self, selector: &Selector, _: Vec2) -> Result<Rect, ViewNotFound> {
+        self.children
+            .iter_mut()
+            .find_map(|c| {
+                c.view
+                    .reveal_view(selector, c.position.size())
+                    .ok()
+                    .map(|area| area + c.position.top_left())
+            })
+            .ok_or(ViewNotFound)
+    }
 }
 
 // TODO: blueprints?
@@ -1,4 +1,5 @@
 use crate::event::AnyCb;
+use crate::rect::Rect;
 use crate::view::{Selector, View, ViewWrapper};
 use crate::Vec2;
 use crate::With;
@@ -121,6 +122,16 @@ impl<V: View> ViewWrapper for HideableView<V> {
     fn wrap_needs_relayout(&self) -> bool {
         self.invalidated || (self.visible && self.view.needs_relayout())
     }
+
+    fn wrap_dirty_area(&self, size: Vec2) -> Option<Rect> {
+        if self.invalidated {
+            Some(Rect::from_size(Vec2::zero(), size))
+        } else if self.visible {
+            self.view.dirty_area(size)
+        } else {
+            None
+        }
+    }
 }
 
 #[crate::blueprint(HideableView::new(view))]
@@ -0,0 +1,237 @@
+use crate::backend::GraphicsProtocol;
+use crate::style::Color;
+use crate::utils::draw::{PixelBuffer, PixelMode};
+use crate::utils::image::Image;
+use crate::view::View;
+use crate::{Printer, Vec2, With};
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+// Protocol, cells covered and cell size the cached sequence was encoded for.
+type EncodingKey = (GraphicsProtocol, Vec2, Option<Vec2>);
+
+/// View showing an image.
+///
+/// Uses the graphics protocol supported by the backend, if any (see
+/// [`Backend::graphics_protocol`]). Otherwise, or if the view is only
+/// partially visible, the image is drawn with half-block characters, each
+/// cell showing two pixels.
+///
+/// By default, the image keeps its aspect ratio and is scaled down to fit
+/// the available space, with one pixel per half-block.
+///
+/// [`Backend::graphics_protocol`]: crate::backend::Backend::graphics_protocol
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::style::Rgb;
+/// use cursive_core::utils::image::Image;
+/// use cursive_core::views::ImageView;
+///
+/// let image = Image::from_fn((32, 32), |pos| Rgb::new(pos.x as u8 * 8, pos.y as u8 * 8, 128));
+/// let view = ImageView::new(image).size((16, 8));
+/// ```
+pub struct ImageView {
+    image: Image,
+    size: Option<Vec2>,
+    use_graphics: bool,
+
+    // Last sequence sent for the graphics protocol.
+    encoded: Mutex<Option<(EncodingKey, Arc<str>)>>,
+}
+
+impl ImageView {
+    /// Creates a new view showing the given image.
+    pub fn new(image: Image) -> Self {
+        ImageView {
+            image,
+            size: None,
+            use_graphics: true,
+            encoded: Mutex::new(None),
+        }
+    }
+
+    /// Replaces the image shown.
+    pub fn set_image(&mut self, image: Image) {
+        self.image = image;
+        *self.encoded.lock() = None;
+    }
+
+    /// Replaces the image shown.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn image(self, image: Image) -> Self {
+        self.with(|s| s.set_image(image))
+    }
+
+    /// Returns the image shown.
+    pub fn get_image(&self) -> &Image {
+        &self.image
+    }
+
+    /// Sets the number of cells covered by the image.
+    ///
+    /// The image is stretched to exactly this size.
+    pub fn set_size<S: Into<Vec2>>(&mut self, size: S) {
+        self.size = Some(size.into());
+    }
+
+    /// Sets the number of cells covered by the image.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn size<S: Into<Vec2>>(self, size: S) -> Self {
+        self.with(|s| s.set_size(size))
+    }
+
+    /// Fits the image in the available space again, keeping its aspect ratio.
+    pub fn clear_size(&mut self) {
+        self.size = None;
+    }
+
+    /// Sets whether the graphics protocol of the backend can be used.
+    ///
+    /// If `false`, the image is always drawn with half-block characters.
+    ///
+    /// Defaults to `true`.
+    pub fn set_use_graphics(&mut self, use_graphics: bool) {
+        self.use_graphics = use_graphics;
+    }
+
+    /// Sets whether the graphics protocol of the backend can be used.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn use_graphics(self, use_graphics: bool) -> Self {
+        self.with(|s| s.set_use_graphics(use_graphics))
+    }
+
+    // Returns the cells covered by the image, given the available space.
+    fn cells(&self, available: Vec2) -> Vec2 {
+        if let Some(size) = self.size {
+            return size.zip_map(available, usize::min);
+        }
+
+        // Each cell shows two pixels, one above the other.
+        let natural = self.image.size();
+        let natural = Vec2::new(natural.x, (natural.y + 1) / 2);
+        if natural.fits_in(available) || natural.x == 0 || natural.y == 0 {
+            return natural;
+        }
+
+        // Scale down, keeping the aspect ratio.
+        let by_width = Vec2::new(available.x, natural.y * available.x / natural.x);
+        let by_height = Vec2::new(natural.x * available.y / natural.y, available.y);
+        let cells = if by_width.fits_in(available) {
+            by_width
+        } else {
+            by_height
+        };
+        cells.zip_map(available.map(|v| v.min(1)), usize::max)
+    }
+
+    // Returns the sequence drawing the image, encoding it again if needed.
+    fn encoded(&self, key: EncodingKey) -> Arc<str> {
+        let mut encoded = self.encoded.lock();
+        match &*encoded {
+            Some((cached, data)) if *cached == key => data.clone(),
+            _ => {
+                let (protocol, cells, cell_pixel_size) = key;
+                let data: Arc<str> = self.image.encode(protocol, cells, cell_pixel_size).into();
+                *encoded = Some((key, data.clone()));
+                data
+            }
+        }
+    }
+}
+
+impl View for ImageView {
+    fn draw(&self, printer: &Printer) {
+        let cells = self.cells(printer.size);
+        if self.image.is_empty() || !Vec2::zero().strictly_lt(cells) {
+            return;
+        }
+
+        // Half-blocks are always drawn, as the fallback below the image.
+        let mut pixels = PixelBuffer::new(PixelMode::HalfBlock, cells);
+        let resized = self.image.resized(pixels.pixel_size());
+        for y in 0..resized.size().y {
+            for x in 0..resized.size().x {
+                if let Some(rgb) = resized.pixel((x, y)) {
+                    pixels.set_pixel(Vec2::new(x, y), Color::Rgb(rgb.r, rgb.g, rgb.b));
+                }
+            }
+        }
+        pixels.draw(printer);
+
+        let Some(protocol) = printer.graphics_protocol().filter(|_| self.use_graphics) else {
+            return;
+        };
+        let data = self.encoded((protocol, cells, printer.cell_pixel_size()));
+        printer.print_graphics((0, 0), cells, data);
+    }
+
+    fn required_size(&mut self, constraint: Vec2) -> Vec2 {
+        self.cells(constraint)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::PrintBuffer;
+    use crate::style::Rgb;
+    use crate::theme::Theme;
+
+    fn checkers() -> Image {
+        Image::from_fn((4, 4), |pos| {
+            if (pos.x + pos.y) % 2 == 0 {
+                Rgb::new(255, 255, 255)
+            } else {
+                Rgb::new(0, 0, 0)
+            }
+        })
+    }
+
+    #[test]
+    fn fit() {
+        let mut view = ImageView::new(checkers());
+        assert_eq!(view.required_size(Vec2::new(80, 24)), Vec2::new(4, 2));
+        assert_eq!(view.required_size(Vec2::new(2, 24)), Vec2::new(2, 1));
+        assert_eq!(view.required_size(Vec2::new(80, 1)), Vec2::new(2, 1));
+
+        view.set_size((10, 3));
+        assert_eq!(view.required_size(Vec2::new(80, 2)), Vec2::new(10, 2));
+    }
+
+    #[test]
+    fn graphics() {
+        let view = ImageView::new(checkers());
+        let theme = Theme::default();
+
+        let size = Vec2::new(6, 4);
+        let mut buffer = PrintBuffer::new();
+        buffer.resize(size);
+        buffer.set_graphics_support(Some(GraphicsProtocol::Kitty), None);
+        let buffer = parking_lot::RwLock::new(buffer);
+
+        let printer = Printer::new(size, &theme, &buffer);
+        view.draw(&printer.offset((1, 1)));
+
+        // The fallback is drawn below the image.
+        let rendered = buffer.read().to_styled_string();
+        let lines: Vec<&str> = rendered.source().lines().collect();
+        assert_eq!(lines[1], " ▀▀▀▀ ");
+
+        let data = view.encoded((GraphicsProtocol::Kitty, Vec2::new(4, 2), None));
+        assert!(data.starts_with("\x1b_Ga=T,f=24,s=4,v=4,c=4,r=2,"));
+
+        // A cropped view cannot show the image.
+        assert!(!printer
+            .cropped((3, 3))
+            .print_graphics((0, 0), (4, 2), data.clone()));
+        assert!(printer.print_graphics((1, 1), (4, 2), data));
+    }
+}
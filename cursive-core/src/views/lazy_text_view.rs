@@ -0,0 +1,168 @@
+use crate::style::StyleType;
+use crate::utils::markup::StyledString;
+use crate::view::View;
+use crate::{Printer, Vec2, With};
+
+use unicode_width::UnicodeWidthStr;
+
+type LineProvider = dyn Fn(usize) -> StyledString + Send + Sync;
+
+/// A view showing text produced line by line, on demand.
+///
+/// Unlike [`TextView`], the content is not stored: only lines currently
+/// visible are requested from the provider, every time the view is drawn.
+/// This makes it possible to show very large content, like multi-gigabyte
+/// files, without holding everything in memory.
+///
+/// Lines are never wrapped: each line takes exactly one row, and whatever
+/// does not fit in the view's width is cut. The view takes all the width it
+/// is given, so it is usually placed in a `ScrollView`.
+///
+/// [`TextView`]: crate::views::TextView
+///
+/// # Examples
+///
+/// ```rust
+/// # use cursive_core::views::{LazyTextView, ScrollView};
+/// // A million lines, and not one of them in memory.
+/// let view = ScrollView::new(LazyTextView::new(1_000_000, |i| {
+///     format!("This is line {i}").into()
+/// }));
+/// ```
+pub struct LazyTextView {
+    line_count: usize,
+    provider: Box<LineProvider>,
+
+    // Default style for the text.
+    style: StyleType,
+}
+
+impl LazyTextView {
+    /// Creates a new `LazyTextView` with `line_count` lines.
+    ///
+    /// `provider(i)` will be called to get line `i`, when it needs to be drawn.
+    /// It should return a single line, with no newline.
+    pub fn new<F>(line_count: usize, provider: F) -> Self
+    where
+        F: Fn(usize) -> StyledString + 'static + Send + Sync,
+    {
+        LazyTextView {
+            line_count,
+            provider: Box::new(provider),
+            style: StyleType::default(),
+        }
+    }
+
+    /// Sets the number of lines.
+    ///
+    /// Useful when the underlying content grows, like a log file being written.
+    pub fn set_line_count(&mut self, line_count: usize) {
+        self.line_count = line_count;
+    }
+
+    /// Sets the number of lines.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn with_line_count(self, line_count: usize) -> Self {
+        self.with(|s| s.set_line_count(line_count))
+    }
+
+    /// Returns the number of lines.
+    pub fn line_count(&self) -> usize {
+        self.line_count
+    }
+
+    /// Sets the function producing lines.
+    pub fn set_provider<F>(&mut self, provider: F)
+    where
+        F: Fn(usize) -> StyledString + 'static + Send + Sync,
+    {
+        self.provider = Box::new(provider);
+    }
+
+    /// Sets the style for the entire content.
+    pub fn set_style<S: Into<StyleType>>(&mut self, style: S) {
+        self.style = style.into();
+    }
+
+    /// Sets the style for the entire content.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn style<S: Into<StyleType>>(self, style: S) -> Self {
+        self.with(|s| s.set_style(style))
+    }
+
+    /// Returns line `i`, as given by the provider.
+    pub fn get_line(&self, i: usize) -> StyledString {
+        (self.provider)(i)
+    }
+}
+
+impl View for LazyTextView {
+    fn draw(&self, printer: &Printer) {
+        let start = printer.content_offset.y;
+        let end = start
+            .saturating_add(printer.output_size.y)
+            .min(self.line_count);
+
+        printer.with_style(self.style, |printer| {
+            for y in start..end {
+                let line = self.get_line(y);
+
+                let mut x = 0;
+                for span in line.spans() {
+                    printer.with_style(*span.attr, |printer| {
+                        printer.print((x, y), span.content);
+                        x += span.content.width();
+                    });
+                }
+            }
+        });
+    }
+
+    fn required_size(&mut self, constraint: Vec2) -> Vec2 {
+        Vec2::new(constraint.x, self.line_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LazyTextView;
+    use crate::buffer::PrintBuffer;
+    use crate::theme::Theme;
+    use crate::view::View;
+    use crate::{Printer, Vec2};
+
+    use parking_lot::RwLock;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn only_visible_lines() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+
+        let mut view = LazyTextView::new(1_000_000, move |i| {
+            counter.fetch_add(1, Ordering::Relaxed);
+            format!("line {i}").into()
+        });
+
+        let size = Vec2::new(10, 5);
+        assert_eq!(view.required_size(size), Vec2::new(10, 1_000_000));
+        assert_eq!(calls.load(Ordering::Relaxed), 0);
+
+        let mut buffer = PrintBuffer::new();
+        buffer.resize(size);
+        let buffer = RwLock::new(buffer);
+        let theme = Theme::default();
+
+        let printer = Printer::new(size, &theme, &buffer).content_offset((0, 500));
+        view.draw(&printer);
+
+        assert_eq!(calls.load(Ordering::Relaxed), 5);
+        let buffer = buffer.read();
+        assert_eq!(buffer.cell_at(Vec2::new(5, 0)).unwrap().text(), "5");
+    }
+}
@@ -2,6 +2,7 @@
 use crate::{
     direction,
     event::{AnyCb, Event, EventResult, Key},
+    keymap,
     rect::Rect,
     view::{CannotFocus, IntoBoxedView, Selector, SizeCache, View, ViewNotFound},
     Printer, Vec2, With, XY,
@@ -9,9 +10,15 @@ use crate::{
 use log::debug;
 use std::cmp::min;
 use std::ops::Deref;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 /// Arranges its children linearly according to its orientation.
 ///
+/// Each child gets the size it requires. If the layout is then given more
+/// room than that, the extra space is shared between the children with a
+/// non-zero weight, in proportion to their weight (see
+/// [`LinearLayout::child_weighted`]).
+///
 /// # Examples
 ///
 /// ```
@@ -29,6 +36,12 @@ pub struct LinearLayout {
     focus: usize,
 
     cache: Option<XY<SizeCache>>,
+
+    // `true` if children moved or got resized since the last draw.
+    rearranged: AtomicBool,
+
+    // If `true`, separator lines are drawn between children by default.
+    separators: bool,
 }
 
 struct Child {
@@ -40,21 +53,76 @@ struct Child {
 
     last_size: Vec2,
 
-    _weight: usize,
+    // Share of the extra space this child gets.
+    weight: usize,
+
+    // Result of the last call to the view's required_size.
+    //
+    // Lets us skip measuring the child again for compatible constraints.
+    size_cache: Option<XY<SizeCache>>,
+
+    // `true` if the view was measured since its last layout.
+    //
+    // Measuring may change the state of the view, so it needs a new layout.
+    needs_layout: bool,
+
+    // Overrides the layout's default for the separator before this child.
+    separator: Option<bool>,
 }
 
 impl Child {
+    fn new(view: Box<dyn View>) -> Self {
+        Child {
+            view,
+            required_size: Vec2::zero(),
+            last_size: Vec2::zero(),
+            weight: 0,
+            size_cache: None,
+            needs_layout: true,
+            separator: None,
+        }
+    }
+
     // Compute and caches the required size.
     fn required_size(&mut self, req: Vec2) -> Vec2 {
-        self.required_size = self.view.required_size(req);
+        self.required_size = match self.get_cache(req) {
+            Some(size) => size,
+            None => {
+                let size = self.view.required_size(req);
+                self.size_cache = Some(SizeCache::build(size, req));
+                self.needs_layout = true;
+                size
+            }
+        };
         self.required_size
     }
 
+    // If the cache can be used, return the cached size.
+    fn get_cache(&self, req: Vec2) -> Option<Vec2> {
+        let cache = self.size_cache?;
+        if cache.zip_map(req, SizeCache::accept).both() && !self.view.needs_relayout() {
+            Some(cache.map(SizeCache::value))
+        } else {
+            None
+        }
+    }
+
     fn layout(&mut self, size: Vec2) {
+        if !self.needs_layout && size == self.last_size && !self.view.needs_relayout() {
+            return;
+        }
+
         self.last_size = size;
+        self.needs_layout = false;
         self.view.layout(size);
     }
 
+    // Forget any cached size, for when the view may have changed.
+    fn invalidate(&mut self) {
+        self.size_cache = None;
+        self.needs_layout = true;
+    }
+
     fn as_view(&self) -> &dyn View {
         &*self.view
     }
@@ -69,21 +137,33 @@ struct ChildIterator<I> {
     available: usize,
     // Orientation for this layout
     orientation: direction::Orientation,
+    // Default separator setting for this layout
+    separators: bool,
+    // `true` until the first child is returned
+    first: bool,
 }
 
 struct ChildItem<T> {
     child: T,
     offset: usize,
-    length: usize,
+    // `true` if a separator is drawn just before this child.
+    separator: bool,
 }
 
 impl<T> ChildIterator<T> {
-    fn new(inner: T, orientation: direction::Orientation, available: usize) -> Self {
+    fn new(
+        inner: T,
+        orientation: direction::Orientation,
+        available: usize,
+        separators: bool,
+    ) -> Self {
         ChildIterator {
             inner,
             available,
             orientation,
             offset: 0,
+            separators,
+            first: true,
         }
     }
 }
@@ -93,12 +173,21 @@ impl<T: Deref<Target = Child>, I: Iterator<Item = T>> Iterator for ChildIterator
 
     fn next(&mut self) -> Option<Self::Item> {
         self.inner.next().map(|child| {
+            // The separator takes a cell before the child.
+            let separator =
+                !self.first && self.available > 0 && child.separator.unwrap_or(self.separators);
+            self.first = false;
+            if separator {
+                self.available -= 1;
+                self.offset += 1;
+            }
+
             // Save the current offset.
             let offset = self.offset;
 
             // debug!("Available: {}", self.available);
 
-            let length = min(self.available, *child.required_size.get(self.orientation));
+            let length = min(self.available, *child.last_size.get(self.orientation));
 
             // Allocated width
             self.available = self.available.saturating_sub(length);
@@ -108,12 +197,37 @@ impl<T: Deref<Target = Child>, I: Iterator<Item = T>> Iterator for ChildIterator
             ChildItem {
                 child,
                 offset,
-                length,
+                separator,
             }
         })
     }
 }
 
+// Shares `extra` between the given lengths, in proportion to their weights.
+fn distribute(lengths: &mut [usize], weights: &[usize], extra: usize) {
+    let total: usize = weights.iter().sum();
+    if total == 0 {
+        return;
+    }
+
+    let mut remaining = extra;
+    for (length, &weight) in lengths.iter_mut().zip(weights) {
+        let share = extra * weight / total;
+        *length += share;
+        remaining -= share;
+    }
+
+    // Rounding leftovers go to the first weighted children.
+    for (length, _) in lengths
+        .iter_mut()
+        .zip(weights)
+        .filter(|&(_, &weight)| weight > 0)
+        .take(remaining)
+    {
+        *length += 1;
+    }
+}
+
 fn cap<'a, I: Iterator<Item = &'a mut usize>>(iter: I, max: usize) {
     let mut available = max;
     for item in iter {
@@ -133,28 +247,133 @@ impl LinearLayout {
             orientation,
             focus: 0,
             cache: None,
+            rearranged: AtomicBool::new(true),
+            separators: false,
         }
     }
 
-    /// Sets the weight of the given child. This weight is currently unused by the layout process.
+    /// Sets the weight of the given child.
+    ///
+    /// If the layout gets more space than its children require, the extra
+    /// space is shared between children in proportion to their weight.
+    ///
+    /// Children have a weight of `0` by default: they never get extra space.
     ///
     /// # Panics
     ///
     /// Panics if `i >= self.len()`.
     pub fn set_weight(&mut self, i: usize, weight: usize) {
-        self.children[i]._weight = weight;
+        self.children[i].weight = weight;
     }
 
-    /// Modifies the weight of the last child added. This weight is currently unused by the layout process.
+    /// Modifies the weight of the last child added.
     ///
     /// It is an error to call this before adding a child (and it will panic).
     #[must_use]
     pub fn weight(mut self, weight: usize) -> Self {
-        self.children.last_mut().unwrap()._weight = weight;
+        self.children.last_mut().unwrap().weight = weight;
 
         self
     }
 
+    /// Returns the weight of the given child.
+    ///
+    /// Returns `None` if `i >= self.len()`.
+    pub fn get_weight(&self, i: usize) -> Option<usize> {
+        self.children.get(i).map(|child| child.weight)
+    }
+
+    /// Draws separator lines between all children.
+    ///
+    /// Each separator takes one cell between two children. Inside a [`Panel`], separators are
+    /// connected to the border.
+    ///
+    /// Individual separators can then be hidden with [`LinearLayout::set_separator`].
+    ///
+    /// [`Panel`]: crate::views::Panel
+    pub fn set_separators(&mut self, separators: bool) {
+        self.separators = separators;
+        self.invalidate();
+    }
+
+    /// Draws separator lines between all children.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::{LinearLayout, Panel, TextView};
+    ///
+    /// let layout = Panel::new(
+    ///     LinearLayout::horizontal()
+    ///         .child(TextView::new("Left"))
+    ///         .child(TextView::new("Middle"))
+    ///         .child(TextView::new("Right"))
+    ///         .separators(),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn separators(self) -> Self {
+        self.with(|s| s.set_separators(true))
+    }
+
+    /// Shows or hides the separator between the children `i` and `i + 1`.
+    ///
+    /// This overrides the setting from [`LinearLayout::set_separators`] for this gap.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i + 1 >= self.len()`.
+    pub fn set_separator(&mut self, i: usize, visible: bool) {
+        assert!(i + 1 < self.children.len(), "no child after {i}");
+        self.children[i + 1].separator = Some(visible);
+        self.invalidate();
+    }
+
+    /// Returns `true` if a separator is drawn between the children `i` and `i + 1`.
+    pub fn has_separator(&self, i: usize) -> bool {
+        match self.children.get(i + 1) {
+            Some(child) => child.separator.unwrap_or(self.separators),
+            None => false,
+        }
+    }
+
+    // Returns the total length taken by separators.
+    fn separators_length(&self) -> usize {
+        (0..self.children.len())
+            .filter(|&i| self.has_separator(i))
+            .count()
+    }
+
+    /// Adds a child to the layout, with the given weight.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::{LinearLayout, TextView};
+    ///
+    /// // The extra width goes for 1/3 to the left panel, 2/3 to the right panel.
+    /// let layout = LinearLayout::horizontal()
+    ///     .child_weighted(TextView::new("Left"), 1)
+    ///     .child(TextView::new("|"))
+    ///     .child_weighted(TextView::new("Right"), 2);
+    /// ```
+    #[must_use]
+    pub fn child_weighted<V: IntoBoxedView + 'static>(self, view: V, weight: usize) -> Self {
+        self.with(|s| s.add_child_weighted(view, weight))
+    }
+
+    /// Adds a child to the layout, with the given weight.
+    ///
+    /// See [`LinearLayout::set_weight`].
+    pub fn add_child_weighted<V: IntoBoxedView + 'static>(&mut self, view: V, weight: usize) {
+        self.add_child(view);
+        self.children.last_mut().unwrap().weight = weight;
+    }
+
     /// Adds a child to the layout.
     ///
     /// Chainable variant.
@@ -165,12 +384,7 @@ impl LinearLayout {
 
     /// Adds a child to the layout.
     pub fn add_child<V: IntoBoxedView + 'static>(&mut self, view: V) {
-        self.children.push(Child {
-            view: view.into_boxed_view(),
-            required_size: Vec2::zero(),
-            last_size: Vec2::zero(),
-            _weight: 0,
-        });
+        self.children.push(Child::new(view.into_boxed_view()));
         self.invalidate();
     }
 
@@ -180,15 +394,7 @@ impl LinearLayout {
     ///
     /// Panics if `i > self.len()`.
     pub fn insert_child<V: IntoBoxedView + 'static>(&mut self, i: usize, view: V) {
-        self.children.insert(
-            i,
-            Child {
-                view: view.into_boxed_view(),
-                required_size: Vec2::zero(),
-                last_size: Vec2::zero(),
-                _weight: 0,
-            },
-        );
+        self.children.insert(i, Child::new(view.into_boxed_view()));
         self.invalidate();
     }
 
@@ -259,7 +465,10 @@ impl LinearLayout {
     pub fn get_child_mut(&mut self, i: usize) -> Option<&mut dyn View> {
         // Anything could happen to the child view, so bust the cache.
         self.invalidate();
-        self.children.get_mut(i).map(|child| &mut *child.view)
+        self.children.get_mut(i).map(|child| {
+            child.invalidate();
+            &mut *child.view
+        })
     }
 
     /// Removes all children from this view.
@@ -325,6 +534,30 @@ impl LinearLayout {
         }
     }
 
+    // Returns the length of each child along the orientation.
+    //
+    // Children first get what they require, then any extra space is shared
+    // by weight.
+    fn child_lengths(&self, available: usize) -> Vec<usize> {
+        let o = self.orientation;
+
+        let mut remaining = available;
+        let mut lengths: Vec<usize> = self
+            .children
+            .iter()
+            .map(|child| {
+                let length = min(remaining, *child.required_size.get(o));
+                remaining -= length;
+                length
+            })
+            .collect();
+
+        let weights: Vec<usize> = self.children.iter().map(|child| child.weight).collect();
+        distribute(&mut lengths, &weights, remaining);
+
+        lengths
+    }
+
     fn children_are_sleeping(&self) -> bool {
         !self
             .children
@@ -404,6 +637,7 @@ impl LinearLayout {
                 self.orientation,
                 // TODO: get actual width (not super important)
                 usize::MAX,
+                self.separators,
             )
             .enumerate()
             {
@@ -427,6 +661,30 @@ impl LinearLayout {
     }
 }
 
+impl LinearLayout {
+    fn draw_separators(&self, printer: &Printer) {
+        let o = self.orientation;
+        let (line, length) = match o {
+            direction::Orientation::Horizontal => ("│", printer.size.y),
+            direction::Orientation::Vertical => ("─", printer.size.x),
+        };
+
+        printer.with_high_border(true, |printer| {
+            for item in ChildIterator::new(
+                self.children.iter(),
+                o,
+                *printer.size.get(o),
+                self.separators,
+            ) {
+                if item.separator {
+                    let start = o.make_vec(item.offset - 1, 0);
+                    printer.print_line(o.swap(), start, length, line);
+                }
+            }
+        });
+    }
+}
+
 fn try_focus(
     (i, child): (usize, &mut Child),
     source: direction::Direction,
@@ -436,12 +694,15 @@ fn try_focus(
 
 impl View for LinearLayout {
     fn draw(&self, printer: &Printer) {
+        self.rearranged.store(false, Ordering::Relaxed);
+
         // Use pre-computed sizes
         // debug!("Pre loop!");
         for (i, item) in ChildIterator::new(
             self.children.iter(),
             self.orientation,
             *printer.size.get(self.orientation),
+            self.separators,
         )
         .enumerate()
         {
@@ -454,6 +715,8 @@ impl View for LinearLayout {
                 .focused(i == self.focus);
             item.child.view.draw(printer);
         }
+
+        self.draw_separators(printer);
     }
 
     fn needs_relayout(&self) -> bool {
@@ -464,6 +727,28 @@ impl View for LinearLayout {
         !self.children_are_sleeping()
     }
 
+    fn dirty_area(&self, size: Vec2) -> Option<Rect> {
+        // Children may have moved.
+        if self.cache.is_none() || self.rearranged.load(Ordering::Relaxed) {
+            return Some(Rect::from_size(Vec2::zero(), size));
+        }
+
+        ChildIterator::new(
+            self.children.iter(),
+            self.orientation,
+            *size.get(self.orientation),
+            self.separators,
+        )
+        .filter_map(|item| {
+            let offset = self.orientation.make_vec(item.offset, 0);
+            item.child
+                .view
+                .dirty_area(item.child.last_size)
+                .map(|area| area + offset)
+        })
+        .reduce(Rect::union)
+    }
+
     fn layout(&mut self, size: Vec2) {
         if self.get_cache(size).is_none() {
             // Build the cache if needed.
@@ -473,11 +758,19 @@ impl View for LinearLayout {
         // We'll use this guy a few times, but it's a mouthful...
         let o = self.orientation;
 
-        for item in ChildIterator::new(self.children.iter_mut(), o, *size.get(o)) {
+        let available = size.get(o).saturating_sub(self.separators_length());
+        let lengths = self.child_lengths(available);
+        let mut rearranged = false;
+        for (child, length) in self.children.iter_mut().zip(lengths) {
             // Every item has the same size orthogonal to the layout
-            let size = size.with_axis(o, item.length);
+            let size = size.with_axis(o, length);
 
-            item.child.layout(size);
+            rearranged |= child.last_size != size;
+            child.layout(size);
+        }
+
+        if rearranged {
+            self.rearranged.store(true, Ordering::Relaxed);
         }
     }
 
@@ -488,6 +781,11 @@ impl View for LinearLayout {
         }
         debug!("Req: {:?}", req);
 
+        // Separators take some room, the rest goes to the children.
+        let separators = self.orientation.make_vec(self.separators_length(), 0);
+        let outer_req = req;
+        let req = req.saturating_sub(separators);
+
         // First, make a naive scenario: everything will work fine.
         let ideal_sizes: Vec<Vec2> = self
             .children
@@ -501,7 +799,8 @@ impl View for LinearLayout {
         // Does it fit?
         if ideal.fits_in(req) {
             // Champagne!
-            self.cache = Some(SizeCache::build(ideal, req));
+            let ideal = ideal + separators;
+            self.cache = Some(SizeCache::build(ideal, outer_req));
             return ideal;
         }
 
@@ -539,7 +838,7 @@ impl View for LinearLayout {
             debug!("Seriously? {:?} > {:?}???", desperate, req);
             // self.cache = Some(SizeCache::build(desperate, req));
             self.cache = None;
-            return desperate;
+            return desperate + separators;
         }
 
         // So now that we know we _can_ make it all fit, we can redistribute
@@ -564,7 +863,6 @@ impl View for LinearLayout {
         debug!("Overweight: {:?}", overweight);
 
         // So... distribute `available` to reduce the overweight...
-        // TODO: use child weight in the distribution...
 
         // We'll give everyone his share of what we have left,
         // starting with those who ask the least.
@@ -604,10 +902,10 @@ impl View for LinearLayout {
         debug!("Final sizes2: {:?}", final_sizes);
 
         // Let's stack everything to see what it looks like.
-        let compromise = self.orientation.stack(final_sizes.iter().copied());
+        let compromise = self.orientation.stack(final_sizes.iter().copied()) + separators;
 
         // Phew, that was a lot of work! I'm not doing it again.
-        self.cache = Some(SizeCache::build(compromise, req));
+        self.cache = Some(SizeCache::build(compromise, outer_req));
 
         compromise
     }
@@ -635,23 +933,32 @@ impl View for LinearLayout {
             return EventResult::Ignored;
         }
 
+        // Focusing a child alone does not consume wheel events, so an outer
+        // scroll view can still use them.
         let res = self
             .check_focus_grab(&event)
+            .filter(|res| !event.is_wheel() || res.has_callback())
             .unwrap_or(EventResult::Ignored);
 
         let result = {
-            let mut iterator =
-                ChildIterator::new(self.children.iter_mut(), self.orientation, usize::MAX);
+            let mut iterator = ChildIterator::new(
+                self.children.iter_mut(),
+                self.orientation,
+                usize::MAX,
+                self.separators,
+            );
             let item = iterator.nth(self.focus).unwrap();
             let offset = self.orientation.make_vec(item.offset, 0);
             item.child.view.on_event(event.relativized(offset))
         };
         res.and(match result {
             EventResult::Ignored => match event {
-                Event::Shift(Key::Tab) if self.focus > 0 => {
+                _ if self.focus > 0 && keymap::matches("focus_prev", &event) => {
                     self.move_focus(direction::Direction::back())
                 }
-                Event::Key(Key::Tab) if self.focus + 1 < self.children.len() => {
+                _ if self.focus + 1 < self.children.len()
+                    && keymap::matches("focus_next", &event) =>
+                {
                     self.move_focus(direction::Direction::front())
                 }
                 Event::Key(Key::Left)
@@ -688,6 +995,12 @@ impl View for LinearLayout {
         }
     }
 
+    fn call_on_focused_child(&mut self, callback: AnyCb) {
+        if let Some(child) = self.children.get_mut(self.focus) {
+            callback(&mut *child.view);
+        }
+    }
+
     fn focus_view(&mut self, selector: &Selector) -> Result<EventResult, ViewNotFound> {
         for (i, child) in self.children.iter_mut().enumerate() {
             if child.view.focus_view(selector).is_ok() {
@@ -698,6 +1011,26 @@ impl View for LinearLayout {
         Err(ViewNotFound)
     }
 
+    fn reveal_view(&mut self, selector: &Selector, _: Vec2) -> Result<Rect, ViewNotFound> {
+        let orientation = self.orientation;
+        ChildIterator::new(
+            self.children.iter_mut(),
+            orientation,
+            usize::MAX,
+            self.separators,
+        )
+        .find_map(|item| {
+            let offset = orientation.make_vec(item.offset, 0);
+            let size = item.child.last_size;
+            item.child
+                .view
+                .reveal_view(selector, size)
+                .ok()
+                .map(|area| area + offset)
+        })
+        .ok_or(ViewNotFound)
+    }
+
     fn important_area(&self, size: Vec2) -> Rect {
         if self.is_empty() {
             // Return dummy area if we are empty.
@@ -706,8 +1039,12 @@ impl View for LinearLayout {
 
         // Pick the focused item, with its offset
         let item = {
-            let mut iterator =
-                ChildIterator::new(self.children.iter(), self.orientation, usize::MAX);
+            let mut iterator = ChildIterator::new(
+                self.children.iter(),
+                self.orientation,
+                usize::MAX,
+                self.separators,
+            );
             iterator.nth(self.focus).unwrap()
         };
 
@@ -754,6 +1091,25 @@ crate::manual_blueprint!(LinearLayout, |config, context| {
         }
     }
 
+    // One weight per child, in the same order.
+    let weights: Option<Vec<usize>> = context.resolve(&config["weights"])?;
+    if let Some(weights) = weights {
+        if weights.len() > layout.len() {
+            return Err(crate::builder::Error::InvalidConfig {
+                message: "LinearLayout.weights cannot be longer than the number of views.".into(),
+                config: config.clone(),
+            });
+        }
+        for (i, weight) in weights.into_iter().enumerate() {
+            layout.set_weight(i, weight);
+        }
+    }
+
+    let separators: Option<bool> = context.resolve(&config["separators"])?;
+    if let Some(separators) = separators {
+        layout.set_separators(separators);
+    }
+
     if let Some(focus) = config.get("focus") {
         let focus = context.resolve(focus)?;
         layout
@@ -766,3 +1122,136 @@ crate::manual_blueprint!(LinearLayout, |config, context| {
 
     Ok(layout)
 });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::Resizable;
+    use crate::views::DummyView;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    fn widths(layout: &LinearLayout) -> Vec<usize> {
+        layout
+            .children
+            .iter()
+            .map(|child| child.last_size.x)
+            .collect()
+    }
+
+    #[test]
+    fn weights() {
+        let mut layout = LinearLayout::horizontal()
+            .child_weighted(DummyView.fixed_width(2), 1)
+            .child(DummyView.fixed_width(4))
+            .child_weighted(DummyView.min_width(3), 2);
+
+        // Just enough room: weights don't matter.
+        layout.layout(Vec2::new(9, 1));
+        assert_eq!(widths(&layout), [2, 4, 3]);
+
+        // 9 extra cells, shared 1:2.
+        layout.layout(Vec2::new(18, 1));
+        assert_eq!(widths(&layout), [5, 4, 9]);
+
+        // Leftovers from rounding go to the first weighted child.
+        layout.layout(Vec2::new(10, 1));
+        assert_eq!(widths(&layout), [3, 4, 3]);
+    }
+
+    #[test]
+    fn no_weights() {
+        let mut layout = LinearLayout::horizontal()
+            .child(DummyView.fixed_width(2))
+            .child(DummyView.fixed_width(4));
+
+        // Without weights, the extra space stays unused.
+        layout.layout(Vec2::new(20, 1));
+        assert_eq!(widths(&layout), [2, 4]);
+    }
+
+    // Counts how many times it gets measured and laid out.
+    struct Measured {
+        measures: Arc<AtomicUsize>,
+        layouts: Arc<AtomicUsize>,
+    }
+
+    impl View for Measured {
+        fn draw(&self, _: &Printer) {}
+
+        fn required_size(&mut self, _: Vec2) -> Vec2 {
+            self.measures.fetch_add(1, Ordering::Relaxed);
+            Vec2::new(3, 1)
+        }
+
+        fn layout(&mut self, _: Vec2) {
+            self.layouts.fetch_add(1, Ordering::Relaxed);
+        }
+
+        fn needs_relayout(&self) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn child_size_cache() {
+        let measures = Arc::new(AtomicUsize::new(0));
+        let layouts = Arc::new(AtomicUsize::new(0));
+
+        // The DummyView always needs a relayout, so the layout itself is never cached.
+        let mut layout = LinearLayout::vertical()
+            .child(Measured {
+                measures: Arc::clone(&measures),
+                layouts: Arc::clone(&layouts),
+            })
+            .child(DummyView);
+
+        for _ in 0..3 {
+            layout.required_size(Vec2::new(10, 10));
+            layout.layout(Vec2::new(10, 5));
+        }
+        assert_eq!(measures.load(Ordering::Relaxed), 1);
+        assert_eq!(layouts.load(Ordering::Relaxed), 1);
+
+        // A compatible size only needs a new layout.
+        layout.layout(Vec2::new(12, 5));
+        assert_eq!(measures.load(Ordering::Relaxed), 1);
+        assert_eq!(layouts.load(Ordering::Relaxed), 2);
+
+        // Accessing the child busts its cache.
+        layout.get_child_mut(0);
+        layout.layout(Vec2::new(12, 5));
+        assert_eq!(measures.load(Ordering::Relaxed), 2);
+        assert_eq!(layouts.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn separators() {
+        let mut layout = LinearLayout::horizontal()
+            .child(DummyView.fixed_width(2))
+            .child(DummyView.fixed_width(2))
+            .child(DummyView.fixed_width(2))
+            .separators();
+
+        assert_eq!(layout.required_size(Vec2::new(20, 1)), Vec2::new(8, 1));
+        layout.layout(Vec2::new(8, 1));
+        assert_eq!(widths(&layout), [2, 2, 2]);
+
+        // Hidden separators take no room.
+        layout.set_separator(0, false);
+        assert!(!layout.has_separator(0));
+        assert!(layout.has_separator(1));
+        assert_eq!(layout.required_size(Vec2::new(20, 1)), Vec2::new(7, 1));
+    }
+
+    #[test]
+    fn separator_junctions() {
+        let layout = LinearLayout::horizontal()
+            .child(crate::views::TextView::new("a"))
+            .child(crate::views::TextView::new("b"))
+            .separators();
+
+        let screen = crate::test::render_view(crate::views::Panel::new(layout), (5, 3));
+        assert_eq!(screen.rows(), ["┌─┬─┐", "│a│b│", "└─┴─┘"]);
+    }
+}
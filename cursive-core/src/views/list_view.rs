@@ -1,13 +1,16 @@
 use crate::builder::{Config, Context, Error, Resolvable};
 use crate::{
-    direction,
+    density, direction,
     event::{AnyCb, Callback, Event, EventResult, Key},
+    keymap,
     rect::Rect,
+    utils::{markup::StyledString, placeholder, TypeAhead},
     view::{CannotFocus, IntoBoxedView, Selector, View, ViewNotFound},
     Cursive, Printer, Vec2, With,
 };
 use log::debug;
 use std::sync::Arc;
+use std::time::Duration;
 use unicode_width::UnicodeWidthStr;
 
 /// Represents a child from a [`ListView`].
@@ -51,6 +54,19 @@ pub struct ListView {
 
     // This callback is called when the selection is changed.
     on_select: Option<Arc<ListCallback>>,
+
+    // If `true`, typed characters ignored by the focused child select the next
+    // row with a matching label.
+    autojump: bool,
+
+    // Characters typed so far for autojump.
+    type_ahead: TypeAhead,
+
+    // If `true`, the autojump prefix is displayed in the bottom-right corner.
+    show_autojump_buffer: bool,
+
+    // Text shown when there is no child.
+    placeholder: Option<StyledString>,
 }
 
 // Implement `Default` around `ListView::new`
@@ -64,6 +80,10 @@ impl ListView {
             children_heights: Vec::new(),
             focus: 0,
             on_select: None,
+            autojump: false,
+            type_ahead: TypeAhead::new(),
+            show_autojump_buffer: false,
+            placeholder: None,
         }
     }
 
@@ -223,6 +243,96 @@ impl ListView {
         self.with(|s| s.set_on_select(cb))
     }
 
+    /// Sets the "auto-jump" property for this view.
+    ///
+    /// If enabled, typing the beginning of a label focuses the next row
+    /// starting with it, unless the focused child uses the typed character.
+    ///
+    /// See [`TypeAhead`] for details.
+    pub fn set_autojump(&mut self, autojump: bool) {
+        self.autojump = autojump;
+        self.type_ahead.clear();
+    }
+
+    /// Sets the "auto-jump" property for this view.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn autojump(self) -> Self {
+        self.with(|s| s.set_autojump(true))
+    }
+
+    /// Sets the delay after which the characters typed for auto-jump are forgotten.
+    ///
+    /// Defaults to 1 second.
+    pub fn set_autojump_timeout(&mut self, timeout: Duration) {
+        self.type_ahead.set_timeout(timeout);
+    }
+
+    /// Sets the delay after which the characters typed for auto-jump are forgotten.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn autojump_timeout(self, timeout: Duration) -> Self {
+        self.with(|s| s.set_autojump_timeout(timeout))
+    }
+
+    /// Returns the characters typed so far for auto-jump.
+    ///
+    /// Returns an empty string once the auto-jump timeout expired.
+    pub fn autojump_buffer(&self) -> &str {
+        self.type_ahead.buffer()
+    }
+
+    /// Controls whether the characters typed for auto-jump are displayed.
+    ///
+    /// If enabled, they are printed in the bottom-right corner of the view.
+    ///
+    /// Defaults to `false`.
+    pub fn set_show_autojump_buffer(&mut self, show: bool) {
+        self.show_autojump_buffer = show;
+    }
+
+    /// Controls whether the characters typed for auto-jump are displayed.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn show_autojump_buffer(self, show: bool) -> Self {
+        self.with(|s| s.set_show_autojump_buffer(show))
+    }
+
+    /// Sets a placeholder to show when there is no child.
+    ///
+    /// The placeholder is drawn dimmed, in the center of the view.
+    pub fn set_placeholder<S: Into<StyledString>>(&mut self, placeholder: S) {
+        self.placeholder = Some(placeholder.into());
+    }
+
+    /// Sets a placeholder to show when there is no child.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::views::ListView;
+    /// let list = ListView::new().placeholder("Nothing here yet");
+    /// ```
+    #[must_use]
+    pub fn placeholder<S: Into<StyledString>>(self, placeholder: S) -> Self {
+        self.with(|s| s.set_placeholder(placeholder))
+    }
+
+    /// Removes the placeholder, if any.
+    pub fn clear_placeholder(&mut self) {
+        self.placeholder = None;
+    }
+
+    /// Returns the placeholder shown when there is no child.
+    pub fn get_placeholder(&self) -> Option<&StyledString> {
+        self.placeholder.as_ref()
+    }
+
     /// Returns the index of the currently focused item.
     ///
     /// Returns `0` if the list is empty.
@@ -296,6 +406,21 @@ impl ListView {
         })))
     }
 
+    fn on_char_event(&mut self, c: char) -> EventResult {
+        let labels = self.children.iter().map(ListChild::label);
+        let Some(i) = self.type_ahead.on_char(c, self.focus, labels) else {
+            return EventResult::Ignored;
+        };
+
+        match self.set_focus(i) {
+            Some(res) => res.and(EventResult::Consumed(self.on_select.clone().map(|cb| {
+                let focused_string = String::from(self.children[i].label());
+                Callback::from_fn(move |s| cb(s, &focused_string))
+            }))),
+            None => EventResult::Ignored,
+        }
+    }
+
     fn labels_width(&self) -> usize {
         self.children
             .iter()
@@ -354,6 +479,16 @@ impl ListView {
     }
 }
 
+// Empty rows between two children, for the current density.
+fn row_padding() -> usize {
+    density::density().spacing(0)
+}
+
+// Height of a delimiter, for the current density.
+fn delimiter_height() -> usize {
+    density::density().spacing(1)
+}
+
 fn try_focus(
     (i, child): (usize, &mut ListChild),
     source: direction::Direction,
@@ -370,6 +505,9 @@ fn try_focus(
 impl View for ListView {
     fn draw(&self, printer: &Printer) {
         if self.children.is_empty() {
+            if let Some(placeholder) = &self.placeholder {
+                placeholder::draw(printer, placeholder);
+            }
             return;
         }
 
@@ -392,9 +530,19 @@ impl View for ListView {
             }
             y += height;
         }
+
+        if self.show_autojump_buffer {
+            self.type_ahead.draw(printer);
+        }
     }
 
     fn required_size(&mut self, req: Vec2) -> Vec2 {
+        if self.children.is_empty() {
+            if let Some(placeholder) = &self.placeholder {
+                return placeholder::required_size(placeholder, req);
+            }
+        }
+
         // We'll show 2 columns: the labels, and the views.
         let label_width = self
             .children
@@ -406,11 +554,13 @@ impl View for ListView {
 
         let view_size =
             direction::Orientation::Vertical.stack(self.children.iter_mut().map(|c| match c {
-                ListChild::Delimiter => Vec2::new(0, 1),
+                ListChild::Delimiter => Vec2::new(0, delimiter_height()),
                 ListChild::Row(_, ref mut view) => view.required_size(req),
             }));
 
-        view_size + (1 + label_width, 0)
+        let padding = row_padding() * self.children.len().saturating_sub(1);
+
+        view_size + (1 + label_width, padding)
     }
 
     fn layout(&mut self, size: Vec2) {
@@ -431,17 +581,28 @@ impl View for ListView {
 
         self.children_heights.resize(self.children.len(), 0);
 
-        for (child, height) in self.children.iter_mut().zip(&mut self.children_heights) {
+        // Padding is included in the height of every row but the last.
+        let padding = row_padding();
+        let last = self.children.len().saturating_sub(1);
+
+        for (i, (child, height)) in self
+            .children
+            .iter_mut()
+            .zip(&mut self.children_heights)
+            .enumerate()
+        {
             match child.view() {
                 Some(child) => {
                     *height = child.required_size(size).y;
                     child.layout(Vec2::new(available, *height));
                 }
                 None => {
-                    // Delimiters have height=1
-                    *height = 1;
+                    *height = delimiter_height();
                 }
             }
+            if i < last {
+                *height += padding;
+            }
         }
     }
 
@@ -450,8 +611,10 @@ impl View for ListView {
             return EventResult::Ignored;
         }
 
+        // Wheel events only moving the focus can still scroll an outer view.
         let res = self
             .check_focus_grab(&event)
+            .filter(|res| !event.is_wheel() || res.has_callback())
             .unwrap_or(EventResult::Ignored);
 
         // Send the event to the focused child.
@@ -467,6 +630,12 @@ impl View for ListView {
 
         // If the child ignored this event, change the focus.
         res.and(match event {
+            _ if keymap::matches("focus_next", &event) => {
+                self.move_focus(1, direction::Direction::front())
+            }
+            _ if keymap::matches("focus_prev", &event) => {
+                self.move_focus(1, direction::Direction::back())
+            }
             Event::Key(Key::Up) if self.focus > 0 => {
                 self.move_focus(1, direction::Direction::down())
             }
@@ -481,8 +650,7 @@ impl View for ListView {
             Event::Key(Key::End) | Event::Ctrl(Key::End) => {
                 self.move_focus(usize::MAX, direction::Direction::front())
             }
-            Event::Key(Key::Tab) => self.move_focus(1, direction::Direction::front()),
-            Event::Shift(Key::Tab) => self.move_focus(1, direction::Direction::back()),
+            Event::Char(c) if self.autojump => self.on_char_event(c),
             _ => EventResult::Ignored,
         })
     }
@@ -507,6 +675,12 @@ impl View for ListView {
         }
     }
 
+    fn call_on_focused_child(&mut self, callback: AnyCb) {
+        if let Some(view) = self.children.get_mut(self.focus).and_then(ListChild::view) {
+            callback(view);
+        }
+    }
+
     fn focus_view(&mut self, selector: &Selector) -> Result<EventResult, ViewNotFound> {
         // Try to focus each view. Skip over delimiters.
         if let Some((i, res)) = self
@@ -522,6 +696,23 @@ impl View for ListView {
         }
     }
 
+    fn reveal_view(&mut self, selector: &Selector, size: Vec2) -> Result<Rect, ViewNotFound> {
+        let offset = self.labels_width() + 1;
+        let mut y = 0;
+
+        for (child, &height) in self.children.iter_mut().zip(&self.children_heights) {
+            if let Some(view) = child.view() {
+                let available = Vec2::new(size.x.saturating_sub(offset), height);
+                if let Ok(area) = view.reveal_view(selector, available) {
+                    return Ok(area + (offset, y));
+                }
+            }
+            y += height;
+        }
+
+        Err(ViewNotFound)
+    }
+
     fn important_area(&self, size: Vec2) -> Rect {
         if self.children.is_empty() {
             return Rect::from_size(Vec2::zero(), size);
@@ -562,5 +753,84 @@ impl Resolvable for ListChild {
 struct Blueprint {
     children: Vec<ListChild>,
 
+    autojump: Option<bool>,
+    show_autojump_buffer: Option<bool>,
+    placeholder: Option<StyledString>,
+
     on_select: Option<_>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::{Checkbox, TextView};
+
+    #[test]
+    fn autojump() {
+        let mut view = ListView::new()
+            .autojump()
+            .child("Name", Checkbox::new())
+            .delimiter()
+            .child("Nickname", Checkbox::new())
+            .child("Notes", Checkbox::new())
+            .child("Nope", TextView::new("Not focusable"));
+
+        assert!(view.on_event(Event::Char('n')).is_consumed());
+        assert_eq!(view.focus(), 2);
+        assert!(view.on_event(Event::Char('o')).is_consumed());
+        assert_eq!(view.focus(), 3);
+
+        // "Nope" matches, but cannot take the focus.
+        assert!(!view.on_event(Event::Char('p')).is_consumed());
+        assert_eq!(view.focus(), 3);
+    }
+
+    #[test]
+    fn density() {
+        use crate::density::Density;
+        use crate::views::Panel;
+
+        let list = || {
+            Panel::new(
+                ListView::new()
+                    .child("a", TextView::new("1"))
+                    .delimiter()
+                    .child("b", TextView::new("2")),
+            )
+        };
+
+        let render = |density, size| {
+            let mut siv = Cursive::new();
+            siv.set_density(density);
+            let mut harness = crate::test::Harness::with_cursive(siv, size);
+            harness.cursive().add_fullscreen_layer(list());
+            harness.screen()
+        };
+
+        let screen = render(Density::Comfortable, (7, 8));
+        assert_eq!(
+            screen.rows(),
+            [
+                "┌─────┐",
+                "│ a 1 │",
+                "│     │",
+                "│     │",
+                "│     │",
+                "│     │",
+                "│ b 2 │",
+                "└─────┘"
+            ]
+        );
+
+        let screen = render(Density::Normal, (5, 5));
+        assert_eq!(screen.rows(), ["┌───┐", "│a 1│", "│   │", "│b 2│", "└───┘"]);
+
+        // Compact layouts drop the delimiters.
+        let screen = render(Density::Compact, (5, 4));
+        assert_eq!(screen.rows(), ["┌───┐", "│a 1│", "│b 2│", "└───┘"]);
+
+        // The density belongs to each root.
+        let screen = crate::test::render_view(list(), (5, 5));
+        assert_eq!(screen.rows(), ["┌───┐", "│a 1│", "│   │", "│b 2│", "└───┘"]);
+    }
+}
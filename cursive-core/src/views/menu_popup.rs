@@ -1,7 +1,7 @@
 use crate::{
     align::Align,
     event::{Callback, Event, EventResult, Key, MouseButton, MouseEvent},
-    menu,
+    keymap, menu,
     rect::Rect,
     style::PaletteStyle,
     view::scroll,
@@ -11,6 +11,7 @@ use crate::{
 };
 use std::cmp::min;
 use std::sync::Arc;
+use unicode_width::UnicodeWidthStr;
 
 /// Popup that shows a list of items.
 ///
@@ -66,11 +67,34 @@ impl MenuPopup {
         self.focus
     }
 
-    fn item_width(item: &menu::Item) -> usize {
+    // Width of the check marks column, if any item has one.
+    fn check_width(&self) -> usize {
+        if self
+            .menu
+            .children
+            .iter()
+            .any(|item| item.get_check().is_some())
+        {
+            4
+        } else {
+            0
+        }
+    }
+
+    fn item_width(&self, item: &menu::Item) -> usize {
         match *item {
             menu::Item::Delimiter => 1,
-            menu::Item::Leaf { ref label, .. } => label.width(),
-            menu::Item::Subtree { ref label, .. } => label.width() + 3,
+            menu::Item::Leaf {
+                ref label,
+                ref accelerator,
+                ..
+            } => {
+                let accelerator = accelerator
+                    .as_ref()
+                    .map_or(0, |event| describe_accelerator(event).width() + 2);
+                self.check_width() + label.width() + accelerator
+            }
+            menu::Item::Subtree { ref label, .. } => self.check_width() + label.width() + 3,
         }
     }
 
@@ -222,7 +246,7 @@ impl MenuPopup {
             .menu
             .children
             .iter()
-            .map(MenuPopup::item_width)
+            .map(|item| self.item_width(item))
             .max()
             .unwrap_or(1);
         let offset = Vec2::new(max_width, self.focus);
@@ -230,6 +254,10 @@ impl MenuPopup {
 
         EventResult::with_cb(move |s| {
             let action_cb = action_cb.clone();
+            let tree = match tree.generate(s) {
+                Some(generated) => Arc::new(generated),
+                None => Arc::clone(&tree),
+            };
             s.screen_mut().add_layer_at(
                 Position::parent(offset),
                 OnEventView::new(MenuPopup::new(Arc::clone(&tree)).on_action(move |s| {
@@ -272,7 +300,9 @@ impl MenuPopup {
                     _ => unreachable!("Child is a subtree"),
                 };
             }
-            Event::Key(Key::Enter) if self.menu.children[self.focus].is_enabled() => {
+            _ if self.menu.children[self.focus].is_enabled()
+                && keymap::matches("submit", &event) =>
+            {
                 return self.submit();
             }
             Event::Mouse {
@@ -301,7 +331,7 @@ impl MenuPopup {
             {
                 return self.submit();
             }
-            Event::Key(Key::Esc) => {
+            _ if keymap::matches("cancel", &event) => {
                 return self.dismiss();
             }
 
@@ -317,7 +347,7 @@ impl MenuPopup {
             .menu
             .children
             .iter()
-            .map(Self::item_width)
+            .map(|item| self.item_width(item))
             .max()
             .unwrap_or(1);
 
@@ -335,6 +365,11 @@ impl MenuPopup {
     }
 }
 
+// Shows an accelerator like `Ctrl-s`.
+fn describe_accelerator(event: &Event) -> String {
+    crate::chords::describe(std::slice::from_ref(event))
+}
+
 impl View for MenuPopup {
     fn draw(&self, printer: &Printer) {
         if !printer.size.fits((2, 2)) {
@@ -367,6 +402,7 @@ impl View for MenuPopup {
             } else {
                 PaletteStyle::Primary
             };
+            let label_x = 1 + s.check_width();
             printer.with_style(style, |printer| {
                 match *item {
                     menu::Item::Delimiter => {
@@ -378,16 +414,29 @@ impl View for MenuPopup {
                             return;
                         }
                         printer.print_hline((0, 0), printer.size.x, " ");
-                        printer.print_styled((1, 0), label);
+                        printer.print_styled((label_x, 0), label);
                         let x = printer.size.x.saturating_sub(3);
                         printer.print((x, 0), ">>");
                     }
-                    menu::Item::Leaf { ref label, .. } => {
+                    menu::Item::Leaf {
+                        ref label,
+                        ref check,
+                        ref accelerator,
+                        ..
+                    } => {
                         if printer.size.x < 2 {
                             return;
                         }
                         printer.print_hline((0, 0), printer.size.x, " ");
-                        printer.print_styled((1, 0), label);
+                        if let Some(check) = check {
+                            printer.print((1, 0), check.marker());
+                        }
+                        printer.print_styled((label_x, 0), label);
+                        if let Some(accelerator) = accelerator {
+                            let text = describe_accelerator(accelerator);
+                            let x = printer.size.x.saturating_sub(text.width() + 1);
+                            printer.print((x, 0), &text);
+                        }
                     }
                 }
             });
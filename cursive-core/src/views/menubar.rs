@@ -9,6 +9,7 @@ use crate::{
     views::{MenuPopup, OnEventView},
     Cursive, Printer, Vec2,
 };
+use std::ops::Range;
 use std::sync::Arc;
 use unicode_width::UnicodeWidthStr;
 
@@ -32,6 +33,10 @@ enum State {
 /// The [`Cursive`] root already includes a menubar
 /// that you just need to configure.
 ///
+/// When the items don't fit in the screen width, the menubar scrolls to keep
+/// the selected item visible, and shows `«` or `»` at the edges when more
+/// items are hidden on that side.
+///
 /// [`Cursive`]: crate::Cursive::menubar
 pub struct Menubar {
     /// Menu items in this menubar.
@@ -41,6 +46,12 @@ pub struct Menubar {
     pub autohide: bool,
     focus: usize,
 
+    // Index of the first visible item.
+    scroll: usize,
+
+    // Width available for the menubar, as of the last layout.
+    width: usize,
+
     // TODO: make Menubar impl View and take out the State management
     state: State,
 }
@@ -55,6 +66,9 @@ impl Menubar {
             autohide: true,
             state: State::Inactive,
             focus: 0,
+            scroll: 0,
+            // Until the first layout, assume everything fits.
+            width: usize::MAX,
         }
     }
 
@@ -151,6 +165,7 @@ impl Menubar {
     pub fn clear(&mut self) {
         self.root.clear();
         self.focus = 0;
+        self.scroll = 0;
     }
 
     /// Returns the number of items in this menubar.
@@ -187,14 +202,80 @@ impl Menubar {
         self.root.remove(i);
     }
 
+    /// Looks for an enabled leaf with the given accelerator in the menus.
+    ///
+    /// See [`menu::Tree::find_accelerator`].
+    pub fn find_accelerator(&self, event: &Event) -> Option<Callback> {
+        self.root.find_accelerator(event)
+    }
+
+    // Width taken by the item at the given position, including its padding.
+    fn item_width(&self, i: usize) -> usize {
+        self.root.children[i].label().width() + 2
+    }
+
+    // Returns `true` if the items don't all fit in the available width.
+    fn overflows(&self) -> bool {
+        let total = (0..self.root.len())
+            .map(|i| self.item_width(i))
+            .sum::<usize>();
+        1 + total > self.width
+    }
+
+    // Width available for the items, between the scroll indicators.
+    fn available_width(&self) -> usize {
+        if self.overflows() {
+            self.width.saturating_sub(2)
+        } else {
+            self.width - 1
+        }
+    }
+
+    /// Scrolls the menubar so the focused item is visible.
+    fn scroll_to_focus(&mut self) {
+        if !self.overflows() {
+            self.scroll = 0;
+            return;
+        }
+
+        let available = self.available_width();
+        let width = |range: Range<usize>| range.map(|i| self.item_width(i)).sum::<usize>();
+
+        let mut scroll = self.scroll.min(self.focus);
+        while scroll < self.focus && width(scroll..self.focus + 1) > available {
+            scroll += 1;
+        }
+        // Don't leave empty space at the end if earlier items could fit.
+        while scroll > 0 && width(scroll - 1..self.root.len()) <= available {
+            scroll -= 1;
+        }
+        self.scroll = scroll;
+    }
+
+    /// Returns the range of items currently visible.
+    fn visible_items(&self) -> Range<usize> {
+        let available = self.available_width();
+        let mut offset = 0;
+        let mut end = self.scroll;
+        while end < self.root.len() && offset + self.item_width(end) <= available {
+            offset += self.item_width(end);
+            end += 1;
+        }
+        // Always show the first item, even if it gets clipped.
+        if end == self.scroll && end < self.root.len() {
+            end += 1;
+        }
+        self.scroll..end
+    }
+
     fn child_at(&self, x: usize) -> Option<usize> {
         if x == 0 {
             return None;
         }
         let mut offset = 1;
 
-        for (i, child) in self.root.children.iter().enumerate() {
-            offset += child.label().width() + 2;
+        for i in self.visible_items() {
+            offset += self.item_width(i);
             if x < offset {
                 return Some(i);
             }
@@ -203,6 +284,43 @@ impl Menubar {
         None
     }
 
+    fn focus_prev(&mut self) {
+        loop {
+            // TODO: fix endless loop if nothing is enabled?
+            if self.focus > 0 {
+                self.focus -= 1;
+            } else {
+                self.focus = self.root.len() - 1;
+            }
+            if self.root.children[self.focus].is_enabled() {
+                break;
+            }
+        }
+        self.scroll_to_focus();
+    }
+
+    fn focus_next(&mut self) {
+        loop {
+            if self.focus + 1 < self.root.len() {
+                self.focus += 1;
+            } else {
+                self.focus = 0;
+            }
+            if self.root.children[self.focus].is_enabled() {
+                break;
+            }
+        }
+        self.scroll_to_focus();
+    }
+
+    // Focuses the first enabled item in the given range.
+    fn focus_first_in<I: Iterator<Item = usize>>(&mut self, mut range: I) {
+        if let Some(i) = range.find(|&i| self.root.children[i].is_enabled()) {
+            self.focus = i;
+            self.scroll_to_focus();
+        }
+    }
+
     fn select_child(&mut self, open_only: bool) -> EventResult {
         match self.root.children[self.focus] {
             menu::Item::Leaf { ref cb, .. } if !open_only => {
@@ -216,11 +334,9 @@ impl Menubar {
                 let menu = Arc::clone(tree);
 
                 self.state = State::Submenu;
+                self.scroll_to_focus();
                 let offset = Vec2::new(
-                    self.root.children[..self.focus]
-                        .iter()
-                        .map(|child| child.label().width() + 2)
-                        .sum(),
+                    (self.scroll..self.focus).map(|i| self.item_width(i)).sum(),
                     usize::from(self.autohide),
                 );
                 // Since the closure will be called multiple times,
@@ -233,6 +349,11 @@ impl Menubar {
 }
 
 fn show_child(s: &mut Cursive, offset: Vec2, menu: Arc<menu::Tree>) {
+    let menu = match menu.generate(s) {
+        Some(generated) => Arc::new(generated),
+        None => menu,
+    };
+
     // Adds a new layer located near the item title with the menu popup.
     // Also adds two key callbacks on this new view, to handle `left` and
     // `right` key presses.
@@ -273,9 +394,17 @@ impl View for Menubar {
         printer.with_style(PaletteStyle::View, |printer| {
             printer.print_hline((0, 0), printer.size.x, " ");
 
-            // TODO: draw the rest
+            let visible = self.visible_items();
+            if visible.start > 0 {
+                printer.print((0, 0), "«");
+            }
+            if visible.end < self.root.len() {
+                printer.print((printer.size.x.saturating_sub(1), 0), "»");
+            }
+
             let mut offset = 1;
-            for (i, item) in self.root.children.iter().enumerate() {
+            for i in visible {
+                let item = &self.root.children[i];
                 let label = item.styled_label();
                 let label_width = label.width();
                 // We print disabled items differently, except delimiters,
@@ -310,27 +439,10 @@ impl View for Menubar {
                 self.hide();
                 return EventResult::with_cb(Cursive::clear);
             }
-            Event::Key(Key::Left) => loop {
-                // TODO: fix endless loop if nothing is enabled?
-                if self.focus > 0 {
-                    self.focus -= 1;
-                } else {
-                    self.focus = self.root.len() - 1;
-                }
-                if self.root.children[self.focus].is_enabled() {
-                    break;
-                }
-            },
-            Event::Key(Key::Right) => loop {
-                if self.focus + 1 < self.root.len() {
-                    self.focus += 1;
-                } else {
-                    self.focus = 0;
-                }
-                if self.root.children[self.focus].is_enabled() {
-                    break;
-                }
-            },
+            Event::Key(Key::Left) => self.focus_prev(),
+            Event::Key(Key::Right) => self.focus_next(),
+            Event::Key(Key::Home) => self.focus_first_in(0..self.root.len()),
+            Event::Key(Key::End) => self.focus_first_in((0..self.root.len()).rev()),
             Event::Key(Key::Down) => {
                 return self.select_child(true);
             }
@@ -342,10 +454,15 @@ impl View for Menubar {
                 position,
                 offset,
             } if position.fits(offset) && position.y == offset.y => {
-                if let Some(child) = position
-                    .checked_sub(offset)
-                    .and_then(|pos| self.child_at(pos.x))
-                {
+                let x = position.x - offset.x;
+                let visible = self.visible_items();
+                if x == 0 && visible.start > 0 {
+                    // Clicked on the left scroll indicator.
+                    self.focus_first_in((0..visible.start).rev());
+                } else if x + 1 == self.width && visible.end < self.root.len() {
+                    // Clicked on the right scroll indicator.
+                    self.focus_first_in(visible.end..self.root.len());
+                } else if let Some(child) = self.child_at(x) {
                     if self.root.children[child].is_enabled() {
                         self.focus = child;
                         if btn == MouseButton::Left {
@@ -388,8 +505,16 @@ impl View for Menubar {
         Ok(EventResult::consumed())
     }
 
+    fn layout(&mut self, size: Vec2) {
+        self.width = size.x;
+        if !self.root.is_empty() {
+            self.focus = self.focus.min(self.root.len() - 1);
+            self.scroll_to_focus();
+        }
+    }
+
     fn required_size(&mut self, _: Vec2) -> Vec2 {
-        // TODO: scroll the options if the screen is too small?
+        // Items that don't fit are scrolled (see `layout()`).
 
         // We add 2 to the length of every label for marin.
         // Also, we add 1 at the beginning.
@@ -411,13 +536,119 @@ impl View for Menubar {
 
         // X position is 1 (margin before the first item) + sum of widths
         // And each item has a 2 cells padding.
-        let x = 1 + self.root.children[..self.focus]
-            .iter()
-            .map(|child| child.label().width() + 2)
-            .sum::<usize>();
+        let x = 1
+            + (self.scroll..self.focus)
+                .map(|i| self.item_width(i))
+                .sum::<usize>();
 
         let width = self.root.children[self.focus].label().width();
 
         Rect::from_size((x, 0), (width, 1))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::event::{Event, Key, MouseButton, MouseEvent};
+    use crate::menu;
+    use crate::Cursive;
+
+    #[test]
+    fn scrolls_to_focus() {
+        let mut siv = Cursive::new();
+        siv.set_autohide_menu(false);
+        for i in 0..6 {
+            siv.menubar()
+                .add_subtree(format!("Menu{i}"), menu::Tree::new().leaf("Item", |_| ()));
+        }
+
+        let mut harness = crate::test::Harness::with_cursive(siv, (20, 6));
+        let row = harness.screen().row(0);
+        assert!(row.starts_with("  Menu0  Menu1"));
+        assert!(row.ends_with('»'));
+
+        harness.cursive().select_menubar();
+        harness.feed_event(Event::Key(Key::End));
+        let row = harness.screen().row(0);
+        assert!(row.starts_with('«'));
+        assert!(row.contains("Menu5"));
+        assert!(!row.contains("Menu0"));
+
+        // The popup is placed below the scrolled item.
+        harness.feed_event(Event::Key(Key::Down));
+        let screen = harness.screen();
+        let x = screen.find("Menu5").unwrap().x;
+        assert_eq!(screen.find("Item").unwrap().y, 2);
+        assert!(screen.find("Item").unwrap().x >= x);
+
+        // Clicking the left indicator scrolls back.
+        harness.feed_event(Event::Key(Key::Esc));
+        harness.cursive().select_menubar();
+        harness.feed_event(Event::Mouse {
+            offset: (0, 0).into(),
+            position: (0, 0).into(),
+            event: MouseEvent::Press(MouseButton::Left),
+        });
+        assert!(harness.screen().row(0).contains("Menu4"));
+    }
+
+    #[test]
+    fn dynamic_subtree() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        static OPENED: AtomicUsize = AtomicUsize::new(0);
+        let saved = Arc::new(AtomicUsize::new(0));
+
+        let mut siv = Cursive::new();
+        siv.set_autohide_menu(false);
+        siv.menubar().add_subtree(
+            "File",
+            menu::Tree::new()
+                .item(
+                    menu::Item::leaf("Save", {
+                        let saved = Arc::clone(&saved);
+                        move |_| {
+                            saved.fetch_add(1, Ordering::Relaxed);
+                        }
+                    })
+                    .accelerator(Event::CtrlChar('s')),
+                )
+                .checkbox("Autosave", true, |_, _| ())
+                .subtree(
+                    "Recent",
+                    menu::Tree::dynamic(|_| {
+                        let n = OPENED.fetch_add(1, Ordering::Relaxed);
+                        menu::Tree::new().leaf(format!("file{n}.txt"), |_| ())
+                    }),
+                ),
+        );
+
+        let mut harness = crate::test::Harness::with_cursive(siv, (40, 10));
+        harness.cursive().select_menubar();
+        harness.feed_event(Event::Key(Key::Down));
+        let screen = harness.screen();
+        let save = screen
+            .rows()
+            .into_iter()
+            .find(|row| row.contains("Save"))
+            .unwrap();
+        assert!(save.contains("Ctrl-s"));
+        assert!(save.find("Save") < save.find("Ctrl-s"));
+        assert!(screen.contains("[X] Autosave"));
+
+        // The subtree children are generated every time it is opened.
+        harness.feed_events([Event::Key(Key::Up), Event::Key(Key::Right)]);
+        assert!(harness.screen().contains("file0.txt"));
+        harness.feed_event(Event::Key(Key::Left));
+        harness.feed_event(Event::Key(Key::Right));
+        assert!(harness.screen().contains("file1.txt"));
+
+        // Accelerators work when no menu is open.
+        harness.feed_event(Event::CtrlChar('s'));
+        assert_eq!(saved.load(Ordering::Relaxed), 0);
+        harness.feed_events(std::iter::repeat(Event::Key(Key::Esc)).take(3));
+        harness.feed_event(Event::CtrlChar('s'));
+        assert_eq!(saved.load(Ordering::Relaxed), 1);
+    }
+}
@@ -61,8 +61,12 @@ macro_rules! impl_enabled {
     };
 }
 
+mod background_view;
+mod bar_chart;
 mod boxed_view;
+mod busy_view;
 mod button;
+mod cached_view;
 mod canvas;
 mod checkbox;
 mod circular_focus;
@@ -75,68 +79,96 @@ mod fixed_layout;
 mod focus_tracker;
 mod gradient_view;
 mod hideable_view;
+mod image_view;
 mod last_size_view;
 mod layer;
+mod lazy_text_view;
 mod linear_layout;
 mod list_view;
 mod menu_popup;
 mod menubar;
+mod multi_select_view;
 mod named_view;
+mod number_input;
 mod on_event_view;
 mod on_layout_view;
 mod padded_view;
 mod panel;
 mod progress_bar;
 mod radio;
+mod radio_panel;
 mod resized_view;
 mod screens_view;
 mod scroll_view;
 mod select_view;
 mod shadow_view;
 mod slider_view;
+mod sparkline;
+mod spinner;
 pub mod stack_view;
+mod status_bar;
+mod tab_view;
 mod text_area;
 mod text_view;
 mod themed_view;
+mod toolbar;
 mod tracked_view;
+pub mod tree_view;
+mod window_manager;
 
 pub use self::{
+    background_view::BackgroundView,
+    bar_chart::BarChart,
     boxed_view::BoxedView,
+    busy_view::BusyView,
     button::Button,
+    cached_view::CachedView,
     canvas::Canvas,
     checkbox::Checkbox,
     circular_focus::CircularFocus,
     debug_view::DebugView,
-    dialog::{Dialog, DialogFocus},
+    dialog::{Dialog, DialogChoice, DialogFocus, MnemonicAction},
     dummy::DummyView,
-    edit_view::EditView,
+    edit_view::{EditView, InputMask},
     enableable_view::EnableableView,
     fixed_layout::FixedLayout,
     focus_tracker::FocusTracker,
     gradient_view::GradientView,
     hideable_view::HideableView,
+    image_view::ImageView,
     last_size_view::LastSizeView,
     layer::Layer,
+    lazy_text_view::LazyTextView,
     linear_layout::LinearLayout,
     list_view::{ListChild, ListView},
     menu_popup::MenuPopup,
     menubar::Menubar,
+    multi_select_view::MultiSelectView,
     named_view::{NamedView, ViewRef},
+    number_input::NumberInput,
     on_event_view::OnEventView,
     on_layout_view::OnLayoutView,
     padded_view::PaddedView,
     panel::Panel,
-    progress_bar::ProgressBar,
+    progress_bar::{ProgressBar, ProgressGlyphs},
     radio::{RadioButton, RadioGroup},
+    radio_panel::RadioPanel,
     resized_view::ResizedView,
-    screens_view::ScreensView,
+    screens_view::{ScreenTransition, ScreensView},
     scroll_view::ScrollView,
-    select_view::SelectView,
+    select_view::{SelectColumns, SelectView},
     shadow_view::ShadowView,
     slider_view::SliderView,
+    sparkline::{ChartGlyphs, SeriesContent, Sparkline},
+    spinner::{Spinner, SpinnerStyle},
     stack_view::{LayerPosition, StackView},
+    status_bar::StatusBar,
+    tab_view::{TabBarPosition, TabView},
     text_area::TextArea,
     text_view::{TextContent, TextContentRef, TextView},
     themed_view::ThemedView,
+    toolbar::Toolbar,
     tracked_view::TrackedView,
+    tree_view::{TreeItem, TreeView},
+    window_manager::{WindowId, WindowManager},
 };
@@ -0,0 +1,879 @@
+use crate::{
+    align::{Align, HAlign, VAlign},
+    direction,
+    event::{Callback, Event, EventResult, Key, MouseButton, MouseEvent},
+    keymap, menu,
+    rect::Rect,
+    style::{PaletteStyle, Style, StyleType},
+    utils::{markup::StyledString, placeholder, TypeAhead},
+    view::{CannotFocus, Position, View},
+    views::{LayerPosition, MenuPopup},
+    Cursive, Printer, Vec2, With,
+};
+use std::cmp::min;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use unicode_width::UnicodeWidthStr;
+
+type MultiSelectCallback<T> = dyn Fn(&mut Cursive, &[Arc<T>]) + Send + Sync;
+
+// Width of the checkbox in front of each label, including the space.
+const CHECKBOX_WIDTH: usize = 4;
+
+/// View to select any number of items among a list.
+///
+/// Each item is shown with a checkbox. `<Space>` toggles the focused item,
+/// and `a` toggles all the items (or `Ctrl-A` when autojump is enabled). Items
+/// can also be toggled with the mouse.
+///
+/// Callbacks are given the values of all the selected items, in order.
+///
+/// # Examples
+///
+/// ```rust
+/// # use cursive_core::views::{Dialog, MultiSelectView};
+/// let toppings = MultiSelectView::new()
+///     .item("Cheese", 1)
+///     .item("Mushrooms", 2)
+///     .item("Olives", 3)
+///     .selected(0)
+///     .on_submit(|s, toppings| {
+///         let text = format!("{} toppings, coming right up!", toppings.len());
+///         s.add_layer(Dialog::info(text));
+///     });
+/// assert_eq!(toppings.selected_ids(), [0]);
+/// ```
+pub struct MultiSelectView<T = String> {
+    items: Vec<Item<T>>,
+
+    // When disabled, we cannot change selection.
+    enabled: bool,
+
+    // Index of the item under the cursor.
+    focus: usize,
+
+    // If true, highlight the focused item even when inactive (not focused).
+    inactive_highlight: bool,
+
+    // Called when the set of selected items changes.
+    on_change: Option<Arc<MultiSelectCallback<T>>>,
+
+    // Called when "Enter" is pressed.
+    on_submit: Option<Arc<MultiSelectCallback<T>>>,
+
+    // If `true`, typed characters focus the next item starting with them.
+    autojump: bool,
+
+    // Characters typed so far for autojump.
+    type_ahead: TypeAhead,
+
+    align: Align,
+
+    // `true` if we show a one-line view, with a popup to toggle items.
+    popup: bool,
+    // Decorators to draw around the popup button.
+    decorators: [String; 2],
+
+    // We need the last offset to place the popup window.
+    last_offset: Mutex<Vec2>,
+    last_size: Vec2,
+
+    // Text shown when there is no item.
+    placeholder: Option<StyledString>,
+}
+
+impl<T: 'static + Send + Sync> Default for MultiSelectView<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: 'static + Send + Sync> MultiSelectView<T> {
+    impl_enabled!(self.enabled);
+
+    /// Creates a new empty MultiSelectView.
+    pub fn new() -> Self {
+        MultiSelectView {
+            items: Vec::new(),
+            enabled: true,
+            focus: 0,
+            inactive_highlight: true,
+            on_change: None,
+            on_submit: None,
+            autojump: false,
+            type_ahead: TypeAhead::new(),
+            align: Align::top_left(),
+            popup: false,
+            decorators: ["<".to_string(), ">".to_string()],
+            last_offset: Mutex::new(Vec2::zero()),
+            last_size: Vec2::zero(),
+            placeholder: None,
+        }
+    }
+
+    /// Sets the "auto-jump" property for this view.
+    ///
+    /// If enabled, when a key is pressed, the focus will jump to the next
+    /// item starting with the typed characters. `Ctrl-A` then toggles all
+    /// the items.
+    pub fn set_autojump(&mut self, autojump: bool) {
+        self.autojump = autojump;
+    }
+
+    /// Sets the "auto-jump" property for this view.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn autojump(self) -> Self {
+        self.with(|s| s.set_autojump(true))
+    }
+
+    /// Sets the "inactive highlight" property.
+    ///
+    /// If `true`, the focused item will be highlighted even when the view
+    /// is not focused.
+    pub fn set_inactive_highlight(&mut self, inactive_highlight: bool) {
+        self.inactive_highlight = inactive_highlight;
+    }
+
+    /// Sets the "inactive highlight" property.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn with_inactive_highlight(self, inactive_highlight: bool) -> Self {
+        self.with(|s| s.set_inactive_highlight(inactive_highlight))
+    }
+
+    /// Gets the "inactive highlight" property.
+    pub fn get_inactive_highlight(&self) -> bool {
+        self.inactive_highlight
+    }
+
+    /// Turns `self` into a popup view.
+    ///
+    /// The view then takes a single line, summarizing the selection. A popup
+    /// opens on `<Enter>` to toggle items.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn popup(self) -> Self {
+        self.with(|s| s.set_popup(true))
+    }
+
+    /// Turns `self` into a popup view.
+    pub fn set_popup(&mut self, popup: bool) {
+        self.popup = popup;
+    }
+
+    /// Use custom decorators around the popup button instead of "<" and ">".
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn decorators<S: Into<String>>(self, start: S, end: S) -> Self {
+        self.with(|s| s.set_decorators(start, end))
+    }
+
+    /// Use custom decorators around the popup button instead of "<" and ">".
+    pub fn set_decorators<S: Into<String>>(&mut self, start: S, end: S) {
+        self.decorators = [start.into(), end.into()];
+    }
+
+    /// Sets a placeholder to show when there is no item.
+    ///
+    /// It is not shown in popup mode.
+    pub fn set_placeholder<S: Into<StyledString>>(&mut self, placeholder: S) {
+        self.placeholder = Some(placeholder.into());
+    }
+
+    /// Sets a placeholder to show when there is no item.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn placeholder<S: Into<StyledString>>(self, placeholder: S) -> Self {
+        self.with(|s| s.set_placeholder(placeholder))
+    }
+
+    // Returns the placeholder if it should be shown instead of the items.
+    fn active_placeholder(&self) -> Option<&StyledString> {
+        if self.items.is_empty() && !self.popup {
+            self.placeholder.as_ref()
+        } else {
+            None
+        }
+    }
+
+    /// Sets a callback to be used when items are selected or unselected.
+    ///
+    /// The callback is given all the selected values.
+    #[crate::callback_helpers]
+    pub fn set_on_change<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, &[Arc<T>]) + 'static + Send + Sync,
+    {
+        self.on_change = Some(Arc::new(cb));
+    }
+
+    /// Sets a callback to be used when items are selected or unselected.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn on_change<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, &[Arc<T>]) + 'static + Send + Sync,
+    {
+        self.with(|s| s.set_on_change(cb))
+    }
+
+    /// Sets a callback to be used when `<Enter>` is pressed.
+    ///
+    /// The callback is given all the selected values.
+    #[crate::callback_helpers]
+    pub fn set_on_submit<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, &[Arc<T>]) + 'static + Send + Sync,
+    {
+        self.on_submit = Some(Arc::new(cb));
+    }
+
+    /// Sets a callback to be used when `<Enter>` is pressed.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn on_submit<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, &[Arc<T>]) + 'static + Send + Sync,
+    {
+        self.with(|s| s.set_on_submit(cb))
+    }
+
+    /// Sets the alignment for this view.
+    #[must_use]
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+
+        self
+    }
+
+    /// Sets the vertical alignment for this view.
+    #[must_use]
+    pub fn v_align(mut self, v: VAlign) -> Self {
+        self.align.v = v;
+
+        self
+    }
+
+    /// Sets the horizontal alignment of the labels.
+    #[must_use]
+    pub fn h_align(mut self, h: HAlign) -> Self {
+        self.align.h = h;
+
+        self
+    }
+
+    /// Returns the values of the selected items, in order.
+    pub fn selection(&self) -> Vec<Arc<T>> {
+        self.items
+            .iter()
+            .filter(|item| item.is_selected())
+            .map(|item| Arc::clone(&item.value))
+            .collect()
+    }
+
+    /// Returns the indexes of the selected items, in order.
+    pub fn selected_ids(&self) -> Vec<usize> {
+        (0..self.items.len())
+            .filter(|&i| self.items[i].is_selected())
+            .collect()
+    }
+
+    /// Returns `true` if the item at index `i` is selected.
+    pub fn is_selected(&self, i: usize) -> bool {
+        matches!(self.items.get(i), Some(item) if item.is_selected())
+    }
+
+    /// Selects or unselects the item at index `i`.
+    ///
+    /// Returns a callback in response to the selection change.
+    ///
+    /// You should run this callback with a `&mut Cursive`.
+    pub fn set_selected(&mut self, i: usize, selected: bool) -> Callback {
+        if self.items[i].selected.swap(selected, Ordering::Relaxed) == selected {
+            return Callback::dummy();
+        }
+        self.make_change_cb().unwrap_or_else(Callback::dummy)
+    }
+
+    /// Selects the item at index `i`.
+    ///
+    /// Chainable variant. Does not call the callback.
+    #[must_use]
+    pub fn selected(self, i: usize) -> Self {
+        self.with(|s| {
+            s.set_selected(i, true);
+        })
+    }
+
+    /// Toggles the item at index `i`.
+    ///
+    /// Returns a callback in response to the selection change.
+    pub fn toggle(&mut self, i: usize) -> Callback {
+        let selected = self.is_selected(i);
+        self.set_selected(i, !selected)
+    }
+
+    /// Selects all the items.
+    ///
+    /// Returns a callback in response to the selection change.
+    pub fn select_all(&mut self) -> Callback {
+        self.set_all(true)
+    }
+
+    /// Unselects all the items.
+    ///
+    /// Returns a callback in response to the selection change.
+    pub fn clear_selection(&mut self) -> Callback {
+        self.set_all(false)
+    }
+
+    fn set_all(&mut self, selected: bool) -> Callback {
+        let mut changed = false;
+        for item in &self.items {
+            changed |= item.selected.swap(selected, Ordering::Relaxed) != selected;
+        }
+
+        changed
+            .then(|| self.make_change_cb())
+            .flatten()
+            .unwrap_or_else(Callback::dummy)
+    }
+
+    /// Returns the index of the item under the cursor.
+    ///
+    /// Returns `None` if the list is empty.
+    pub fn focused_id(&self) -> Option<usize> {
+        if self.items.is_empty() {
+            None
+        } else {
+            Some(self.focus)
+        }
+    }
+
+    /// Removes all items from this view.
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.focus = 0;
+    }
+
+    /// Adds a item to the list, with given label and value.
+    ///
+    /// The item is not selected.
+    pub fn add_item<S: Into<StyledString>>(&mut self, label: S, value: T) {
+        self.items.push(Item::new(label.into(), value));
+    }
+
+    /// Chainable variant of add_item
+    #[must_use]
+    pub fn item<S: Into<StyledString>>(self, label: S, value: T) -> Self {
+        self.with(|s| s.add_item(label, value))
+    }
+
+    /// Adds all items from from an iterator.
+    pub fn add_all<S, I>(&mut self, iter: I)
+    where
+        S: Into<StyledString>,
+        I: IntoIterator<Item = (S, T)>,
+    {
+        for (s, t) in iter {
+            self.add_item(s, t);
+        }
+    }
+
+    /// Adds all items from from an iterator.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn with_all<S, I>(self, iter: I) -> Self
+    where
+        S: Into<StyledString>,
+        I: IntoIterator<Item = (S, T)>,
+    {
+        self.with(|s| s.add_all(iter))
+    }
+
+    /// Inserts an item at position `index`, shifting all elements after it to
+    /// the right.
+    pub fn insert_item<S>(&mut self, index: usize, label: S, value: T)
+    where
+        S: Into<StyledString>,
+    {
+        self.items.insert(index, Item::new(label.into(), value));
+        // Do not increase focus if we were empty with focus=0.
+        if self.focus >= index && self.items.len() > 1 {
+            self.focus += 1;
+        }
+    }
+
+    /// Removes an item from the list.
+    ///
+    /// Returns a callback if the removed item was selected.
+    pub fn remove_item(&mut self, id: usize) -> Callback {
+        let item = self.items.remove(id);
+        if self.focus >= id && self.focus > 0 {
+            self.focus -= 1;
+        }
+
+        item.is_selected()
+            .then(|| self.make_change_cb())
+            .flatten()
+            .unwrap_or_else(Callback::dummy)
+    }
+
+    /// Gets an item at given idx or None.
+    ///
+    /// Returns the label, the value, and whether the item is selected.
+    pub fn get_item(&self, i: usize) -> Option<(&str, &T, bool)> {
+        self.iter().nth(i)
+    }
+
+    /// Iterate on the items in this view.
+    ///
+    /// Returns an iterator with each label, value, and whether the item is
+    /// selected.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &T, bool)> {
+        self.items
+            .iter()
+            .map(|item| (item.label.source(), &*item.value, item.is_selected()))
+    }
+
+    /// Returns the number of items in this list.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if this list has no item.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    fn make_change_cb(&self) -> Option<Callback> {
+        let cb = self.on_change.clone()?;
+        let selection = self.selection();
+        Some(Callback::from_fn(move |s| cb(s, &selection)))
+    }
+
+    fn submit(&mut self) -> EventResult {
+        let cb = self.on_submit.clone().unwrap();
+        let selection = self.selection();
+        EventResult::with_cb(move |s| cb(s, &selection))
+    }
+
+    // Toggles all items, or unselects them if they are all selected.
+    fn toggle_all(&mut self) -> Callback {
+        if self.items.iter().all(Item::is_selected) {
+            self.clear_selection()
+        } else {
+            self.select_all()
+        }
+    }
+
+    fn draw_item(&self, printer: &Printer, i: usize) {
+        let item = &self.items[i];
+        printer.print((0, 0), if item.is_selected() { "[X] " } else { "[ ] " });
+
+        let printer = &printer.offset((CHECKBOX_WIDTH, 0));
+        let l = item.label.width();
+        let x = self.align.h.get_offset(l, printer.size.x);
+        printer.print_hline((0, 0), x, " ");
+        printer.print_styled((x, 0), &item.label);
+        if l + x < printer.size.x {
+            printer.print_hline((x + l, 0), printer.size.x - (l + x), " ");
+        }
+    }
+
+    // Text shown in popup mode.
+    fn summary(&self) -> StyledString {
+        let selected: Vec<_> = self
+            .items
+            .iter()
+            .filter(|item| item.is_selected())
+            .collect();
+        match selected[..] {
+            [] => StyledString::new(),
+            [item] => item.label.clone(),
+            _ => format!("{} selected", selected.len()).into(),
+        }
+    }
+
+    fn decorators_width(&self) -> usize {
+        self.decorators.iter().map(|d| d.width()).sum()
+    }
+
+    fn on_char_event(&mut self, c: char) -> EventResult {
+        let labels = self.items.iter().map(|item| item.label.source());
+
+        match self.type_ahead.on_char(c, self.focus, labels) {
+            Some(i) => {
+                self.focus = i;
+                EventResult::consumed()
+            }
+            None => EventResult::Ignored,
+        }
+    }
+
+    fn on_event_regular(&mut self, event: Event) -> EventResult {
+        let last = self.items.len().saturating_sub(1);
+        match event {
+            Event::Key(Key::Up) if self.focus > 0 => self.focus -= 1,
+            Event::Key(Key::Down) if self.focus < last => self.focus += 1,
+            Event::Key(Key::PageUp) => self.focus = self.focus.saturating_sub(10),
+            Event::Key(Key::PageDown) => self.focus = min(self.focus + 10, last),
+            Event::Key(Key::Home) => self.focus = 0,
+            Event::Key(Key::End) => self.focus = last,
+            Event::Char(' ') if !self.items.is_empty() => {
+                return EventResult::Consumed(Some(self.toggle(self.focus)));
+            }
+            Event::Char('a') if !self.autojump => {
+                return EventResult::Consumed(Some(self.toggle_all()));
+            }
+            Event::CtrlChar('a') => return EventResult::Consumed(Some(self.toggle_all())),
+            Event::Mouse {
+                event: MouseEvent::Press(_),
+                position,
+                offset,
+            } if position
+                .checked_sub(offset)
+                .map(|position| position < self.last_size && position.y < self.len())
+                .unwrap_or(false) =>
+            {
+                self.focus = position.y - offset.y;
+            }
+            Event::Mouse {
+                event: MouseEvent::Release(MouseButton::Left),
+                position,
+                offset,
+            } if position
+                .checked_sub(offset)
+                .map(|position| position < self.last_size && position.y == self.focus)
+                .unwrap_or(false) =>
+            {
+                return EventResult::Consumed(Some(self.toggle(self.focus)));
+            }
+            _ if self.on_submit.is_some() && keymap::matches("submit", &event) => {
+                return self.submit();
+            }
+            Event::Char(c) if self.autojump => return self.on_char_event(c),
+            _ => return EventResult::Ignored,
+        }
+
+        EventResult::consumed()
+    }
+
+    fn open_popup(&mut self) -> EventResult {
+        // Each entry toggles an item, then reports the new selection.
+        let items: Arc<Vec<_>> = Arc::new(
+            self.items
+                .iter()
+                .map(|item| (Arc::clone(&item.selected), Arc::clone(&item.value)))
+                .collect(),
+        );
+
+        let mut tree = menu::Tree::new();
+        for (i, item) in self.items.iter().enumerate() {
+            let label = if item.is_selected() { "[X] " } else { "[ ] " };
+            let items = Arc::clone(&items);
+            let on_change = self.on_change.clone();
+
+            tree.add_leaf(format!("{label}{}", item.label.source()), move |s| {
+                items[i].0.fetch_xor(true, Ordering::Relaxed);
+                if let Some(ref on_change) = on_change {
+                    let selection: Vec<_> = items
+                        .iter()
+                        .filter(|(selected, _)| selected.load(Ordering::Relaxed))
+                        .map(|(_, value)| Arc::clone(value))
+                        .collect();
+                    on_change(s, &selection);
+                }
+            });
+        }
+        let tree = Arc::new(tree);
+
+        // Align the popup with the view, shifted top-left of its border.
+        let focus = self.focus;
+        let offset = *self.last_offset.lock().unwrap();
+        let offset = offset.saturating_sub((0, focus)).saturating_sub((2, 1));
+
+        EventResult::with_cb(move |s| {
+            let tree = Arc::clone(&tree);
+            let current_offset = s
+                .screen()
+                .layer_offset(LayerPosition::FromFront(0))
+                .unwrap_or_else(Vec2::zero);
+            let offset = offset.signed() - current_offset;
+            s.screen_mut()
+                .add_layer_at(Position::parent(offset), MenuPopup::new(tree).focus(focus));
+        })
+    }
+
+    fn on_event_popup(&mut self, event: Event) -> EventResult {
+        match event {
+            _ if keymap::matches("submit", &event) => self.open_popup(),
+            Event::Mouse {
+                event: MouseEvent::Release(MouseButton::Left),
+                position,
+                offset,
+            } if position.fits_in_rect(offset, self.last_size) => self.open_popup(),
+            _ => EventResult::Ignored,
+        }
+    }
+}
+
+impl MultiSelectView<String> {
+    /// Convenient method to use the label as value.
+    pub fn add_item_str<S: Into<String>>(&mut self, label: S) {
+        let label = label.into();
+        self.add_item(label.clone(), label);
+    }
+
+    /// Chainable variant of add_item_str
+    #[must_use]
+    pub fn item_str<S: Into<String>>(self, label: S) -> Self {
+        self.with(|s| s.add_item_str(label))
+    }
+
+    /// Adds all strings from an iterator.
+    pub fn add_all_str<S, I>(&mut self, iter: I)
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = S>,
+    {
+        for s in iter {
+            self.add_item_str(s);
+        }
+    }
+
+    /// Adds all strings from an iterator.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn with_all_str<S, I>(self, iter: I) -> Self
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = S>,
+    {
+        self.with(|s| s.add_all_str(iter))
+    }
+}
+
+impl<T: 'static + Send + Sync> View for MultiSelectView<T> {
+    fn draw(&self, printer: &Printer) {
+        *self.last_offset.lock().unwrap() = printer.offset;
+
+        if self.popup {
+            let style = if !(self.enabled && printer.enabled) {
+                PaletteStyle::Secondary.into()
+            } else if printer.focused {
+                printer.theme.focus.list
+            } else {
+                PaletteStyle::Primary.into()
+            };
+
+            let Some(available) = printer.size.x.checked_sub(self.decorators_width()) else {
+                return;
+            };
+
+            printer.with_style(style, |printer| {
+                let decorator0_width = self.decorators[0].width();
+                printer.print_hline((decorator0_width, 0), available, " ");
+                printer.print((0, 0), &self.decorators[0]);
+                printer.print((decorator0_width + available, 0), &self.decorators[1]);
+
+                let summary = self.summary();
+                let offset =
+                    decorator0_width + HAlign::Center.get_offset(summary.width(), available);
+                printer.print_styled((offset, 0), &summary);
+            });
+        } else if let Some(placeholder) = self.active_placeholder() {
+            placeholder::draw(printer, placeholder);
+        } else {
+            let h = self.items.len();
+            let offset = self.align.v.get_offset(h, printer.size.y);
+            let printer = &printer.offset((0, offset));
+
+            let enabled = self.enabled && printer.enabled;
+
+            let regular_style: StyleType = if enabled {
+                Style::inherit_parent().into()
+            } else {
+                PaletteStyle::Secondary.into()
+            };
+
+            let highlight_style = if printer.focused {
+                printer.theme.focus.list
+            } else if self.inactive_highlight {
+                PaletteStyle::HighlightInactive.into()
+            } else {
+                regular_style
+            };
+
+            for i in 0..self.len() {
+                let style = if i == self.focus {
+                    highlight_style
+                } else {
+                    regular_style
+                };
+
+                printer.offset((0, i)).with_style(style, |printer| {
+                    self.draw_item(printer, i);
+                });
+            }
+        }
+    }
+
+    fn required_size(&mut self, constraint: Vec2) -> Vec2 {
+        if let Some(placeholder) = self.active_placeholder() {
+            return placeholder::required_size(placeholder, constraint);
+        }
+
+        let w = self
+            .items
+            .iter()
+            .map(|item| item.label.width())
+            .max()
+            .unwrap_or(1);
+
+        if self.popup {
+            // Leave room for the longest summary.
+            let summary = format!("{} selected", self.items.len()).width();
+            Vec2::new(w.max(summary) + self.decorators_width(), 1)
+        } else {
+            Vec2::new(w + CHECKBOX_WIDTH, self.items.len())
+        }
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        if !self.enabled {
+            return EventResult::Ignored;
+        }
+
+        if self.popup {
+            self.on_event_popup(event)
+        } else {
+            self.on_event_regular(event)
+        }
+    }
+
+    fn take_focus(&mut self, source: direction::Direction) -> Result<EventResult, CannotFocus> {
+        (self.enabled && !self.items.is_empty())
+            .then(|| {
+                if !self.popup {
+                    match source {
+                        direction::Direction::Abs(direction::Absolute::Up) => {
+                            self.focus = 0;
+                        }
+                        direction::Direction::Abs(direction::Absolute::Down) => {
+                            self.focus = self.items.len().saturating_sub(1);
+                        }
+                        _ => (),
+                    }
+                }
+                EventResult::Consumed(None)
+            })
+            .ok_or(CannotFocus)
+    }
+
+    fn layout(&mut self, size: Vec2) {
+        self.last_size = size;
+    }
+
+    fn important_area(&self, size: Vec2) -> Rect {
+        match self.focused_id() {
+            Some(i) if !self.popup => Rect::from_size((0, i), (size.x, 1)),
+            _ => Rect::from_size(Vec2::zero(), size),
+        }
+    }
+}
+
+// Items are shared with the popup, which toggles them directly.
+struct Item<T> {
+    label: StyledString,
+    value: Arc<T>,
+    selected: Arc<AtomicBool>,
+}
+
+impl<T> Item<T> {
+    fn new(label: StyledString, value: T) -> Self {
+        Item {
+            label,
+            value: Arc::new(value),
+            selected: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    fn is_selected(&self) -> bool {
+        self.selected.load(Ordering::Relaxed)
+    }
+}
+
+#[crate::blueprint(MultiSelectView::<String>::new())]
+struct Blueprint {
+    autojump: Option<bool>,
+    popup: Option<bool>,
+    placeholder: Option<StyledString>,
+
+    on_change: Option<_>,
+    on_submit: Option<_>,
+
+    #[blueprint(foreach = add_item_str)]
+    items: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::render_view;
+
+    #[test]
+    fn toggle() {
+        let mut view = MultiSelectView::new()
+            .item("One", 1)
+            .item("Two", 2)
+            .item("Three", 3);
+        assert!(view.selection().is_empty());
+
+        view.on_event(Event::Char(' '));
+        view.on_event(Event::Key(Key::Down));
+        view.on_event(Event::Key(Key::Down));
+        view.on_event(Event::Char(' '));
+        assert_eq!(view.selected_ids(), [0, 2]);
+        let values: Vec<i32> = view.selection().iter().map(|v| **v).collect();
+        assert_eq!(values, [1, 3]);
+
+        // Select all, then nothing.
+        view.on_event(Event::Char('a'));
+        assert_eq!(view.selected_ids(), [0, 1, 2]);
+        view.on_event(Event::Char('a'));
+        assert!(view.selected_ids().is_empty());
+
+        view.set_selected(1, true);
+        view.remove_item(0);
+        assert_eq!(view.selected_ids(), [0]);
+
+        let screen = render_view(view, (9, 2));
+        assert_eq!(screen.rows(), ["[X] Two  ", "[ ] Three"]);
+    }
+
+    #[test]
+    fn popup_summary() {
+        let mut view = MultiSelectView::new()
+            .popup()
+            .with_all_str(["Red", "Green"]);
+        assert_eq!(view.summary().source(), "");
+
+        view.set_selected(1, true);
+        assert_eq!(view.summary().source(), "Green");
+
+        view.set_selected(0, true);
+        assert_eq!(view.summary().source(), "2 selected");
+    }
+}
@@ -1,10 +1,17 @@
 use crate::{
+    buffer::PrintBuffer,
     event::{AnyCb, EventResult},
+    rect::Rect,
     view::{Selector, View, ViewNotFound, ViewWrapper},
+    Printer, Vec2,
 };
-use parking_lot::Mutex;
+use parking_lot::{Mutex, RwLock};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
+// Used to generate unique names.
+static NAME_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
 /// Wrapper around a view to make it identifiable.
 ///
 /// This lets other views refer to this one using a string identifier.
@@ -46,6 +53,31 @@ impl<V> NamedView<V> {
         }
     }
 
+    /// Wraps `view` in a new `NamedView` with a generated name.
+    ///
+    /// The name is made from the type of the view and a global counter (for
+    /// example `TextView#3`), so it will not collide with other generated names.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::views::{NamedView, TextView};
+    /// let a = NamedView::new_unique(TextView::new("a"));
+    /// let b = NamedView::new_unique(TextView::new("b"));
+    ///
+    /// assert!(a.name().starts_with("TextView#"));
+    /// assert_ne!(a.name(), b.name());
+    /// ```
+    pub fn new_unique(view: V) -> Self {
+        let type_name = std::any::type_name::<V>();
+        let type_name = type_name.split('<').next().unwrap_or(type_name);
+        let type_name = type_name.rsplit("::").next().unwrap_or(type_name);
+
+        let id = NAME_COUNTER.fetch_add(1, Ordering::Relaxed);
+
+        NamedView::new(format!("{type_name}#{id}"), view)
+    }
+
     /// Gets mutable access to the inner view.
     ///
     /// This returns a `ViewRef<V>`, which implement `DerefMut<Target = V>`.
@@ -101,9 +133,30 @@ impl<T: View + 'static> ViewWrapper for NamedView<T> {
         }
     }
 
+    fn wrap_draw(&self, printer: &Printer) {
+        if !printer.is_capturing(&self.name) {
+            self.with_view(|v| v.draw(printer));
+            return;
+        }
+
+        // Draw on a separate buffer first, to keep a copy of the output.
+        let buffer = RwLock::new(PrintBuffer::new());
+        buffer.write().resize(printer.output_size);
+        self.with_view(|v| v.draw(&printer.detached(&buffer)));
+
+        let buffer = buffer.into_inner();
+        printer.print_buffer(&buffer);
+        printer.set_captured(buffer);
+    }
+
     fn wrap_call_on_any(&mut self, selector: &Selector, callback: AnyCb) {
         match selector {
             &Selector::Name(name) if name == self.name => callback(self),
+            &Selector::NameMatches(matches) if matches(&self.name) => {
+                callback(self);
+                // Named views could be nested, so keep looking.
+                self.with_view_mut(|v| v.call_on_any(selector, callback));
+            }
             s => {
                 self.with_view_mut(|v| v.call_on_any(s, callback));
             }
@@ -113,6 +166,9 @@ impl<T: View + 'static> ViewWrapper for NamedView<T> {
     fn wrap_focus_view(&mut self, selector: &Selector) -> Result<EventResult, ViewNotFound> {
         match selector {
             &Selector::Name(name) if name == self.name => Ok(EventResult::Consumed(None)),
+            &Selector::NameMatches(matches) if matches(&self.name) => {
+                Ok(EventResult::Consumed(None))
+            }
             s => self
                 .view
                 .try_lock()
@@ -120,6 +176,20 @@ impl<T: View + 'static> ViewWrapper for NamedView<T> {
                 .and_then(|mut v| v.focus_view(s)),
         }
     }
+
+    fn wrap_reveal_view(&mut self, selector: &Selector, size: Vec2) -> Result<Rect, ViewNotFound> {
+        match selector {
+            &Selector::Name(name) if name == self.name => Ok(Rect::from_size(Vec2::zero(), size)),
+            &Selector::NameMatches(matches) if matches(&self.name) => {
+                Ok(Rect::from_size(Vec2::zero(), size))
+            }
+            s => self
+                .view
+                .try_lock()
+                .ok_or(ViewNotFound)
+                .and_then(|mut v| v.reveal_view(s, size)),
+        }
+    }
 }
 
 #[crate::blueprint(NamedView::new(name, view))]
@@ -0,0 +1,474 @@
+use crate::{
+    direction::Direction,
+    event::{Callback, Event, EventResult, Key, MouseButton, MouseEvent},
+    rect::Rect,
+    view::{CannotFocus, View},
+    views::{EditView, InputMask},
+    Cursive, Printer, Vec2, With,
+};
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::Arc;
+use unicode_width::UnicodeWidthStr;
+
+type NumberCallback<T> = dyn Fn(&mut Cursive, T) + Send + Sync;
+
+// Width of the `[-]` and `[+]` buttons.
+const BUTTON_WIDTH: usize = 3;
+
+/// Input box for a number, with buttons to increment or decrement it.
+///
+/// The value can be typed directly, changed by `step` using the arrow keys
+/// or the mouse wheel, or with the `[-]` and `[+]` buttons.
+///
+/// Typed content that does not parse as a number in the allowed range is
+/// shown as invalid, and the value keeps its last valid state.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::NumberInput;
+///
+/// let volume = NumberInput::new()
+///     .min(0)
+///     .max(100)
+///     .step(5)
+///     .value(50)
+///     .on_change(|_, volume: i32| {
+///         // Adjust the volume...
+/// #       let _ = volume;
+///     });
+/// assert_eq!(volume.get_value(), 50);
+/// ```
+pub struct NumberInput<T> {
+    edit: EditView,
+    value: T,
+    min: Option<T>,
+    max: Option<T>,
+    step: T,
+    precision: Option<usize>,
+    on_change: Option<Arc<NumberCallback<T>>>,
+    last_size: Vec2,
+}
+
+impl<T> Default for NumberInput<T>
+where
+    T: num::Num + PartialOrd + Copy + Display + FromStr + Send + Sync + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> NumberInput<T>
+where
+    T: num::Num + PartialOrd + Copy + Display + FromStr + Send + Sync + 'static,
+{
+    /// Creates a new `NumberInput` with a value of zero and a step of one.
+    pub fn new() -> Self {
+        NumberInput {
+            edit: EditView::new().mask(InputMask::Numeric),
+            value: T::zero(),
+            min: None,
+            max: None,
+            step: T::one(),
+            precision: None,
+            on_change: None,
+            last_size: Vec2::zero(),
+        }
+        .with(|s| {
+            s.update_validator();
+            s.update_content();
+        })
+    }
+
+    /// Sets the current value.
+    ///
+    /// The value is clamped to the allowed range.
+    ///
+    /// Returns an event result with a possible callback,
+    /// if `on_change` was set.
+    pub fn set_value(&mut self, value: T) -> EventResult {
+        self.value = self.clamp(value);
+        self.update_content();
+        self.get_change_result()
+    }
+
+    /// Sets the current value.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn value(self, value: T) -> Self {
+        self.with(|s| {
+            s.set_value(value);
+        })
+    }
+
+    /// Gets the current value.
+    pub fn get_value(&self) -> T {
+        self.value
+    }
+
+    /// Sets the smallest allowed value.
+    ///
+    /// The current value is clamped if needed, without calling `on_change`.
+    pub fn set_min(&mut self, min: T) {
+        self.min = Some(min);
+        self.update_validator();
+        self.value = self.clamp(self.value);
+        self.update_content();
+    }
+
+    /// Sets the smallest allowed value.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn min(self, min: T) -> Self {
+        self.with(|s| s.set_min(min))
+    }
+
+    /// Sets the largest allowed value.
+    ///
+    /// The current value is clamped if needed, without calling `on_change`.
+    pub fn set_max(&mut self, max: T) {
+        self.max = Some(max);
+        self.update_validator();
+        self.value = self.clamp(self.value);
+        self.update_content();
+    }
+
+    /// Sets the largest allowed value.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn max(self, max: T) -> Self {
+        self.with(|s| s.set_max(max))
+    }
+
+    /// Sets by how much the value changes when incremented or decremented.
+    pub fn set_step(&mut self, step: T) {
+        self.step = step;
+    }
+
+    /// Sets by how much the value changes when incremented or decremented.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn step(self, step: T) -> Self {
+        self.with(|s| s.set_step(step))
+    }
+
+    /// Sets the number of decimals shown for floating point values.
+    ///
+    /// Use `None` to show as many decimals as needed. This has no effect on
+    /// integers.
+    pub fn set_precision(&mut self, precision: Option<usize>) {
+        self.precision = precision;
+        self.update_content();
+    }
+
+    /// Sets the number of decimals shown for floating point values.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn precision(self, precision: usize) -> Self {
+        self.with(|s| s.set_precision(Some(precision)))
+    }
+
+    /// Sets a callback to be called when the value changes.
+    #[crate::callback_helpers]
+    pub fn set_on_change<F>(&mut self, callback: F)
+    where
+        F: Fn(&mut Cursive, T) + 'static + Send + Sync,
+    {
+        self.on_change = Some(Arc::new(callback));
+    }
+
+    /// Sets a callback to be called when the value changes.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn on_change<F>(self, callback: F) -> Self
+    where
+        F: Fn(&mut Cursive, T) + 'static + Send + Sync,
+    {
+        self.with(|s| s.set_on_change(callback))
+    }
+
+    /// Increments the value by one step, up to the maximum.
+    ///
+    /// Returns `EventResult::Ignored` if the value did not change.
+    pub fn increment(&mut self) -> EventResult {
+        self.change_to(self.clamp(self.value + self.step))
+    }
+
+    /// Decrements the value by one step, down to the minimum.
+    ///
+    /// Returns `EventResult::Ignored` if the value did not change.
+    pub fn decrement(&mut self) -> EventResult {
+        self.change_to(self.clamp(self.value - self.step))
+    }
+
+    fn change_to(&mut self, value: T) -> EventResult {
+        if value == self.value {
+            return EventResult::Ignored;
+        }
+        self.set_value(value)
+    }
+
+    fn get_change_result(&self) -> EventResult {
+        EventResult::Consumed(self.on_change.clone().map(|cb| {
+            let value = self.value;
+            Callback::from_fn(move |s| {
+                cb(s, value);
+            })
+        }))
+    }
+
+    fn clamp(&self, value: T) -> T {
+        clamp(value, self.min, self.max)
+    }
+
+    fn format(&self, value: T) -> String {
+        match self.precision {
+            Some(precision) => format!("{value:.precision$}"),
+            None => value.to_string(),
+        }
+    }
+
+    // Shows the current value in the edit view.
+    fn update_content(&mut self) {
+        let content = self.format(self.value);
+        // The edit view has no callback set.
+        self.edit.set_content(content);
+    }
+
+    fn update_validator(&mut self) {
+        let (min, max) = (self.min, self.max);
+        self.edit
+            .set_validator(move |text| parse(text, min, max).map(|_: T| ()));
+    }
+
+    // Width needed to show the longest value.
+    fn edit_width(&self) -> usize {
+        let content = self.edit.get_content().width();
+        let values = [Some(self.value), self.min, self.max]
+            .into_iter()
+            .flatten()
+            .map(|value| self.format(value).width());
+
+        // Keep a cell free for the cursor.
+        values.fold(content, usize::max) + 1
+    }
+
+    fn button_at(&self, position: Vec2, offset: Vec2) -> Option<bool> {
+        let size = Vec2::new(BUTTON_WIDTH, 1);
+        let plus = offset + (self.last_size.x.saturating_sub(BUTTON_WIDTH), 0);
+
+        if position.fits_in_rect(offset, size) {
+            Some(false)
+        } else if position.fits_in_rect(plus, size) {
+            Some(true)
+        } else {
+            None
+        }
+    }
+}
+
+// Parses a value, making sure it is in the given range.
+fn parse<T>(text: &str, min: Option<T>, max: Option<T>) -> Result<T, String>
+where
+    T: PartialOrd + Copy + Display + FromStr,
+{
+    let value: T = text
+        .trim()
+        .parse()
+        .map_err(|_| String::from("Not a number"))?;
+
+    match (min, max) {
+        (Some(min), _) if value < min => Err(format!("Must be at least {min}")),
+        (_, Some(max)) if value > max => Err(format!("Must be at most {max}")),
+        _ => Ok(value),
+    }
+}
+
+fn clamp<T: PartialOrd>(value: T, min: Option<T>, max: Option<T>) -> T {
+    match (min, max) {
+        (Some(min), _) if value < min => min,
+        (_, Some(max)) if value > max => max,
+        _ => value,
+    }
+}
+
+impl<T> View for NumberInput<T>
+where
+    T: num::Num + PartialOrd + Copy + Display + FromStr + Send + Sync + 'static,
+{
+    fn draw(&self, printer: &Printer) {
+        let edit_width = self.last_size.x.saturating_sub(2 * BUTTON_WIDTH);
+
+        printer.print((0, 0), "[-]");
+        self.edit.draw(
+            &printer
+                .offset((BUTTON_WIDTH, 0))
+                .cropped((edit_width, printer.size.y)),
+        );
+        printer.print((BUTTON_WIDTH + edit_width, 0), "[+]");
+    }
+
+    fn layout(&mut self, size: Vec2) {
+        self.last_size = size;
+        let edit_width = size.x.saturating_sub(2 * BUTTON_WIDTH);
+        self.edit.layout(Vec2::new(edit_width, 1));
+    }
+
+    fn required_size(&mut self, _: Vec2) -> Vec2 {
+        Vec2::new(self.edit_width() + 2 * BUTTON_WIDTH, 1)
+    }
+
+    fn take_focus(&mut self, source: Direction) -> Result<EventResult, CannotFocus> {
+        self.edit.take_focus(source)
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        match event {
+            Event::Key(Key::Up) => return self.increment(),
+            Event::Key(Key::Down) => return self.decrement(),
+            Event::FocusLost => {
+                // Drop any invalid content.
+                self.update_content();
+                return EventResult::Ignored;
+            }
+            Event::Mouse {
+                event: MouseEvent::Press(MouseButton::Left),
+                position,
+                offset,
+            } => match self.button_at(position, offset) {
+                Some(true) => return self.increment().and(EventResult::consumed()),
+                Some(false) => return self.decrement().and(EventResult::consumed()),
+                None => (),
+            },
+            Event::Mouse {
+                event: MouseEvent::WheelUp,
+                position,
+                offset,
+            } if position.fits_in_rect(offset, self.last_size) => return self.increment(),
+            Event::Mouse {
+                event: MouseEvent::WheelDown,
+                position,
+                offset,
+            } if position.fits_in_rect(offset, self.last_size) => return self.decrement(),
+            _ => (),
+        }
+
+        let result = self.edit.on_event(event.relativized((BUTTON_WIDTH, 0)));
+
+        match parse(&self.edit.get_content(), self.min, self.max) {
+            Ok(value) if value != self.value => {
+                self.value = value;
+                result.and(self.get_change_result())
+            }
+            _ => result,
+        }
+    }
+
+    fn important_area(&self, size: Vec2) -> Rect {
+        self.edit.important_area(size) + Vec2::new(BUTTON_WIDTH, 0)
+    }
+}
+
+crate::manual_blueprint!(NumberInput, |config, context| {
+    // Configs use 64-bit floats for numbers.
+    let mut number_input = NumberInput::<f64>::new();
+
+    if let Some(min) = config.get("min") {
+        number_input.set_min(context.resolve(min)?);
+    }
+
+    if let Some(max) = config.get("max") {
+        number_input.set_max(context.resolve(max)?);
+    }
+
+    if let Some(step) = config.get("step") {
+        number_input.set_step(context.resolve(step)?);
+    }
+
+    if let Some(precision) = config.get("precision") {
+        number_input.set_precision(Some(context.resolve(precision)?));
+    }
+
+    if let Some(value) = config.get("value") {
+        number_input.set_value(context.resolve(value)?);
+    }
+
+    if let Some(on_change) = config.get("on_change") {
+        number_input.set_on_change_cb(context.resolve(on_change)?);
+    }
+
+    Ok(number_input)
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::render_view;
+
+    #[test]
+    fn steps() {
+        let mut input = NumberInput::new().min(0).max(10).step(4).value(5);
+        assert_eq!(input.get_value(), 5);
+
+        assert!(input.on_event(Event::Key(Key::Up)).is_consumed());
+        assert_eq!(input.get_value(), 9);
+        input.on_event(Event::Key(Key::Up));
+        assert_eq!(input.get_value(), 10);
+        // Already at the maximum.
+        assert!(!input.on_event(Event::Key(Key::Up)).is_consumed());
+
+        input.on_event(Event::Key(Key::Down));
+        assert_eq!(input.get_value(), 6);
+        assert_eq!(&*input.edit.get_content(), "6");
+
+        // Out-of-range values are clamped.
+        input.set_value(-3);
+        assert_eq!(input.get_value(), 0);
+    }
+
+    #[test]
+    fn typing() {
+        let mut input = NumberInput::new().max(100);
+
+        input.on_event(Event::Key(Key::Backspace));
+        input.on_event(Event::Char('4'));
+        input.on_event(Event::Char('2'));
+        assert_eq!(input.get_value(), 42);
+
+        // Out of range: the last valid value is kept.
+        input.on_event(Event::Char('0'));
+        assert_eq!(input.get_value(), 42);
+        assert!(!input.edit.is_valid());
+
+        input.on_event(Event::FocusLost);
+        assert_eq!(&*input.edit.get_content(), "42");
+    }
+
+    #[test]
+    fn buttons() {
+        let mut input = NumberInput::new().precision(1).step(0.5).value(1.0);
+        input.layout(Vec2::new(12, 1));
+
+        let click = |x| Event::Mouse {
+            offset: Vec2::zero(),
+            position: Vec2::new(x, 0),
+            event: MouseEvent::Press(MouseButton::Left),
+        };
+        input.on_event(click(10));
+        assert_eq!(input.get_value(), 1.5);
+        input.on_event(click(1));
+        input.on_event(click(1));
+        assert_eq!(input.get_value(), 0.5);
+
+        let screen = render_view(input, (10, 1));
+        assert_eq!(screen.row(0), "[-]0.5_[+]");
+    }
+}
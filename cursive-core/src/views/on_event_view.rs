@@ -1,6 +1,8 @@
-use crate::event::{Callback, Event, EventResult, EventTrigger};
+use crate::event::{Callback, Event, EventResult, EventTrigger, MouseButton, MouseEvent};
+use crate::menu;
 use crate::view::{View, ViewWrapper};
 use crate::Cursive;
+use crate::Vec2;
 use crate::With;
 use std::sync::Arc;
 
@@ -260,6 +262,60 @@ impl<T> OnEventView<T> {
         ));
     }
 
+    /// Shows a context menu when the child view is right-clicked.
+    ///
+    /// When the child ignores a right-click release, `cb` is called with the
+    /// mouse position relative to this view, and returns the menu to show.
+    /// The menu opens at the mouse position (see [`Cursive::show_context_menu`]).
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::menu;
+    /// # use cursive_core::views::{OnEventView, TextView};
+    /// let view = OnEventView::new(TextView::new("Right-click me")).on_context_menu(|_, _| {
+    ///     menu::Tree::new()
+    ///         .leaf("Copy", |_| ())
+    ///         .leaf("Paste", |_| ())
+    /// });
+    /// ```
+    #[must_use]
+    pub fn on_context_menu<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, Vec2) -> menu::Tree + 'static + Send + Sync,
+    {
+        self.with(|s| s.set_on_context_menu(cb))
+    }
+
+    /// Shows a context menu when the child view is right-clicked.
+    ///
+    /// See [`OnEventView::on_context_menu`].
+    pub fn set_on_context_menu<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, Vec2) -> menu::Tree + 'static + Send + Sync,
+    {
+        let cb = Arc::new(cb);
+        self.set_on_event_inner(EventTrigger::mouse(), move |_, event| {
+            let Event::Mouse {
+                event: MouseEvent::Release(MouseButton::Right),
+                position,
+                offset,
+            } = *event
+            else {
+                return None;
+            };
+            let local = position.checked_sub(offset)?;
+
+            let cb = Arc::clone(&cb);
+            Some(EventResult::with_cb(move |s| {
+                let tree = cb(s, local);
+                s.show_context_menu(position, tree);
+            }))
+        });
+    }
+
     /// Remove any callbacks defined for this view.
     pub fn clear_callbacks(&mut self) {
         self.callbacks.clear();
@@ -312,4 +368,116 @@ impl<T: View> ViewWrapper for OnEventView<T> {
     }
 }
 
-// TODO: blueprints?
+// Callbacks from a config, like:
+//
+// ```yaml
+// ctrl-s: $save
+// cancel: $close
+// ```
+//
+// Each key is either an event, or the name of a keymap action.
+#[cfg(feature = "builder")]
+struct Bindings(Vec<(EventTrigger, Callback)>);
+
+#[cfg(feature = "builder")]
+impl Bindings {
+    fn apply<T>(self, mut view: OnEventView<T>) -> OnEventView<T> {
+        for (trigger, cb) in self.0 {
+            view.set_on_event(trigger, move |s| cb(s));
+        }
+        view
+    }
+}
+
+#[cfg(feature = "builder")]
+impl crate::builder::Resolvable for Bindings {
+    fn from_config(
+        config: &crate::builder::Config,
+        context: &crate::builder::Context,
+    ) -> Result<Self, crate::builder::Error> {
+        use crate::builder::Error;
+
+        let object = config
+            .as_object()
+            .ok_or_else(|| Error::invalid_config("Expected object", config))?;
+
+        let mut bindings = Vec::new();
+        for (key, value) in object {
+            let trigger = if let Some(event) = crate::keymap::parse_event(key) {
+                EventTrigger::from(event)
+            } else if !crate::keymap::keymap().get(key).is_empty() {
+                // Follow the keymap, even if it gets remapped later.
+                let action = key.clone();
+                EventTrigger::from_fn_and_tag(
+                    move |event| crate::keymap::matches(&action, event),
+                    key.clone(),
+                )
+            } else {
+                return Err(Error::invalid_config(
+                    format!("Unknown event or action: {key}"),
+                    config,
+                ));
+            };
+            let cb: Arc<dyn Fn(&mut Cursive) + Send + Sync> = context.resolve(value)?;
+            bindings.push((trigger, Callback::from(cb)));
+        }
+
+        Ok(Bindings(bindings))
+    }
+}
+
+crate::manual_blueprint!(OnEventView, |config, context| {
+    let view: crate::views::BoxedView = context.resolve(&config["view"])?;
+    let bindings: Option<Bindings> = context.resolve(&config["events"])?;
+
+    let view = OnEventView::new(view);
+    Ok(match bindings {
+        Some(bindings) => bindings.apply(view),
+        None => view,
+    })
+});
+
+crate::manual_blueprint!(with on_event, |config, context| {
+    let bindings: Bindings = context.resolve(config)?;
+    Ok(move |view| bindings.apply(OnEventView::new(view)))
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::Key;
+    use crate::test::Harness;
+    use crate::views::TextView;
+
+    #[test]
+    fn context_menu() {
+        let mut siv = Cursive::new();
+        siv.add_fullscreen_layer(OnEventView::new(TextView::new("Content")).on_context_menu(
+            |_, position| {
+                menu::Tree::new()
+                    .leaf(format!("At {}x{}", position.x, position.y), |_| ())
+                    .leaf("Paste", |_| ())
+            },
+        ));
+
+        let mut harness = Harness::with_cursive(siv, (30, 10));
+        let right_click = |x: usize, y: usize| Event::Mouse {
+            offset: Vec2::zero(),
+            position: Vec2::new(x, y),
+            event: MouseEvent::Release(MouseButton::Right),
+        };
+
+        harness.feed_event(right_click(2, 1));
+        let screen = harness.screen();
+        assert_eq!(screen.find("At 2x1"), Some(Vec2::new(4, 2)));
+
+        // Near the bottom-right corner, the menu opens up and to the left.
+        harness.feed_event(Event::Key(Key::Esc));
+        harness.feed_event(right_click(28, 9));
+        let screen = harness.screen();
+        let paste = screen.find("Paste").unwrap();
+        assert!(paste.x + 5 <= 28);
+        assert!(paste.y < 9);
+        assert!(screen.contains("At 28x9"));
+    }
+}
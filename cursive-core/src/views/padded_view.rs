@@ -1,6 +1,6 @@
 use crate::event::{Event, EventResult};
 use crate::rect::Rect;
-use crate::view::{Margins, View, ViewWrapper};
+use crate::view::{Margins, Selector, View, ViewNotFound, ViewWrapper};
 use crate::Printer;
 use crate::Vec2;
 
@@ -73,6 +73,24 @@ impl<V: View> ViewWrapper for PaddedView<V> {
         let inner_size = view_size.saturating_sub(self.margins.combined());
         self.view.important_area(inner_size) + self.margins.top_left()
     }
+
+    fn wrap_dirty_area(&self, view_size: Vec2) -> Option<Rect> {
+        let inner_size = view_size.saturating_sub(self.margins.combined());
+        self.view
+            .dirty_area(inner_size)
+            .map(|area| area + self.margins.top_left())
+    }
+
+    fn wrap_reveal_view(
+        &mut self,
+        selector: &Selector,
+        view_size: Vec2,
+    ) -> Result<Rect, ViewNotFound> {
+        let inner_size = view_size.saturating_sub(self.margins.combined());
+        self.view
+            .reveal_view(selector, inner_size)
+            .map(|area| area + self.margins.top_left())
+    }
 }
 
 #[crate::blueprint(PaddedView::new(margins, view))]
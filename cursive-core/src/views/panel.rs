@@ -1,9 +1,10 @@
 use crate::align::*;
+use crate::density::{self, Density};
 use crate::event::{Event, EventResult};
 use crate::rect::Rect;
 use crate::style::PaletteStyle;
 use crate::utils::markup::StyledString;
-use crate::view::{View, ViewWrapper};
+use crate::view::{Selector, View, ViewNotFound, ViewWrapper};
 use crate::Printer;
 use crate::Vec2;
 use crate::With;
@@ -22,6 +23,9 @@ pub struct Panel<V> {
 
     // `true` when we needs to relayout
     invalidated: bool,
+
+    // Density used in the last layout.
+    density: Density,
 }
 
 new_default!(Panel<V: Default>);
@@ -37,6 +41,7 @@ impl<V> Panel<V> {
             title: StyledString::new(),
             title_position: HAlign::Center,
             invalidated: true,
+            density: density::density(),
         }
     }
 
@@ -89,6 +94,53 @@ impl<V> Panel<V> {
         }
     }
 
+    // Connects separator lines drawn by the content to the border.
+    fn draw_junctions(&self, printer: &Printer) {
+        if printer.size.x < 3 || printer.size.y < 3 {
+            return;
+        }
+
+        let last = printer.size - (1, 1);
+        let joins = |content: Vec2, border: Vec2, line: &str, edge: &str| {
+            printer.is_printed_at(content, line) && printer.is_printed_at(border, edge)
+        };
+
+        printer.with_high_border(true, |printer| {
+            for x in 1..last.x {
+                if joins(Vec2::new(x, 1), Vec2::new(x, 0), "│", "─") {
+                    printer.print((x, 0), "┬");
+                }
+            }
+            for y in 1..last.y {
+                if joins(Vec2::new(1, y), Vec2::new(0, y), "─", "│") {
+                    printer.print((0, y), "├");
+                }
+            }
+        });
+        printer.with_low_border(true, |printer| {
+            for x in 1..last.x {
+                if joins(Vec2::new(x, last.y - 1), Vec2::new(x, last.y), "│", "─") {
+                    printer.print((x, last.y), "┴");
+                }
+            }
+            for y in 1..last.y {
+                if joins(Vec2::new(last.x - 1, y), Vec2::new(last.x, y), "─", "│") {
+                    printer.print((last.x, y), "┤");
+                }
+            }
+        });
+    }
+
+    // Offset of the content: the border, and the padding for the given density.
+    fn offset_for(density: Density) -> Vec2 {
+        Vec2::new(1 + density.spacing(0), 1)
+    }
+
+    // Offset of the content, as of the last layout.
+    fn inner_offset(&self) -> Vec2 {
+        Self::offset_for(self.density)
+    }
+
     fn invalidate(&mut self) {
         self.invalidated = true;
     }
@@ -100,14 +152,16 @@ impl<V: View> ViewWrapper for Panel<V> {
     wrap_impl!(self.view: V);
 
     fn wrap_on_event(&mut self, event: Event) -> EventResult {
-        self.view.on_event(event.relativized((1, 1)))
+        self.view.on_event(event.relativized(self.inner_offset()))
     }
 
     fn wrap_required_size(&mut self, req: Vec2) -> Vec2 {
         // TODO: make borders conditional?
-        let req = req.saturating_sub((2, 2));
+        // The next layout will use the current density.
+        let overhead = Self::offset_for(density::density()) * 2;
+        let req = req.saturating_sub(overhead);
 
-        let size = self.view.required_size(req) + (2, 2);
+        let size = self.view.required_size(req) + overhead;
         if self.title.is_empty() {
             size
         } else {
@@ -120,21 +174,43 @@ impl<V: View> ViewWrapper for Panel<V> {
         printer.print_box((0, 0), printer.size, true);
         self.draw_title(printer);
 
-        let printer = printer.offset((1, 1)).shrinked((1, 1));
-        self.view.draw(&printer);
+        let offset = self.inner_offset();
+        self.view.draw(&printer.offset(offset).shrinked(offset));
+        self.draw_junctions(printer);
     }
 
     fn wrap_layout(&mut self, size: Vec2) {
-        self.view.layout(size.saturating_sub((2, 2)));
+        self.density = density::density();
+        self.view
+            .layout(size.saturating_sub(self.inner_offset() * 2));
     }
 
     fn wrap_important_area(&self, size: Vec2) -> Rect {
-        let inner_size = size.saturating_sub((2, 2));
-        self.view.important_area(inner_size) + (1, 1)
+        let offset = self.inner_offset();
+        let inner_size = size.saturating_sub(offset * 2);
+        self.view.important_area(inner_size) + offset
     }
 
     fn wrap_needs_relayout(&self) -> bool {
-        self.invalidated || self.view.needs_relayout()
+        self.invalidated || self.density != density::density() || self.view.needs_relayout()
+    }
+
+    fn wrap_dirty_area(&self, size: Vec2) -> Option<Rect> {
+        if self.invalidated {
+            return Some(Rect::from_size(Vec2::zero(), size));
+        }
+
+        let offset = self.inner_offset();
+        self.view
+            .dirty_area(size.saturating_sub(offset * 2))
+            .map(|area| area + offset)
+    }
+
+    fn wrap_reveal_view(&mut self, selector: &Selector, size: Vec2) -> Result<Rect, ViewNotFound> {
+        let offset = self.inner_offset();
+        self.view
+            .reveal_view(selector, size.saturating_sub(offset * 2))
+            .map(|area| area + offset)
     }
 }
 
@@ -171,3 +247,25 @@ crate::manual_blueprint!(with panel, |config, context| {
         panel
     })
 });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view::{Resizable, View};
+    use crate::views::DummyView;
+
+    #[test]
+    fn density_from_layout() {
+        let mut panel = Panel::new(DummyView.fixed_size((4, 1)));
+        let size = Vec2::new(8, 3);
+
+        density::with_density(Density::Comfortable, || panel.layout(size));
+
+        // Until the next layout, the content stays where it was laid out.
+        assert_eq!(panel.important_area(size).top_left(), Vec2::new(2, 1));
+        assert!(panel.needs_relayout());
+
+        panel.layout(size);
+        assert_eq!(panel.important_area(size).top_left(), Vec2::new(1, 1));
+    }
+}
@@ -1,10 +1,13 @@
 use crate::align::HAlign;
-use crate::style::{ColorStyle, ColorType, Effect, PaletteColor};
+use crate::style::{gradient::Linear, ColorStyle, ColorType, Effect, PaletteColor};
 use crate::utils::Counter;
 use crate::view::View;
 use crate::{Printer, With};
 use std::cmp;
 use std::thread;
+use std::time::{Duration, Instant};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 // pub type CbPromise = Option<Box<Fn(&mut Cursive) + Send>>;
 
@@ -20,6 +23,9 @@ use std::thread;
 /// The bar defaults to the current theme's highlight color,
 /// but that can be customized.
 ///
+/// When the total is unknown, an indeterminate bar shows a block moving back
+/// and forth instead (see [`ProgressBar::set_indeterminate`]).
+///
 /// # Example
 ///
 /// ```
@@ -38,16 +44,68 @@ pub struct ProgressBar {
     max: usize,
     value: Counter,
     color: ColorType,
+    // If set, used instead of `color` for the filled part.
+    gradient: Option<Linear>,
+    glyphs: ProgressGlyphs,
+    // If set, a moving block is shown instead of the value, since this time.
+    indeterminate: Option<Instant>,
     // TODO: use a Promise instead?
     label_maker: Box<dyn Fn(usize, (usize, usize)) -> String + Send + Sync>,
 }
 
+/// Characters used to draw the filled part of a [`ProgressBar`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ProgressGlyphs {
+    /// Colored cells, with eighth blocks (`▏`, `▎`, ...) for the last one.
+    #[default]
+    Blocks,
+
+    /// Braille patterns, filling each cell in two steps (`⡇`, then `⣿`).
+    Braille,
+
+    /// Custom glyphs, from the emptiest partial cell to the full cell.
+    ///
+    /// For example, `["░", "▒", "▓", "█"]` fills each cell in 4 steps.
+    Custom(Vec<String>),
+}
+
+impl ProgressGlyphs {
+    // Number of steps to fill a cell.
+    fn resolution(&self) -> usize {
+        match self {
+            ProgressGlyphs::Blocks => 8,
+            ProgressGlyphs::Braille => 2,
+            ProgressGlyphs::Custom(glyphs) => cmp::max(glyphs.len(), 1),
+        }
+    }
+
+    // Glyph for a cell filled up to `step`, from 1 to `resolution()`.
+    fn glyph(&self, step: usize) -> &str {
+        match self {
+            ProgressGlyphs::Blocks => sub_block(step),
+            ProgressGlyphs::Braille => ["⡇", "⣿"][step - 1],
+            ProgressGlyphs::Custom(glyphs) => glyphs.get(step - 1).map_or("█", String::as_str),
+        }
+    }
+}
+
+impl std::str::FromStr for ProgressGlyphs {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Blocks" | "blocks" => Self::Blocks,
+            "Braille" | "braille" => Self::Braille,
+            _ => return Err(()),
+        })
+    }
+}
+
 fn make_percentage(value: usize, (min, max): (usize, usize)) -> String {
     if value < min {
         return String::from("0 %");
     }
 
-    let (percentage, extra) = ratio(value - min, max - min, 100);
+    let (percentage, extra) = ratio(value - min, max - min, 100, 8);
     let percentage = if extra > 4 {
         percentage + 1
     } else {
@@ -63,12 +121,12 @@ fn make_percentage(value: usize, (min, max): (usize, usize)) -> String {
 ///
 /// Returns a tuple with:
 /// * The integer part of the division
-/// * A value between 0 and 8 (exclusive) corresponding to the remainder.
-fn ratio(value: usize, max: usize, length: usize) -> (usize, usize) {
+/// * A value between 0 and `steps` (exclusive) corresponding to the remainder.
+fn ratio(value: usize, max: usize, length: usize, steps: usize) -> (usize, usize) {
     let integer = length * value / max;
     let fraction = length * value - max * integer;
 
-    let fraction = fraction * 8 / max;
+    let fraction = fraction * steps / max;
 
     (integer, fraction)
 }
@@ -89,10 +147,16 @@ impl ProgressBar {
             max: 100,
             value: Counter::new(0),
             color: PaletteColor::Highlight.into(),
+            gradient: None,
+            glyphs: ProgressGlyphs::Blocks,
+            indeterminate: None,
             label_maker: Box::new(make_percentage),
         }
     }
 
+    /// Time for the indeterminate block to move by one cell.
+    const BLOCK_INTERVAL: Duration = Duration::from_millis(50);
+
     /// Sets the value to follow.
     ///
     /// Use this to manually control the progress to display
@@ -149,6 +213,18 @@ impl ProgressBar {
     ///
     /// The given function will be called with `(value, (min, max))`.
     /// Its output will be used as the label to print inside the progress bar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cursive_core::views::ProgressBar;
+    /// // Counts bytes, shown as "3.2 MB / 10.0 MB".
+    /// let mut bar = ProgressBar::new().max(10_000_000);
+    /// bar.set_label(|value, (_, max)| {
+    ///     let mb = |bytes| bytes as f64 / 1_000_000.0;
+    ///     format!("{:.1} MB / {:.1} MB", mb(value), mb(max))
+    /// });
+    /// ```
     #[crate::callback_helpers]
     pub fn set_label<F: Fn(usize, (usize, usize)) -> String + 'static + Send + Sync>(
         &mut self,
@@ -259,6 +335,108 @@ impl ProgressBar {
     {
         self.with(|s| s.set_color(color))
     }
+
+    /// Sets a gradient to color the filled part of the bar.
+    ///
+    /// The gradient goes across the entire width of the bar, so the color of
+    /// a cell does not change as the bar fills up. It replaces the color set
+    /// with `set_color`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cursive_core::views::ProgressBar;
+    /// use cursive_core::style::{gradient::Linear, Rgb};
+    ///
+    /// let bar = ProgressBar::new().with_gradient(Linear::simple(Rgb::red(), Rgb::green()));
+    /// ```
+    pub fn set_gradient(&mut self, gradient: Linear) {
+        self.gradient = Some(gradient);
+    }
+
+    /// Sets a gradient to color the filled part of the bar.
+    ///
+    /// Chainable variant of `set_gradient`.
+    #[must_use]
+    pub fn with_gradient(self, gradient: Linear) -> Self {
+        self.with(|s| s.set_gradient(gradient))
+    }
+
+    /// Removes the gradient, using the color style again.
+    pub fn clear_gradient(&mut self) {
+        self.gradient = None;
+    }
+
+    /// Sets the characters used to draw the filled part of the bar.
+    ///
+    /// Defaults to `ProgressGlyphs::Blocks`.
+    pub fn set_glyphs(&mut self, glyphs: ProgressGlyphs) {
+        self.glyphs = glyphs;
+    }
+
+    /// Sets the characters used to draw the filled part of the bar.
+    ///
+    /// Chainable variant of `set_glyphs`.
+    #[must_use]
+    pub fn with_glyphs(self, glyphs: ProgressGlyphs) -> Self {
+        self.with(|s| s.set_glyphs(glyphs))
+    }
+
+    /// Makes the bar indeterminate, for when the total is unknown.
+    ///
+    /// An indeterminate bar ignores its value and label, and shows a block
+    /// moving back and forth. It only moves when the screen is redrawn, so
+    /// it needs an auto-refresh (see `Cursive::set_fps`).
+    pub fn set_indeterminate(&mut self, indeterminate: bool) {
+        self.indeterminate = indeterminate.then(Instant::now);
+    }
+
+    /// Makes the bar indeterminate, for when the total is unknown.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn indeterminate(self) -> Self {
+        self.with(|s| s.set_indeterminate(true))
+    }
+
+    /// Returns `true` if this bar is indeterminate.
+    pub fn is_indeterminate(&self) -> bool {
+        self.indeterminate.is_some()
+    }
+
+    // Draws the moving block of an indeterminate bar.
+    fn draw_indeterminate(&self, printer: &Printer, started: Instant) {
+        let available = printer.size.x;
+        let width = cmp::max(available / 5, 1).min(available);
+
+        // Bounce between both ends.
+        let travel = available - width;
+        let step = started.elapsed().as_millis() / Self::BLOCK_INTERVAL.as_millis();
+        let step = step as usize % cmp::max(2 * travel, 1);
+        let start = if step <= travel {
+            step
+        } else {
+            2 * travel - step
+        };
+
+        for x in start..start + width {
+            printer.with_color(
+                ColorStyle::new(PaletteColor::HighlightText, self.color_at(x, available)),
+                |printer| printer.print((x, 0), " "),
+            );
+        }
+    }
+
+    // Color of the filled cell at `x`.
+    fn color_at(&self, x: usize, width: usize) -> ColorType {
+        match self.gradient {
+            Some(ref gradient) => {
+                let position = x as f32 / cmp::max(width, 2).saturating_sub(1) as f32;
+                gradient.interpolate(position).as_color().into()
+            }
+            None => self.color,
+        }
+    }
 }
 
 fn sub_block(extra: usize) -> &'static str {
@@ -277,6 +455,11 @@ fn sub_block(extra: usize) -> &'static str {
 
 impl View for ProgressBar {
     fn draw(&self, printer: &Printer) {
+        if let Some(started) = self.indeterminate {
+            self.draw_indeterminate(printer, started);
+            return;
+        }
+
         // Now, the bar itself...
         let available = printer.size.x;
 
@@ -288,27 +471,59 @@ impl View for ProgressBar {
         let (length, extra) = if value < self.min {
             (0, 0)
         } else {
-            ratio(value - self.min, self.max - self.min, available)
+            ratio(
+                value - self.min,
+                self.max - self.min,
+                available,
+                self.glyphs.resolution(),
+            )
         };
 
         let label = (self.label_maker)(value, (self.min, self.max));
-        let offset = HAlign::Center.get_offset(label.len(), printer.size.x);
+        let offset = HAlign::Center.get_offset(label.width(), printer.size.x);
 
-        let color_style = ColorStyle::new(PaletteColor::HighlightText, self.color);
+        let color_style =
+            |x| ColorStyle::new(PaletteColor::HighlightText, self.color_at(x, available));
 
-        printer.with_color(color_style, |printer| {
+        printer.with_color(color_style(length), |printer| {
             // TODO: Instead, write it with self.color and inherit_parent background?
             // Draw the right half of the label in reverse
             printer.with_effect(Effect::Reverse, |printer| {
-                printer.print((length, 0), sub_block(extra));
+                if self.glyphs == ProgressGlyphs::Blocks {
+                    printer.print((length, 0), sub_block(extra));
+                }
                 printer.print((offset, 0), &label);
             });
-            let printer = &printer.cropped((length, 1));
-            printer.print_hline((0, 0), length, " ");
-
-            // Draw the left part in color_style (it may be cropped)
-            printer.print((offset, 0), &label);
         });
+
+        // Draw the filled cells
+        for x in 0..length {
+            if self.glyphs == ProgressGlyphs::Blocks {
+                printer.with_color(color_style(x), |printer| printer.print((x, 0), " "));
+            } else {
+                let glyph = self.glyphs.glyph(self.glyphs.resolution());
+                printer.with_color(ColorStyle::front(self.color_at(x, available)), |printer| {
+                    printer.print((x, 0), glyph)
+                });
+            }
+        }
+        if extra > 0 && self.glyphs != ProgressGlyphs::Blocks {
+            let glyph = self.glyphs.glyph(extra);
+            printer.with_color(
+                ColorStyle::front(self.color_at(length, available)),
+                |printer| printer.print((length, 0), glyph),
+            );
+        }
+
+        // Draw the left part of the label in color_style
+        let mut x = offset;
+        for grapheme in label.graphemes(true) {
+            if x + grapheme.width() > length {
+                break;
+            }
+            printer.with_color(color_style(x), |printer| printer.print((x, 0), grapheme));
+            x += grapheme.width();
+        }
     }
 }
 
@@ -318,5 +533,47 @@ struct Blueprint {
     max: Option<usize>,
     value: Option<usize>,
     color: Option<ColorType>,
+    gradient: Option<Linear>,
+    glyphs: Option<ProgressGlyphs>,
+    indeterminate: Option<bool>,
     label: Option<_>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view::Resizable;
+
+    #[test]
+    fn fill_steps() {
+        // 5/8 of 10 cells: 6 full cells, and a quarter of the next one.
+        assert_eq!(ratio(5, 8, 10, 8), (6, 2));
+        assert_eq!(ratio(5, 8, 10, 2), (6, 0));
+        assert_eq!(ratio(8, 8, 10, 2), (10, 0));
+
+        assert_eq!(ProgressGlyphs::Blocks.glyph(2), "▎");
+        assert_eq!(ProgressGlyphs::Braille.glyph(1), "⡇");
+        let custom = ProgressGlyphs::Custom(vec!["░".into(), "▓".into()]);
+        assert_eq!(custom.resolution(), 2);
+        assert_eq!(custom.glyph(2), "▓");
+        assert_eq!(ProgressGlyphs::Custom(Vec::new()).glyph(1), "█");
+    }
+
+    #[test]
+    fn indeterminate() {
+        let bar = ProgressBar::new().indeterminate();
+        assert!(bar.is_indeterminate());
+
+        let mut harness = crate::test::Harness::new((20, 1));
+        harness.cursive().add_fullscreen_layer(bar.full_width());
+        let highlight = harness.cursive().current_theme().palette[PaletteColor::Highlight];
+        let screen = harness.screen();
+
+        // No label, and a block of a fifth of the width.
+        assert!(!screen.contains("%"));
+        let filled = (0..20)
+            .filter(|&x| screen.style_at((x, 0)).unwrap().color.back == highlight)
+            .count();
+        assert_eq!(filled, 4);
+    }
+}
@@ -35,6 +35,19 @@ impl<T> SharedState<T> {
     }
 }
 
+impl<T: 'static + Send + Sync> SharedState<T> {
+    fn select(&mut self, id: usize) -> EventResult {
+        self.selection = id;
+        if let Some(ref on_change) = self.on_change {
+            let on_change = Arc::clone(on_change);
+            let value = self.selection();
+            EventResult::with_cb(move |s| on_change(s, &value))
+        } else {
+            EventResult::Consumed(None)
+        }
+    }
+}
+
 /// Group to coordinate multiple radio buttons.
 ///
 /// A `RadioGroup` is used to create and manage [`RadioButton`]s.
@@ -125,6 +138,19 @@ impl<T: 'static + Send + Sync> RadioGroup<T> {
         self.state.lock().unwrap().selection()
     }
 
+    /// Selects the button with the given id.
+    ///
+    /// Returns a callback in response to the selection change, or
+    /// `EventResult::Ignored` if there is no such button.
+    pub fn set_selection(&mut self, id: usize) -> EventResult {
+        let mut state = self.state.lock().unwrap();
+        if id < state.values.len() {
+            state.select(id)
+        } else {
+            EventResult::Ignored
+        }
+    }
+
     /// Sets a callback to be used when the selection changes.
     pub fn set_on_change<F: 'static + Fn(&mut Cursive, &T) + Send + Sync>(&mut self, on_change: F) {
         self.state.lock().unwrap().on_change = Some(Arc::new(on_change));
@@ -199,15 +225,7 @@ impl<T: 'static + Send + Sync> RadioButton<T> {
 
     /// Selects this button, un-selecting any other in the same group.
     pub fn select(&mut self) -> EventResult {
-        let mut state = self.state.lock().unwrap();
-        state.selection = self.id;
-        if let Some(ref on_change) = state.on_change {
-            let on_change = Arc::clone(on_change);
-            let value = state.selection();
-            EventResult::with_cb(move |s| on_change(s, &value))
-        } else {
-            EventResult::Consumed(None)
-        }
+        self.state.lock().unwrap().select(self.id)
     }
 
     /// Selects this button, un-selecting any other in the same group.
@@ -269,8 +287,12 @@ impl<T: 'static + Send + Sync> View for RadioButton<T> {
     }
 
     fn draw(&self, printer: &Printer) {
-        if self.enabled && printer.enabled {
-            printer.with_selection(printer.focused, |printer| self.draw_internal(printer));
+        if self.enabled && printer.enabled && printer.focused {
+            printer.with_style(printer.theme.focus.toggle, |printer| {
+                self.draw_internal(printer)
+            });
+        } else if self.enabled && printer.enabled {
+            self.draw_internal(printer);
         } else {
             printer.with_style(PaletteStyle::Secondary, |printer| {
                 self.draw_internal(printer)
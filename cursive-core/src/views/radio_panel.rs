@@ -0,0 +1,327 @@
+use crate::{
+    direction::Orientation,
+    event::EventResult,
+    utils::markup::StyledString,
+    view::{Margins, ViewWrapper},
+    views::{LinearLayout, PaddedView, Panel, RadioButton, RadioGroup},
+    Cursive, With,
+};
+use std::sync::Arc;
+
+// Space between two buttons in a horizontal panel.
+const BUTTON_SPACING: usize = 2;
+
+/// A titled panel of radio buttons.
+///
+/// The buttons are created from the items added, and share an internal
+/// [`RadioGroup`].
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::RadioPanel;
+///
+/// let size = RadioPanel::new()
+///     .title("Size")
+///     .item("Small", 1)
+///     .item("Medium", 2)
+///     .item("Large", 3)
+///     .selected(1)
+///     .on_change(|_, size| println!("Picked size {size}"));
+///
+/// assert_eq!(size.selection().as_deref(), Some(&2));
+/// ```
+pub struct RadioPanel<T = String> {
+    group: RadioGroup<T>,
+    panel: Panel<LinearLayout>,
+    orientation: Orientation,
+    len: usize,
+}
+
+impl<T: 'static + Send + Sync> Default for RadioPanel<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: 'static + Send + Sync> RadioPanel<T> {
+    /// Creates a new empty panel, with buttons arranged vertically.
+    pub fn new() -> Self {
+        RadioPanel {
+            group: RadioGroup::new(),
+            panel: Panel::new(LinearLayout::vertical()),
+            orientation: Orientation::Vertical,
+            len: 0,
+        }
+    }
+
+    /// Sets the title of the panel.
+    pub fn set_title<S: Into<StyledString>>(&mut self, label: S) {
+        self.panel.set_title(label);
+    }
+
+    /// Sets the title of the panel.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn title<S: Into<StyledString>>(self, label: S) -> Self {
+        self.with(|s| s.set_title(label))
+    }
+
+    /// Sets how the buttons are arranged.
+    ///
+    /// Defaults to `Orientation::Vertical`.
+    pub fn set_orientation(&mut self, orientation: Orientation) {
+        if self.orientation == orientation {
+            return;
+        }
+        self.orientation = orientation;
+
+        let layout = self.panel.get_inner_mut();
+        let mut new_layout = LinearLayout::new(orientation);
+        while let Some(button) = layout.remove_child(0) {
+            new_layout.add_child(button);
+        }
+        *layout = new_layout;
+
+        self.update_margins();
+    }
+
+    /// Sets how the buttons are arranged.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn orientation(self, orientation: Orientation) -> Self {
+        self.with(|s| s.set_orientation(orientation))
+    }
+
+    /// Adds a button with the given label and value.
+    ///
+    /// The first button added is selected.
+    pub fn add_item<S: Into<StyledString>>(&mut self, label: S, value: T) {
+        let button = self.group.button(value, label);
+        self.panel
+            .get_inner_mut()
+            .add_child(PaddedView::new(Margins::zeroes(), button));
+        self.len += 1;
+
+        self.update_margins();
+    }
+
+    /// Adds a button with the given label and value.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn item<S: Into<StyledString>>(self, label: S, value: T) -> Self {
+        self.with(|s| s.add_item(label, value))
+    }
+
+    /// Adds a button for each item from an iterator.
+    pub fn add_all<S, I>(&mut self, iter: I)
+    where
+        S: Into<StyledString>,
+        I: IntoIterator<Item = (S, T)>,
+    {
+        for (label, value) in iter {
+            self.add_item(label, value);
+        }
+    }
+
+    /// Adds a button for each item from an iterator.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn with_all<S, I>(self, iter: I) -> Self
+    where
+        S: Into<StyledString>,
+        I: IntoIterator<Item = (S, T)>,
+    {
+        self.with(|s| s.add_all(iter))
+    }
+
+    /// Returns the number of buttons in this panel.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if this panel has no button.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the index of the selected button, or `None` if the panel is empty.
+    pub fn selected_id(&self) -> Option<usize> {
+        (!self.is_empty()).then(|| self.group.selected_id())
+    }
+
+    /// Returns the value of the selected button, or `None` if the panel is empty.
+    pub fn selection(&self) -> Option<Arc<T>> {
+        (!self.is_empty()).then(|| self.group.selection())
+    }
+
+    /// Selects the button at the given index.
+    ///
+    /// Returns a callback in response to the selection change, or
+    /// `EventResult::Ignored` if there is no such button.
+    pub fn set_selection(&mut self, i: usize) -> EventResult {
+        self.group.set_selection(i)
+    }
+
+    /// Selects the button at the given index.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn selected(self, i: usize) -> Self {
+        self.with(|s| {
+            // Ignore the potential callback here
+            s.set_selection(i);
+        })
+    }
+
+    /// Sets a callback to be used when the selection changes.
+    #[crate::callback_helpers]
+    pub fn set_on_change<F>(&mut self, on_change: F)
+    where
+        F: Fn(&mut Cursive, &T) + 'static + Send + Sync,
+    {
+        self.group.set_on_change(on_change);
+    }
+
+    /// Sets a callback to be used when the selection changes.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn on_change<F>(self, on_change: F) -> Self
+    where
+        F: Fn(&mut Cursive, &T) + 'static + Send + Sync,
+    {
+        self.with(|s| s.set_on_change(on_change))
+    }
+
+    /// Returns the group of the buttons.
+    ///
+    /// It can be cloned to read the selection from a callback.
+    pub fn group(&self) -> &RadioGroup<T> {
+        &self.group
+    }
+
+    // Leaves some space between buttons in a horizontal panel.
+    fn update_margins(&mut self) {
+        let horizontal = self.orientation == Orientation::Horizontal;
+        let layout = self.panel.get_inner_mut();
+        let last = layout.len().saturating_sub(1);
+
+        for i in 0..layout.len() {
+            let right = if horizontal && i < last {
+                BUTTON_SPACING
+            } else {
+                0
+            };
+
+            if let Some(button) = layout
+                .get_child_mut(i)
+                .and_then(|child| child.downcast_mut::<PaddedView<RadioButton<T>>>())
+            {
+                button.set_margins(Margins::lr(0, right));
+            }
+        }
+    }
+}
+
+impl RadioPanel<String> {
+    /// Adds a button, using the label itself as value.
+    pub fn add_item_str<S: Into<String>>(&mut self, label: S) {
+        let label = label.into();
+        self.add_item(label.clone(), label);
+    }
+
+    /// Adds a button, using the label itself as value.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn item_str<S: Into<String>>(self, label: S) -> Self {
+        self.with(|s| s.add_item_str(label))
+    }
+
+    /// Adds a button for each label from an iterator, using the label itself as value.
+    pub fn add_all_str<S, I>(&mut self, iter: I)
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = S>,
+    {
+        for label in iter {
+            self.add_item_str(label);
+        }
+    }
+
+    /// Adds a button for each label from an iterator, using the label itself as value.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn with_all_str<S, I>(self, iter: I) -> Self
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = S>,
+    {
+        self.with(|s| s.add_all_str(iter))
+    }
+}
+
+impl<T: 'static + Send + Sync> ViewWrapper for RadioPanel<T> {
+    wrap_impl!(self.panel: Panel<LinearLayout>);
+}
+
+#[crate::blueprint(RadioPanel::<String>::new())]
+struct Blueprint {
+    title: Option<StyledString>,
+    orientation: Option<Orientation>,
+
+    on_change: Option<_>,
+
+    #[blueprint(foreach = add_item_str)]
+    items: Vec<String>,
+
+    selection: Option<usize>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{Event, Key};
+    use crate::view::View;
+    use crate::Vec2;
+
+    #[test]
+    fn radio_panel() {
+        let mut panel = RadioPanel::new()
+            .title("Size")
+            .with_all_str(["Small", "Medium", "Large"]);
+        assert_eq!(
+            panel.selection().as_deref().map(String::as_str),
+            Some("Small")
+        );
+
+        // Each button on its own line, inside the borders.
+        assert_eq!(panel.required_size(Vec2::new(80, 24)), Vec2::new(12, 5));
+        panel.layout(Vec2::new(12, 5));
+        panel.take_focus(crate::direction::Direction::none()).ok();
+
+        panel.on_event(Event::Key(Key::Down));
+        assert!(panel.on_event(Event::Char(' ')).is_consumed());
+        assert_eq!(panel.selected_id(), Some(1));
+
+        assert!(!panel.set_selection(3).is_consumed());
+        panel.set_selection(2);
+        assert_eq!(
+            panel.selection().as_deref().map(String::as_str),
+            Some("Large")
+        );
+
+        // Buttons are spaced when side by side.
+        panel.set_orientation(Orientation::Horizontal);
+        assert_eq!(panel.required_size(Vec2::new(80, 24)), Vec2::new(34, 3));
+        assert_eq!(panel.selected_id(), Some(2));
+
+        assert_eq!(RadioPanel::<String>::new().selection(), None);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::printer::Printer;
+use crate::rect::Rect;
 use crate::view::{SizeConstraint, View, ViewWrapper};
 use crate::Vec2;
 use crate::XY;
@@ -221,6 +222,14 @@ impl<T: View> ViewWrapper for ResizedView<T> {
     fn wrap_needs_relayout(&self) -> bool {
         self.invalidated || self.view.needs_relayout()
     }
+
+    fn wrap_dirty_area(&self, size: Vec2) -> Option<Rect> {
+        if self.invalidated {
+            Some(Rect::from_size(Vec2::zero(), size))
+        } else {
+            self.view.dirty_area(size)
+        }
+    }
 }
 
 #[cfg(test)]
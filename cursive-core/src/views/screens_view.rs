@@ -1,16 +1,80 @@
 use crate::{
-    event::{AnyCb, EventResult},
+    event::{AnyCb, Event, EventResult},
+    rect::Rect,
+    style::PaletteStyle,
     view::{Selector, View, ViewNotFound},
     views::BoxedView,
+    Cursive, Printer, Vec2, With,
 };
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 /// Identifies a screen in the cursive root.
 pub type ScreenId = usize;
 
+type ScreenChangeCallback = dyn Fn(&mut Cursive, ScreenId, ScreenId) + Send + Sync;
+
+// How often the screen is refreshed while a transition is running.
+const TRANSITION_FRAME_MS: u64 = 16;
+
+/// Animation played when switching screens.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ScreenTransition {
+    /// Immediately show the new screen.
+    None,
+
+    /// Slide the new screen in, over the given duration.
+    ///
+    /// The new screen comes from the right when moving forward (to a higher
+    /// screen ID), and from the left when moving backward.
+    Slide(Duration),
+}
+
+// A transition currently running.
+struct Transition {
+    from: ScreenId,
+    start: Instant,
+    duration: Duration,
+
+    // `true` if the new screen comes from the right.
+    forward: bool,
+
+    // Set once the final frame was drawn.
+    done: AtomicBool,
+}
+
+impl Transition {
+    // Returns how many columns are covered by the new screen.
+    fn shift(&self, width: usize) -> usize {
+        let elapsed = self.start.elapsed();
+        if elapsed >= self.duration {
+            self.done.store(true, Ordering::Relaxed);
+            return width;
+        }
+
+        (width as u128 * elapsed.as_millis() / self.duration.as_millis().max(1)) as usize
+    }
+}
+
 /// A view that can switch between different screens.
+///
+/// Only the active screen is visible and receives events.
+///
+/// Screens can be cycled through with [`ScreensView::next_screen`] and
+/// [`ScreensView::previous_screen`], or with keys set by
+/// [`ScreensView::set_switch_keys`].
 pub struct ScreensView<V = BoxedView> {
     screens: Vec<V>,
     active_screen: ScreenId,
+
+    transition_style: ScreenTransition,
+    transition: Option<Transition>,
+
+    // Keys to go to the next and previous screens.
+    switch_keys: Option<(Event, Event)>,
+
+    on_screen_change: Option<Arc<ScreenChangeCallback>>,
 }
 
 new_default!(ScreensView<V>);
@@ -21,15 +85,18 @@ impl<V> ScreensView<V> {
         ScreensView {
             screens: Vec::new(),
             active_screen: 0,
+            transition_style: ScreenTransition::None,
+            transition: None,
+            switch_keys: None,
+            on_screen_change: None,
         }
     }
 
     /// Creates a new `ScreensView` with a single screen.
     pub fn single_screen(v: V) -> Self {
-        ScreensView {
-            screens: vec![v],
-            active_screen: 0,
-        }
+        Self::new().with(|s| {
+            s.add_screen(v);
+        })
     }
 
     /// Returns a reference to the currently active screen.
@@ -45,11 +112,28 @@ impl<V> ScreensView<V> {
         self.screens.get_mut(id)
     }
 
+    /// Returns a mutable reference to the screen with the given id.
+    ///
+    /// Returns `None` if there is no such screen.
+    pub fn get_screen_mut(&mut self, screen_id: ScreenId) -> Option<&mut V> {
+        self.screens.get_mut(screen_id)
+    }
+
     /// Returns the id of the currently active screen.
     pub fn active_screen(&self) -> ScreenId {
         self.active_screen
     }
 
+    /// Returns the number of screens.
+    pub fn len(&self) -> usize {
+        self.screens.len()
+    }
+
+    /// Returns `true` if there is no screen.
+    pub fn is_empty(&self) -> bool {
+        self.screens.is_empty()
+    }
+
     /// Adds a new screen, and returns its ID.
     pub fn add_screen(&mut self, v: V) -> ScreenId {
         let res = self.screens.len();
@@ -65,7 +149,147 @@ impl<V> ScreensView<V> {
     }
 
     /// Sets the active screen. Panics if no such screen exist.
+    ///
+    /// This does not play any transition, nor does it call the callback
+    /// set by [`ScreensView::set_on_screen_change`].
+    /// Use [`ScreensView::switch_to_screen`] for that.
     pub fn set_active_screen(&mut self, screen_id: ScreenId) {
+        self.check_screen_id(screen_id);
+        self.transition = None;
+        self.active_screen = screen_id;
+    }
+
+    /// Switches to the given screen.
+    ///
+    /// Unlike [`ScreensView::set_active_screen`], this plays the transition
+    /// set by [`ScreensView::set_transition`], and the returned result will
+    /// run the callback set by [`ScreensView::set_on_screen_change`].
+    ///
+    /// # Panics
+    ///
+    /// If no such screen exist.
+    pub fn switch_to_screen(&mut self, screen_id: ScreenId) -> EventResult {
+        self.check_screen_id(screen_id);
+        let forward = screen_id > self.active_screen;
+        self.switch(screen_id, forward)
+    }
+
+    /// Switches to the next screen, wrapping around after the last one.
+    ///
+    /// Returns `EventResult::Ignored` if there are less than 2 screens.
+    pub fn next_screen(&mut self) -> EventResult {
+        if self.screens.len() < 2 {
+            return EventResult::Ignored;
+        }
+
+        let next = (self.active_screen + 1) % self.screens.len();
+        self.switch(next, true)
+    }
+
+    /// Switches to the previous screen, wrapping around before the first one.
+    ///
+    /// Returns `EventResult::Ignored` if there are less than 2 screens.
+    pub fn previous_screen(&mut self) -> EventResult {
+        if self.screens.len() < 2 {
+            return EventResult::Ignored;
+        }
+
+        let previous = self
+            .active_screen
+            .checked_sub(1)
+            .unwrap_or(self.screens.len() - 1);
+        self.switch(previous, false)
+    }
+
+    /// Sets the transition played when switching screens.
+    ///
+    /// Defaults to `ScreenTransition::None`.
+    pub fn set_transition(&mut self, transition: ScreenTransition) {
+        self.transition_style = transition;
+    }
+
+    /// Sets the transition played when switching screens.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn with_transition(self, transition: ScreenTransition) -> Self {
+        self.with(|s| s.set_transition(transition))
+    }
+
+    /// Returns `true` if a transition is currently running.
+    pub fn is_transitioning(&self) -> bool {
+        self.transition.is_some()
+    }
+
+    /// Sets the keys used to switch to the next and previous screens.
+    ///
+    /// These keys are checked before the active screen gets the event, if
+    /// there is more than one screen.
+    ///
+    /// No key is set by default.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::event::{Event, Key};
+    /// # use cursive_core::views::{ScreensView, TextView};
+    /// let mut screens = ScreensView::new();
+    /// screens.add_screen(TextView::new("Screen 1"));
+    /// screens.add_screen(TextView::new("Screen 2"));
+    ///
+    /// screens.set_switch_keys(Event::Ctrl(Key::PageDown), Event::Ctrl(Key::PageUp));
+    /// ```
+    pub fn set_switch_keys<N, P>(&mut self, next: N, previous: P)
+    where
+        N: Into<Event>,
+        P: Into<Event>,
+    {
+        self.switch_keys = Some((next.into(), previous.into()));
+    }
+
+    /// Sets the keys used to switch to the next and previous screens.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn with_switch_keys<N, P>(self, next: N, previous: P) -> Self
+    where
+        N: Into<Event>,
+        P: Into<Event>,
+    {
+        self.with(|s| s.set_switch_keys(next, previous))
+    }
+
+    /// Removes the keys set by [`ScreensView::set_switch_keys`].
+    pub fn clear_switch_keys(&mut self) {
+        self.switch_keys = None;
+    }
+
+    /// Sets a callback to run when the active screen changes.
+    ///
+    /// The callback is given the previous and the new screen IDs.
+    ///
+    /// It is only called for changes made through
+    /// [`ScreensView::switch_to_screen`], [`ScreensView::next_screen`],
+    /// [`ScreensView::previous_screen`] and the switch keys.
+    pub fn set_on_screen_change<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, ScreenId, ScreenId) + Send + Sync + 'static,
+    {
+        self.on_screen_change = Some(Arc::new(cb));
+    }
+
+    /// Sets a callback to run when the active screen changes.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn on_screen_change<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, ScreenId, ScreenId) + Send + Sync + 'static,
+    {
+        self.with(|s| s.set_on_screen_change(cb))
+    }
+
+    fn check_screen_id(&self, screen_id: ScreenId) {
         if screen_id >= self.screens.len() {
             panic!(
                 "Tried to set an invalid screen ID: {}, but only {} \
@@ -74,7 +298,79 @@ impl<V> ScreensView<V> {
                 self.screens.len()
             );
         }
+    }
+
+    fn switch(&mut self, screen_id: ScreenId, forward: bool) -> EventResult {
+        let previous = self.active_screen;
+        if screen_id == previous {
+            return EventResult::Consumed(None);
+        }
+
         self.active_screen = screen_id;
+
+        let duration = match self.transition_style {
+            ScreenTransition::Slide(duration) if !duration.is_zero() => Some(duration),
+            _ => None,
+        };
+
+        self.transition = duration.map(|duration| Transition {
+            from: previous,
+            start: Instant::now(),
+            duration,
+            forward,
+            done: AtomicBool::new(false),
+        });
+
+        let on_screen_change = self.on_screen_change.clone();
+
+        EventResult::with_cb_once(move |s| {
+            if let Some(duration) = duration {
+                // Keep the event loop busy so the animation gets drawn.
+                let frame = Duration::from_millis(TRANSITION_FRAME_MS);
+                let ticker = s.set_interval(frame, |_| ());
+                s.set_timeout(duration + 2 * frame, move |_| ticker.cancel());
+            }
+
+            if let Some(cb) = on_screen_change {
+                cb(s, previous, screen_id);
+            }
+        })
+    }
+
+    // Draws the active screen, and during a transition, the previous one.
+    fn draw_screens<F>(&self, printer: &Printer, draw: F)
+    where
+        F: Fn(&V, &Printer),
+    {
+        let Some(screen) = self.screen() else {
+            return;
+        };
+
+        let Some(transition) = &self.transition else {
+            draw(screen, printer);
+            return;
+        };
+
+        let width = printer.size.x;
+        let shift = transition.shift(width);
+        let old = &self.screens[transition.from];
+        let old_printer = printer.focused(false);
+
+        if transition.forward {
+            // The old screen leaves on the left, the new one comes from the right.
+            draw(old, &old_printer.content_offset((shift, 0)));
+            draw(screen, &printer.offset((width - shift, 0)));
+        } else {
+            draw(old, &old_printer.offset((shift, 0)));
+            draw(screen, &printer.content_offset((width - shift, 0)));
+        }
+    }
+}
+
+// Fills the printer area, so no leftover from the previous frame remains.
+fn clear_area(printer: &Printer) {
+    for y in 0..printer.size.y {
+        printer.print_hline((0, y), printer.size.x, " ");
     }
 }
 
@@ -84,7 +380,10 @@ impl ScreensView<crate::views::StackView> {
     /// This is mostly used internally by cursive. You probably just want
     /// `View::draw`.
     pub fn draw_bg(&self, printer: &crate::Printer) {
-        if let Some(screen) = self.screen() {
+        if self.transition.is_some() {
+            // Screens move around, so the entire background needs a refresh.
+            printer.with_style(PaletteStyle::Background, clear_area);
+        } else if let Some(screen) = self.screen() {
             screen.draw_bg(printer);
         }
     }
@@ -94,9 +393,7 @@ impl ScreensView<crate::views::StackView> {
     /// This is mostly used internally by cursive. You probably just want
     /// `View::draw`.
     pub fn draw_fg(&self, printer: &crate::Printer) {
-        if let Some(screen) = self.screen() {
-            screen.draw_fg(printer);
-        }
+        self.draw_screens(printer, crate::views::StackView::draw_fg);
     }
 }
 
@@ -120,6 +417,55 @@ where
         self.screen_mut().map(f)
     }
 
+    fn wrap_draw(&self, printer: &Printer) {
+        if self.transition.is_some() {
+            clear_area(printer);
+        }
+        self.draw_screens(printer, |screen, printer| screen.draw(printer));
+    }
+
+    fn wrap_dirty_area(&self, size: Vec2) -> Option<Rect> {
+        // Everything moves during a transition.
+        if self.transition.is_some() {
+            return Some(Rect::from_size(Vec2::zero(), size));
+        }
+
+        self.screen().and_then(|screen| screen.dirty_area(size))
+    }
+
+    fn wrap_layout(&mut self, size: Vec2) {
+        // Only forget the transition once its last frame was drawn.
+        if let Some(transition) = &self.transition {
+            if transition.done.load(Ordering::Relaxed) {
+                self.transition = None;
+            }
+        }
+
+        if let Some(transition) = &self.transition {
+            self.screens[transition.from].layout(size);
+        }
+
+        if let Some(screen) = self.screen_mut() {
+            screen.layout(size);
+        }
+    }
+
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        if let Some((next, previous)) = self.switch_keys.as_ref().filter(|_| self.screens.len() > 1)
+        {
+            if event == *next {
+                return self.next_screen();
+            }
+            if event == *previous {
+                return self.previous_screen();
+            }
+        }
+
+        self.screen_mut()
+            .map(|screen| screen.on_event(event))
+            .unwrap_or(EventResult::Ignored)
+    }
+
     fn wrap_call_on_any(&mut self, selector: &Selector, callback: AnyCb) {
         for screen in &mut self.screens {
             screen.call_on_any(selector, callback);
@@ -138,4 +484,64 @@ where
     }
 }
 
-// TODO: blueprint?
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::Key;
+    use crate::views::TextView;
+    use std::sync::atomic::AtomicUsize;
+
+    #[test]
+    fn switch_keys() {
+        let mut screens = ScreensView::single_screen(TextView::new("1"));
+        let next = Event::Ctrl(Key::PageDown);
+
+        // No key is set by default.
+        assert!(!screens.on_event(next.clone()).is_consumed());
+
+        // With a single screen, the keys go to the screen.
+        screens.set_switch_keys(next.clone(), Event::Ctrl(Key::PageUp));
+        assert!(!screens.on_event(next.clone()).is_consumed());
+
+        screens.add_screen(TextView::new("2"));
+        screens.on_event(next.clone()).process(&mut Cursive::new());
+        assert_eq!(screens.active_screen(), 1);
+
+        screens.clear_switch_keys();
+        assert!(!screens.on_event(next).is_consumed());
+    }
+
+    #[test]
+    fn cycle_screens() {
+        let mut screens = ScreensView::new()
+            .with_switch_keys(Key::PageDown, Key::PageUp)
+            .with(|s| {
+                s.add_screen(TextView::new("1"));
+                s.add_screen(TextView::new("2"));
+                s.add_screen(TextView::new("3"));
+            });
+
+        let changes = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&changes);
+        screens.set_on_screen_change(move |_, _, _| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+
+        let mut siv = Cursive::new();
+
+        screens.on_event(Event::Key(Key::PageUp)).process(&mut siv);
+        assert_eq!(screens.active_screen(), 2);
+
+        screens
+            .on_event(Event::Key(Key::PageDown))
+            .process(&mut siv);
+        assert_eq!(screens.active_screen(), 0);
+
+        screens.switch_to_screen(0).process(&mut siv);
+        assert_eq!(changes.load(Ordering::Relaxed), 2);
+
+        // Silent change.
+        screens.set_active_screen(1);
+        assert_eq!(changes.load(Ordering::Relaxed), 2);
+    }
+}
@@ -1,11 +1,16 @@
 use crate::{
     direction::Direction,
     event::{AnyCb, Event, EventResult},
-    view::{scroll, CannotFocus, ScrollStrategy, Selector, View, ViewNotFound},
+    view::{
+        scroll,
+        scroll::{Easing, ScrollIndicator, ScrollKeys, ScrollStyle},
+        CannotFocus, ScrollStrategy, Selector, View, ViewNotFound,
+    },
     Cursive, Printer, Rect, Vec2, With,
 };
 
 use std::sync::Arc;
+use std::time::Duration;
 
 type InnerScrollCallback<V> = dyn Fn(&mut ScrollView<V>, Rect) -> EventResult + Send + Sync;
 type ScrollCallback = dyn Fn(&mut Cursive, Rect) + Send + Sync;
@@ -115,6 +120,149 @@ impl<V: 'static> ScrollView<V> {
         self.with(|s| s.set_show_scrollbars(show_scrollbars))
     }
 
+    /// Sets the number of cells scrolled by each mouse wheel event.
+    ///
+    /// `scroll_step.x` is used for horizontal scrolling, `scroll_step.y` for vertical scrolling.
+    ///
+    /// Defaults to `(3, 3)`.
+    pub fn set_scroll_step<S: Into<Vec2>>(&mut self, scroll_step: S) {
+        self.core.set_scroll_step(scroll_step);
+    }
+
+    /// Sets the number of cells scrolled by each mouse wheel event.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn scroll_step<S: Into<Vec2>>(self, scroll_step: S) -> Self {
+        self.with(|s| s.set_scroll_step(scroll_step))
+    }
+
+    /// Returns the number of cells scrolled by each mouse wheel event.
+    pub fn get_scroll_step(&self) -> Vec2 {
+        self.core.get_scroll_step()
+    }
+
+    /// Sets the style used to draw the scrollbars, overriding the theme.
+    pub fn set_scroll_style(&mut self, style: ScrollStyle) {
+        self.core.set_scroll_style(style);
+    }
+
+    /// Sets the style used to draw the scrollbars, overriding the theme.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn scroll_style(self, style: ScrollStyle) -> Self {
+        self.with(|s| s.set_scroll_style(style))
+    }
+
+    /// Uses the theme's style to draw the scrollbars.
+    ///
+    /// This is the default.
+    pub fn reset_scroll_style(&mut self) {
+        self.core.reset_scroll_style();
+    }
+
+    /// Shows an overlay with the scroll position.
+    ///
+    /// See [`ScrollIndicator`] for the available options.
+    pub fn set_indicator(&mut self, indicator: ScrollIndicator) {
+        self.core.set_indicator(indicator);
+    }
+
+    /// Shows an overlay with the scroll position.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn indicator(self, indicator: ScrollIndicator) -> Self {
+        self.with(|s| s.set_indicator(indicator))
+    }
+
+    /// Removes the scroll position indicator.
+    ///
+    /// This is the default.
+    pub fn remove_indicator(&mut self) {
+        self.core.remove_indicator();
+    }
+
+    /// Enables optional keyboard shortcuts, like half-page scrolling or marks.
+    ///
+    /// See [`ScrollKeys`] for the available shortcuts.
+    pub fn set_scroll_keys(&mut self, keys: ScrollKeys) {
+        self.core.set_scroll_keys(keys);
+    }
+
+    /// Enables optional keyboard shortcuts, like half-page scrolling or marks.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn scroll_keys(self, keys: ScrollKeys) -> Self {
+        self.with(|s| s.set_scroll_keys(keys))
+    }
+
+    /// Sets whether scroll events can reach an outer scroll view.
+    ///
+    /// See [`Core::set_scroll_chaining`](scroll::Core::set_scroll_chaining).
+    ///
+    /// Defaults to `true`.
+    pub fn set_scroll_chaining(&mut self, scroll_chaining: bool) {
+        self.core.set_scroll_chaining(scroll_chaining);
+    }
+
+    /// Sets whether scroll events can reach an outer scroll view.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn scroll_chaining(self, scroll_chaining: bool) -> Self {
+        self.with(|s| s.set_scroll_chaining(scroll_chaining))
+    }
+
+    /// Controls whether scrolling by pages or to the focused element is animated.
+    ///
+    /// This requires an auto-refresh to be set with `Cursive::set_fps`.
+    ///
+    /// Defaults to `false`.
+    pub fn set_smooth_scroll(&mut self, smooth_scroll: bool) {
+        self.core.set_smooth_scroll(smooth_scroll);
+    }
+
+    /// Controls whether scrolling by pages or to the focused element is animated.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn smooth_scroll(self, smooth_scroll: bool) -> Self {
+        self.with(|s| s.set_smooth_scroll(smooth_scroll))
+    }
+
+    /// Sets the easing curve used by smooth scrolling.
+    ///
+    /// Defaults to [`Easing::EaseOut`].
+    pub fn set_easing(&mut self, easing: Easing) {
+        self.core.set_easing(easing);
+    }
+
+    /// Sets the easing curve used by smooth scrolling.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn easing(self, easing: Easing) -> Self {
+        self.with(|s| s.set_easing(easing))
+    }
+
+    /// Sets how long a smooth scrolling animation lasts.
+    ///
+    /// Defaults to 200ms.
+    pub fn set_animation_duration(&mut self, duration: Duration) {
+        self.core.set_animation_duration(duration);
+    }
+
+    /// Sets how long a smooth scrolling animation lasts.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn animation_duration(self, duration: Duration) -> Self {
+        self.with(|s| s.set_animation_duration(duration))
+    }
+
     /// Sets the scroll offset to the given value
     pub fn set_offset<S>(&mut self, offset: S) -> EventResult
     where
@@ -205,8 +353,35 @@ impl<V: 'static> ScrollView<V> {
         V: View,
     {
         self.scroll_operation(|s| {
+            let visible_offset = s.core.visible_offset();
             let important_area = s.inner.important_area(s.core.last_outer_size());
-            s.core.scroll_to_rect(important_area)
+            s.core.scroll_to_rect(important_area);
+            s.core.animate_from(visible_offset);
+        })
+    }
+
+    /// Programmatically scroll just enough to make `area` visible.
+    ///
+    /// `area` is given in the child's coordinates. If it is larger than the
+    /// viewport, its top-left corner is shown.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::views::{ScrollView, TextView};
+    /// use cursive_core::Rect;
+    ///
+    /// let mut view = ScrollView::new(TextView::new("Lots of text"));
+    /// view.reveal(Rect::from_size((0, 40), (10, 2)));
+    /// ```
+    pub fn reveal(&mut self, area: Rect) -> EventResult
+    where
+        V: View,
+    {
+        self.scroll_operation(|s| {
+            let visible_offset = s.core.visible_offset();
+            s.core.scroll_to_rect(area);
+            s.core.animate_from(visible_offset);
         })
     }
 
@@ -366,6 +541,10 @@ where
     }
 
     fn layout(&mut self, size: Vec2) {
+        // Keep showing the same content if rows were removed above it.
+        let removed_rows = self.inner.take_removed_rows();
+        self.core.scroll_up(removed_rows);
+
         scroll::layout(
             self,
             size,
@@ -391,6 +570,10 @@ where
         self.inner.call_on_any(selector, cb)
     }
 
+    fn call_on_focused_child(&mut self, callback: AnyCb) {
+        callback(&mut self.inner);
+    }
+
     fn focus_view(&mut self, selector: &Selector) -> Result<EventResult, ViewNotFound> {
         self.inner.focus_view(selector).map(|res| {
             self.scroll_to_important_area();
@@ -398,6 +581,23 @@ where
         })
     }
 
+    fn reveal_view(&mut self, selector: &Selector, _: Vec2) -> Result<Rect, ViewNotFound> {
+        let area = self.inner.reveal_view(selector, self.core.inner_size())?;
+
+        // Note: like in `take_focus`, we can't return the callback here.
+        self.reveal(area);
+
+        // Only the visible part should be revealed by the parents.
+        let viewport = self.core.content_viewport();
+        let visible = area
+            .intersection(viewport)
+            .unwrap_or_else(|| Rect::from_point(viewport.clamp_point(area.top_left())));
+        Ok(Rect::from_corners(
+            visible.top_left() - viewport.top_left(),
+            visible.bottom_right() - viewport.top_left(),
+        ))
+    }
+
     fn take_focus(&mut self, source: Direction) -> Result<EventResult, CannotFocus> {
         // If the inner view takes focus, re-align the important area.
         match self.inner.take_focus(source) {
@@ -433,6 +633,13 @@ struct Blueprint {
     scroll_y: Option<bool>,
     scroll_strategy: Option<ScrollStrategy>,
     show_scrollbars: Option<bool>,
+    scroll_step: Option<Vec2>,
+    smooth_scroll: Option<bool>,
+    easing: Option<Easing>,
+    scroll_style: Option<ScrollStyle>,
+    scroll_keys: Option<ScrollKeys>,
+    scroll_chaining: Option<bool>,
+    indicator: Option<ScrollIndicator>,
 
     on_scroll: Option<_>,
     on_scroll_inner: Option<_>,
@@ -490,3 +697,235 @@ crate::manual_blueprint!(with scroll, |config, context| {
 
     Ok(move |view| ScrollView::new(view).scroll_x(x).scroll_y(y))
 });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::MouseEvent;
+    use crate::views::TextView;
+
+    fn wheel<V: View>(view: &mut ScrollView<V>, event: MouseEvent) {
+        view.on_event(Event::Mouse {
+            offset: Vec2::zero(),
+            position: Vec2::new(1, 1),
+            event,
+        });
+    }
+
+    #[test]
+    fn scroll_step() {
+        let content = "0123456789".repeat(4);
+        let content = vec![content; 20].join("\n");
+        let mut view = ScrollView::new(TextView::new(content).no_wrap())
+            .scroll_x(true)
+            .scroll_step((5, 2));
+        view.layout(Vec2::new(10, 5));
+
+        wheel(&mut view, MouseEvent::WheelDown);
+        assert_eq!(view.content_viewport().top(), 2);
+
+        wheel(&mut view, MouseEvent::WheelRight);
+        wheel(&mut view, MouseEvent::WheelRight);
+        assert_eq!(view.content_viewport().left(), 10);
+
+        wheel(&mut view, MouseEvent::WheelLeft);
+        assert_eq!(view.content_viewport().left(), 5);
+
+        wheel(&mut view, MouseEvent::WheelUp);
+        assert_eq!(view.content_viewport().top(), 0);
+    }
+
+    #[test]
+    fn smooth_scroll() {
+        let content = vec!["line"; 20].join("\n");
+        let mut view = ScrollView::new(TextView::new(content))
+            .smooth_scroll(true)
+            .animation_duration(Duration::from_secs(60));
+        view.layout(Vec2::new(10, 5));
+
+        view.on_event(Event::Key(crate::event::Key::PageDown));
+
+        // The target is reached right away, but the display lags behind.
+        assert_eq!(view.content_viewport().top(), 5);
+        assert!(view.core.is_animating());
+        assert!(view.core.visible_offset().y < 5);
+
+        // Small steps are not animated.
+        view.on_event(Event::Key(crate::event::Key::Down));
+        assert!(!view.core.is_animating());
+        assert_eq!(view.core.visible_offset().y, 6);
+    }
+
+    #[test]
+    fn scroll_keys() {
+        let content = vec!["line"; 40].join("\n");
+        let mut view = ScrollView::new(TextView::new(content)).scroll_keys(ScrollKeys {
+            half_page: true,
+            page_size: Some(8),
+            marks: true,
+        });
+        view.layout(Vec2::new(10, 5));
+
+        view.on_event(Event::Key(crate::event::Key::PageDown));
+        assert_eq!(view.content_viewport().top(), 8);
+
+        view.on_event(Event::CtrlChar('u'));
+        assert_eq!(view.content_viewport().top(), 4);
+
+        // Save this position, move away, and jump back.
+        view.on_event(Event::Char('m'));
+        view.on_event(Event::Char('a'));
+        view.on_event(Event::CtrlChar('d'));
+        view.on_event(Event::CtrlChar('d'));
+        assert_eq!(view.content_viewport().top(), 12);
+
+        view.on_event(Event::Char('\''));
+        view.on_event(Event::Char('a'));
+        assert_eq!(view.content_viewport().top(), 4);
+
+        // `''` goes back to where we were before the jump.
+        view.on_event(Event::Char('\''));
+        view.on_event(Event::Char('\''));
+        assert_eq!(view.content_viewport().top(), 12);
+    }
+
+    #[test]
+    fn scroll_chaining() {
+        use crate::traits::Resizable;
+        use crate::views::LinearLayout;
+
+        // A 3-rows scroll area above more content, in a 5-rows scroll view.
+        fn nested(chaining: bool) -> ScrollView<LinearLayout> {
+            let lines = ["line"; 10].join("\n");
+            let inner = ScrollView::new(TextView::new(lines.clone()))
+                .scroll_chaining(chaining)
+                .fixed_height(3);
+            let mut view = ScrollView::new(
+                LinearLayout::vertical()
+                    .child(inner)
+                    .child(TextView::new(lines)),
+            );
+            view.layout(Vec2::new(10, 5));
+            view
+        }
+
+        // The inner view scrolls first, without moving the outer one.
+        let mut view = nested(true);
+        wheel(&mut view, MouseEvent::WheelDown);
+        wheel(&mut view, MouseEvent::WheelDown);
+        assert_eq!(view.content_viewport().top(), 0);
+
+        // Once it reaches its bottom, the outer view takes over.
+        wheel(&mut view, MouseEvent::WheelDown);
+        wheel(&mut view, MouseEvent::WheelDown);
+        assert_eq!(view.content_viewport().top(), 3);
+
+        // Without chaining, the inner view keeps the events at its edge.
+        let mut view = nested(false);
+        for _ in 0..4 {
+            wheel(&mut view, MouseEvent::WheelDown);
+        }
+        assert_eq!(view.content_viewport().top(), 0);
+    }
+
+    #[test]
+    fn scroll_style() {
+        use crate::buffer::PrintBuffer;
+        use crate::theme::Theme;
+
+        let content = vec!["line"; 20].join("\n");
+        let style = ScrollStyle {
+            thumb: "#".into(),
+            vertical_track: ":".into(),
+            thickness: 2,
+            ..ScrollStyle::default()
+        };
+        let mut view = ScrollView::new(TextView::new(content)).scroll_style(style);
+
+        let size = Vec2::new(10, 4);
+        view.layout(size);
+        assert_eq!(view.content_viewport().width(), 7);
+
+        let mut buffer = PrintBuffer::new();
+        buffer.resize(size);
+        let buffer = parking_lot::RwLock::new(buffer);
+        view.draw(&Printer::new(size, &Theme::default(), &buffer));
+
+        let rendered = buffer.read().to_styled_string();
+        let lines: Vec<&str> = rendered.source().lines().collect();
+        assert_eq!(lines[0], "line    ##");
+        assert_eq!(lines[3], "line    ::");
+    }
+
+    #[test]
+    fn indicator() {
+        use crate::buffer::PrintBuffer;
+        use crate::theme::Theme;
+        use crate::view::scroll::IndicatorFormat;
+
+        let content = vec!["line"; 20].join("\n");
+        let mut view = ScrollView::new(TextView::new(content))
+            .show_scrollbars(false)
+            .indicator(ScrollIndicator {
+                format: IndicatorFormat::Lines,
+                auto_hide: Some(Duration::from_secs(60)),
+                ..ScrollIndicator::default()
+            });
+
+        let size = Vec2::new(10, 4);
+        view.layout(size);
+
+        let render = |view: &ScrollView<TextView>| {
+            let mut buffer = PrintBuffer::new();
+            buffer.resize(size);
+            let buffer = parking_lot::RwLock::new(buffer);
+            view.draw(&Printer::new(size, &Theme::default(), &buffer));
+            let rendered = buffer.read().to_styled_string();
+            rendered
+                .source()
+                .lines()
+                .map(String::from)
+                .collect::<Vec<_>>()
+        };
+
+        // Hidden until we scroll.
+        assert_eq!(render(&view)[3], "line      ");
+
+        view.on_event(Event::Key(crate::event::Key::Down));
+        // Space for the scrollbar is still reserved.
+        assert_eq!(render(&view)[3], "li 2/20   ");
+    }
+
+    #[test]
+    fn reveal() {
+        use crate::traits::{Nameable, Resizable};
+        use crate::views::LinearLayout;
+
+        let lines = |n| vec!["line"; n].join("\n");
+        let inner = ScrollView::new(
+            LinearLayout::vertical()
+                .child(TextView::new(lines(10)))
+                .child(TextView::new("target").with_name("target")),
+        )
+        .fixed_height(4);
+        let mut view = ScrollView::new(
+            LinearLayout::vertical()
+                .child(TextView::new(lines(20)))
+                .child(inner),
+        );
+        view.layout(Vec2::new(10, 5));
+
+        assert!(view
+            .reveal_view(&Selector::Name("other"), Vec2::new(10, 5))
+            .is_err());
+        let area = view.reveal_view(&Selector::Name("target"), Vec2::new(10, 5));
+
+        // The inner view scrolled to its last line, the outer one just enough
+        // to show that line.
+        assert_eq!(area.unwrap(), Rect::from_size((0, 4), (6, 1)));
+        assert_eq!(view.content_viewport().top(), 19);
+
+        view.reveal(Rect::from_size((0, 2), (1, 1)));
+        assert_eq!(view.content_viewport().top(), 2);
+    }
+}
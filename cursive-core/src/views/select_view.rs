@@ -2,22 +2,63 @@ use crate::{
     align::{Align, HAlign, VAlign},
     direction,
     event::{Callback, Event, EventResult, Key, MouseButton, MouseEvent},
-    menu,
+    keymap, menu,
     rect::Rect,
-    style::{PaletteStyle, Style, StyleType},
-    utils::markup::StyledString,
+    style::{Effect, PaletteStyle, Style, StyleType},
+    utils::{markup::StyledString, matching, placeholder, TypeAhead},
     view::{CannotFocus, Position, View},
-    views::{LayerPosition, MenuPopup},
+    views::{LayerPosition, MenuPopup, TextView},
     Cursive, Printer, Vec2, With,
 };
 use std::borrow::Borrow;
-use std::cmp::{min, Ordering};
+use std::cmp::{max, min, Ordering};
+use std::collections::HashMap;
 use std::sync::atomic::AtomicUsize;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use unicode_width::UnicodeWidthStr;
 
 type SelectCallback<T> = dyn Fn(&mut Cursive, &T) + Send + Sync;
+type SelectFullCallback<T> = dyn Fn(&mut Cursive, usize, Option<&T>, &T) + Send + Sync;
+type FilterMatcher = dyn Fn(&str, &str) -> Option<Vec<usize>> + Send + Sync;
+type ItemProvider<T> = dyn Fn(usize) -> (StyledString, T) + Send + Sync;
+type DetailMaker<T> = dyn Fn(&T) -> StyledString + Send + Sync;
+
+// Prompt shown before the filter query.
+const FILTER_PROMPT: &str = "> ";
+
+// Space between two columns.
+const COLUMN_GAP: usize = 2;
+
+/// How a [`SelectView`] spreads its items into columns.
+///
+/// Items flow from top to bottom, then from left to right, so each column
+/// has the same height (except the last one).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SelectColumns {
+    /// Use this many columns.
+    Fixed(usize),
+
+    /// Use as many columns as fit in the available width.
+    Auto,
+}
+
+impl Default for SelectColumns {
+    fn default() -> Self {
+        SelectColumns::Fixed(1)
+    }
+}
+
+impl std::str::FromStr for SelectColumns {
+    type Err = std::num::ParseIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Auto" | "auto" => Self::Auto,
+            _ => Self::Fixed(s.parse()?),
+        })
+    }
+}
 
 /// View to select an item among a list.
 ///
@@ -63,13 +104,33 @@ pub struct SelectView<T = String> {
     on_submit: Option<Arc<SelectCallback<T>>>,
 
     // This callback is called when the selection is changed.
-    // TODO: add the previous selection? Indices?
     on_select: Option<Arc<SelectCallback<T>>>,
 
-    // If `true`, when a character is pressed, jump to the next item starting
-    // with this character.
+    // Also called when the selection is changed, with the new index and the
+    // previous selection.
+    on_select_full: Option<Arc<SelectFullCallback<T>>>,
+
+    // Name of a `TextView` updated with a description of the selection.
+    detail: Option<(String, Arc<DetailMaker<T>>)>,
+
+    // If `true`, typed characters select the next item starting with them.
     autojump: bool,
 
+    // Characters typed so far for autojump.
+    type_ahead: TypeAhead,
+
+    // If `true`, the autojump prefix is displayed in the bottom-right corner.
+    show_autojump_buffer: bool,
+
+    // If `true`, typed characters filter the items, under an input line.
+    filterable: bool,
+
+    // Query typed so far to filter the items.
+    filter_query: String,
+
+    // Returns the matched characters of the query in a label, if it matches.
+    filter_matcher: Arc<FilterMatcher>,
+
     align: Align,
 
     // `true` if we show a one-line view, with popup on selection.
@@ -84,6 +145,27 @@ pub struct SelectView<T = String> {
 
     // Cache of required_size. Set to None when it needs to be recomputed.
     last_required_size: Option<Vec2>,
+
+    // Text shown when there is no item.
+    placeholder: Option<StyledString>,
+
+    // Set during a batch update, to the selection from before the update.
+    //
+    // Selection callbacks are not generated during a batch update.
+    update_start: Option<Option<Arc<T>>>,
+
+    // Set while a selection callback is queued by a `Cursive` batch update, to the selection
+    // from before the update.
+    batch_previous: Arc<Mutex<Option<Option<Arc<T>>>>>,
+
+    // If set, items come from here instead of `items`.
+    provider: Option<Provider<T>>,
+
+    // Separators and headers shown between the items, sorted by position.
+    dividers: Vec<Divider>,
+
+    // How the items are spread into columns.
+    columns: SelectColumns,
 }
 
 impl<T: 'static + Send + Sync> Default for SelectView<T> {
@@ -103,29 +185,161 @@ impl<T: 'static + Send + Sync> SelectView<T> {
             focus: Arc::new(AtomicUsize::new(0)),
             inactive_highlight: true,
             on_select: None,
+            on_select_full: None,
+            detail: None,
             on_submit: None,
             align: Align::top_left(),
             popup: false,
             decorators: ["<".to_string(), ">".to_string()],
             autojump: false,
+            type_ahead: TypeAhead::new(),
+            show_autojump_buffer: false,
+            filterable: false,
+            filter_query: String::new(),
+            filter_matcher: Arc::new(matching::substring),
             last_offset: Mutex::new(Vec2::zero()),
             last_size: Vec2::zero(),
             last_required_size: None,
+            placeholder: None,
+            update_start: None,
+            batch_previous: Arc::new(Mutex::new(None)),
+            provider: None,
+            dividers: Vec::new(),
+            columns: SelectColumns::default(),
+        }
+    }
+
+    /// Creates a new SelectView getting its items from `provider`.
+    ///
+    /// See [`SelectView::set_provider`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::views::{ScrollView, SelectView};
+    ///
+    /// let select = SelectView::with_provider(100_000, |i| (format!("Item {i}").into(), i));
+    /// let view = ScrollView::new(select);
+    /// ```
+    pub fn with_provider<F>(len: usize, provider: F) -> Self
+    where
+        F: Fn(usize) -> (StyledString, T) + 'static + Send + Sync,
+    {
+        Self::new().with(|s| s.set_provider(len, provider))
+    }
+
+    /// Gets the items from `provider` instead of storing them.
+    ///
+    /// `provider` returns the label and value of the item at the given index,
+    /// for indices up to `len`. It is only called for visible rows and for
+    /// the selection, so this can show very large lists, usually inside a
+    /// [`ScrollView`](crate::views::ScrollView). The values returned are
+    /// cached for a while, so `provider` should always return the same item
+    /// for a given index, unless [`SelectView::set_provider_len`] is called.
+    ///
+    /// The width of the view is the largest label seen so far, so it may
+    /// grow while scrolling.
+    ///
+    /// This replaces any item added so far. While a provider is set, items
+    /// cannot be added, removed or accessed individually, and the popup mode,
+    /// filtering and auto-jump are not available. Use [`SelectView::clear`]
+    /// to remove the provider.
+    pub fn set_provider<F>(&mut self, len: usize, provider: F)
+    where
+        F: Fn(usize) -> (StyledString, T) + 'static + Send + Sync,
+    {
+        self.items.clear();
+        self.provider = Some(Provider::new(len, Box::new(provider)));
+        self.set_focus(min(self.focus(), len.saturating_sub(1)));
+        self.last_required_size = None;
+    }
+
+    /// Changes the number of items given by the provider.
+    ///
+    /// This also forgets about the items already fetched from it.
+    ///
+    /// Does nothing if no provider is set.
+    pub fn set_provider_len(&mut self, len: usize) {
+        let Some(ref mut provider) = self.provider else {
+            return;
+        };
+
+        provider.reset(len);
+        self.set_focus(min(self.focus(), len.saturating_sub(1)));
+        self.last_required_size = None;
+    }
+
+    /// Starts a batch update.
+    ///
+    /// Until [`SelectView::end_update`] is called, selection changes will not
+    /// generate any callback. This is useful when making many changes at once,
+    /// like adding or removing lots of items.
+    ///
+    /// Does nothing if a batch update is already active.
+    pub fn begin_update(&mut self) {
+        if self.update_start.is_none() {
+            self.update_start = Some(self.selection());
+        }
+    }
+
+    /// Ends a batch update started with [`SelectView::begin_update`].
+    ///
+    /// Returns a callback for the selection change since the start of the
+    /// update, if the selection changed.
+    ///
+    /// You should run this callback with a `&mut Cursive`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// # use cursive_core::views::SelectView;
+    /// fn reload(siv: &mut Cursive, view: &mut SelectView<usize>) {
+    ///     view.begin_update();
+    ///     view.clear();
+    ///     for i in 0..10_000 {
+    ///         view.add_item(i.to_string(), i);
+    ///     }
+    ///     view.set_selection(42);
+    ///     let cb = view.end_update();
+    ///     cb(siv);
+    /// }
+    /// ```
+    pub fn end_update(&mut self) -> Callback {
+        let Some(previous) = self.update_start.take() else {
+            return Callback::dummy();
+        };
+
+        let unchanged = match (&previous, self.selection()) {
+            (Some(previous), Some(current)) => Arc::ptr_eq(previous, &current),
+            (None, None) => true,
+            _ => false,
+        };
+
+        if unchanged {
+            return Callback::dummy();
         }
+
+        self.make_select_cb(previous)
+            .unwrap_or_else(Callback::dummy)
     }
 
     /// Sets the "auto-jump" property for this view.
     ///
-    /// If enabled, when a key is pressed, the selection will jump to the next
-    /// item beginning with the pressed letter.
+    /// If enabled, typing the beginning of a label selects the next item
+    /// starting with it. Repeating a single letter cycles through the items
+    /// beginning with this letter.
+    ///
+    /// See [`TypeAhead`] for details.
     pub fn set_autojump(&mut self, autojump: bool) {
         self.autojump = autojump;
+        self.type_ahead.clear();
     }
 
     /// Sets the "auto-jump" property for this view.
     ///
-    /// If enabled, when a key is pressed, the selection will jump to the next
-    /// item beginning with the pressed letter.
+    /// If enabled, typing the beginning of a label selects the next item
+    /// starting with it.
     ///
     /// Chainable variant.
     #[must_use]
@@ -133,6 +347,172 @@ impl<T: 'static + Send + Sync> SelectView<T> {
         self.with(|s| s.set_autojump(true))
     }
 
+    /// Sets the delay after which the characters typed for auto-jump are forgotten.
+    ///
+    /// Defaults to 1 second.
+    pub fn set_autojump_timeout(&mut self, timeout: Duration) {
+        self.type_ahead.set_timeout(timeout);
+    }
+
+    /// Sets the delay after which the characters typed for auto-jump are forgotten.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn autojump_timeout(self, timeout: Duration) -> Self {
+        self.with(|s| s.set_autojump_timeout(timeout))
+    }
+
+    /// Returns the characters typed so far for auto-jump.
+    ///
+    /// Returns an empty string once the auto-jump timeout expired.
+    pub fn autojump_buffer(&self) -> &str {
+        self.type_ahead.buffer()
+    }
+
+    /// Controls whether the characters typed for auto-jump are displayed.
+    ///
+    /// If enabled, they are printed in the bottom-right corner of the view.
+    /// This has no effect in popup mode.
+    ///
+    /// Defaults to `false`.
+    pub fn set_show_autojump_buffer(&mut self, show: bool) {
+        self.show_autojump_buffer = show;
+    }
+
+    /// Controls whether the characters typed for auto-jump are displayed.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn show_autojump_buffer(self, show: bool) -> Self {
+        self.with(|s| s.set_show_autojump_buffer(show))
+    }
+
+    /// Controls whether the items can be filtered by typing.
+    ///
+    /// If enabled, an input line is shown above the items, and typed
+    /// characters are added to it. Only the items matching it are shown, with
+    /// the matched characters underlined. `Backspace` removes the last
+    /// character, and `Esc` clears the query.
+    ///
+    /// This replaces auto-jump, and has no effect in popup mode.
+    ///
+    /// Disabling filtering clears the query. Defaults to `false`.
+    pub fn set_filterable(&mut self, filterable: bool) {
+        self.filterable = filterable;
+        self.filter_query.clear();
+        self.last_required_size = None;
+    }
+
+    /// Controls whether the items can be filtered by typing.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::utils::matching;
+    /// use cursive_core::views::SelectView;
+    ///
+    /// let select = SelectView::new()
+    ///     .with_all_str(["Apple", "Banana", "Blueberry"])
+    ///     .filterable(true)
+    ///     .filter_matcher(matching::fuzzy);
+    /// ```
+    #[must_use]
+    pub fn filterable(self, filterable: bool) -> Self {
+        self.with(|s| s.set_filterable(filterable))
+    }
+
+    /// Sets the function used to match the filter query with the labels.
+    ///
+    /// It receives the query and the text of a label, and returns the byte
+    /// offset of each matched character in the label, or `None` if it does
+    /// not match. See [`matching`] for some matchers.
+    ///
+    /// Defaults to [`matching::substring`].
+    pub fn set_filter_matcher<F>(&mut self, matcher: F)
+    where
+        F: Fn(&str, &str) -> Option<Vec<usize>> + 'static + Send + Sync,
+    {
+        self.filter_matcher = Arc::new(matcher);
+    }
+
+    /// Sets the function used to match the filter query with the labels.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn filter_matcher<F>(self, matcher: F) -> Self
+    where
+        F: Fn(&str, &str) -> Option<Vec<usize>> + 'static + Send + Sync,
+    {
+        self.with(|s| s.set_filter_matcher(matcher))
+    }
+
+    /// Returns the query currently filtering the items.
+    pub fn get_filter_query(&self) -> &str {
+        &self.filter_query
+    }
+
+    /// Sets the query filtering the items.
+    ///
+    /// If the selected item is filtered out, the first matching item is
+    /// selected instead.
+    ///
+    /// Returns a callback in response to the selection change.
+    ///
+    /// You should run this callback with a `&mut Cursive`.
+    pub fn set_filter_query<S: Into<String>>(&mut self, query: S) -> Callback {
+        self.update_filter(|filter| *filter = query.into())
+            .unwrap_or_else(Callback::dummy)
+    }
+
+    // Returns `true` if the items are currently filtered by the query.
+    fn is_filtering(&self) -> bool {
+        self.filterable && !self.popup && self.provider.is_none()
+    }
+
+    // Returns the matched characters in the label of item `i`, if it is shown.
+    fn filter_matches(&self, i: usize) -> Option<Vec<usize>> {
+        if !self.is_filtering() || self.filter_query.is_empty() {
+            return Some(Vec::new());
+        }
+
+        let label: String = self.items[i]
+            .label
+            .spans()
+            .map(|span| span.content)
+            .collect();
+        (self.filter_matcher)(&self.filter_query, &label)
+    }
+
+    // Returns the items shown, with their matched characters.
+    fn filtered_items(&self) -> Vec<(usize, Vec<usize>)> {
+        (0..self.items.len())
+            .filter_map(|i| self.filter_matches(i).map(|matched| (i, matched)))
+            .collect()
+    }
+
+    // Changes the filter query, and fixes the selection if it gets hidden.
+    fn update_filter<F>(&mut self, f: F) -> Option<Callback>
+    where
+        F: FnOnce(&mut String),
+    {
+        let previous = self.selection();
+        f(&mut self.filter_query);
+
+        if self.filter_matches(self.focus()).is_none() {
+            if let Some(&(first, _)) = self.filtered_items().first() {
+                self.set_focus(first);
+            }
+        }
+
+        match (&previous, self.selection()) {
+            (Some(previous), Some(current)) if Arc::ptr_eq(previous, &current) => None,
+            (None, None) => None,
+            _ => self.make_select_cb(previous),
+        }
+    }
+
     /// Sets the "inactive highlight" property for this view.
     ///
     /// * If true (the default), the selected row will be highlighted when the
@@ -185,6 +565,51 @@ impl<T: 'static + Send + Sync> SelectView<T> {
         self.decorators = [start.into(), end.into()];
     }
 
+    /// Sets a placeholder to show when there is no item.
+    ///
+    /// The placeholder is drawn dimmed, in the center of the view.
+    ///
+    /// It is not shown in popup mode.
+    pub fn set_placeholder<S: Into<StyledString>>(&mut self, placeholder: S) {
+        self.placeholder = Some(placeholder.into());
+        self.last_required_size = None;
+    }
+
+    /// Sets a placeholder to show when there is no item.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::views::SelectView;
+    /// let select = SelectView::<String>::new().placeholder("No results");
+    /// ```
+    #[must_use]
+    pub fn placeholder<S: Into<StyledString>>(self, placeholder: S) -> Self {
+        self.with(|s| s.set_placeholder(placeholder))
+    }
+
+    /// Removes the placeholder, if any.
+    pub fn clear_placeholder(&mut self) {
+        self.placeholder = None;
+        self.last_required_size = None;
+    }
+
+    /// Returns the placeholder shown when there is no item.
+    pub fn get_placeholder(&self) -> Option<&StyledString> {
+        self.placeholder.as_ref()
+    }
+
+    // Returns the placeholder if it should be shown instead of the items.
+    fn active_placeholder(&self) -> Option<&StyledString> {
+        if self.is_empty() && (!self.popup || self.provider.is_some()) {
+            self.placeholder.as_ref()
+        } else {
+            None
+        }
+    }
+
     /// Sets a callback to be used when an item is selected.
     #[crate::callback_helpers]
     pub fn set_on_select<F>(&mut self, cb: F)
@@ -231,6 +656,102 @@ impl<T: 'static + Send + Sync> SelectView<T> {
         self.with(|s| s.set_on_select(cb))
     }
 
+    /// Sets a callback to be used when an item is selected.
+    ///
+    /// The callback is given the index of the new selection, the previously
+    /// selected value (if any), and the newly selected value.
+    ///
+    /// This is called in addition to any callback set with
+    /// [`SelectView::set_on_select`].
+    #[crate::callback_helpers]
+    pub fn set_on_select_full<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, usize, Option<&T>, &T) + 'static + Send + Sync,
+    {
+        self.on_select_full = Some(Arc::new(cb));
+    }
+
+    /// Sets a callback to be used when an item is selected.
+    ///
+    /// The callback is given the index of the new selection, the previously
+    /// selected value (if any), and the newly selected value.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::SelectView;
+    ///
+    /// let select_view = SelectView::new()
+    ///     .item("One", 1)
+    ///     .item("Two", 2)
+    ///     .on_select_full(|_, index, old, new| {
+    ///         if let Some(old) = old {
+    ///             // Undo whatever was done for the previous item.
+    ///             println!("Leaving item {old}");
+    ///         }
+    ///         println!("Entering item {new} at index {index}");
+    ///     });
+    /// ```
+    #[must_use]
+    pub fn on_select_full<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, usize, Option<&T>, &T) + 'static + Send + Sync,
+    {
+        self.with(|s| s.set_on_select_full(cb))
+    }
+
+    /// Keeps a `TextView` updated with a description of the selection.
+    ///
+    /// Whenever the selection changes, or this view takes the focus, the
+    /// `TextView` named `detail_name` is given the result of `describe` for
+    /// the selected item. This is called in addition to any `on_select`
+    /// callback.
+    ///
+    /// The `TextView` can be anywhere in the view tree, for example next to
+    /// this view.
+    pub fn set_detail<F, S>(&mut self, describe: F, detail_name: S)
+    where
+        F: Fn(&T) -> StyledString + 'static + Send + Sync,
+        S: Into<String>,
+    {
+        self.detail = Some((detail_name.into(), Arc::new(describe)));
+    }
+
+    /// Keeps a `TextView` updated with a description of the selection.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::traits::Nameable;
+    /// use cursive_core::views::{LinearLayout, SelectView, TextView};
+    ///
+    /// let planets = SelectView::new()
+    ///     .item("Mercury", 0.38)
+    ///     .item("Venus", 0.91)
+    ///     .with_detail(|gravity| format!("Gravity: {gravity} g").into(), "detail");
+    ///
+    /// let layout = LinearLayout::horizontal()
+    ///     .child(planets)
+    ///     .child(TextView::new("Gravity: 0.38 g").with_name("detail"));
+    /// ```
+    #[must_use]
+    pub fn with_detail<F, S>(self, describe: F, detail_name: S) -> Self
+    where
+        F: Fn(&T) -> StyledString + 'static + Send + Sync,
+        S: Into<String>,
+    {
+        self.with(|s| s.set_detail(describe, detail_name))
+    }
+
+    /// Stops updating the detail `TextView`.
+    pub fn clear_detail(&mut self) {
+        self.detail = None;
+    }
+
     /// Sets a callback to be used when `<Enter>` is pressed.
     ///
     /// Also happens if the user clicks an item.
@@ -322,19 +843,26 @@ impl<T: 'static + Send + Sync> SelectView<T> {
 
     /// Returns the value of the currently selected item.
     ///
-    /// Returns `None` if the list is empty.
+    /// Returns `None` if the list is empty, or if the selected item is
+    /// filtered out.
     pub fn selection(&self) -> Option<Arc<T>> {
         let focus = self.focus();
-        if self.len() <= focus {
+        if self.len() <= focus || self.filter_matches(focus).is_none() {
             None
+        } else if let Some(ref provider) = self.provider {
+            Some(provider.get(focus).value)
         } else {
             Some(Arc::clone(&self.items[focus].value))
         }
     }
 
     /// Removes all items from this view.
+    ///
+    /// This also removes the provider, if any.
     pub fn clear(&mut self) {
         self.items.clear();
+        self.dividers.clear();
+        self.provider = None;
         self.focus.store(0, std::sync::atomic::Ordering::Relaxed);
         self.last_required_size = None;
     }
@@ -430,13 +958,19 @@ impl<T: 'static + Send + Sync> SelectView<T> {
     ///
     /// You should run this callback with a `&mut Cursive`.
     pub fn remove_item(&mut self, id: usize) -> Callback {
+        let previous = self.selection();
         self.items.remove(id);
+        for divider in &mut self.dividers {
+            if divider.before > id {
+                divider.before -= 1;
+            }
+        }
         self.last_required_size = None;
         let focus = self.focus();
         (focus >= id && focus > 0)
             .then(|| {
                 self.set_focus(focus - 1);
-                self.make_select_cb()
+                self.make_select_cb(previous)
             })
             .flatten()
             .unwrap_or_else(Callback::dummy)
@@ -449,6 +983,12 @@ impl<T: 'static + Send + Sync> SelectView<T> {
         S: Into<StyledString>,
     {
         self.items.insert(index, Item::new(label.into(), value));
+        // The item goes after any divider at this position.
+        for divider in &mut self.dividers {
+            if divider.before > index {
+                divider.before += 1;
+            }
+        }
         let focus = self.focus();
         // Do not increase focus if we were empty with focus=0.
         if focus >= index && !self.items.is_empty() {
@@ -507,11 +1047,218 @@ impl<T: 'static + Send + Sync> SelectView<T> {
         self.with(|s| s.add_all(iter))
     }
 
-    fn draw_item(&self, printer: &Printer, i: usize) {
-        let l = self.items[i].label.width();
+    /// Adds a separator line after the current items.
+    ///
+    /// Separators cannot be selected, and do not count as items: they don't
+    /// change the index of the items around them.
+    ///
+    /// Separators and headers are not shown in popup mode, or while a filter
+    /// query is typed.
+    pub fn add_separator(&mut self) {
+        self.add_divider(None);
+    }
+
+    /// Adds a separator line after the current items.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn separator(self) -> Self {
+        self.with(Self::add_separator)
+    }
+
+    /// Adds a header after the current items.
+    ///
+    /// Like separators, headers cannot be selected and do not count as items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::SelectView;
+    ///
+    /// let select_view = SelectView::new()
+    ///     .header("Fruits")
+    ///     .item("Apple", 1)
+    ///     .item("Banana", 2)
+    ///     .header("Vegetables")
+    ///     .item("Carrot", 3);
+    ///
+    /// assert_eq!(select_view.len(), 3);
+    /// ```
+    pub fn add_header<S: Into<StyledString>>(&mut self, label: S) {
+        self.add_divider(Some(label.into()));
+    }
+
+    /// Adds a header after the current items.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn header<S: Into<StyledString>>(self, label: S) -> Self {
+        self.with(|s| s.add_header(label))
+    }
+
+    fn add_divider(&mut self, label: Option<StyledString>) {
+        self.dividers.push(Divider {
+            before: self.items.len(),
+            label,
+        });
+        self.last_required_size = None;
+    }
+
+    /// Sets how the items are spread into columns.
+    ///
+    /// With several columns, `Left` and `Right` move the selection to the
+    /// next column. Columns are not used in popup mode or with a provider.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::{SelectColumns, SelectView};
+    ///
+    /// let select_view = SelectView::new()
+    ///     .with_all_str((1..100).map(|i| i.to_string()))
+    ///     .columns(SelectColumns::Auto);
+    /// ```
+    pub fn set_columns(&mut self, columns: SelectColumns) {
+        self.columns = columns;
+        self.last_required_size = None;
+    }
+
+    /// Sets how the items are spread into columns.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn columns(self, columns: SelectColumns) -> Self {
+        self.with(|s| s.set_columns(columns))
+    }
+
+    /// Returns how the items are spread into columns.
+    pub fn get_columns(&self) -> SelectColumns {
+        self.columns
+    }
+
+    // Returns the entries shown in the list.
+    fn rows(&self) -> Vec<Row> {
+        if self.is_filtering() && !self.filter_query.is_empty() {
+            return self
+                .filtered_items()
+                .into_iter()
+                .map(|(i, matched)| Row::Item(i, matched))
+                .collect();
+        }
+
+        let mut dividers = self.dividers.iter().enumerate().peekable();
+        let mut rows = Vec::with_capacity(self.items.len() + self.dividers.len());
+        for i in 0..=self.items.len() {
+            while let Some((d, _)) = dividers.next_if(|(_, divider)| divider.before <= i) {
+                rows.push(Row::Divider(d));
+            }
+            if i < self.items.len() {
+                rows.push(Row::Item(i, Vec::new()));
+            }
+        }
+        rows
+    }
+
+    // Width of the widest item or header.
+    fn column_width(&self) -> usize {
+        let items = self.items.iter().map(|item| item.label.width());
+        let headers = self
+            .dividers
+            .iter()
+            .filter_map(|divider| divider.label.as_ref())
+            .map(StyledString::width);
+        items.chain(headers).max().unwrap_or(1)
+    }
+
+    // Number of columns used for `rows` entries in the given width.
+    fn column_count(&self, rows: usize, width: usize) -> usize {
+        let columns = match self.columns {
+            SelectColumns::Fixed(n) => n,
+            SelectColumns::Auto => (width + COLUMN_GAP) / (self.column_width() + COLUMN_GAP),
+        };
+        columns.clamp(1, max(rows, 1))
+    }
+
+    // Returns where the entries go for the given width.
+    fn grid(&self, rows: usize, width: usize) -> Grid {
+        let columns = self.column_count(rows, width);
+        Grid {
+            columns,
+            height: (rows + columns - 1) / columns,
+            // Columns share the available width.
+            column_width: width.saturating_sub(COLUMN_GAP * (columns - 1)) / columns,
+        }
+    }
+
+    // Returns the item drawn at `position`, relative to the list.
+    fn item_at(&self, rows: &[Row], position: Vec2) -> Option<usize> {
+        if position.x >= self.last_size.x {
+            return None;
+        }
+        match rows.get(self.grid(rows.len(), self.last_size.x).index(position)?) {
+            Some(&Row::Item(i, _)) => Some(i),
+            _ => None,
+        }
+    }
+
+    // Moves the focus to the item in the next column, or in the previous one.
+    //
+    // Returns `false` if there is no such column.
+    fn focus_column(&mut self, right: bool) -> bool {
+        let rows = self.rows();
+        let grid = self.grid(rows.len(), self.last_size.x);
+        if grid.columns < 2 {
+            return false;
+        }
+
+        let focus = self.focus();
+        let Some(p) = rows
+            .iter()
+            .position(|row| matches!(row, &Row::Item(i, _) if i == focus))
+        else {
+            return false;
+        };
+
+        // Stop on the closest item if the target is a divider.
+        let target = if right {
+            rows.iter().skip(p + grid.height).find_map(Row::item)
+        } else {
+            p.checked_sub(grid.height)
+                .and_then(|target| rows[..=target].iter().rev().find_map(Row::item))
+        };
+
+        match target {
+            Some(i) => {
+                self.set_focus(i);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn draw_divider(&self, printer: &Printer, divider: &Divider) {
+        match divider.label {
+            Some(ref label) => {
+                let x = self.align.h.get_offset(label.width(), printer.size.x);
+                printer.with_effect(Effect::Bold, |printer| {
+                    printer.print_styled((x, 0), label);
+                });
+            }
+            None => printer.with_style(PaletteStyle::Secondary, |printer| {
+                printer.print_hline((0, 0), printer.size.x, "─");
+            }),
+        }
+    }
+
+    fn draw_item(&self, printer: &Printer, label: &StyledString, matched: &[usize]) {
+        let l = label.width();
         let x = self.align.h.get_offset(l, printer.size.x);
         printer.print_hline((0, 0), x, " ");
-        printer.print_styled((x, 0), &self.items[i].label);
+        if matched.is_empty() {
+            printer.print_styled((x, 0), label);
+        } else {
+            printer.print_styled((x, 0), &highlight_matches(label, matched));
+        }
         if l < printer.size.x {
             assert!((l + x) <= printer.size.x);
             printer.print_hline((x + l, 0), printer.size.x - (l + x), " ");
@@ -522,7 +1269,7 @@ impl<T: 'static + Send + Sync> SelectView<T> {
     ///
     /// Returns `None` if the list is empty.
     pub fn selected_id(&self) -> Option<usize> {
-        if self.items.is_empty() {
+        if self.is_empty() {
             None
         } else {
             Some(self.focus())
@@ -544,7 +1291,10 @@ impl<T: 'static + Send + Sync> SelectView<T> {
     /// assert_eq!(select_view.len(), 3);
     /// ```
     pub fn len(&self) -> usize {
-        self.items.len()
+        match self.provider {
+            Some(ref provider) => provider.len,
+            None => self.items.len(),
+        }
     }
 
     /// Returns `true` if this list has no item.
@@ -565,7 +1315,7 @@ impl<T: 'static + Send + Sync> SelectView<T> {
     /// assert!(select_view.is_empty());
     /// ```
     pub fn is_empty(&self) -> bool {
-        self.items.is_empty()
+        self.len() == 0
     }
 
     fn focus(&self) -> usize {
@@ -634,9 +1384,11 @@ impl<T: 'static + Send + Sync> SelectView<T> {
         } else {
             min(i, self.len() - 1)
         };
+        let previous = self.selection();
         self.set_focus(i);
 
-        self.make_select_cb().unwrap_or_else(Callback::dummy)
+        self.make_select_cb(previous)
+            .unwrap_or_else(Callback::dummy)
     }
 
     /// Sets the selection to the given position.
@@ -666,8 +1418,10 @@ impl<T: 'static + Send + Sync> SelectView<T> {
     /// }
     /// ```
     pub fn select_up(&mut self, n: usize) -> Callback {
+        let previous = self.selection();
         self.focus_up(n);
-        self.make_select_cb().unwrap_or_else(Callback::dummy)
+        self.make_select_cb(previous)
+            .unwrap_or_else(Callback::dummy)
     }
 
     /// Moves the selection down by the given number of rows.
@@ -676,8 +1430,10 @@ impl<T: 'static + Send + Sync> SelectView<T> {
     ///
     /// You should run this callback with a `&mut Cursive`.
     pub fn select_down(&mut self, n: usize) -> Callback {
+        let previous = self.selection();
         self.focus_down(n);
-        self.make_select_cb().unwrap_or_else(Callback::dummy)
+        self.make_select_cb(previous)
+            .unwrap_or_else(Callback::dummy)
     }
 
     fn focus_up(&mut self, n: usize) {
@@ -686,7 +1442,7 @@ impl<T: 'static + Send + Sync> SelectView<T> {
     }
 
     fn focus_down(&mut self, n: usize) {
-        let focus = min(self.focus() + n, self.items.len().saturating_sub(1));
+        let focus = min(self.focus() + n, self.len().saturating_sub(1));
         self.set_focus(focus);
     }
 
@@ -700,53 +1456,52 @@ impl<T: 'static + Send + Sync> SelectView<T> {
     }
 
     fn on_char_event(&mut self, c: char) -> EventResult {
-        let i = {
-            // * Starting from the current focus, find the first item that
-            //   match the char.
-            // * Cycle back to the beginning of the list when we reach the end.
-            // * This is achieved by chaining twice the iterator.
-            let iter = self.iter().chain(self.iter());
-
-            // We'll do a lowercase check.
-            let lower_c: Vec<char> = c.to_lowercase().collect();
-            let lower_c: &[char] = &lower_c;
-
-            if let Some((i, _)) = iter
-                .enumerate()
-                .skip(self.focus() + 1)
-                .find(|&(_, (label, _))| label.to_lowercase().starts_with(lower_c))
-            {
-                i % self.len()
-            } else {
-                return EventResult::Ignored;
-            }
-        };
+        let focus = self.focus();
+        let labels = self.items.iter().map(|item| item.label.source());
 
-        self.set_focus(i);
-        // Apply modulo in case we have a hit from the chained iterator
-        let cb = self.set_selection(i);
-        EventResult::Consumed(Some(cb))
+        match self.type_ahead.on_char(c, focus, labels) {
+            Some(i) => EventResult::Consumed(Some(self.set_selection(i))),
+            None => EventResult::Ignored,
+        }
     }
 
     fn on_event_regular(&mut self, event: Event) -> EventResult {
+        let previous = self.selection();
         match event {
             Event::Key(Key::Up) if self.focus() > 0 => self.focus_up(1),
-            Event::Key(Key::Down) if self.focus() + 1 < self.items.len() => self.focus_down(1),
+            Event::Key(Key::Down) if self.focus() + 1 < self.len() => self.focus_down(1),
             Event::Key(Key::PageUp) => self.focus_up(10),
             Event::Key(Key::PageDown) => self.focus_down(10),
             Event::Key(Key::Home) => self.set_focus(0),
-            Event::Key(Key::End) => self.set_focus(self.items.len().saturating_sub(1)),
+            Event::Key(Key::End) => self.set_focus(self.len().saturating_sub(1)),
+            Event::Key(Key::Left) if self.provider.is_none() && self.focus_column(false) => (),
+            Event::Key(Key::Right) if self.provider.is_none() && self.focus_column(true) => (),
             Event::Mouse {
                 event: MouseEvent::Press(_),
                 position,
                 offset,
-            } if position
-                .checked_sub(offset)
-                .map(|position| position < self.last_size && position.y < self.len())
-                .unwrap_or(false) =>
+            } if self.provider.is_some()
+                && position
+                    .checked_sub(offset)
+                    .map(|position| position < self.last_size && position.y < self.len())
+                    .unwrap_or(false) =>
             {
                 self.set_focus(position.y - offset.y)
             }
+            Event::Mouse {
+                event: MouseEvent::Press(_),
+                position,
+                offset,
+            } if self.provider.is_none() => {
+                let rows = self.rows();
+                match position
+                    .checked_sub(offset)
+                    .and_then(|position| self.item_at(&rows, position))
+                {
+                    Some(i) => self.set_focus(i),
+                    None => return EventResult::Ignored,
+                }
+            }
             Event::Mouse {
                 event: MouseEvent::Release(MouseButton::Left),
                 position,
@@ -754,29 +1509,164 @@ impl<T: 'static + Send + Sync> SelectView<T> {
             } if self.on_submit.is_some()
                 && position
                     .checked_sub(offset)
-                    .map(|position| position < self.last_size && position.y == self.focus())
-                    .unwrap_or(false) =>
+                    .and_then(|position| match self.provider {
+                        Some(_) => (position < self.last_size).then(|| position.y),
+                        None => self.item_at(&self.rows(), position),
+                    })
+                    == Some(self.focus()) =>
             {
                 return self.submit();
             }
-            Event::Key(Key::Enter) if self.on_submit.is_some() => {
+            _ if self.on_submit.is_some() && keymap::matches("submit", &event) => {
                 return self.submit();
             }
             Event::Char(c) if self.autojump => return self.on_char_event(c),
             _ => return EventResult::Ignored,
         }
 
-        EventResult::Consumed(self.make_select_cb())
+        EventResult::Consumed(self.make_select_cb(previous))
+    }
+
+    // Events in filter mode, where only the filtered items can be selected.
+    fn on_event_filter(&mut self, event: Event) -> EventResult {
+        let items: Vec<usize> = self.filtered_items().into_iter().map(|(i, _)| i).collect();
+        let position = items.iter().position(|&i| i == self.focus());
+        let previous = self.selection();
+
+        // Moves the selection to the given filtered item.
+        let select = |view: &mut Self, position: usize| {
+            if let Some(&i) = items.get(position) {
+                view.set_focus(i);
+            }
+        };
+
+        match event {
+            Event::Char(c) => {
+                return EventResult::Consumed(self.update_filter(|query| query.push(c)));
+            }
+            Event::Key(Key::Backspace) if !self.filter_query.is_empty() => {
+                return EventResult::Consumed(self.update_filter(|query| {
+                    query.pop();
+                }));
+            }
+            Event::Key(Key::Esc) if !self.filter_query.is_empty() => {
+                return EventResult::Consumed(self.update_filter(String::clear));
+            }
+            Event::Key(Key::Up) if matches!(position, Some(p) if p > 0) => {
+                select(self, position.unwrap_or(0) - 1)
+            }
+            Event::Key(Key::Down) if matches!(position, Some(p) if p + 1 < items.len()) => {
+                select(self, position.unwrap_or(0) + 1)
+            }
+            Event::Key(Key::PageUp) => select(self, position.unwrap_or(0).saturating_sub(10)),
+            Event::Key(Key::PageDown) => select(
+                self,
+                min(position.unwrap_or(0) + 10, items.len().saturating_sub(1)),
+            ),
+            Event::Key(Key::Home) => select(self, 0),
+            Event::Key(Key::End) => select(self, items.len().saturating_sub(1)),
+            Event::Key(Key::Left) if self.focus_column(false) => (),
+            Event::Key(Key::Right) if self.focus_column(true) => (),
+            Event::Mouse {
+                event: MouseEvent::Press(_),
+                position: mouse,
+                offset,
+            } => {
+                let rows = self.rows();
+                match mouse
+                    .checked_sub(offset + (0, 1))
+                    .and_then(|mouse| self.item_at(&rows, mouse))
+                {
+                    Some(i) => self.set_focus(i),
+                    None => return EventResult::Ignored,
+                }
+            }
+            Event::Mouse {
+                event: MouseEvent::Release(MouseButton::Left),
+                position: mouse,
+                offset,
+            } if self.on_submit.is_some()
+                && mouse
+                    .checked_sub(offset + (0, 1))
+                    .and_then(|mouse| self.item_at(&self.rows(), mouse))
+                    == Some(self.focus()) =>
+            {
+                return self.submit();
+            }
+            _ if self.on_submit.is_some() && keymap::matches("submit", &event) => {
+                return self.submit();
+            }
+            _ => return EventResult::Ignored,
+        }
+
+        EventResult::Consumed(self.make_select_cb(previous))
     }
 
     /// Returns a callback from selection change.
-    fn make_select_cb(&self) -> Option<Callback> {
-        self.on_select.clone().and_then(|cb| {
-            self.selection()
-                .map(|v| Callback::from_fn(move |s| cb(s, &v)))
+    ///
+    /// `previous` is the value selected before the change.
+    fn make_select_cb(&self, previous: Option<Arc<T>>) -> Option<Callback> {
+        if self.update_start.is_some() {
+            // We'll send a single callback at the end of the update.
+            return None;
+        }
+
+        if self.on_select.is_none() && self.on_select_full.is_none() && self.detail.is_none() {
+            return None;
+        }
+
+        let on_select = self.on_select.clone();
+        let on_select_full = self.on_select_full.clone();
+        let detail = self.detail.clone();
+        let index = self.focus();
+        let batch_previous = Arc::clone(&self.batch_previous);
+        // Identifies this view, to only keep its last change during a batch update.
+        let key = Arc::as_ptr(&batch_previous) as usize;
+
+        self.selection().map(|v| {
+            Callback::from_fn(move |s| {
+                let detail = detail.clone();
+                let on_select = on_select.clone();
+                let on_select_full = on_select_full.clone();
+                let v = Arc::clone(&v);
+
+                // A queued callback may be replaced: keep the first previous selection.
+                let batch_previous = Arc::clone(&batch_previous);
+                if s.is_updating() {
+                    batch_previous
+                        .lock()
+                        .unwrap()
+                        .get_or_insert_with(|| previous.clone());
+                }
+                let previous = previous.clone();
+
+                s.defer(key, move |s| {
+                    let previous = batch_previous.lock().unwrap().take().unwrap_or(previous);
+                    if let Some((ref name, ref describe)) = detail {
+                        Self::update_detail(s, name, describe(&v));
+                    }
+                    if let Some(ref cb) = on_select {
+                        cb(s, &v);
+                    }
+                    if let Some(ref cb) = on_select_full {
+                        cb(s, index, previous.as_deref(), &v);
+                    }
+                });
+            })
         })
     }
 
+    /// Returns a callback updating the detail view, if any.
+    fn make_detail_cb(&self) -> Option<Callback> {
+        let (name, describe) = self.detail.clone()?;
+        self.selection()
+            .map(|v| Callback::from_fn(move |s| Self::update_detail(s, &name, describe(&v))))
+    }
+
+    fn update_detail(s: &mut Cursive, name: &str, content: StyledString) {
+        s.call_on_name(name, |view: &mut TextView| view.set_content(content));
+    }
+
     fn open_popup(&mut self) -> EventResult {
         // Build a shallow menu tree to mimic the items array.
         // TODO: cache it?
@@ -843,7 +1733,7 @@ impl<T: 'static + Send + Sync> SelectView<T> {
     fn on_event_popup(&mut self, event: Event) -> EventResult {
         match event {
             // TODO: add Left/Right support for quick-switch?
-            Event::Key(Key::Enter) => self.open_popup(),
+            _ if keymap::matches("submit", &event) => self.open_popup(),
             Event::Mouse {
                 event: MouseEvent::Release(MouseButton::Left),
                 position,
@@ -854,6 +1744,28 @@ impl<T: 'static + Send + Sync> SelectView<T> {
         }
     }
 
+    // Returns the style of regular rows and of the selected row.
+    fn row_styles(&self, printer: &Printer) -> (StyleType, StyleType) {
+        let enabled = self.enabled && printer.enabled;
+        let active = printer.focused;
+
+        let regular_style: StyleType = if enabled {
+            Style::inherit_parent().into()
+        } else {
+            PaletteStyle::Secondary.into()
+        };
+
+        let highlight_style = if active {
+            printer.theme.focus.list
+        } else if self.inactive_highlight {
+            PaletteStyle::HighlightInactive.into()
+        } else {
+            regular_style
+        };
+
+        (regular_style, highlight_style)
+    }
+
     fn decorators_width(&self) -> usize {
         self.decorators.iter().map(|d| d.width()).sum()
     }
@@ -974,15 +1886,38 @@ impl<T: 'static + Send + Sync> View for SelectView<T> {
 
         let focus = self.focus();
 
-        if self.popup {
+        if let Some(placeholder) = self.active_placeholder() {
+            placeholder::draw(printer, placeholder);
+        } else if let Some(ref provider) = self.provider {
+            let h = provider.len;
+            let offset = self.align.v.get_offset(h, printer.size.y);
+            let printer = &printer.offset((0, offset));
+            let (regular_style, highlight_style) = self.row_styles(printer);
+
+            // Only fetch the visible items.
+            let start = min(printer.content_offset.y, h);
+            let end = min(start + printer.output_size.y, h);
+            for i in start..end {
+                let style = if i == focus {
+                    highlight_style
+                } else {
+                    regular_style
+                };
+
+                let item = provider.get(i);
+                printer.offset((0, i)).with_style(style, |printer| {
+                    self.draw_item(printer, &item.label, &[]);
+                });
+            }
+        } else if self.popup {
             // Popup-select only draw the active element.
             // We'll draw the full list in a popup if needed.
             let style = if !(self.enabled && printer.enabled) {
-                PaletteStyle::Secondary
+                PaletteStyle::Secondary.into()
             } else if printer.focused {
-                PaletteStyle::Highlight
+                printer.theme.focus.list
             } else {
-                PaletteStyle::Primary
+                PaletteStyle::Primary.into()
             };
 
             let available = match printer.size.x.checked_sub(self.decorators_width()) {
@@ -1007,63 +1942,108 @@ impl<T: 'static + Send + Sync> View for SelectView<T> {
                 }
             });
         } else {
-            // Non-popup mode: we always print the entire list.
-            let h = self.items.len();
-            let offset = self.align.v.get_offset(h, printer.size.y);
-            let printer = &printer.offset((0, offset));
-
-            let enabled = self.enabled && printer.enabled;
-            let active = printer.focused;
+            // Non-popup mode: we print the entire list, unless filtered.
+            let rows = self.rows();
 
-            let regular_style: StyleType = if enabled {
-                Style::inherit_parent().into()
-            } else {
-                PaletteStyle::Secondary.into()
-            };
+            let printer = if self.is_filtering() {
+                printer.print((0, 0), FILTER_PROMPT);
+                printer.print((FILTER_PROMPT.width(), 0), &self.filter_query);
 
-            let highlight_style = if active {
-                PaletteStyle::Highlight.into()
-            } else if self.inactive_highlight {
-                PaletteStyle::HighlightInactive.into()
+                let printer = printer.offset((0, 1));
+                if rows.is_empty() {
+                    if let Some(ref placeholder) = self.placeholder {
+                        placeholder::draw(&printer, placeholder);
+                    }
+                }
+                printer
             } else {
-                regular_style
+                printer.clone()
             };
 
-            for i in 0..self.len() {
-                let style = if i == focus {
-                    highlight_style
-                } else {
-                    regular_style
-                };
+            let grid = self.grid(rows.len(), printer.size.x);
+            let offset = self.align.v.get_offset(grid.height, printer.size.y);
+            let printer = &printer.offset((0, offset));
+            let (regular_style, highlight_style) = self.row_styles(printer);
+
+            for (p, row) in rows.iter().enumerate() {
+                let printer = printer.offset(grid.cell(p)).cropped((grid.column_width, 1));
+
+                match *row {
+                    Row::Item(i, ref matched) => {
+                        let style = if i == focus {
+                            highlight_style
+                        } else {
+                            regular_style
+                        };
+
+                        printer.with_style(style, |printer| {
+                            self.draw_item(printer, &self.items[i].label, matched);
+                        });
+                    }
+                    Row::Divider(d) => self.draw_divider(&printer, &self.dividers[d]),
+                }
+            }
 
-                printer.offset((0, i)).with_style(style, |printer| {
-                    self.draw_item(printer, i);
-                });
+            if self.show_autojump_buffer {
+                self.type_ahead.draw(printer);
             }
         }
     }
 
-    fn required_size(&mut self, _: Vec2) -> Vec2 {
+    fn required_size(&mut self, constraint: Vec2) -> Vec2 {
+        if let Some(placeholder) = self.active_placeholder() {
+            // This depends on the constraint, so don't cache it.
+            return placeholder::required_size(placeholder, constraint);
+        }
+
+        if let Some(ref provider) = self.provider {
+            // Measure at least the first rows, the others are measured when drawn.
+            for i in 0..min(constraint.y, provider.len) {
+                provider.get(i);
+            }
+            let w = provider
+                .max_width
+                .load(std::sync::atomic::Ordering::Relaxed);
+
+            return Vec2::new(max(w, 1), provider.len);
+        }
+
         if let Some(s) = self.last_required_size {
             return s;
         }
         // Items here are not compressible.
         // So no matter what the horizontal requirements are,
         // we'll still return our longest item.
-        let w = self
-            .items
-            .iter()
-            .map(|item| item.label.width())
-            .max()
-            .unwrap_or(1);
-        let size = if self.popup {
-            Vec2::new(w + self.decorators_width(), 1)
-        } else {
-            let h = self.items.len();
+        if self.popup {
+            let w = self
+                .items
+                .iter()
+                .map(|item| item.label.width())
+                .max()
+                .unwrap_or(1);
+            let size = Vec2::new(w + self.decorators_width(), 1);
+            self.last_required_size = Some(size);
+            return size;
+        }
 
-            Vec2::new(w, h)
-        };
-        self.last_required_size = Some(size);
+        // Keep the same size while filtering.
+        let w = self.column_width();
+        let rows = self.items.len() + self.dividers.len();
+        let columns = self.column_count(rows, constraint.x);
+        let mut size = Vec2::new(
+            columns * w + COLUMN_GAP * (columns - 1),
+            (rows + columns - 1) / columns,
+        );
+
+        if self.filterable {
+            // Leave room for the input line.
+            size = Vec2::new(max(size.x, FILTER_PROMPT.width() + 1), size.y + 1);
+        }
+
+        // With automatic columns, the size depends on the constraint.
+        if self.columns != SelectColumns::Auto {
+            self.last_required_size = Some(size);
+        }
         size
     }
 
@@ -1072,28 +2052,32 @@ impl<T: 'static + Send + Sync> View for SelectView<T> {
             return EventResult::Ignored;
         }
 
-        if self.popup {
+        if self.provider.is_some() {
+            self.on_event_regular(event)
+        } else if self.popup {
             self.on_event_popup(event)
+        } else if self.filterable {
+            self.on_event_filter(event)
         } else {
             self.on_event_regular(event)
         }
     }
 
     fn take_focus(&mut self, source: direction::Direction) -> Result<EventResult, CannotFocus> {
-        (self.enabled && !self.items.is_empty())
+        (self.enabled && !self.is_empty())
             .then(|| {
-                if !self.popup {
+                if !self.popup || self.provider.is_some() {
                     match source {
                         direction::Direction::Abs(direction::Absolute::Up) => {
                             self.set_focus(0);
                         }
                         direction::Direction::Abs(direction::Absolute::Down) => {
-                            self.set_focus(self.items.len().saturating_sub(1));
+                            self.set_focus(self.len().saturating_sub(1));
                         }
                         _ => (),
                     }
                 }
-                EventResult::Consumed(None)
+                EventResult::Consumed(self.make_detail_cb())
             })
             .ok_or(CannotFocus)
     }
@@ -1103,8 +2087,21 @@ impl<T: 'static + Send + Sync> View for SelectView<T> {
     }
 
     fn important_area(&self, size: Vec2) -> Rect {
-        self.selected_id()
-            .map(|i| Rect::from_size((0, i), (size.x, 1)))
+        if self.provider.is_some() || self.popup {
+            return self
+                .selected_id()
+                .map(|y| Rect::from_size((0, y), (size.x, 1)))
+                .unwrap_or_else(|| Rect::from_size(Vec2::zero(), size));
+        }
+
+        let focus = self.focus();
+        let rows = self.rows();
+        let grid = self.grid(rows.len(), size.x);
+        let top = if self.is_filtering() { 1 } else { 0 };
+
+        rows.iter()
+            .position(|row| matches!(row, &Row::Item(i, _) if i == focus))
+            .map(|p| Rect::from_size(grid.cell(p) + (0, top), (grid.column_width, 1)))
             .unwrap_or_else(|| Rect::from_size(Vec2::zero(), size))
     }
 }
@@ -1122,10 +2119,157 @@ impl<T> Item<T> {
     }
 }
 
+// Not derived, to avoid requiring `T: Clone`.
+impl<T> Clone for Item<T> {
+    fn clone(&self) -> Self {
+        Item {
+            label: self.label.clone(),
+            value: Arc::clone(&self.value),
+        }
+    }
+}
+
+// A separator or a header, shown before the item at index `before`.
+struct Divider {
+    before: usize,
+    label: Option<StyledString>,
+}
+
+// An entry in the list.
+enum Row {
+    // An item, with the characters matched by the filter query.
+    Item(usize, Vec<usize>),
+
+    // The index of a divider.
+    Divider(usize),
+}
+
+impl Row {
+    fn item(&self) -> Option<usize> {
+        match *self {
+            Row::Item(i, _) => Some(i),
+            Row::Divider(_) => None,
+        }
+    }
+}
+
+// Where the entries of the list are drawn.
+struct Grid {
+    columns: usize,
+
+    // Number of entries in each column.
+    height: usize,
+
+    column_width: usize,
+}
+
+impl Grid {
+    // Returns the position of the entry at index `p`.
+    fn cell(&self, p: usize) -> Vec2 {
+        Vec2::new(
+            (p / self.height) * (self.column_width + COLUMN_GAP),
+            p % self.height,
+        )
+    }
+
+    // Returns the index of the entry at `position`, if any.
+    fn index(&self, position: Vec2) -> Option<usize> {
+        let column = position.x / (self.column_width + COLUMN_GAP);
+        let in_gap = position.x % (self.column_width + COLUMN_GAP) >= self.column_width;
+        (column < self.columns && position.y < self.height && !in_gap)
+            .then(|| column * self.height + position.y)
+    }
+}
+
+// Maximum number of items kept from a provider.
+const PROVIDER_CACHE_SIZE: usize = 1024;
+
+// Items fetched on demand.
+struct Provider<T> {
+    len: usize,
+    fetch: Box<ItemProvider<T>>,
+
+    // Items fetched recently, for the visible rows and the selection.
+    cache: Mutex<HashMap<usize, Item<T>>>,
+
+    // Width of the largest label fetched so far.
+    max_width: AtomicUsize,
+}
+
+impl<T> Provider<T> {
+    fn new(len: usize, fetch: Box<ItemProvider<T>>) -> Self {
+        Provider {
+            len,
+            fetch,
+            cache: Mutex::new(HashMap::new()),
+            max_width: AtomicUsize::new(0),
+        }
+    }
+
+    fn reset(&mut self, len: usize) {
+        self.len = len;
+        self.cache.get_mut().unwrap().clear();
+        *self.max_width.get_mut() = 0;
+    }
+
+    // Returns the item at index `i`, fetching it if needed.
+    fn get(&self, i: usize) -> Item<T> {
+        let mut cache = self.cache.lock().unwrap();
+        if let Some(item) = cache.get(&i) {
+            return item.clone();
+        }
+
+        let (label, value) = (self.fetch)(i);
+        self.max_width
+            .fetch_max(label.width(), std::sync::atomic::Ordering::Relaxed);
+
+        // Start again rather than tracking which items are the oldest.
+        if cache.len() >= PROVIDER_CACHE_SIZE {
+            cache.clear();
+        }
+
+        let item = Item::new(label, value);
+        cache.insert(i, item.clone());
+        item
+    }
+}
+
+// Underlines the characters at the given byte offsets in the label text.
+fn highlight_matches(label: &StyledString, matched: &[usize]) -> StyledString {
+    let mut result = StyledString::new();
+    let mut offset = 0;
+
+    for span in label.spans() {
+        let content = span.content;
+        let mut start = 0;
+        let mut highlighted = false;
+        for (i, _) in content.char_indices().chain(Some((content.len(), ' '))) {
+            let is_match = i < content.len() && matched.contains(&(offset + i));
+            if start < i && (is_match != highlighted || i == content.len()) {
+                let style = if highlighted {
+                    span.attr.combine(Effect::Underline)
+                } else {
+                    *span.attr
+                };
+                result.append_styled(&content[start..i], style);
+                start = i;
+            }
+            highlighted = is_match;
+        }
+        offset += content.len();
+    }
+
+    result
+}
+
 #[crate::blueprint(SelectView::<String>::new())]
 struct Blueprint {
     autojump: Option<bool>,
+    show_autojump_buffer: Option<bool>,
     popup: Option<bool>,
+    filterable: Option<bool>,
+    placeholder: Option<StyledString>,
+    columns: Option<SelectColumns>,
 
     on_select: Option<_>,
 
@@ -1137,6 +2281,22 @@ struct Blueprint {
 mod tests {
     use super::*;
 
+    #[test]
+    fn autojump_prefix() {
+        let mut view = SelectView::new().autojump();
+        view.add_all_str(["Apple", "Banana", "Apricot", "Blueberry"]);
+
+        for c in "apr".chars() {
+            assert!(view.on_event(Event::Char(c)).is_consumed());
+        }
+        assert_eq!(view.selected_id(), Some(2));
+        assert_eq!(view.autojump_buffer(), "apr");
+
+        // Nothing starts with "apz", so the selection does not change.
+        assert!(!view.on_event(Event::Char('z')).is_consumed());
+        assert_eq!(view.selected_id(), Some(2));
+    }
+
     #[test]
     fn select_view_sorting() {
         // We add items in no particular order, from going by their label.
@@ -1229,4 +2389,243 @@ mod tests {
         view.on_event(Event::Key(Key::Down));
         assert_eq!(view.selection(), Some(Arc::new(3)));
     }
+
+    #[test]
+    fn detail() {
+        use crate::traits::Nameable;
+        use crate::views::LinearLayout;
+
+        let mut siv = Cursive::new();
+        siv.add_layer(
+            LinearLayout::horizontal()
+                .child(
+                    SelectView::new()
+                        .item("One", 1)
+                        .item("Two", 2)
+                        .with_detail(|n| format!("Item {n}").into(), "detail"),
+                )
+                .child(TextView::new("").with_name("detail")),
+        );
+        let detail = |siv: &mut Cursive| {
+            siv.call_on_name("detail", |v: &mut TextView| {
+                v.get_content().source().to_string()
+            })
+        };
+
+        siv.on_event(Event::Key(Key::Down));
+        assert_eq!(detail(&mut siv).as_deref(), Some("Item 2"));
+    }
+
+    #[test]
+    fn select_view_on_select_full() {
+        let changes = Arc::new(Mutex::new(Vec::new()));
+
+        let mut view = SelectView::new()
+            .item_str("X")
+            .item_str("Y")
+            .item_str("Z")
+            .on_select_full({
+                let changes = Arc::clone(&changes);
+                move |_, i, old: Option<&String>, new: &String| {
+                    changes.lock().unwrap().push((i, old.cloned(), new.clone()));
+                }
+            });
+
+        let mut siv = Cursive::new();
+
+        if let EventResult::Consumed(Some(cb)) = view.on_event(Event::Key(Key::Down)) {
+            cb(&mut siv);
+        }
+        view.set_selection(2)(&mut siv);
+
+        assert_eq!(
+            *changes.lock().unwrap(),
+            vec![
+                (1, Some("X".to_string()), "Y".to_string()),
+                (2, Some("Y".to_string()), "Z".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn select_view_batch_update() {
+        let changes = Arc::new(Mutex::new(Vec::new()));
+
+        let mut view = SelectView::new().item_str("X").on_select_full({
+            let changes = Arc::clone(&changes);
+            move |_, i, old: Option<&String>, new: &String| {
+                changes.lock().unwrap().push((i, old.cloned(), new.clone()));
+            }
+        });
+
+        let mut siv = Cursive::new();
+
+        view.begin_update();
+        for i in 0..100 {
+            view.add_item_str(i.to_string());
+            view.select_down(1)(&mut siv);
+        }
+        assert!(changes.lock().unwrap().is_empty());
+
+        view.end_update()(&mut siv);
+        assert_eq!(
+            *changes.lock().unwrap(),
+            vec![(100, Some("X".to_string()), "99".to_string())]
+        );
+    }
+
+    #[test]
+    fn placeholder() {
+        let mut view = SelectView::<String>::new().placeholder("No results");
+        assert_eq!(view.required_size(Vec2::new(20, 5)), Vec2::new(10, 1));
+
+        // Wrapped if needed.
+        assert_eq!(view.required_size(Vec2::new(8, 5)), Vec2::new(7, 2));
+
+        view.add_item_str("Item");
+        assert_eq!(view.required_size(Vec2::new(20, 5)), Vec2::new(4, 1));
+    }
+
+    #[test]
+    fn filter() {
+        let mut view = SelectView::new()
+            .with_all_str(["Apple", "Banana", "Apricot", "Blueberry"])
+            .filterable(true)
+            .selected(1);
+
+        // Filtering out the selection selects the first match.
+        for c in "ap".chars() {
+            assert!(view.on_event(Event::Char(c)).is_consumed());
+        }
+        assert_eq!(view.get_filter_query(), "ap");
+        assert_eq!(view.selected_id(), Some(0));
+
+        // Navigation skips hidden items.
+        view.on_event(Event::Key(Key::Down));
+        assert_eq!(
+            view.selection().as_deref().map(String::as_str),
+            Some("Apricot")
+        );
+        assert!(!view.on_event(Event::Key(Key::Down)).is_consumed());
+
+        view.set_filter_matcher(matching::fuzzy);
+        view.set_filter_query("bry");
+        assert_eq!(
+            view.selection().as_deref().map(String::as_str),
+            Some("Blueberry")
+        );
+
+        view.on_event(Event::Key(Key::Esc));
+        assert_eq!(view.get_filter_query(), "");
+        assert_eq!(view.selected_id(), Some(3));
+    }
+
+    #[test]
+    fn filter_highlight() {
+        let label = StyledString::plain("Banana");
+        let highlighted = highlight_matches(&label, &[0, 2, 3]);
+
+        let underlined: Vec<&str> = highlighted
+            .spans()
+            .filter(|span| *span.attr == Effect::Underline.into())
+            .map(|span| span.content)
+            .collect();
+        assert_eq!(underlined, ["B", "na"]);
+        assert_eq!(highlighted.source(), "Banana");
+    }
+
+    #[test]
+    fn provider() {
+        let fetched = Arc::new(AtomicUsize::new(0));
+        let mut view = SelectView::with_provider(100_000, {
+            let fetched = Arc::clone(&fetched);
+            move |i| {
+                fetched.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                (format!("Item {i}").into(), i)
+            }
+        });
+        assert_eq!(view.len(), 100_000);
+
+        // Only the first rows are measured.
+        assert_eq!(view.required_size(Vec2::new(80, 10)), Vec2::new(6, 100_000));
+        assert_eq!(fetched.load(std::sync::atomic::Ordering::Relaxed), 10);
+
+        view.on_event(Event::Key(Key::End));
+        assert_eq!(view.selection().as_deref(), Some(&99_999));
+        assert_eq!(
+            view.required_size(Vec2::new(80, 10)),
+            Vec2::new(10, 100_000)
+        );
+        assert_eq!(fetched.load(std::sync::atomic::Ordering::Relaxed), 11);
+
+        view.set_provider_len(5);
+        assert_eq!(view.selection().as_deref(), Some(&4));
+
+        view.clear();
+        assert!(view.is_empty());
+        assert_eq!(view.selection(), None);
+    }
+
+    #[test]
+    fn columns() {
+        let mut view = SelectView::new()
+            .with_all_str((1..=7).map(|i| format!("Item {i}")))
+            .columns(SelectColumns::Fixed(3));
+
+        // 3 columns of 6 characters, with 2 spaces in between.
+        assert_eq!(view.required_size(Vec2::new(80, 10)), Vec2::new(22, 3));
+        view.layout(Vec2::new(22, 3));
+
+        // Items 0-2 in the first column, 3-5 in the second, 6 in the third.
+        view.on_event(Event::Key(Key::Right));
+        assert_eq!(view.selected_id(), Some(3));
+        view.on_event(Event::Key(Key::Right));
+        assert_eq!(view.selected_id(), Some(6));
+        assert!(!view.on_event(Event::Key(Key::Right)).is_consumed());
+        view.on_event(Event::Key(Key::Left));
+        assert_eq!(view.selected_id(), Some(3));
+
+        let click = |x, y| Event::Mouse {
+            event: MouseEvent::Press(MouseButton::Left),
+            position: Vec2::new(x, y),
+            offset: Vec2::zero(),
+        };
+        view.on_event(click(17, 0));
+        assert_eq!(view.selected_id(), Some(6));
+        // Clicks between columns are ignored.
+        assert!(!view.on_event(click(6, 1)).is_consumed());
+        assert_eq!(view.selected_id(), Some(6));
+
+        view.set_columns(SelectColumns::Auto);
+        assert_eq!(view.required_size(Vec2::new(14, 10)), Vec2::new(14, 4));
+        assert_eq!(view.required_size(Vec2::new(13, 10)), Vec2::new(6, 7));
+    }
+
+    #[test]
+    fn dividers() {
+        let mut view = SelectView::new()
+            .header("Fruits")
+            .item_str("Apple")
+            .item_str("Banana")
+            .separator()
+            .item_str("Carrot");
+
+        assert_eq!(view.len(), 3);
+        assert_eq!(view.required_size(Vec2::new(80, 10)), Vec2::new(6, 5));
+        view.layout(Vec2::new(6, 5));
+
+        // Dividers are skipped.
+        view.on_event(Event::Key(Key::Down));
+        view.on_event(Event::Key(Key::Down));
+        assert_eq!(
+            view.selection().as_deref().map(String::as_str),
+            Some("Carrot")
+        );
+        assert_eq!(view.important_area(Vec2::new(6, 5)).top(), 4);
+
+        view.insert_item_str(2, "Cherry");
+        view.remove_item(0);
+        let rows: Vec<Option<usize>> = view.rows().iter().map(Row::item).collect();
+        assert_eq!(rows, [None, Some(0), None, Some(1), Some(2)]);
+    }
 }
@@ -1,7 +1,7 @@
 use crate::event::{Event, EventResult};
 use crate::rect::Rect;
 use crate::style::PaletteStyle;
-use crate::view::{View, ViewWrapper};
+use crate::view::{Selector, View, ViewNotFound, ViewWrapper};
 use crate::Printer;
 use crate::Vec2;
 
@@ -112,6 +112,24 @@ impl<T: View> ViewWrapper for ShadowView<T> {
             .important_area(view_size.saturating_sub(self.padding()))
             + self.top_left_padding()
     }
+
+    fn wrap_dirty_area(&self, view_size: Vec2) -> Option<Rect> {
+        self.view
+            .dirty_area(view_size.saturating_sub(self.padding()))
+            .map(|area| area + self.top_left_padding())
+    }
+
+    fn wrap_reveal_view(
+        &mut self,
+        selector: &Selector,
+        view_size: Vec2,
+    ) -> Result<Rect, ViewNotFound> {
+        let padding = self.padding();
+        let top_left = self.top_left_padding();
+        self.view
+            .reveal_view(selector, view_size.saturating_sub(padding))
+            .map(|area| area + top_left)
+    }
 }
 
 #[crate::blueprint(ShadowView::new(view))]
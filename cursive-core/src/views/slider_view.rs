@@ -5,12 +5,16 @@ use crate::{
     view::{CannotFocus, View},
     Cursive, Printer, Vec2, With,
 };
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 type SliderCallback = dyn Fn(&mut Cursive, usize) + Send + Sync;
 
 /// A horizontal or vertical slider.
 ///
+/// The knob can be moved with the arrow keys, or with the mouse: click to jump to a position,
+/// drag it, or use the scroll wheel to move it by one step. While dragging, `on_change` is called
+/// at most once per frame, with the latest value.
+///
 /// # Examples
 ///
 /// ```
@@ -34,6 +38,8 @@ pub struct SliderView {
     value: usize,
     max_value: usize,
     dragging: bool,
+    // Latest value from the mouse, waiting for its `on_change` call.
+    pending_change: Arc<Mutex<Option<usize>>>,
 }
 
 impl SliderView {
@@ -51,6 +57,7 @@ impl SliderView {
             on_change: None,
             on_enter: None,
             dragging: false,
+            pending_change: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -140,6 +147,39 @@ impl SliderView {
         }))
     }
 
+    // Moves the knob to the given value, following the mouse.
+    //
+    // Mouse events come in bursts while dragging, so `on_change` goes through the cb sink: it
+    // runs once all the input of this frame was handled, and only sees the latest value.
+    fn slide_to(&mut self, value: usize) -> EventResult {
+        let value = std::cmp::min(value, self.max_value.saturating_sub(1));
+        if value == self.value {
+            return EventResult::Consumed(None);
+        }
+        self.value = value;
+
+        let Some(cb) = self.on_change.clone() else {
+            return EventResult::Consumed(None);
+        };
+        let pending_change = Arc::clone(&self.pending_change);
+        EventResult::with_cb(move |s| {
+            if pending_change.lock().unwrap().replace(value).is_some() {
+                // A call is already queued for this frame.
+                return;
+            }
+
+            let cb = Arc::clone(&cb);
+            let pending_change = Arc::clone(&pending_change);
+            s.cb_sink()
+                .send(Box::new(move |s| {
+                    if let Some(value) = pending_change.lock().unwrap().take() {
+                        cb(s, value);
+                    }
+                }))
+                .unwrap();
+        })
+    }
+
     fn slide_plus(&mut self) -> EventResult {
         if self.value + 1 < self.max_value {
             self.value += 1;
@@ -208,21 +248,44 @@ impl View for SliderView {
                 offset,
             } if self.dragging => {
                 let position = position.saturating_sub(offset);
-                let position = self.orientation.get(&position);
-                let position = ::std::cmp::min(position, self.max_value.saturating_sub(1));
-                self.value = position;
-                self.get_change_result()
+                self.slide_to(self.orientation.get(&position))
             }
             Event::Mouse {
                 event: MouseEvent::Press(MouseButton::Left),
                 position,
                 offset,
             } if position.fits_in_rect(offset, self.req_size()) => {
-                if let Some(position) = position.checked_sub(offset) {
-                    self.dragging = true;
-                    self.value = self.orientation.get(&position);
-                }
-                self.get_change_result()
+                self.dragging = true;
+                let position = position.saturating_sub(offset);
+                self.slide_to(self.orientation.get(&position))
+            }
+            Event::Mouse {
+                event: MouseEvent::WheelUp,
+                position,
+                offset,
+            } if position.fits_in_rect(offset, self.req_size()) => self.slide_minus(),
+            Event::Mouse {
+                event: MouseEvent::WheelDown,
+                position,
+                offset,
+            } if position.fits_in_rect(offset, self.req_size()) => self.slide_plus(),
+            Event::Mouse {
+                event: MouseEvent::WheelLeft,
+                position,
+                offset,
+            } if self.orientation == Orientation::Horizontal
+                && position.fits_in_rect(offset, self.req_size()) =>
+            {
+                self.slide_minus()
+            }
+            Event::Mouse {
+                event: MouseEvent::WheelRight,
+                position,
+                offset,
+            } if self.orientation == Orientation::Horizontal
+                && position.fits_in_rect(offset, self.req_size()) =>
+            {
+                self.slide_plus()
             }
             Event::Mouse {
                 event: MouseEvent::Release(MouseButton::Left),
@@ -249,3 +312,112 @@ struct Blueprint {
     on_change: Option<_>,
     on_enter: Option<_>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mouse(event: MouseEvent, x: usize) -> Event {
+        Event::Mouse {
+            offset: Vec2::new(2, 1),
+            position: Vec2::new(2 + x, 1),
+            event,
+        }
+    }
+
+    fn has_callback(result: &EventResult) -> bool {
+        matches!(result, EventResult::Consumed(Some(_)))
+    }
+
+    #[test]
+    fn mouse_input() {
+        let mut slider = SliderView::horizontal(10).on_change(|_, _| ());
+
+        // Click to jump to a position.
+        let result = slider.on_event(mouse(MouseEvent::Press(MouseButton::Left), 3));
+        assert!(has_callback(&result));
+        assert_eq!(slider.get_value(), 3);
+
+        // Drag the knob, even outside the slider.
+        assert!(has_callback(
+            &slider.on_event(mouse(MouseEvent::Hold(MouseButton::Left), 5))
+        ));
+        assert_eq!(slider.get_value(), 5);
+        assert!(has_callback(
+            &slider.on_event(mouse(MouseEvent::Hold(MouseButton::Left), 20))
+        ));
+        assert_eq!(slider.get_value(), 9);
+
+        // No callback when the value doesn't change.
+        let result = slider.on_event(mouse(MouseEvent::Hold(MouseButton::Left), 15));
+        assert!(result.is_consumed() && !has_callback(&result));
+
+        slider.on_event(mouse(MouseEvent::Release(MouseButton::Left), 15));
+        assert!(!slider
+            .on_event(mouse(MouseEvent::Hold(MouseButton::Left), 2))
+            .is_consumed());
+        assert_eq!(slider.get_value(), 9);
+
+        // Scroll wheel moves by one step.
+        assert!(has_callback(
+            &slider.on_event(mouse(MouseEvent::WheelUp, 0))
+        ));
+        assert_eq!(slider.get_value(), 8);
+        slider.on_event(mouse(MouseEvent::WheelDown, 0));
+        assert_eq!(slider.get_value(), 9);
+        assert!(!slider
+            .on_event(mouse(MouseEvent::WheelDown, 0))
+            .is_consumed());
+        assert!(!slider
+            .on_event(mouse(MouseEvent::WheelUp, 12))
+            .is_consumed());
+
+        // Horizontal scrolling only moves horizontal sliders.
+        slider.on_event(mouse(MouseEvent::WheelLeft, 0));
+        assert_eq!(slider.get_value(), 8);
+        slider.on_event(mouse(MouseEvent::WheelRight, 0));
+        assert_eq!(slider.get_value(), 9);
+
+        let mut slider = SliderView::vertical(10);
+        assert!(!slider
+            .on_event(mouse(MouseEvent::WheelRight, 0))
+            .is_consumed());
+        assert_eq!(slider.get_value(), 0);
+    }
+
+    #[test]
+    fn drag_once_per_frame() {
+        let values = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&values);
+        let mut slider =
+            SliderView::horizontal(10).on_change(move |_, n| seen.lock().unwrap().push(n));
+
+        let mut siv = Cursive::new();
+        let events = [
+            mouse(MouseEvent::Press(MouseButton::Left), 3),
+            mouse(MouseEvent::Hold(MouseButton::Left), 4),
+            mouse(MouseEvent::Hold(MouseButton::Left), 5),
+            // Back to the same value: nothing to report.
+            mouse(MouseEvent::Hold(MouseButton::Left), 5),
+        ];
+        for event in events {
+            if let EventResult::Consumed(Some(cb)) = slider.on_event(event) {
+                cb(&mut siv);
+            }
+        }
+        while siv.process_callback() {}
+        assert_eq!(*values.lock().unwrap(), [5]);
+
+        // The next frame gets its own call.
+        let result = slider.on_event(mouse(MouseEvent::Hold(MouseButton::Left), 7));
+        if let EventResult::Consumed(Some(cb)) = result {
+            cb(&mut siv);
+        }
+        while siv.process_callback() {}
+        assert_eq!(*values.lock().unwrap(), [5, 7]);
+
+        slider.on_event(mouse(MouseEvent::Hold(MouseButton::Left), 7));
+        while siv.process_callback() {}
+        assert_eq!(*values.lock().unwrap(), [5, 7]);
+    }
+}
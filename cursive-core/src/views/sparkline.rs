@@ -0,0 +1,609 @@
+use crate::style::{gradient::Linear, ColorStyle, ColorType, PaletteColor};
+use crate::view::View;
+use crate::{Printer, Vec2, With};
+use parking_lot::Mutex;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use unicode_width::UnicodeWidthStr;
+
+// Turns a value into its label.
+pub(super) type Formatter = dyn Fn(f64) -> String + Send + Sync;
+
+/// Shared series of values, displayed by a [`Sparkline`] or a [`BarChart`].
+///
+/// Cloning this object will still point to the same series: values can be
+/// added from another thread while the view is displayed.
+///
+/// [`BarChart`]: crate::views::BarChart
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::{SeriesContent, Sparkline};
+///
+/// let content = SeriesContent::new([1.0, 4.0, 2.0]);
+/// content.set_max_len(60);
+///
+/// let view = Sparkline::new_with_content(content.clone());
+///
+/// // Later, possibly in a different thread
+/// content.push(8.0);
+/// assert_eq!(content.values(), [1.0, 4.0, 2.0, 8.0]);
+/// ```
+#[derive(Clone, Default)]
+pub struct SeriesContent {
+    content: Arc<Mutex<SeriesContentInner>>,
+}
+
+#[derive(Default)]
+struct SeriesContentInner {
+    values: VecDeque<f64>,
+
+    // If set, the oldest values are dropped beyond this.
+    max_len: Option<usize>,
+}
+
+impl SeriesContentInner {
+    fn truncate(&mut self) {
+        if let Some(max_len) = self.max_len {
+            let extra = self.values.len().saturating_sub(max_len);
+            self.values.drain(..extra);
+        }
+    }
+}
+
+impl SeriesContent {
+    /// Creates a new series with the given values.
+    pub fn new<I: IntoIterator<Item = f64>>(values: I) -> Self {
+        Self::default().with(|s| s.set_values(values))
+    }
+
+    /// Replaces all the values.
+    pub fn set_values<I: IntoIterator<Item = f64>>(&self, values: I) {
+        let mut content = self.content.lock();
+        content.values = values.into_iter().collect();
+        content.truncate();
+    }
+
+    /// Adds a value at the end of the series.
+    ///
+    /// If a maximum length is set, the oldest value may be dropped.
+    pub fn push(&self, value: f64) {
+        let mut content = self.content.lock();
+        content.values.push_back(value);
+        content.truncate();
+    }
+
+    /// Removes all the values.
+    pub fn clear(&self) {
+        self.content.lock().values.clear();
+    }
+
+    /// Keeps at most `max_len` values, dropping the oldest ones.
+    pub fn set_max_len(&self, max_len: usize) {
+        let mut content = self.content.lock();
+        content.max_len = Some(max_len);
+        content.truncate();
+    }
+
+    /// Removes the maximum length set by `set_max_len`.
+    pub fn clear_max_len(&self) {
+        self.content.lock().max_len = None;
+    }
+
+    /// Returns the maximum length, if any.
+    pub fn max_len(&self) -> Option<usize> {
+        self.content.lock().max_len
+    }
+
+    /// Returns the number of values in the series.
+    pub fn len(&self) -> usize {
+        self.content.lock().values.len()
+    }
+
+    /// Returns `true` if the series has no value.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a copy of the values.
+    pub fn values(&self) -> Vec<f64> {
+        self.with_values(<[f64]>::to_vec)
+    }
+
+    /// Runs a closure on the values, without copying them.
+    pub fn with_values<F, O>(&self, f: F) -> O
+    where
+        F: FnOnce(&[f64]) -> O,
+    {
+        f(self.content.lock().values.make_contiguous())
+    }
+}
+
+/// Characters used to draw the values of a [`Sparkline`] or a [`BarChart`].
+///
+/// [`BarChart`]: crate::views::BarChart
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ChartGlyphs {
+    /// Eighth blocks (`▁`, `▂`, ...), filling each cell in 8 steps.
+    #[default]
+    Blocks,
+
+    /// Braille patterns, filling each cell in 4 steps.
+    ///
+    /// A sparkline fits two values in each cell.
+    Braille,
+}
+
+impl ChartGlyphs {
+    // Number of steps to fill a cell.
+    pub(super) fn resolution(self) -> usize {
+        match self {
+            ChartGlyphs::Blocks => 8,
+            ChartGlyphs::Braille => 4,
+        }
+    }
+
+    // Glyph for a cell with its left and right halves filled up to the given
+    // steps. Blocks only use the left one.
+    pub(super) fn glyph(self, left: usize, right: usize) -> char {
+        match self {
+            ChartGlyphs::Blocks => [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'][left],
+            ChartGlyphs::Braille => {
+                // Dots of each column, from the bottom up.
+                const LEFT: [u32; 4] = [0x40, 0x04, 0x02, 0x01];
+                const RIGHT: [u32; 4] = [0x80, 0x20, 0x10, 0x08];
+                let dots: u32 = LEFT[..left].iter().chain(&RIGHT[..right]).sum();
+                char::from_u32(0x2800 + dots).unwrap_or(' ')
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for ChartGlyphs {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Blocks" | "blocks" => Self::Blocks,
+            "Braille" | "braille" => Self::Braille,
+            _ => return Err(()),
+        })
+    }
+}
+
+// Bounds used to scale the values: the given ones, or the values' own range,
+// always including 0.
+pub(super) fn value_range(values: &[f64], min: Option<f64>, max: Option<f64>) -> (f64, f64) {
+    let min = min.unwrap_or_else(|| values.iter().copied().fold(0.0, f64::min));
+    let max = max.unwrap_or_else(|| values.iter().copied().fold(0.0, f64::max));
+    (min, max)
+}
+
+// Position of `value` between `min` and `max`, from 0 to 1.
+pub(super) fn ratio(value: f64, (min, max): (f64, f64)) -> f64 {
+    if max <= min {
+        return if value > min { 1.0 } else { 0.0 };
+    }
+    ((value - min) / (max - min)).clamp(0.0, 1.0)
+}
+
+// Steps filled in a column of `height` cells, for a value at `ratio`.
+pub(super) fn column_steps(ratio: f64, height: usize, resolution: usize) -> usize {
+    (ratio * (height * resolution) as f64).round() as usize
+}
+
+// Steps filled in the cell `row` (counted from the bottom) of a column.
+pub(super) fn cell_steps(steps: usize, row: usize, resolution: usize) -> usize {
+    steps.saturating_sub(row * resolution).min(resolution)
+}
+
+// Color used for a value at `ratio`.
+pub(super) fn value_color(gradient: Option<&Linear>, color: ColorType, ratio: f64) -> ColorStyle {
+    match gradient {
+        Some(gradient) => ColorStyle::front(gradient.interpolate(ratio as f32).as_color()),
+        None => ColorStyle::front(color),
+    }
+}
+
+// Default label for a value: integers are printed as such, with a single
+// decimal otherwise.
+pub(super) fn format_value(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.1}")
+    }
+}
+
+/// Compact chart showing the evolution of a series of values.
+///
+/// Each value is drawn as a small column, from left to right. When there are
+/// more values than columns, only the most recent ones are shown.
+///
+/// Values are scaled between 0 and the maximum value shown, unless a range
+/// is set.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::style::{gradient::Linear, Rgb};
+/// use cursive_core::views::Sparkline;
+///
+/// let mut cpu = Sparkline::new()
+///     .height(2)
+///     .range(0.0, 100.0)
+///     .show_range(true)
+///     .gradient(Linear::simple(Rgb::green(), Rgb::red()));
+///
+/// let content = cpu.get_shared_content();
+/// content.push(12.0);
+/// content.push(56.5);
+/// ```
+pub struct Sparkline {
+    content: SeriesContent,
+    height: usize,
+    glyphs: ChartGlyphs,
+    color: ColorType,
+    gradient: Option<Linear>,
+    min: Option<f64>,
+    max: Option<f64>,
+    show_range: bool,
+    formatter: Box<Formatter>,
+}
+
+new_default!(Sparkline);
+
+impl Sparkline {
+    /// Creates a new empty sparkline, one row high.
+    pub fn new() -> Self {
+        Self::new_with_content(SeriesContent::default())
+    }
+
+    /// Creates a new sparkline showing the given shared series.
+    pub fn new_with_content(content: SeriesContent) -> Self {
+        Sparkline {
+            content,
+            height: 1,
+            glyphs: ChartGlyphs::Blocks,
+            color: PaletteColor::Highlight.into(),
+            gradient: None,
+            min: None,
+            max: None,
+            show_range: false,
+            formatter: Box::new(format_value),
+        }
+    }
+
+    /// Returns a shared reference to the series, allowing live updates.
+    pub fn get_shared_content(&mut self) -> SeriesContent {
+        self.content.clone()
+    }
+
+    /// Replaces the values shown.
+    pub fn set_values<I: IntoIterator<Item = f64>>(&mut self, values: I) {
+        self.content.set_values(values);
+    }
+
+    /// Replaces the values shown.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn values<I: IntoIterator<Item = f64>>(self, values: I) -> Self {
+        self.with(|s| s.set_values(values))
+    }
+
+    /// Sets the number of rows used by the chart.
+    ///
+    /// Defaults to 1.
+    pub fn set_height(&mut self, height: usize) {
+        self.height = height.max(1);
+    }
+
+    /// Sets the number of rows used by the chart.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn height(self, height: usize) -> Self {
+        self.with(|s| s.set_height(height))
+    }
+
+    /// Sets the characters used to draw the values.
+    ///
+    /// Defaults to `ChartGlyphs::Blocks`.
+    pub fn set_glyphs(&mut self, glyphs: ChartGlyphs) {
+        self.glyphs = glyphs;
+    }
+
+    /// Sets the characters used to draw the values.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn glyphs(self, glyphs: ChartGlyphs) -> Self {
+        self.with(|s| s.set_glyphs(glyphs))
+    }
+
+    /// Sets the color of the chart.
+    ///
+    /// Defaults to the theme's highlight color.
+    pub fn set_color<C: Into<ColorType>>(&mut self, color: C) {
+        self.color = color.into();
+    }
+
+    /// Sets the color of the chart.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn color<C: Into<ColorType>>(self, color: C) -> Self {
+        self.with(|s| s.set_color(color))
+    }
+
+    /// Sets a gradient to color each value, from the minimum to the maximum.
+    ///
+    /// It replaces the color set with `set_color`.
+    pub fn set_gradient(&mut self, gradient: Linear) {
+        self.gradient = Some(gradient);
+    }
+
+    /// Sets a gradient to color each value, from the minimum to the maximum.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn gradient(self, gradient: Linear) -> Self {
+        self.with(|s| s.set_gradient(gradient))
+    }
+
+    /// Removes the gradient, using the color again.
+    pub fn clear_gradient(&mut self) {
+        self.gradient = None;
+    }
+
+    /// Sets the value shown as an empty column.
+    ///
+    /// Smaller values are shown empty too.
+    pub fn set_min(&mut self, min: f64) {
+        self.min = Some(min);
+    }
+
+    /// Sets the value shown as an empty column.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn min(self, min: f64) -> Self {
+        self.with(|s| s.set_min(min))
+    }
+
+    /// Sets the value shown as a full column.
+    ///
+    /// Larger values are shown full too.
+    pub fn set_max(&mut self, max: f64) {
+        self.max = Some(max);
+    }
+
+    /// Sets the value shown as a full column.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn max(self, max: f64) -> Self {
+        self.with(|s| s.set_max(max))
+    }
+
+    /// Sets the values shown as empty and full columns.
+    pub fn set_range(&mut self, min: f64, max: f64) {
+        self.set_min(min);
+        self.set_max(max);
+    }
+
+    /// Sets the values shown as empty and full columns.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn range(self, min: f64, max: f64) -> Self {
+        self.with(|s| s.set_range(min, max))
+    }
+
+    /// Scales the values from 0 to the maximum value shown again.
+    pub fn clear_range(&mut self) {
+        self.min = None;
+        self.max = None;
+    }
+
+    /// Shows the range of the values on the right of the chart.
+    ///
+    /// The maximum is shown on the first row and the minimum on the last one.
+    pub fn set_show_range(&mut self, show_range: bool) {
+        self.show_range = show_range;
+    }
+
+    /// Shows the range of the values on the right of the chart.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn show_range(self, show_range: bool) -> Self {
+        self.with(|s| s.set_show_range(show_range))
+    }
+
+    /// Sets the function used to print the range.
+    pub fn set_formatter<F>(&mut self, formatter: F)
+    where
+        F: Fn(f64) -> String + 'static + Send + Sync,
+    {
+        self.formatter = Box::new(formatter);
+    }
+
+    /// Sets the function used to print the range.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn formatter<F>(self, formatter: F) -> Self
+    where
+        F: Fn(f64) -> String + 'static + Send + Sync,
+    {
+        self.with(|s| s.set_formatter(formatter))
+    }
+
+    // Number of values drawn in each cell.
+    fn values_per_cell(&self) -> usize {
+        match self.glyphs {
+            ChartGlyphs::Blocks => 1,
+            ChartGlyphs::Braille => 2,
+        }
+    }
+
+    // Lines showing the range, from the top.
+    fn range_labels(&self, range: (f64, f64)) -> Vec<String> {
+        if !self.show_range {
+            return Vec::new();
+        }
+
+        let (min, max) = ((self.formatter)(range.0), (self.formatter)(range.1));
+        if self.height == 1 {
+            vec![format!("{min}..{max}")]
+        } else {
+            vec![max, min]
+        }
+    }
+
+    // Width of the range labels, including the space before them.
+    fn labels_width(labels: &[String]) -> usize {
+        labels
+            .iter()
+            .map(|label| label.width() + 1)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl View for Sparkline {
+    fn draw(&self, printer: &Printer) {
+        let per_cell = self.values_per_cell();
+        let resolution = self.glyphs.resolution();
+
+        self.content.with_values(|values| {
+            // Labels depend on the values shown, which depend on the space
+            // left by the labels: use the width needed for all values.
+            let all_labels = self.range_labels(value_range(values, self.min, self.max));
+            let chart_width = printer
+                .size
+                .x
+                .saturating_sub(Self::labels_width(&all_labels));
+
+            let start = values.len().saturating_sub(chart_width * per_cell);
+            let values = &values[start..];
+            let range = value_range(values, self.min, self.max);
+
+            for (x, cell) in values.chunks(per_cell).enumerate() {
+                let steps: Vec<usize> = cell
+                    .iter()
+                    .map(|&value| column_steps(ratio(value, range), self.height, resolution))
+                    .collect();
+                let top = cell.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+                let color = value_color(self.gradient.as_ref(), self.color, ratio(top, range));
+
+                printer.with_color(color, |printer| {
+                    for row in 0..self.height {
+                        let left = cell_steps(steps[0], row, resolution);
+                        let right = steps.get(1).map_or(0, |&s| cell_steps(s, row, resolution));
+                        let y = self.height - 1 - row;
+                        printer.print((x, y), &self.glyphs.glyph(left, right).to_string());
+                    }
+                });
+            }
+
+            let labels = self.range_labels(range);
+            let x = chart_width + 1;
+            if let [first, rest @ ..] = labels.as_slice() {
+                printer.print((x, 0), first);
+                if let Some(last) = rest.last() {
+                    printer.print((x, self.height - 1), last);
+                }
+            }
+        });
+    }
+
+    fn required_size(&mut self, _constraint: Vec2) -> Vec2 {
+        let per_cell = self.values_per_cell();
+        self.content.with_values(|values| {
+            let labels = self.range_labels(value_range(values, self.min, self.max));
+            let width = (values.len() + per_cell - 1) / per_cell + Self::labels_width(&labels);
+            Vec2::new(width, self.height)
+        })
+    }
+}
+
+#[crate::blueprint(Sparkline::new())]
+struct Blueprint {
+    values: Option<Vec<f64>>,
+    height: Option<usize>,
+    glyphs: Option<ChartGlyphs>,
+    color: Option<ColorType>,
+    gradient: Option<Linear>,
+    min: Option<f64>,
+    max: Option<f64>,
+    show_range: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::PrintBuffer;
+    use crate::theme::Theme;
+
+    fn render(view: &Sparkline, size: Vec2) -> Vec<String> {
+        let mut buffer = PrintBuffer::new();
+        buffer.resize(size);
+        let buffer = parking_lot::RwLock::new(buffer);
+        view.draw(&Printer::new(size, &Theme::default(), &buffer));
+
+        let rendered = buffer.read().to_styled_string();
+        rendered.source().lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn series_content() {
+        let content = SeriesContent::new([1.0, 2.0, 3.0]);
+        content.set_max_len(2);
+        assert_eq!(content.values(), [2.0, 3.0]);
+
+        content.push(4.0);
+        assert_eq!(content.values(), [3.0, 4.0]);
+        assert_eq!(
+            content.with_values(|values| values.iter().sum::<f64>()),
+            7.0
+        );
+    }
+
+    #[test]
+    fn glyphs() {
+        assert_eq!(ChartGlyphs::Blocks.glyph(0, 0), ' ');
+        assert_eq!(ChartGlyphs::Blocks.glyph(3, 0), '▃');
+        assert_eq!(ChartGlyphs::Braille.glyph(4, 4), '⣿');
+        assert_eq!(ChartGlyphs::Braille.glyph(1, 2), '⣠');
+
+        assert_eq!(ratio(5.0, (0.0, 10.0)), 0.5);
+        assert_eq!(ratio(20.0, (0.0, 10.0)), 1.0);
+        assert_eq!(ratio(0.0, (0.0, 0.0)), 0.0);
+
+        // 3/4 of 2 cells: a full cell, and half the top one.
+        let steps = column_steps(0.75, 2, 8);
+        assert_eq!(cell_steps(steps, 0, 8), 8);
+        assert_eq!(cell_steps(steps, 1, 8), 4);
+    }
+
+    #[test]
+    fn sparkline() {
+        let mut view = Sparkline::new().values([0.0, 2.0, 4.0, 8.0]);
+        assert_eq!(view.required_size(Vec2::new(80, 24)), Vec2::new(4, 1));
+
+        view.set_show_range(true);
+        assert_eq!(view.required_size(Vec2::new(80, 24)), Vec2::new(9, 1));
+
+        assert_eq!(render(&view, Vec2::new(9, 1)), [" ▂▄█ 0..8"]);
+
+        // Only the latest values fit, two per cell with braille.
+        view.set_show_range(false);
+        view.set_height(2);
+        view.set_glyphs(ChartGlyphs::Braille);
+        assert_eq!(view.required_size(Vec2::new(80, 24)), Vec2::new(2, 2));
+        assert_eq!(render(&view, Vec2::new(1, 2)), ["⢸", "⣿"]);
+    }
+}
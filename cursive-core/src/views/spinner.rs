@@ -0,0 +1,276 @@
+use crate::utils::markup::StyledString;
+use crate::utils::Counter;
+use crate::view::View;
+use crate::{Printer, Vec2, With};
+use std::time::{Duration, Instant};
+use unicode_width::UnicodeWidthStr;
+
+const BRAILLE: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+const DOTS: [&str; 4] = ["   ", ".  ", ".. ", "..."];
+const LINE: [&str; 4] = ["-", "\\", "|", "/"];
+
+/// Animation shown by a [`Spinner`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SpinnerStyle {
+    /// A dot turning in a braille cell (`⠋`, `⠙`, `⠹`, ...).
+    #[default]
+    Braille,
+
+    /// Up to three dots appearing one after the other.
+    Dots,
+
+    /// A turning line (`-`, `\`, `|`, `/`).
+    Line,
+
+    /// Custom frames, shown in order.
+    Custom(Vec<String>),
+}
+
+impl SpinnerStyle {
+    /// Returns the number of frames in the animation.
+    pub fn len(&self) -> usize {
+        match self {
+            SpinnerStyle::Braille => BRAILLE.len(),
+            SpinnerStyle::Dots => DOTS.len(),
+            SpinnerStyle::Line => LINE.len(),
+            SpinnerStyle::Custom(frames) => frames.len(),
+        }
+    }
+
+    /// Returns `true` if the animation has no frame.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the frame at the given position, looping over the animation.
+    ///
+    /// Returns an empty string if there is no frame.
+    pub fn frame(&self, i: usize) -> &str {
+        if self.is_empty() {
+            return "";
+        }
+        let i = i % self.len();
+        match self {
+            SpinnerStyle::Braille => BRAILLE[i],
+            SpinnerStyle::Dots => DOTS[i],
+            SpinnerStyle::Line => LINE[i],
+            SpinnerStyle::Custom(frames) => &frames[i],
+        }
+    }
+
+    // Width of the widest frame.
+    fn width(&self) -> usize {
+        (0..self.len())
+            .map(|i| self.frame(i).width())
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl std::str::FromStr for SpinnerStyle {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Braille" | "braille" => Self::Braille,
+            "Dots" | "dots" => Self::Dots,
+            "Line" | "line" => Self::Line,
+            _ => return Err(()),
+        })
+    }
+}
+
+// What moves the animation forward.
+enum Driver {
+    // One frame per interval since the start.
+    Clock(Instant),
+    // One frame per tick.
+    Ticks(Counter),
+}
+
+/// Animated spinner, showing that something is in progress.
+///
+/// By default, the spinner moves with time, so it needs the screen to be
+/// redrawn regularly (see [`Cursive::set_fps`]). Calling [`Spinner::tick`], or
+/// following a [`Counter`], moves it one frame at a time instead.
+///
+/// [`Cursive::set_fps`]: crate::Cursive::set_fps
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::{Spinner, SpinnerStyle};
+///
+/// let spinner = Spinner::new()
+///     .style(SpinnerStyle::Line)
+///     .label("Connecting");
+/// ```
+pub struct Spinner {
+    style: SpinnerStyle,
+    driver: Driver,
+    interval: Duration,
+    label: StyledString,
+}
+
+new_default!(Spinner);
+
+impl Spinner {
+    /// Time between two frames, by default.
+    pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(100);
+
+    /// Creates a new spinner, moving with time.
+    pub fn new() -> Self {
+        Spinner {
+            style: SpinnerStyle::default(),
+            driver: Driver::Clock(Instant::now()),
+            interval: Self::DEFAULT_INTERVAL,
+            label: StyledString::new(),
+        }
+    }
+
+    /// Sets the animation of this spinner.
+    pub fn set_style(&mut self, style: SpinnerStyle) {
+        self.style = style;
+    }
+
+    /// Sets the animation of this spinner.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn style(self, style: SpinnerStyle) -> Self {
+        self.with(|s| s.set_style(style))
+    }
+
+    /// Returns the animation of this spinner.
+    pub fn get_style(&self) -> &SpinnerStyle {
+        &self.style
+    }
+
+    /// Sets the text shown after the spinner.
+    pub fn set_label<S: Into<StyledString>>(&mut self, label: S) {
+        self.label = label.into();
+    }
+
+    /// Sets the text shown after the spinner.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn label<S: Into<StyledString>>(self, label: S) -> Self {
+        self.with(|s| s.set_label(label))
+    }
+
+    /// Returns the text shown after the spinner.
+    pub fn get_label(&self) -> &StyledString {
+        &self.label
+    }
+
+    /// Sets the time between two frames, when moving with time.
+    ///
+    /// Defaults to 100ms.
+    pub fn set_interval(&mut self, interval: Duration) {
+        self.interval = interval.max(Duration::from_millis(1));
+    }
+
+    /// Sets the time between two frames, when moving with time.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn interval(self, interval: Duration) -> Self {
+        self.with(|s| s.set_interval(interval))
+    }
+
+    /// Follows the given counter: the spinner shows one frame per tick.
+    ///
+    /// The counter can be ticked from another thread.
+    pub fn set_counter(&mut self, counter: Counter) {
+        self.driver = Driver::Ticks(counter);
+    }
+
+    /// Follows the given counter: the spinner shows one frame per tick.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn with_counter(self, counter: Counter) -> Self {
+        self.with(|s| s.set_counter(counter))
+    }
+
+    /// Moves the spinner to the next frame.
+    ///
+    /// From then on, the spinner only moves on ticks, not with time.
+    pub fn tick(&mut self) {
+        match self.driver {
+            Driver::Ticks(ref counter) => counter.tick(1),
+            Driver::Clock(_) => {
+                let frame = self.current_frame() + 1;
+                self.driver = Driver::Ticks(Counter::new(frame));
+            }
+        }
+    }
+
+    /// Returns the position of the current frame.
+    ///
+    /// This keeps increasing: it is not bounded by the number of frames.
+    pub fn current_frame(&self) -> usize {
+        match self.driver {
+            Driver::Clock(started) => {
+                (started.elapsed().as_millis() / self.interval.as_millis()) as usize
+            }
+            Driver::Ticks(ref counter) => counter.get(),
+        }
+    }
+}
+
+impl View for Spinner {
+    fn draw(&self, printer: &Printer) {
+        printer.print((0, 0), self.style.frame(self.current_frame()));
+        if !self.label.is_empty() {
+            printer.print_styled((self.style.width() + 1, 0), &self.label);
+        }
+    }
+
+    fn required_size(&mut self, _: Vec2) -> Vec2 {
+        let label = if self.label.is_empty() {
+            0
+        } else {
+            self.label.width() + 1
+        };
+        Vec2::new(self.style.width() + label, 1)
+    }
+}
+
+#[crate::blueprint(Spinner::new())]
+struct Blueprint {
+    style: Option<SpinnerStyle>,
+    label: Option<StyledString>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ticks() {
+        let mut spinner = Spinner::new().style(SpinnerStyle::Line).label("Wait");
+        assert_eq!(spinner.required_size(Vec2::zero()), Vec2::new(6, 1));
+
+        let start = spinner.current_frame();
+        spinner.tick();
+        spinner.tick();
+        assert_eq!(spinner.current_frame(), start + 2);
+
+        let counter = Counter::new(5);
+        let mut spinner = spinner.with_counter(counter.clone());
+        counter.tick(1);
+        spinner.tick();
+        let screen = crate::test::render_view(spinner, (10, 1));
+        assert_eq!(screen.row(0).trim_end(), "/ Wait");
+    }
+
+    #[test]
+    fn styles() {
+        assert_eq!(SpinnerStyle::Dots.frame(6), ".. ");
+        assert_eq!(SpinnerStyle::Braille.frame(10), "⠋");
+        assert_eq!(SpinnerStyle::Custom(Vec::new()).frame(3), "");
+        assert_eq!(SpinnerStyle::Dots.width(), 3);
+        assert_eq!("line".parse(), Ok(SpinnerStyle::Line));
+    }
+}
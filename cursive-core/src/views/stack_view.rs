@@ -6,7 +6,8 @@
 use crate::{
     direction::Direction,
     event::{AnyCb, Event, EventResult},
-    style::PaletteStyle,
+    rect::Rect,
+    style::{gradient::Dynterpolator, Color, ConcreteStyle, Effect, PaletteStyle, Rgb},
     view::{
         CannotFocus, IntoBoxedView, Offset, Position, Selector, View, ViewNotFound, ViewWrapper,
     },
@@ -26,6 +27,81 @@ pub struct StackView {
     // TODO: this is broken! Transparent views could change their content and lead to weirdness.
     // Instead, just rely on buffered backend.
     bg_dirty: std::sync::atomic::AtomicBool,
+    // Shade applied to the layers behind a dimming layer.
+    shade: LayerShade,
+}
+
+/// Shade applied to the layers behind a dimming layer.
+///
+/// See [`StackView::add_modal_layer`].
+pub enum LayerShade {
+    /// Adds the `Dim` effect to the layers behind.
+    Dim,
+
+    /// Blends the colors of the layers behind with the given color.
+    ///
+    /// The amount goes from 0 (unchanged) to 1 (only the given color).
+    ///
+    /// Only colors with a known RGB value are blended.
+    Blend(Color, f32),
+
+    /// Blends the colors of the layers behind with a gradient.
+    ///
+    /// The amount goes from 0 (unchanged) to 1 (only the gradient).
+    Gradient(Dynterpolator, f32),
+}
+
+impl Default for LayerShade {
+    fn default() -> Self {
+        LayerShade::Dim
+    }
+}
+
+impl LayerShade {
+    // Shades everything already drawn in the printer area.
+    fn apply(&self, printer: &Printer) {
+        let size = printer.size;
+        let start = printer.content_offset;
+        let end = (start + printer.output_size).or_min(size);
+
+        printer.on_window(|window| {
+            for y in start.y..end.y {
+                for x in start.x..end.x {
+                    let pos = Vec2::new(x, y);
+                    if let Some(style) = window.style_at_mut(pos - start) {
+                        self.shade(style, pos, size);
+                    }
+                }
+            }
+        });
+    }
+
+    // Shades a single cell.
+    fn shade(&self, style: &mut ConcreteStyle, pos: Vec2, size: Vec2) {
+        let (target, amount) = match *self {
+            LayerShade::Dim => {
+                style.effects.insert(Effect::Dim);
+                return;
+            }
+            LayerShade::Blend(color, amount) => {
+                let Some(target) = crate::style::to_rgb(color) else {
+                    return;
+                };
+                (target.into(), amount)
+            }
+            LayerShade::Gradient(ref gradient, amount) => (gradient.interpolate(pos, size), amount),
+        };
+
+        let blend = |color: &mut Color| {
+            if let Some(rgb) = crate::style::to_rgb(*color) {
+                *color = Rgb::zip(Rgb::<f32>::from(rgb), target)
+                    .interpolate(amount)
+                    .as_color();
+            }
+        };
+        blend(&mut style.color.front);
+        blend(&mut style.color.back);
+    }
 }
 
 // This is a poor man's optional parameter, or kinda builder pattern.
@@ -35,6 +111,7 @@ pub struct StackView {
 pub struct LayerConfig<V> {
     view: V,
     modal: bool,
+    dim: bool,
     placement: Placement,
     wrapper: WrapperType,
 }
@@ -62,6 +139,23 @@ pub struct Transparent<T>(pub T);
 /// Place the layer at the given position.
 pub struct LayerAt<T>(pub Position, pub T);
 
+/// Shade the layers behind this one.
+///
+/// This also makes the layer modal. See [`StackView::set_shade`].
+pub struct DimBehind<T>(pub T);
+
+impl<T, V> From<DimBehind<T>> for LayerConfig<V>
+where
+    T: Into<LayerConfig<V>>,
+{
+    fn from(other: DimBehind<T>) -> Self {
+        other.0.into().with(|config| {
+            config.modal = true;
+            config.dim = true;
+        })
+    }
+}
+
 impl<T, V> From<Transparent<T>> for LayerConfig<V>
 where
     T: Into<LayerConfig<V>>,
@@ -124,6 +218,7 @@ impl<V: IntoBoxedView> From<V> for LayerConfig<V> {
         LayerConfig {
             view,
             modal: true,
+            dim: false,
             placement: Placement::Floating(Position::center()),
             wrapper: WrapperType::Shadow,
         }
@@ -239,6 +334,14 @@ impl<T: View> View for ChildWrapper<T> {
         }
     }
 
+    fn dirty_area(&self, size: Vec2) -> Option<Rect> {
+        match *self {
+            ChildWrapper::Shadow(ref v) => v.dirty_area(size),
+            ChildWrapper::Backfilled(ref v) => v.dirty_area(size),
+            ChildWrapper::Plain(ref v) => v.dirty_area(size),
+        }
+    }
+
     fn on_event(&mut self, event: Event) -> EventResult {
         match *self {
             ChildWrapper::Shadow(ref mut v) => v.on_event(event),
@@ -279,6 +382,14 @@ impl<T: View> View for ChildWrapper<T> {
         }
     }
 
+    fn call_on_focused_child(&mut self, callback: AnyCb) {
+        match *self {
+            ChildWrapper::Shadow(ref mut v) => callback(v),
+            ChildWrapper::Backfilled(ref mut v) => callback(v),
+            ChildWrapper::Plain(ref mut v) => callback(v),
+        }
+    }
+
     fn focus_view(&mut self, selector: &Selector) -> Result<EventResult, ViewNotFound> {
         match *self {
             ChildWrapper::Shadow(ref mut v) => v.focus_view(selector),
@@ -286,6 +397,14 @@ impl<T: View> View for ChildWrapper<T> {
             ChildWrapper::Plain(ref mut v) => v.focus_view(selector),
         }
     }
+
+    fn reveal_view(&mut self, selector: &Selector, size: Vec2) -> Result<Rect, ViewNotFound> {
+        match *self {
+            ChildWrapper::Shadow(ref mut v) => v.reveal_view(selector, size),
+            ChildWrapper::Backfilled(ref mut v) => v.reveal_view(selector, size),
+            ChildWrapper::Plain(ref mut v) => v.reveal_view(selector, size),
+        }
+    }
 }
 
 struct Child {
@@ -294,6 +413,9 @@ struct Child {
     placement: Placement,
     modal: bool,
 
+    // If `true`, the layers behind are shaded.
+    dim: bool,
+
     // We cannot call `take_focus` until we've called `layout()`
     // (for instance, a textView must know it will scroll to be focusable).
     // So we want to call `take_focus` right after the first call to `layout`.
@@ -310,6 +432,7 @@ impl StackView {
             layers: Vec::new(),
             last_size: Vec2::zero(),
             bg_dirty: std::sync::atomic::AtomicBool::new(true),
+            shade: LayerShade::default(),
         }
     }
 
@@ -355,6 +478,7 @@ impl StackView {
         let LayerConfig {
             view,
             modal,
+            dim,
             placement,
             wrapper,
         } = view.into();
@@ -371,6 +495,7 @@ impl StackView {
         self.layers.push(Child {
             view,
             modal,
+            dim,
             placement,
             size: Vec2::zero(),
             virgin: true,
@@ -389,6 +514,108 @@ impl StackView {
         self.with(|s| s.add_layer(view))
     }
 
+    /// Adds a new modal view on top of the stack, shading the layers behind.
+    ///
+    /// Like other modal layers, it prevents events from reaching the layers
+    /// behind. Use [`StackView::set_shade`] to choose how they are shaded.
+    ///
+    /// This is the same as `add_layer(DimBehind(view))`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::views::{Dialog, StackView, TextView};
+    /// let mut stack = StackView::new();
+    /// stack.add_layer(TextView::new("Content"));
+    /// stack.add_modal_layer(Dialog::info("Are you sure?"));
+    /// ```
+    pub fn add_modal_layer<T, V>(&mut self, view: T)
+    where
+        T: Into<LayerConfig<V>>,
+        V: IntoBoxedView,
+    {
+        self.add_layer(DimBehind(view));
+    }
+
+    /// Adds a new modal view on top of the stack, shading the layers behind.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn modal_layer<T, V>(self, view: T) -> Self
+    where
+        T: Into<LayerConfig<V>>,
+        V: IntoBoxedView,
+    {
+        self.with(|s| s.add_modal_layer(view))
+    }
+
+    /// Sets the shade applied behind dimming layers.
+    ///
+    /// Defaults to [`LayerShade::Dim`].
+    pub fn set_shade(&mut self, shade: LayerShade) {
+        self.shade = shade;
+        self.set_dirty();
+    }
+
+    /// Sets the shade applied behind dimming layers.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn shade(self, shade: LayerShade) -> Self {
+        self.with(|s| s.set_shade(shade))
+    }
+
+    /// Returns the shade applied behind dimming layers.
+    pub fn get_shade(&self) -> &LayerShade {
+        &self.shade
+    }
+
+    /// Returns an iterator on the layers, from back to front.
+    pub fn layers(&self) -> impl DoubleEndedIterator<Item = &dyn View> {
+        self.layers
+            .iter()
+            .map(|child| &**child.view.get_inner().get_inner())
+    }
+
+    /// Returns an iterator on mutable references to the layers, from back to front.
+    pub fn layers_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut dyn View> {
+        self.layers
+            .iter_mut()
+            .map(|child| &mut **child.view.get_inner_mut().get_inner_mut())
+    }
+
+    /// Returns `true` if the given layer is modal.
+    ///
+    /// Returns `None` if `layer` is out of bounds.
+    pub fn is_modal(&self, layer: LayerPosition) -> Option<bool> {
+        let i = self.get_index(layer)?;
+        self.layers.get(i).map(|child| child.modal)
+    }
+
+    /// Returns `true` if the given layer shades the layers behind it.
+    ///
+    /// Returns `None` if `layer` is out of bounds.
+    pub fn is_dimming(&self, layer: LayerPosition) -> Option<bool> {
+        let i = self.get_index(layer)?;
+        self.layers.get(i).map(|child| child.dim)
+    }
+
+    /// Makes the given layer shade (or not) the layers behind it.
+    ///
+    /// # Panics
+    ///
+    /// If `layer` is out of bounds.
+    pub fn set_dimming(&mut self, layer: LayerPosition, dim: bool) {
+        let i = self.get_index(layer).unwrap();
+        self.layers[i].dim = dim;
+        self.set_dirty();
+    }
+
+    // Index of the front-most layer shading the layers behind.
+    fn dim_index(&self) -> Option<usize> {
+        self.layers.iter().rposition(|layer| layer.dim)
+    }
+
     /// Returns a reference to the layer at the given position.
     pub fn get(&self, pos: LayerPosition) -> Option<&dyn View> {
         self.get_index(pos).and_then(|i| {
@@ -536,7 +763,8 @@ impl StackView {
     }
 
     fn is_dirty(&self) -> bool {
-        self.bg_dirty.load(std::sync::atomic::Ordering::Relaxed)
+        // The shade would add up if the background was kept between frames.
+        self.bg_dirty.load(std::sync::atomic::Ordering::Relaxed) || self.dim_index().is_some()
     }
 
     /// Remove the top-most layer.
@@ -675,10 +903,14 @@ impl StackView {
     /// you probably just want to call draw()
     pub fn draw_fg(&self, printer: &Printer) {
         let last = self.layers.len();
+        let dim = self.dim_index();
         printer.with_style(PaletteStyle::Background, |printer| {
             for (i, (v, offset)) in
                 StackPositionIterator::new(self.layers.iter(), printer.size).enumerate()
             {
+                if dim == Some(i) {
+                    self.shade.apply(printer);
+                }
                 v.view.draw(
                     &printer
                         .offset(offset)
@@ -743,6 +975,18 @@ impl View for StackView {
         self.draw_fg(printer);
     }
 
+    fn dirty_area(&self, size: Vec2) -> Option<Rect> {
+        if self.is_dirty() {
+            return Some(Rect::from_size(Vec2::zero(), size));
+        }
+
+        StackPositionIterator::new(self.layers.iter(), size)
+            .filter_map(|(layer, offset)| {
+                layer.view.dirty_area(layer.size).map(|area| area + offset)
+            })
+            .reduce(Rect::union)
+    }
+
     fn on_event(&mut self, event: Event) -> EventResult {
         if event == Event::WindowResize {
             self.set_dirty();
@@ -817,6 +1061,13 @@ impl View for StackView {
         }
     }
 
+    fn call_on_focused_child(&mut self, callback: AnyCb) {
+        // Only the front-most layer has the focus.
+        if let Some(layer) = self.layers.last_mut() {
+            callback(&mut layer.view);
+        }
+    }
+
     fn focus_view(&mut self, selector: &Selector) -> Result<EventResult, ViewNotFound> {
         for layer in &mut self.layers {
             if layer.view.focus_view(selector).is_ok() {
@@ -826,6 +1077,19 @@ impl View for StackView {
 
         Err(ViewNotFound)
     }
+
+    fn reveal_view(&mut self, selector: &Selector, _: Vec2) -> Result<Rect, ViewNotFound> {
+        StackPositionIterator::new(self.layers.iter_mut(), self.last_size)
+            .find_map(|(layer, offset)| {
+                let size = layer.size;
+                layer
+                    .view
+                    .reveal_view(selector, size)
+                    .ok()
+                    .map(|area| area + offset)
+            })
+            .ok_or(ViewNotFound)
+    }
 }
 
 #[cfg(test)]
@@ -889,6 +1153,61 @@ mod tests {
         assert!(stack.pop_layer().is_none());
     }
 
+    #[test]
+    fn modal_layer() {
+        use crate::buffer::PrintBuffer;
+        use crate::event::Event;
+        use crate::style::Rgb;
+        use crate::theme::Theme;
+        use crate::views::DummyView;
+
+        let mut stack = StackView::new()
+            .layer(TextView::new("Back"))
+            .modal_layer(DummyView);
+        assert_eq!(stack.is_modal(LayerPosition::FromFront(0)), Some(true));
+        assert_eq!(stack.is_dimming(LayerPosition::FromFront(0)), Some(true));
+        assert_eq!(stack.is_dimming(LayerPosition::FromBack(0)), Some(false));
+        assert_eq!(stack.is_dimming(LayerPosition::FromBack(2)), None);
+        assert!(stack.layers().next().unwrap().is::<TextView>());
+
+        // Events don't reach the layers behind.
+        assert!(matches!(
+            stack.on_event(Event::Char('a')),
+            EventResult::Ignored
+        ));
+
+        let size = Vec2::new(8, 3);
+        stack.layout(size);
+        let draw = |stack: &StackView| {
+            let mut buffer = PrintBuffer::new();
+            buffer.resize(size);
+            let buffer = parking_lot::RwLock::new(buffer);
+            stack.draw(&Printer::new(size, &Theme::default(), &buffer));
+            buffer.into_inner()
+        };
+
+        // The text behind is dimmed.
+        let buffer = draw(&stack);
+        // Offset of the text layer, centered with its shadow.
+        let offset = stack.layer_offset(LayerPosition::FromBack(0)).unwrap();
+        let style = buffer.cell_style(offset).unwrap();
+        assert!(style.effects.contains(Effect::Dim));
+
+        // Colors can be blended instead.
+        stack.set_shade(LayerShade::Blend(Rgb::new(0, 0, 0).as_color(), 1.0));
+        let buffer = draw(&stack);
+        let style = buffer.cell_style(offset).unwrap();
+        assert!(!style.effects.contains(Effect::Dim));
+        assert_eq!(style.color.back, Color::Rgb(0, 0, 0));
+
+        stack.set_dimming(LayerPosition::FromFront(0), false);
+        let buffer = draw(&stack);
+        assert_ne!(
+            buffer.cell_style(offset).unwrap().color.back,
+            Color::Rgb(0, 0, 0)
+        );
+    }
+
     #[test]
     fn get() {
         let mut stack = StackView::new()
@@ -949,6 +1268,7 @@ impl crate::builder::Resolvable for Child {
         let view: crate::views::BoxedView = context.resolve(&config["child"])?;
 
         let modal: Option<bool> = context.resolve(&config["modal"])?;
+        let dim: Option<bool> = context.resolve(&config["dim"])?;
         let placement = context.resolve(&config["placement"])?;
         let position: Position = context.resolve(&config["position"])?;
 
@@ -960,7 +1280,8 @@ impl crate::builder::Resolvable for Child {
                     .left_padding(position.x == Offset::Center),
             ))
             .wrap_tab(),
-            modal: modal.unwrap_or(true),
+            modal: modal.unwrap_or(true) || dim.unwrap_or(false),
+            dim: dim.unwrap_or(false),
             size: Vec2::zero(),
             placement,
             virgin: true,
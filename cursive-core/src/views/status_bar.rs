@@ -0,0 +1,168 @@
+use crate::{style::PaletteStyle, utils::markup::StyledString, view::View, Printer, Vec2, With};
+use unicode_width::UnicodeWidthStr;
+
+// Shown between two segments on the same side.
+const SEPARATOR: &str = " │ ";
+
+/// Single-line bar with segments on the left and on the right.
+///
+/// This is meant to be used as the status bar of the [`Cursive`] root, but
+/// it can be used like any other view.
+///
+/// Left segments are shown from the left edge, right segments end at the right
+/// edge. Segments on the same side are separated by a vertical bar.
+///
+/// # Examples
+///
+/// ```rust
+/// # use cursive_core::views::StatusBar;
+/// # let mut siv = cursive_core::Cursive::new();
+/// siv.set_statusbar(StatusBar::new().left("NORMAL").left("main.rs").right("12:4"));
+///
+/// // Later on, update a segment.
+/// siv.call_on_statusbar(|bar: &mut StatusBar| bar.set_right(0, "13:1"));
+/// ```
+///
+/// [`Cursive`]: crate::Cursive::set_statusbar
+#[derive(Default)]
+pub struct StatusBar {
+    left: Vec<StyledString>,
+    right: Vec<StyledString>,
+}
+
+impl StatusBar {
+    /// Creates a new, empty status bar.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a segment after the existing left segments.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn left<S: Into<StyledString>>(self, content: S) -> Self {
+        self.with(|s| s.add_left(content))
+    }
+
+    /// Adds a segment after the existing left segments.
+    pub fn add_left<S: Into<StyledString>>(&mut self, content: S) {
+        self.left.push(content.into());
+    }
+
+    /// Adds a segment before the existing right segments.
+    ///
+    /// Right segments are indexed from the right edge: the first one added
+    /// stays at the right edge.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn right<S: Into<StyledString>>(self, content: S) -> Self {
+        self.with(|s| s.add_right(content))
+    }
+
+    /// Adds a segment before the existing right segments.
+    ///
+    /// Right segments are indexed from the right edge: the first one added
+    /// stays at the right edge.
+    pub fn add_right<S: Into<StyledString>>(&mut self, content: S) {
+        self.right.push(content.into());
+    }
+
+    /// Replaces the content of the `i`-th left segment.
+    ///
+    /// Missing segments before it are added empty.
+    pub fn set_left<S: Into<StyledString>>(&mut self, i: usize, content: S) {
+        set_segment(&mut self.left, i, content.into());
+    }
+
+    /// Replaces the content of the `i`-th right segment, counted from the right edge.
+    ///
+    /// Missing segments before it are added empty.
+    pub fn set_right<S: Into<StyledString>>(&mut self, i: usize, content: S) {
+        set_segment(&mut self.right, i, content.into());
+    }
+
+    /// Returns the left segments, from left to right.
+    pub fn left_segments(&self) -> &[StyledString] {
+        &self.left
+    }
+
+    /// Returns the right segments, from the right edge.
+    pub fn right_segments(&self) -> &[StyledString] {
+        &self.right
+    }
+
+    /// Removes all segments.
+    pub fn clear(&mut self) {
+        self.left.clear();
+        self.right.clear();
+    }
+
+    fn width(segments: &[StyledString]) -> usize {
+        segments.iter().map(StyledString::width).sum::<usize>()
+            + SEPARATOR.width() * segments.len().saturating_sub(1)
+    }
+}
+
+fn set_segment(segments: &mut Vec<StyledString>, i: usize, content: StyledString) {
+    if segments.len() <= i {
+        segments.resize_with(i + 1, StyledString::new);
+    }
+    segments[i] = content;
+}
+
+impl View for StatusBar {
+    fn draw(&self, printer: &Printer) {
+        printer.with_style(PaletteStyle::View, |printer| {
+            printer.print_hline((0, 0), printer.size.x, " ");
+
+            // Right segments first, so left ones win if they overlap.
+            let mut x = printer.size.x.saturating_sub(1 + Self::width(&self.right));
+            for (i, segment) in self.right.iter().rev().enumerate() {
+                if i > 0 {
+                    printer.print((x, 0), SEPARATOR);
+                    x += SEPARATOR.width();
+                }
+                printer.print_styled((x, 0), segment);
+                x += segment.width();
+            }
+
+            let mut x = 1;
+            for (i, segment) in self.left.iter().enumerate() {
+                if i > 0 {
+                    printer.print((x, 0), SEPARATOR);
+                    x += SEPARATOR.width();
+                }
+                printer.print_styled((x, 0), segment);
+                x += segment.width();
+            }
+        });
+    }
+
+    fn required_size(&mut self, _: Vec2) -> Vec2 {
+        // One column of padding on each side, and a gap between the sides.
+        let width = Self::width(&self.left) + Self::width(&self.right) + 3;
+        Vec2::new(width, 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view::Resizable;
+
+    #[test]
+    fn segments() {
+        let mut bar = StatusBar::new()
+            .left("NORMAL")
+            .left("main.rs")
+            .right("12:4");
+        bar.set_right(1, "utf-8");
+
+        let screen = crate::test::render_view(bar.full_width(), (40, 1));
+        assert_eq!(
+            screen.row(0).trim_end(),
+            " NORMAL │ main.rs          utf-8 │ 12:4"
+        );
+    }
+}
@@ -0,0 +1,979 @@
+use crate::{
+    direction::{Absolute, Direction, Relative},
+    event::{AnyCb, Event, EventResult, Key, MouseButton, MouseEvent},
+    rect::Rect,
+    style::PaletteStyle,
+    view::{CannotFocus, IntoBoxedView, Selector, View, ViewNotFound},
+    views::BoxedView,
+    Cursive, Printer, Vec2, With,
+};
+use std::sync::Arc;
+use unicode_width::UnicodeWidthStr;
+
+type CloseCallback = dyn Fn(&mut Cursive, &str) + Send + Sync;
+
+// Label of the button closing a tab.
+const CLOSE_LABEL: &str = "×";
+
+/// Where the tab bar is drawn in a [`TabView`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TabBarPosition {
+    /// Above the tabs.
+    Top,
+
+    /// Below the tabs.
+    Bottom,
+}
+
+struct Tab {
+    label: String,
+    view: BoxedView,
+    closable: bool,
+}
+
+impl Tab {
+    // Width in the bar: ` label ` or ` label × `.
+    fn width(&self) -> usize {
+        let close_width = if self.closable {
+            CLOSE_LABEL.width() + 1
+        } else {
+            0
+        };
+        self.label.width() + 2 + close_width
+    }
+}
+
+/// A view showing one of several tabs, with a bar to switch between them.
+///
+/// The tab bar can be focused to navigate with the arrow keys. From there:
+///
+/// * `Shift+Left` and `Shift+Right` move the active tab in the bar.
+/// * `Del` closes the active tab, if it is closable.
+///
+/// Tabs can also be cycled from anywhere with `Ctrl+Tab` and
+/// `Ctrl+Shift+Tab` (see [`TabView::set_switch_keys`]).
+///
+/// Tabs are identified by their position, which changes when tabs are
+/// closed or moved. [`TabView::find_tab`] returns the position of a tab from
+/// its label.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::{TabView, TextView};
+///
+/// let tabs = TabView::new()
+///     .tab("Files", TextView::new("No file"))
+///     .tab("Logs", TextView::new("Nothing yet"))
+///     .closable()
+///     .on_close(|_, label| eprintln!("Closed {label}"));
+///
+/// assert_eq!(tabs.active_tab(), Some(0));
+/// assert_eq!(tabs.find_tab("Logs"), Some(1));
+/// ```
+pub struct TabView {
+    tabs: Vec<Tab>,
+    active: usize,
+
+    bar_position: TabBarPosition,
+
+    // `true` if the tab bar has the focus, rather than the active tab.
+    bar_focused: bool,
+
+    // Whether tabs added from now on can be closed.
+    closable: bool,
+
+    // Keys to go to the next and previous tabs.
+    switch_keys: Option<(Event, Event)>,
+
+    on_close: Option<Arc<CloseCallback>>,
+
+    // Horizontal position of each tab in the bar.
+    offsets: Vec<usize>,
+
+    // Number of columns of the bar hidden on the left.
+    //
+    // The bar is scrolled to keep the active tab visible.
+    bar_scroll: usize,
+
+    // Size given to the last layout.
+    size: Vec2,
+}
+
+new_default!(TabView);
+
+impl TabView {
+    /// Creates a new `TabView` without any tab.
+    pub fn new() -> Self {
+        TabView {
+            tabs: Vec::new(),
+            active: 0,
+            bar_position: TabBarPosition::Top,
+            bar_focused: false,
+            closable: false,
+            switch_keys: Some((Event::Ctrl(Key::Tab), Event::CtrlShift(Key::Tab))),
+            on_close: None,
+            offsets: Vec::new(),
+            bar_scroll: 0,
+            size: Vec2::zero(),
+        }
+    }
+
+    /// Adds a new tab at the end of the bar, and returns its position.
+    ///
+    /// The first tab added becomes active.
+    pub fn add_tab<S, V>(&mut self, label: S, view: V) -> usize
+    where
+        S: Into<String>,
+        V: IntoBoxedView,
+    {
+        let i = self.tabs.len();
+        self.insert_tab(i, label, view);
+        i
+    }
+
+    /// Adds a new tab at the end of the bar.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn tab<S, V>(self, label: S, view: V) -> Self
+    where
+        S: Into<String>,
+        V: IntoBoxedView,
+    {
+        self.with(|s| {
+            s.add_tab(label, view);
+        })
+    }
+
+    /// Inserts a new tab at the given position.
+    ///
+    /// The active tab does not change, unless this is the first tab.
+    ///
+    /// # Panics
+    ///
+    /// If `i > self.len()`.
+    pub fn insert_tab<S, V>(&mut self, i: usize, label: S, view: V)
+    where
+        S: Into<String>,
+        V: IntoBoxedView,
+    {
+        self.tabs.insert(
+            i,
+            Tab {
+                label: label.into(),
+                view: BoxedView::new(view.into_boxed_view()),
+                closable: self.closable,
+            },
+        );
+
+        if i <= self.active && self.tabs.len() > 1 {
+            self.active += 1;
+        }
+    }
+
+    /// Removes the tab at the given position, and returns its view.
+    ///
+    /// This does not call the callback set by [`TabView::set_on_close`].
+    /// Use [`TabView::close_tab`] for that.
+    ///
+    /// Returns `None` if `i >= self.len()`.
+    pub fn remove_tab(&mut self, i: usize) -> Option<BoxedView> {
+        if i >= self.tabs.len() {
+            return None;
+        }
+
+        let tab = self.tabs.remove(i);
+
+        if self.tabs.is_empty() {
+            self.active = 0;
+            self.bar_focused = false;
+        } else if i < self.active || self.active == self.tabs.len() {
+            self.active -= 1;
+        }
+
+        Some(tab.view)
+    }
+
+    /// Closes the tab at the given position.
+    ///
+    /// Unlike [`TabView::remove_tab`], the returned result will run the
+    /// callback set by [`TabView::set_on_close`].
+    ///
+    /// Returns `EventResult::Ignored` if `i >= self.len()`.
+    pub fn close_tab(&mut self, i: usize) -> EventResult {
+        let Some(label) = self.tabs.get(i).map(|tab| tab.label.clone()) else {
+            return EventResult::Ignored;
+        };
+        self.remove_tab(i);
+
+        let on_close = self.on_close.clone();
+        EventResult::with_cb_once(move |s| {
+            if let Some(cb) = on_close {
+                cb(s, &label);
+            }
+        })
+    }
+
+    /// Moves a tab to a new position in the bar.
+    ///
+    /// The same tab stays active.
+    ///
+    /// # Panics
+    ///
+    /// If `from >= self.len()` or `to >= self.len()`.
+    pub fn move_tab(&mut self, from: usize, to: usize) {
+        let tab = self.tabs.remove(from);
+        self.tabs.insert(to, tab);
+
+        if self.active == from {
+            self.active = to;
+        } else if from < self.active && self.active <= to {
+            self.active -= 1;
+        } else if to <= self.active && self.active < from {
+            self.active += 1;
+        }
+    }
+
+    /// Returns the number of tabs.
+    pub fn len(&self) -> usize {
+        self.tabs.len()
+    }
+
+    /// Returns `true` if there is no tab.
+    pub fn is_empty(&self) -> bool {
+        self.tabs.is_empty()
+    }
+
+    /// Returns the position of the first tab with the given label.
+    pub fn find_tab(&self, label: &str) -> Option<usize> {
+        self.tabs.iter().position(|tab| tab.label == label)
+    }
+
+    /// Returns the labels of all tabs, in order.
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.tabs.iter().map(|tab| tab.label.as_str())
+    }
+
+    /// Returns the label of the tab at the given position.
+    pub fn get_label(&self, i: usize) -> Option<&str> {
+        self.tabs.get(i).map(|tab| tab.label.as_str())
+    }
+
+    /// Sets the label of the tab at the given position.
+    ///
+    /// # Panics
+    ///
+    /// If `i >= self.len()`.
+    pub fn set_label<S: Into<String>>(&mut self, i: usize, label: S) {
+        self.tabs[i].label = label.into();
+    }
+
+    /// Returns a reference to the view in the tab at the given position.
+    pub fn get_tab(&self, i: usize) -> Option<&BoxedView> {
+        self.tabs.get(i).map(|tab| &tab.view)
+    }
+
+    /// Returns a mutable reference to the view in the tab at the given position.
+    pub fn get_tab_mut(&mut self, i: usize) -> Option<&mut BoxedView> {
+        self.tabs.get_mut(i).map(|tab| &mut tab.view)
+    }
+
+    /// Returns the position of the active tab.
+    ///
+    /// Returns `None` if there is no tab.
+    pub fn active_tab(&self) -> Option<usize> {
+        if self.tabs.is_empty() {
+            None
+        } else {
+            Some(self.active)
+        }
+    }
+
+    /// Returns the label of the active tab.
+    pub fn active_label(&self) -> Option<&str> {
+        self.get_label(self.active)
+    }
+
+    /// Sets the active tab.
+    ///
+    /// # Panics
+    ///
+    /// If `i >= self.len()`.
+    pub fn set_active_tab(&mut self, i: usize) {
+        if i >= self.tabs.len() {
+            panic!(
+                "Tried to set an invalid tab: {}, but only {} tabs present.",
+                i,
+                self.tabs.len()
+            );
+        }
+
+        self.active = i;
+
+        // The new tab may not accept the focus.
+        if !self.bar_focused && self.tabs[i].view.take_focus(Direction::none()).is_err() {
+            self.bar_focused = true;
+        }
+    }
+
+    /// Sets the active tab.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn with_active_tab(self, i: usize) -> Self {
+        self.with(|s| s.set_active_tab(i))
+    }
+
+    /// Activates the next tab, wrapping around after the last one.
+    ///
+    /// Returns `EventResult::Ignored` if there are less than 2 tabs.
+    pub fn next_tab(&mut self) -> EventResult {
+        if self.tabs.len() < 2 {
+            return EventResult::Ignored;
+        }
+
+        self.set_active_tab((self.active + 1) % self.tabs.len());
+        EventResult::consumed()
+    }
+
+    /// Activates the previous tab, wrapping around before the first one.
+    ///
+    /// Returns `EventResult::Ignored` if there are less than 2 tabs.
+    pub fn previous_tab(&mut self) -> EventResult {
+        if self.tabs.len() < 2 {
+            return EventResult::Ignored;
+        }
+
+        let previous = self.active.checked_sub(1).unwrap_or(self.tabs.len() - 1);
+        self.set_active_tab(previous);
+        EventResult::consumed()
+    }
+
+    /// Sets where the tab bar is drawn.
+    ///
+    /// Defaults to `TabBarPosition::Top`.
+    pub fn set_bar_position(&mut self, position: TabBarPosition) {
+        self.bar_position = position;
+    }
+
+    /// Sets where the tab bar is drawn.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn with_bar_position(self, position: TabBarPosition) -> Self {
+        self.with(|s| s.set_bar_position(position))
+    }
+
+    /// Returns where the tab bar is drawn.
+    pub fn get_bar_position(&self) -> TabBarPosition {
+        self.bar_position
+    }
+
+    /// Sets whether tabs can be closed.
+    ///
+    /// This applies to all current tabs, and to the tabs added later.
+    /// Closable tabs show a close button in the bar.
+    ///
+    /// Tabs cannot be closed by default.
+    pub fn set_closable(&mut self, closable: bool) {
+        self.closable = closable;
+        for tab in &mut self.tabs {
+            tab.closable = closable;
+        }
+    }
+
+    /// Makes tabs closable.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn closable(self) -> Self {
+        self.with(|s| s.set_closable(true))
+    }
+
+    /// Sets whether the tab at the given position can be closed.
+    ///
+    /// # Panics
+    ///
+    /// If `i >= self.len()`.
+    pub fn set_tab_closable(&mut self, i: usize, closable: bool) {
+        self.tabs[i].closable = closable;
+    }
+
+    /// Returns `true` if the tab at the given position can be closed.
+    pub fn is_tab_closable(&self, i: usize) -> bool {
+        matches!(self.tabs.get(i), Some(tab) if tab.closable)
+    }
+
+    /// Sets a callback to run when a tab is closed.
+    ///
+    /// The callback is given the label of the closed tab.
+    ///
+    /// It is called for tabs closed from the bar, or with
+    /// [`TabView::close_tab`].
+    #[crate::callback_helpers]
+    pub fn set_on_close<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, &str) + Send + Sync + 'static,
+    {
+        self.on_close = Some(Arc::new(cb));
+    }
+
+    /// Sets a callback to run when a tab is closed.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn on_close<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, &str) + Send + Sync + 'static,
+    {
+        self.with(|s| s.set_on_close(cb))
+    }
+
+    /// Sets the keys used to switch to the next and previous tabs.
+    ///
+    /// These keys are checked before the active tab gets the event.
+    ///
+    /// Defaults to `Ctrl+Tab` and `Ctrl+Shift+Tab`.
+    pub fn set_switch_keys<N, P>(&mut self, next: N, previous: P)
+    where
+        N: Into<Event>,
+        P: Into<Event>,
+    {
+        self.switch_keys = Some((next.into(), previous.into()));
+    }
+
+    /// Sets the keys used to switch to the next and previous tabs.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn with_switch_keys<N, P>(self, next: N, previous: P) -> Self
+    where
+        N: Into<Event>,
+        P: Into<Event>,
+    {
+        self.with(|s| s.set_switch_keys(next, previous))
+    }
+
+    /// Removes the keys used to switch tabs.
+    pub fn clear_switch_keys(&mut self) {
+        self.switch_keys = None;
+    }
+
+    /// Returns `true` if the tab bar has the focus, rather than the active tab.
+    pub fn is_bar_focused(&self) -> bool {
+        self.bar_focused
+    }
+
+    // Total width of the bar, including the separators between tabs.
+    fn bar_width(&self) -> usize {
+        let widths: usize = self.tabs.iter().map(Tab::width).sum();
+        widths + self.tabs.len().saturating_sub(1)
+    }
+
+    fn bar_row(&self) -> usize {
+        match self.bar_position {
+            TabBarPosition::Top => 0,
+            TabBarPosition::Bottom => self.size.y.saturating_sub(1),
+        }
+    }
+
+    // Offset of the active tab's view.
+    fn content_offset(&self) -> Vec2 {
+        match self.bar_position {
+            TabBarPosition::Top => Vec2::new(0, 1),
+            TabBarPosition::Bottom => Vec2::zero(),
+        }
+    }
+
+    // Event moving the focus from the bar to the content.
+    fn is_towards_content(&self, event: &Event) -> bool {
+        match self.bar_position {
+            TabBarPosition::Top => matches!(event, Event::Key(Key::Down) | Event::Key(Key::Tab)),
+            TabBarPosition::Bottom => matches!(event, Event::Key(Key::Up) | Event::Shift(Key::Tab)),
+        }
+    }
+
+    // Event moving the focus from the content to the bar.
+    fn is_towards_bar(&self, event: &Event) -> bool {
+        match self.bar_position {
+            TabBarPosition::Top => matches!(event, Event::Key(Key::Up) | Event::Shift(Key::Tab)),
+            TabBarPosition::Bottom => matches!(event, Event::Key(Key::Down) | Event::Key(Key::Tab)),
+        }
+    }
+
+    // Direction the focus comes from when it enters the content from the bar.
+    fn bar_side(&self) -> Direction {
+        match self.bar_position {
+            TabBarPosition::Top => Direction::up(),
+            TabBarPosition::Bottom => Direction::down(),
+        }
+    }
+
+    // Returns the tab at the given horizontal position in the bar, and whether
+    // this is its close button.
+    fn tab_at(&self, x: usize) -> Option<(usize, bool)> {
+        let x = x + self.bar_scroll;
+        let i = (0..self.offsets.len())
+            .find(|&i| x >= self.offsets[i] && x < self.offsets[i] + self.tabs[i].width())?;
+
+        let tab = &self.tabs[i];
+        let close_x = self.offsets[i] + tab.label.width() + 2;
+        let on_close = tab.closable && x >= close_x && x < close_x + CLOSE_LABEL.width();
+        Some((i, on_close))
+    }
+
+    fn focus_content(&mut self, source: Direction) -> EventResult {
+        match self.tabs[self.active].view.take_focus(source) {
+            Ok(res) => {
+                self.bar_focused = false;
+                res
+            }
+            Err(CannotFocus) => EventResult::Ignored,
+        }
+    }
+
+    fn on_bar_click(&mut self, event: MouseEvent, x: usize) -> EventResult {
+        let Some((i, on_close)) = self.tab_at(x) else {
+            return EventResult::Ignored;
+        };
+
+        match event {
+            MouseEvent::Release(MouseButton::Left) if on_close => self.close_tab(i),
+            event if event.grabs_focus() => {
+                self.bar_focused = true;
+                self.set_active_tab(i);
+                EventResult::consumed()
+            }
+            _ => EventResult::Ignored,
+        }
+    }
+
+    fn on_bar_event(&mut self, event: Event) -> EventResult {
+        if self.is_towards_content(&event) {
+            return self.focus_content(self.bar_side());
+        }
+
+        let last = self.tabs.len() - 1;
+        match event {
+            Event::Key(Key::Left) if self.active > 0 => self.set_active_tab(self.active - 1),
+            Event::Key(Key::Right) if self.active < last => self.set_active_tab(self.active + 1),
+            Event::Shift(Key::Left) if self.active > 0 => {
+                self.move_tab(self.active, self.active - 1)
+            }
+            Event::Shift(Key::Right) if self.active < last => {
+                self.move_tab(self.active, self.active + 1)
+            }
+            Event::Key(Key::Del) if self.tabs[self.active].closable => {
+                return self.close_tab(self.active)
+            }
+            _ => return EventResult::Ignored,
+        }
+
+        EventResult::consumed()
+    }
+
+    fn on_content_event(&mut self, event: Event) -> EventResult {
+        let offset = self.content_offset();
+        let res = self.tabs[self.active]
+            .view
+            .on_event(event.relativized(offset));
+
+        if !res.is_consumed() && self.is_towards_bar(&event) {
+            self.bar_focused = true;
+            return EventResult::consumed();
+        }
+
+        res
+    }
+
+    fn draw_bar(&self, printer: &Printer) {
+        let printer = printer
+            .offset((0, self.bar_row()))
+            .cropped((printer.size.x, 1))
+            .content_offset((self.bar_scroll, 0));
+
+        for (i, tab) in self.tabs.iter().enumerate() {
+            let x = self.offsets[i];
+            if i > 0 {
+                printer.print((x - 1, 0), "|");
+            }
+
+            let style = if i != self.active {
+                PaletteStyle::Primary
+            } else if printer.focused && self.bar_focused {
+                PaletteStyle::Highlight
+            } else {
+                PaletteStyle::HighlightInactive
+            };
+
+            printer.with_style(style, |printer| {
+                printer.print((x, 0), " ");
+                printer.print((x + 1, 0), &tab.label);
+                printer.print((x + 1 + tab.label.width(), 0), " ");
+            });
+
+            if tab.closable {
+                let x = x + tab.label.width() + 2;
+                printer.with_style(PaletteStyle::Secondary, |printer| {
+                    printer.print((x, 0), CLOSE_LABEL);
+                });
+                printer.print((x + CLOSE_LABEL.width(), 0), " ");
+            }
+        }
+    }
+}
+
+impl View for TabView {
+    fn draw(&self, printer: &Printer) {
+        if self.tabs.is_empty() {
+            return;
+        }
+
+        self.draw_bar(printer);
+
+        let content_size = self.size.saturating_sub((0, 1));
+        let printer = printer
+            .offset(self.content_offset())
+            .cropped(content_size)
+            .focused(!self.bar_focused);
+        self.tabs[self.active].view.draw(&printer);
+    }
+
+    fn required_size(&mut self, constraint: Vec2) -> Vec2 {
+        let bar_width = self.bar_width();
+        let content = match self.tabs.get_mut(self.active) {
+            Some(tab) => tab.view.required_size(constraint.saturating_sub((0, 1))),
+            None => Vec2::zero(),
+        };
+
+        Vec2::new(bar_width.max(content.x), content.y + 1)
+    }
+
+    fn layout(&mut self, size: Vec2) {
+        self.size = size;
+
+        self.offsets.clear();
+        let mut x = 0;
+        for tab in &self.tabs {
+            self.offsets.push(x);
+            x += tab.width() + 1;
+        }
+
+        // Scroll the bar to keep the active tab visible.
+        if self.bar_width() <= size.x {
+            self.bar_scroll = 0;
+        } else if let Some(tab) = self.tabs.get(self.active) {
+            let start = self.offsets[self.active];
+            let end = start + tab.width();
+            if end > self.bar_scroll + size.x {
+                self.bar_scroll = end.saturating_sub(size.x);
+            }
+            self.bar_scroll = self.bar_scroll.min(start);
+        }
+
+        if let Some(tab) = self.tabs.get_mut(self.active) {
+            tab.view.layout(size.saturating_sub((0, 1)));
+        }
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        if self.tabs.is_empty() {
+            return EventResult::Ignored;
+        }
+
+        if let Some((next, previous)) = &self.switch_keys {
+            if event == *next {
+                return self.next_tab();
+            }
+            if event == *previous {
+                return self.previous_tab();
+            }
+        }
+
+        let mut result = EventResult::Ignored;
+        if let Event::Mouse {
+            event: mouse_event,
+            position,
+            offset,
+        } = event
+        {
+            let Some(position) = position.checked_sub(offset) else {
+                return EventResult::Ignored;
+            };
+
+            if position.y == self.bar_row() {
+                return self.on_bar_click(mouse_event, position.x);
+            }
+
+            if self.bar_focused && mouse_event.grabs_focus() {
+                result = self.focus_content(Direction::none());
+            }
+        }
+
+        if self.bar_focused {
+            self.on_bar_event(event)
+        } else {
+            result.and(self.on_content_event(event))
+        }
+    }
+
+    fn take_focus(&mut self, source: Direction) -> Result<EventResult, CannotFocus> {
+        if self.tabs.is_empty() {
+            return Err(CannotFocus);
+        }
+
+        let from_bar = match (source, self.bar_position) {
+            (Direction::Abs(Absolute::None), _) => self.bar_focused,
+            (
+                Direction::Abs(Absolute::Up) | Direction::Rel(Relative::Front),
+                TabBarPosition::Top,
+            ) => true,
+            (
+                Direction::Abs(Absolute::Down) | Direction::Rel(Relative::Back),
+                TabBarPosition::Bottom,
+            ) => true,
+            _ => false,
+        };
+
+        if !from_bar {
+            if let Ok(res) = self.tabs[self.active].view.take_focus(source) {
+                self.bar_focused = false;
+                return Ok(res);
+            }
+        }
+
+        self.bar_focused = true;
+        Ok(EventResult::consumed())
+    }
+
+    fn call_on_any(&mut self, selector: &Selector, callback: AnyCb) {
+        for tab in &mut self.tabs {
+            tab.view.call_on_any(selector, callback);
+        }
+    }
+
+    fn call_on_focused_child(&mut self, callback: AnyCb) {
+        // When the bar is focused, no child has the focus.
+        if self.bar_focused {
+            return;
+        }
+
+        if let Some(tab) = self.tabs.get_mut(self.active) {
+            callback(&mut tab.view);
+        }
+    }
+
+    fn focus_view(&mut self, selector: &Selector) -> Result<EventResult, ViewNotFound> {
+        for (i, tab) in self.tabs.iter_mut().enumerate() {
+            if let Ok(res) = tab.view.focus_view(selector) {
+                self.active = i;
+                self.bar_focused = false;
+                return Ok(res);
+            }
+        }
+
+        Err(ViewNotFound)
+    }
+
+    fn reveal_view(&mut self, selector: &Selector, size: Vec2) -> Result<Rect, ViewNotFound> {
+        // Only the active tab is visible.
+        let offset = self.content_offset();
+        let tab = self.tabs.get_mut(self.active).ok_or(ViewNotFound)?;
+        tab.view
+            .reveal_view(selector, size.saturating_sub((0, 1)))
+            .map(|area| area + offset)
+    }
+
+    fn important_area(&self, size: Vec2) -> Rect {
+        let Some(tab) = self.tabs.get(self.active) else {
+            return Rect::from_size((0, 0), size);
+        };
+
+        if self.bar_focused {
+            let x = self.offsets.get(self.active).copied().unwrap_or(0);
+            let x = x.saturating_sub(self.bar_scroll);
+            Rect::from_size((x, self.bar_row()), (tab.width(), 1))
+        } else {
+            let content_size = size.saturating_sub((0, 1));
+            tab.view.important_area(content_size) + self.content_offset()
+        }
+    }
+}
+
+// ```yaml
+// - TabView
+//     bar: bottom
+//     closable: true
+//     on_close: $on_close
+//     active: 1
+//     tabs:
+//         - Files:
+//             TextView: No file
+//         - Logs:
+//             TextView: Nothing yet
+// ```
+crate::manual_blueprint!(TabView, |config, context| {
+    use crate::builder::{Config, Context, Error, Resolvable};
+
+    struct Entry {
+        label: String,
+        view: BoxedView,
+    }
+
+    impl Resolvable for Entry {
+        fn from_config(config: &Config, context: &Context) -> Result<Self, Error> {
+            let config = config
+                .as_object()
+                .ok_or_else(|| Error::invalid_config("Expected object", config))?;
+
+            let (label, view) = config
+                .iter()
+                .next()
+                .ok_or_else(|| Error::invalid_config("Expected non-empty object", config))?;
+
+            Ok(Entry {
+                label: label.into(),
+                view: context.resolve(view)?,
+            })
+        }
+    }
+
+    let mut tabs = TabView::new();
+
+    match context
+        .resolve::<Option<String>>(&config["bar"])?
+        .as_deref()
+    {
+        Some("top") | None => (),
+        Some("bottom") => tabs.set_bar_position(TabBarPosition::Bottom),
+        Some(_) => {
+            return Err(Error::invalid_config(
+                "Expected top or bottom",
+                &config["bar"],
+            ))
+        }
+    }
+
+    if let Some(closable) = context.resolve(&config["closable"])? {
+        tabs.set_closable(closable);
+    }
+
+    if let Some(on_close) = context.resolve(&config["on_close"])? {
+        tabs.set_on_close_cb(on_close);
+    }
+
+    let entries: Option<Vec<Entry>> = context.resolve(&config["tabs"])?;
+    for entry in entries.unwrap_or_default() {
+        tabs.add_tab(entry.label, entry.view);
+    }
+
+    if let Some(active) = context.resolve::<Option<usize>>(&config["active"])? {
+        if active >= tabs.len() {
+            return Err(Error::invalid_config(
+                "TabView.active cannot be larger than the number of tabs.",
+                config,
+            ));
+        }
+        tabs.set_active_tab(active);
+    }
+
+    Ok(tabs)
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::{EditView, TextView};
+
+    fn tabs() -> TabView {
+        TabView::new()
+            .tab("One", EditView::new())
+            .tab("Two", TextView::new("2"))
+            .tab("Three", EditView::new())
+    }
+
+    #[test]
+    fn move_and_close() {
+        let mut tabs = tabs().closable();
+        tabs.set_active_tab(1);
+
+        tabs.move_tab(1, 2);
+        assert_eq!(tabs.labels().collect::<Vec<_>>(), ["One", "Three", "Two"]);
+        assert_eq!(tabs.active_label(), Some("Two"));
+
+        tabs.move_tab(0, 2);
+        assert_eq!(tabs.labels().collect::<Vec<_>>(), ["Three", "Two", "One"]);
+        assert_eq!(tabs.active_label(), Some("Two"));
+
+        let closed = Arc::new(std::sync::Mutex::new(Vec::new()));
+        tabs.set_on_close({
+            let closed = Arc::clone(&closed);
+            move |_, label| closed.lock().unwrap().push(label.to_string())
+        });
+
+        let mut siv = Cursive::new();
+        tabs.close_tab(0).process(&mut siv);
+        assert_eq!(tabs.active_label(), Some("Two"));
+        tabs.close_tab(0).process(&mut siv);
+        assert_eq!(tabs.active_label(), Some("One"));
+        assert_eq!(*closed.lock().unwrap(), ["Three", "Two"]);
+    }
+
+    #[test]
+    fn bar_navigation() {
+        let mut tabs = tabs().closable();
+        tabs.layout(Vec2::new(30, 5));
+        tabs.take_focus(Direction::up()).unwrap();
+        assert!(tabs.is_bar_focused());
+
+        tabs.on_event(Event::Key(Key::Right));
+        assert_eq!(tabs.active_tab(), Some(1));
+
+        // Reorder from the bar.
+        tabs.on_event(Event::Shift(Key::Left));
+        assert_eq!(tabs.active_tab(), Some(0));
+        assert_eq!(tabs.active_label(), Some("Two"));
+
+        // `Two` is a TextView, which cannot take the focus.
+        tabs.on_event(Event::Key(Key::Down));
+        assert!(tabs.is_bar_focused());
+
+        tabs.on_event(Event::Ctrl(Key::Tab));
+        assert_eq!(tabs.active_label(), Some("One"));
+        tabs.on_event(Event::Key(Key::Down));
+        assert!(!tabs.is_bar_focused());
+
+        // Back to the bar when the content ignores the key.
+        tabs.on_event(Event::Key(Key::Up));
+        assert!(tabs.is_bar_focused());
+
+        tabs.on_event(Event::Key(Key::Del));
+        assert_eq!(tabs.labels().collect::<Vec<_>>(), ["Two", "Three"]);
+    }
+
+    #[test]
+    fn bar_mouse() {
+        let mut tabs = tabs().closable();
+        tabs.layout(Vec2::new(40, 5));
+
+        // | One × | Two × | Three × |
+        assert_eq!(tabs.offsets, [0, 8, 16]);
+        assert_eq!(tabs.tab_at(9), Some((1, false)));
+        assert_eq!(tabs.tab_at(13), Some((1, true)));
+        assert_eq!(tabs.tab_at(15), None);
+
+        let click = |event, x| Event::Mouse {
+            offset: Vec2::zero(),
+            position: Vec2::new(x, 0),
+            event,
+        };
+
+        tabs.on_event(click(MouseEvent::Press(MouseButton::Left), 17));
+        assert_eq!(tabs.active_label(), Some("Three"));
+
+        tabs.on_event(click(MouseEvent::Release(MouseButton::Left), 5));
+        assert_eq!(tabs.labels().collect::<Vec<_>>(), ["Two", "Three"]);
+        assert_eq!(tabs.active_label(), Some("Three"));
+    }
+}
@@ -1,18 +1,24 @@
 #[allow(deprecated)]
 use crate::{
+    clipboard,
     direction::Direction,
     event::{Event, EventResult, Key, MouseButton, MouseEvent},
     rect::Rect,
-    style::PaletteStyle,
+    style::{PaletteStyle, StyleType},
     utils::lines::simple::{prefix, simple_prefix, LinesIterator, Row},
+    utils::markup::StyledString,
+    utils::{ViAction, ViBindings, ViMode},
     view::{CannotFocus, ScrollBase, SizeCache, View},
     Vec2, {Printer, With, XY},
 };
 use log::debug;
 use std::cmp::min;
+use std::sync::Arc;
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
+type Highlighter = dyn Fn(&str) -> StyledString + Send + Sync;
+
 /// Multi-lines text editor.
 ///
 /// A `TextArea` will attempt to grow vertically and horizontally
@@ -52,7 +58,91 @@ pub struct TextArea {
     last_size: Vec2,
 
     /// Byte offset of the currently selected grapheme.
+    ///
+    /// Invariant: always at the start of a grapheme (or at the end of the content).
     cursor: usize,
+
+    /// Text selected with the mouse, as `(anchor, head)` byte offsets.
+    selection: Option<(usize, usize)>,
+
+    /// Styles computed for each line, if a highlighter is set.
+    highlighting: Option<Highlighting>,
+
+    /// Modal editing state, if vi bindings are enabled.
+    vi: Option<ViBindings>,
+}
+
+struct Highlighting {
+    highlighter: Arc<Highlighter>,
+
+    /// Byte offset of the start of each line.
+    line_starts: Vec<usize>,
+
+    /// Highlighted version of each line.
+    lines: Vec<StyledString>,
+}
+
+impl Highlighting {
+    fn new(highlighter: Arc<Highlighter>, content: &str) -> Self {
+        let mut highlighting = Highlighting {
+            highlighter,
+            line_starts: Vec::new(),
+            lines: Vec::new(),
+        };
+        highlighting.update(content, 0, 0, usize::MAX);
+        highlighting
+    }
+
+    /// Returns the index of the line containing the given byte.
+    fn line_at(&self, byte_offset: usize) -> usize {
+        self.line_starts
+            .partition_point(|&start| start <= byte_offset)
+            .saturating_sub(1)
+    }
+
+    /// Re-highlights lines after an edit.
+    ///
+    /// `old_lines` lines starting at `first_line` were replaced by `new_lines` lines.
+    fn update(&mut self, content: &str, first_line: usize, old_lines: usize, new_lines: usize) {
+        self.line_starts.clear();
+        self.line_starts.push(0);
+        self.line_starts
+            .extend(content.match_indices('\n').map(|(i, _)| i + 1));
+
+        let end = min(first_line.saturating_add(new_lines), self.line_starts.len());
+        let highlighted: Vec<_> = (first_line..end)
+            .map(|line| (self.highlighter)(self.line_text(content, line)))
+            .collect();
+
+        let old_end = min(first_line + old_lines, self.lines.len());
+        self.lines.splice(first_line..old_end, highlighted);
+    }
+
+    fn line_text<'a>(&self, content: &'a str, line: usize) -> &'a str {
+        let start = self.line_starts[line];
+        let end = self
+            .line_starts
+            .get(line + 1)
+            .map_or(content.len(), |&next| next - 1);
+        &content[start..end]
+    }
+}
+
+/// Returns the start of the grapheme containing the given byte offset.
+fn grapheme_start(text: &str, offset: usize) -> usize {
+    // Graphemes never span across a newline, so only look at the current line.
+    let mut line_start = offset;
+    while line_start > 0 && text.as_bytes()[line_start - 1] != b'\n' {
+        line_start -= 1;
+    }
+
+    text[line_start..]
+        .grapheme_indices(true)
+        .map(|(i, _)| line_start + i)
+        .chain(std::iter::once(text.len()))
+        .take_while(|&i| i <= offset)
+        .last()
+        .unwrap_or(line_start)
 }
 
 fn make_rows(text: &str, width: usize) -> Vec<Row> {
@@ -75,6 +165,9 @@ impl TextArea {
             size_cache: None,
             last_size: Vec2::zero(),
             cursor: 0,
+            selection: None,
+            highlighting: None,
+            vi: None,
         }
         .with(|area| area.compute_rows(Vec2::new(1, 1)))
         // Make sure we have valid rows, even for empty text.
@@ -90,39 +183,101 @@ impl TextArea {
         self.size_cache = None;
     }
 
+    /// Returns the position of the cursor, as a `(row, grapheme)` pair.
+    ///
+    /// `row` is the index of the line (separated by `\n`) containing the
+    /// cursor, and `grapheme` is the number of graphemes to the left of the
+    /// cursor in this line.
+    ///
+    /// Wide characters (like CJK or most emojis) and multi-codepoint
+    /// graphemes count as a single grapheme.
+    pub fn cursor(&self) -> (usize, usize) {
+        let line_start = self.content[..self.cursor].rfind('\n').map_or(0, |i| i + 1);
+
+        let row = self.content[..line_start].matches('\n').count();
+        let grapheme = self.content[line_start..self.cursor]
+            .graphemes(true)
+            .count();
+
+        (row, grapheme)
+    }
+
+    /// Moves the cursor to the given `(row, grapheme)` position.
+    ///
+    /// `row` is the index of a line (separated by `\n`), and `grapheme` the
+    /// number of graphemes to the left of the cursor in this line.
+    ///
+    /// Positions outside of the content are clamped: to the last line if
+    /// `row` is too large, and to the end of the line if `grapheme` is.
+    pub fn set_cursor(&mut self, (row, grapheme): (usize, usize)) {
+        let line_start = match row.checked_sub(1) {
+            None => 0,
+            Some(newlines) => self
+                .content
+                .match_indices('\n')
+                .nth(newlines)
+                .map(|(i, _)| i)
+                .or_else(|| self.content.rfind('\n'))
+                .map_or(0, |i| i + 1),
+        };
+
+        let line = self.content[line_start..].split('\n').next().unwrap();
+        // A `\r\n` line ending is a single grapheme: we can't stop before the `\n`.
+        let line = line.strip_suffix('\r').unwrap_or(line);
+
+        let offset = line
+            .grapheme_indices(true)
+            .nth(grapheme)
+            .map_or(line.len(), |(i, _)| i);
+
+        self.set_cursor_offset(line_start + offset);
+    }
+
     /// Returns the position of the cursor in the content string.
     ///
     /// This is a byte index.
-    pub fn cursor(&self) -> usize {
+    pub fn cursor_offset(&self) -> usize {
         self.cursor
     }
 
     /// Moves the cursor to the given byte position.
     ///
-    /// # Panics
-    ///
-    /// This method panics if `cursor` is not the starting byte of a character in
-    /// the content string.
-    pub fn set_cursor(&mut self, cursor: usize) {
-        // TODO: What to do if we fall inside a multi-codepoint grapheme?
-        // Move back to the start of the grapheme?
-        self.cursor = cursor;
+    /// If `offset` falls inside a grapheme, the cursor is moved to the start
+    /// of this grapheme. If it is past the end of the content, the cursor is
+    /// moved to the end.
+    pub fn set_cursor_offset(&mut self, offset: usize) {
+        self.cursor = grapheme_start(&self.content, min(offset, self.content.len()));
 
         let focus = self.selected_row();
         self.scrollbase.scroll_to(focus);
     }
 
+    /// Returns the text currently selected with the mouse, if any.
+    pub fn selected_text(&self) -> Option<&str> {
+        let (start, end) = self.selection_range()?;
+        Some(&self.content[start..end])
+    }
+
+    /// Returns the selected range of bytes, if it isn't empty.
+    fn selection_range(&self) -> Option<(usize, usize)> {
+        let (anchor, head) = self.selection?;
+        (anchor != head).then(|| (min(anchor, head), anchor.max(head)))
+    }
+
     /// Sets the content of the view.
     pub fn set_content<S: Into<String>>(&mut self, content: S) {
         self.content = content.into();
+        self.selection = None;
 
         // First, make sure we are within the bounds.
         self.cursor = min(self.cursor, self.content.len());
 
-        // We have no guarantee cursor is now at a correct UTF8 location.
+        // We have no guarantee cursor is now at a correct location.
         // So look backward until we find a valid grapheme start.
-        while !self.content.is_char_boundary(self.cursor) {
-            self.cursor -= 1;
+        self.cursor = grapheme_start(&self.content, self.cursor);
+
+        if let Some(highlighting) = self.highlighting.take() {
+            self.highlighting = Some(Highlighting::new(highlighting.highlighter, &self.content));
         }
 
         if let Some(size) = self.size_cache.map(|s| s.map(|s| s.value)) {
@@ -139,6 +294,108 @@ impl TextArea {
         self.with(|s| s.set_content(content))
     }
 
+    /// Sets a function to style the content.
+    ///
+    /// The highlighter is called on each line of the content (without the
+    /// trailing newline), and should return the same text with styles
+    /// applied. After an edit, only the modified lines are highlighted again.
+    ///
+    /// This can be used with `cursive-syntect` to make a code editor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::style::Effect;
+    /// use cursive_core::utils::markup::StyledString;
+    /// use cursive_core::views::TextArea;
+    ///
+    /// // Make comments bold.
+    /// let text_area = TextArea::new().highlighter(|line| {
+    ///     if line.starts_with('#') {
+    ///         StyledString::styled(line, Effect::Bold)
+    ///     } else {
+    ///         StyledString::plain(line)
+    ///     }
+    /// });
+    /// ```
+    pub fn set_highlighter<F>(&mut self, highlighter: F)
+    where
+        F: Fn(&str) -> StyledString + 'static + Send + Sync,
+    {
+        self.highlighting = Some(Highlighting::new(Arc::new(highlighter), &self.content));
+    }
+
+    /// Sets a function to style the content.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn highlighter<F>(self, highlighter: F) -> Self
+    where
+        F: Fn(&str) -> StyledString + 'static + Send + Sync,
+    {
+        self.with(|s| s.set_highlighter(highlighter))
+    }
+
+    /// Removes the highlighter, if any.
+    ///
+    /// The content will be shown as plain text.
+    pub fn clear_highlighter(&mut self) {
+        self.highlighting = None;
+    }
+
+    /// Enables or disables vi-style modal editing.
+    ///
+    /// When enabled, the view starts in normal mode. See [`ViBindings`] for
+    /// the supported commands.
+    pub fn set_vi_bindings(&mut self, enabled: bool) {
+        self.vi = if enabled {
+            Some(ViBindings::new())
+        } else {
+            None
+        };
+        self.selection = None;
+    }
+
+    /// Enables vi-style modal editing.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn with_vi_bindings(self) -> Self {
+        self.with(|s| s.set_vi_bindings(true))
+    }
+
+    /// Returns the current editing mode, if vi bindings are enabled.
+    pub fn vi_mode(&self) -> Option<ViMode> {
+        self.vi.as_ref().map(ViBindings::mode)
+    }
+
+    // Gives the event to the vi bindings, if any.
+    //
+    // Returns `None` if the event should be handled as usual.
+    fn on_vi_event(&mut self, event: &Event) -> Option<EventResult> {
+        let vi = self.vi.as_mut()?;
+        let result = match vi.on_event(&self.content, self.cursor, event, true) {
+            ViAction::Pass => return None,
+            ViAction::Ignored => EventResult::Ignored,
+            ViAction::Consumed => EventResult::consumed(),
+            ViAction::Move(cursor) => {
+                self.set_cursor_offset(cursor);
+                EventResult::consumed()
+            }
+            ViAction::Edit { content, cursor } => {
+                self.replace_changed(&content);
+                self.set_cursor_offset(cursor);
+                EventResult::consumed()
+            }
+        };
+
+        self.selection = self
+            .vi
+            .as_ref()
+            .and_then(|vi| vi.selection(&self.content, self.cursor));
+        Some(result)
+    }
+
     /// Disables this view.
     ///
     /// A disabled view cannot be selected.
@@ -205,6 +462,16 @@ impl TextArea {
         self.col_at(self.cursor)
     }
 
+    /// Returns the byte offset for the given position in the view.
+    fn offset_at(&self, position: Vec2) -> usize {
+        #[allow(deprecated)]
+        let y = position.y + self.scrollbase.start_line;
+        let row = &self.rows[min(y, self.rows.len() - 1)];
+        let content = &self.content[row.start..row.end];
+
+        row.start + simple_prefix(content, position.x).length
+    }
+
     fn page_up(&mut self) {
         for _ in 0..5 {
             self.move_up();
@@ -345,6 +612,8 @@ impl TextArea {
             .len();
         let start = self.cursor;
         let end = self.cursor + len;
+        let removed_lines = self.content[start..end].matches('\n').count();
+        let line = self.highlighted_line();
         debug!("Start/end: {}/{}", start, end);
         debug!("Content: `{}`", self.content);
         for _ in self.content.drain(start..end) {}
@@ -367,11 +636,15 @@ impl TextArea {
         }
         debug!("Rows: {:?}", self.rows);
 
+        self.update_highlighting(line, 1 + removed_lines, 1);
+
         self.fix_damages();
         debug!("Rows: {:?}", self.rows);
     }
 
     fn insert(&mut self, ch: char) {
+        let line = self.highlighted_line();
+
         // First, we inject the data, but keep the cursor unmoved
         // (So the cursor is to the left of the injected char)
         self.content.insert(self.cursor, ch);
@@ -388,10 +661,115 @@ impl TextArea {
         }
         self.cursor += shift;
 
+        // The new char may have joined the next grapheme (like a `\r` before a `\n`).
+        // In this case, put the cursor after the entire grapheme.
+        let start = grapheme_start(&self.content, self.cursor);
+        if start != self.cursor {
+            self.cursor = start
+                + self.content[start..]
+                    .graphemes(true)
+                    .next()
+                    .map_or(0, str::len);
+        }
+
+        self.update_highlighting(line, 1, if ch == '\n' { 2 } else { 1 });
+
         // Finally, rows may not have the correct width anymore, so fix them.
         self.fix_damages();
     }
 
+    /// Inserts `text` at the cursor position, and moves the cursor after it.
+    pub fn insert_str(&mut self, text: &str) {
+        let line = self.highlighted_line();
+
+        self.content.insert_str(self.cursor, text);
+        self.selection = None;
+        self.cursor += text.len();
+
+        // As with a single char, the text may have joined the next grapheme.
+        let start = grapheme_start(&self.content, self.cursor);
+        if start != self.cursor {
+            self.cursor = start
+                + self.content[start..]
+                    .graphemes(true)
+                    .next()
+                    .map_or(0, str::len);
+        }
+
+        let new_lines = 1 + text.matches('\n').count();
+        self.update_highlighting(line, 1, new_lines);
+
+        if let Some(size) = self.size_cache.map(|s| s.map(|s| s.value)) {
+            self.invalidate();
+            self.compute_rows(size);
+        }
+    }
+
+    /// Replaces the content with `new`, only editing the part that changed.
+    ///
+    /// Unlike `set_content`, this only highlights the modified lines again.
+    fn replace_changed(&mut self, new: &str) {
+        let old = &self.content;
+
+        let mut prefix = old
+            .bytes()
+            .zip(new.bytes())
+            .take_while(|(a, b)| a == b)
+            .count();
+        while !old.is_char_boundary(prefix) || !new.is_char_boundary(prefix) {
+            prefix -= 1;
+        }
+
+        let max_suffix = min(old.len(), new.len()) - prefix;
+        let mut suffix = old
+            .bytes()
+            .rev()
+            .zip(new.bytes().rev())
+            .take(max_suffix)
+            .take_while(|(a, b)| a == b)
+            .count();
+        while !old.is_char_boundary(old.len() - suffix) || !new.is_char_boundary(new.len() - suffix)
+        {
+            suffix -= 1;
+        }
+
+        let range = prefix..old.len() - suffix;
+        let text = &new[prefix..new.len() - suffix];
+
+        let line = self
+            .highlighting
+            .as_ref()
+            .map_or(0, |highlighting| highlighting.line_at(range.start));
+        let old_lines = 1 + self.content[range.clone()].matches('\n').count();
+        let new_lines = 1 + text.matches('\n').count();
+
+        self.content.replace_range(range, text);
+        self.selection = None;
+        self.cursor = grapheme_start(&self.content, min(self.cursor, self.content.len()));
+        self.update_highlighting(line, old_lines, new_lines);
+
+        if let Some(size) = self.size_cache.map(|s| s.map(|s| s.value)) {
+            self.invalidate();
+            self.compute_rows(size);
+        }
+    }
+
+    /// Re-highlights the lines modified by an edit.
+    ///
+    /// `line` is the line where the edit happened, _before_ the edit.
+    fn update_highlighting(&mut self, line: usize, old_lines: usize, new_lines: usize) {
+        if let Some(highlighting) = &mut self.highlighting {
+            highlighting.update(&self.content, line, old_lines, new_lines);
+        }
+    }
+
+    /// Returns the line of the cursor, if a highlighter is set.
+    fn highlighted_line(&self) -> usize {
+        self.highlighting
+            .as_ref()
+            .map_or(0, |highlighting| highlighting.line_at(self.cursor))
+    }
+
     /// Fix a damage located at the cursor.
     ///
     /// The only damages are assumed to have occurred around the cursor.
@@ -463,6 +841,41 @@ impl TextArea {
         self.fix_ghost_row();
         self.scrollbase.set_heights(size.y, self.rows.len());
     }
+
+    /// Prints a row using the styles from the highlighter.
+    fn draw_highlighted(&self, printer: &Printer, highlighting: &Highlighting, row: &Row) {
+        let line = highlighting.line_at(row.start);
+        let line_start = highlighting.line_starts[line];
+        let styled = &highlighting.lines[line];
+
+        // Highlighters are supposed to keep the text, but just in case...
+        if styled.source() != highlighting.line_text(&self.content, line) {
+            printer.print((0, 0), &self.content[row.start..row.end]);
+            return;
+        }
+
+        // Byte range of this row within the line.
+        let (row_start, row_end) = (row.start - line_start, row.end - line_start);
+
+        let mut offset = 0;
+        let mut x = 0;
+        for span in styled.spans() {
+            let (start, end) = (offset, offset + span.content.len());
+            offset = end;
+
+            let start = start.max(row_start);
+            let end = end.min(row_end);
+            if start >= end {
+                continue;
+            }
+
+            let text = &styled.source()[start..end];
+            printer.with_style(*span.attr, |printer| {
+                printer.print((x, 0), text);
+            });
+            x += text.width();
+        }
+    }
 }
 
 impl View for TextArea {
@@ -495,6 +908,13 @@ impl View for TextArea {
                 PaletteStyle::EditableTextInactive,
             )
         };
+        let style: StyleType = if self.enabled && printer.enabled && printer.focused {
+            let palette = &printer.theme.palette;
+            let focus = printer.theme.focus.input.resolve(palette);
+            style.resolve(palette).combine(focus).into()
+        } else {
+            style.into()
+        };
 
         let w = if self.scrollbase.scrollable() {
             printer.size.x.saturating_sub(1)
@@ -514,10 +934,21 @@ impl View for TextArea {
             debug!("row: {:?}", row);
             let text = &self.content[row.start..row.end];
             debug!("row text: `{}`", text);
-            printer.with_style(style, |printer| {
-                printer.print((0, 0), text);
+            printer.with_style(style, |printer| match &self.highlighting {
+                Some(highlighting) => self.draw_highlighted(printer, highlighting, row),
+                None => printer.print((0, 0), text),
             });
 
+            if let Some((start, end)) = self.selection_range() {
+                if start < row.end && end > row.start {
+                    let first = self.content[row.start..start.max(row.start)].width();
+                    let last = self.content[row.start..end.min(row.end)].width();
+                    // Include the newline at the end of the row, if selected.
+                    let last = if end > row.end { last + 1 } else { last };
+                    printer.highlight_selection((first, 0), (last, 0));
+                }
+            }
+
             if printer.focused && i == self.selected_row() {
                 let cursor_offset = self.cursor - row.start;
                 let c = if cursor_offset == text.len() {
@@ -541,9 +972,19 @@ impl View for TextArea {
             return EventResult::Ignored;
         }
 
+        if let Some(result) = self.on_vi_event(&event) {
+            return result;
+        }
+
+        if !matches!(event, Event::Mouse { .. }) {
+            // Any other input cancels the selection.
+            self.selection = None;
+        }
+
         let mut fix_scroll = true;
         match event {
             Event::Char(ch) => self.insert(ch),
+            Event::Paste(text) => self.insert_str(&text),
             Event::Key(Key::Enter) => self.insert('\n'),
             Event::Key(Key::Backspace) if self.cursor > 0 => self.backspace(),
             Event::Key(Key::Del) if self.cursor < self.content.len() => self.delete(),
@@ -594,24 +1035,36 @@ impl View for TextArea {
                 position,
                 offset,
             } => {
-                fix_scroll = false;
                 let position = position.saturating_sub(offset);
-                self.scrollbase.drag(position);
+                if self.scrollbase.is_dragging() {
+                    fix_scroll = false;
+                    self.scrollbase.drag(position);
+                } else if self.selection.is_some() {
+                    self.cursor = self.offset_at(position);
+                    self.selection = self.selection.map(|(anchor, _)| (anchor, self.cursor));
+                }
+            }
+            Event::Mouse {
+                event: MouseEvent::Release(MouseButton::Left),
+                ..
+            } => {
+                self.scrollbase.release_grab();
+                return match self.selected_text() {
+                    Some(text) => {
+                        EventResult::Consumed(Some(clipboard::copy_callback(text.to_string())))
+                    }
+                    None => EventResult::Ignored,
+                };
             }
             Event::Mouse {
-                event: MouseEvent::Press(_),
+                event: MouseEvent::Press(button),
                 position,
                 offset,
             } if !self.rows.is_empty() && position.fits_in_rect(offset, self.last_size) => {
                 if let Some(position) = position.checked_sub(offset) {
-                    #[allow(deprecated)]
-                    let y = position.y + self.scrollbase.start_line;
-                    let y = min(y, self.rows.len() - 1);
-                    let x = position.x;
-                    let row = &self.rows[y];
-                    let content = &self.content[row.start..row.end];
-
-                    self.cursor = row.start + simple_prefix(content, x).length;
+                    self.cursor = self.offset_at(position);
+                    self.selection =
+                        (button == MouseButton::Left).then(|| (self.cursor, self.cursor));
                 }
             }
             _ => return EventResult::Ignored,
@@ -647,6 +1100,8 @@ impl View for TextArea {
                 .next()
                 .unwrap()
                 .width()
+                // Zero-width graphemes still need to be visible.
+                .max(1)
         };
 
         Rect::from_size((self.selected_col(), self.selected_row()), (char_width, 1))
@@ -656,4 +1111,223 @@ impl View for TextArea {
 #[crate::blueprint(TextArea::new())]
 struct Blueprint {
     content: Option<String>,
+
+    vi_bindings: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TextArea;
+    use crate::event::{Event, Key};
+    use crate::utils::markup::StyledString;
+    use crate::view::View;
+    use crate::Vec2;
+
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn highlight_edited_lines() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let highlighted = Arc::clone(&calls);
+
+        let mut area = TextArea::new()
+            .content("one\ntwo\nthree")
+            .highlighter(move |line| {
+                highlighted.lock().unwrap().push(line.to_string());
+                StyledString::plain(line)
+            });
+        area.layout(Vec2::new(10, 10));
+        assert_eq!(*calls.lock().unwrap(), ["one", "two", "three"]);
+        calls.lock().unwrap().clear();
+
+        // Type at the end of the second line.
+        area.set_cursor((1, 3));
+        area.on_event(Event::Char('s'));
+        assert_eq!(*calls.lock().unwrap(), ["twos"]);
+        calls.lock().unwrap().clear();
+
+        // Splitting a line highlights both halves.
+        area.on_event(Event::Key(Key::Left));
+        area.on_event(Event::Key(Key::Enter));
+        assert_eq!(*calls.lock().unwrap(), ["two", "s"]);
+        calls.lock().unwrap().clear();
+
+        // Merging them back highlights the result.
+        area.on_event(Event::Key(Key::Backspace));
+        assert_eq!(*calls.lock().unwrap(), ["twos"]);
+
+        let lines: Vec<_> = area
+            .highlighting
+            .as_ref()
+            .unwrap()
+            .lines
+            .iter()
+            .map(|line| line.source().to_string())
+            .collect();
+        assert_eq!(lines, ["one", "twos", "three"]);
+    }
+
+    #[test]
+    fn grapheme_cursor() {
+        // Wide characters, a combining accent, an emoji with a modifier and a
+        // nerd-font glyph: each is a single grapheme.
+        let mut area = TextArea::new().content("漢字e\u{301}👍🏽\u{f07b}!\nab");
+        area.layout(Vec2::new(20, 5));
+
+        let mut positions = vec![area.cursor()];
+        for _ in 0..8 {
+            area.on_event(Event::Key(Key::Right));
+            positions.push(area.cursor());
+        }
+        assert_eq!(
+            positions,
+            [
+                (0, 0),
+                (0, 1),
+                (0, 2),
+                (0, 3),
+                (0, 4),
+                (0, 5),
+                (0, 6),
+                (1, 0),
+                (1, 1)
+            ]
+        );
+
+        // Backspace removes an entire grapheme.
+        area.set_cursor((0, 3));
+        area.on_event(Event::Key(Key::Backspace));
+        assert_eq!(area.get_content(), "漢字👍🏽\u{f07b}!\nab");
+        assert_eq!(area.cursor(), (0, 2));
+
+        // Columns are counted in cells: the cursor lands after `漢` (2 cells).
+        area.set_cursor((1, 2));
+        area.on_event(Event::Key(Key::Up));
+        assert_eq!(area.cursor(), (0, 1));
+        assert_eq!(
+            area.important_area(Vec2::new(20, 5)).top_left(),
+            Vec2::new(2, 0)
+        );
+    }
+
+    #[test]
+    fn set_cursor_clamps() {
+        let mut area = TextArea::new().content("ab\r\ncd");
+
+        area.set_cursor((0, 10));
+        assert_eq!(area.cursor(), (0, 2));
+        assert_eq!(area.cursor_offset(), 2);
+
+        area.set_cursor((5, 1));
+        assert_eq!(area.cursor(), (1, 1));
+
+        // Offsets inside a grapheme are moved to its start.
+        area.set_cursor_offset(3);
+        assert_eq!(area.cursor_offset(), 2);
+        area.set_cursor_offset(100);
+        assert_eq!(area.cursor_offset(), 6);
+
+        area.set_content("\u{1f468}\u{200d}\u{1f469}");
+        assert_eq!(area.cursor_offset(), 0);
+    }
+
+    #[test]
+    fn mouse_selection() {
+        use crate::event::{EventResult, MouseButton, MouseEvent};
+
+        let mouse = |event, position: (usize, usize)| Event::Mouse {
+            event,
+            position: position.into(),
+            offset: Vec2::zero(),
+        };
+
+        let mut area = TextArea::new().content("first line\n漢字 second");
+        area.layout(Vec2::new(20, 5));
+
+        area.on_event(mouse(MouseEvent::Press(MouseButton::Left), (6, 0)));
+        area.on_event(mouse(MouseEvent::Hold(MouseButton::Left), (4, 1)));
+        assert_eq!(area.selected_text(), Some("line\n漢字"));
+        assert_eq!(area.cursor(), (1, 2));
+
+        let result = area.on_event(mouse(MouseEvent::Release(MouseButton::Left), (4, 1)));
+        assert!(matches!(result, EventResult::Consumed(Some(_))));
+
+        // Typing cancels the selection.
+        area.on_event(Event::Char('!'));
+        assert_eq!(area.selected_text(), None);
+        assert_eq!(area.get_content(), "first line\n漢字! second");
+    }
+
+    #[test]
+    fn paste() {
+        let mut area = TextArea::new().content("ab");
+        area.layout(Vec2::new(10, 5));
+        area.set_cursor((0, 1));
+
+        area.on_event(Event::Paste("one\ntwo\n".into()));
+        assert_eq!(area.get_content(), "aone\ntwo\nb");
+        assert_eq!(area.cursor(), (2, 0));
+        assert_eq!(area.rows.len(), 3);
+    }
+
+    #[test]
+    fn vi_bindings() {
+        let mut area = TextArea::new().content("one\ntwo").with_vi_bindings();
+        area.layout(Vec2::new(10, 5));
+        area.set_cursor_offset(0);
+
+        for c in "ddp".chars() {
+            area.on_event(Event::Char(c));
+        }
+        assert_eq!(area.get_content(), "two\none");
+        assert_eq!(area.cursor(), (1, 0));
+
+        area.on_event(Event::Char('v'));
+        area.on_event(Event::Key(Key::Right));
+        assert_eq!(area.selected_text(), Some("on"));
+        area.on_event(Event::Char('d'));
+        assert_eq!(area.get_content(), "two\ne");
+        assert_eq!(area.selected_text(), None);
+    }
+
+    #[test]
+    fn vi_highlight_edited_lines() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let highlighted = Arc::clone(&calls);
+
+        let mut area = TextArea::new()
+            .content("one\ntwo\nthree")
+            .highlighter(move |line| {
+                highlighted.lock().unwrap().push(line.to_string());
+                StyledString::plain(line)
+            })
+            .with_vi_bindings();
+        area.layout(Vec2::new(10, 10));
+        calls.lock().unwrap().clear();
+
+        area.set_cursor((1, 0));
+        area.on_event(Event::Char('x'));
+        assert_eq!(*calls.lock().unwrap(), ["wo"]);
+        calls.lock().unwrap().clear();
+
+        // Deleting a line highlights the line now in its place.
+        for c in "dd".chars() {
+            area.on_event(Event::Char(c));
+        }
+        assert_eq!(area.get_content(), "one\nthree");
+        assert_eq!(*calls.lock().unwrap(), ["three"]);
+        calls.lock().unwrap().clear();
+
+        area.on_event(Event::Char('p'));
+        assert_eq!(area.get_content(), "one\nthree\nwo");
+        let lines: Vec<_> = area
+            .highlighting
+            .as_ref()
+            .unwrap()
+            .lines
+            .iter()
+            .map(|line| line.source().to_string())
+            .collect();
+        assert_eq!(lines, ["one", "three", "wo"]);
+    }
 }
@@ -1,14 +1,24 @@
 use parking_lot::Mutex;
-use std::ops::Deref;
+use std::collections::{HashMap, VecDeque};
+use std::ops::{Deref, Range};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
 use unicode_width::UnicodeWidthStr;
 
 use crate::align::*;
-use crate::style::{Effect, StyleType};
+use crate::clipboard;
+use crate::direction::Direction;
+use crate::event::{Event, EventResult, Key, MouseButton, MouseEvent};
+use crate::rect::Rect;
+use crate::style::{Effect, PaletteStyle, StyleType};
+use crate::utils::lines::simple::simple_prefix;
 use crate::utils::lines::spans::{LinesIterator, Row};
+use crate::utils::lines::tabs::{expand_tabs, TabStops};
 use crate::utils::markup::StyledString;
-use crate::view::{SizeCache, View};
+use crate::utils::placeholder;
+use crate::utils::span::SpannedStr;
+use crate::view::{CannotFocus, SizeCache, View};
 use crate::{Printer, Vec2, With, XY};
 
 // Content type used internally for caching and storage
@@ -44,13 +54,17 @@ impl TextContent {
     where
         S: Into<StyledString>,
     {
-        let content = Arc::new(content.into());
+        let content: StyledString = content.into();
+        let newlines = count_newlines(&content);
 
         TextContent {
             content: Arc::new(Mutex::new(TextContentInner {
-                content_value: content,
+                content_value: Arc::new(content),
                 content_cache: Arc::new(StyledString::default()),
                 size_cache: None,
+                rows: HashMap::new(),
+                newlines,
+                max_lines: None,
             })),
         }
     }
@@ -86,18 +100,128 @@ impl TextContent {
         });
     }
 
+    /// Replaces the content, only computing rows again for the lines that changed.
+    ///
+    /// This is meant for content re-rendered entirely on a regular basis,
+    /// like the output of a watched command: when only a few lines change,
+    /// rows for the others are kept, and a `ScrollView` showing this content
+    /// keeps its position.
+    ///
+    /// Spans spanning multiple lines are split at each newline.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::views::TextContent;
+    /// let content = TextContent::new("CPU: 12%\nMemory: 2.1G\n");
+    /// content.set_content_diffed("CPU: 15%\nMemory: 2.1G\n");
+    ///
+    /// assert_eq!(content.get_content().source(), "CPU: 15%\nMemory: 2.1G\n");
+    /// ```
+    pub fn set_content_diffed<S>(&self, content: S)
+    where
+        S: Into<StyledString>,
+    {
+        let content = split_lines(&content.into());
+
+        let fallback = self.content.lock().set_content_diffed(content).err();
+        if let Some(content) = fallback {
+            self.with_content_inner(|c| c.content_value = Arc::new(content));
+        }
+    }
+
     /// Append `content` to the end of a `TextView`.
+    ///
+    /// Only the rows for the last line and the new content will be computed again.
     pub fn append<S>(&self, content: S)
     where
         S: Into<StyledString>,
     {
-        self.with_content(|c| {
-            // This will only clone content if content_cached and content_value
-            // are sharing the same underlying Rc.
-            c.append(content);
+        self.with_content_incremental(|c| {
+            c.append(content.into());
         })
     }
 
+    /// Append `line` to the end of a `TextView`, followed by a newline.
+    ///
+    /// Like `append()`, rows for existing lines are kept, so this is suitable
+    /// for large, growing content such as logs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::views::TextContent;
+    /// let content = TextContent::new("");
+    /// content.append_line("first");
+    /// content.append_line("second");
+    ///
+    /// assert_eq!(content.get_content().source(), "first\nsecond\n");
+    /// ```
+    pub fn append_line<S>(&self, line: S)
+    where
+        S: Into<StyledString>,
+    {
+        self.with_content_incremental(|c| {
+            c.append(line.into());
+            c.append(StyledString::plain("\n"));
+        })
+    }
+
+    /// Removes the first `n` lines from the content.
+    ///
+    /// Lines end with a newline. If there are fewer than `n` of them, the
+    /// content is cleared.
+    ///
+    /// Rows for the remaining lines are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::views::TextContent;
+    /// let content = TextContent::new("a\nb\nc\n");
+    /// content.remove_first_lines(2);
+    ///
+    /// assert_eq!(content.get_content().source(), "c\n");
+    /// ```
+    pub fn remove_first_lines(&self, n: usize) {
+        self.with_content_incremental(|c| c.remove_first_lines(n))
+    }
+
+    /// Sets the maximum number of lines to keep.
+    ///
+    /// When content is added beyond this limit, the oldest lines are removed.
+    /// A `ScrollView` showing this content will keep showing the same lines,
+    /// unless they are removed.
+    ///
+    /// This keeps memory bounded for long-running log viewers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::views::TextContent;
+    /// let content = TextContent::new("");
+    /// content.set_max_lines(2);
+    ///
+    /// for i in 0..5 {
+    ///     content.append_line(format!("line {i}"));
+    /// }
+    ///
+    /// assert_eq!(content.get_content().source(), "line 3\nline 4\n");
+    /// ```
+    pub fn set_max_lines(&self, max_lines: usize) {
+        self.with_content_incremental(|c| c.max_lines = Some(max_lines));
+    }
+
+    /// Removes the limit on the number of lines.
+    pub fn clear_max_lines(&self) {
+        self.content.lock().max_lines = None;
+    }
+
+    /// Returns the maximum number of lines to keep, if any.
+    pub fn max_lines(&self) -> Option<usize> {
+        self.content.lock().max_lines
+    }
+
     /// Returns a reference to the content.
     ///
     /// This locks the data while the returned value is alive,
@@ -123,6 +247,51 @@ impl TextContent {
 
         let out = f(&mut content);
 
+        content.size_cache = None;
+        for rows in content.rows.values_mut() {
+            rows.invalidate();
+            // We don't know what changed, so we can't keep the same content in view.
+            rows.removed_rows = 0;
+        }
+        content.newlines = count_newlines(&content.content_value);
+        content.enforce_max_lines();
+
+        out
+    }
+
+    /// Apply the given closure to the inner content, keeping the rows already computed.
+    ///
+    /// The closure can only change the content through `TextContentInner::append`
+    /// or `TextContentInner::remove_first_lines`.
+    fn with_content_incremental<F, O>(&self, f: F) -> O
+    where
+        F: FnOnce(&mut TextContentInner) -> O,
+    {
+        let mut content = self.content.lock();
+        let content = &mut *content;
+
+        // If the cache and the value are the same, release the cache so
+        // `make_mut` doesn't need to clone the entire content.
+        let shared = Arc::ptr_eq(&content.content_cache, &content.content_value);
+        if shared {
+            content.content_cache = Arc::default();
+        }
+
+        let out = f(content);
+        content.enforce_max_lines();
+
+        for rows in content.rows.values_mut() {
+            if shared {
+                // The last line may have been extended.
+                rows.fresh = false;
+            } else {
+                // The rows were computed for a different cache.
+                rows.invalidate();
+            }
+        }
+        if shared {
+            content.content_cache = Arc::clone(&content.content_value);
+        }
         content.size_cache = None;
 
         out
@@ -145,6 +314,235 @@ struct TextContentInner {
 
     // We keep the cache here so it can be busted when we change the content.
     size_cache: Option<XY<SizeCache>>,
+
+    // Rows computed from `content_cache` for each view sharing this content.
+    //
+    // Views may have different widths, so each view has its own rows.
+    // They are kept here so incremental changes can update them.
+    rows: HashMap<usize, RowCache>,
+
+    // Number of newlines in `content_value`.
+    newlines: usize,
+
+    // Maximum number of lines to keep.
+    max_lines: Option<usize>,
+}
+
+fn count_newlines(content: &StyledString) -> usize {
+    content
+        .spans()
+        .map(|span| span.content.matches('\n').count())
+        .sum()
+}
+
+// Splits the spans at each newline, so each line has its own spans.
+fn split_lines(content: &StyledString) -> StyledString {
+    let mut result = StyledString::new();
+    for span in content.spans() {
+        for piece in span.content.split_inclusive('\n') {
+            result.append_styled(piece, *span.attr);
+        }
+    }
+    result
+}
+
+// Returns the range of spans for each line.
+//
+// Returns `None` if some span does not end at the end of a line.
+fn line_spans(content: &StyledString) -> Option<Vec<Range<usize>>> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+
+    for (i, span) in content.spans().enumerate() {
+        match span.content.matches('\n').count() {
+            0 => (),
+            1 if span.content.ends_with('\n') => {
+                lines.push(start..i + 1);
+                start = i + 1;
+            }
+            _ => return None,
+        }
+    }
+
+    let len = content.spans_raw().len();
+    if start < len {
+        lines.push(start..len);
+    }
+
+    Some(lines)
+}
+
+/// Rows computed for some content, grouped by line.
+///
+/// This lets us only compute rows for the lines that changed.
+#[derive(Default)]
+struct RowCache {
+    // Width used to compute the rows.
+    //
+    // `None` if the rows need to be computed again entirely.
+    width: Option<usize>,
+
+    // Rows for the entire content.
+    rows: VecDeque<Row>,
+
+    // Number of rows in each line.
+    lines: VecDeque<usize>,
+
+    // Set when the rows match the current content.
+    fresh: bool,
+
+    // Number of rows removed from the top since the view last asked.
+    removed_rows: usize,
+}
+
+impl RowCache {
+    fn invalidate(&mut self) {
+        self.width = None;
+        self.fresh = false;
+        self.rows.clear();
+        self.lines.clear();
+    }
+
+    // Returns `true` if the rows are up to date for the given width.
+    fn is_valid(&self, width: usize) -> bool {
+        self.fresh && self.width == Some(width)
+    }
+
+    // Returns the position of the last line, as a span id and an offset in this span.
+    fn last_line_start(&self) -> Option<(usize, usize)> {
+        let n_rows = self.lines.back()?;
+        let segment = self.rows[self.rows.len() - n_rows].segments.first()?;
+
+        Some((segment.span_id, segment.start))
+    }
+
+    /// Computes the rows for the given content.
+    ///
+    /// If the rows were computed for the same width, only the last line and
+    /// anything after it will be processed.
+    fn update(&mut self, content: &StyledString, width: usize) {
+        let start = if self.width == Some(width) {
+            self.last_line_start()
+        } else {
+            None
+        };
+
+        // The last line will be computed again, in case it was extended.
+        let (span_id, offset) = match start {
+            Some(start) => {
+                let n_rows = self.lines.pop_back().unwrap_or(0);
+                self.rows.truncate(self.rows.len() - n_rows);
+                start
+            }
+            None => {
+                self.invalidate();
+                (0, 0)
+            }
+        };
+        self.width = Some(width);
+        self.fresh = true;
+
+        let spans = &content.spans_raw()[span_id..];
+
+        let rows: Vec<Row> = if offset == 0 {
+            LinesIterator::new(SpannedStr::new(content.source(), spans), width).collect()
+        } else {
+            // The line starts in the middle of a span: skip the beginning of it.
+            let mut spans = spans.to_vec();
+            let first = &mut spans[0];
+            let len = first.content.resolve(content.source()).len();
+            first.content = first.content.subcow(offset..len);
+            first.width = first.content.resolve(content.source()).width();
+
+            LinesIterator::new(SpannedStr::new(content.source(), &spans), width).collect()
+        };
+
+        let mut line_rows = 0;
+        for mut row in rows {
+            for segment in &mut row.segments {
+                if segment.span_id == 0 {
+                    segment.start += offset;
+                    segment.end += offset;
+                }
+                segment.span_id += span_id;
+            }
+
+            line_rows += 1;
+            if !row.is_wrapped {
+                self.lines.push_back(line_rows);
+                line_rows = 0;
+            }
+
+            self.rows.push_back(row);
+        }
+    }
+
+    // Replaces the rows for the given lines with `rows`.
+    //
+    // `shift` gives the first span id after these lines, before and after the change.
+    fn splice(&mut self, lines: Range<usize>, rows: Vec<Row>, shift: (usize, usize)) {
+        let start: usize = self.lines.range(..lines.start).sum();
+        let end: usize = start + self.lines.range(lines.clone()).sum::<usize>();
+
+        let mut tail_rows = self.rows.split_off(end);
+        for segment in tail_rows.iter_mut().flat_map(|row| &mut row.segments) {
+            segment.span_id = segment.span_id - shift.0 + shift.1;
+        }
+        let tail_lines = self.lines.split_off(lines.end);
+
+        self.rows.truncate(start);
+        self.lines.truncate(lines.start);
+
+        let mut line_rows = 0;
+        for row in rows {
+            line_rows += 1;
+            if !row.is_wrapped {
+                self.lines.push_back(line_rows);
+                line_rows = 0;
+            }
+            self.rows.push_back(row);
+        }
+
+        self.rows.extend(tail_rows);
+        self.lines.extend(tail_lines);
+    }
+
+    // Forgets about the rows for the first `n` lines.
+    //
+    // `removed_spans` and `trimmed` describe how the content itself was changed.
+    //
+    // Returns the number of rows removed.
+    fn remove_first_lines(&mut self, n: usize, removed_spans: usize, trimmed: usize) -> usize {
+        if self.width.is_none() {
+            return 0;
+        }
+
+        if n >= self.lines.len() {
+            // We don't know enough about what is left.
+            let n_rows = self.rows.len();
+            self.invalidate();
+            return n_rows;
+        }
+
+        let n_rows: usize = self.lines.drain(..n).sum();
+        self.rows.drain(..n_rows);
+
+        for row in &mut self.rows {
+            for segment in &mut row.segments {
+                if segment.span_id == removed_spans {
+                    segment.start -= trimmed;
+                    segment.end -= trimmed;
+                }
+                segment.span_id -= removed_spans;
+            }
+        }
+
+        n_rows
+    }
+
+    fn len(&self) -> usize {
+        self.rows.len()
+    }
 }
 
 impl TextContentInner {
@@ -164,6 +562,142 @@ impl TextContentInner {
     fn get_cache(&self) -> &InnerContentType {
         &self.content_cache
     }
+
+    // Returns the rows computed for the given view.
+    //
+    // Views add their rows when created, and remove them when dropped.
+    fn rows(&self, view: usize) -> &RowCache {
+        &self.rows[&view]
+    }
+
+    fn rows_mut(&mut self, view: usize) -> &mut RowCache {
+        self.rows.entry(view).or_default()
+    }
+
+    // Replaces the content, only computing rows for the lines that changed.
+    //
+    // `content` should come from `split_lines`. If the rows cannot be kept,
+    // returns it to be set with the cache busted.
+    fn set_content_diffed(&mut self, content: StyledString) -> Result<(), StyledString> {
+        if *self.content_value == content {
+            return Ok(());
+        }
+
+        // Some rows must be up to date, and computed without expanding tabs.
+        if self.rows.values().all(|rows| rows.width.is_none()) {
+            return Err(content);
+        }
+        if !Arc::ptr_eq(&self.content_cache, &self.content_value)
+            || self.content_value.source().contains('\t')
+            || content.source().contains('\t')
+        {
+            return Err(content);
+        }
+
+        let old = &self.content_value;
+        let (Some(old_lines), Some(new_lines)) = (line_spans(old), line_spans(&content)) else {
+            return Err(content);
+        };
+
+        let same_line = |a: &Range<usize>, b: &Range<usize>| {
+            a.len() == b.len()
+                && old
+                    .spans()
+                    .skip(a.start)
+                    .take(a.len())
+                    .eq(content.spans().skip(b.start).take(b.len()))
+        };
+
+        let prefix = old_lines
+            .iter()
+            .zip(&new_lines)
+            .take_while(|(a, b)| same_line(a, b))
+            .count();
+        let suffix = old_lines[prefix..]
+            .iter()
+            .rev()
+            .zip(new_lines[prefix..].iter().rev())
+            .take_while(|(a, b)| same_line(a, b))
+            .count();
+
+        // First span of a line, or the number of spans after the last line.
+        let line_start = |lines: &[Range<usize>], i: usize, content: &StyledString| {
+            lines
+                .get(i)
+                .map_or(content.spans_raw().len(), |line| line.start)
+        };
+        let old_end = line_start(&old_lines, old_lines.len() - suffix, old);
+        let new_start = line_start(&new_lines, prefix, &content);
+        let new_end = line_start(&new_lines, new_lines.len() - suffix, &content);
+
+        // Compute rows for the changed lines only.
+        let spans = &content.spans_raw()[new_start..new_end];
+        for rows in self.rows.values_mut() {
+            match rows.width {
+                Some(width) if rows.fresh && rows.lines.len() == old_lines.len() => {
+                    let new_rows =
+                        LinesIterator::new(SpannedStr::new(content.source(), spans), width)
+                            .map(|mut row| {
+                                for segment in &mut row.segments {
+                                    segment.span_id += new_start;
+                                }
+                                row
+                            })
+                            .collect();
+                    rows.splice(
+                        prefix..old_lines.len() - suffix,
+                        new_rows,
+                        (old_end, new_end),
+                    );
+                }
+                _ => rows.invalidate(),
+            }
+        }
+
+        self.newlines = count_newlines(&content);
+        self.content_value = Arc::new(content);
+        self.content_cache = Arc::clone(&self.content_value);
+        self.size_cache = None;
+        self.enforce_max_lines();
+
+        Ok(())
+    }
+
+    fn append(&mut self, content: StyledString) {
+        self.newlines += count_newlines(&content);
+        Arc::make_mut(&mut self.content_value).append(content);
+    }
+
+    // Removes the first `n` lines, keeping the rows for the others.
+    fn remove_first_lines(&mut self, n: usize) {
+        let content = Arc::make_mut(&mut self.content_value);
+        let (removed_spans, trimmed) = content.remove_first_lines(n);
+
+        self.newlines = self.newlines.saturating_sub(n);
+        for rows in self.rows.values_mut() {
+            rows.removed_rows += rows.remove_first_lines(n, removed_spans, trimmed);
+        }
+    }
+
+    // Removes the oldest lines if we have more than `max_lines`.
+    fn enforce_max_lines(&mut self) {
+        let Some(max_lines) = self.max_lines else {
+            return;
+        };
+
+        // The last line may not end with a newline.
+        let unterminated = self
+            .content_value
+            .spans()
+            .rev()
+            .find(|span| !span.content.is_empty())
+            .map_or(false, |span| !span.content.ends_with('\n'));
+
+        let lines = self.newlines + usize::from(unterminated);
+        if lines > max_lines {
+            self.remove_first_lines(lines - max_lines);
+        }
+    }
 }
 
 /// A simple view showing a fixed text.
@@ -181,8 +715,8 @@ pub struct TextView {
     // Possibly shared content
     content: TextContent,
 
-    // Pre-computed rows for the content, based on the last view size.
-    rows: Vec<Row>,
+    // Identifies the rows computed for this view in the content.
+    id: usize,
 
     // Text alignment
     align: Align,
@@ -195,18 +729,155 @@ pub struct TextView {
     // True if we can wrap long lines.
     wrap: bool,
 
+    // How tab characters are expanded.
+    tab_stops: TabStops,
+
     // Last requested width.
     //
     // Usually the longest row, but if a row had to be wrapped, it may be a bit larger.
     width: Option<usize>,
-    // Selection?
-    // selection: Option<Selection>,
+
+    // Size given in the last layout phase.
+    last_size: Vec2,
+
+    // If `true`, text can be selected with the mouse.
+    selectable: bool,
+
+    // Text selected with the mouse, as `(anchor, head)` positions.
+    //
+    // Each position is a `(row, column)` pair.
+    selection: Option<((usize, usize), (usize, usize))>,
+
+    // Text shown when the content is empty.
+    placeholder: Option<StyledString>,
+
+    // If `true`, `/` opens a search prompt.
+    searchable: bool,
+
+    // Current search, if any.
+    search: Option<Search>,
 }
 
-// struct Selection {
-//     segments: Vec<crate::utils::lines::spans::Segment>,
-//     // dragging?
-// }
+/// State of a search in a `TextView`.
+struct Search {
+    // Text being searched.
+    query: String,
+
+    // True while the search prompt is open.
+    editing: bool,
+
+    // Where the search started, as an offset in the content.
+    origin: usize,
+
+    // Byte ranges of the matches in the displayed content.
+    matches: Vec<Range<usize>>,
+
+    // Index of the active match in `matches`.
+    current: Option<usize>,
+
+    // Offset in the displayed content of each span.
+    span_offsets: Vec<usize>,
+
+    // True if the active match should be revealed.
+    follow: bool,
+}
+
+impl Search {
+    fn new(query: String, origin: usize) -> Self {
+        Search {
+            query,
+            editing: false,
+            origin,
+            matches: Vec::new(),
+            current: None,
+            span_offsets: Vec::new(),
+            follow: true,
+        }
+    }
+
+    // Finds the matches in the given content.
+    //
+    // The active match is the first one starting at or after `from`.
+    fn update(&mut self, content: &StyledString, from: usize) {
+        let mut text = String::new();
+        self.span_offsets.clear();
+        for span in content.spans() {
+            self.span_offsets.push(text.len());
+            text.push_str(span.content);
+        }
+
+        self.matches = if self.query.is_empty() {
+            Vec::new()
+        } else {
+            text.match_indices(&self.query)
+                .map(|(start, found)| start..start + found.len())
+                .collect()
+        };
+        self.current = (!self.matches.is_empty()).then(|| {
+            self.matches
+                .iter()
+                .position(|found| found.start >= from)
+                .unwrap_or(0)
+        });
+    }
+
+    // Start of the active match in the content.
+    fn position(&self) -> usize {
+        self.current.map_or(0, |i| self.matches[i].start)
+    }
+
+    // Moves to the next or previous match, wrapping around.
+    fn step(&mut self, forward: bool) {
+        let len = self.matches.len();
+        self.current = self.current.map(|i| {
+            if forward {
+                (i + 1) % len
+            } else {
+                (i + len - 1) % len
+            }
+        });
+        self.follow = true;
+    }
+
+    // Returns the matches in the given row, as byte ranges in the row text.
+    //
+    // Each range comes with `true` if it is part of the active match.
+    fn row_matches(&self, row: &Row) -> Vec<(Range<usize>, bool)> {
+        let mut result = Vec::new();
+        let mut offset = 0;
+        for segment in &row.segments {
+            let Some(&span_offset) = self.span_offsets.get(segment.span_id) else {
+                break;
+            };
+            let start = span_offset + segment.start;
+            let end = span_offset + segment.end;
+
+            let first = self.matches.partition_point(|found| found.end <= start);
+            for (i, found) in self.matches.iter().enumerate().skip(first) {
+                if found.start >= end {
+                    break;
+                }
+                let range =
+                    found.start.max(start) - start + offset..found.end.min(end) - start + offset;
+                result.push((range, self.current == Some(i)));
+            }
+            offset += end - start;
+        }
+        result
+    }
+
+    // Text shown in the search prompt.
+    fn prompt(&self) -> String {
+        let mut prompt = format!("/{}", self.query);
+        if !self.query.is_empty() {
+            match self.current {
+                Some(i) => prompt += &format!("  ({}/{})", i + 1, self.matches.len()),
+                None => prompt += "  (not found)",
+            }
+        }
+        prompt
+    }
+}
 
 impl TextView {
     /// Creates a new TextView with the given content.
@@ -234,6 +905,43 @@ impl TextView {
         Self::new(crate::utils::markup::cursup::parse(content))
     }
 
+    /// Convenient function to create a TextView by parsing the given content as HTML.
+    ///
+    /// Shortcut for `TextView::new(html::parse(content))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cursive_core::views::TextView;
+    /// let view = TextView::html("<b>warning:</b> <span style='color: red'>low disk</span>");
+    /// ```
+    pub fn html<S>(content: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::new(crate::utils::markup::html::parse(content))
+    }
+
+    /// Convenient function to create a TextView by parsing the given content as text with ANSI
+    /// escape codes.
+    ///
+    /// Shortcut for `TextView::new(ansi::parse(content))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cursive_core::views::TextView;
+    /// let view = TextView::ansi("\x1b[32mOK\x1b[0m all tests passed");
+    /// ```
+    #[cfg(feature = "ansi")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "ansi")))]
+    pub fn ansi<S>(content: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::new(crate::utils::markup::ansi::parse(content))
+    }
+
     /// Creates a new TextView using the given `TextContent`.
     ///
     /// If you kept a clone of the given content, you'll be able to update it
@@ -251,13 +959,25 @@ impl TextView {
     /// assert!(view.get_content().source().contains("new"));
     /// ```
     pub fn new_with_content(content: TextContent) -> Self {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        content.content.lock().rows.insert(id, RowCache::default());
+
         TextView {
             content,
+            id,
             style: StyleType::default(),
-            rows: Vec::new(),
             wrap: true,
+            tab_stops: TabStops::default(),
             align: Align::top_left(),
             width: None,
+            last_size: Vec2::zero(),
+            selectable: false,
+            selection: None,
+            placeholder: None,
+            searchable: false,
+            search: None,
         }
     }
 
@@ -309,6 +1029,43 @@ impl TextView {
         self.wrap = wrap;
     }
 
+    /// Sets how tab characters are rendered.
+    ///
+    /// Defaults to `TabStops::Fixed(8)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::views::TextView;
+    /// # use cursive_core::utils::lines::tabs::TabStops;
+    /// // Align tab-separated columns, with 2 spaces between them.
+    /// let view = TextView::new("NAME\tSIZE\nfoo.txt\t12K\nbar\t3M")
+    ///     .tab_stops(TabStops::Elastic(2));
+    /// ```
+    pub fn set_tab_stops(&mut self, tab_stops: TabStops) {
+        self.tab_stops = tab_stops;
+
+        // Bust the cache so rows are computed again.
+        let mut content = self.content.content.lock();
+        content.size_cache = None;
+        content.rows_mut(self.id).invalidate();
+    }
+
+    /// Sets how tab characters are rendered.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn tab_stops(self, tab_stops: TabStops) -> Self {
+        self.with(|s| s.set_tab_stops(tab_stops))
+    }
+
+    /// Sets the width of fixed tab stops.
+    ///
+    /// Shortcut for `set_tab_stops(TabStops::Fixed(width))`.
+    pub fn set_tab_width(&mut self, width: usize) {
+        self.set_tab_stops(TabStops::Fixed(width));
+    }
+
     /// Sets the horizontal alignment for this view.
     #[must_use]
     pub fn h_align(mut self, h: HAlign) -> Self {
@@ -340,6 +1097,293 @@ impl TextView {
         self
     }
 
+    /// Allows selecting text with the mouse.
+    ///
+    /// The selected text is copied to the clipboard when the mouse is released.
+    ///
+    /// Selectable text views can take the focus, in order to receive mouse events.
+    pub fn set_selectable(&mut self, selectable: bool) {
+        self.selectable = selectable;
+        if !selectable {
+            self.selection = None;
+        }
+    }
+
+    /// Allows selecting text with the mouse.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn selectable(self) -> Self {
+        self.with(|s| s.set_selectable(true))
+    }
+
+    /// Returns `true` if text can be selected with the mouse.
+    pub fn is_selectable(&self) -> bool {
+        self.selectable
+    }
+
+    /// Enables or disables searching the text.
+    ///
+    /// When enabled, `/` opens a search prompt at the bottom of the visible
+    /// area. Matches are highlighted as the query is typed; `Enter` closes the
+    /// prompt, and `n`/`N` then jump to the next or previous match. `Esc`
+    /// clears the search.
+    ///
+    /// The active match is the important area of this view, so an enclosing
+    /// `ScrollView` scrolls to reveal it.
+    ///
+    /// This also makes the view focusable.
+    pub fn set_searchable(&mut self, searchable: bool) {
+        self.searchable = searchable;
+        if !searchable {
+            self.search = None;
+        }
+    }
+
+    /// Enables searching the text.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn searchable(self) -> Self {
+        self.with(|s| s.set_searchable(true))
+    }
+
+    /// Returns `true` if the text can be searched.
+    pub fn is_searchable(&self) -> bool {
+        self.searchable
+    }
+
+    /// Searches for `query`, highlighting all matches.
+    ///
+    /// The first match becomes active. Call `ScrollView::scroll_to_important_area`
+    /// on an enclosing `ScrollView` to reveal it.
+    ///
+    /// This works even if the view is not searchable.
+    pub fn set_search<S: Into<String>>(&mut self, query: S) {
+        self.search = Some(Search::new(query.into(), 0));
+        self.update_search(0);
+    }
+
+    /// Clears the current search, if any.
+    pub fn clear_search(&mut self) {
+        self.search = None;
+    }
+
+    /// Returns the current search query, if any.
+    pub fn search_query(&self) -> Option<&str> {
+        self.search.as_ref().map(|search| search.query.as_str())
+    }
+
+    /// Returns the number of matches for the current search.
+    pub fn search_match_count(&self) -> usize {
+        self.search
+            .as_ref()
+            .map_or(0, |search| search.matches.len())
+    }
+
+    /// Makes the next match active, wrapping around at the end.
+    pub fn find_next(&mut self) {
+        if let Some(search) = &mut self.search {
+            search.step(true);
+        }
+    }
+
+    /// Makes the previous match active, wrapping around at the start.
+    pub fn find_previous(&mut self) {
+        if let Some(search) = &mut self.search {
+            search.step(false);
+        }
+    }
+
+    // Finds the matches of the current search again.
+    fn update_search(&mut self, from: usize) {
+        let Some(search) = &mut self.search else {
+            return;
+        };
+        let content = self.content.content.lock();
+        search.update(content.get_cache(), from);
+    }
+
+    // Handles the keys controlling the search.
+    //
+    // Returns `None` if the event is not for the search.
+    fn on_search_event(&mut self, event: &Event) -> Option<EventResult> {
+        match (&mut self.search, event) {
+            (Some(search @ Search { editing: true, .. }), event) => match *event {
+                Event::Char(c) => search.query.push(c),
+                Event::Key(Key::Backspace) => {
+                    search.query.pop();
+                }
+                Event::Key(Key::Enter) => search.editing = false,
+                Event::Key(Key::Esc) => self.search = None,
+                _ => return None,
+            },
+            (search, Event::Char('/')) => {
+                let origin = search.as_ref().map_or(0, Search::position);
+                let mut new_search = Search::new(String::new(), origin);
+                new_search.editing = true;
+                *search = Some(new_search);
+            }
+            (Some(search), Event::Char(c @ ('n' | 'N'))) => search.step(*c == 'n'),
+            (search @ Some(_), Event::Key(Key::Esc)) => *search = None,
+            _ => return None,
+        }
+
+        if let Some(search) = &mut self.search {
+            search.follow = true;
+            if search.editing {
+                let origin = search.origin;
+                self.update_search(origin);
+            }
+        }
+        Some(EventResult::consumed())
+    }
+
+    // Returns the area of the active match, if it should be revealed.
+    fn match_area(&self) -> Option<Rect> {
+        let search = self.search.as_ref().filter(|search| search.follow)?;
+        search.current?;
+
+        let content = self.content.content.lock();
+        let rows = &content.rows(self.id).rows;
+        let cache = content.get_cache();
+        let offset = self.align.v.get_offset(rows.len(), self.last_size.y);
+        rows.iter().enumerate().find_map(|(y, row)| {
+            let (range, _) = search
+                .row_matches(row)
+                .into_iter()
+                .find(|&(_, active)| active)?;
+            let text: String = row
+                .resolve_stream(cache.as_ref())
+                .map(|span| span.content)
+                .collect();
+            let x =
+                self.align.h.get_offset(row.width, self.last_size.x) + text[..range.start].width();
+            let width = text[range].width().max(1);
+
+            // Keep the row below visible too, so the prompt doesn't hide the match.
+            let height = if search.editing { 2 } else { 1 };
+            Some(Rect::from_size((x, y + offset), (width, height)))
+        })
+    }
+
+    /// Sets a placeholder to show when the content is empty.
+    ///
+    /// The placeholder is drawn dimmed, in the center of the view.
+    pub fn set_placeholder<S>(&mut self, placeholder: S)
+    where
+        S: Into<StyledString>,
+    {
+        self.placeholder = Some(placeholder.into());
+        self.content.content.lock().size_cache = None;
+    }
+
+    /// Sets a placeholder to show when the content is empty.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::views::TextView;
+    /// let view = TextView::empty().placeholder("No results");
+    /// ```
+    #[must_use]
+    pub fn placeholder<S>(self, placeholder: S) -> Self
+    where
+        S: Into<StyledString>,
+    {
+        self.with(|s| s.set_placeholder(placeholder))
+    }
+
+    /// Removes the placeholder, if any.
+    pub fn clear_placeholder(&mut self) {
+        self.placeholder = None;
+        self.content.content.lock().size_cache = None;
+    }
+
+    /// Returns the placeholder shown when the content is empty.
+    pub fn get_placeholder(&self) -> Option<&StyledString> {
+        self.placeholder.as_ref()
+    }
+
+    // Returns the placeholder if it should be shown instead of the content.
+    fn active_placeholder(&self, content: &TextContentInner) -> Option<&StyledString> {
+        if content.content_cache.source().is_empty() {
+            self.placeholder.as_ref()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the text currently selected with the mouse, if any.
+    pub fn selected_text(&self) -> Option<String> {
+        self.selection_range()?;
+
+        let content = self.content.content.lock();
+        let rows = &content.rows(self.id).rows;
+
+        let mut result = String::new();
+        for (y, row) in rows.iter().enumerate() {
+            let Some((first, last)) = self.selected_columns(y, row.width) else {
+                continue;
+            };
+
+            let text: String = row
+                .resolve_stream(content.get_cache().as_ref())
+                .map(|span| span.content)
+                .collect();
+            let start = simple_prefix(&text, first).length;
+            let end = simple_prefix(&text, last).length;
+            result.push_str(&text[start..end]);
+
+            let is_last = matches!(self.selection_range(), Some((_, (end_row, _))) if end_row == y);
+            if !is_last && !row.is_wrapped {
+                result.push('\n');
+            }
+        }
+
+        Some(result)
+    }
+
+    /// Returns the ordered positions of the selection, if it isn't empty.
+    fn selection_range(&self) -> Option<((usize, usize), (usize, usize))> {
+        let (anchor, head) = self.selection?;
+        (anchor != head).then(|| (anchor.min(head), anchor.max(head)))
+    }
+
+    /// Returns the range of selected columns in the given row.
+    fn selected_columns(&self, y: usize, width: usize) -> Option<(usize, usize)> {
+        let ((start_y, start_x), (end_y, end_x)) = self.selection_range()?;
+        if y < start_y || y > end_y {
+            return None;
+        }
+
+        let first = if y == start_y { start_x.min(width) } else { 0 };
+        let last = if y == end_y { end_x.min(width) } else { width };
+
+        Some((first, last))
+    }
+
+    /// Returns the `(row, column)` position of the text at the given position in the view.
+    fn text_position(&self, position: Vec2) -> (usize, usize) {
+        let content = self.content.content.lock();
+        let rows = &content.rows(self.id).rows;
+
+        let offset = self.align.v.get_offset(rows.len(), self.last_size.y);
+        let y = position.y.saturating_sub(offset);
+        let Some(row) = rows.get(y) else {
+            // Below the text: select until the end.
+            return rows
+                .len()
+                .checked_sub(1)
+                .map_or((0, 0), |y| (y, rows[y].width));
+        };
+
+        let offset = self.align.h.get_offset(row.width, self.last_size.x);
+        (y, position.x.saturating_sub(offset))
+    }
+
     /// Replace the text in this view.
     ///
     /// Chainable variant.
@@ -359,6 +1403,16 @@ impl TextView {
         self.content.set_content(content);
     }
 
+    /// Replace the text in this view, only computing rows again for the lines that changed.
+    ///
+    /// See [`TextContent::set_content_diffed`].
+    pub fn set_content_diffed<S>(&mut self, content: S)
+    where
+        S: Into<StyledString>,
+    {
+        self.content.set_content_diffed(content);
+    }
+
     /// Append `content` to the end of a `TextView`.
     pub fn append<S>(&mut self, content: S)
     where
@@ -367,6 +1421,38 @@ impl TextView {
         self.content.append(content);
     }
 
+    /// Append `line` to the end of a `TextView`, followed by a newline.
+    ///
+    /// See [`TextContent::append_line`].
+    pub fn append_line<S>(&mut self, line: S)
+    where
+        S: Into<StyledString>,
+    {
+        self.content.append_line(line);
+    }
+
+    /// Removes the first `n` lines of this `TextView`.
+    ///
+    /// See [`TextContent::remove_first_lines`].
+    pub fn remove_first_lines(&mut self, n: usize) {
+        self.content.remove_first_lines(n);
+    }
+
+    /// Sets the maximum number of lines to keep.
+    ///
+    /// See [`TextContent::set_max_lines`].
+    pub fn set_max_lines(&mut self, max_lines: usize) {
+        self.content.set_max_lines(max_lines);
+    }
+
+    /// Sets the maximum number of lines to keep.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn max_lines(self, max_lines: usize) -> Self {
+        self.with(|s| s.set_max_lines(max_lines))
+    }
+
     /// Returns the current text in this view.
     pub fn get_content(&self) -> TextContentRef {
         TextContentInner::get_content(&self.content.content)
@@ -387,43 +1473,66 @@ impl TextView {
         let size = if self.wrap { size } else { Vec2::max_value() };
 
         let mut content = self.content.content.lock();
-        if content.is_cache_valid(size) {
+        if content.is_cache_valid(size) && content.rows_mut(self.id).is_valid(size.x) {
             return;
         }
 
         // Completely bust the cache
         // Just in case we fail, we don't want to leave a bad cache.
         content.size_cache = None;
-        content.content_cache = Arc::clone(&content.content_value);
+        content.content_cache = if content.content_value.source().contains('\t') {
+            // Expanded tabs may depend on other lines, so start from scratch.
+            content.rows_mut(self.id).invalidate();
+            Arc::new(expand_tabs(&content.content_value, self.tab_stops))
+        } else {
+            Arc::clone(&content.content_value)
+        };
 
         if size.x == 0 {
             // Nothing we can do at this point.
+            content.rows_mut(self.id).invalidate();
             return;
         }
 
-        self.rows = LinesIterator::new(content.get_cache().as_ref(), size.x).collect();
+        let content = &mut *content;
+        let rows = content.rows.entry(self.id).or_default();
+        rows.update(&content.content_cache, size.x);
+
+        let rows = &rows.rows;
 
         // Desired width
-        self.width = if self.rows.iter().any(|row| row.is_wrapped) {
+        self.width = if rows.iter().any(|row| row.is_wrapped) {
             // If any rows are wrapped, then require the full width.
             Some(size.x)
         } else {
-            self.rows.iter().map(|row| row.width).max()
+            rows.iter().map(|row| row.width).max()
         }
     }
 }
 
+impl Drop for TextView {
+    fn drop(&mut self) {
+        self.content.content.lock().rows.remove(&self.id);
+    }
+}
+
 impl View for TextView {
     fn draw(&self, printer: &Printer) {
-        let h = self.rows.len();
+        let content = self.content.content.lock();
+
+        if let Some(placeholder) = self.active_placeholder(&content) {
+            placeholder::draw(printer, placeholder);
+            return;
+        }
+
+        let h = content.rows(self.id).len();
         // If the content is smaller than the view, align it somewhere.
         let offset = self.align.v.get_offset(h, printer.size.y);
         let printer = &printer.offset((0, offset));
 
-        let content = self.content.content.lock();
-
         printer.with_style(self.style, |printer| {
-            for (y, row) in self
+            for (y, row) in content
+                .rows(self.id)
                 .rows
                 .iter()
                 .enumerate()
@@ -433,37 +1542,159 @@ impl View for TextView {
                 let l = row.width;
                 let mut x = self.align.h.get_offset(l, printer.size.x);
 
-                for span in row.resolve_stream(content.get_cache().as_ref()) {
-                    printer.with_style(*span.attr, |printer| {
-                        printer.print((x, y), span.content);
-                        x += span.content.width();
-                    });
+                let start = x;
+                let matches = self
+                    .search
+                    .as_ref()
+                    .map(|search| search.row_matches(row))
+                    .unwrap_or_default();
+                if matches.is_empty() {
+                    for span in row.resolve_stream(content.get_cache().as_ref()) {
+                        printer.with_style(*span.attr, |printer| {
+                            printer.print((x, y), span.content);
+                            x += span.content.width();
+                        });
+                    }
+                } else {
+                    // Restyle the matches in a copy of the row.
+                    let mut text = StyledString::new();
+                    for span in row.resolve_stream(content.get_cache().as_ref()) {
+                        text.append_styled(span.content, *span.attr);
+                    }
+                    let palette = &printer.theme.palette;
+                    for (range, active) in matches {
+                        let style = if active {
+                            PaletteStyle::Highlight
+                        } else {
+                            PaletteStyle::HighlightInactive
+                        };
+                        text.style_range(range, palette[style]);
+                    }
+                    printer.print_styled((x, y), &text);
+                }
+
+                if let Some((first, last)) = self.selected_columns(y, l) {
+                    printer.highlight_selection((start + first, y), (start + last, y));
                 }
             }
         });
+
+        if let Some(search) = self.search.as_ref().filter(|search| search.editing) {
+            // The prompt covers the last visible row.
+            let bottom = (printer.content_offset.y + printer.output_size.y).min(printer.size.y);
+            let Some(y) = bottom.checked_sub(1) else {
+                return;
+            };
+            printer.with_style(PaletteStyle::Primary, |printer| {
+                let x = printer.content_offset.x;
+                printer.print_hline((x, y), printer.output_size.x, " ");
+                printer.print((x, y), &search.prompt());
+            });
+        }
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        if self.searchable {
+            if let Some(result) = self.on_search_event(&event) {
+                return result;
+            }
+        }
+
+        // Other events should not move back to the active match.
+        if let Some(search) = &mut self.search {
+            search.follow = false;
+        }
+
+        if !self.selectable {
+            return EventResult::Ignored;
+        }
+
+        match event {
+            Event::Mouse {
+                event: MouseEvent::Press(button),
+                position,
+                offset,
+            } if position.fits_in_rect(offset, self.last_size) => {
+                self.selection = (button == MouseButton::Left).then(|| {
+                    let position = self.text_position(position.saturating_sub(offset));
+                    (position, position)
+                });
+                EventResult::consumed()
+            }
+            Event::Mouse {
+                event: MouseEvent::Hold(MouseButton::Left),
+                position,
+                offset,
+            } if self.selection.is_some() => {
+                let head = self.text_position(position.saturating_sub(offset));
+                self.selection = self.selection.map(|(anchor, _)| (anchor, head));
+                EventResult::consumed()
+            }
+            Event::Mouse {
+                event: MouseEvent::Release(MouseButton::Left),
+                ..
+            } => match self.selected_text() {
+                Some(text) => EventResult::Consumed(Some(clipboard::copy_callback(text))),
+                None => EventResult::Ignored,
+            },
+            _ => EventResult::Ignored,
+        }
+    }
+
+    fn take_focus(&mut self, _: Direction) -> Result<EventResult, CannotFocus> {
+        (self.selectable || self.searchable)
+            .then(EventResult::consumed)
+            .ok_or(CannotFocus)
     }
 
     fn needs_relayout(&self) -> bool {
         let content = self.content.content.lock();
-        content.size_cache.is_none()
+        // Another view sharing the content may have been laid out already.
+        content.size_cache.is_none() || !content.rows(self.id).fresh
     }
 
     fn required_size(&mut self, size: Vec2) -> Vec2 {
         self.compute_rows(size);
 
-        Vec2::new(self.width.unwrap_or(0), self.rows.len())
+        let content = self.content.content.lock();
+        if let Some(placeholder) = self.active_placeholder(&content) {
+            return placeholder::required_size(placeholder, size);
+        }
+
+        Vec2::new(self.width.unwrap_or(0), content.rows(self.id).len())
     }
 
     fn layout(&mut self, size: Vec2) {
+        self.last_size = size;
+
         // Compute the text rows.
         self.compute_rows(size);
 
-        // The entire "virtual" size (includes all rows)
-        let my_size = Vec2::new(self.width.unwrap_or(0), self.rows.len());
-
         // Build a fresh cache.
         let mut content = self.content.content.lock();
+
+        // The entire "virtual" size (includes all rows)
+        let my_size = Vec2::new(self.width.unwrap_or(0), content.rows(self.id).len());
+
+        let changed = content.size_cache.is_none();
         content.size_cache = Some(SizeCache::build(my_size, size));
+        drop(content);
+
+        // The content may have changed: find the matches again.
+        if changed {
+            let from = self.search.as_ref().map_or(0, Search::position);
+            self.update_search(from);
+        }
+    }
+
+    fn important_area(&self, size: Vec2) -> Rect {
+        self.match_area()
+            .unwrap_or_else(|| Rect::from_size((0, 0), size))
+    }
+
+    fn take_removed_rows(&mut self) -> usize {
+        let mut content = self.content.content.lock();
+        std::mem::take(&mut content.rows_mut(self.id).removed_rows)
     }
 }
 
@@ -478,5 +1709,315 @@ enum Blueprint {
 
     // Full object with optional content field
     // This is also used to add a `with` block
-    Object { content: Option<StyledString> },
+    Object {
+        content: Option<StyledString>,
+        tab_stops: Option<TabStops>,
+        selectable: Option<bool>,
+        searchable: Option<bool>,
+        placeholder: Option<StyledString>,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TextView;
+    use crate::event::EventResult;
+    use crate::style::Effect;
+    use crate::utils::markup::StyledString;
+    use crate::view::View;
+    use crate::Vec2;
+
+    // Returns the text of each row, after a layout with the given width.
+    fn rows(view: &mut TextView, width: usize) -> Vec<String> {
+        view.layout(Vec2::new(width, 100));
+
+        let content = view.content.content.lock();
+        content
+            .rows(view.id)
+            .rows
+            .iter()
+            .map(|row| {
+                row.resolve_stream(content.content_cache.as_ref())
+                    .map(|span| span.content)
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn shared_content() {
+        let content = super::TextContent::new("one two three four");
+        let mut narrow = TextView::new_with_content(content.clone());
+        let mut wide = TextView::new_with_content(content.clone());
+
+        assert_eq!(rows(&mut narrow, 5), ["one ", "two ", "three", "four"]);
+        assert_eq!(rows(&mut wide, 20), ["one two three four"]);
+        assert_eq!(content.content.lock().rows(narrow.id).len(), 4);
+
+        // Both views need to compute their rows again.
+        content.append_line(" five");
+        wide.layout(Vec2::new(20, 100));
+        assert!(narrow.needs_relayout());
+        assert_eq!(rows(&mut narrow, 5).len(), 5);
+
+        drop(narrow);
+        assert_eq!(content.content.lock().rows.len(), 1);
+    }
+
+    #[test]
+    fn incremental_rows() {
+        let mut view = TextView::new("Some text\nspanning two lines");
+        rows(&mut view, 10);
+
+        view.append(" and a half\n");
+        view.append_line(StyledString::styled("then a bold line", Effect::Bold));
+        rows(&mut view, 10);
+
+        view.append_line("");
+        view.append_line("and a last line");
+        view.remove_first_lines(1);
+
+        let incremental = rows(&mut view, 10);
+
+        let content = view.get_content().clone();
+        assert_eq!(
+            content.source(),
+            "spanning two lines and a half\nthen a bold line\n\nand a last line\n"
+        );
+
+        let mut fresh = TextView::new(content);
+        assert_eq!(incremental, rows(&mut fresh, 10));
+    }
+
+    #[test]
+    fn diffed_content() {
+        let mut view = TextView::new("header\nsome long line\nsame\nfooter");
+        rows(&mut view, 8);
+
+        let diffed = |view: &mut TextView, content: StyledString| {
+            view.set_content_diffed(content.clone());
+
+            // The rows were kept.
+            assert!(view.content.content.lock().rows(view.id).width.is_some());
+
+            let mut fresh = TextView::new(content);
+            assert_eq!(rows(view, 8), rows(&mut fresh, 8));
+        };
+
+        // The first update splits spans at newlines, so it starts from scratch.
+        view.set_content_diffed("header\nsome long line\nsame\nfooter");
+        rows(&mut view, 8);
+
+        let mut content = StyledString::plain("header\nsome longer line\nsame\n");
+        content.append_styled("new\n", Effect::Bold);
+        content.append_plain("footer");
+        diffed(&mut view, content);
+
+        diffed(&mut view, StyledString::plain("header\nsame\nfooter\n"));
+        diffed(
+            &mut view,
+            StyledString::plain("first\nheader\nsame\nfooter\n"),
+        );
+        diffed(&mut view, StyledString::plain(""));
+    }
+
+    #[test]
+    fn remove_mid_span() {
+        let mut view = TextView::new("first line\nsecond line\nthird");
+        rows(&mut view, 8);
+
+        view.remove_first_lines(1);
+        let incremental = rows(&mut view, 8);
+
+        let mut fresh = TextView::new("second line\nthird");
+        assert_eq!(incremental, rows(&mut fresh, 8));
+
+        view.remove_first_lines(5);
+        assert!(rows(&mut view, 8).is_empty());
+        assert!(view.get_content().is_empty());
+    }
+
+    #[test]
+    fn max_lines_keeps_scroll() {
+        use crate::views::ScrollView;
+
+        let mut content = TextView::new("").max_lines(10);
+        let shared = content.get_shared_content();
+        for i in 0..10 {
+            shared.append_line(format!("line {i}"));
+        }
+
+        let mut view = ScrollView::new(content);
+        view.layout(Vec2::new(10, 3));
+        view.set_offset((0, 5));
+
+        // Two lines will be removed from the top.
+        shared.append_line("line 10");
+        shared.append_line("line 11");
+        view.layout(Vec2::new(10, 3));
+
+        assert_eq!(view.content_viewport().top(), 3);
+        assert_eq!(shared.get_content().source().lines().next(), Some("line 2"));
+    }
+
+    #[test]
+    fn mouse_selection() {
+        use crate::buffer::PrintBuffer;
+        use crate::event::{Event, MouseButton, MouseEvent};
+        use crate::style::PaletteStyle;
+        use crate::theme::Theme;
+        use crate::Printer;
+
+        let mouse = |event, position: (usize, usize)| Event::Mouse {
+            event,
+            position: position.into(),
+            offset: Vec2::zero(),
+        };
+
+        let mut view = TextView::new("Some text\nspanning two lines").selectable();
+        assert_eq!(rows(&mut view, 10), ["Some text", "spanning ", "two lines"]);
+
+        view.on_event(mouse(MouseEvent::Press(MouseButton::Left), (5, 0)));
+        view.on_event(mouse(MouseEvent::Hold(MouseButton::Left), (3, 2)));
+        assert_eq!(view.selected_text().as_deref(), Some("text\nspanning two"));
+
+        // Releasing the mouse copies the selection.
+        let result = view.on_event(mouse(MouseEvent::Release(MouseButton::Left), (3, 2)));
+        assert!(matches!(result, EventResult::Consumed(Some(_))));
+
+        let size = Vec2::new(10, 3);
+        let mut buffer = PrintBuffer::new();
+        buffer.resize(size);
+        let buffer = parking_lot::RwLock::new(buffer);
+        let theme = Theme::default();
+        view.draw(&Printer::new(size, &theme, &buffer));
+
+        let highlight = theme.palette[PaletteStyle::Highlight];
+        let buffer = buffer.read();
+        let is_highlighted = |x, y| {
+            buffer.cell_style(Vec2::new(x, y)).map(|style| style.color)
+                == Some(highlight.resolve(&theme.palette, Default::default()).color)
+        };
+        assert!(!is_highlighted(4, 0));
+        assert!(is_highlighted(5, 0));
+        assert!(is_highlighted(0, 1));
+        assert!(is_highlighted(2, 2));
+        assert!(!is_highlighted(3, 2));
+
+        // A click without drag clears the selection.
+        view.on_event(mouse(MouseEvent::Press(MouseButton::Left), (1, 1)));
+        assert_eq!(view.selected_text(), None);
+    }
+
+    #[test]
+    fn placeholder() {
+        use crate::buffer::PrintBuffer;
+        use crate::theme::Theme;
+        use crate::Printer;
+
+        let mut view = TextView::empty().placeholder("None");
+        let size = Vec2::new(8, 3);
+        assert_eq!(view.required_size(size), Vec2::new(4, 1));
+        view.layout(size);
+
+        let mut buffer = PrintBuffer::new();
+        buffer.resize(size);
+        let buffer = parking_lot::RwLock::new(buffer);
+        view.draw(&Printer::new(size, &Theme::default(), &buffer));
+
+        // Centered and dimmed.
+        let buffer = buffer.read();
+        assert_eq!(buffer.cell_text(Vec2::new(2, 1)), Some("N"));
+        let style = buffer.cell_style(Vec2::new(2, 1)).unwrap();
+        assert!(style.effects.contains(Effect::Dim));
+
+        // Hidden as soon as there is some content.
+        view.set_content("Text");
+        assert_eq!(view.required_size(size), Vec2::new(4, 1));
+        assert_eq!(rows(&mut view, 8), ["Text"]);
+    }
+
+    #[test]
+    fn search() {
+        use crate::buffer::PrintBuffer;
+        use crate::event::{Event, Key};
+        use crate::style::PaletteStyle;
+        use crate::theme::Theme;
+        use crate::Printer;
+
+        let mut view = TextView::new("one two one\nthree one").searchable();
+        assert_eq!(rows(&mut view, 12), ["one two one", "three one"]);
+
+        for event in "/one".chars().map(Event::Char) {
+            view.on_event(event);
+        }
+        view.on_event(Event::Key(Key::Enter));
+        assert_eq!(view.search_query(), Some("one"));
+        assert_eq!(view.search_match_count(), 3);
+
+        // `n` and `N` move between matches, wrapping around.
+        view.on_event(Event::Char('n'));
+        view.on_event(Event::Char('n'));
+        assert_eq!(
+            view.important_area(Vec2::new(12, 2)).top_left(),
+            Vec2::new(6, 1)
+        );
+        view.on_event(Event::Char('n'));
+        view.on_event(Event::Char('N'));
+        assert_eq!(
+            view.important_area(Vec2::new(12, 2)).top_left(),
+            Vec2::new(6, 1)
+        );
+
+        let size = Vec2::new(12, 2);
+        let mut buffer = PrintBuffer::new();
+        buffer.resize(size);
+        let buffer = parking_lot::RwLock::new(buffer);
+        let theme = Theme::default();
+        view.draw(&Printer::new(size, &theme, &buffer));
+
+        let color = |style: PaletteStyle| {
+            theme.palette[style]
+                .resolve(&theme.palette, Default::default())
+                .color
+        };
+        let buffer = buffer.read();
+        let cell_color = |x, y| buffer.cell_style(Vec2::new(x, y)).map(|style| style.color);
+        assert_eq!(cell_color(6, 1), Some(color(PaletteStyle::Highlight)));
+        assert_eq!(
+            cell_color(8, 0),
+            Some(color(PaletteStyle::HighlightInactive))
+        );
+        assert_ne!(
+            cell_color(4, 0),
+            Some(color(PaletteStyle::HighlightInactive))
+        );
+
+        // `Esc` clears the search.
+        view.on_event(Event::Key(Key::Esc));
+        assert_eq!(view.search_query(), None);
+        assert!(matches!(
+            view.on_event(Event::Char('n')),
+            EventResult::Ignored
+        ));
+    }
+
+    #[test]
+    fn search_scrolls() {
+        use crate::event::Event;
+        use crate::views::ScrollView;
+
+        let text: Vec<_> = (0..20).map(|i| format!("line {i}")).collect();
+        let mut view = ScrollView::new(TextView::new(text.join("\n")).searchable());
+        view.layout(Vec2::new(10, 4));
+
+        for event in "/line 12".chars().map(Event::Char) {
+            view.on_event(event);
+        }
+        view.layout(Vec2::new(10, 4));
+
+        // The match is visible, above the prompt.
+        let viewport = view.content_viewport();
+        assert!(viewport.top() <= 12 && 12 < viewport.bottom());
+    }
 }
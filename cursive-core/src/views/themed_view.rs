@@ -1,25 +1,94 @@
+use crate::style::PalettePatch;
+use crate::theme::Theme;
 use crate::view::{View, ViewWrapper};
+use std::borrow::Cow;
 
 /// Applies a theme to the wrapped view.
+///
+/// The view can either use a complete theme, frozen when the view is created,
+/// or inherit the current theme and only patch some palette entries.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::style::{BaseColor::*, PaletteColor, PalettePatch};
+/// use cursive_core::views::{TextView, ThemedView};
+///
+/// // Only the primary color is overridden,
+/// // everything else follows the application theme.
+/// let view = ThemedView::patched(
+///     PalettePatch::new().color(PaletteColor::Primary, Red.light()),
+///     TextView::new("Red text"),
+/// );
+/// ```
 pub struct ThemedView<T> {
-    theme: crate::theme::Theme,
+    theme: Option<Theme>,
+    patch: PalettePatch,
     view: T,
 }
 
 impl<T> ThemedView<T> {
     /// Wrap the given view with a theme.
-    pub fn new(theme: crate::theme::Theme, view: T) -> Self {
-        ThemedView { theme, view }
+    pub fn new(theme: Theme, view: T) -> Self {
+        ThemedView {
+            theme: Some(theme),
+            patch: PalettePatch::new(),
+            view,
+        }
+    }
+
+    /// Wrap the given view, patching the palette of the inherited theme.
+    ///
+    /// Any change to the parent theme will be reflected here, except for
+    /// the entries overridden by `patch`.
+    pub fn patched(patch: PalettePatch, view: T) -> Self {
+        ThemedView {
+            theme: None,
+            patch,
+            view,
+        }
     }
 
     /// Retrieve the wrapped theme.
-    pub fn get_theme(&self) -> &crate::theme::Theme {
-        &self.theme
+    ///
+    /// Returns `None` if this view inherits the theme from its parent.
+    pub fn get_theme(&self) -> Option<&Theme> {
+        self.theme.as_ref()
     }
 
     /// Sets a new theme for the wrapped view.
-    pub fn set_theme(&mut self, theme: crate::theme::Theme) {
-        self.theme = theme;
+    ///
+    /// The palette patch, if any, will be applied on top of this theme.
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = Some(theme);
+    }
+
+    /// Stops using a fixed theme, and inherit the theme from the parent instead.
+    pub fn inherit_theme(&mut self) {
+        self.theme = None;
+    }
+
+    /// Returns the palette patch applied to the theme.
+    pub fn get_palette_patch(&self) -> &PalettePatch {
+        &self.patch
+    }
+
+    /// Returns a mutable reference to the palette patch applied to the theme.
+    pub fn get_palette_patch_mut(&mut self) -> &mut PalettePatch {
+        &mut self.patch
+    }
+
+    /// Sets the palette patch applied to the theme.
+    pub fn set_palette_patch(&mut self, patch: PalettePatch) {
+        self.patch = patch;
+    }
+
+    /// Sets the palette patch applied to the theme.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn palette_patch(self, patch: PalettePatch) -> Self {
+        crate::With::with(self, |s| s.set_palette_patch(patch))
     }
 
     inner_getters!(self.view: T);
@@ -29,6 +98,15 @@ impl<T: View> ViewWrapper for ThemedView<T> {
     wrap_impl!(self.view: T);
 
     fn wrap_draw(&self, printer: &crate::Printer) {
+        let mut theme = match self.theme {
+            Some(ref theme) => Cow::Borrowed(theme),
+            None => Cow::Borrowed(printer.theme),
+        };
+
+        if !self.patch.is_empty() {
+            self.patch.apply(&mut theme.to_mut().palette);
+        }
+
         // Hack: We need to re-apply the View (+Primary) style.
         //
         // InheritParent would not be enough because it re-uses the previous _concrete color_
@@ -36,20 +114,75 @@ impl<T: View> ViewWrapper for ThemedView<T> {
         // Ideally we would need to know the previous _StyleType_ (before the theme is applied),
         // but that's not easy for now.
         printer
-            .theme(&self.theme)
+            .theme(&theme)
             .with_style(crate::style::PaletteStyle::View, |printer| {
                 self.view.draw(printer);
             });
     }
 }
 
-#[crate::blueprint(ThemedView::new(theme, view))]
-struct Blueprint {
-    view: crate::views::BoxedView,
-    theme: crate::theme::Theme,
-}
+crate::manual_blueprint!(ThemedView, |config, context| {
+    let view: crate::views::BoxedView = context.resolve(&config["view"])?;
+    let patch = context.resolve::<Option<PalettePatch>>(&config["palette"])?;
+
+    let mut themed = match context.resolve::<Option<Theme>>(&config["theme"])? {
+        Some(theme) => ThemedView::new(theme, view),
+        None => ThemedView::patched(PalettePatch::new(), view),
+    };
+    if let Some(patch) = patch {
+        themed.set_palette_patch(patch);
+    }
+
+    Ok(themed)
+});
 
 crate::manual_blueprint!(with theme, |config, context| {
     let theme = context.resolve(config)?;
     Ok(move |view| ThemedView::new(theme, view))
 });
+
+crate::manual_blueprint!(with palette, |config, context| {
+    let patch = context.resolve(config)?;
+    Ok(move |view| ThemedView::patched(patch, view))
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::{BaseColor, ColorPair, PaletteColor};
+    use crate::views::TextView;
+    use crate::{buffer::PrintBuffer, Printer, Vec2};
+
+    fn draw_with(theme: &Theme, view: &mut ThemedView<TextView>) -> ColorPair {
+        let size = Vec2::new(4, 1);
+        let mut buffer = PrintBuffer::new();
+        buffer.resize(size);
+        let buffer = parking_lot::RwLock::new(buffer);
+
+        view.layout(size);
+        view.draw(&Printer::new(size, theme, &buffer));
+
+        let style = buffer.read().cell_style(Vec2::zero()).unwrap();
+        style.color
+    }
+
+    #[test]
+    fn patch_inherits_theme() {
+        let mut view = ThemedView::patched(
+            PalettePatch::new().color(PaletteColor::Primary, BaseColor::Red.light()),
+            TextView::new("text"),
+        );
+
+        let mut theme = Theme::default();
+        let style = draw_with(&theme, &mut view);
+        assert_eq!(style.front, BaseColor::Red.light());
+        assert_eq!(style.back, theme.palette[PaletteColor::View]);
+
+        // Changes to the parent theme propagate, except for patched entries.
+        theme.palette[PaletteColor::View] = BaseColor::Blue.dark();
+        theme.palette[PaletteColor::Primary] = BaseColor::Green.dark();
+        let style = draw_with(&theme, &mut view);
+        assert_eq!(style.front, BaseColor::Red.light());
+        assert_eq!(style.back, BaseColor::Blue.dark());
+    }
+}
@@ -0,0 +1,527 @@
+use crate::{
+    direction::{Absolute, Direction, Relative},
+    event::{Callback, Event, EventResult, Key, MouseButton, MouseEvent},
+    menu,
+    rect::Rect,
+    style::PaletteStyle,
+    utils::markup::StyledString,
+    view::{CannotFocus, Position, View},
+    views::{Button, MenuPopup},
+    Cursive, Printer, Vec2, With,
+};
+use std::sync::{Arc, Mutex};
+use unicode_width::UnicodeWidthStr;
+
+// Label of the button opening the overflow menu.
+const OVERFLOW_LABEL: &str = "»";
+
+enum Item {
+    Button {
+        // Label without brackets, used in the overflow menu.
+        label: StyledString,
+        callback: Callback,
+        button: Button,
+    },
+    Separator,
+}
+
+impl Item {
+    fn width(&self) -> usize {
+        match self {
+            Item::Button { button, .. } => button.label().width(),
+            Item::Separator => 1,
+        }
+    }
+
+    fn is_focusable(&self) -> bool {
+        matches!(self, Item::Button { button, .. } if button.is_enabled())
+    }
+}
+
+/// A horizontal row of buttons.
+///
+/// Buttons can be grouped using separators.
+///
+/// When there is not enough room for all the buttons, the last ones are
+/// collapsed into a menu, opened with a `»` button.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::Toolbar;
+///
+/// let toolbar = Toolbar::new()
+///     .button("New", |_| ())
+///     .button("Open", |_| ())
+///     .separator()
+///     .button("Quit", |s| s.quit());
+/// ```
+pub struct Toolbar {
+    items: Vec<Item>,
+
+    // Index of the focused item.
+    //
+    // `items.len()` means the overflow button is focused.
+    focus: usize,
+
+    // Number of items drawn directly. The others are in the overflow menu.
+    visible: usize,
+
+    // Horizontal position of each visible item, followed by the overflow button.
+    offsets: Vec<usize>,
+
+    // We need the last offset to place the popup window.
+    last_offset: Mutex<Vec2>,
+}
+
+new_default!(Toolbar);
+
+impl Toolbar {
+    /// Creates a new, empty toolbar.
+    pub fn new() -> Self {
+        Toolbar {
+            items: Vec::new(),
+            focus: 0,
+            visible: 0,
+            offsets: Vec::new(),
+            last_offset: Mutex::new(Vec2::zero()),
+        }
+    }
+
+    /// Adds a button to the end of the toolbar.
+    #[crate::callback_helpers]
+    pub fn add_button<F, S>(&mut self, label: S, cb: F)
+    where
+        F: 'static + Fn(&mut Cursive) + Send + Sync,
+        S: Into<StyledString>,
+    {
+        let label = label.into();
+        let callback = Callback::from_fn(cb);
+        let button = Button::new(label.clone(), {
+            let callback = callback.clone();
+            move |s| callback(s)
+        });
+
+        self.items.push(Item::Button {
+            label,
+            callback,
+            button,
+        });
+    }
+
+    /// Adds a button to the end of the toolbar.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn button<F, S>(self, label: S, cb: F) -> Self
+    where
+        F: 'static + Fn(&mut Cursive) + Send + Sync,
+        S: Into<StyledString>,
+    {
+        self.with(|s| s.add_button(label, cb))
+    }
+
+    /// Adds a separator to the end of the toolbar.
+    pub fn add_separator(&mut self) {
+        self.items.push(Item::Separator);
+    }
+
+    /// Adds a separator to the end of the toolbar.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn separator(self) -> Self {
+        self.with(Self::add_separator)
+    }
+
+    /// Returns the number of items in this toolbar, including separators.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if this toolbar has no item.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Removes all items from this toolbar.
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.focus = 0;
+        self.visible = 0;
+        self.offsets.clear();
+    }
+
+    /// Gives mutable access to the button at the given position.
+    ///
+    /// Returns `None` if `i >= self.len()` or if this item is a separator.
+    pub fn get_button_mut(&mut self, i: usize) -> Option<&mut Button> {
+        match self.items.get_mut(i) {
+            Some(Item::Button { button, .. }) => Some(button),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of items collapsed into the overflow menu.
+    ///
+    /// This is updated during layout.
+    pub fn overflow_len(&self) -> usize {
+        self.items.len() - self.visible
+    }
+
+    fn has_overflow(&self) -> bool {
+        self.visible < self.items.len()
+    }
+
+    // Total width of the first `n` items, including the gaps between them.
+    fn items_width(&self, n: usize) -> usize {
+        let widths: usize = self.items[..n].iter().map(Item::width).sum();
+        widths + n.saturating_sub(1)
+    }
+
+    fn is_focusable(&self, i: usize) -> bool {
+        if i == self.items.len() {
+            self.has_overflow()
+        } else {
+            i < self.visible && self.items[i].is_focusable()
+        }
+    }
+
+    // Focusable indices, in order, including the overflow button.
+    fn focusable(&self) -> impl DoubleEndedIterator<Item = usize> + '_ {
+        (0..=self.items.len()).filter(move |&i| self.is_focusable(i))
+    }
+
+    fn move_focus(&mut self, rel: Relative) -> EventResult {
+        let focus = self.focus;
+        let next = match rel {
+            Relative::Front => self.focusable().rfind(|&i| i < focus),
+            Relative::Back => self.focusable().find(|&i| i > focus),
+        };
+
+        match next {
+            Some(i) => {
+                self.focus = i;
+                EventResult::consumed()
+            }
+            None => EventResult::Ignored,
+        }
+    }
+
+    // Returns the item at the given horizontal position.
+    fn item_at(&self, x: usize) -> Option<usize> {
+        (0..self.offsets.len()).find(|&i| {
+            let width = if i == self.visible {
+                OVERFLOW_LABEL.width()
+            } else {
+                self.items[i].width()
+            };
+            x >= self.offsets[i] && x < self.offsets[i] + width
+        })
+    }
+
+    fn open_overflow(&self) -> EventResult {
+        let mut tree = menu::Tree::new();
+        for item in &self.items[self.visible..] {
+            match item {
+                Item::Button {
+                    label,
+                    callback,
+                    button,
+                } => tree.add_item(menu::Item::Leaf {
+                    label: label.clone(),
+                    cb: callback.clone(),
+                    enabled: button.is_enabled(),
+                    check: None,
+                    accelerator: None,
+                }),
+                Item::Separator if !tree.is_empty() => tree.add_delimiter(),
+                Item::Separator => (),
+            }
+        }
+        let tree = Arc::new(tree);
+
+        // Show the menu right below the overflow button.
+        let x = self.offsets.last().copied().unwrap_or(0);
+        let offset = *self.last_offset.lock().unwrap() + (x, 1);
+
+        EventResult::with_cb(move |s| {
+            let tree = Arc::clone(&tree);
+            s.screen_mut()
+                .add_layer_at(Position::absolute(offset), MenuPopup::new(tree));
+        })
+    }
+
+    fn on_focused_event(&mut self, event: Event) -> EventResult {
+        if self.focus == self.items.len() {
+            return match event {
+                Event::Key(Key::Enter)
+                | Event::Mouse {
+                    event: MouseEvent::Release(MouseButton::Left),
+                    ..
+                } => self.open_overflow(),
+                _ => EventResult::Ignored,
+            };
+        }
+
+        let offset = self.offsets[self.focus];
+        match &mut self.items[self.focus] {
+            Item::Button { button, .. } => button.on_event(event.relativized((offset, 0))),
+            Item::Separator => EventResult::Ignored,
+        }
+    }
+}
+
+impl View for Toolbar {
+    fn draw(&self, printer: &Printer) {
+        *self.last_offset.lock().unwrap() = printer.offset;
+
+        for (i, item) in self.items[..self.visible].iter().enumerate() {
+            let x = self.offsets[i];
+            match item {
+                Item::Button { button, .. } => {
+                    let printer = printer
+                        .offset((x, 0))
+                        .cropped((item.width(), 1))
+                        .focused(self.focus == i);
+                    button.draw(&printer);
+                }
+                Item::Separator => printer.print((x, 0), "|"),
+            }
+        }
+
+        if self.has_overflow() {
+            let style = if printer.focused && self.focus == self.items.len() {
+                PaletteStyle::Highlight
+            } else {
+                PaletteStyle::Primary
+            };
+            let x = self.offsets[self.visible];
+            printer.with_style(style, |printer| printer.print((x, 0), OVERFLOW_LABEL));
+        }
+    }
+
+    fn required_size(&mut self, _: Vec2) -> Vec2 {
+        Vec2::new(self.items_width(self.items.len()), 1)
+    }
+
+    fn layout(&mut self, size: Vec2) {
+        self.visible = self.items.len();
+
+        if self.items_width(self.visible) > size.x {
+            // Keep room for the overflow button, and the gap before it.
+            let overflow_width = OVERFLOW_LABEL.width();
+            self.visible = (0..self.items.len())
+                .rfind(|&n| self.items_width(n) + usize::from(n > 0) + overflow_width <= size.x)
+                .unwrap_or(0);
+
+            // Don't end with a dangling separator.
+            while self.visible > 0 && matches!(self.items[self.visible - 1], Item::Separator) {
+                self.visible -= 1;
+            }
+        }
+
+        self.offsets.clear();
+        let mut x = 0;
+        for item in &mut self.items[..self.visible] {
+            self.offsets.push(x);
+            let width = item.width();
+            if let Item::Button { button, .. } = item {
+                button.layout(Vec2::new(width, 1));
+            }
+            x += width + 1;
+        }
+        if self.has_overflow() {
+            self.offsets.push(x);
+        }
+
+        // The focused button may have been collapsed into the menu.
+        if !self.is_focusable(self.focus) {
+            let focus = self.focus;
+            let previous = self.focusable().rfind(|&i| i <= focus);
+            if let Some(i) = previous.or_else(|| self.focusable().next()) {
+                self.focus = i;
+            }
+        }
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        if self.focusable().next().is_none() {
+            return EventResult::Ignored;
+        }
+
+        let mut result = EventResult::Ignored;
+        if let Event::Mouse {
+            event: mouse_event,
+            position,
+            offset,
+        } = event
+        {
+            let clicked = position
+                .checked_sub(offset)
+                .filter(|position| position.y == 0)
+                .and_then(|position| self.item_at(position.x));
+
+            match clicked {
+                Some(i) if mouse_event.grabs_focus() && self.is_focusable(i) => {
+                    self.focus = i;
+                    result = EventResult::consumed();
+                }
+                Some(_) => (),
+                None => return EventResult::Ignored,
+            }
+        }
+
+        if !self.is_focusable(self.focus) {
+            return result;
+        }
+
+        let res = self.on_focused_event(event.clone());
+        if res.is_consumed() {
+            return result.and(res);
+        }
+
+        result.and(match event {
+            Event::Key(Key::Left) | Event::Shift(Key::Tab) => self.move_focus(Relative::Front),
+            Event::Key(Key::Right) | Event::Key(Key::Tab) => self.move_focus(Relative::Back),
+            _ => EventResult::Ignored,
+        })
+    }
+
+    fn take_focus(&mut self, source: Direction) -> Result<EventResult, CannotFocus> {
+        let focus = match source {
+            Direction::Abs(Absolute::Right) | Direction::Rel(Relative::Back) => {
+                self.focusable().next_back()
+            }
+            Direction::Abs(Absolute::None) if self.is_focusable(self.focus) => Some(self.focus),
+            _ => self.focusable().next(),
+        };
+
+        let focus = focus.ok_or(CannotFocus)?;
+        self.focus = focus;
+        Ok(EventResult::consumed())
+    }
+
+    fn important_area(&self, size: Vec2) -> Rect {
+        match self.offsets.get(self.focus) {
+            Some(&x) if self.focus == self.visible => {
+                Rect::from_size((x, 0), (OVERFLOW_LABEL.width(), 1))
+            }
+            Some(&x) => Rect::from_size((x, 0), (self.items[self.focus].width(), 1)),
+            None => Rect::from_size((0, 0), size),
+        }
+    }
+}
+
+// ```yaml
+// - Toolbar
+//     buttons:
+//         - New: $on_new
+//         - Open: $on_open
+//         - separator
+//         - Quit: $quit
+// ```
+crate::manual_blueprint!(Toolbar, |config, context| {
+    use crate::builder::{Config, Context, Error, Resolvable};
+
+    enum Entry {
+        Button {
+            label: String,
+            callback: Arc<dyn Fn(&mut Cursive) + Send + Sync>,
+        },
+        Separator,
+    }
+
+    impl Resolvable for Entry {
+        fn from_config(config: &Config, context: &Context) -> Result<Self, Error> {
+            if config.as_str() == Some("separator") {
+                return Ok(Entry::Separator);
+            }
+
+            let config = config
+                .as_object()
+                .ok_or_else(|| Error::invalid_config("Expected object or `separator`", config))?;
+
+            let (label, callback) = config
+                .iter()
+                .next()
+                .ok_or_else(|| Error::invalid_config("Expected non-empty object", config))?;
+
+            Ok(Entry::Button {
+                label: label.into(),
+                callback: context.resolve(callback)?,
+            })
+        }
+    }
+
+    let mut toolbar = Toolbar::new();
+
+    let entries: Option<Vec<Entry>> = context.resolve(&config["buttons"])?;
+    for entry in entries.unwrap_or_default() {
+        match entry {
+            Entry::Button { label, callback } => toolbar.add_button_with_cb(label, callback),
+            Entry::Separator => toolbar.add_separator(),
+        }
+    }
+
+    Ok(toolbar)
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn toolbar() -> Toolbar {
+        Toolbar::new()
+            .button("New", |_| ())
+            .button("Open", |_| ())
+            .separator()
+            .button("Save", |_| ())
+            .button("Quit", |_| ())
+    }
+
+    #[test]
+    fn overflow() {
+        let mut toolbar = toolbar();
+
+        // <New> <Open> | <Save> <Quit>
+        assert_eq!(toolbar.required_size(Vec2::new(100, 1)), Vec2::new(28, 1));
+        toolbar.layout(Vec2::new(28, 1));
+        assert_eq!(toolbar.overflow_len(), 0);
+
+        // <New> <Open> | <Save> »
+        toolbar.layout(Vec2::new(27, 1));
+        assert_eq!(toolbar.overflow_len(), 1);
+
+        // <New> <Open> »
+        // The separator is not left dangling before the overflow button.
+        toolbar.layout(Vec2::new(20, 1));
+        assert_eq!(toolbar.overflow_len(), 3);
+        assert_eq!(toolbar.offsets, [0, 6, 13]);
+
+        toolbar.layout(Vec2::new(1, 1));
+        assert_eq!(toolbar.overflow_len(), 5);
+    }
+
+    #[test]
+    fn focus() {
+        let mut toolbar = toolbar();
+        toolbar.layout(Vec2::new(20, 1));
+        toolbar.take_focus(Direction::none()).unwrap();
+        assert_eq!(toolbar.focus, 0);
+
+        // Skips the collapsed items and lands on the overflow button.
+        toolbar.on_event(Event::Key(Key::Right));
+        toolbar.on_event(Event::Key(Key::Right));
+        assert_eq!(toolbar.focus, toolbar.len());
+        assert!(!toolbar.on_event(Event::Key(Key::Right)).is_consumed());
+
+        // Clicking on a button focuses it.
+        toolbar.on_event(Event::Mouse {
+            offset: Vec2::zero(),
+            position: Vec2::new(8, 0),
+            event: MouseEvent::Press(MouseButton::Left),
+        });
+        assert_eq!(toolbar.focus, 1);
+    }
+}
@@ -0,0 +1,783 @@
+//! A view showing a tree of collapsible items.
+use crate::{
+    direction,
+    event::{Callback, Event, EventResult, Key, MouseButton, MouseEvent},
+    rect::Rect,
+    style::{PaletteStyle, Style, StyleType},
+    utils::markup::StyledString,
+    view::{CannotFocus, View},
+    Cursive, Printer, Vec2, With,
+};
+use std::cmp::min;
+use std::sync::Arc;
+
+type TreeCallback<T> = dyn Fn(&mut Cursive, &T) + Send + Sync;
+type Loader<T> = dyn Fn(&T) -> Vec<TreeItem<T>> + Send + Sync;
+
+/// Identifies a node in a [`TreeView`].
+///
+/// Ids stay valid until the node is removed, and are never re-used.
+pub type NodeId = usize;
+
+/// An item to add to a [`TreeView`], as returned by its loader.
+pub struct TreeItem<T> {
+    /// Label shown for this item.
+    pub label: StyledString,
+
+    /// Value associated with this item.
+    pub value: T,
+
+    /// If `true`, this item can be expanded, and its children will be
+    /// requested from the loader the first time it is.
+    pub lazy: bool,
+}
+
+impl<T> TreeItem<T> {
+    /// Creates a new item without children.
+    pub fn leaf<S: Into<StyledString>>(label: S, value: T) -> Self {
+        TreeItem {
+            label: label.into(),
+            value,
+            lazy: false,
+        }
+    }
+
+    /// Creates a new item with children to load on demand.
+    pub fn lazy<S: Into<StyledString>>(label: S, value: T) -> Self {
+        TreeItem {
+            label: label.into(),
+            value,
+            lazy: true,
+        }
+    }
+}
+
+struct Node<T> {
+    label: StyledString,
+    value: Arc<T>,
+    style: Option<StyleType>,
+
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+
+    // If `true`, children will be loaded when this node is first expanded.
+    lazy: bool,
+    expanded: bool,
+}
+
+impl<T> Node<T> {
+    fn is_expandable(&self) -> bool {
+        self.lazy || !self.children.is_empty()
+    }
+}
+
+/// View showing a tree of items, where each node can be collapsed or expanded.
+///
+/// Children can be added up-front, or loaded on demand when a node is first
+/// expanded (for example to list the content of a directory).
+///
+/// Use the arrow keys to navigate: `Left` collapses the current node (or
+/// jumps to its parent), `Right` expands it (or jumps to its first child).
+///
+/// # Examples
+///
+/// ```rust
+/// # use cursive_core::views::{TreeItem, TreeView};
+/// let mut tree = TreeView::new().on_submit(|s, path: &String| {
+///     s.quit();
+/// });
+///
+/// let root = tree.insert_item(None, "src", String::from("src"));
+/// tree.insert_item(Some(root), "main.rs", String::from("src/main.rs"));
+///
+/// // Children of this node will be loaded when it is expanded.
+/// tree.insert_lazy_item(None, "target", String::from("target"));
+/// tree.set_loader(|path| vec![TreeItem::leaf("debug", format!("{path}/debug"))]);
+/// ```
+pub struct TreeView<T = String> {
+    // Every node, indexed by id. Removed nodes leave a hole.
+    nodes: Vec<Option<Node<T>>>,
+    roots: Vec<NodeId>,
+
+    // Visible nodes, in display order, with their depth.
+    rows: Vec<(NodeId, usize)>,
+
+    // Index in `rows` of the focused node.
+    focus: usize,
+
+    enabled: bool,
+
+    on_select: Option<Arc<TreeCallback<T>>>,
+    on_submit: Option<Arc<TreeCallback<T>>>,
+    loader: Option<Arc<Loader<T>>>,
+
+    last_size: Vec2,
+}
+
+impl<T: 'static + Send + Sync> Default for TreeView<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: 'static + Send + Sync> TreeView<T> {
+    /// Creates a new empty `TreeView`.
+    pub fn new() -> Self {
+        TreeView {
+            nodes: Vec::new(),
+            roots: Vec::new(),
+            rows: Vec::new(),
+            focus: 0,
+            enabled: true,
+            on_select: None,
+            on_submit: None,
+            loader: None,
+            last_size: Vec2::zero(),
+        }
+    }
+
+    crate::impl_enabled!(self.enabled);
+
+    /// Adds an item to the tree.
+    ///
+    /// It is added as the last child of `parent`, or as a top-level node if
+    /// `parent` is `None`.
+    ///
+    /// Returns the id of the new node.
+    ///
+    /// # Panics
+    ///
+    /// If `parent` is not a valid id.
+    pub fn insert_item<S>(&mut self, parent: Option<NodeId>, label: S, value: T) -> NodeId
+    where
+        S: Into<StyledString>,
+    {
+        self.insert(parent, label.into(), value, false)
+    }
+
+    /// Adds an item with children to load when it is first expanded.
+    ///
+    /// Children will be requested from the loader given to
+    /// [`TreeView::set_loader`].
+    ///
+    /// # Panics
+    ///
+    /// If `parent` is not a valid id.
+    pub fn insert_lazy_item<S>(&mut self, parent: Option<NodeId>, label: S, value: T) -> NodeId
+    where
+        S: Into<StyledString>,
+    {
+        self.insert(parent, label.into(), value, true)
+    }
+
+    fn insert(
+        &mut self,
+        parent: Option<NodeId>,
+        label: StyledString,
+        value: T,
+        lazy: bool,
+    ) -> NodeId {
+        let id = self.nodes.len();
+
+        match parent {
+            Some(parent) => self
+                .node_mut(parent)
+                .expect("invalid parent id")
+                .children
+                .push(id),
+            None => self.roots.push(id),
+        }
+
+        self.nodes.push(Some(Node {
+            label,
+            value: Arc::new(value),
+            style: None,
+            parent,
+            children: Vec::new(),
+            lazy,
+            expanded: false,
+        }));
+
+        self.refresh_rows();
+
+        id
+    }
+
+    /// Removes a node and all its descendants.
+    ///
+    /// Does nothing if `id` is not a valid id.
+    pub fn remove_item(&mut self, id: NodeId) {
+        // If the focus is in the removed subtree, move it to the parent first.
+        let mut ancestor = self.selected_id();
+        while let Some(current) = ancestor {
+            if current == id {
+                let parent = self.parent(id);
+                if let Some(i) = self.rows.iter().position(|&(row, _)| Some(row) == parent) {
+                    self.focus = i;
+                }
+                break;
+            }
+            ancestor = self.parent(current);
+        }
+
+        let Some(node) = self.nodes.get_mut(id).and_then(Option::take) else {
+            return;
+        };
+
+        let siblings = match node.parent {
+            Some(parent) => self.node_mut(parent).map(|parent| &mut parent.children),
+            None => Some(&mut self.roots),
+        };
+        if let Some(siblings) = siblings {
+            siblings.retain(|&child| child != id);
+        }
+
+        let mut to_remove = node.children;
+        while let Some(id) = to_remove.pop() {
+            if let Some(node) = self.nodes[id].take() {
+                to_remove.extend(node.children);
+            }
+        }
+
+        self.refresh_rows();
+    }
+
+    /// Removes every node from the tree.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.roots.clear();
+        self.rows.clear();
+        self.focus = 0;
+    }
+
+    /// Returns `true` if the tree has no node.
+    pub fn is_empty(&self) -> bool {
+        self.roots.is_empty()
+    }
+
+    /// Sets the function used to load the children of lazy nodes.
+    ///
+    /// It will be called with the value of a lazy node the first time it is expanded.
+    pub fn set_loader<F>(&mut self, loader: F)
+    where
+        F: Fn(&T) -> Vec<TreeItem<T>> + 'static + Send + Sync,
+    {
+        self.loader = Some(Arc::new(loader));
+    }
+
+    /// Sets the function used to load the children of lazy nodes.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn loader<F>(self, loader: F) -> Self
+    where
+        F: Fn(&T) -> Vec<TreeItem<T>> + 'static + Send + Sync,
+    {
+        self.with(|s| s.set_loader(loader))
+    }
+
+    /// Sets a callback to be used when the selection changes.
+    pub fn set_on_select<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, &T) + 'static + Send + Sync,
+    {
+        self.on_select = Some(Arc::new(cb));
+    }
+
+    /// Sets a callback to be used when the selection changes.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn on_select<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, &T) + 'static + Send + Sync,
+    {
+        self.with(|s| s.set_on_select(cb))
+    }
+
+    /// Sets a callback to be used when `<Enter>` is pressed.
+    ///
+    /// Without such a callback, `<Enter>` toggles the current node instead.
+    pub fn set_on_submit<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, &T) + 'static + Send + Sync,
+    {
+        self.on_submit = Some(Arc::new(cb));
+    }
+
+    /// Sets a callback to be used when `<Enter>` is pressed.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn on_submit<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, &T) + 'static + Send + Sync,
+    {
+        self.with(|s| s.set_on_submit(cb))
+    }
+
+    /// Returns the value of the given node.
+    pub fn get_value(&self, id: NodeId) -> Option<&T> {
+        self.node(id).map(|node| node.value.as_ref())
+    }
+
+    /// Returns the label of the given node.
+    pub fn get_label(&self, id: NodeId) -> Option<&StyledString> {
+        self.node(id).map(|node| &node.label)
+    }
+
+    /// Changes the label of the given node.
+    ///
+    /// Does nothing if `id` is not a valid id.
+    pub fn set_label<S: Into<StyledString>>(&mut self, id: NodeId, label: S) {
+        if let Some(node) = self.node_mut(id) {
+            node.label = label.into();
+        }
+    }
+
+    /// Sets a style for the label of the given node.
+    ///
+    /// Does nothing if `id` is not a valid id.
+    pub fn set_item_style<S: Into<StyleType>>(&mut self, id: NodeId, style: S) {
+        if let Some(node) = self.node_mut(id) {
+            node.style = Some(style.into());
+        }
+    }
+
+    /// Returns the parent of the given node.
+    ///
+    /// Returns `None` for top-level nodes.
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.node(id).and_then(|node| node.parent)
+    }
+
+    /// Returns the children of the given node.
+    pub fn children(&self, id: NodeId) -> &[NodeId] {
+        self.node(id).map_or(&[], |node| &node.children)
+    }
+
+    /// Returns the top-level nodes.
+    pub fn roots(&self) -> &[NodeId] {
+        &self.roots
+    }
+
+    /// Returns `true` if the given node is expanded.
+    pub fn is_expanded(&self, id: NodeId) -> bool {
+        self.node(id).map_or(false, |node| node.expanded)
+    }
+
+    /// Expands the given node, showing its children.
+    ///
+    /// If this is a lazy node, its children are loaded first.
+    ///
+    /// Does nothing if `id` is not a valid id.
+    pub fn expand(&mut self, id: NodeId) {
+        let Some(node) = self.node_mut(id) else {
+            return;
+        };
+
+        if node.lazy {
+            node.lazy = false;
+            let value = Arc::clone(&node.value);
+
+            let children = self
+                .loader
+                .as_ref()
+                .map(|loader| loader(&value))
+                .unwrap_or_default();
+
+            for item in children {
+                self.insert(Some(id), item.label, item.value, item.lazy);
+            }
+        }
+
+        if let Some(node) = self.node_mut(id) {
+            node.expanded = true;
+        }
+        self.refresh_rows();
+    }
+
+    /// Collapses the given node, hiding its children.
+    pub fn collapse(&mut self, id: NodeId) {
+        if let Some(node) = self.nodes.get_mut(id).and_then(Option::as_mut) {
+            node.expanded = false;
+            self.refresh_rows();
+        }
+    }
+
+    /// Expands or collapses the given node.
+    pub fn toggle(&mut self, id: NodeId) {
+        if self.is_expanded(id) {
+            self.collapse(id);
+        } else {
+            self.expand(id);
+        }
+    }
+
+    /// Returns the id of the selected node.
+    ///
+    /// Returns `None` if the tree is empty.
+    pub fn selected_id(&self) -> Option<NodeId> {
+        self.rows.get(self.focus).map(|&(id, _)| id)
+    }
+
+    /// Returns the value of the selected node.
+    ///
+    /// Returns `None` if the tree is empty.
+    pub fn selection(&self) -> Option<Arc<T>> {
+        let id = self.selected_id()?;
+        self.node(id).map(|node| Arc::clone(&node.value))
+    }
+
+    /// Selects the given node, expanding its ancestors if needed.
+    ///
+    /// Does not change the selection if `id` is not a valid id.
+    ///
+    /// Returns a callback in case `on_select` is set.
+    pub fn set_selection(&mut self, id: NodeId) -> Callback {
+        let mut parent = self.parent(id);
+        while let Some(id) = parent {
+            if let Some(node) = self.node_mut(id) {
+                node.expanded = true;
+            }
+            parent = self.parent(id);
+        }
+        self.refresh_rows();
+
+        if let Some(i) = self.rows.iter().position(|&(row, _)| row == id) {
+            self.focus = i;
+        }
+
+        self.make_select_cb().unwrap_or_else(Callback::dummy)
+    }
+
+    fn node(&self, id: NodeId) -> Option<&Node<T>> {
+        self.nodes.get(id).and_then(Option::as_ref)
+    }
+
+    fn node_mut(&mut self, id: NodeId) -> Option<&mut Node<T>> {
+        self.nodes.get_mut(id).and_then(Option::as_mut)
+    }
+
+    // Computes the list of visible nodes again.
+    //
+    // Keeps the focus on the same node, or on its closest visible ancestor.
+    fn refresh_rows(&mut self) {
+        let mut focused = self.selected_id();
+
+        self.rows.clear();
+
+        let mut stack: Vec<(NodeId, usize)> = self.roots.iter().rev().map(|&id| (id, 0)).collect();
+        while let Some((id, depth)) = stack.pop() {
+            self.rows.push((id, depth));
+
+            let node = self.node(id).expect("invalid node id");
+            if node.expanded {
+                stack.extend(node.children.iter().rev().map(|&child| (child, depth + 1)));
+            }
+        }
+
+        while let Some(id) = focused {
+            if let Some(i) = self.rows.iter().position(|&(row, _)| row == id) {
+                self.focus = i;
+                return;
+            }
+            focused = self.parent(id);
+        }
+
+        self.focus = min(self.focus, self.rows.len().saturating_sub(1));
+    }
+
+    fn make_select_cb(&self) -> Option<Callback> {
+        let cb = self.on_select.clone()?;
+        let value = self.selection()?;
+
+        Some(Callback::from_fn(move |s| cb(s, &value)))
+    }
+
+    fn submit(&mut self) -> EventResult {
+        let cb = self.on_submit.clone().unwrap();
+
+        EventResult::Consumed(
+            self.selection()
+                .map(|value| Callback::from_fn(move |s| cb(s, &value))),
+        )
+    }
+
+    fn focus_up(&mut self, n: usize) {
+        self.focus = self.focus.saturating_sub(n);
+    }
+
+    fn focus_down(&mut self, n: usize) {
+        self.focus = min(self.focus + n, self.rows.len().saturating_sub(1));
+    }
+
+    // Width of the indentation and expand marker before the label.
+    fn label_offset(depth: usize) -> usize {
+        2 * depth + 2
+    }
+}
+
+impl<T: 'static + Send + Sync> View for TreeView<T> {
+    fn draw(&self, printer: &Printer) {
+        let enabled = self.enabled && printer.enabled;
+
+        let regular_style: StyleType = if enabled {
+            Style::inherit_parent().into()
+        } else {
+            PaletteStyle::Secondary.into()
+        };
+
+        let highlight_style = if printer.focused {
+            printer.theme.focus.list
+        } else {
+            PaletteStyle::HighlightInactive.into()
+        };
+
+        for (y, &(id, depth)) in self
+            .rows
+            .iter()
+            .enumerate()
+            .skip(printer.content_offset.y)
+            .take(printer.output_size.y)
+        {
+            let Some(node) = self.node(id) else {
+                continue;
+            };
+
+            let style = if y == self.focus {
+                highlight_style
+            } else {
+                regular_style
+            };
+
+            printer.offset((0, y)).with_style(style, |printer| {
+                printer.print_hline((0, 0), printer.size.x, " ");
+
+                let marker = match (node.is_expandable(), node.expanded) {
+                    (false, _) => " ",
+                    (true, false) => "▸",
+                    (true, true) => "▾",
+                };
+                printer.print((2 * depth, 0), marker);
+
+                let offset = Self::label_offset(depth);
+                match node.style {
+                    Some(label_style) => printer.with_style(label_style, |printer| {
+                        printer.print_styled((offset, 0), &node.label)
+                    }),
+                    None => printer.print_styled((offset, 0), &node.label),
+                }
+            });
+        }
+    }
+
+    fn required_size(&mut self, _: Vec2) -> Vec2 {
+        let width = self
+            .rows
+            .iter()
+            .filter_map(|&(id, depth)| {
+                let node = self.node(id)?;
+                Some(Self::label_offset(depth) + node.label.width())
+            })
+            .max()
+            .unwrap_or(1);
+
+        Vec2::new(width, self.rows.len())
+    }
+
+    fn layout(&mut self, size: Vec2) {
+        self.last_size = size;
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        if !self.enabled {
+            return EventResult::Ignored;
+        }
+
+        let previous = self.selected_id();
+        let Some(focused) = previous else {
+            return EventResult::Ignored;
+        };
+        let node = self.node(focused).expect("invalid node id");
+
+        match event {
+            Event::Key(Key::Up) if self.focus > 0 => self.focus_up(1),
+            Event::Key(Key::Down) if self.focus + 1 < self.rows.len() => self.focus_down(1),
+            Event::Key(Key::PageUp) => self.focus_up(10),
+            Event::Key(Key::PageDown) => self.focus_down(10),
+            Event::Key(Key::Home) => self.focus = 0,
+            Event::Key(Key::End) => self.focus = self.rows.len() - 1,
+            Event::Key(Key::Left) if node.expanded => {
+                self.collapse(focused);
+                return EventResult::consumed();
+            }
+            Event::Key(Key::Left) if node.parent.is_some() => {
+                let parent = node.parent;
+                self.focus = self
+                    .rows
+                    .iter()
+                    .position(|&(id, _)| Some(id) == parent)
+                    .unwrap();
+            }
+            Event::Key(Key::Right) if node.is_expandable() && !node.expanded => {
+                self.expand(focused);
+                return EventResult::consumed();
+            }
+            Event::Key(Key::Right) if node.expanded && !node.children.is_empty() => {
+                self.focus_down(1);
+            }
+            Event::Key(Key::Enter) if self.on_submit.is_some() => {
+                return self.submit();
+            }
+            Event::Key(Key::Enter) if node.is_expandable() => {
+                self.toggle(focused);
+                return EventResult::consumed();
+            }
+            Event::Mouse {
+                event: MouseEvent::Press(_),
+                position,
+                offset,
+            } => {
+                let Some(position) = position
+                    .checked_sub(offset)
+                    .filter(|position| position.fits_in(self.last_size))
+                    .filter(|position| position.y < self.rows.len())
+                else {
+                    return EventResult::Ignored;
+                };
+
+                self.focus = position.y;
+
+                // Clicking on the marker toggles the node.
+                let (id, depth) = self.rows[position.y];
+                if position.x == 2 * depth {
+                    self.toggle(id);
+                    return EventResult::consumed();
+                }
+            }
+            Event::Mouse {
+                event: MouseEvent::Release(MouseButton::Left),
+                position,
+                offset,
+            } if self.on_submit.is_some()
+                && position
+                    .checked_sub(offset)
+                    .map(|position| position < self.last_size && position.y == self.focus)
+                    .unwrap_or(false) =>
+            {
+                return self.submit();
+            }
+            _ => return EventResult::Ignored,
+        }
+
+        if self.selected_id() == previous {
+            return EventResult::consumed();
+        }
+
+        EventResult::Consumed(self.make_select_cb())
+    }
+
+    fn take_focus(&mut self, source: direction::Direction) -> Result<EventResult, CannotFocus> {
+        (self.enabled && !self.rows.is_empty())
+            .then(|| {
+                match source {
+                    direction::Direction::Abs(direction::Absolute::Up) => self.focus = 0,
+                    direction::Direction::Abs(direction::Absolute::Down) => {
+                        self.focus = self.rows.len() - 1;
+                    }
+                    _ => (),
+                }
+                EventResult::Consumed(None)
+            })
+            .ok_or(CannotFocus)
+    }
+
+    fn important_area(&self, size: Vec2) -> Rect {
+        if self.rows.is_empty() {
+            Rect::from_size(Vec2::zero(), size)
+        } else {
+            Rect::from_size((0, self.focus), (size.x, 1))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TreeItem, TreeView};
+    use crate::event::{Event, Key};
+    use crate::view::View;
+
+    #[test]
+    fn stale_ids() {
+        let mut tree = TreeView::new();
+        let a = tree.insert_item(None, "a", 1);
+        let b = tree.insert_item(Some(a), "b", 2);
+        tree.remove_item(a);
+
+        // Removed nodes are ignored.
+        tree.set_label(b, "c");
+        tree.set_item_style(b, crate::style::Effect::Bold);
+        tree.expand(b);
+        tree.set_selection(b);
+        tree.remove_item(b);
+        assert!(tree.is_empty());
+        assert_eq!(tree.get_label(b), None);
+    }
+
+    #[test]
+    fn navigation() {
+        let mut tree = TreeView::new();
+        let a = tree.insert_item(None, "a", 1);
+        let b = tree.insert_item(Some(a), "b", 2);
+        tree.insert_item(None, "c", 3);
+
+        // Children are hidden until the node is expanded.
+        assert_eq!(tree.required_size((10, 10).into()).y, 2);
+
+        tree.on_event(Event::Key(Key::Right));
+        assert!(tree.is_expanded(a));
+        assert_eq!(tree.required_size((10, 10).into()), (5, 3));
+
+        tree.on_event(Event::Key(Key::Right));
+        assert_eq!(tree.selected_id(), Some(b));
+
+        // Left goes back to the parent, then collapses it.
+        tree.on_event(Event::Key(Key::Left));
+        assert_eq!(tree.selected_id(), Some(a));
+        tree.on_event(Event::Key(Key::Left));
+        assert!(!tree.is_expanded(a));
+
+        tree.on_event(Event::Key(Key::Down));
+        assert_eq!(tree.selection().as_deref(), Some(&3));
+    }
+
+    #[test]
+    fn lazy_children() {
+        let mut tree = TreeView::new().loader(|depth: &usize| {
+            vec![
+                TreeItem::lazy("dir", depth + 1),
+                TreeItem::leaf("file", depth + 1),
+            ]
+        });
+        let root = tree.insert_lazy_item(None, "root", 0);
+        assert!(tree.children(root).is_empty());
+
+        tree.expand(root);
+        let children = tree.children(root).to_vec();
+        assert_eq!(children.len(), 2);
+        assert_eq!(tree.get_value(children[0]), Some(&1));
+
+        // Collapsing and expanding again does not load the children twice.
+        tree.collapse(root);
+        tree.expand(root);
+        assert_eq!(tree.children(root).len(), 2);
+
+        // Selecting a node reveals it, and removing it moves the focus to its parent.
+        tree.collapse(root);
+        tree.set_selection(children[0]);
+        assert_eq!(tree.selected_id(), Some(children[0]));
+        tree.remove_item(children[0]);
+        assert_eq!(tree.selected_id(), Some(root));
+    }
+}
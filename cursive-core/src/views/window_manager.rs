@@ -0,0 +1,648 @@
+use crate::{
+    direction::Direction,
+    event::{AnyCb, Event, EventResult, MouseButton, MouseEvent},
+    keymap,
+    rect::Rect,
+    style::PaletteStyle,
+    utils::lines::simple::simple_prefix,
+    view::{CannotFocus, IntoBoxedView, Selector, View, ViewNotFound},
+    views::BoxedView,
+    Printer, Vec2, With,
+};
+use unicode_width::UnicodeWidthStr;
+
+/// Smallest size of a window, including its frame.
+const MIN_SIZE: Vec2 = Vec2::new(8, 3);
+
+/// Text of the minimize button, at the right of the title bar.
+const MINIMIZE: &str = "[_]";
+
+/// Identifies a window in a [`WindowManager`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WindowId(usize);
+
+/// Shows views in floating windows.
+///
+/// Each window has a border and a title bar. With the mouse, a window can be:
+///
+/// * Brought to the front by clicking on it.
+/// * Moved by dragging its title bar.
+/// * Resized by dragging its bottom-right corner.
+/// * Minimized with the `[_]` button in its title bar.
+///
+/// Minimized windows are listed on the bottom row; click them to restore them.
+///
+/// The `next_window` action from the [`keymap`] (`F6` by default) brings the
+/// next window to the front.
+///
+/// Only the front window receives keyboard events.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::{TextView, WindowManager};
+///
+/// let mut windows = WindowManager::new();
+/// let notes = windows.add_window("Notes", TextView::new("Buy milk"));
+/// windows.add_window("Help", TextView::new("Press F6 to switch windows."));
+///
+/// windows.raise_window(notes);
+/// assert_eq!(windows.front_window(), Some(notes));
+/// ```
+pub struct WindowManager {
+    // Windows from back to front.
+    windows: Vec<Window>,
+
+    // ID for the next window.
+    next_id: usize,
+
+    // Mouse drag in progress, on the front window.
+    drag: Option<Drag>,
+
+    last_size: Vec2,
+}
+
+struct Window {
+    id: WindowId,
+    title: String,
+    view: BoxedView,
+
+    // Position of the frame.
+    position: Vec2,
+
+    // Size of the frame, once resized with the mouse.
+    //
+    // If `None`, the window fits its content.
+    size: Option<Vec2>,
+
+    // Size of the frame in the last layout.
+    last_size: Vec2,
+
+    minimized: bool,
+
+    // `take_focus` should only be called after the first layout.
+    virgin: bool,
+}
+
+impl Window {
+    fn rect(&self) -> Rect {
+        Rect::from_size(self.position, self.last_size)
+    }
+
+    // Area of the content, inside the frame.
+    fn inner_rect(&self) -> Option<Rect> {
+        let size = self.last_size.checked_sub((2, 2))?;
+        (size.x > 0 && size.y > 0).then(|| Rect::from_size(self.position + (1, 1), size))
+    }
+
+    fn draw(&self, printer: &Printer) {
+        let size = printer.size;
+        printer.with_style(PaletteStyle::View, |printer| {
+            for y in 0..size.y {
+                printer.print_hline((0, y), size.x, " ");
+            }
+        });
+        printer.print_box((0, 0), size, true);
+
+        // The title is cut before the minimize button.
+        let room = size.x.saturating_sub(MINIMIZE.width() + 5);
+        let title = simple_prefix(&self.title, room);
+        let title = &self.title[..title.length];
+        let style = if printer.focused {
+            PaletteStyle::TitlePrimary
+        } else {
+            PaletteStyle::TitleSecondary
+        };
+        printer.with_style(style, |printer| {
+            printer.print((2, 0), &format!(" {title} "));
+        });
+        printer.print((size.x.saturating_sub(MINIMIZE.width() + 1), 0), MINIMIZE);
+
+        self.view.draw(&printer.offset((1, 1)).shrinked((1, 1)));
+    }
+}
+
+// A mouse drag on the front window.
+#[derive(Clone, Copy)]
+enum Drag {
+    // Moving the window, grabbed at the given point in the frame.
+    Move(Vec2),
+
+    // Resizing the window from its bottom-right corner.
+    Resize,
+}
+
+new_default!(WindowManager);
+
+impl WindowManager {
+    /// Creates a new window manager without windows.
+    pub fn new() -> Self {
+        WindowManager {
+            windows: Vec::new(),
+            next_id: 0,
+            drag: None,
+            last_size: Vec2::zero(),
+        }
+    }
+
+    /// Adds a new window in front of the others.
+    ///
+    /// New windows are placed diagonally from the top-left corner.
+    pub fn add_window<S, V>(&mut self, title: S, view: V) -> WindowId
+    where
+        S: Into<String>,
+        V: IntoBoxedView,
+    {
+        let n = self.windows.len() % 8;
+        self.add_window_at(title, Vec2::new(2 * n, n), view)
+    }
+
+    /// Adds a new window in front of the others.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn window<S, V>(self, title: S, view: V) -> Self
+    where
+        S: Into<String>,
+        V: IntoBoxedView,
+    {
+        self.with(|s| {
+            s.add_window(title, view);
+        })
+    }
+
+    /// Adds a new window at the given position, in front of the others.
+    pub fn add_window_at<S, V>(&mut self, title: S, position: Vec2, view: V) -> WindowId
+    where
+        S: Into<String>,
+        V: IntoBoxedView,
+    {
+        let id = WindowId(self.next_id);
+        self.next_id += 1;
+
+        self.drag = None;
+        self.windows.push(Window {
+            id,
+            title: title.into(),
+            view: BoxedView::new(view.into_boxed_view()),
+            position,
+            size: None,
+            last_size: Vec2::zero(),
+            minimized: false,
+            virgin: true,
+        });
+        id
+    }
+
+    /// Removes a window, and returns its content.
+    ///
+    /// Returns `None` if the window was not found.
+    pub fn remove_window(&mut self, id: WindowId) -> Option<Box<dyn View>> {
+        let i = self.find(id)?;
+        self.drag = None;
+        Some(self.windows.remove(i).view.unwrap())
+    }
+
+    /// Returns the number of windows, including minimized ones.
+    pub fn len(&self) -> usize {
+        self.windows.len()
+    }
+
+    /// Returns `true` if there are no windows.
+    pub fn is_empty(&self) -> bool {
+        self.windows.is_empty()
+    }
+
+    /// Returns the windows, from back to front.
+    pub fn window_ids(&self) -> Vec<WindowId> {
+        self.windows.iter().map(|window| window.id).collect()
+    }
+
+    /// Returns the front window, which has the focus.
+    ///
+    /// Minimized windows are never in front.
+    pub fn front_window(&self) -> Option<WindowId> {
+        self.front().map(|i| self.windows[i].id)
+    }
+
+    /// Returns the content of a window.
+    pub fn get_window(&self, id: WindowId) -> Option<&dyn View> {
+        let i = self.find(id)?;
+        Some(&*self.windows[i].view)
+    }
+
+    /// Returns a mutable reference to the content of a window.
+    pub fn get_window_mut(&mut self, id: WindowId) -> Option<&mut dyn View> {
+        let i = self.find(id)?;
+        Some(&mut *self.windows[i].view)
+    }
+
+    /// Sets the title of a window.
+    pub fn set_window_title<S: Into<String>>(&mut self, id: WindowId, title: S) {
+        if let Some(i) = self.find(id) {
+            self.windows[i].title = title.into();
+        }
+    }
+
+    /// Returns the title of a window.
+    pub fn window_title(&self, id: WindowId) -> Option<&str> {
+        let i = self.find(id)?;
+        Some(&self.windows[i].title)
+    }
+
+    /// Moves a window to the given position.
+    ///
+    /// The window is kept inside the available area.
+    pub fn set_window_position(&mut self, id: WindowId, position: Vec2) {
+        if let Some(i) = self.find(id) {
+            self.windows[i].position = position;
+        }
+    }
+
+    /// Returns the position of a window.
+    pub fn window_position(&self, id: WindowId) -> Option<Vec2> {
+        let i = self.find(id)?;
+        Some(self.windows[i].position)
+    }
+
+    /// Sets the size of a window, including its frame.
+    ///
+    /// If `None`, the window fits its content.
+    pub fn set_window_size(&mut self, id: WindowId, size: Option<Vec2>) {
+        if let Some(i) = self.find(id) {
+            self.windows[i].size = size.map(|size| size.or_max(MIN_SIZE));
+        }
+    }
+
+    /// Returns the size of a window in the last layout, including its frame.
+    pub fn window_size(&self, id: WindowId) -> Option<Vec2> {
+        let i = self.find(id)?;
+        Some(self.windows[i].last_size)
+    }
+
+    /// Brings a window to the front and gives it the focus.
+    ///
+    /// A minimized window is restored.
+    pub fn raise_window(&mut self, id: WindowId) -> EventResult {
+        let Some(i) = self.find(id) else {
+            return EventResult::Ignored;
+        };
+        self.drag = None;
+
+        let mut window = self.windows.remove(i);
+        window.minimized = false;
+        let result = window.view.take_focus(Direction::none());
+        self.windows.push(window);
+
+        result.unwrap_or(EventResult::Ignored)
+    }
+
+    /// Minimizes a window.
+    ///
+    /// It is listed on the bottom row, until it is raised again.
+    pub fn minimize_window(&mut self, id: WindowId) {
+        if let Some(i) = self.find(id) {
+            self.windows[i].minimized = true;
+            self.drag = None;
+        }
+    }
+
+    /// Returns `true` if the window is minimized.
+    pub fn is_minimized(&self, id: WindowId) -> Option<bool> {
+        let i = self.find(id)?;
+        Some(self.windows[i].minimized)
+    }
+
+    /// Sends the front window to the back, bringing the next one to the front.
+    ///
+    /// Returns `EventResult::Ignored` if there is no other window to show.
+    pub fn focus_next_window(&mut self) -> EventResult {
+        let visible = self
+            .windows
+            .iter()
+            .filter(|window| !window.minimized)
+            .count();
+        let Some(front) = self.front().filter(|_| visible > 1) else {
+            return EventResult::Ignored;
+        };
+
+        let window = self.windows.remove(front);
+        self.windows.insert(0, window);
+        match self.front_window() {
+            Some(id) => self.raise_window(id).and(EventResult::consumed()),
+            None => EventResult::Ignored,
+        }
+    }
+
+    fn find(&self, id: WindowId) -> Option<usize> {
+        self.windows.iter().position(|window| window.id == id)
+    }
+
+    fn front(&self) -> Option<usize> {
+        self.windows.iter().rposition(|window| !window.minimized)
+    }
+
+    // Front-most window under the given point.
+    fn window_at(&self, position: Vec2) -> Option<usize> {
+        self.windows
+            .iter()
+            .rposition(|window| !window.minimized && window.rect().contains(position))
+    }
+
+    // Entries on the bottom row for minimized windows, as `(index, area)`.
+    fn minimized_entries(&self) -> Vec<(usize, Rect)> {
+        let Some(y) = self.last_size.y.checked_sub(1) else {
+            return Vec::new();
+        };
+
+        let mut x = 0;
+        let mut entries = Vec::new();
+        for (i, window) in self.windows.iter().enumerate() {
+            if window.minimized {
+                let width = window.title.width() + 2;
+                entries.push((i, Rect::from_size((x, y), (width, 1))));
+                x += width + 1;
+            }
+        }
+        entries
+    }
+
+    fn on_mouse_event(&mut self, event: Event, position: Vec2) -> EventResult {
+        let Event::Mouse {
+            event: mouse_event, ..
+        } = event
+        else {
+            return EventResult::Ignored;
+        };
+
+        match (mouse_event, self.drag) {
+            (MouseEvent::Hold(MouseButton::Left), Some(drag)) => {
+                let Some(window) = self.front().map(|i| &mut self.windows[i]) else {
+                    return EventResult::Ignored;
+                };
+                match drag {
+                    Drag::Move(grab) => window.position = position.saturating_sub(grab),
+                    Drag::Resize => {
+                        let size = (position + (1, 1)).saturating_sub(window.position);
+                        window.size = Some(size.or_max(MIN_SIZE));
+                    }
+                }
+                return EventResult::consumed();
+            }
+            (MouseEvent::Release(MouseButton::Left), Some(_)) => {
+                self.drag = None;
+                return EventResult::consumed();
+            }
+            (MouseEvent::Press(MouseButton::Left), _) => {
+                if let Some(&(i, _)) = self
+                    .minimized_entries()
+                    .iter()
+                    .find(|(_, area)| area.contains(position))
+                {
+                    let id = self.windows[i].id;
+                    return self.raise_window(id).and(EventResult::consumed());
+                }
+            }
+            _ => (),
+        }
+
+        let Some(i) = self.window_at(position) else {
+            return EventResult::Ignored;
+        };
+
+        // Clicking a window brings it to the front.
+        let mut result = EventResult::Ignored;
+        let mut i = i;
+        if matches!(mouse_event, MouseEvent::Press(_)) && Some(i) != self.front() {
+            let id = self.windows[i].id;
+            result = self.raise_window(id).and(EventResult::consumed());
+            i = self.windows.len() - 1;
+        }
+        let window = &mut self.windows[i];
+
+        let local = position - window.position;
+        let last = window.last_size.saturating_sub((1, 1));
+        if mouse_event == MouseEvent::Press(MouseButton::Left) {
+            if local.y == 0 {
+                let button = last.x.saturating_sub(MINIMIZE.width());
+                if (button..last.x).contains(&local.x) {
+                    window.minimized = true;
+                } else {
+                    self.drag = Some(Drag::Move(local));
+                }
+                return EventResult::consumed();
+            }
+            if local == last {
+                self.drag = Some(Drag::Resize);
+                return EventResult::consumed();
+            }
+        }
+
+        match window.inner_rect() {
+            Some(inner) if inner.contains(position) => window
+                .view
+                .on_event(event.relativized(inner.top_left()))
+                .and(result),
+            _ => result,
+        }
+    }
+}
+
+impl View for WindowManager {
+    fn draw(&self, printer: &Printer) {
+        let front = self.front();
+        for (i, window) in self.windows.iter().enumerate() {
+            if window.minimized {
+                continue;
+            }
+            window.draw(
+                &printer
+                    .offset(window.position)
+                    .cropped(window.last_size)
+                    .focused(printer.focused && Some(i) == front),
+            );
+        }
+
+        for (i, area) in self.minimized_entries() {
+            printer.with_style(PaletteStyle::TitleSecondary, |printer| {
+                printer.print(area.top_left(), &format!("[{}]", self.windows[i].title));
+            });
+        }
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        if keymap::matches("next_window", &event) {
+            return self.focus_next_window();
+        }
+
+        if let Event::Mouse {
+            position, offset, ..
+        } = event
+        {
+            return match position.checked_sub(offset) {
+                Some(position) => self.on_mouse_event(event, position),
+                None => EventResult::Ignored,
+            };
+        }
+
+        match self.front() {
+            Some(i) => self.windows[i].view.on_event(event),
+            None => EventResult::Ignored,
+        }
+    }
+
+    fn required_size(&mut self, constraint: Vec2) -> Vec2 {
+        // Windows can be placed anywhere in the available space.
+        constraint
+    }
+
+    fn layout(&mut self, size: Vec2) {
+        self.last_size = size;
+
+        for window in &mut self.windows {
+            let frame = match window.size {
+                Some(frame) => frame,
+                None => window.view.required_size(size.saturating_sub((2, 2))) + (2, 2),
+            };
+            let frame = frame.or_max(MIN_SIZE).or_min(size);
+
+            // Keep the whole window in view.
+            window.position = window.position.or_min(size.saturating_sub(frame));
+            window.last_size = frame;
+            window.view.layout(frame.saturating_sub((2, 2)));
+
+            if window.virgin {
+                window.view.take_focus(Direction::none()).ok();
+                window.virgin = false;
+            }
+        }
+    }
+
+    fn take_focus(&mut self, source: Direction) -> Result<EventResult, CannotFocus> {
+        match self.front() {
+            Some(i) => self.windows[i].view.take_focus(source),
+            None => Err(CannotFocus),
+        }
+    }
+
+    fn call_on_any(&mut self, selector: &Selector, callback: AnyCb) {
+        for window in &mut self.windows {
+            window.view.call_on_any(selector, callback);
+        }
+    }
+
+    fn call_on_focused_child(&mut self, callback: AnyCb) {
+        if let Some(i) = self.front() {
+            callback(&mut self.windows[i].view);
+        }
+    }
+
+    fn focus_view(&mut self, selector: &Selector) -> Result<EventResult, ViewNotFound> {
+        let (id, result) = self
+            .windows
+            .iter_mut()
+            .find_map(|window| {
+                let result = window.view.focus_view(selector).ok()?;
+                Some((window.id, result))
+            })
+            .ok_or(ViewNotFound)?;
+
+        self.raise_window(id);
+        Ok(result)
+    }
+
+    fn reveal_view(&mut self, selector: &Selector, _: Vec2) -> Result<Rect, ViewNotFound> {
+        self.windows
+            .iter_mut()
+            .find_map(|window| {
+                let inner = window.inner_rect()?;
+                window
+                    .view
+                    .reveal_view(selector, inner.size())
+                    .ok()
+                    .map(|area| area + inner.top_left())
+            })
+            .ok_or(ViewNotFound)
+    }
+
+    fn important_area(&self, size: Vec2) -> Rect {
+        match self.front() {
+            Some(i) => self.windows[i].rect(),
+            None => Rect::from_size((0, 0), size),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::Key;
+    use crate::views::TextView;
+
+    fn mouse(event: MouseEvent, position: (usize, usize)) -> Event {
+        Event::Mouse {
+            event,
+            position: position.into(),
+            offset: Vec2::zero(),
+        }
+    }
+
+    #[test]
+    fn drag_and_resize() {
+        let mut windows = WindowManager::new();
+        let id = windows.add_window_at("Notes", Vec2::new(2, 2), TextView::new("Hello"));
+        windows.layout(Vec2::new(40, 20));
+        assert_eq!(windows.window_size(id), Some(Vec2::new(8, 3)));
+
+        // Drag the title bar.
+        windows.on_event(mouse(MouseEvent::Press(MouseButton::Left), (3, 2)));
+        windows.on_event(mouse(MouseEvent::Hold(MouseButton::Left), (11, 6)));
+        windows.on_event(mouse(MouseEvent::Release(MouseButton::Left), (11, 6)));
+        assert_eq!(windows.window_position(id), Some(Vec2::new(10, 6)));
+
+        // Drag the bottom-right corner.
+        windows.on_event(mouse(MouseEvent::Press(MouseButton::Left), (17, 8)));
+        windows.on_event(mouse(MouseEvent::Hold(MouseButton::Left), (24, 12)));
+        windows.on_event(mouse(MouseEvent::Release(MouseButton::Left), (24, 12)));
+        windows.layout(Vec2::new(40, 20));
+        assert_eq!(windows.window_size(id), Some(Vec2::new(15, 7)));
+
+        // Windows are kept inside the available area.
+        windows.set_window_position(id, Vec2::new(100, 100));
+        windows.layout(Vec2::new(40, 20));
+        assert_eq!(windows.window_position(id), Some(Vec2::new(25, 13)));
+    }
+
+    #[test]
+    fn focus_and_minimize() {
+        let mut windows = WindowManager::new()
+            .window("First", TextView::new("1"))
+            .window("Second", TextView::new("2"));
+        let [first, second] = <[WindowId; 2]>::try_from(windows.window_ids()).unwrap();
+        windows.layout(Vec2::new(40, 20));
+        assert_eq!(windows.front_window(), Some(second));
+
+        // The shortcut cycles through windows.
+        let result = windows.on_event(Event::Key(Key::F6));
+        assert!(result.is_consumed());
+        assert_eq!(windows.front_window(), Some(first));
+
+        // Clicking a window brings it to the front.
+        windows.on_event(mouse(MouseEvent::Press(MouseButton::Left), (8, 3)));
+        windows.on_event(mouse(MouseEvent::Release(MouseButton::Left), (8, 3)));
+        assert_eq!(windows.front_window(), Some(second));
+
+        // The minimize button is at the right of the title bar.
+        let right =
+            windows.window_position(second).unwrap().x + windows.window_size(second).unwrap().x - 2;
+        windows.on_event(mouse(MouseEvent::Press(MouseButton::Left), (right, 1)));
+        assert_eq!(windows.is_minimized(second), Some(true));
+        assert_eq!(windows.front_window(), Some(first));
+        assert!(!windows.on_event(Event::Key(Key::F6)).is_consumed());
+
+        // Minimized windows are restored from the bottom row.
+        windows.on_event(mouse(MouseEvent::Press(MouseButton::Left), (1, 19)));
+        assert_eq!(windows.is_minimized(second), Some(false));
+        assert_eq!(windows.front_window(), Some(second));
+    }
+}
@@ -159,6 +159,9 @@ struct Variable {
     // How to load the variable from a config.
     loader: Loader,
 
+    // Doc comment of the field, used to describe it in schemas.
+    doc: String,
+
     // How to call the variable between loading and consuming.
     // Mostly irrelevant, except for constructor.
     ident: syn::Ident,
@@ -367,9 +370,202 @@ impl Variable {
         Ok(Variable {
             consumer,
             loader,
+            doc: doc_comment(&field.attrs),
             ident,
         })
     }
+
+    // Returns the quote!d `BlueprintField` describing this variable.
+    //
+    // Returns `None` if the value is not read from a config key.
+    fn schema(
+        &self,
+        required: bool,
+        root: &proc_macro2::TokenStream,
+    ) -> Option<proc_macro2::TokenStream> {
+        if self.loader.no_config {
+            return None;
+        }
+        let name = self.loader.config_name.as_ref()?;
+        let ty = &self.loader.ty;
+
+        let rust_type = if looks_inferred(ty) {
+            "callback".to_string()
+        } else {
+            type_name(ty)
+        };
+        let json_type = json_type(ty);
+        let doc = &self.doc;
+
+        Some(quote! {
+            #root::builder::BlueprintField {
+                name: #name,
+                rust_type: #rust_type,
+                json_type: #json_type,
+                required: #required,
+                description: #doc,
+            }
+        })
+    }
+
+    // Returns `true` if the config must include this variable.
+    //
+    // Missing arrays are resolved as empty ones.
+    fn is_required(&self) -> bool {
+        is_option_type(&self.loader.ty).is_none()
+            && self.loader.default.is_none()
+            && json_type(&self.loader.ty) != "array"
+    }
+
+    // Returns the json type of this variable, if it is read from the config itself.
+    fn shorthand(&self) -> Option<&'static str> {
+        (!self.loader.no_config && self.loader.config_name.is_none())
+            .then(|| json_type(&self.loader.ty))
+    }
+}
+
+// Joins the `///` comments from these attributes.
+fn doc_comment(attrs: &[syn::Attribute]) -> String {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                value:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(doc),
+                        ..
+                    }),
+                ..
+            }) => Some(doc.value().trim().to_string()),
+            _ => None,
+        })
+        .collect();
+    lines.join(" ").trim().to_string()
+}
+
+// Returns the type as written in the source, without the spaces added by `quote!`.
+fn type_name(ty: &syn::Type) -> String {
+    quote! { #ty }
+        .to_string()
+        .replace(" < ", "<")
+        .replace("< ", "<")
+        .replace(" <", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",")
+        .replace(" :: ", "::")
+        .replace(":: ", "::")
+        .replace("& ", "&")
+}
+
+// Returns the json schema type for values of this type.
+//
+// This is only a hint: an empty string means any value may be accepted.
+// `"view"` is used for nested view configs.
+fn json_type(ty: &syn::Type) -> &'static str {
+    let ty = is_option_type(ty).unwrap_or(ty);
+    let path = match ty {
+        syn::Type::Path(syn::TypePath { path, .. }) => path,
+        syn::Type::Reference(syn::TypeReference { elem, .. }) => return json_type(elem),
+        _ => return "",
+    };
+    let Some(last) = path.segments.last() else {
+        return "";
+    };
+
+    match last.ident.to_string().as_str() {
+        "bool" => "boolean",
+        "String" | "StyledString" | "str" | "char" => "string",
+        "u8" | "u16" | "u32" | "u64" | "usize" | "i8" | "i16" | "i32" | "i64" | "isize" => {
+            "integer"
+        }
+        "f32" | "f64" => "number",
+        "Vec" | "VecDeque" | "HashSet" | "BTreeSet" => "array",
+        "HashMap" | "BTreeMap" => "object",
+        "BoxedView" => "view",
+        _ => "",
+    }
+}
+
+// Config accepted by a blueprint, gathered for `BlueprintSchema`.
+#[derive(Default)]
+struct Schema {
+    // Quote!d `BlueprintField`s.
+    fields: Vec<proc_macro2::TokenStream>,
+
+    // Json types accepted instead of an object.
+    shorthands: Vec<&'static str>,
+
+    // `true` if a null config (just the blueprint name) is enough.
+    allows_null: bool,
+}
+
+impl Schema {
+    // Adds the config keys from these fields.
+    //
+    // With `optional`, no key is required: other enum variants may not use them.
+    fn add_fields(
+        &mut self,
+        fields: &syn::Fields,
+        optional: bool,
+        struct_name: &str,
+        parameter_names: &HashSet<String>,
+        root: &proc_macro2::TokenStream,
+    ) -> syn::parse::Result<()> {
+        let fields = match fields {
+            syn::Fields::Named(fields) => &fields.named,
+            syn::Fields::Unnamed(fields) => &fields.unnamed,
+            syn::Fields::Unit => {
+                self.allows_null = true;
+                return Ok(());
+            }
+        };
+
+        let mut required = false;
+        for field in fields {
+            let var = Variable::parse(field, struct_name, parameter_names)?;
+            if let Some(shorthand) = var.shorthand() {
+                self.shorthands.push(shorthand);
+            }
+            if let Some(schema) = var.schema(var.is_required() && !optional, root) {
+                required |= var.is_required();
+                self.fields.push(schema);
+            }
+        }
+        if !required
+            && matches!(
+                fields.first(),
+                Some(syn::Field { ident: Some(_), .. }) | None
+            )
+        {
+            self.allows_null = true;
+        }
+
+        Ok(())
+    }
+
+    fn submit(
+        &self,
+        name: &syn::Ident,
+        root: &proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let Schema {
+            fields,
+            shorthands,
+            allows_null,
+        } = self;
+
+        quote! {
+            #root::submit! {
+                #root::builder::BlueprintSchema {
+                    name: stringify!(#name),
+                    fields: &[#(#fields),*],
+                    shorthands: &[#(#shorthands),*],
+                    allows_null: #allows_null,
+                }
+            }
+        }
+    }
 }
 
 struct Setter {
@@ -607,6 +803,7 @@ pub fn blueprint(attrs: TokenStream, item: TokenStream) -> TokenStream {
     // * A list of setter variables
     // * A way to check each setter variable and each parameter.
 
+    let mut schema = Schema::default();
     let builder = match &input {
         syn::Item::Enum(item) => {
             if !item.generics.params.is_empty() {
@@ -629,6 +826,17 @@ pub fn blueprint(attrs: TokenStream, item: TokenStream) -> TokenStream {
             //     // Plan B: try enums one by one until one works
             //     unimplemented!();
             // }
+            for variant in &item.variants {
+                schema
+                    .add_fields(
+                        &variant.fields,
+                        item.variants.len() > 1,
+                        &variant.ident.to_string(),
+                        &attributes.base_parameters,
+                        &root,
+                    )
+                    .unwrap();
+            }
             parse_enum(item, &attributes.base_parameters, &attributes.base, &root).unwrap()
         }
         syn::Item::Struct(item) => {
@@ -637,6 +845,15 @@ pub fn blueprint(attrs: TokenStream, item: TokenStream) -> TokenStream {
             }
 
             let struct_name = item.ident.to_string();
+            schema
+                .add_fields(
+                    &item.fields,
+                    false,
+                    &struct_name,
+                    &attributes.base_parameters,
+                    &root,
+                )
+                .unwrap();
             parse_struct(
                 &item.fields,
                 &attributes.base_parameters,
@@ -650,10 +867,13 @@ pub fn blueprint(attrs: TokenStream, item: TokenStream) -> TokenStream {
     };
 
     let ident = syn::Ident::new(&attributes.name, Span::call_site());
+    let schema = schema.submit(&ident, &root);
     let result = quote! {
         #root::manual_blueprint!(#ident, |config, context| {
             Ok({ #builder })
         });
+
+        #schema
     };
 
     // eprintln!("Res: {result}");
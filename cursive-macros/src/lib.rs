@@ -1,6 +1,7 @@
 use proc_macro::TokenStream;
 
 mod builder;
+mod style;
 
 /// Generate two helper functions to help working with cursive blueprints.
 ///
@@ -101,3 +102,12 @@ pub fn callback_helpers(_attrs: TokenStream, item: TokenStream) -> TokenStream {
 pub fn blueprint(attrs: TokenStream, item: TokenStream) -> TokenStream {
     builder::blueprint(attrs, item)
 }
+
+/// Implementation detail of the `style!` macro from cursive.
+///
+/// Expects the path to the cursive crate, a comma, and the style description.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __style(input: TokenStream) -> TokenStream {
+    style::style(input)
+}
@@ -0,0 +1,272 @@
+use proc_macro::{Delimiter, Group, Literal, Span, TokenStream, TokenTree};
+
+// Placeholder for the path to the cursive crate in the generated code.
+const ROOT: &str = "__cursive_root";
+
+pub fn style(input: TokenStream) -> TokenStream {
+    // We expect `$crate, "description"`.
+    let mut tokens = input.into_iter();
+    let root: TokenStream = tokens
+        .by_ref()
+        .take_while(|token| !matches!(token, TokenTree::Punct(p) if p.as_char() == ','))
+        .collect();
+
+    let literal = match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Literal(literal)), None) => literal,
+        // The literal may be wrapped in an invisible group when coming from macro_rules.
+        (Some(TokenTree::Group(group)), None) if group.delimiter() == Delimiter::None => {
+            let mut inner = group.stream().into_iter();
+            match (inner.next(), inner.next()) {
+                (Some(TokenTree::Literal(literal)), None) => literal,
+                _ => return error("expected a string literal", group.span()),
+            }
+        }
+        (Some(token), _) => return error("expected a string literal", token.span()),
+        (None, _) => return error("expected a string literal", Span::call_site()),
+    };
+
+    let description = match parse_string(&literal) {
+        Some(description) => description,
+        None => return error("expected a string literal", literal.span()),
+    };
+
+    let code = match parse_style(&description) {
+        Ok(code) => code,
+        Err(message) => return error(&message, literal.span()),
+    };
+
+    let code: TokenStream = code.parse().expect("generated invalid code");
+    replace_root(code, &root)
+}
+
+// Returns the content of a plain or raw string literal.
+fn parse_string(literal: &Literal) -> Option<String> {
+    let repr = literal.to_string();
+
+    if let Some(raw) = repr.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let raw = &raw[hashes..raw.len() - hashes];
+        return Some(raw.strip_prefix('"')?.strip_suffix('"')?.to_string());
+    }
+
+    let content = repr.strip_prefix('"')?.strip_suffix('"')?;
+    if content.contains('\\') {
+        // Style descriptions have no use for escape sequences.
+        return None;
+    }
+
+    Some(content.to_string())
+}
+
+// Parses a style description like `"bold red on black"`.
+//
+// Returns the code building the corresponding `Style`.
+fn parse_style(description: &str) -> Result<String, String> {
+    let mut effects = Vec::new();
+    let mut front = None;
+    let mut back = None;
+
+    let mut words = description.split_whitespace();
+
+    while let Some(word) = words.next() {
+        if let Some(effect) = parse_effect(word) {
+            if !effects.contains(&effect) {
+                effects.push(effect);
+            }
+            continue;
+        }
+
+        let (target, word) = if word == "on" {
+            let word = words
+                .next()
+                .ok_or_else(|| "expected a background color after `on`".to_string())?;
+            (&mut back, word)
+        } else {
+            (&mut front, word)
+        };
+
+        let color = match word {
+            "light" | "dark" => {
+                let base = words
+                    .next()
+                    .and_then(parse_base_color)
+                    .ok_or_else(|| format!("expected a base color after `{word}`"))?;
+                let brightness = if word == "light" { "Light" } else { "Dark" };
+                color(&format!("{brightness}({ROOT}::style::BaseColor::{base})"))
+            }
+            _ => parse_color(word).ok_or_else(|| format!("unknown effect or color: `{word}`"))?,
+        };
+
+        if target.replace(color).is_some() {
+            return Err(format!("color given twice: `{word}`"));
+        }
+    }
+
+    let effects: String = effects
+        .iter()
+        .map(|effect| format!(".and({ROOT}::style::Effect::{effect})"))
+        .collect();
+
+    let inherit = format!("{ROOT}::style::ColorType::InheritParent");
+    let front = front.unwrap_or_else(|| inherit.clone());
+    let back = back.unwrap_or(inherit);
+
+    Ok(format!(
+        "{ROOT}::style::Style {{
+            effects: {ROOT}::style::Effects::empty(){effects},
+            color: {ROOT}::style::ColorStyle {{
+                front: {front},
+                back: {back},
+            }},
+            underline: {ROOT}::style::ColorType::InheritParent,
+        }}"
+    ))
+}
+
+fn parse_effect(word: &str) -> Option<&'static str> {
+    Some(match word {
+        "Simple" | "simple" => "Simple",
+        "Reverse" | "reverse" => "Reverse",
+        "Dim" | "dim" => "Dim",
+        "Bold" | "bold" => "Bold",
+        "Italic" | "italic" => "Italic",
+        "Strikethrough" | "strikethrough" => "Strikethrough",
+        "Underline" | "underline" => "Underline",
+        "Blink" | "blink" => "Blink",
+        "CurlyUnderline" | "curly_underline" => "CurlyUnderline",
+        "DoubleUnderline" | "double_underline" => "DoubleUnderline",
+        "Overline" | "overline" => "Overline",
+        _ => return None,
+    })
+}
+
+fn parse_base_color(word: &str) -> Option<&'static str> {
+    Some(match word {
+        "Black" | "black" => "Black",
+        "Red" | "red" => "Red",
+        "Green" | "green" => "Green",
+        "Yellow" | "yellow" => "Yellow",
+        "Blue" | "blue" => "Blue",
+        "Magenta" | "magenta" => "Magenta",
+        "Cyan" | "cyan" => "Cyan",
+        "White" | "white" => "White",
+        _ => return None,
+    })
+}
+
+fn parse_palette_color(word: &str) -> Option<&'static str> {
+    Some(match word {
+        "Background" | "background" => "Background",
+        "Shadow" | "shadow" => "Shadow",
+        "View" | "view" => "View",
+        "Primary" | "primary" => "Primary",
+        "Secondary" | "secondary" => "Secondary",
+        "Tertiary" | "tertiary" => "Tertiary",
+        "TitlePrimary" | "title_primary" => "TitlePrimary",
+        "TitleSecondary" | "title_secondary" => "TitleSecondary",
+        "Highlight" | "highlight" => "Highlight",
+        "HighlightInactive" | "highlight_inactive" => "HighlightInactive",
+        "HighlightText" | "highlight_text" => "HighlightText",
+        _ => return None,
+    })
+}
+
+fn color(color: &str) -> String {
+    format!("{ROOT}::style::ColorType::Color({ROOT}::style::Color::{color})")
+}
+
+// Parses a single-word color, following `Color::parse`.
+fn parse_color(word: &str) -> Option<String> {
+    if word == "default" {
+        return Some(color("TerminalDefault"));
+    }
+
+    if let Some(base) = parse_base_color(word) {
+        return Some(color(&format!("Dark({ROOT}::style::BaseColor::{base})")));
+    }
+
+    if let Some(palette) = parse_palette_color(word) {
+        return Some(format!(
+            "{ROOT}::style::ColorType::Palette({ROOT}::style::PaletteColor::{palette})"
+        ));
+    }
+
+    let hex = word.strip_prefix('#').or_else(|| word.strip_prefix("0x"));
+    match (hex, word.len()) {
+        (Some(hex), _) => parse_hex(hex),
+        (None, 6) => parse_hex(word),
+        (None, 3) => {
+            // RGB values between 0 and 5, like 050 for green.
+            let rgb: Vec<u8> = word
+                .bytes()
+                .map(|b| b.wrapping_sub(b'0'))
+                .filter(|&v| v < 6)
+                .collect();
+            (rgb.len() == 3)
+                .then(|| color(&format!("RgbLowRes({}, {}, {})", rgb[0], rgb[1], rgb[2])))
+        }
+        _ => None,
+    }
+}
+
+fn parse_hex(hex: &str) -> Option<String> {
+    let (len, multiplier) = match hex.len() {
+        6 => (2, 1),
+        3 => (1, 17),
+        _ => return None,
+    };
+
+    let component = |i: usize| {
+        u8::from_str_radix(hex.get(i * len..(i + 1) * len)?, 16)
+            .ok()
+            .map(|v| v * multiplier)
+    };
+
+    Some(color(&format!(
+        "Rgb({}, {}, {})",
+        component(0)?,
+        component(1)?,
+        component(2)?
+    )))
+}
+
+// Replaces every occurrence of the `ROOT` placeholder with `root`.
+fn replace_root(code: TokenStream, root: &TokenStream) -> TokenStream {
+    code.into_iter()
+        .flat_map(|token| match token {
+            TokenTree::Ident(ident) if ident.to_string() == ROOT => root.clone(),
+            TokenTree::Group(group) => {
+                let mut new_group =
+                    Group::new(group.delimiter(), replace_root(group.stream(), root));
+                new_group.set_span(group.span());
+                TokenTree::Group(new_group).into()
+            }
+            token => token.into(),
+        })
+        .collect()
+}
+
+fn error(message: &str, span: Span) -> TokenStream {
+    let code: TokenStream = format!("::core::compile_error!({message:?})")
+        .parse()
+        .expect("generated invalid code");
+
+    respan(code, span)
+}
+
+// Sets the span of every token, so errors point to the right place.
+fn respan(code: TokenStream, span: Span) -> TokenStream {
+    code.into_iter()
+        .map(|token| match token {
+            TokenTree::Group(group) => {
+                let mut group = Group::new(group.delimiter(), respan(group.stream(), span));
+                group.set_span(span);
+                TokenTree::Group(group)
+            }
+            mut token => {
+                token.set_span(span);
+                token
+            }
+        })
+        .collect()
+}
@@ -3,6 +3,9 @@
 //! The [`parse()`] function can be used to generate a StyledString using a
 //! highlighter and a syntax set.
 //!
+//! [`code_highlighter()`] highlights code blocks in markdown text, when
+//! given to `cursive::utils::markup::markdown::parse_with_highlighter`.
+//!
 //! [`syntect`]: https://docs.rs/syntect
 #![deny(missing_docs)]
 
@@ -48,6 +51,7 @@ pub fn translate_style(style: syntect::highlighting::Style) -> style::Style {
     style::Style {
         color: (front, back).into(),
         effects: translate_effects(style.font_style),
+        underline: style::ColorType::InheritParent,
     }
 }
 
@@ -72,3 +76,20 @@ pub fn parse<S: Into<String>>(
 
     Ok(StyledString::with_spans(input, spans))
 }
+
+/// Returns a function highlighting code blocks, based on their language.
+///
+/// Languages are found by extension or name in the syntax set. Unknown
+/// languages return `None`.
+///
+/// This can be used with `cursive::utils::markup::markdown::parse_with_highlighter`.
+pub fn code_highlighter<'a>(
+    theme: &'a syntect::highlighting::Theme,
+    syntax_set: &'a syntect::parsing::SyntaxSet,
+) -> impl FnMut(Option<&str>, &str) -> Option<StyledString> + 'a {
+    move |language, code| {
+        let syntax = syntax_set.find_syntax_by_token(language?)?;
+        let mut highlighter = syntect::easy::HighlightLines::new(syntax, theme);
+        parse(code, &mut highlighter, syntax_set).ok()
+    }
+}
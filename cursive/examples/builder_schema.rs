@@ -0,0 +1,14 @@
+// Prints a JSON Schema for view configs, including the blueprints from `builder.rs`.
+//
+// Editors can use it to complete and validate layouts, for example with the
+// YAML language server:
+//
+// ```
+// cargo run --example builder_schema --features builder > cursive.schema.json
+// ```
+//
+// Then add `# yaml-language-server: $schema=cursive.schema.json` at the top of a layout.
+fn main() {
+    let schema = cursive::builder::schema();
+    println!("{}", serde_json::to_string_pretty(&schema).unwrap());
+}
@@ -59,6 +59,7 @@ fn single_style_span(str: &str) -> StyledString {
         Style {
             effects: Default::default(),
             color: ColorStyle::new(Red, Black),
+            ..Default::default()
         },
     )
 }
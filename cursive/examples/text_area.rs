@@ -58,7 +58,7 @@ fn find(siv: &mut Cursive, text: &str) {
         // Possible improvement: search after the current cursor.
         if let Some(i) = v.get_content().find(text) {
             // If we found it, move the cursor
-            v.set_cursor(i);
+            v.set_cursor_offset(i);
             Ok(())
         } else {
             // Otherwise, return an error so we can show a warning.
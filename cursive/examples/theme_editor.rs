@@ -160,6 +160,13 @@ fn make_dialog(theme: &Theme) -> impl cursive::View {
                                 .with_name("borders")
                                 .max_width(10),
                         )
+                        .child(
+                            "ASCII",
+                            cursive::views::Checkbox::new()
+                                .with_checked(theme.ascii)
+                                .on_change(|s, _| apply(s))
+                                .with_name("ascii"),
+                        )
                         .child(
                             "Palette",
                             cursive::views::ListView::new().with(|l| {
@@ -287,6 +294,10 @@ fn apply(siv: &mut cursive::Cursive) {
         )
         .unwrap();
 
+    let ascii = siv
+        .call_on_name("ascii", |c: &mut cursive::views::Checkbox| c.is_checked())
+        .unwrap();
+
     let mut palette = Palette::default();
     for color in PaletteColor::all() {
         if let Some(c) = find_color(siv, &format!("{color:?}")) {
@@ -297,6 +308,10 @@ fn apply(siv: &mut cursive::Cursive) {
     siv.set_theme(Theme {
         shadow,
         borders,
+        ascii,
         palette,
+        scrollbar: Default::default(),
+        focus: Default::default(),
+        color_downgrade: Default::default(),
     })
 }
@@ -10,6 +10,7 @@ fn main() {
     siv.set_theme(cursive::theme::Theme {
         shadow: true,
         borders: BorderStyle::Simple,
+        ascii: false,
         palette: Palette::retro().with(|palette| {
             use cursive::style::BaseColor::*;
 
@@ -35,6 +36,9 @@ fn main() {
                 palette[EditableTextCursor] = Style::secondary().combine(Reverse).combine(Underline)
             }
         }),
+        scrollbar: Default::default(),
+        focus: Default::default(),
+        color_downgrade: Default::default(),
     });
 
     let layout = LinearLayout::vertical()
@@ -274,6 +274,9 @@ impl backend::Backend for Backend {
             | Effect::Bold
             | Effect::Italic
             | Effect::Underline
+            | Effect::CurlyUnderline
+            | Effect::DoubleUnderline
+            | Effect::Overline
             | Effect::Strikethrough
             | Effect::Blink
             | Effect::Simple => {}
@@ -290,6 +293,9 @@ impl backend::Backend for Backend {
             | Effect::Bold
             | Effect::Italic
             | Effect::Underline
+            | Effect::CurlyUnderline
+            | Effect::DoubleUnderline
+            | Effect::Overline
             | Effect::Strikethrough
             | Effect::Blink
             | Effect::Simple => {}
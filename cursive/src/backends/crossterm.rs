@@ -18,12 +18,15 @@ pub use crossterm;
 use crossterm::{
     cursor,
     event::{
-        poll, read, DisableMouseCapture, EnableMouseCapture, Event as CEvent, KeyCode,
-        KeyEvent as CKeyEvent, KeyEventKind, KeyModifiers, MouseButton as CMouseButton,
-        MouseEvent as CMouseEvent, MouseEventKind,
+        poll, read, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste,
+        EnableMouseCapture, Event as CEvent, KeyCode, KeyEvent as CKeyEvent, KeyEventKind,
+        KeyModifiers, MouseButton as CMouseButton, MouseEvent as CMouseEvent, MouseEventKind,
     },
     execute, queue,
-    style::{Attribute, Color, Print, SetAttribute, SetBackgroundColor, SetForegroundColor},
+    style::{
+        Attribute, Color, Print, SetAttribute, SetBackgroundColor, SetForegroundColor,
+        SetUnderlineColor,
+    },
     terminal::{
         self, disable_raw_mode, enable_raw_mode, Clear, ClearType, EnterAlternateScreen,
         LeaveAlternateScreen,
@@ -46,6 +49,12 @@ type Stdout = std::fs::File;
 pub struct Backend {
     current_style: Cell<theme::ColorPair>,
 
+    // Protocol used to draw images, guessed from the environment.
+    graphics_protocol: Option<backend::GraphicsProtocol>,
+
+    // Colors the terminal can display, guessed from the environment.
+    color_resolution: crate::style::ColorResolution,
+
     stdout: RefCell<BufWriter<Stdout>>,
 }
 
@@ -224,11 +233,14 @@ impl Backend {
             stdout,
             EnterAlternateScreen,
             EnableMouseCapture,
+            EnableBracketedPaste,
             cursor::Hide
         )?;
 
         Ok(Box::new(Backend {
             current_style: Cell::new(theme::ColorPair::from_256colors(0, 0)),
+            graphics_protocol: backend::GraphicsProtocol::detect(),
+            color_resolution: crate::style::ColorResolution::detect(),
             stdout: RefCell::new(BufWriter::new(stdout)),
         }))
     }
@@ -272,9 +284,11 @@ impl Backend {
                 kind,
                 column,
                 row,
-                modifiers: _,
+                modifiers,
             }) => {
                 let position = (column, row).into();
+                // Shift+wheel is the usual way to scroll horizontally.
+                let shift = modifiers.contains(KeyModifiers::SHIFT);
                 let event = match kind {
                     MouseEventKind::Down(button) => MouseEvent::Press(translate_button(button)),
                     MouseEventKind::Up(button) => MouseEvent::Release(translate_button(button)),
@@ -282,12 +296,12 @@ impl Backend {
                     MouseEventKind::Moved => {
                         return None;
                     }
+                    MouseEventKind::ScrollDown if shift => MouseEvent::WheelRight,
+                    MouseEventKind::ScrollUp if shift => MouseEvent::WheelLeft,
                     MouseEventKind::ScrollDown => MouseEvent::WheelDown,
                     MouseEventKind::ScrollUp => MouseEvent::WheelUp,
-                    MouseEventKind::ScrollLeft | MouseEventKind::ScrollRight => {
-                        // TODO: Currently unsupported.
-                        return None;
-                    }
+                    MouseEventKind::ScrollLeft => MouseEvent::WheelLeft,
+                    MouseEventKind::ScrollRight => MouseEvent::WheelRight,
                 };
 
                 Event::Mouse {
@@ -297,9 +311,8 @@ impl Backend {
                 }
             }
             CEvent::Resize(_, _) => Event::WindowResize,
-            CEvent::Paste(_) => {
-                unreachable!("Did not enable bracketed paste.")
-            }
+            // Terminals usually send `\r` for line breaks in pasted text.
+            CEvent::Paste(text) => Event::Paste(text.replace("\r\n", "\n").replace('\r', "\n")),
             CEvent::FocusGained | CEvent::FocusLost => return None,
         })
     }
@@ -315,6 +328,7 @@ impl Drop for Backend {
                 SetBackgroundColor(Color::Reset),
                 LeaveAlternateScreen,
                 DisableMouseCapture,
+                DisableBracketedPaste,
                 cursor::Show,
                 cursor::MoveTo(0, 0),
                 terminal::Clear(terminal::ClearType::All)
@@ -402,6 +416,9 @@ impl backend::Backend for Backend {
             theme::Effect::Italic => self.set_attr(Attribute::Italic),
             theme::Effect::Strikethrough => self.set_attr(Attribute::CrossedOut),
             theme::Effect::Underline => self.set_attr(Attribute::Underlined),
+            theme::Effect::CurlyUnderline => self.set_attr(Attribute::Undercurled),
+            theme::Effect::DoubleUnderline => self.set_attr(Attribute::DoubleUnderlined),
+            theme::Effect::Overline => self.set_attr(Attribute::OverLined),
         }
     }
 
@@ -413,10 +430,41 @@ impl backend::Backend for Backend {
             theme::Effect::Blink => self.set_attr(Attribute::NoBlink),
             theme::Effect::Italic => self.set_attr(Attribute::NoItalic),
             theme::Effect::Strikethrough => self.set_attr(Attribute::NotCrossedOut),
-            theme::Effect::Underline => self.set_attr(Attribute::NoUnderline),
+            theme::Effect::Underline
+            | theme::Effect::CurlyUnderline
+            | theme::Effect::DoubleUnderline => self.set_attr(Attribute::NoUnderline),
+            theme::Effect::Overline => self.set_attr(Attribute::NotOverLined),
         }
     }
 
+    fn set_underline_color(&self, color: Option<theme::Color>) {
+        let color = color.map_or(Color::Reset, translate_color);
+        self.with_stdout(|stdout| queue!(stdout, SetUnderlineColor(color)).unwrap());
+    }
+
+    fn color_resolution(&self) -> crate::style::ColorResolution {
+        self.color_resolution
+    }
+
+    fn graphics_protocol(&self) -> Option<backend::GraphicsProtocol> {
+        self.graphics_protocol
+    }
+
+    fn cell_pixel_size(&self) -> Option<Vec2> {
+        let size = terminal::window_size().ok()?;
+        if size.columns == 0 || size.rows == 0 || size.width == 0 || size.height == 0 {
+            return None;
+        }
+        Some(Vec2::new(
+            usize::from(size.width / size.columns),
+            usize::from(size.height / size.rows),
+        ))
+    }
+
+    fn print_raw(&self, data: &str) {
+        self.with_stdout(|stdout| stdout.write_all(data.as_bytes()).unwrap());
+    }
+
     fn name(&self) -> &str {
         "crossterm"
     }
@@ -344,6 +344,10 @@ impl backend::Backend for Backend {
         ncurses::has_colors()
     }
 
+    fn color_resolution(&self) -> crate::style::ColorResolution {
+        crate::style::ColorResolution::from_colors(ncurses::COLORS().max(0) as usize)
+    }
+
     fn poll_event(&mut self) -> Option<Event> {
         self.parse_next()
     }
@@ -367,7 +371,11 @@ impl backend::Backend for Backend {
             Effect::Blink => ncurses::A_BLINK,
             Effect::Italic => ncurses::A_ITALIC,
             Effect::Strikethrough => ncurses::A_NORMAL,
-            Effect::Underline => ncurses::A_UNDERLINE,
+            // Other underline styles degrade to a plain underline.
+            Effect::Underline | Effect::CurlyUnderline | Effect::DoubleUnderline => {
+                ncurses::A_UNDERLINE
+            }
+            Effect::Overline => ncurses::A_NORMAL,
         };
         ncurses::attron(style);
     }
@@ -381,7 +389,11 @@ impl backend::Backend for Backend {
             Effect::Blink => ncurses::A_BLINK,
             Effect::Italic => ncurses::A_ITALIC,
             Effect::Strikethrough => ncurses::A_NORMAL,
-            Effect::Underline => ncurses::A_UNDERLINE,
+            // Other underline styles degrade to a plain underline.
+            Effect::Underline | Effect::CurlyUnderline | Effect::DoubleUnderline => {
+                ncurses::A_UNDERLINE
+            }
+            Effect::Overline => ncurses::A_NORMAL,
         };
         ncurses::attroff(style);
     }
@@ -396,6 +396,10 @@ impl backend::Backend for Backend {
         pancurses::has_colors()
     }
 
+    fn color_resolution(&self) -> crate::style::ColorResolution {
+        crate::style::ColorResolution::from_colors(pancurses::COLORS().max(0) as usize)
+    }
+
     fn set_color(&self, colors: ColorPair) -> ColorPair {
         let current = self.current_style.get();
 
@@ -415,7 +419,11 @@ impl backend::Backend for Backend {
             Effect::Blink => pancurses::Attribute::Blink,
             Effect::Italic => pancurses::Attribute::Italic,
             Effect::Strikethrough => pancurses::Attribute::Strikeout,
-            Effect::Underline => pancurses::Attribute::Underline,
+            // Other underline styles degrade to a plain underline.
+            Effect::Underline | Effect::CurlyUnderline | Effect::DoubleUnderline => {
+                pancurses::Attribute::Underline
+            }
+            Effect::Overline => pancurses::Attribute::Normal,
         };
         self.window.attron(style);
     }
@@ -429,7 +437,11 @@ impl backend::Backend for Backend {
             Effect::Blink => pancurses::Attribute::Blink,
             Effect::Italic => pancurses::Attribute::Italic,
             Effect::Strikethrough => pancurses::Attribute::Strikeout,
-            Effect::Underline => pancurses::Attribute::Underline,
+            // Other underline styles degrade to a plain underline.
+            Effect::Underline | Effect::CurlyUnderline | Effect::DoubleUnderline => {
+                pancurses::Attribute::Underline
+            }
+            Effect::Overline => pancurses::Attribute::Normal,
         };
         self.window.attroff(style);
     }
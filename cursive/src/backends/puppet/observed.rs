@@ -1,4 +1,5 @@
 //! Structs representing output of puppet backend
+use crate::buffer::PrintBuffer;
 use crate::reexports::enumset::EnumSet;
 use crate::theme::ColorPair;
 use crate::theme::Effect;
@@ -413,6 +414,42 @@ impl IndexMut<Vec2> for ObservedScreen {
     }
 }
 
+impl From<&PrintBuffer> for ObservedScreen {
+    /// Converts a rendered buffer, for example from [`Cursive::screenshot`].
+    ///
+    /// [`Cursive::screenshot`]: crate::Cursive::screenshot
+    fn from(buffer: &PrintBuffer) -> Self {
+        let mut screen = ObservedScreen::new(buffer.size());
+
+        for (y, row) in buffer.rows().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                let Some(cell) = cell else {
+                    continue;
+                };
+
+                let style = cell.style();
+                let style = Arc::new(ObservedStyle {
+                    colors: style.color,
+                    effects: style.effects,
+                });
+
+                let pos = Vec2::new(x, y);
+                screen[pos] = Some(ObservedCell::new(
+                    pos,
+                    style.clone(),
+                    Some(cell.text().to_string()),
+                ));
+                for dx in (1..cell.width()).take_while(|dx| x + dx < row.len()) {
+                    let pos = pos + (dx, 0);
+                    screen[pos] = Some(ObservedCell::new(pos, style.clone(), None));
+                }
+            }
+        }
+
+        screen
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -578,4 +615,30 @@ mod tests {
         assert_eq!(expanded_right.size(), Vec2::new(10, 1));
         assert_eq!(expanded_right.to_string(), "▸ <root> e");
     }
+
+    #[test]
+    fn from_print_buffer() {
+        use crate::style::{ConcreteStyle, Effect};
+
+        let mut buffer = PrintBuffer::new();
+        buffer.resize(Vec2::new(4, 2));
+        let bold = ConcreteStyle {
+            effects: Effect::Bold.into(),
+            ..ConcreteStyle::terminal_default()
+        };
+        buffer.print_at(Vec2::new(0, 0), "a界", bold);
+
+        let os = ObservedScreen::from(&buffer);
+        assert_eq!(os.size(), Vec2::new(4, 2));
+
+        let cell = os[Vec2::new(1, 0)].as_ref().unwrap();
+        assert_eq!(cell.letter.as_option(), Some(&"界".to_owned()));
+        assert!(cell.style.effects.contains(Effect::Bold));
+        assert!(os[Vec2::new(2, 0)]
+            .as_ref()
+            .unwrap()
+            .letter
+            .is_continuation());
+        assert_eq!(os[Vec2::new(3, 0)], None);
+    }
 }
@@ -29,6 +29,14 @@ use std::io::Write;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
+// Sequences enabling and disabling bracketed paste.
+const BRACKETED_PASTE_ON: &str = "\x1b[?2004h";
+const BRACKETED_PASTE_OFF: &str = "\x1b[?2004l";
+
+// Sequences sent by the terminal around pasted text.
+const PASTE_START: &[u8] = b"\x1b[200~";
+const PASTE_END: &[u8] = b"\x1b[201~";
+
 /// Backend using termion
 pub struct Backend {
     // Do we want to make this generic on the writer?
@@ -38,6 +46,9 @@ pub struct Backend {
     // Inner state required to parse input
     last_button: Option<MouseButton>,
 
+    // Text received so far, while in the middle of a bracketed paste.
+    paste: Option<String>,
+
     events: Events<File>,
 
     // Raw input file descriptor, to fix the file on exit, since we can't
@@ -47,6 +58,12 @@ pub struct Backend {
 
     resize_receiver: Receiver<()>,
     running: Arc<AtomicBool>,
+
+    // Protocol used to draw images, guessed from the environment.
+    graphics_protocol: Option<backend::GraphicsProtocol>,
+
+    // Colors the terminal can display, guessed from the environment.
+    color_resolution: crate::style::ColorResolution,
 }
 
 /// Set the given file to be read in non-blocking mode. That is, attempting a
@@ -132,7 +149,12 @@ impl Backend {
             MouseTerminal::from(output_file.into_raw_mode()?).into_alternate_screen()?,
         );
 
-        write!(terminal.borrow_mut(), "{}", termion::cursor::Hide)?;
+        write!(
+            terminal.borrow_mut(),
+            "{}{}",
+            termion::cursor::Hide,
+            BRACKETED_PASTE_ON
+        )?;
 
         let (resize_sender, resize_receiver) = crossbeam_channel::bounded(0);
         let running = Arc::new(AtomicBool::new(true));
@@ -144,11 +166,14 @@ impl Backend {
             current_style: Cell::new(theme::ColorPair::from_256colors(0, 0)),
 
             last_button: None,
+            paste: None,
             events: input_file.events(),
             #[cfg(unix)]
             input_fd,
             resize_receiver,
             running,
+            graphics_protocol: backend::GraphicsProtocol::detect(),
+            color_resolution: crate::style::ColorResolution::detect(),
         };
 
         Ok(Box::new(c))
@@ -191,9 +216,8 @@ impl Backend {
                     TMouseButton::Right => MouseEvent::Press(MouseButton::Right),
                     TMouseButton::WheelUp => MouseEvent::WheelUp,
                     TMouseButton::WheelDown => MouseEvent::WheelDown,
-                    // TODO: Support left/right wheel moves?
-                    // Or convert to left/right arrow keys?
-                    TMouseButton::WheelLeft | TMouseButton::WheelRight => return Event::Refresh,
+                    TMouseButton::WheelLeft => MouseEvent::WheelLeft,
+                    TMouseButton::WheelRight => MouseEvent::WheelRight,
                 };
 
                 if let MouseEvent::Press(btn) = event {
@@ -228,6 +252,27 @@ impl Backend {
         }
     }
 
+    // Collects bracketed pastes, and translates any other event.
+    fn map_input(&mut self, event: TEvent) -> Option<Event> {
+        let Some(paste) = &mut self.paste else {
+            if matches!(&event, TEvent::Unsupported(bytes) if bytes == PASTE_START) {
+                self.paste = Some(String::new());
+                return None;
+            }
+            return Some(self.map_key(event));
+        };
+
+        match event {
+            TEvent::Unsupported(bytes) if bytes == PASTE_END => self.paste.take().map(Event::Paste),
+            TEvent::Key(TKey::Char(c)) => {
+                paste.push(c);
+                None
+            }
+            // Anything else in the pasted text is dropped.
+            _ => None,
+        }
+    }
+
     fn write<T>(&self, content: T)
     where
         T: std::fmt::Display,
@@ -245,7 +290,8 @@ impl Drop for Backend {
 
         write!(
             self.terminal.get_mut(),
-            "{}{}",
+            "{}{}{}",
+            BRACKETED_PASTE_OFF,
             termion::cursor::Show,
             termion::cursor::Goto(1, 1)
         )
@@ -296,6 +342,10 @@ impl backend::Backend for Backend {
             theme::Effect::Italic => self.write(tstyle::Italic),
             theme::Effect::Strikethrough => self.write(tstyle::CrossedOut),
             theme::Effect::Underline => self.write(tstyle::Underline),
+            // termion has no styles for these: write the SGR sequences directly.
+            theme::Effect::CurlyUnderline => self.write("\x1b[4:3m"),
+            theme::Effect::DoubleUnderline => self.write("\x1b[4:2m"),
+            theme::Effect::Overline => self.write("\x1b[53m"),
         }
     }
 
@@ -307,10 +357,17 @@ impl backend::Backend for Backend {
             theme::Effect::Blink => self.write(tstyle::NoBlink),
             theme::Effect::Italic => self.write(tstyle::NoItalic),
             theme::Effect::Strikethrough => self.write(tstyle::NoCrossedOut),
-            theme::Effect::Underline => self.write(tstyle::NoUnderline),
+            theme::Effect::Underline
+            | theme::Effect::CurlyUnderline
+            | theme::Effect::DoubleUnderline => self.write(tstyle::NoUnderline),
+            theme::Effect::Overline => self.write("\x1b[55m"),
         }
     }
 
+    fn set_underline_color(&self, color: Option<theme::Color>) {
+        self.write(format_args!("\x1b[{}m", underline_color_code(color)));
+    }
+
     fn has_colors(&self) -> bool {
         // TODO: color support detection?
         true
@@ -349,10 +406,41 @@ impl backend::Backend for Backend {
         write!(self.terminal.borrow_mut(), "{text}",).unwrap();
     }
 
+    fn color_resolution(&self) -> crate::style::ColorResolution {
+        self.color_resolution
+    }
+
+    fn graphics_protocol(&self) -> Option<backend::GraphicsProtocol> {
+        self.graphics_protocol
+    }
+
+    fn cell_pixel_size(&self) -> Option<Vec2> {
+        let (width, height) = termion::terminal_size_pixels().ok()?;
+        let (columns, rows) = termion::terminal_size().ok()?;
+        if columns == 0 || rows == 0 || width == 0 || height == 0 {
+            return None;
+        }
+        Some(Vec2::new(
+            usize::from(width / columns),
+            usize::from(height / rows),
+        ))
+    }
+
+    fn print_raw(&self, data: &str) {
+        self.terminal
+            .borrow_mut()
+            .write_all(data.as_bytes())
+            .unwrap();
+    }
+
     fn poll_event(&mut self) -> Option<Event> {
-        if let Some(Ok(event)) = self.events.next() {
-            Some(self.map_key(event))
-        } else if let Ok(()) = self.resize_receiver.try_recv() {
+        while let Some(Ok(event)) = self.events.next() {
+            if let Some(event) = self.map_input(event) {
+                return Some(event);
+            }
+        }
+
+        if let Ok(()) = self.resize_receiver.try_recv() {
             Some(Event::WindowResize)
         } else {
             None
@@ -360,6 +448,17 @@ impl backend::Backend for Backend {
     }
 }
 
+// Returns the SGR parameters setting the underline color.
+fn underline_color_code(color: Option<theme::Color>) -> String {
+    match color {
+        None | Some(theme::Color::TerminalDefault) => "59".into(),
+        Some(theme::Color::Dark(base)) => format!("58;5;{}", base as u8),
+        Some(theme::Color::Light(base)) => format!("58;5;{}", base as u8 + 8),
+        Some(theme::Color::Rgb(r, g, b)) => format!("58;2;{r};{g};{b}"),
+        Some(theme::Color::RgbLowRes(r, g, b)) => format!("58;5;{}", 16 + 36 * r + 6 * g + b),
+    }
+}
+
 fn with_color<F, R>(clr: theme::Color, f: F) -> R
 where
     F: FnOnce(&dyn tcolor::Color) -> R,